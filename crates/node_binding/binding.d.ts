@@ -15,6 +15,11 @@ export class JsCompilation {
   getAssetSource(name: string): JsCompatSource | null
   getModules(): Array<JsModule>
   getChunks(): Array<JsChunk>
+  /**
+   * Explain why the chunk with the given id or name exists,
+   * return `null` if the chunk is not found.
+   */
+  explainChunk(idOrName: string): JsChunkExplanation | null
   /**
    * Only available for those none Js and Css source,
    * return true if set module source successfully, false if failed.
@@ -43,24 +48,43 @@ export class JsCompilation {
   addContextDependencies(deps: Array<string>): void
   addMissingDependencies(deps: Array<string>): void
   addBuildDependencies(deps: Array<string>): void
-  rebuildModule(moduleIdentifiers: Array<string>, f: (...args: any[]) => any): void
+  rebuildModule(moduleIdentifiers: string[], f: (err: Error | null, results: JsRebuildModuleResult[]) => void): void
+  addEntry(entries: JsAddEntryArgs[], f: (err: Error | null, results: JsAddEntryResult[]) => void): void
 }
 
 export class JsStats {
   getAssets(): JsStatsGetAssets
   getModules(reasons: boolean, moduleAssets: boolean, nestedModules: boolean, source: boolean): Array<JsStatsModule>
-  getChunks(chunkModules: boolean, chunksRelations: boolean, reasons: boolean, moduleAssets: boolean, nestedModules: boolean, source: boolean): Array<JsStatsChunk>
+  getChunks(chunkModules: boolean, chunksRelations: boolean, chunkExplanations: boolean, reasons: boolean, moduleAssets: boolean, nestedModules: boolean, source: boolean): Array<JsStatsChunk>
+  /**
+   * Get the modules of the chunk with the given id, `null` if the chunk is not found.
+   * The modules are fetched chunk by chunk, so the modules of a huge graph aren't converted at once.
+   */
+  getChunkModules(chunkId: string, reasons: boolean, moduleAssets: boolean, nestedModules: boolean, source: boolean): Array<JsStatsModule> | null
   getEntrypoints(): Array<JsStatsChunkGroup>
   getNamedChunkGroups(): Array<JsStatsChunkGroup>
   getErrors(): Array<JsStatsError>
   getWarnings(): Array<JsStatsWarning>
   getLogging(acceptedTypes: number): Array<JsStatsLogging>
+  getProfileSummary(): JsStatsProfileSummary
   getHash(): string
 }
 
 export class Rspack {
   constructor(options: RawOptions, jsHooks: JsHooks | undefined | null, outputFilesystem: ThreadsafeNodeFS, jsLoaderRunner: (...args: any[]) => any)
   unsafe_set_disabled_hooks(hooks: Array<string>): void
+  /**
+   * Switch the output file system, the assets are kept in memory with `"memory"`
+   * and can be read back with `unsafe_read_output_file`.
+   */
+  unsafe_set_output_file_system(kind: "node" | "memory"): void
+  /**
+   * Report the infrastructure logs of the compiler to the JS logger instead of stderr,
+   * the logs filtered out by `infrastructureLogging.level` are never sent.
+   */
+  unsafe_set_infrastructure_logger(callback: (log: JsStatsLogging) => void): void
+  /** Read an emitted file from the memory output file system, returns `null` if it doesn't exist. */
+  unsafe_read_output_file(path: string): Buffer | null
   /**
    * Build with the given option passed to the constructor
    *
@@ -75,6 +99,26 @@ export class Rspack {
    * Calling this method recursively will cause a deadlock.
    */
   unsafe_rebuild(changed_files: string[], removed_files: string[], callback: (err: null | Error) => void): void
+  /**
+   * Compile whenever the watched files change until the watching is closed, `callback` is called
+   * once the watching is stopped.
+   *
+   * The file events are reported with `unsafe_watching_notify` unless `options.poll` is set.
+   * `watch_run` returns how the changes are compiled and `done` is called after each compilation.
+   *
+   * Warning:
+   * Build and rebuild are rejected until the watching is stopped.
+   */
+  unsafe_watch(options: RawWatchOptions, watch_run: (args: JsWatchRunArgs) => Promise<"build" | "rebuild" | "blocked">, done: (error: string | null) => Promise<void>, callback: (err: null | Error) => void): void
+  /** Report the file events of the native watcher, which are compiled after `aggregateTimeout` */
+  unsafe_watching_notify(changed_files: string[], removed_files: string[]): void
+  /** Compile again even if no file is changed */
+  unsafe_watching_invalidate(): void
+  /** Stop compiling on changes, the changes are compiled after `unsafe_watching_resume` */
+  unsafe_watching_suspend(): void
+  unsafe_watching_resume(): void
+  /** Stop watching, `callback` is called after the running compilation is finished */
+  unsafe_watching_close(callback: (err: null | Error) => void): void
   /**
    * Get the last compilation
    *
@@ -98,6 +142,7 @@ export class Rspack {
 export interface AfterResolveData {
   request: string
   context: string
+  issuer?: string
   fileDependencies: Array<string>
   contextDependencies: Array<string>
   missingDependencies: Array<string>
@@ -107,6 +152,7 @@ export interface AfterResolveData {
 export interface BeforeResolveData {
   request: string
   context: string
+  issuer?: string
 }
 
 export function cleanupGlobalTrace(): void
@@ -115,6 +161,22 @@ export interface FactoryMeta {
   sideEffects?: boolean
 }
 
+/** The names of the registered builtin loaders */
+export function getBuiltinLoaderNames(): Array<string>
+
+export interface JsAddEntryArgs {
+  context?: string
+  request: string
+  name: string
+  /** Add the module with `addInclude`, which doesn't execute it on startup */
+  isInclude: boolean
+}
+
+export interface JsAddEntryResult {
+  error?: string
+  module?: JsModule
+}
+
 export interface JsAsset {
   name: string
   source?: JsCompatSource
@@ -142,8 +204,9 @@ export interface JsAssetInfo {
    * the value(s) of the content hash used for this asset
    */
   contentHash: Array<string>
+  /** when asset was created from source files (potentially transformed), the original filenames relative to compilation context */
+  sourceFilename: Array<string>
   /**
-   * when asset was created from a source file (potentially transformed), the original filename relative to compilation context
    * size in bytes, only set after asset has been emitted
    * when asset is only used for development and doesn't count towards user-facing assets
    */
@@ -160,12 +223,31 @@ export interface JsAssetInfo {
    * An empty string means no version, it will always emit
    */
   version: string
+  /** the banners added to the asset by the BannerPlugin, each of them is added only once */
+  banners: Array<string>
 }
 
 export interface JsAssetInfoRelated {
   sourceMap?: string
 }
 
+export interface JsBuildMeta {
+  esm: boolean
+  strictHarmonyModule: boolean
+  isAsync: boolean
+  /** One of `default`, `namespace`, `flagged` and `dynamic`, `undefined` if unset */
+  exportsType?: string
+  sideEffectFree?: boolean
+  /** The exports provided by a CommonJS module, `undefined` if they aren't all known */
+  providedExports?: Array<string>
+}
+
+/** A builtin loader and its JSON serialized options, run by [run_builtin_loaders] */
+export interface JsBuiltinLoader {
+  builtin: string
+  options?: string
+}
+
 export interface JsChunk {
   files: Array<string>
 }
@@ -175,6 +257,19 @@ export interface JsChunkAssetArgs {
   filename: string
 }
 
+export interface JsChunkExplanation {
+  createdBy: string
+  summary: string
+  modules: Array<JsChunkExplanationModule>
+  decisions: Array<JsSplitChunksDecision>
+  rejected: Array<JsSplitChunksDecision>
+}
+
+export interface JsChunkExplanationModule {
+  identifier: string
+  size: number
+}
+
 export interface JsChunkGroup {
   chunks: Array<JsChunk>
 }
@@ -188,6 +283,14 @@ export interface JsCompatSource {
   map?: Buffer
 }
 
+export interface JsCreateModuleData {
+  rawRequest: string
+  userRequest: string
+  resourceData: JsResourceData
+  matchResource?: JsResourceData
+  settings: Record<string, any>
+}
+
 export interface JsHooks {
   processAssetsStageAdditional: (...args: any[]) => any
   processAssetsStagePreProcess: (...args: any[]) => any
@@ -220,6 +323,7 @@ export interface JsHooks {
   buildModule: (...args: any[]) => any
   beforeResolve: (...args: any[]) => any
   afterResolve: (...args: any[]) => any
+  createModule: (...args: any[]) => any
   contextModuleBeforeResolve: (...args: any[]) => any
   normalModuleFactoryResolveForScheme: (...args: any[]) => any
   chunkAsset: (...args: any[]) => any
@@ -242,8 +346,20 @@ export interface JsLoaderContext {
   missingDependencies: Array<string>
   buildDependencies: Array<string>
   assetFilenames: Array<string>
+  /** JSON serialized metadata stashed by loaders into the build info of the module */
+  extras?: Buffer
   currentLoader: string
+  /** JSON serialized options of each loader in `current_loader`, split by `$` */
+  loaderOptions: Array<string | undefined | null>
+  /** Ident of each loader in `current_loader`, split by `$` */
+  loaderIdents: Array<string>
+  /** Origin of each loader in `current_loader`, one of `pre`, `normal`, `post` and `inline` */
+  loaderOrigins: Array<string>
+  /** Settings attached to the module by `createModule` hooks */
+  moduleSettings: Record<string, any>
   isPitching: boolean
+  /** Serialized `data` of each loader in `current_loader` stashed at the pitching stage */
+  pitchData?: Buffer
   /**
    * Internal loader context
    * @internal
@@ -263,17 +379,39 @@ export interface JsLoaderResult {
   contextDependencies: Array<string>
   missingDependencies: Array<string>
   buildDependencies: Array<string>
+  /**
+   * Glob patterns added by `addGlobDependency`, the matched files are added as file dependencies,
+   * and the directory before the first glob segment is added as a context dependency
+   */
+  globDependencies: Array<string>
   sourceMap?: Buffer
   additionalData?: Buffer
   cacheable: boolean
   /** Used to instruct how rust loaders should execute */
   isPitching: boolean
+  /** Serialized `data` of each loader stashed at the pitching stage */
+  pitchData?: Buffer
+  /** JSON serialized metadata stashed by loaders, replaces the build info extras of the module */
+  extras?: Buffer
+  /** The error of the JS loader failed midway, returned with the result produced before the failure */
+  error?: string
 }
 
 export interface JsModule {
   originalSource?: JsCompatSource
   resource: string
   moduleIdentifier: string
+  /** Metadata stashed by loaders, only available when the module is enumerated from the compilation */
+  buildInfo?: Record<string, any>
+  /** Only available when the module is enumerated from the compilation */
+  buildMeta?: JsBuildMeta
+}
+
+export interface JsRebuildModuleResult {
+  moduleIdentifier: string
+  error?: string
+  /** The rebuilt module, which is absent if its hash is unchanged */
+  module?: JsModule
 }
 
 export interface JsResolveForSchemeInput {
@@ -297,6 +435,22 @@ export interface JsResourceData {
   fragment?: string
 }
 
+export interface JsSplitChunksDecision {
+  cacheGroup: string
+  name?: string
+  modules: Array<string>
+  enforce: boolean
+  minSizeSatisfied: boolean
+  maxRequestsLimited: boolean
+  excludedModules: Array<JsSplitChunksExcludedModule>
+  rejectedReason?: string
+}
+
+export interface JsSplitChunksExcludedModule {
+  identifier: string
+  reason: string
+}
+
 export interface JsStatsAsset {
   type: string
   name: string
@@ -308,8 +462,19 @@ export interface JsStatsAsset {
 }
 
 export interface JsStatsAssetInfo {
+  immutable: boolean
+  minimized: boolean
+  chunkHash: Array<string>
+  contentHash: Array<string>
+  sourceFilename?: string
   development: boolean
   hotModuleReplacement: boolean
+  related: JsStatsAssetInfoRelated
+  deduplicated?: number
+}
+
+export interface JsStatsAssetInfoRelated {
+  sourceMap?: string
 }
 
 export interface JsStatsAssetsByChunkName {
@@ -330,6 +495,7 @@ export interface JsStatsChunk {
   parents?: Array<string>
   children?: Array<string>
   siblings?: Array<string>
+  reason?: string
 }
 
 export interface JsStatsChunkGroup {
@@ -337,6 +503,7 @@ export interface JsStatsChunkGroup {
   assets: Array<JsStatsChunkGroupAsset>
   chunks: Array<string>
   assetsSize: number
+  fetchPriority?: string
 }
 
 export interface JsStatsChunkGroupAsset {
@@ -348,6 +515,10 @@ export interface JsStatsError {
   message: string
   formatted: string
   title: string
+  moduleIdentifier?: string
+  moduleName?: string
+  moduleTrace: Array<JsStatsModuleTrace>
+  details?: string
 }
 
 export interface JsStatsGetAssets {
@@ -355,6 +526,11 @@ export interface JsStatsGetAssets {
   assetsByChunkName: Array<JsStatsAssetsByChunkName>
 }
 
+export interface JsStatsLoaderProfile {
+  identifier: string
+  duration: JsStatsMillisecond
+}
+
 export interface JsStatsLogging {
   name: string
   type: string
@@ -383,6 +559,7 @@ export interface JsStatsModule {
   assets?: Array<string>
   source?: string | Buffer
   profile?: JsStatsModuleProfile
+  optimizationBailout: Array<string>
 }
 
 export interface JsStatsModuleIssuer {
@@ -395,6 +572,8 @@ export interface JsStatsModuleProfile {
   factory: JsStatsMillisecond
   integration: JsStatsMillisecond
   building: JsStatsMillisecond
+  loaders: Array<JsStatsLoaderProfile>
+  parsing?: JsStatsMillisecond
 }
 
 export interface JsStatsModuleReason {
@@ -403,11 +582,44 @@ export interface JsStatsModuleReason {
   moduleId?: string
   type?: string
   userRequest?: string
+  loc?: string
+}
+
+export interface JsStatsModuleTrace {
+  moduleIdentifier: string
+  moduleName: string
+  loc?: string
+}
+
+export interface JsStatsProfileSummary {
+  modules: Array<JsStatsProfileSummaryModule>
+  loaders: Array<JsStatsProfileSummaryLoader>
+}
+
+export interface JsStatsProfileSummaryLoader {
+  identifier: string
+  duration: JsStatsMillisecond
+  modules: number
+}
+
+export interface JsStatsProfileSummaryModule {
+  identifier: string
+  name: string
+  building: JsStatsMillisecond
 }
 
 export interface JsStatsWarning {
   message: string
   formatted: string
+  moduleIdentifier?: string
+  moduleName?: string
+  moduleTrace: Array<JsStatsModuleTrace>
+  details?: string
+}
+
+export interface JsWatchRunArgs {
+  changedFiles: Array<string>
+  removedFiles: Array<string>
 }
 
 export interface NodeFS {
@@ -432,8 +644,14 @@ export interface PathWithInfo {
 }
 
 export interface RawAssetGeneratorDataUrl {
-  type: "options"
+  type: "options" | "function"
   options?: RawAssetGeneratorDataUrlOptions
+  function?: (args: RawAssetGeneratorDataUrlFnArgs) => string
+}
+
+export interface RawAssetGeneratorDataUrlFnArgs {
+  filename: string
+  content: Buffer
 }
 
 export interface RawAssetGeneratorDataUrlOptions {
@@ -483,7 +701,7 @@ export interface RawBannerConditions {
 }
 
 export interface RawBannerConfig {
-  banner: string
+  banner: RawBannerContent
   entryOnly?: boolean
   footer?: boolean
   raw?: boolean
@@ -492,6 +710,24 @@ export interface RawBannerConfig {
   exclude?: RawBannerConditions
 }
 
+export interface RawBannerContent {
+  type: "string" | "function"
+  stringPayload?: string
+  fnPayload?: (args: RawBannerContentFnCtx) => string
+}
+
+export interface RawBannerContentFnCtx {
+  hash: string
+  chunk: RawBannerContentFnCtxChunk
+  filename: string
+}
+
+export interface RawBannerContentFnCtxChunk {
+  id?: string
+  name?: string
+  hash?: string
+}
+
 export interface RawBuiltins {
   html?: Array<RawHtmlPluginConfig>
   css?: RawCssPluginConfig
@@ -511,6 +747,8 @@ export interface RawBuiltins {
   pluginImport?: Array<RawPluginImportConfig>
   relay?: RawRelayConfig
   codeGeneration?: RawCodeGeneration
+  manifest?: RawManifestConfig
+  containerReference?: RawContainerReferenceConfig
 }
 
 export interface RawCacheGroupOptions {
@@ -542,14 +780,35 @@ export interface RawCacheOptions {
   version: string
 }
 
+export interface RawCleanKeep {
+  type: "string" | "regexp"
+  stringMatcher?: string
+  regexpMatcher?: string
+}
+
+export interface RawCleanOptions {
+  enable: boolean
+  keep?: RawCleanKeep
+}
+
 export interface RawCodeGeneration {
   keepComments: boolean
 }
 
+export interface RawContainerReferenceConfig {
+  remoteType: string
+  remotes: Array<RawRemoteOptions>
+}
+
 export interface RawCopyConfig {
   patterns: Array<RawPattern>
 }
 
+export interface RawCopyToFnCtx {
+  context: string
+  absoluteFilename: string
+}
+
 export interface RawCrossOriginLoading {
   type: "bool" | "string"
   stringPayload?: string
@@ -575,6 +834,22 @@ export interface RawDevServer {
   hot: boolean
 }
 
+export interface RawDevtoolIgnoreList {
+  type: "string" | "regexp"
+  stringMatcher?: string
+  regexpMatcher?: string
+}
+
+/** The entry provided as a function, which is called at the start of each compilation */
+export interface RawDynamicEntry {
+  function?: () => Promise<RawDynamicEntryItem[]>
+}
+
+export interface RawDynamicEntryItem {
+  name: string
+  description: RawEntryDescription
+}
+
 export interface RawEntryDescription {
   import: Array<string>
   runtime?: string
@@ -583,6 +858,12 @@ export interface RawEntryDescription {
   publicPath?: string
   baseUri?: string
   filename?: string
+  dependOn?: Array<string>
+}
+
+export interface RawEnvironment {
+  arrowFunction: boolean
+  const: boolean
 }
 
 export interface RawExperiments {
@@ -591,6 +872,7 @@ export interface RawExperiments {
   asyncWebAssembly: boolean
   newSplitChunks: boolean
   css: boolean
+  topLevelAwait: boolean
 }
 
 export interface RawExternalItem {
@@ -679,11 +961,29 @@ export interface RawHtmlPluginConfig {
   meta?: Record<string, Record<string, string>>
 }
 
+export interface RawIgnoreWarning {
+  type: "message" | "module" | "function"
+  regexpMatcher?: string
+  funcMatcher?: (warning: RawIgnoreWarningFnCtx) => boolean
+}
+
+export interface RawIgnoreWarningFnCtx {
+  message: string
+  moduleIdentifier?: string
+  file?: string
+}
+
 export interface RawIncrementalRebuild {
   make: boolean
   emitAsset: boolean
 }
 
+export interface RawInfrastructureLoggingOptions {
+  level: 'none' | 'error' | 'warn' | 'info' | 'log' | 'verbose'
+  /** Whether `infrastructureLogging.debug` enables the debug logs of any logger */
+  debug: boolean
+}
+
 export interface RawLibraryAuxiliaryComment {
   root?: string
   commonjs?: string
@@ -705,6 +1005,10 @@ export interface RawLibraryOptions {
   auxiliaryComment?: RawLibraryAuxiliaryComment
 }
 
+export interface RawManifestConfig {
+  filename?: string
+}
+
 export interface RawMinification {
   passes: number
   dropConsole: boolean
@@ -799,6 +1103,9 @@ export interface RawOptimizationOptions {
   removeEmptyChunks: boolean
   sideEffects: string
   realContentHash: boolean
+  runtimeChunk?: RawRuntimeChunkOptions
+  concatenateModules: boolean
+  mangleExports: "false" | "deterministic" | "size"
 }
 
 export interface RawOptions {
@@ -809,6 +1116,8 @@ export interface RawOptions {
    * https://doc.rust-lang.org/std/collections/index.html#iterators:~:text=For%20unordered%20collections%20like%20HashMap%2C%20the%20items%20will%20be%20yielded%20in%20whatever%20order%20the%20internal%20representation%20made%20most%20convenient.%20This%20is%20great%20for%20reading%20through%20all%20the%20contents%20of%20the%20collection.
    */
   entryOrder: Array<string>
+  /** The entry provided as a function, `entry` is empty in this case */
+  dynamicEntry?: RawDynamicEntry
   mode?: undefined | 'production' | 'development' | 'none'
   target: Array<string>
   context: string
@@ -828,12 +1137,17 @@ export interface RawOptions {
   cache: RawCacheOptions
   experiments: RawExperiments
   node?: RawNodeOption
+  amd: boolean
+  /** Whether `require.cache` is the module cache, only for the node targets */
+  requireCache: boolean
   profile: boolean
+  ignoreWarnings?: Array<RawIgnoreWarning>
+  infrastructureLogging: RawInfrastructureLoggingOptions
 }
 
 export interface RawOutputOptions {
   path: string
-  clean: boolean
+  clean: RawCleanOptions
   publicPath: string
   assetModuleFilename: string
   wasmLoading: string
@@ -848,11 +1162,13 @@ export interface RawOutputOptions {
   hotUpdateChunkFilename: string
   uniqueName: string
   chunkLoadingGlobal: string
+  hotUpdateGlobal: string
   library?: RawLibraryOptions
   strictModuleErrorHandling: boolean
   enabledLibraryTypes?: Array<string>
   globalObject: string
   importFunctionName: string
+  importMetaName: string
   iife: boolean
   module: boolean
   chunkFormat: string
@@ -860,6 +1176,8 @@ export interface RawOutputOptions {
   enabledChunkLoadingTypes?: Array<string>
   trustedTypes?: RawTrustedTypes
   sourceMapFilename: string
+  /** The sources listed in the `ignoreList` of the source maps, taken by the devtool plugin */
+  devtoolIgnoreList?: RawDevtoolIgnoreList
   hashFunction: string
   hashDigest: string
   hashDigestLength: number
@@ -868,6 +1186,7 @@ export interface RawOutputOptions {
   workerChunkLoading: string
   workerWasmLoading: string
   workerPublicPath: string
+  environment: RawEnvironment
 }
 
 export interface RawParserOptions {
@@ -878,6 +1197,7 @@ export interface RawParserOptions {
 export interface RawPattern {
   from: string
   to?: string
+  toFn?: (ctx: RawCopyToFnCtx) => string
   context?: string
   toType?: string
   noErrorOnMissing: boolean
@@ -906,6 +1226,14 @@ export interface RawPresetEnv {
 
 export interface RawProgressPluginConfig {
   prefix?: string
+  profile?: boolean
+  handler?: (args: RawProgressPluginHandlerArgs) => void
+}
+
+export interface RawProgressPluginHandlerArgs {
+  percentage: number
+  msg: string
+  items: Array<string>
 }
 
 export interface RawReactOptions {
@@ -925,6 +1253,12 @@ export interface RawRelayConfig {
   language: 'javascript' | 'typescript' | 'flow'
 }
 
+export interface RawRemoteOptions {
+  key: string
+  external: string
+  shareScope: string
+}
+
 export interface RawResolveOptions {
   preferRelative?: boolean
   extensions?: Array<string>
@@ -936,6 +1270,7 @@ export interface RawResolveOptions {
   fallback?: Record<string, Array<string | false>>
   symlinks?: boolean
   tsConfigPath?: string
+  tsconfig?: RawResolveTsconfigOptions
   modules?: Array<string>
   byDependency?: Record<string, RawResolveOptions>
   fullySpecified?: boolean
@@ -943,6 +1278,13 @@ export interface RawResolveOptions {
   extensionAlias?: Record<string, Array<string>>
 }
 
+export interface RawResolveTsconfigOptions {
+  configFile: string
+  referencesType: "auto" | "manual" | "disabled"
+  /** Only used when `referencesType` is `manual` */
+  references?: Array<string>
+}
+
 export interface RawRuleSetCondition {
   type: "string" | "regexp" | "logical" | "array" | "function"
   stringMatcher?: string
@@ -958,9 +1300,27 @@ export interface RawRuleSetLogicalConditions {
   not?: RawRuleSetCondition
 }
 
+export interface RawRuntimeChunkNameFnCtx {
+  name: string
+}
+
+export interface RawRuntimeChunkOptions {
+  type: "single" | "multiple" | "string" | "function"
+  stringPayload?: string
+  fnPayload?: (ctx: RawRuntimeChunkNameFnCtx) => string | undefined
+}
+
 export interface RawSnapshotOptions {
   resolve: RawSnapshotStrategy
   module: RawSnapshotStrategy
+  immutablePaths: Array<RawSnapshotPath>
+  managedPaths: Array<RawSnapshotPath>
+}
+
+export interface RawSnapshotPath {
+  type: "string" | "regexp"
+  stringMatcher?: string
+  regexpMatcher?: string
 }
 
 export interface RawSnapshotStrategy {
@@ -987,6 +1347,8 @@ export interface RawSplitChunksOptions {
 
 export interface RawStatsOptions {
   colors: boolean
+  similarDiagnosticsThreshold?: number
+  moduleTraceDepth: number
 }
 
 export interface RawStyleConfig {
@@ -1000,6 +1362,13 @@ export interface RawTrustedTypes {
   policyName?: string
 }
 
+export interface RawWatchOptions {
+  aggregateTimeout?: number
+  /** The interval of polling in milliseconds, the native watcher is used if it's `undefined` */
+  poll?: number
+  ignored?: Array<string>
+}
+
 /**
  * Some code is modified based on
  * https://github.com/swc-project/swc/blob/d1d0607158ab40463d1b123fed52cc526eba8385/bindings/binding_core_node/src/util.rs#L29-L58
@@ -1012,11 +1381,18 @@ export function registerGlobalTrace(filter: string, layer: "chrome" | "logger",
 /** Builtin loader runner */
 export function runBuiltinLoader(builtin: string, options: string | undefined | null, loaderContext: JsLoaderContext): Promise<JsLoaderContext>
 
+/**
+ * Run several builtin loaders in one call, the pitching loaders in order and then the normal
+ * loaders in reverse order, like the loader runner
+ */
+export function runBuiltinLoaders(loaders: Array<JsBuiltinLoader>, loaderContext: JsLoaderContext): Promise<JsLoaderContext>
+
 export interface ThreadsafeNodeFS {
   writeFile: (...args: any[]) => any
   removeFile: (...args: any[]) => any
   mkdir: (...args: any[]) => any
   mkdirp: (...args: any[]) => any
   removeDirAll: (...args: any[]) => any
+  readDirAll: (...args: any[]) => any
 }
 
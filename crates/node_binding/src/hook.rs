@@ -37,6 +37,7 @@ pub enum Hook {
   NormalModuleFactoryResolveForScheme,
   AfterResolve,
   BeforeResolve,
+  CreateModule,
   SucceedModule,
   StillValidModule,
 }
@@ -77,6 +78,7 @@ impl From<String> for Hook {
       "normalModuleFactoryResolveForScheme" => Hook::NormalModuleFactoryResolveForScheme,
       "afterResolve" => Hook::AfterResolve,
       "beforeResolve" => Hook::BeforeResolve,
+      "createModule" => Hook::CreateModule,
       "succeedModule" => Hook::SucceedModule,
       "stillValidModule" => Hook::StillValidModule,
       hook_name => panic!("{hook_name} is an invalid hook name"),
@@ -26,8 +26,8 @@ pub struct JsAssetInfo {
   // pub module_hash:
   /// the value(s) of the content hash used for this asset
   pub content_hash: Vec<String>,
-  /// when asset was created from a source file (potentially transformed), the original filename relative to compilation context
-  // pub source_filename:
+  /// when asset was created from source files (potentially transformed), the original filenames relative to compilation context
+  pub source_filename: Vec<String>,
   /// size in bytes, only set after asset has been emitted
   // pub size: f64,
   /// when asset is only used for development and doesn't count towards user-facing assets
@@ -41,6 +41,8 @@ pub struct JsAssetInfo {
   /// the asset version, emit can be skipped when both filename and version are the same
   /// An empty string means no version, it will always emit
   pub version: String,
+  /// the banners added to the asset by the BannerPlugin, each of them is added only once
+  pub banners: Vec<String>,
 }
 
 impl From<JsAssetInfo> for rspack_core::AssetInfo {
@@ -53,7 +55,9 @@ impl From<JsAssetInfo> for rspack_core::AssetInfo {
       chunk_hash: i.chunk_hash.into_iter().collect(),
       related: i.related.into(),
       content_hash: i.content_hash.into_iter().collect(),
+      source_filename: i.source_filename.into_iter().collect(),
       version: i.version,
+      banners: i.banners.into_iter().collect(),
     }
   }
 }
@@ -83,7 +87,9 @@ impl From<rspack_core::AssetInfo> for JsAssetInfo {
       related: info.related.into(),
       chunk_hash: info.chunk_hash.into_iter().collect(),
       content_hash: info.content_hash.into_iter().collect(),
+      source_filename: info.source_filename.into_iter().collect(),
       version: info.version,
+      banners: info.banners.into_iter().collect(),
     }
   }
 }
@@ -27,3 +27,89 @@ impl From<&ChunkAssetArgs<'_>> for JsChunkAssetArgs {
     }
   }
 }
+
+#[napi(object)]
+pub struct JsChunkExplanationModule {
+  pub identifier: String,
+  pub size: f64,
+}
+
+#[napi(object)]
+pub struct JsSplitChunksExcludedModule {
+  pub identifier: String,
+  pub reason: String,
+}
+
+#[napi(object)]
+pub struct JsSplitChunksDecision {
+  pub cache_group: String,
+  pub name: Option<String>,
+  pub modules: Vec<String>,
+  pub enforce: bool,
+  pub min_size_satisfied: bool,
+  pub max_requests_limited: bool,
+  pub excluded_modules: Vec<JsSplitChunksExcludedModule>,
+  pub rejected_reason: Option<String>,
+}
+
+impl From<rspack_core::SplitChunksDecision> for JsSplitChunksDecision {
+  fn from(decision: rspack_core::SplitChunksDecision) -> Self {
+    Self {
+      cache_group: decision.cache_group,
+      name: decision.name,
+      modules: decision
+        .modules
+        .into_iter()
+        .map(|identifier| identifier.to_string())
+        .collect(),
+      enforce: decision.enforce,
+      min_size_satisfied: decision.min_size_satisfied,
+      max_requests_limited: decision.max_requests_limited,
+      excluded_modules: decision
+        .excluded_modules
+        .into_iter()
+        .map(|(identifier, reason)| JsSplitChunksExcludedModule {
+          identifier: identifier.to_string(),
+          reason,
+        })
+        .collect(),
+      rejected_reason: decision.rejected_reason,
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsChunkExplanation {
+  pub created_by: String,
+  pub summary: String,
+  pub modules: Vec<JsChunkExplanationModule>,
+  pub decisions: Vec<JsSplitChunksDecision>,
+  pub rejected: Vec<JsSplitChunksDecision>,
+}
+
+impl From<rspack_core::ChunkExplanation> for JsChunkExplanation {
+  fn from(explanation: rspack_core::ChunkExplanation) -> Self {
+    Self {
+      created_by: explanation.created_by.to_string(),
+      summary: explanation.summary(),
+      modules: explanation
+        .modules
+        .into_iter()
+        .map(|(identifier, size)| JsChunkExplanationModule {
+          identifier: identifier.to_string(),
+          size,
+        })
+        .collect(),
+      decisions: explanation
+        .decisions
+        .into_iter()
+        .map(JsSplitChunksDecision::from)
+        .collect(),
+      rejected: explanation
+        .rejected
+        .into_iter()
+        .map(JsSplitChunksDecision::from)
+        .collect(),
+    }
+  }
+}
@@ -7,6 +7,7 @@ use rspack_core::rspack_sources::BoxSource;
 use rspack_core::AssetInfo;
 use rspack_core::ModuleIdentifier;
 use rspack_core::{rspack_sources::SourceExt, NormalModuleSource};
+use rspack_core::{Context, RuntimeEntry};
 use rspack_error::Diagnostic;
 use rspack_identifier::Identifier;
 use rspack_napi_shared::NapiResultExt;
@@ -15,7 +16,11 @@ use super::module::ToJsModule;
 use super::PathWithInfo;
 use crate::utils::callbackify;
 use crate::{
-  js_values::{chunk::JsChunk, module::JsModule, PathData},
+  js_values::{
+    chunk::{JsChunk, JsChunkExplanation},
+    module::JsModule,
+    PathData,
+  },
   CompatSource, JsAsset, JsAssetInfo, JsChunkGroup, JsCompatSource, JsStats, ToJsCompatSource,
 };
 
@@ -66,7 +71,11 @@ impl JsCompilation {
         let new_info: napi::Result<Option<AssetInfo>> = asset_info_update_or_function
           .map(
             |asset_info_update_or_function| match asset_info_update_or_function {
-              Either::A(asset_info) => Ok(asset_info.into()),
+              Either::A(asset_info) => {
+                let mut info = original_info.clone();
+                info.merge_another_asset(asset_info.into());
+                Ok(info)
+              }
               Either::B(asset_info_fn) => {
                 let asset_info = unsafe {
                   call_js_function_with_napi_objects!(
@@ -142,7 +151,11 @@ impl JsCompilation {
       .module_graph
       .modules()
       .values()
-      .filter_map(|module| module.to_js_module().ok())
+      .filter_map(|module| {
+        module
+          .to_js_module_with_build(&self.inner.module_graph)
+          .ok()
+      })
       .collect::<Vec<_>>()
   }
 
@@ -156,6 +169,16 @@ impl JsCompilation {
       .collect::<Vec<_>>()
   }
 
+  #[napi]
+  /// Explain why the chunk with the given id or name exists,
+  /// return `null` if the chunk is not found.
+  pub fn explain_chunk(&self, id_or_name: String) -> Option<JsChunkExplanation> {
+    self
+      .inner
+      .explain_chunk(&id_or_name)
+      .map(JsChunkExplanation::from)
+  }
+
   #[napi]
   /// Only available for those none Js and Css source,
   /// return true if set module source successfully, false if failed.
@@ -393,7 +416,9 @@ impl JsCompilation {
       .extend(deps.into_iter().map(PathBuf::from))
   }
 
-  #[napi]
+  #[napi(
+    ts_args_type = r#"moduleIdentifiers: string[], f: (err: Error | null, results: JsRebuildModuleResult[]) => void"#
+  )]
   pub fn rebuild_module(
     &'static mut self,
     env: Env,
@@ -401,7 +426,7 @@ impl JsCompilation {
     f: JsFunction,
   ) -> Result<()> {
     callbackify(env, f, async {
-      let modules = self
+      let results = self
         .inner
         .rebuild_module(rustc_hash::FxHashSet::from_iter(
           module_identifiers.into_iter().map(ModuleIdentifier::from),
@@ -409,13 +434,103 @@ impl JsCompilation {
         .await
         .map_err(|e| Error::new(napi::Status::GenericFailure, format!("{e}")))?;
       Ok(
-        modules
+        results
           .into_iter()
-          .filter_map(|item| item.to_js_module().ok())
+          .map(|(module_identifier, result)| match result {
+            Ok(changed) => JsRebuildModuleResult {
+              module_identifier: module_identifier.to_string(),
+              error: None,
+              // The modules whose hash is unchanged are left intact
+              module: changed
+                .then(|| {
+                  self
+                    .inner
+                    .module_graph
+                    .module_by_identifier(&module_identifier)
+                    .and_then(|module| module.to_js_module().ok())
+                })
+                .flatten(),
+            },
+            Err(e) => JsRebuildModuleResult {
+              module_identifier: module_identifier.to_string(),
+              error: Some(format!("{e}")),
+              module: None,
+            },
+          })
           .collect::<Vec<_>>(),
       )
     })
   }
+
+  #[napi(
+    ts_args_type = r#"entries: JsAddEntryArgs[], f: (err: Error | null, results: JsAddEntryResult[]) => void"#
+  )]
+  pub fn add_entry(
+    &'static mut self,
+    env: Env,
+    entries: Vec<JsAddEntryArgs>,
+    f: JsFunction,
+  ) -> Result<()> {
+    callbackify(env, f, async {
+      let entries = entries
+        .into_iter()
+        .map(|entry| RuntimeEntry {
+          context: entry.context.map(Context::from),
+          request: entry.request,
+          name: entry.name,
+          options: Default::default(),
+          is_include: entry.is_include,
+        })
+        .collect();
+      let results = self
+        .inner
+        .add_entry_runtime(entries)
+        .await
+        .map_err(|e| Error::new(napi::Status::GenericFailure, format!("{e}")))?;
+      Ok(
+        results
+          .into_iter()
+          .map(|result| match result {
+            Ok(module_identifier) => JsAddEntryResult {
+              error: None,
+              module: self
+                .inner
+                .module_graph
+                .module_by_identifier(&module_identifier)
+                .and_then(|module| module.to_js_module().ok()),
+            },
+            Err(e) => JsAddEntryResult {
+              error: Some(format!("{e}")),
+              module: None,
+            },
+          })
+          .collect::<Vec<_>>(),
+      )
+    })
+  }
+}
+
+#[napi(object)]
+pub struct JsAddEntryArgs {
+  pub context: Option<String>,
+  pub request: String,
+  pub name: String,
+  /// Add the module with `addInclude`, which doesn't execute it on startup
+  pub is_include: bool,
+}
+
+#[napi(object)]
+pub struct JsAddEntryResult {
+  pub error: Option<String>,
+  pub module: Option<JsModule>,
+}
+
+#[napi(object)]
+pub struct JsRebuildModuleResult {
+  pub module_identifier: String,
+  pub error: Option<String>,
+  /// The rebuilt module, which is absent if its hash is unchanged
+  pub module: Option<JsModule>,
 }
 
 impl JsCompilation {
@@ -33,6 +33,7 @@ pub struct JsHooks {
   pub build_module: JsFunction,
   pub before_resolve: JsFunction,
   pub after_resolve: JsFunction,
+  pub create_module: JsFunction,
   pub context_module_before_resolve: JsFunction,
   pub normal_module_factory_resolve_for_scheme: JsFunction,
   pub chunk_asset: JsFunction,
@@ -1,5 +1,5 @@
 use napi::bindgen_prelude::*;
-use rspack_core::Module;
+use rspack_core::{BuildMeta, BuildMetaExportsType, BuildMetaProvidedExports, Module, ModuleGraph};
 use rspack_identifier::Identifiable;
 
 use super::{JsCompatSource, ToJsCompatSource};
@@ -9,10 +9,55 @@ pub struct JsModule {
   pub original_source: Option<JsCompatSource>,
   pub resource: String,
   pub module_identifier: String,
+  /// Metadata stashed by loaders, only available when the module is enumerated from the compilation
+  #[napi(ts_type = "Record<string, any>")]
+  pub build_info: Option<serde_json::Map<String, serde_json::Value>>,
+  /// Only available when the module is enumerated from the compilation
+  pub build_meta: Option<JsBuildMeta>,
+}
+
+#[napi(object)]
+pub struct JsBuildMeta {
+  pub esm: bool,
+  pub strict_harmony_module: bool,
+  pub is_async: bool,
+  /// One of `default`, `namespace`, `flagged` and `dynamic`, `undefined` if unset
+  pub exports_type: Option<String>,
+  pub side_effect_free: Option<bool>,
+  /// The exports provided by a CommonJS module, `undefined` if they aren't all known
+  pub provided_exports: Option<Vec<String>>,
+}
+
+impl From<&BuildMeta> for JsBuildMeta {
+  fn from(build_meta: &BuildMeta) -> Self {
+    let exports_type = match build_meta.exports_type {
+      BuildMetaExportsType::Unset => None,
+      BuildMetaExportsType::Default => Some("default"),
+      BuildMetaExportsType::Namespace => Some("namespace"),
+      BuildMetaExportsType::Flagged => Some("flagged"),
+      BuildMetaExportsType::Dynamic => Some("dynamic"),
+    };
+    Self {
+      esm: build_meta.esm,
+      strict_harmony_module: build_meta.strict_harmony_module,
+      is_async: build_meta.is_async,
+      exports_type: exports_type.map(ToOwned::to_owned),
+      side_effect_free: build_meta.side_effect_free,
+      provided_exports: match &build_meta.provided_exports {
+        BuildMetaProvidedExports::Names(names) => {
+          Some(names.iter().map(|name| name.to_string()).collect())
+        }
+        BuildMetaProvidedExports::Partial(_) | BuildMetaProvidedExports::Unknown => None,
+      },
+    }
+  }
 }
 
 pub trait ToJsModule {
   fn to_js_module(&self) -> Result<JsModule>;
+
+  /// Same as [ToJsModule::to_js_module], with the build info and build meta recorded in the module graph.
+  fn to_js_module_with_build(&self, module_graph: &ModuleGraph) -> Result<JsModule>;
 }
 
 impl ToJsModule for dyn Module + '_ {
@@ -31,7 +76,21 @@ impl ToJsModule for dyn Module + '_ {
           .to_string_lossy()
           .to_string(),
         module_identifier: normal_module.identifier().to_string(),
+        build_info: None,
+        build_meta: None,
       })
       .map_err(|_| napi::Error::from_reason("Failed to convert module to JsModule"))
   }
+
+  fn to_js_module_with_build(&self, module_graph: &ModuleGraph) -> Result<JsModule> {
+    let mut js_module = self.to_js_module()?;
+    if let Some(mgm) = module_graph.module_graph_module_by_identifier(&self.identifier()) {
+      js_module.build_info = mgm
+        .build_info
+        .as_ref()
+        .map(|build_info| build_info.build_info_extras.values.clone());
+      js_module.build_meta = mgm.build_meta.as_ref().map(JsBuildMeta::from);
+    }
+    Ok(js_module)
+  }
 }
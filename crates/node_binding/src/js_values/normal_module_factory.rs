@@ -1,4 +1,8 @@
-use rspack_core::{NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs, ResourceData};
+use std::{collections::HashMap, path::PathBuf};
+
+use rspack_core::{
+  CreateModuleData, NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs, ResourceData,
+};
 
 #[napi(object)]
 pub struct JsResolveForSchemeInput {
@@ -16,18 +20,30 @@ pub struct JsResolveForSchemeResult {
 pub struct BeforeResolveData {
   pub request: String,
   pub context: String,
+  pub issuer: Option<String>,
 }
 
 #[napi(object)]
 pub struct AfterResolveData {
   pub request: String,
   pub context: String,
+  pub issuer: Option<String>,
   pub file_dependencies: Vec<String>,
   pub context_dependencies: Vec<String>,
   pub missing_dependencies: Vec<String>,
   pub factory_meta: FactoryMeta,
 }
 
+#[napi(object)]
+pub struct JsCreateModuleData {
+  pub raw_request: String,
+  pub user_request: String,
+  pub resource_data: JsResourceData,
+  pub match_resource: Option<JsResourceData>,
+  #[napi(ts_type = "Record<string, any>")]
+  pub settings: HashMap<String, serde_json::Value>,
+}
+
 #[napi(object)]
 pub struct FactoryMeta {
   pub side_effects: Option<bool>,
@@ -56,6 +72,56 @@ impl From<ResourceData> for JsResourceData {
   }
 }
 
+impl From<&ResourceData> for JsResourceData {
+  fn from(value: &ResourceData) -> Self {
+    Self {
+      resource: value.resource.to_owned(),
+      path: value.resource_path.to_string_lossy().to_string(),
+      query: value.resource_query.to_owned(),
+      fragment: value.resource_fragment.to_owned(),
+    }
+  }
+}
+
+impl JsResourceData {
+  /// Write the values modified on js side back, description data is kept as is.
+  fn apply_to(self, resource_data: &mut ResourceData) {
+    resource_data.resource = self.resource;
+    resource_data.resource_path = PathBuf::from(self.path);
+    resource_data.resource_query = self.query;
+    resource_data.resource_fragment = self.fragment;
+  }
+}
+
+impl From<&CreateModuleData> for JsCreateModuleData {
+  fn from(value: &CreateModuleData) -> Self {
+    Self {
+      raw_request: value.raw_request.to_owned(),
+      user_request: value.user_request.to_owned(),
+      resource_data: (&value.resource_data).into(),
+      match_resource: value.match_resource.as_ref().map(Into::into),
+      settings: value.settings.clone(),
+    }
+  }
+}
+
+impl JsCreateModuleData {
+  pub fn apply_to(self, data: &mut CreateModuleData) {
+    data.raw_request = self.raw_request;
+    data.user_request = self.user_request;
+    self.resource_data.apply_to(&mut data.resource_data);
+    data.match_resource = self.match_resource.map(|match_resource| {
+      let mut resource_data = data
+        .match_resource
+        .take()
+        .unwrap_or_else(|| ResourceData::new(String::new(), PathBuf::new()));
+      match_resource.apply_to(&mut resource_data);
+      resource_data
+    });
+    data.settings = self.settings;
+  }
+}
+
 impl From<ResourceData> for JsResolveForSchemeInput {
   fn from(value: ResourceData) -> Self {
     Self {
@@ -70,6 +136,7 @@ impl From<NormalModuleBeforeResolveArgs> for BeforeResolveData {
     Self {
       context: value.context,
       request: value.request,
+      issuer: value.issuer,
     }
   }
 }
@@ -79,6 +146,7 @@ impl From<NormalModuleAfterResolveArgs<'_>> for AfterResolveData {
     Self {
       context: value.context.to_owned(),
       request: value.request.to_string(),
+      issuer: value.issuer.map(ToOwned::to_owned),
       file_dependencies: value
         .file_dependencies
         .clone()
@@ -13,6 +13,10 @@ pub struct JsStatsError {
   pub message: String,
   pub formatted: String,
   pub title: String,
+  pub module_identifier: Option<String>,
+  pub module_name: Option<String>,
+  pub module_trace: Vec<JsStatsModuleTrace>,
+  pub details: Option<String>,
 }
 
 impl From<rspack_core::StatsError> for JsStatsError {
@@ -21,6 +25,10 @@ impl From<rspack_core::StatsError> for JsStatsError {
       message: stats.message,
       formatted: stats.formatted,
       title: stats.title,
+      module_identifier: stats.module_identifier,
+      module_name: stats.module_name,
+      module_trace: stats.module_trace.into_iter().map(Into::into).collect(),
+      details: stats.details,
     }
   }
 }
@@ -29,6 +37,10 @@ impl From<rspack_core::StatsError> for JsStatsError {
 pub struct JsStatsWarning {
   pub message: String,
   pub formatted: String,
+  pub module_identifier: Option<String>,
+  pub module_name: Option<String>,
+  pub module_trace: Vec<JsStatsModuleTrace>,
+  pub details: Option<String>,
 }
 
 impl From<rspack_core::StatsWarning> for JsStatsWarning {
@@ -36,6 +48,27 @@ impl From<rspack_core::StatsWarning> for JsStatsWarning {
     Self {
       message: stats.message,
       formatted: stats.formatted,
+      module_identifier: stats.module_identifier,
+      module_name: stats.module_name,
+      module_trace: stats.module_trace.into_iter().map(Into::into).collect(),
+      details: stats.details,
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsStatsModuleTrace {
+  pub module_identifier: String,
+  pub module_name: String,
+  pub loc: Option<String>,
+}
+
+impl From<rspack_core::StatsModuleTrace> for JsStatsModuleTrace {
+  fn from(stats: rspack_core::StatsModuleTrace) -> Self {
+    Self {
+      module_identifier: stats.module_identifier,
+      module_name: stats.module_name,
+      loc: stats.loc,
     }
   }
 }
@@ -174,15 +207,36 @@ impl From<rspack_core::StatsAsset> for JsStatsAsset {
 
 #[napi(object)]
 pub struct JsStatsAssetInfo {
+  pub immutable: bool,
+  pub minimized: bool,
+  pub chunk_hash: Vec<String>,
+  pub content_hash: Vec<String>,
+  pub source_filename: Option<String>,
   pub development: bool,
   pub hot_module_replacement: bool,
+  pub related: JsStatsAssetInfoRelated,
+  pub deduplicated: Option<u32>,
+}
+
+#[napi(object)]
+pub struct JsStatsAssetInfoRelated {
+  pub source_map: Option<String>,
 }
 
 impl From<rspack_core::StatsAssetInfo> for JsStatsAssetInfo {
   fn from(stats: rspack_core::StatsAssetInfo) -> Self {
     Self {
+      immutable: stats.immutable,
+      minimized: stats.minimized,
+      chunk_hash: stats.chunk_hash,
+      content_hash: stats.content_hash,
+      source_filename: stats.source_filename,
       development: stats.development,
       hot_module_replacement: stats.hot_module_replacement,
+      related: JsStatsAssetInfoRelated {
+        source_map: stats.related_source_map,
+      },
+      deduplicated: stats.deduplicated,
     }
   }
 }
@@ -205,6 +259,7 @@ pub struct JsStatsModule {
   pub assets: Option<Vec<String>>,
   pub source: Option<Either<String, Buffer>>,
   pub profile: Option<JsStatsModuleProfile>,
+  pub optimization_bailout: Vec<String>,
 }
 
 impl TryFrom<rspack_core::StatsModule<'_>> for JsStatsModule {
@@ -243,6 +298,7 @@ impl TryFrom<rspack_core::StatsModule<'_>> for JsStatsModule {
       assets: stats.assets,
       source,
       profile: stats.profile.map(|p| p.into()),
+      optimization_bailout: stats.optimization_bailout,
     })
   }
 }
@@ -252,6 +308,8 @@ pub struct JsStatsModuleProfile {
   pub factory: JsStatsMillisecond,
   pub integration: JsStatsMillisecond,
   pub building: JsStatsMillisecond,
+  pub loaders: Vec<JsStatsLoaderProfile>,
+  pub parsing: Option<JsStatsMillisecond>,
 }
 
 impl From<rspack_core::StatsModuleProfile> for JsStatsModuleProfile {
@@ -260,6 +318,72 @@ impl From<rspack_core::StatsModuleProfile> for JsStatsModuleProfile {
       factory: value.factory.into(),
       integration: value.integration.into(),
       building: value.building.into(),
+      loaders: value.loaders.into_iter().map(Into::into).collect(),
+      parsing: value.parsing.map(Into::into),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsStatsLoaderProfile {
+  pub identifier: String,
+  pub duration: JsStatsMillisecond,
+}
+
+impl From<rspack_core::StatsLoaderProfile> for JsStatsLoaderProfile {
+  fn from(value: rspack_core::StatsLoaderProfile) -> Self {
+    Self {
+      identifier: value.identifier,
+      duration: value.duration.into(),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsStatsProfileSummary {
+  pub modules: Vec<JsStatsProfileSummaryModule>,
+  pub loaders: Vec<JsStatsProfileSummaryLoader>,
+}
+
+impl From<rspack_core::StatsProfileSummary> for JsStatsProfileSummary {
+  fn from(value: rspack_core::StatsProfileSummary) -> Self {
+    Self {
+      modules: value.modules.into_iter().map(Into::into).collect(),
+      loaders: value.loaders.into_iter().map(Into::into).collect(),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsStatsProfileSummaryModule {
+  pub identifier: String,
+  pub name: String,
+  pub building: JsStatsMillisecond,
+}
+
+impl From<rspack_core::StatsProfileSummaryModule> for JsStatsProfileSummaryModule {
+  fn from(value: rspack_core::StatsProfileSummaryModule) -> Self {
+    Self {
+      identifier: value.identifier.to_string(),
+      name: value.name,
+      building: value.building.into(),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsStatsProfileSummaryLoader {
+  pub identifier: String,
+  pub duration: JsStatsMillisecond,
+  pub modules: u32,
+}
+
+impl From<rspack_core::StatsProfileSummaryLoader> for JsStatsProfileSummaryLoader {
+  fn from(value: rspack_core::StatsProfileSummaryLoader) -> Self {
+    Self {
+      identifier: value.identifier,
+      duration: value.duration.into(),
+      modules: value.modules as u32,
     }
   }
 }
@@ -303,6 +427,7 @@ pub struct JsStatsModuleReason {
   pub module_id: Option<String>,
   pub r#type: Option<String>,
   pub user_request: Option<String>,
+  pub loc: Option<String>,
 }
 
 impl From<rspack_core::StatsModuleReason> for JsStatsModuleReason {
@@ -313,6 +438,7 @@ impl From<rspack_core::StatsModuleReason> for JsStatsModuleReason {
       module_id: stats.module_id,
       r#type: stats.r#type,
       user_request: stats.user_request,
+      loc: stats.loc,
     }
   }
 }
@@ -331,6 +457,7 @@ pub struct JsStatsChunk {
   pub parents: Option<Vec<String>>,
   pub children: Option<Vec<String>>,
   pub siblings: Option<Vec<String>>,
+  pub reason: Option<String>,
 }
 
 impl TryFrom<rspack_core::StatsChunk<'_>> for JsStatsChunk {
@@ -352,6 +479,7 @@ impl TryFrom<rspack_core::StatsChunk<'_>> for JsStatsChunk {
       parents: stats.parents,
       children: stats.children,
       siblings: stats.siblings,
+      reason: stats.reason,
     })
   }
 }
@@ -377,6 +505,7 @@ pub struct JsStatsChunkGroup {
   pub assets: Vec<JsStatsChunkGroupAsset>,
   pub chunks: Vec<String>,
   pub assets_size: f64,
+  pub fetch_priority: Option<String>,
 }
 
 impl From<rspack_core::StatsChunkGroup> for JsStatsChunkGroup {
@@ -386,6 +515,7 @@ impl From<rspack_core::StatsChunkGroup> for JsStatsChunkGroup {
       assets: stats.assets.into_iter().map(Into::into).collect(),
       chunks: stats.chunks,
       assets_size: stats.assets_size,
+      fetch_priority: stats.fetch_priority.map(|p| p.to_string()),
     }
   }
 }
@@ -452,11 +582,13 @@ impl JsStats {
       .collect()
   }
 
+  #[allow(clippy::too_many_arguments)]
   #[napi]
   pub fn get_chunks(
     &self,
     chunk_modules: bool,
     chunks_relations: bool,
+    chunk_explanations: bool,
     reasons: bool,
     module_assets: bool,
     nested_modules: bool,
@@ -467,6 +599,7 @@ impl JsStats {
       .get_chunks(
         chunk_modules,
         chunks_relations,
+        chunk_explanations,
         reasons,
         module_assets,
         nested_modules,
@@ -478,6 +611,25 @@ impl JsStats {
       .collect()
   }
 
+  /// Get the modules of the chunk with the given id, `null` if the chunk is not found.
+  /// The modules are fetched chunk by chunk, so the modules of a huge graph aren't converted at once.
+  #[napi]
+  pub fn get_chunk_modules(
+    &self,
+    chunk_id: String,
+    reasons: bool,
+    module_assets: bool,
+    nested_modules: bool,
+    source: bool,
+  ) -> Result<Option<Vec<JsStatsModule>>> {
+    self
+      .inner
+      .get_chunk_modules(&chunk_id, reasons, module_assets, nested_modules, source)
+      .map_err(|e| napi::Error::from_reason(e.to_string()))?
+      .map(|modules| modules.into_iter().map(TryInto::try_into).collect())
+      .transpose()
+  }
+
   #[napi]
   pub fn get_entrypoints(&self) -> Vec<JsStatsChunkGroup> {
     self
@@ -532,6 +684,11 @@ impl JsStats {
       .collect()
   }
 
+  #[napi]
+  pub fn get_profile_summary(&self) -> JsStatsProfileSummary {
+    self.inner.get_profile_summary().into()
+  }
+
   #[napi(catch_unwind)]
   pub fn get_hash(&self) -> String {
     self
@@ -10,29 +10,34 @@ extern crate rspack_binding_macros;
 use std::collections::HashSet;
 use std::pin::Pin;
 use std::sync::atomic::{AtomicU32, Ordering};
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
 use napi::bindgen_prelude::*;
 use once_cell::sync::Lazy;
 use rspack_core::PluginExt;
 use rspack_fs_node::{AsyncNodeWritableFileSystem, ThreadsafeNodeFS};
+use rspack_napi_shared::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use rspack_napi_shared::NAPI_ENV;
 
 mod hook;
 mod js_values;
 mod loader;
+mod output_fs;
 mod plugins;
 mod utils;
+mod watching;
 
 use hook::*;
 use js_values::*;
 // Napi macro registered this successfully
 #[allow(unused)]
 use loader::*;
+use output_fs::OutputFileSystem;
 use plugins::*;
 use rspack_binding_options::*;
 use rspack_tracing::chrome::FlushGuard;
 use utils::*;
+use watching::*;
 
 #[cfg(not(target_os = "linux"))]
 #[global_allocator]
@@ -47,7 +52,7 @@ static GLOBAL: mimalloc_rust::GlobalMiMalloc = mimalloc_rust::GlobalMiMalloc;
 static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 
 static COMPILERS: Lazy<
-  SingleThreadedHashMap<CompilerId, Pin<Box<rspack_core::Compiler<AsyncNodeWritableFileSystem>>>>,
+  SingleThreadedHashMap<CompilerId, Pin<Box<rspack_core::Compiler<OutputFileSystem>>>>,
 > = Lazy::new(Default::default);
 
 static NEXT_COMPILER_ID: AtomicU32 = AtomicU32::new(0);
@@ -58,6 +63,7 @@ type CompilerId = u32;
 pub struct Rspack {
   id: CompilerId,
   disabled_hooks: DisabledHooks,
+  watching: Option<Arc<rspack_core::Watching>>,
 }
 
 #[napi]
@@ -71,6 +77,7 @@ impl Rspack {
     js_loader_runner: JsFunction,
   ) -> Result<Self> {
     Self::prepare_environment(&env);
+    rspack_binding_options::register_builtin_loaders();
     tracing::info!("raw_options: {:#?}", &options);
 
     let disabled_hooks: DisabledHooks = Default::default();
@@ -91,14 +98,20 @@ impl Rspack {
     let rspack = rspack_core::Compiler::new(
       compiler_options,
       plugins,
-      AsyncNodeWritableFileSystem::new(env, output_filesystem)
-        .map_err(|e| Error::from_reason(format!("Failed to create writable filesystem: {e}",)))?,
+      OutputFileSystem::new(
+        AsyncNodeWritableFileSystem::new(env, output_filesystem)
+          .map_err(|e| Error::from_reason(format!("Failed to create writable filesystem: {e}",)))?,
+      ),
     );
 
     let id = NEXT_COMPILER_ID.fetch_add(1, Ordering::SeqCst);
     unsafe { COMPILERS.insert_if_vacant(id, Box::pin(rspack)) }?;
 
-    Ok(Self { id, disabled_hooks })
+    Ok(Self {
+      id,
+      disabled_hooks,
+      watching: None,
+    })
   }
 
   #[allow(clippy::unwrap_in_result, clippy::unwrap_used)]
@@ -113,6 +126,72 @@ impl Rspack {
     Ok(())
   }
 
+  /// Switch the output file system, the assets are kept in memory with `"memory"`
+  /// and can be read back with `unsafe_read_output_file`.
+  #[napi(
+    catch_unwind,
+    js_name = "unsafe_set_output_file_system",
+    ts_args_type = "kind: \"node\" | \"memory\""
+  )]
+  pub fn set_output_file_system(&self, kind: String) -> Result<()> {
+    self.ensure_not_closed()?;
+    self.ensure_not_watching()?;
+    let memory = match kind.as_str() {
+      "node" => false,
+      "memory" => true,
+      _ => {
+        return Err(Error::from_reason(format!(
+          "Unknown output file system {kind}, expected \"node\" or \"memory\""
+        )))
+      }
+    };
+    let handle_set_output_file_system = |compiler: &mut Pin<Box<rspack_core::Compiler<_>>>| {
+      compiler.output_filesystem.set_memory(memory);
+      // The emitted assets are in the previous file system, emit them all again
+      compiler.emitted_asset_hashes.clear();
+      Ok(())
+    };
+    unsafe { COMPILERS.borrow_mut(&self.id, handle_set_output_file_system) }
+  }
+
+  /// Report the infrastructure logs of the compiler to the JS logger instead of stderr,
+  /// the logs filtered out by `infrastructureLogging.level` are never sent.
+  #[napi(
+    catch_unwind,
+    js_name = "unsafe_set_infrastructure_logger",
+    ts_args_type = "callback: (log: JsStatsLogging) => void"
+  )]
+  pub fn set_infrastructure_logger(&self, env: Env, callback: JsFunction) -> Result<()> {
+    self.ensure_not_closed()?;
+    let tsfn: ThreadsafeFunction<JsStatsLogging, ()> = js_fn_into_threadsafe_fn!(callback, env);
+    let handle_set_infrastructure_logger = |compiler: &mut Pin<Box<rspack_core::Compiler<_>>>| {
+      compiler.set_infrastructure_log_callback(Arc::new(move |name, log_type| {
+        // The receiver is dropped, the log is reported without waiting for the JS side
+        let _ = tsfn.call(
+          JsStatsLogging::from((name.to_string(), log_type)),
+          ThreadsafeFunctionCallMode::NonBlocking,
+        );
+      }));
+      Ok(())
+    };
+    unsafe { COMPILERS.borrow_mut(&self.id, handle_set_infrastructure_logger) }
+  }
+
+  /// Read an emitted file from the memory output file system, returns `null` if it doesn't exist.
+  #[napi(catch_unwind, js_name = "unsafe_read_output_file")]
+  pub fn read_output_file(&self, path: String) -> Result<Option<Buffer>> {
+    self.ensure_not_closed()?;
+    let handle_read_output_file = |compiler: &mut Pin<Box<rspack_core::Compiler<_>>>| {
+      Ok(
+        compiler
+          .output_filesystem
+          .read_memory(std::path::Path::new(&path))
+          .map(Buffer::from),
+      )
+    };
+    unsafe { COMPILERS.borrow_mut(&self.id, handle_read_output_file) }
+  }
+
   /// Build with the given option passed to the constructor
   ///
   /// Warning:
@@ -123,9 +202,11 @@ impl Rspack {
     ts_args_type = "callback: (err: null | Error) => void"
   )]
   pub fn build(&self, env: Env, f: JsFunction) -> Result<()> {
+    self.ensure_not_closed()?;
+    self.ensure_not_watching()?;
     let handle_build = |compiler: &mut Pin<Box<rspack_core::Compiler<_>>>| {
       // Safety: compiler is stored in a global hashmap, so it's guaranteed to be alive.
-      let compiler: &'static mut Pin<Box<rspack_core::Compiler<AsyncNodeWritableFileSystem>>> =
+      let compiler: &'static mut Pin<Box<rspack_core::Compiler<OutputFileSystem>>> =
         unsafe { std::mem::transmute::<&'_ mut _, &'static mut _>(compiler) };
 
       callbackify(env, f, async move {
@@ -156,12 +237,14 @@ impl Rspack {
     removed_files: Vec<String>,
     f: JsFunction,
   ) -> Result<()> {
+    self.ensure_not_closed()?;
+    self.ensure_not_watching()?;
     let handle_rebuild = |compiler: &mut Pin<Box<rspack_core::Compiler<_>>>| {
       // Safety: compiler is stored in a global hashmap, so it's guaranteed to be alive.
       // The reason why use Box<Compiler> here instead of Compiler itself is that:
       // Compilers may expand and change its layout underneath, make Compiler layout change.
       // Use Box to make sure the Compiler layout won't change
-      let compiler: &'static mut Pin<Box<rspack_core::Compiler<AsyncNodeWritableFileSystem>>> =
+      let compiler: &'static mut Pin<Box<rspack_core::Compiler<OutputFileSystem>>> =
         unsafe { std::mem::transmute::<&'_ mut _, &'static mut _>(compiler) };
 
       callbackify(env, f, async move {
@@ -180,6 +263,101 @@ impl Rspack {
     unsafe { COMPILERS.borrow_mut(&self.id, handle_rebuild) }
   }
 
+  /// Compile whenever the watched files change until the watching is closed, `callback` is called
+  /// once the watching is stopped.
+  ///
+  /// The file events are reported with `unsafe_watching_notify` unless `options.poll` is set.
+  /// `watch_run` returns how the changes are compiled and `done` is called after each compilation.
+  ///
+  /// Warning:
+  /// Build and rebuild are rejected until the watching is stopped.
+  #[napi(
+    catch_unwind,
+    js_name = "unsafe_watch",
+    ts_args_type = "options: RawWatchOptions, watch_run: (args: JsWatchRunArgs) => Promise<\"build\" | \"rebuild\" | \"blocked\">, done: (error: string | null) => Promise<void>, callback: (err: null | Error) => void"
+  )]
+  pub fn watch(
+    &mut self,
+    env: Env,
+    options: RawWatchOptions,
+    watch_run: JsFunction,
+    done: JsFunction,
+    f: JsFunction,
+  ) -> Result<()> {
+    self.ensure_not_closed()?;
+    self.ensure_not_watching()?;
+    let hooks = JsWatchingHooks::new(env, watch_run, done)?;
+    let watching = Arc::new(rspack_core::Watching::new(options.into()));
+    self.watching = Some(watching.clone());
+
+    let handle_watch = |compiler: &mut Pin<Box<rspack_core::Compiler<_>>>| {
+      // Safety: compiler is stored in a global hashmap, so it's guaranteed to be alive.
+      // Build and rebuild are rejected until the watching is stopped, so the watching is the
+      // only one compiling with it.
+      let compiler: &'static mut Pin<Box<rspack_core::Compiler<OutputFileSystem>>> =
+        unsafe { std::mem::transmute::<&'_ mut _, &'static mut _>(compiler) };
+
+      callbackify(env, f, async move {
+        watching
+          .run(&mut **compiler, &hooks)
+          .await
+          .map_err(|e| Error::new(napi::Status::GenericFailure, format!("{e}")))?;
+        tracing::info!("watching stopped");
+        Ok(())
+      })
+    };
+    unsafe { COMPILERS.borrow_mut(&self.id, handle_watch) }
+  }
+
+  /// Report the file events of the native watcher, which are compiled after `aggregateTimeout`
+  #[napi(
+    catch_unwind,
+    js_name = "unsafe_watching_notify",
+    ts_args_type = "changed_files: string[], removed_files: string[]"
+  )]
+  pub fn watching_notify(
+    &self,
+    changed_files: Vec<String>,
+    removed_files: Vec<String>,
+  ) -> Result<()> {
+    self.watching()?.notify(changed_files, removed_files);
+    Ok(())
+  }
+
+  /// Compile again even if no file is changed
+  #[napi(catch_unwind, js_name = "unsafe_watching_invalidate")]
+  pub fn watching_invalidate(&self) -> Result<()> {
+    self.watching()?.invalidate();
+    Ok(())
+  }
+
+  /// Stop compiling on changes, the changes are compiled after `unsafe_watching_resume`
+  #[napi(catch_unwind, js_name = "unsafe_watching_suspend")]
+  pub fn watching_suspend(&self) -> Result<()> {
+    self.watching()?.suspend();
+    Ok(())
+  }
+
+  #[napi(catch_unwind, js_name = "unsafe_watching_resume")]
+  pub fn watching_resume(&self) -> Result<()> {
+    self.watching()?.resume();
+    Ok(())
+  }
+
+  /// Stop watching, `callback` is called after the running compilation is finished
+  #[napi(
+    catch_unwind,
+    js_name = "unsafe_watching_close",
+    ts_args_type = "callback: (err: null | Error) => void"
+  )]
+  pub fn watching_close(&self, env: Env, f: JsFunction) -> Result<()> {
+    let watching = self.watching()?.clone();
+    callbackify(env, f, async move {
+      watching.close().await;
+      Ok(())
+    })
+  }
+
   /// Get the last compilation
   ///
   /// Warning:
@@ -194,7 +372,7 @@ impl Rspack {
       // The reason why use Box<Compiler> here instead of Compiler itself is that:
       // Compilers may expand and change its layout underneath, make Compiler layout change.
       // Use Box to make sure the Compiler layout won't change
-      let compiler: &'static mut Pin<Box<rspack_core::Compiler<AsyncNodeWritableFileSystem>>> =
+      let compiler: &'static mut Pin<Box<rspack_core::Compiler<OutputFileSystem>>> =
         unsafe { std::mem::transmute::<&'_ mut _, &'static mut _>(compiler) };
       f(JsCompilation::from_compilation(&mut compiler.compilation))
     };
@@ -207,9 +385,14 @@ impl Rspack {
   /// Warning:
   ///
   /// Anything related to this compiler will be invalidated after this method is called.
+  /// Dropping the compiler releases the threadsafe functions it holds, including the JS loader runner.
+  /// This must only be called when no build or rebuild is in flight.
   #[napi(catch_unwind, js_name = "unsafe_drop")]
   pub fn drop(&self) -> Result<()> {
-    unsafe { COMPILERS.remove(&self.id) };
+    self.ensure_not_watching()?;
+    if let Some(compiler) = unsafe { COMPILERS.remove(&self.id) } {
+      compiler.cache.begin_idle();
+    }
 
     Ok(())
   }
@@ -223,6 +406,34 @@ impl ObjectFinalize for Rspack {
 }
 
 impl Rspack {
+  fn ensure_not_closed(&self) -> Result<()> {
+    if COMPILERS.contains_key(&self.id) {
+      Ok(())
+    } else {
+      Err(Error::from_reason(format!(
+        "Compiler {} has been closed, create a new compiler to run another compilation",
+        self.id
+      )))
+    }
+  }
+
+  fn ensure_not_watching(&self) -> Result<()> {
+    match &self.watching {
+      Some(watching) if !watching.is_stopped() => Err(Error::from_reason(format!(
+        "Compiler {} is watching, close the watching before running another compilation",
+        self.id
+      ))),
+      _ => Ok(()),
+    }
+  }
+
+  fn watching(&self) -> Result<&Arc<rspack_core::Watching>> {
+    self
+      .watching
+      .as_ref()
+      .ok_or_else(|| Error::from_reason("The compiler is not watching"))
+  }
+
   fn prepare_environment(env: &Env) {
     NAPI_ENV.with(|napi_env| *napi_env.borrow_mut() = Some(env.raw()));
   }
@@ -1,5 +1,8 @@
 use napi::Result;
-use rspack_binding_options::{run_builtin_loader as run_builtin, JsLoaderContext};
+use rspack_binding_options::{
+  register_builtin_loaders, run_builtin_loader as run_builtin, run_builtin_loaders as run_builtins,
+  JsBuiltinLoader, JsLoaderContext,
+};
 
 /// Builtin loader runner
 #[napi(catch_unwind)]
@@ -11,3 +14,21 @@ pub async fn run_builtin_loader(
 ) -> Result<JsLoaderContext> {
   run_builtin(builtin, options, loader_context).await
 }
+
+/// Run several builtin loaders in one call, the pitching loaders in order and then the normal
+/// loaders in reverse order, like the loader runner
+#[napi(catch_unwind)]
+#[allow(unused)]
+pub async fn run_builtin_loaders(
+  loaders: Vec<JsBuiltinLoader>,
+  loader_context: JsLoaderContext,
+) -> Result<JsLoaderContext> {
+  run_builtins(loaders, loader_context).await
+}
+
+/// The names of the registered builtin loaders
+#[napi]
+pub fn get_builtin_loader_names() -> Vec<String> {
+  register_builtin_loaders();
+  rspack_core::builtin_loader_names()
+}
@@ -0,0 +1,79 @@
+use std::path::Path;
+
+use futures::future::BoxFuture;
+use rspack_fs::{AsyncMemoryFileSystem, AsyncWritableFileSystem, ReadableFileSystem};
+use rspack_fs_node::AsyncNodeWritableFileSystem;
+
+/// The output file system of a compiler, the assets are written to the Node one
+/// unless the memory one is switched on with `setOutputFileSystem("memory")`.
+pub struct OutputFileSystem {
+  node: AsyncNodeWritableFileSystem,
+  memory: Option<AsyncMemoryFileSystem>,
+}
+
+impl OutputFileSystem {
+  pub fn new(node: AsyncNodeWritableFileSystem) -> Self {
+    Self { node, memory: None }
+  }
+
+  /// Switch between the Node and the memory file system, the memory store is dropped when
+  /// switching back to the Node one.
+  pub fn set_memory(&mut self, memory: bool) {
+    if memory != self.memory.is_some() {
+      self.memory = memory.then(AsyncMemoryFileSystem::new);
+    }
+  }
+
+  /// Read an emitted file from the memory file system, returns `None` if the file doesn't exist.
+  pub fn read_memory(&self, file: &Path) -> Option<Vec<u8>> {
+    ReadableFileSystem::read(self.memory.as_ref()?, file).ok()
+  }
+}
+
+impl AsyncWritableFileSystem for OutputFileSystem {
+  fn create_dir<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, rspack_fs::Result<()>> {
+    match &self.memory {
+      Some(memory) => memory.create_dir(dir),
+      None => self.node.create_dir(dir),
+    }
+  }
+
+  fn create_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, rspack_fs::Result<()>> {
+    match &self.memory {
+      Some(memory) => memory.create_dir_all(dir),
+      None => self.node.create_dir_all(dir),
+    }
+  }
+
+  fn write<P: AsRef<Path>, D: AsRef<[u8]>>(
+    &self,
+    file: P,
+    data: D,
+  ) -> BoxFuture<'_, rspack_fs::Result<()>> {
+    match &self.memory {
+      Some(memory) => memory.write(file, data),
+      None => self.node.write(file, data),
+    }
+  }
+
+  fn remove_file<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, rspack_fs::Result<()>> {
+    match &self.memory {
+      Some(memory) => memory.remove_file(file),
+      None => self.node.remove_file(file),
+    }
+  }
+
+  fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, rspack_fs::Result<()>> {
+    match &self.memory {
+      Some(memory) => memory.remove_dir_all(dir),
+      None => self.node.remove_dir_all(dir),
+    }
+  }
+
+  fn read_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, rspack_fs::Result<Vec<String>>> {
+    match &self.memory {
+      Some(memory) => memory.read_dir_all(dir),
+      None => self.node.read_dir_all(dir),
+    }
+  }
+}
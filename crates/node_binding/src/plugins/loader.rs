@@ -2,7 +2,8 @@ use std::{fmt::Debug, path::Path, sync::Arc};
 
 use rspack_binding_options::{get_builtin_loader, JsLoaderAdapter, JsLoaderRunner};
 use rspack_core::{
-  BoxLoader, CompilerOptions, NormalModule, Plugin, ResolveResult, Resolver, BUILTIN_LOADER_PREFIX,
+  BoxLoader, CompilerOptions, LoaderItemOrigin, NormalModule, Plugin, ResolveResult, Resolver,
+  BUILTIN_LOADER_PREFIX,
 };
 use rspack_error::{internal_error, Result};
 
@@ -23,9 +24,16 @@ impl Plugin for JsLoaderResolver {
   async fn before_loaders(&self, module: &mut NormalModule) -> Result<()> {
     let contains_inline = module.contains_inline_loader();
     let old_loaders = module.loaders_mut_vec();
-    if old_loaders.is_empty() || old_loaders.len() == 1 {
+    if old_loaders.is_empty() {
       return Ok(());
     }
+    if let [(loader, _)] = old_loaders.as_slice() {
+      // A single builtin loader runs on the Rust side,
+      // and a composed loader has already been collapsed by a previous build.
+      if loader.identifier().starts_with(BUILTIN_LOADER_PREFIX) || loader.is_composed() {
+        return Ok(());
+      }
+    }
 
     // If there's any JS loader, then we switch to the JS loader runner.
     // Else, we run loader on the Rust side using the Rust loader runner.
@@ -36,15 +44,31 @@ impl Plugin for JsLoaderResolver {
         .iter()
         .any(|l| !l.identifier().starts_with(BUILTIN_LOADER_PREFIX))
     {
-      *module.loaders_mut_vec() = vec![Arc::new(JsLoaderAdapter {
+      let origins = old_loaders
+        .iter()
+        .map(|(_, origin)| *origin)
+        .collect::<Vec<_>>();
+      // The composed loader is regarded as a normal loader if its loaders come from different origins
+      let origin = if origins.iter().all(|origin| *origin == origins[0]) {
+        origins[0]
+      } else {
+        LoaderItemOrigin::Normal
+      };
+      let loader: BoxLoader = Arc::new(JsLoaderAdapter {
         runner: self.js_loader_runner.clone(),
         identifier: old_loaders
           .iter()
-          .map(|l| l.identifier().as_str())
+          .map(|(l, _)| l.identifier().as_str())
           .collect::<Vec<_>>()
           .join("$")
           .into(),
-      })];
+        options: old_loaders
+          .iter()
+          .map(|(l, _)| l.options().map(|o| o.to_owned()))
+          .collect(),
+        origins,
+      });
+      *module.loaders_mut_vec() = vec![(loader, origin)];
     }
 
     Ok(())
@@ -67,7 +91,7 @@ impl Plugin for JsLoaderResolver {
     };
 
     if loader_request.starts_with(BUILTIN_LOADER_PREFIX) {
-      return Ok(Some(get_builtin_loader(loader_request, loader_options)));
+      return get_builtin_loader(loader_request, loader_options).map(Some);
     }
 
     let resolve_result = resolver
@@ -85,6 +109,9 @@ impl Plugin for JsLoaderResolver {
         Ok(Some(Arc::new(JsLoaderAdapter {
           identifier: resource.into(),
           runner: self.js_loader_runner.clone(),
+          options: vec![loader_options.map(|o| o.to_owned())],
+          // Origins are only known after the loaders of a module are collected, see `before_loaders`
+          origins: vec![],
         })))
       }
       ResolveResult::Ignored => {
@@ -7,17 +7,18 @@ pub use loader::JsLoaderResolver;
 use napi::{Env, Result};
 use rspack_binding_macros::js_fn_into_threadsafe_fn;
 use rspack_core::{
-  ChunkAssetArgs, NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs,
+  ChunkAssetArgs, CreateModuleData, NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs,
   PluginNormalModuleFactoryAfterResolveOutput, PluginNormalModuleFactoryBeforeResolveOutput,
-  PluginNormalModuleFactoryResolveForSchemeOutput, ResourceData,
+  PluginNormalModuleFactoryCreateModuleDataOutput, PluginNormalModuleFactoryResolveForSchemeOutput,
+  ResourceData,
 };
 use rspack_error::internal_error;
 use rspack_napi_shared::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use rspack_napi_shared::NapiResultExt;
 
 use crate::js_values::{
-  AfterResolveData, BeforeResolveData, JsAssetEmittedArgs, JsChunkAssetArgs, JsModule,
-  JsResolveForSchemeInput, JsResolveForSchemeResult, ToJsModule,
+  AfterResolveData, BeforeResolveData, JsAssetEmittedArgs, JsChunkAssetArgs, JsCreateModuleData,
+  JsModule, JsResolveForSchemeInput, JsResolveForSchemeResult, ToJsModule,
 };
 use crate::{DisabledHooks, Hook, JsCompilation, JsHooks};
 
@@ -55,6 +56,7 @@ pub struct JsHooksAdapter {
   pub chunk_asset_tsfn: ThreadsafeFunction<JsChunkAssetArgs, ()>,
   pub before_resolve: ThreadsafeFunction<BeforeResolveData, (Option<bool>, BeforeResolveData)>,
   pub after_resolve: ThreadsafeFunction<AfterResolveData, Option<bool>>,
+  pub create_module: ThreadsafeFunction<JsCreateModuleData, JsCreateModuleData>,
   pub context_module_before_resolve: ThreadsafeFunction<BeforeResolveData, Option<bool>>,
   pub normal_module_factory_resolve_for_scheme:
     ThreadsafeFunction<JsResolveForSchemeInput, JsResolveForSchemeResult>,
@@ -193,6 +195,24 @@ impl rspack_core::Plugin for JsHooksAdapter {
       .await
       .map_err(|err| internal_error!("Failed to call this_compilation: {err}"))?
   }
+  async fn create_module_data(
+    &self,
+    _ctx: rspack_core::PluginContext,
+    args: &mut CreateModuleData,
+  ) -> PluginNormalModuleFactoryCreateModuleDataOutput {
+    if self.is_hook_disabled(&Hook::CreateModule) {
+      return Ok(());
+    }
+    let data = self
+      .create_module
+      .call((&*args).into(), ThreadsafeFunctionCallMode::NonBlocking)
+      .into_rspack_result()?
+      .await
+      .map_err(|err| internal_error!("Failed to call create_module: {err}"))??;
+    data.apply_to(args);
+    Ok(())
+  }
+
   async fn context_module_before_resolve(
     &self,
     _ctx: rspack_core::PluginContext,
@@ -756,6 +776,7 @@ impl JsHooksAdapter {
       optimize_chunk_module,
       before_resolve,
       after_resolve,
+      create_module,
       context_module_before_resolve,
       normal_module_factory_resolve_for_scheme,
       before_compile,
@@ -829,6 +850,8 @@ impl JsHooksAdapter {
       js_fn_into_threadsafe_fn!(before_resolve, env);
     let after_resolve: ThreadsafeFunction<AfterResolveData, Option<bool>> =
       js_fn_into_threadsafe_fn!(after_resolve, env);
+    let create_module: ThreadsafeFunction<JsCreateModuleData, JsCreateModuleData> =
+      js_fn_into_threadsafe_fn!(create_module, env);
     let normal_module_factory_resolve_for_scheme: ThreadsafeFunction<
       JsResolveForSchemeInput,
       JsResolveForSchemeResult,
@@ -876,6 +899,7 @@ impl JsHooksAdapter {
       build_module_tsfn,
       chunk_asset_tsfn,
       after_resolve,
+      create_module,
       succeed_module_tsfn,
       still_valid_module_tsfn,
     })
@@ -164,6 +164,11 @@ where
     f(&*inner)
   }
 
+  /// Returns `true` if the map contains a value for the key.
+  pub(crate) fn contains_key(&self, key: &K) -> bool {
+    self.0.contains_key(key)
+  }
+
   /// Insert a value into the map.
   ///
   /// Safety: It's not thread-safe if a value has thread affinity, so this is intended to be used from the thread where the map was created.
@@ -0,0 +1,68 @@
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use napi::{Env, JsFunction, Result};
+use rspack_binding_macros::js_fn_into_threadsafe_fn;
+use rspack_core::{WatchRunResult, WatchingHooks};
+use rspack_error::internal_error;
+use rspack_napi_shared::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use rspack_napi_shared::NapiResultExt;
+
+#[napi(object)]
+pub struct JsWatchRunArgs {
+  pub changed_files: Vec<String>,
+  pub removed_files: Vec<String>,
+}
+
+/// Calls `compiler.hooks.watchRun` and reports the compilations to the JS `Watching`
+pub struct JsWatchingHooks {
+  watch_run: ThreadsafeFunction<JsWatchRunArgs, String>,
+  done: ThreadsafeFunction<Option<String>, ()>,
+}
+
+impl JsWatchingHooks {
+  pub fn new(env: Env, watch_run: JsFunction, done: JsFunction) -> Result<Self> {
+    let watch_run: ThreadsafeFunction<JsWatchRunArgs, String> =
+      js_fn_into_threadsafe_fn!(watch_run, env);
+    let done: ThreadsafeFunction<Option<String>, ()> = js_fn_into_threadsafe_fn!(done, env);
+    Ok(Self { watch_run, done })
+  }
+}
+
+#[async_trait]
+impl WatchingHooks for JsWatchingHooks {
+  async fn watch_run(
+    &self,
+    changed_files: &HashSet<String>,
+    removed_files: &HashSet<String>,
+  ) -> rspack_error::Result<WatchRunResult> {
+    let args = JsWatchRunArgs {
+      changed_files: changed_files.iter().cloned().collect(),
+      removed_files: removed_files.iter().cloned().collect(),
+    };
+    let result = self
+      .watch_run
+      .call(args, ThreadsafeFunctionCallMode::NonBlocking)
+      .into_rspack_result()?
+      .await
+      .map_err(|err| internal_error!("Failed to call watch run: {err}"))??;
+    match result.as_str() {
+      "build" => Ok(WatchRunResult::Build),
+      "rebuild" => Ok(WatchRunResult::Rebuild),
+      "blocked" => Ok(WatchRunResult::Blocked),
+      _ => Err(internal_error!(
+        "Unknown watch run result {result}, expected \"build\", \"rebuild\" or \"blocked\""
+      )),
+    }
+  }
+
+  async fn done(&self, result: rspack_error::Result<()>) -> rspack_error::Result<()> {
+    let error = result.err().map(|e| format!("{e}"));
+    self
+      .done
+      .call(error, ThreadsafeFunctionCallMode::NonBlocking)
+      .into_rspack_result()?
+      .await
+      .map_err(|err| internal_error!("Failed to call watching done: {err}"))?
+  }
+}
@@ -63,6 +63,22 @@ fn criterion_benchmark(c: &mut Criterion) {
     .build()
     .expect("TODO:");
   generate_bench!(ten_copy_of_threejs_production, "threejs10x", group, rt);
+  group.finish();
+
+  // Many modules finish building at the same time and update the module graph,
+  // which measures the cost of the module graph mutations during make
+  let mut group = c.benchmark_group("wide_shallow_benchmark");
+  group.sample_size(50);
+  let sh = Shell::new().expect("TODO:");
+  sh.change_dir(PathBuf::from(env!("CARGO_WORKSPACE_DIR")));
+  cmd!(sh, "node ./scripts/bench/make-wide-shallow.js")
+    .run()
+    .expect("TODO:");
+  let rt = tokio::runtime::Builder::new_multi_thread()
+    .enable_all()
+    .build()
+    .expect("TODO:");
+  generate_bench!(ten_thousand_modules_fan_out_100, "wide-shallow", group, rt);
   group.finish()
 }
 
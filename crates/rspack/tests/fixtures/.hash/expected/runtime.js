@@ -142,7 +142,7 @@ __webpack_require__.O = function (result, chunkIds, fn, priority) {
 // webpack/runtime/load_script
 (function() {
 var inProgress = {};
-// var dataWebpackPrefix = "webpack:";
+var dataWebpackPrefix = "__rspack_test__:";
 // loadScript function to load a script via script tag
 __webpack_require__.l = function loadScript(url, done, key, chunkId) {
 	// TODO add this after hash
@@ -156,8 +156,9 @@ __webpack_require__.l = function loadScript(url, done, key, chunkId) {
 		for (var i = 0; i < scripts.length; i++) {
 			var s = scripts[i];
 			if (
-				s.getAttribute("src") == url
-				// || s.getAttribute("data-webpack") == dataWebpackPrefix + key
+				s.getAttribute("src") == url ||
+				(dataWebpackPrefix &&
+					s.getAttribute("data-webpack") == dataWebpackPrefix + key)
 			) {
 				script = s;
 				break;
@@ -170,7 +171,9 @@ __webpack_require__.l = function loadScript(url, done, key, chunkId) {
 
 		script.charset = "utf-8";
 		script.timeout = 120;
-		// script.setAttribute("data-webpack", dataWebpackPrefix + key);
+		if (dataWebpackPrefix) {
+			script.setAttribute("data-webpack", dataWebpackPrefix + key);
+		}
 		script.src = url;
 
 		if (false && script.src.indexOf(window.location.origin + '/') !== 0) {
@@ -227,7 +230,7 @@ __webpack_require__.p = "/";
 // webpack/runtime/css_loading
 (function() {
 var installedChunks = {};
-var uniqueName = "webpack";
+var uniqueName = "__rspack_test__";
 // loadCssChunkData is unnecessary
 var loadingAttribute = "data-webpack-loading";
 var loadStylesheet = (chunkId, url, done, hmr) => {
@@ -1,4 +1,4 @@
-(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["a_js"], {
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["a-js"], {
 "./a.js": function (__unused_webpack_module, exports, __webpack_require__) {
 console.log('a');
 },
@@ -1,4 +1,4 @@
-(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["b_js"], {
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["b-js"], {
 "./b.js": function (__unused_webpack_module, exports, __webpack_require__) {
 console.log('b');
 },
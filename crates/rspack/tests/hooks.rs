@@ -0,0 +1,321 @@
+use std::path::Path;
+use std::sync::{
+  atomic::{AtomicBool, Ordering},
+  Arc, Mutex,
+};
+
+use rspack_core::{
+  BoxLoader, BuildInfo, BuildMeta, Compilation, Compiler, JsParserHandlers, Loader, LoaderContext,
+  LoaderItemOrigin, LoaderRunnerContext, ModuleGraphModule, ModuleIdentifier, ModuleType,
+  NormalModule, NormalModuleBeforeResolveArgs, Plugin, PluginContext,
+  PluginNormalModuleFactoryBeforeResolveOutput, RawModule, RuntimeGlobals,
+};
+use rspack_error::Result;
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_identifier::{Identifiable, Identifier};
+use rspack_regex::RspackRegex;
+use rspack_testing::apply_from_fixture;
+use swc_core::ecma::ast::{Expr, Lit};
+
+#[derive(Debug, Default)]
+struct RequestReplacementPlugin {
+  context_issuer_checked: Arc<AtomicBool>,
+}
+
+#[async_trait::async_trait]
+impl Plugin for RequestReplacementPlugin {
+  fn name(&self) -> &'static str {
+    "request-replacement"
+  }
+
+  async fn before_resolve(
+    &self,
+    _ctx: PluginContext,
+    args: &mut NormalModuleBeforeResolveArgs,
+  ) -> PluginNormalModuleFactoryBeforeResolveOutput {
+    if args.request == "old-lib" {
+      assert!(args
+        .issuer
+        .as_deref()
+        .is_some_and(|issuer| issuer.ends_with("index.js")));
+      args.request = "./lib/new-lib".to_string();
+    }
+    if args.request == "skipped-lib" {
+      return Ok(Some(false));
+    }
+    Ok(None)
+  }
+
+  async fn context_module_before_resolve(
+    &self,
+    _ctx: PluginContext,
+    args: &mut NormalModuleBeforeResolveArgs,
+  ) -> PluginNormalModuleFactoryBeforeResolveOutput {
+    assert!(args
+      .issuer
+      .as_deref()
+      .is_some_and(|issuer| issuer.ends_with("index.js")));
+    self.context_issuer_checked.store(true, Ordering::SeqCst);
+    Ok(None)
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn before_resolve_should_rewrite_request() {
+  let fixture_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/hooks/before-resolve");
+  let (options, mut plugins) = apply_from_fixture(&fixture_path);
+  let plugin = RequestReplacementPlugin::default();
+  let context_issuer_checked = plugin.context_issuer_checked.clone();
+  plugins.push(Box::new(plugin));
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+
+  let stats = compiler.compilation.get_stats();
+  assert!(stats.get_errors().is_empty());
+  let module_graph = &compiler.compilation.module_graph;
+  assert!(module_graph
+    .modules()
+    .keys()
+    .any(|identifier| identifier.ends_with("lib/new-lib.js")));
+  // No module is created for the request skipped by `beforeResolve`
+  assert!(!module_graph
+    .modules()
+    .keys()
+    .any(|identifier| identifier.contains("skipped-lib")));
+  assert!(context_issuer_checked.load(Ordering::SeqCst));
+}
+
+/// Replace the modules matching `test` with an empty module, and remove the modules only
+/// referenced by them.
+#[derive(Debug)]
+struct StripModulesPlugin {
+  test: RspackRegex,
+}
+
+#[async_trait::async_trait]
+impl Plugin for StripModulesPlugin {
+  fn name(&self) -> &'static str {
+    "strip-modules"
+  }
+
+  async fn optimize_dependencies(&self, compilation: &mut Compilation) -> Result<()> {
+    let module_graph = &mut compilation.module_graph;
+    let matched = module_graph
+      .modules()
+      .values()
+      .filter(|module| {
+        module.as_normal_module().is_some_and(|module| {
+          self.test.test(
+            &module
+              .resource_resolved_data()
+              .resource_path
+              .to_string_lossy(),
+          )
+        })
+      })
+      .map(|module| module.identifier())
+      .collect::<Vec<_>>();
+    if matched.is_empty() {
+      return Ok(());
+    }
+
+    let stripped = ModuleIdentifier::from("strip-modules|stripped");
+    module_graph.add_module_graph_module(ModuleGraphModule::new(stripped, ModuleType::Js));
+    module_graph.add_module(Box::new(RawModule::new(
+      "/* stripped */".to_string(),
+      stripped,
+      "stripped".to_string(),
+      RuntimeGlobals::default(),
+    )));
+    module_graph.set_module_build_info_and_meta(
+      &stripped,
+      BuildInfo::default(),
+      BuildMeta::default(),
+    );
+    for identifier in &matched {
+      module_graph.replace_module(identifier, &stripped)?;
+    }
+    let mut queue = matched;
+    while let Some(identifier) = queue.pop() {
+      // Modules still referenced by others are kept
+      if let Ok(referenced) = module_graph.remove_module_if_orphan(&identifier) {
+        queue.extend(referenced);
+      }
+    }
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn optimize_dependencies_should_strip_modules() {
+  let fixture_path =
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/hooks/optimize-dependencies");
+  let (options, mut plugins) = apply_from_fixture(&fixture_path);
+  plugins.push(Box::new(StripModulesPlugin {
+    test: RspackRegex::new("debug").expect("should be valid regex"),
+  }));
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+
+  let stats = compiler.compilation.get_stats();
+  assert!(stats.get_errors().is_empty());
+  let modules = compiler.compilation.module_graph.modules();
+  assert!(!modules
+    .keys()
+    .any(|identifier| identifier.contains("debug")));
+  assert!(modules
+    .keys()
+    .any(|identifier| identifier.ends_with("lib.js")));
+  assert!(modules
+    .keys()
+    .any(|identifier| identifier.ends_with("shared.js")));
+}
+
+/// Claim the `require()` of the remote modules, which are loaded at runtime.
+#[derive(Debug)]
+struct RemoteRequirePlugin;
+
+impl Plugin for RemoteRequirePlugin {
+  fn name(&self) -> &'static str {
+    "remote-require"
+  }
+
+  fn register_js_parser_handlers(&self, handlers: &mut JsParserHandlers) {
+    handlers.call("require", |call_expr, context| {
+      let Some(Expr::Lit(Lit::Str(request))) = call_expr.args.first().map(|arg| &*arg.expr)
+      else {
+        return false;
+      };
+      if !request.value.starts_with("remote:") {
+        return false;
+      }
+      context.warn(
+        call_expr.span,
+        "Remote require".to_string(),
+        format!("{} is loaded at runtime", request.value),
+      );
+      true
+    });
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn js_parser_handlers_should_claim_calls() {
+  let fixture_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/hooks/js-parser-handlers");
+  let (options, mut plugins) = apply_from_fixture(&fixture_path);
+  plugins.push(Box::new(RemoteRequirePlugin));
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+
+  let stats = compiler.compilation.get_stats();
+  assert!(stats.get_errors().is_empty());
+  let warnings = stats.get_warnings();
+  assert_eq!(warnings.len(), 1);
+  assert!(warnings[0]
+    .message
+    .contains("remote:config is loaded at runtime"));
+  // The warning is rendered with the excerpt of the claimed call
+  assert!(warnings[0]
+    .formatted
+    .contains(r#"require("remote:config")"#));
+
+  let module_graph = &compiler.compilation.module_graph;
+  let requests = module_graph
+    .modules()
+    .keys()
+    .filter_map(|identifier| module_graph.dependencies_by_module_identifier(identifier))
+    .flatten()
+    .filter_map(|id| module_graph.dependency_by_id(id))
+    .filter_map(|dependency| dependency.as_module_dependency())
+    .map(|dependency| dependency.request().to_string())
+    .collect::<Vec<_>>();
+  assert!(requests.iter().any(|request| request == "./lib"));
+  assert!(!requests
+    .iter()
+    .any(|request| request.starts_with("remote:")));
+}
+
+/// Record the name of the loader once it's run.
+#[derive(Debug)]
+struct RecordLoader {
+  name: &'static str,
+  records: Arc<Mutex<Vec<&'static str>>>,
+}
+
+impl Identifiable for RecordLoader {
+  fn identifier(&self) -> Identifier {
+    format!("record-loader?{}", self.name).into()
+  }
+}
+
+#[async_trait::async_trait]
+impl Loader<LoaderRunnerContext> for RecordLoader {
+  async fn run(&self, _loader_context: &mut LoaderContext<'_, LoaderRunnerContext>) -> Result<()> {
+    self.records.lock().expect("should lock").push(self.name);
+    Ok(())
+  }
+}
+
+/// Insert the record loaders around the swc loader of the modules.
+#[derive(Debug, Default)]
+struct InsertLoaderPlugin {
+  records: Arc<Mutex<Vec<&'static str>>>,
+}
+
+impl InsertLoaderPlugin {
+  fn record_loader(&self, name: &'static str) -> Arc<RecordLoader> {
+    Arc::new(RecordLoader {
+      name,
+      records: self.records.clone(),
+    })
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for InsertLoaderPlugin {
+  fn name(&self) -> &'static str {
+    "insert-loader"
+  }
+
+  async fn before_loaders(&self, module: &mut NormalModule) -> Result<()> {
+    let is_swc_loader = |loader: &BoxLoader, origin: LoaderItemOrigin| {
+      origin == LoaderItemOrigin::Normal && loader.identifier().starts_with("builtin:swc-loader")
+    };
+    assert!(module.insert_loader_before(
+      is_swc_loader,
+      self.record_loader("before"),
+      LoaderItemOrigin::Normal
+    ));
+    assert!(module.insert_loader_after(
+      is_swc_loader,
+      self.record_loader("after"),
+      LoaderItemOrigin::Normal
+    ));
+    // Nothing is inserted without a matched loader
+    assert!(!module.insert_loader_before(
+      |_, origin| origin == LoaderItemOrigin::Pre,
+      self.record_loader("pre"),
+      LoaderItemOrigin::Pre
+    ));
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn before_loaders_should_insert_loaders() {
+  let fixture_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/hooks/before-loaders");
+  let (options, mut plugins) = apply_from_fixture(&fixture_path);
+  let plugin = InsertLoaderPlugin::default();
+  let records = plugin.records.clone();
+  plugins.push(Box::new(plugin));
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+
+  let stats = compiler.compilation.get_stats();
+  assert!(stats.get_errors().is_empty());
+  // The loaders are kept in the pitching order, so the normal loaders run in reverse
+  assert_eq!(
+    *records.lock().expect("should lock"),
+    vec!["after", "before"]
+  );
+}
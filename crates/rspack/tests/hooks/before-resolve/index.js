@@ -0,0 +1,5 @@
+import { name } from "old-lib";
+import "skipped-lib";
+
+export { name };
+export const libs = require.context("./lib", false, /\.js$/);
@@ -0,0 +1,4 @@
+const config = require("remote:config");
+const { name } = require("./lib");
+
+export { config, name };
@@ -0,0 +1,4 @@
+import { format } from "./format";
+import { shared } from "../shared";
+
+export const panel = format(shared);
@@ -0,0 +1,4 @@
+import { name } from "./lib";
+import { panel } from "./debug/panel";
+
+export { name, panel };
@@ -0,0 +1,3 @@
+import { shared } from "./shared";
+
+export const name = "lib" + shared;
@@ -1,4 +1,4 @@
-(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["chunk_js"], {
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["chunk-js"], {
 "./chunk.js": function (__unused_webpack_module, __webpack_exports__, __webpack_require__) {
 'use strict';
 __webpack_require__.r(__webpack_exports__);
@@ -15,6 +15,8 @@ mod raw_devtool;
 mod raw_entry;
 mod raw_experiments;
 mod raw_external;
+mod raw_ignore_warnings;
+mod raw_infrastructure_logging;
 mod raw_mode;
 mod raw_module;
 mod raw_node;
@@ -25,6 +27,7 @@ mod raw_snapshot;
 mod raw_split_chunks;
 mod raw_stats;
 mod raw_target;
+mod raw_watch;
 
 pub use raw_builtins::*;
 pub use raw_cache::*;
@@ -34,6 +37,8 @@ pub use raw_devtool::*;
 pub use raw_entry::*;
 pub use raw_experiments::*;
 pub use raw_external::*;
+pub use raw_ignore_warnings::*;
+pub use raw_infrastructure_logging::*;
 pub use raw_mode::*;
 pub use raw_module::*;
 pub use raw_node::*;
@@ -44,6 +49,7 @@ pub use raw_snapshot::*;
 pub use raw_split_chunks::*;
 pub use raw_stats::*;
 pub use raw_target::*;
+pub use raw_watch::*;
 
 pub trait RawOptionsApply {
   type Options;
@@ -59,6 +65,8 @@ pub struct RawOptions {
   /// std::collection::HashMap does not guarantee the insertion order, for more details you could refer
   /// https://doc.rust-lang.org/std/collections/index.html#iterators:~:text=For%20unordered%20collections%20like%20HashMap%2C%20the%20items%20will%20be%20yielded%20in%20whatever%20order%20the%20internal%20representation%20made%20most%20convenient.%20This%20is%20great%20for%20reading%20through%20all%20the%20contents%20of%20the%20collection.
   pub __entry_order: Vec<String>,
+  /// The entry provided as a function, `entry` is empty in this case
+  pub dynamic_entry: Option<RawDynamicEntry>,
   #[napi(ts_type = "undefined | 'production' | 'development' | 'none'")]
   pub mode: Option<RawMode>,
   #[napi(ts_type = "Array<string>")]
@@ -82,7 +90,13 @@ pub struct RawOptions {
   pub cache: RawCacheOptions,
   pub experiments: RawExperiments,
   pub node: Option<RawNodeOption>,
+  /// Whether to parse the AMD `define()` calls
+  pub amd: bool,
+  /// Whether `require.cache` is the module cache, only for the node targets
+  pub require_cache: bool,
   pub profile: bool,
+  pub ignore_warnings: Option<Vec<RawIgnoreWarning>>,
+  pub infrastructure_logging: RawInfrastructureLoggingOptions,
 }
 
 impl RawOptionsApply for RawOptions {
@@ -95,29 +109,30 @@ impl RawOptionsApply for RawOptions {
     // care about EntryOptions instead EntryDescription
     for key in &self.__entry_order {
       if let Some((name, desc)) = self.entry.remove_entry(key) {
+        let desc: rspack_core::EntryDescription = desc.into();
+        let options = desc.entry_options();
         for request in desc.import {
           plugins.push(
-            rspack_plugin_entry::EntryPlugin::new(
-              name.clone(),
-              request,
-              rspack_core::EntryOptions {
-                runtime: desc.runtime.clone(),
-                chunk_loading: desc.chunk_loading.as_deref().map(Into::into),
-                async_chunks: desc.async_chunks,
-                public_path: desc.public_path.clone().map(Into::into),
-                base_uri: desc.base_uri.clone(),
-                filename: desc.filename.clone().map(Into::into),
-              },
-            )
-            .boxed(),
+            rspack_plugin_entry::EntryPlugin::new(name.clone(), request, options.clone()).boxed(),
           );
         }
       }
     }
+    if let Some(dynamic_entry) = self.dynamic_entry {
+      plugins.push(rspack_plugin_entry::DynamicEntryPlugin::try_from(dynamic_entry)?.boxed());
+    }
+    // Must be applied before the chunk loading plugins, which provide the prefetch and preload handlers
+    plugins.push(rspack_plugin_runtime::ChunkPrefetchPreloadPlugin.boxed());
+    let devtool_ignore_list = self
+      .output
+      .devtool_ignore_list
+      .take()
+      .map(TryInto::try_into)
+      .transpose()?;
     let output: OutputOptions = self.output.apply(plugins)?;
     let resolve = self.resolve.try_into()?;
     let resolve_loader = self.resolve_loader.try_into()?;
-    let devtool: Devtool = self.devtool.into();
+    let devtool: Devtool = self.devtool.try_into()?;
     let mode = self.mode.unwrap_or_default().into();
     let module: ModuleOptions = self.module.apply(plugins)?;
     let target = self.target.apply(plugins)?;
@@ -135,15 +150,22 @@ impl RawOptionsApply for RawOptions {
       async_web_assembly: self.experiments.async_web_assembly,
       new_split_chunks: self.experiments.new_split_chunks,
       css: self.experiments.css,
+      top_level_await: self.experiments.top_level_await,
     };
     let optimization = IS_ENABLE_NEW_SPLIT_CHUNKS.set(&experiments.new_split_chunks, || {
       self.optimization.apply(plugins)
     })?;
     let stats = self.stats.into();
-    let snapshot = self.snapshot.into();
+    let snapshot = self.snapshot.try_into()?;
     let node = self.node.map(|n| n.into());
     let dev_server: DevServerOptions = self.dev_server.into();
     let builtins = self.builtins.apply(plugins)?;
+    let ignore_warnings = self
+      .ignore_warnings
+      .unwrap_or_default()
+      .into_iter()
+      .map(|ignore_warning| ignore_warning.try_into())
+      .collect::<Result<Vec<_>, _>>()?;
 
     plugins.push(rspack_plugin_schemes::DataUriPlugin.boxed());
     plugins.push(rspack_plugin_schemes::FileUriPlugin.boxed());
@@ -225,7 +247,7 @@ impl RawOptionsApply for RawOptions {
     plugins.push(rspack_plugin_javascript::JsPlugin::new().boxed());
     plugins.push(rspack_plugin_javascript::InferAsyncModulesPlugin {}.boxed());
 
-    if devtool.source_map() {
+    if devtool.source_map() || devtool.eval() {
       plugins.push(
         rspack_plugin_devtool::DevtoolPlugin::new(rspack_plugin_devtool::DevtoolPluginOptions {
           inline: devtool.inline(),
@@ -234,6 +256,7 @@ impl RawOptionsApply for RawOptions {
           columns: !devtool.cheap(),
           no_sources: devtool.no_sources(),
           public_path: None,
+          ignore_list: devtool_ignore_list,
         })
         .boxed(),
       );
@@ -265,7 +288,11 @@ impl RawOptionsApply for RawOptions {
       node,
       dev_server,
       builtins,
+      amd: self.amd,
+      require_cache: self.require_cache,
       profile: self.profile,
+      ignore_warnings,
+      infrastructure_logging: self.infrastructure_logging.into(),
     })
   }
 }
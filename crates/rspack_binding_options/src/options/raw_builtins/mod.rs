@@ -5,18 +5,22 @@ use rspack_core::{
 };
 use rspack_error::internal_error;
 use rspack_plugin_banner::{BannerConfig, BannerPlugin};
+use rspack_plugin_container::ContainerReferencePlugin;
 use rspack_plugin_copy::CopyPlugin;
 use rspack_plugin_css::{plugin::CssConfig, CssPlugin};
 use rspack_plugin_dev_friendly_split_chunks::DevFriendlySplitChunksPlugin;
 use rspack_plugin_html::HtmlPlugin;
+use rspack_plugin_manifest::ManifestPlugin;
 use rspack_plugin_progress::ProgressPlugin;
 use serde::Deserialize;
 
 mod raw_banner;
+mod raw_container_reference;
 mod raw_copy;
 mod raw_css;
 mod raw_decorator;
 mod raw_html;
+mod raw_manifest;
 mod raw_plugin_import;
 mod raw_progress;
 mod raw_react;
@@ -29,8 +33,9 @@ pub use raw_progress::*;
 pub use raw_react::*;
 
 use self::{
-  raw_banner::RawBannerConfig, raw_copy::RawCopyConfig, raw_plugin_import::RawPluginImportConfig,
-  raw_relay::RawRelayConfig,
+  raw_banner::RawBannerConfig, raw_container_reference::RawContainerReferenceConfig,
+  raw_copy::RawCopyConfig, raw_manifest::RawManifestConfig,
+  raw_plugin_import::RawPluginImportConfig, raw_relay::RawRelayConfig,
 };
 use crate::RawOptionsApply;
 
@@ -226,6 +231,8 @@ pub struct RawBuiltins {
   pub plugin_import: Option<Vec<RawPluginImportConfig>>,
   pub relay: Option<RawRelayConfig>,
   pub code_generation: Option<RawCodeGeneration>,
+  pub manifest: Option<RawManifestConfig>,
+  pub container_reference: Option<RawContainerReferenceConfig>,
 }
 
 impl RawOptionsApply for RawBuiltins {
@@ -252,13 +259,18 @@ impl RawOptionsApply for RawBuiltins {
       plugins.push(CssPlugin::new(options).boxed());
     }
     if let Some(progress) = self.progress {
-      plugins.push(ProgressPlugin::new(progress.into()).boxed());
+      plugins.push(ProgressPlugin::new(progress.try_into()?).boxed());
     }
     if self.dev_friendly_split_chunks {
       plugins.push(DevFriendlySplitChunksPlugin::new().boxed());
     }
     if let Some(copy) = self.copy {
-      plugins.push(CopyPlugin::new(copy.patterns.into_iter().map(Into::into).collect()).boxed());
+      let patterns = copy
+        .patterns
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<rspack_error::Result<_>>()?;
+      plugins.push(CopyPlugin::new(patterns).boxed());
     }
 
     if let Some(banners) = self.banner {
@@ -272,6 +284,14 @@ impl RawOptionsApply for RawBuiltins {
         .for_each(|banner| plugins.push(BannerPlugin::new(banner).boxed()));
     }
 
+    if let Some(manifest) = self.manifest {
+      plugins.push(ManifestPlugin::new(manifest.into()).boxed());
+    }
+
+    if let Some(container_reference) = self.container_reference {
+      plugins.push(ContainerReferencePlugin::new(container_reference.into()).boxed());
+    }
+
     Ok(Builtins {
       minify_options: self.minify_options.map(|i| i.try_into()).transpose()?,
       preset_env: self.preset_env.map(Into::into),
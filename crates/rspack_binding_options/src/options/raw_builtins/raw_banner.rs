@@ -1,10 +1,112 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::Arc};
 
+use napi::{Env, JsFunction};
 use napi_derive::napi;
 use rspack_error::internal_error;
-use rspack_plugin_banner::{BannerCondition, BannerConditions, BannerConfig};
+use rspack_napi_shared::{
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  NapiResultExt, NAPI_ENV,
+};
+use rspack_plugin_banner::{
+  BannerCondition, BannerConditions, BannerConfig, BannerContent, BannerContentFnCtx,
+  BannerContentFnCtxChunk,
+};
 use serde::Deserialize;
 
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawBannerContent {
+  #[napi(ts_type = r#""string" | "function""#)]
+  pub r#type: String,
+  pub string_payload: Option<String>,
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = r#"(args: RawBannerContentFnCtx) => string"#)]
+  pub fn_payload: Option<JsFunction>,
+}
+
+impl Debug for RawBannerContent {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("RawBannerContent")
+      .field("r#type", &self.r#type)
+      .field("string_payload", &self.string_payload)
+      .field("fn_payload", &"Function")
+      .finish()
+  }
+}
+
+#[napi(object)]
+pub struct RawBannerContentFnCtx {
+  pub hash: String,
+  pub chunk: RawBannerContentFnCtxChunk,
+  pub filename: String,
+}
+
+impl From<BannerContentFnCtx> for RawBannerContentFnCtx {
+  fn from(value: BannerContentFnCtx) -> Self {
+    Self {
+      hash: value.hash,
+      chunk: value.chunk.into(),
+      filename: value.filename,
+    }
+  }
+}
+
+#[napi(object)]
+pub struct RawBannerContentFnCtxChunk {
+  pub id: Option<String>,
+  pub name: Option<String>,
+  pub hash: Option<String>,
+}
+
+impl From<BannerContentFnCtxChunk> for RawBannerContentFnCtxChunk {
+  fn from(value: BannerContentFnCtxChunk) -> Self {
+    Self {
+      id: value.id,
+      name: value.name,
+      hash: value.hash,
+    }
+  }
+}
+
+impl TryFrom<RawBannerContent> for BannerContent {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawBannerContent) -> rspack_error::Result<Self> {
+    match value.r#type.as_str() {
+      "string" => Ok(Self::String(value.string_payload.ok_or_else(|| {
+        internal_error!("should have a string_payload when RawBannerContent.type is \"string\"")
+      })?)),
+      "function" => {
+        let func = value.fn_payload.ok_or_else(|| {
+          internal_error!("should have a fn_payload when RawBannerContent.type is \"function\"")
+        })?;
+        let func: ThreadsafeFunction<RawBannerContentFnCtx, String> =
+          NAPI_ENV.with(|env| -> anyhow::Result<_> {
+            let env = env.borrow().expect("Failed to get env with banner");
+            let func = rspack_binding_macros::js_fn_into_threadsafe_fn!(func, &Env::from(env));
+            Ok(func)
+          })?;
+        let func = Arc::new(func);
+        Ok(Self::Fn(Box::new(move |ctx: BannerContentFnCtx| {
+          let func = func.clone();
+          Box::pin(async move {
+            func
+              .call(ctx.into(), ThreadsafeFunctionCallMode::NonBlocking)
+              .into_rspack_result()?
+              .await
+              .map_err(|err| internal_error!("Failed to call banner function: {err}"))?
+          })
+        })))
+      }
+      _ => Err(internal_error!(
+        "Failed to resolve the RawBannerContent.type {}. Expected type is `string` or `function`.",
+        value.r#type
+      )),
+    }
+  }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
@@ -30,7 +132,7 @@ pub struct RawBannerConditions {
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawBannerConfig {
-  pub banner: String,
+  pub banner: RawBannerContent,
   pub entry_only: Option<bool>,
   pub footer: Option<bool>,
   pub raw: Option<bool>,
@@ -117,7 +219,7 @@ impl TryFrom<RawBannerConfig> for BannerConfig {
     }
 
     Ok(BannerConfig {
-      banner: value.banner,
+      banner: value.banner.try_into()?,
       entry_only: value.entry_only,
       footer: value.footer,
       raw: value.raw,
@@ -0,0 +1,41 @@
+use napi_derive::napi;
+use rspack_plugin_container::{ContainerReferencePluginOptions, RemoteOptions};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawRemoteOptions {
+  pub key: String,
+  pub external: String,
+  pub share_scope: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawContainerReferenceConfig {
+  pub remote_type: String,
+  pub remotes: Vec<RawRemoteOptions>,
+}
+
+impl From<RawContainerReferenceConfig> for ContainerReferencePluginOptions {
+  fn from(value: RawContainerReferenceConfig) -> Self {
+    Self {
+      remote_type: value.remote_type,
+      remotes: value
+        .remotes
+        .into_iter()
+        .map(|remote| {
+          (
+            remote.key,
+            RemoteOptions {
+              external: remote.external,
+              share_scope: remote.share_scope,
+            },
+          )
+        })
+        .collect(),
+    }
+  }
+}
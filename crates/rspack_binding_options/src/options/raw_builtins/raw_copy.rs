@@ -1,15 +1,24 @@
-use std::path::PathBuf;
+use std::{fmt::Debug, path::PathBuf, sync::Arc};
 
+use napi::{Env, JsFunction};
 use napi_derive::napi;
-use rspack_core::{CopyPluginConfig, GlobOptions, Pattern, ToType};
+use rspack_core::{CopyPluginConfig, CopyTo, CopyToFnCtx, GlobOptions, Pattern, ToType};
+use rspack_error::internal_error;
+use rspack_napi_shared::{
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  NapiResultExt, NAPI_ENV,
+};
 use serde::Deserialize;
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawPattern {
   pub from: String,
   pub to: Option<String>,
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "(ctx: RawCopyToFnCtx) => string")]
+  pub to_fn: Option<JsFunction>,
   pub context: Option<String>,
   pub to_type: Option<String>,
   pub no_error_on_missing: bool,
@@ -18,6 +27,37 @@ pub struct RawPattern {
   pub glob_options: RawGlobOptions,
 }
 
+impl Debug for RawPattern {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("RawPattern")
+      .field("from", &self.from)
+      .field("to", &self.to)
+      .field("to_fn", &self.to_fn.as_ref().map(|_| "Function"))
+      .field("context", &self.context)
+      .field("to_type", &self.to_type)
+      .field("no_error_on_missing", &self.no_error_on_missing)
+      .field("force", &self.force)
+      .field("priority", &self.priority)
+      .field("glob_options", &self.glob_options)
+      .finish()
+  }
+}
+
+#[napi(object)]
+pub struct RawCopyToFnCtx {
+  pub context: String,
+  pub absolute_filename: String,
+}
+
+impl From<CopyToFnCtx> for RawCopyToFnCtx {
+  fn from(value: CopyToFnCtx) -> Self {
+    Self {
+      context: value.context.to_string_lossy().to_string(),
+      absolute_filename: value.absolute_filename.to_string_lossy().to_string(),
+    }
+  }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
@@ -34,11 +74,40 @@ pub struct RawCopyConfig {
   pub patterns: Vec<RawPattern>,
 }
 
-impl From<RawPattern> for Pattern {
-  fn from(value: RawPattern) -> Self {
+fn try_into_copy_to(
+  to: Option<String>,
+  to_fn: Option<JsFunction>,
+) -> rspack_error::Result<Option<CopyTo>> {
+  let Some(to_fn) = to_fn else {
+    return Ok(to.map(CopyTo::String));
+  };
+  let to_fn: ThreadsafeFunction<RawCopyToFnCtx, String> =
+    NAPI_ENV.with(|env| -> anyhow::Result<_> {
+      let env = env.borrow().expect("Failed to get env with copy");
+      let to_fn = rspack_binding_macros::js_fn_into_threadsafe_fn!(to_fn, &Env::from(env));
+      Ok(to_fn)
+    })?;
+  let to_fn = Arc::new(to_fn);
+  Ok(Some(CopyTo::Fn(Arc::new(move |ctx: CopyToFnCtx| {
+    let to_fn = to_fn.clone();
+    Box::pin(async move {
+      to_fn
+        .call(ctx.into(), ThreadsafeFunctionCallMode::NonBlocking)
+        .into_rspack_result()?
+        .await
+        .map_err(|err| internal_error!("Failed to call the to function of copy: {err}"))?
+    })
+  }))))
+}
+
+impl TryFrom<RawPattern> for Pattern {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawPattern) -> rspack_error::Result<Self> {
     let RawPattern {
       from,
       to,
+      to_fn,
       context,
       to_type,
       no_error_on_missing,
@@ -47,9 +116,9 @@ impl From<RawPattern> for Pattern {
       glob_options,
     } = value;
 
-    Self {
+    Ok(Self {
       from,
-      to,
+      to: try_into_copy_to(to, to_fn)?,
       context: context.map(PathBuf::from),
       to_type: if let Some(to_type) = to_type {
         match to_type.to_lowercase().as_str() {
@@ -78,14 +147,20 @@ impl From<RawPattern> for Pattern {
             .collect()
         }),
       },
-    }
+    })
   }
 }
 
-impl From<RawCopyConfig> for CopyPluginConfig {
-  fn from(val: RawCopyConfig) -> Self {
-    Self {
-      patterns: val.patterns.into_iter().map(Into::into).collect(),
-    }
+impl TryFrom<RawCopyConfig> for CopyPluginConfig {
+  type Error = rspack_error::Error;
+
+  fn try_from(val: RawCopyConfig) -> rspack_error::Result<Self> {
+    Ok(Self {
+      patterns: val
+        .patterns
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<rspack_error::Result<_>>()?,
+    })
   }
 }
@@ -0,0 +1,19 @@
+use napi_derive::napi;
+use rspack_plugin_manifest::ManifestPluginOptions;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawManifestConfig {
+  pub filename: Option<String>,
+}
+
+impl From<RawManifestConfig> for ManifestPluginOptions {
+  fn from(value: RawManifestConfig) -> Self {
+    let default = Self::default();
+    Self {
+      filename: value.filename.unwrap_or(default.filename),
+    }
+  }
+}
@@ -1,18 +1,76 @@
+use std::fmt::Debug;
+
+use napi::JsFunction;
 use napi_derive::napi;
 use rspack_plugin_progress::ProgressPluginConfig;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
+use {
+  napi::Env,
+  rspack_napi_shared::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  rspack_napi_shared::NAPI_ENV,
+};
 
-#[derive(Deserialize, Debug, Serialize, Clone)]
+#[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawProgressPluginConfig {
   pub prefix: Option<String>,
+  pub profile: Option<bool>,
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = r#"(args: RawProgressPluginHandlerArgs) => void"#)]
+  pub handler: Option<JsFunction>,
 }
 
-impl From<RawProgressPluginConfig> for ProgressPluginConfig {
-  fn from(value: RawProgressPluginConfig) -> Self {
-    Self {
+impl Debug for RawProgressPluginConfig {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("RawProgressPluginConfig")
+      .field("prefix", &self.prefix)
+      .field("profile", &self.profile)
+      .field("handler", &"Function")
+      .finish()
+  }
+}
+
+#[napi(object)]
+pub struct RawProgressPluginHandlerArgs {
+  pub percentage: f64,
+  pub msg: String,
+  pub items: Vec<String>,
+}
+
+impl TryFrom<RawProgressPluginConfig> for ProgressPluginConfig {
+  type Error = rspack_error::Error;
+
+  #[allow(clippy::unwrap_in_result)]
+  fn try_from(value: RawProgressPluginConfig) -> Result<Self, Self::Error> {
+    let handler = value
+      .handler
+      .map(|handler| {
+        NAPI_ENV.with(|env| -> anyhow::Result<_> {
+          let env = env.borrow().expect("Failed to get env with progress");
+          let handler: ThreadsafeFunction<RawProgressPluginHandlerArgs, ()> =
+            rspack_binding_macros::js_fn_into_threadsafe_fn!(handler, &Env::from(env));
+          Ok(handler)
+        })
+      })
+      .transpose()?;
+
+    Ok(Self {
       prefix: value.prefix,
-    }
+      profile: value.profile.unwrap_or_default(),
+      handler: handler.map(|handler| {
+        Box::new(move |percentage: f32, msg: String, items: Vec<String>| {
+          // Progress is reported in a fire-and-forget manner, the result of the handler is ignored.
+          let _ = handler.call(
+            RawProgressPluginHandlerArgs {
+              percentage: percentage as f64,
+              msg,
+              items,
+            },
+            ThreadsafeFunctionCallMode::NonBlocking,
+          );
+        }) as _
+      }),
+    })
   }
 }
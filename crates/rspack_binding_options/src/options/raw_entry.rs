@@ -1,4 +1,14 @@
+use std::{fmt::Debug, sync::Arc};
+
+use napi::{Env, JsFunction};
 use napi_derive::napi;
+use rspack_core::EntryDescription;
+use rspack_error::internal_error;
+use rspack_napi_shared::{
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  NapiResultExt, NAPI_ENV,
+};
+use rspack_plugin_entry::DynamicEntryPlugin;
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug)]
@@ -12,4 +22,78 @@ pub struct RawEntryDescription {
   pub public_path: Option<String>,
   pub base_uri: Option<String>,
   pub filename: Option<String>,
+  pub depend_on: Option<Vec<String>>,
+}
+
+impl From<RawEntryDescription> for EntryDescription {
+  fn from(value: RawEntryDescription) -> Self {
+    Self {
+      import: value.import,
+      runtime: value.runtime,
+      chunk_loading: value.chunk_loading.as_deref().map(Into::into),
+      async_chunks: value.async_chunks,
+      public_path: value.public_path.map(Into::into),
+      base_uri: value.base_uri,
+      filename: value.filename.map(Into::into),
+      depend_on: value.depend_on,
+    }
+  }
+}
+
+#[derive(Deserialize, Debug)]
+#[napi(object)]
+pub struct RawDynamicEntryItem {
+  pub name: String,
+  pub description: RawEntryDescription,
+}
+
+/// The entry provided as a function, which is called at the start of each compilation
+#[derive(Deserialize)]
+#[napi(object)]
+pub struct RawDynamicEntry {
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = r#"() => Promise<RawDynamicEntryItem[]>"#)]
+  pub function: Option<JsFunction>,
+}
+
+impl Debug for RawDynamicEntry {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("RawDynamicEntry")
+      .field("function", &"Function")
+      .finish()
+  }
+}
+
+impl TryFrom<RawDynamicEntry> for DynamicEntryPlugin {
+  type Error = rspack_error::Error;
+
+  #[allow(clippy::unwrap_in_result)]
+  fn try_from(value: RawDynamicEntry) -> Result<Self, Self::Error> {
+    let function = value
+      .function
+      .ok_or_else(|| internal_error!("should have a function when entry is dynamic"))?;
+    let function: ThreadsafeFunction<(), Vec<RawDynamicEntryItem>> =
+      NAPI_ENV.with(|env| -> anyhow::Result<_> {
+        let env = env.borrow().expect("Failed to get env with dynamic entry");
+        let function = rspack_binding_macros::js_fn_into_threadsafe_fn!(function, &Env::from(env));
+        Ok(function)
+      })?;
+    let function = Arc::new(function);
+    Ok(DynamicEntryPlugin::new(Box::new(move || {
+      let function = function.clone();
+      Box::pin(async move {
+        let entries = function
+          .call((), ThreadsafeFunctionCallMode::NonBlocking)
+          .into_rspack_result()?
+          .await
+          .map_err(|err| internal_error!("Failed to call dynamic entry function: {err}"))??;
+        Ok(
+          entries
+            .into_iter()
+            .map(|item| (item.name, item.description.into()))
+            .collect(),
+        )
+      })
+    })))
+  }
 }
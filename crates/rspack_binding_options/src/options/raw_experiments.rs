@@ -18,4 +18,5 @@ pub struct RawExperiments {
   pub async_web_assembly: bool,
   pub new_split_chunks: bool,
   pub css: bool,
+  pub top_level_await: bool,
 }
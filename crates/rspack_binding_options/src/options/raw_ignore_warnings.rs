@@ -0,0 +1,103 @@
+use std::{fmt::Debug, sync::Arc};
+
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use rspack_core::{IgnoreWarning, IgnoreWarningFnCtx};
+use rspack_error::internal_error;
+use serde::Deserialize;
+use {
+  rspack_napi_shared::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  rspack_napi_shared::{NapiResultExt, NAPI_ENV},
+};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawIgnoreWarning {
+  #[napi(ts_type = r#""message" | "module" | "function""#)]
+  pub r#type: String,
+  pub regexp_matcher: Option<String>,
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = r#"(warning: RawIgnoreWarningFnCtx) => boolean"#)]
+  pub func_matcher: Option<JsFunction>,
+}
+
+impl Debug for RawIgnoreWarning {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("RawIgnoreWarning")
+      .field("r#type", &self.r#type)
+      .field("regexp_matcher", &self.regexp_matcher)
+      .field("func_matcher", &"...")
+      .finish()
+  }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawIgnoreWarningFnCtx {
+  pub message: String,
+  pub module_identifier: Option<String>,
+  pub file: Option<String>,
+}
+
+impl From<IgnoreWarningFnCtx> for RawIgnoreWarningFnCtx {
+  fn from(value: IgnoreWarningFnCtx) -> Self {
+    Self {
+      message: value.message,
+      module_identifier: value.module_identifier,
+      file: value.file,
+    }
+  }
+}
+
+impl TryFrom<RawIgnoreWarning> for IgnoreWarning {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawIgnoreWarning) -> rspack_error::Result<Self> {
+    let regexp_matcher = |regexp_matcher: Option<String>| {
+      let regexp_matcher = regexp_matcher.ok_or_else(|| {
+        internal_error!(
+          "should have a regexp_matcher when RawIgnoreWarning.type is \"{}\"",
+          value.r#type
+        )
+      })?;
+      rspack_regex::RspackRegex::new(&regexp_matcher)
+    };
+    let result = match value.r#type.as_str() {
+      "message" => Self::Message(regexp_matcher(value.regexp_matcher)?),
+      "module" => Self::Module(regexp_matcher(value.regexp_matcher)?),
+      "function" => {
+        let func_matcher = value.func_matcher.ok_or_else(|| {
+          internal_error!("should have a func_matcher when RawIgnoreWarning.type is \"function\"")
+        })?;
+        let func_matcher: ThreadsafeFunction<RawIgnoreWarningFnCtx, bool> =
+          NAPI_ENV.with(|env| -> anyhow::Result<_> {
+            let env = env.borrow().expect("Failed to get env with ignoreWarnings");
+            let func_matcher =
+              rspack_binding_macros::js_fn_into_threadsafe_fn!(func_matcher, &Env::from(env));
+            Ok(func_matcher)
+          })?;
+        let func_matcher = Arc::new(func_matcher);
+
+        Self::Fn(Box::new(move |ctx: IgnoreWarningFnCtx| {
+          let func_matcher = func_matcher.clone();
+          Box::pin(async move {
+            func_matcher
+              .call(ctx.into(), ThreadsafeFunctionCallMode::NonBlocking)
+              .into_rspack_result()?
+              .await
+              .map_err(|err| internal_error!("Failed to call ignoreWarnings function: {err}"))?
+          })
+        }))
+      }
+      _ => {
+        return Err(internal_error!(
+          "Failed to resolve the ignoreWarnings type {}. Expected type is `message`, `module` or `function`.",
+          value.r#type
+        ))
+      }
+    };
+    Ok(result)
+  }
+}
@@ -0,0 +1,22 @@
+use napi_derive::napi;
+use rspack_core::InfrastructureLoggingOptions;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawInfrastructureLoggingOptions {
+  #[napi(ts_type = "'none' | 'error' | 'warn' | 'info' | 'log' | 'verbose'")]
+  pub level: String,
+  /// Whether `infrastructureLogging.debug` enables the debug logs of any logger
+  pub debug: bool,
+}
+
+impl From<RawInfrastructureLoggingOptions> for InfrastructureLoggingOptions {
+  fn from(value: RawInfrastructureLoggingOptions) -> Self {
+    Self {
+      level: value.level.as_str().into(),
+      debug: value.debug,
+    }
+  }
+}
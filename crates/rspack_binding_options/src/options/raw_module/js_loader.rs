@@ -1,18 +1,21 @@
 use std::{
+  collections::HashMap,
   path::{Path, PathBuf},
   str::FromStr,
 };
 
 use napi_derive::napi;
-use rspack_core::{rspack_sources::SourceMap, Content, ResourceData};
+use rspack_core::{rspack_sources::SourceMap, BuildInfoExtras, Content, ResourceData};
 use rspack_error::Diagnostic;
 use rustc_hash::FxHashSet as HashSet;
+use serde::{Deserialize, Serialize};
+use sugar_path::SugarPath;
 use tracing::{span_enabled, Level};
 use {
   napi::bindgen_prelude::*,
   napi::NapiRaw,
   rspack_binding_macros::call_js_function_with_napi_objects,
-  rspack_core::{Loader, LoaderContext, LoaderRunnerContext},
+  rspack_core::{Loader, LoaderContext, LoaderItemOrigin, LoaderRunnerContext},
   rspack_error::internal_error,
   rspack_identifier::{Identifiable, Identifier},
   rspack_napi_shared::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
@@ -109,6 +112,83 @@ impl TryFrom<JsFunction> for JsLoaderRunner {
 pub struct JsLoaderAdapter {
   pub runner: JsLoaderRunner,
   pub identifier: Identifier,
+  /// Options of each loader in `identifier`, split by `$` if it's a composed loader
+  pub options: Vec<Option<String>>,
+  /// Origin of each loader in `identifier`, empty if the origins are unknown
+  pub origins: Vec<LoaderItemOrigin>,
+}
+
+impl JsLoaderAdapter {
+  fn loader_idents(&self) -> Vec<String> {
+    self
+      .identifier
+      .split('$')
+      .map(|loader| {
+        let query = loader.find('?').map(|i| &loader[i..]).unwrap_or_default();
+        let query = query.split('#').next().unwrap_or_default();
+        match query.strip_prefix("??") {
+          Some(ident) => ident.to_owned(),
+          None => loader.to_owned(),
+        }
+      })
+      .collect()
+  }
+
+  /// Whether the JS loaders make use of source maps, that is `loaderContext.sourceMap` on the JS side
+  /// is enabled by the devtool of this compilation, or any of the loaders asks for them with its
+  /// `sourceMap` option. Source maps are not serialized for the JS loaders otherwise.
+  fn needs_source_map(&self, loader_context: &LoaderContext<'_, LoaderRunnerContext>) -> bool {
+    loader_context.context.options.devtool.enabled()
+      || self.options.iter().flatten().any(|options| {
+        serde_json::from_str::<serde_json::Value>(options)
+          .ok()
+          .and_then(|options| options.get("sourceMap")?.as_bool())
+          .unwrap_or_default()
+      })
+  }
+
+  /// The content, source map and additional data are moved to the JS side at the normal stage,
+  /// as they are replaced by the result of the JS loaders, and they are not needed at the pitching stage.
+  fn to_js_loader_context(
+    &self,
+    loader_context: &mut LoaderContext<'_, LoaderRunnerContext>,
+    is_pitching: bool,
+  ) -> rspack_error::Result<JsLoaderContext> {
+    let needs_source_map = self.needs_source_map(loader_context);
+    let content = loader_context.content.take();
+    // The source map of the input is left to the runner if it's not passed to the JS loaders.
+    let source_map = if is_pitching {
+      loader_context.source_map.take()
+    } else if needs_source_map {
+      loader_context.take_source_map()
+    } else {
+      None
+    };
+    let additional_data = loader_context.additional_data.take();
+    let mut js_loader_context: JsLoaderContext = (&*loader_context).try_into()?;
+    if is_pitching {
+      loader_context.content = content;
+      loader_context.source_map = source_map;
+      loader_context.additional_data = additional_data;
+    } else {
+      js_loader_context.content = content.map(|c| c.into_bytes().into());
+      js_loader_context.source_map = source_map
+        .map(|v| v.to_json())
+        .transpose()
+        .map_err(|e| internal_error!(e.to_string()))?
+        .map(|v| v.into_bytes().into());
+      js_loader_context.additional_data = additional_data.map(|v| v.into_bytes().into());
+    }
+    js_loader_context.is_pitching = is_pitching;
+    js_loader_context.loader_options = self.options.clone();
+    js_loader_context.loader_idents = self.loader_idents();
+    js_loader_context.loader_origins = self
+      .origins
+      .iter()
+      .map(|origin| origin.as_str().to_owned())
+      .collect();
+    Ok(js_loader_context)
+  }
 }
 
 impl std::fmt::Debug for JsLoaderAdapter {
@@ -131,8 +211,7 @@ impl Loader<LoaderRunnerContext> for JsLoaderAdapter {
     &self,
     loader_context: &mut LoaderContext<'_, LoaderRunnerContext>,
   ) -> rspack_error::Result<()> {
-    let mut js_loader_context: JsLoaderContext = (&*loader_context).try_into()?;
-    js_loader_context.is_pitching = true;
+    let js_loader_context = self.to_js_loader_context(loader_context, true)?;
 
     let loader_result = self
       .runner
@@ -142,6 +221,8 @@ impl Loader<LoaderRunnerContext> for JsLoaderAdapter {
       .map_err(|err| internal_error!("Failed to call loader: {err}"))??;
 
     if let Some(loader_result) = loader_result {
+      // Keep the data of the pitching stage for the normal stage of the same loader
+      loader_context.set_pitch_data(loader_result.pitch_data.as_ref().map(|d| d.to_vec()));
       // This indicate that the JS loaders pitched(return something) successfully
       // and executed the normal loader on the JS loader side(in that group),
       // then here we want to change the control flow in order
@@ -160,9 +241,8 @@ impl Loader<LoaderRunnerContext> for JsLoaderAdapter {
     &self,
     loader_context: &mut LoaderContext<'_, LoaderRunnerContext>,
   ) -> rspack_error::Result<()> {
-    let mut js_loader_context: JsLoaderContext = (&*loader_context).try_into()?;
     // Instruct the JS loader-runner to execute loaders in backwards.
-    js_loader_context.is_pitching = false;
+    let js_loader_context = self.to_js_loader_context(loader_context, false)?;
 
     let loader_result = self
       .runner
@@ -177,47 +257,189 @@ impl Loader<LoaderRunnerContext> for JsLoaderAdapter {
 
     Ok(())
   }
+
+  fn options(&self) -> Option<&str> {
+    match self.options.as_slice() {
+      [options] => options.as_deref(),
+      _ => None,
+    }
+  }
+
+  fn is_composed(&self) -> bool {
+    // Only the loaders composed by `JsLoaderResolver::before_loaders` know their origins
+    !self.origins.is_empty()
+  }
 }
 
+/// The result is synced even if a JS loader failed midway, the dependencies recorded before
+/// the failure are kept by the loader runner, and the error is returned afterwards.
 fn sync_loader_context(
   loader_result: JsLoaderResult,
   loader_context: &mut LoaderContext<'_, LoaderRunnerContext>,
 ) -> rspack_error::Result<()> {
   loader_context.cacheable = loader_result.cacheable;
-  loader_context.file_dependencies = loader_result
-    .file_dependencies
-    .into_iter()
-    .map(std::path::PathBuf::from)
-    .collect();
-  loader_context.context_dependencies = loader_result
-    .context_dependencies
-    .into_iter()
-    .map(std::path::PathBuf::from)
-    .collect();
-  loader_context.missing_dependencies = loader_result
-    .missing_dependencies
-    .into_iter()
-    .map(std::path::PathBuf::from)
-    .collect();
-  loader_context.build_dependencies = loader_result
-    .build_dependencies
-    .into_iter()
-    .map(std::path::PathBuf::from)
-    .collect();
+  let context = loader_context
+    .resource_path
+    .parent()
+    .map(Path::to_path_buf)
+    .unwrap_or_default();
+  let mut diagnostics = vec![];
+  let mut normalize = |dependencies: Vec<String>, check_glob: bool| -> HashSet<PathBuf> {
+    dependencies
+      .into_iter()
+      .filter_map(|dependency| {
+        let path = normalize_dependency(&dependency, &context);
+        if check_glob && dependency.contains(GLOB_CHARS) && !path.exists() {
+          diagnostics.push(Diagnostic::warn(
+            "Invalid Dependency".to_string(),
+            format!(
+              "{dependency} looks like a glob pattern which can never be watched, use `addGlobDependency` or `addContextDependency` with its directory instead"
+            ),
+            0,
+            0,
+          ));
+          return None;
+        }
+        Some(path)
+      })
+      .collect()
+  };
+  let mut file_dependencies = normalize(loader_result.file_dependencies, true);
+  let mut context_dependencies = normalize(loader_result.context_dependencies, true);
+  let missing_dependencies = normalize(loader_result.missing_dependencies, false);
+  let build_dependencies = normalize(loader_result.build_dependencies, true);
+  for pattern in loader_result.glob_dependencies {
+    match expand_glob_dependency(&pattern, &context) {
+      Ok((base, matches)) => {
+        file_dependencies.extend(matches);
+        context_dependencies.insert(base);
+      }
+      Err(e) => diagnostics.push(Diagnostic::warn(
+        "Invalid Dependency".to_string(),
+        e.to_string(),
+        0,
+        0,
+      )),
+    }
+  }
+  loader_context.file_dependencies = file_dependencies;
+  loader_context.context_dependencies = context_dependencies;
+  loader_context.missing_dependencies = missing_dependencies;
+  loader_context.build_dependencies = build_dependencies;
+  loader_context.diagnostics.extend(diagnostics);
   loader_context.content = loader_result
     .content
     .map(|c| rspack_core::Content::from(Into::<Vec<u8>>::into(c)));
   loader_context.source_map = loader_result
     .source_map
-    .as_ref()
-    .map(|s| rspack_core::rspack_sources::SourceMap::from_slice(s))
-    .transpose()
-    .map_err(|e| internal_error!(e.to_string()))?;
+    .as_deref()
+    .map(source_map_from_slice)
+    .transpose()?;
   loader_context.additional_data = loader_result
     .additional_data
     .map(|item| String::from_utf8_lossy(&item).to_string());
+  loader_context.build_info_extras = loader_result
+    .extras
+    .as_deref()
+    .map(build_info_extras_from_slice)
+    .transpose()?
+    .unwrap_or_default();
+
+  match loader_result.error {
+    Some(error) => Err(internal_error!(error)),
+    None => Ok(()),
+  }
+}
 
-  Ok(())
+/// The serialized form of [BuildInfoExtras] exchanged with the JS loaders.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawBuildInfoExtras {
+  values: serde_json::Map<String, serde_json::Value>,
+  #[serde(default)]
+  affects_hash: std::collections::BTreeSet<String>,
+}
+
+fn build_info_extras_to_buffer(extras: &BuildInfoExtras) -> rspack_error::Result<Option<Buffer>> {
+  if extras.is_empty() {
+    return Ok(None);
+  }
+  let raw = RawBuildInfoExtras {
+    values: extras.values.clone(),
+    affects_hash: extras.affects_hash.clone(),
+  };
+  let raw = serde_json::to_vec(&raw).map_err(|e| internal_error!(e.to_string()))?;
+  Ok(Some(raw.into()))
+}
+
+fn build_info_extras_from_slice(slice: &[u8]) -> rspack_error::Result<BuildInfoExtras> {
+  let raw: RawBuildInfoExtras =
+    serde_json::from_slice(slice).map_err(|e| internal_error!(e.to_string()))?;
+  Ok(BuildInfoExtras {
+    values: raw.values,
+    affects_hash: raw.affects_hash,
+  })
+}
+
+const GLOB_CHARS: [char; 5] = ['*', '?', '[', '{', '!'];
+
+/// Resolve the dependency returned by JS loaders against the directory of the resource.
+fn normalize_dependency(dependency: &str, context: &Path) -> PathBuf {
+  let path = Path::new(dependency);
+  if path.is_absolute() {
+    path.normalize()
+  } else {
+    context.join(path).normalize()
+  }
+}
+
+/// Expand the glob pattern added by `addGlobDependency`, returns the directory before the first
+/// glob segment, which is watched as a context dependency, and the matched files.
+fn expand_glob_dependency(
+  pattern: &str,
+  context: &Path,
+) -> rspack_error::Result<(PathBuf, Vec<PathBuf>)> {
+  let pattern = normalize_dependency(pattern, context);
+  let base = pattern
+    .components()
+    .take_while(|component| !component.as_os_str().to_string_lossy().contains(GLOB_CHARS))
+    .collect::<PathBuf>();
+  // `glob` only accepts `/` as the separator
+  let pattern = pattern.to_string_lossy().replace('\\', "/");
+  let matches = glob::glob(&pattern)
+    .map_err(|e| internal_error!("Invalid glob dependency {pattern}: {e}"))?
+    .filter_map(|entry| entry.ok())
+    .filter(|path| path.is_file())
+    .map(|path| path.normalize())
+    .collect();
+  Ok((base.normalize(), matches))
+}
+
+/// Parse the source map returned by JS loaders, `sourceRoot` is resolved into `sources`,
+/// so maps with different `sourceRoot`s can be chained by the loader runner.
+fn source_map_from_slice(slice: &[u8]) -> rspack_error::Result<SourceMap> {
+  let mut raw: serde_json::Value =
+    serde_json::from_slice(slice).map_err(|e| internal_error!(e.to_string()))?;
+  if let Some(map) = raw.as_object_mut() {
+    let source_root = match map.remove("sourceRoot") {
+      Some(serde_json::Value::String(source_root)) => source_root,
+      _ => String::new(),
+    };
+    let source_root = source_root.trim_end_matches('/');
+    if let (false, Some(serde_json::Value::Array(sources))) =
+      (source_root.is_empty(), map.get_mut("sources"))
+    {
+      for source in sources.iter_mut() {
+        if let serde_json::Value::String(source) = source {
+          if !source.starts_with('/') && !source.contains("://") {
+            *source = format!("{source_root}/{source}");
+          }
+        }
+      }
+    }
+  }
+  let raw = serde_json::to_vec(&raw).map_err(|e| internal_error!(e.to_string()))?;
+  SourceMap::from_slice(&raw).map_err(|e| internal_error!(e.to_string()))
 }
 
 #[napi(object)]
@@ -236,9 +458,22 @@ pub struct JsLoaderContext {
   pub missing_dependencies: Vec<String>,
   pub build_dependencies: Vec<String>,
   pub asset_filenames: Vec<String>,
+  /// JSON serialized metadata stashed by loaders into the build info of the module
+  pub extras: Option<Buffer>,
 
   pub current_loader: String,
+  /// JSON serialized options of each loader in `current_loader`, split by `$`
+  pub loader_options: Vec<Option<String>>,
+  /// Ident of each loader in `current_loader`, split by `$`
+  pub loader_idents: Vec<String>,
+  /// Origin of each loader in `current_loader`, one of `pre`, `normal`, `post` and `inline`
+  pub loader_origins: Vec<String>,
+  /// Settings attached to the module by `createModule` hooks
+  #[napi(ts_type = "Record<string, any>")]
+  pub module_settings: HashMap<String, serde_json::Value>,
   pub is_pitching: bool,
+  /// Serialized `data` of each loader in `current_loader` stashed at the pitching stage
+  pub pitch_data: Option<Buffer>,
   /// Internal loader context
   /// @internal
   pub context: External<rspack_core::LoaderRunnerContext>,
@@ -294,25 +529,65 @@ impl TryFrom<&rspack_core::LoaderContext<'_, rspack_core::LoaderRunnerContext>>
         .map(|i| i.to_string_lossy().to_string())
         .collect(),
       asset_filenames: cx.asset_filenames.iter().map(|i| i.to_owned()).collect(),
+      extras: build_info_extras_to_buffer(&cx.build_info_extras)?,
 
       current_loader: cx.current_loader().to_string(),
+      loader_options: vec![cx.current_loader().options().map(|o| o.to_owned())],
+      loader_idents: vec![cx.current_loader().identifier().to_string()],
+      loader_origins: vec![],
+      module_settings: (*cx.context.module_settings).clone(),
       is_pitching: true,
+      pitch_data: cx.pitch_data().map(|d| d.to_vec().into()),
       context: External::new(cx.context.clone()),
       diagnostics: External::new(cx.diagnostics.clone()),
     })
   }
 }
 
+/// A builtin loader and its JSON serialized options, run by [run_builtin_loaders]
+#[napi(object)]
+pub struct JsBuiltinLoader {
+  pub builtin: String,
+  pub options: Option<String>,
+}
+
 pub async fn run_builtin_loader(
   builtin: String,
   options: Option<&str>,
   loader_context: JsLoaderContext,
 ) -> Result<JsLoaderContext> {
-  use rspack_loader_runner::__private::loader::LoaderItemList;
+  run_builtin_loaders(
+    vec![JsBuiltinLoader {
+      builtin,
+      options: options.map(ToOwned::to_owned),
+    }],
+    loader_context,
+  )
+  .await
+}
+
+/// Run several builtin loaders in one call, the normal loaders are run in reverse order just like
+/// the loader runner. The builtin loaders are never pitched.
+///
+/// The diagnostics reported by each loader are attributed to the loader.
+pub async fn run_builtin_loaders(
+  loaders: Vec<JsBuiltinLoader>,
+  loader_context: JsLoaderContext,
+) -> Result<JsLoaderContext> {
+  use rspack_loader_runner::__private::loader::{LoaderItem, LoaderItemList};
 
-  let loader = get_builtin_loader(&builtin, options);
-  let loader_item = loader.clone().into();
-  let list = &[loader_item];
+  if loaders.is_empty() {
+    return Err(Error::from_reason("No builtin loaders to run"));
+  }
+  let loaders = loaders
+    .iter()
+    .map(|loader| get_builtin_loader(&loader.builtin, loader.options.as_deref()))
+    .collect::<rspack_error::Result<Vec<_>>>()
+    .map_err(|e| Error::from_reason(e.to_string()))?;
+  let list = loaders
+    .iter()
+    .map(|loader| LoaderItem::from(loader.clone()))
+    .collect::<Vec<_>>();
 
   let mut cx = LoaderContext {
     content: loader_context
@@ -357,29 +632,55 @@ pub async fn run_builtin_loader(
         .map(|m| PathBuf::from_str(m).expect("Should convert to path")),
     ),
     asset_filenames: HashSet::from_iter(loader_context.asset_filenames.into_iter()),
+    build_info_extras: loader_context
+      .extras
+      .as_deref()
+      .map(build_info_extras_from_slice)
+      .transpose()
+      .map_err(|e| Error::from_reason(e.to_string()))?
+      .unwrap_or_default(),
     // Initialize with no diagnostic
     diagnostics: vec![],
 
     __resource_data: &ResourceData::new(Default::default(), Default::default()),
-    __loader_items: LoaderItemList(list),
+    __loader_items: LoaderItemList(&list),
     __loader_index: 0,
     __plugins: &[],
+    __pitch_data: loader_context
+      .pitch_data
+      .map(|d| (0, d.to_vec()))
+      .into_iter()
+      .collect(),
+    __loader_durations: None,
+    __input_source_map: None,
   };
-  if loader_context.is_pitching {
-    // Run pitching loader
-    loader
-      .pitch(&mut cx)
-      .await
-      .map_err(|e| Error::from_reason(e.to_string()))?;
-  } else {
-    // Run normal loader
-    loader
-      .run(&mut cx)
-      .await
-      .map_err(|e| Error::from_reason(e.to_string()))?;
+
+  for (index, loader) in loaders.iter().enumerate().rev() {
+    cx.__loader_index = index;
+    let diagnostics = cx.diagnostics.len();
+    cx.__input_source_map = cx.source_map.take();
+    let result = loader.run(&mut cx).await;
+    // Keep the source map of the input if the loader neither takes it nor produces one
+    if cx.source_map.is_none() {
+      cx.source_map = cx.__input_source_map.take();
+    }
+    let reported = cx.diagnostics.split_off(diagnostics);
+    cx.diagnostics
+      .extend(reported.into_iter().map(|diagnostic| {
+        if diagnostic.loader.is_some() {
+          diagnostic
+        } else {
+          diagnostic.with_loader(Some(loader.identifier().to_string()))
+        }
+      }));
+    result.map_err(|e| Error::from_reason(e.to_string()))?;
   }
 
-  JsLoaderContext::try_from(&cx).map_err(|e| Error::from_reason(e.to_string()))
+  let mut js_loader_context =
+    JsLoaderContext::try_from(&cx).map_err(|e| Error::from_reason(e.to_string()))?;
+  // The loaders have finished the normal stage
+  js_loader_context.is_pitching = false;
+  Ok(js_loader_context)
 }
 
 #[napi(object)]
@@ -390,11 +691,20 @@ pub struct JsLoaderResult {
   pub context_dependencies: Vec<String>,
   pub missing_dependencies: Vec<String>,
   pub build_dependencies: Vec<String>,
+  /// Glob patterns added by `addGlobDependency`, the matched files are added as file dependencies,
+  /// and the directory before the first glob segment is added as a context dependency
+  pub glob_dependencies: Vec<String>,
   pub source_map: Option<Buffer>,
   pub additional_data: Option<Buffer>,
   pub cacheable: bool,
   /// Used to instruct how rust loaders should execute
   pub is_pitching: bool,
+  /// Serialized `data` of each loader stashed at the pitching stage
+  pub pitch_data: Option<Buffer>,
+  /// JSON serialized metadata stashed by loaders, replaces the build info extras of the module
+  pub extras: Option<Buffer>,
+  /// The error of the JS loader failed midway, returned with the result produced before the failure
+  pub error: Option<String>,
 }
 
 pub type LoaderThreadsafeLoaderResult = Option<JsLoaderResult>;
@@ -1,6 +1,10 @@
 mod js_loader;
 
-use std::{collections::HashMap, fmt::Debug, sync::Arc};
+use std::{
+  collections::HashMap,
+  fmt::Debug,
+  sync::{Arc, Once},
+};
 
 use derivative::Derivative;
 pub use js_loader::JsLoaderAdapter;
@@ -8,11 +12,12 @@ pub use js_loader::*;
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 use rspack_core::{
-  AssetGeneratorDataUrl, AssetGeneratorDataUrlOptions, AssetGeneratorOptions,
-  AssetInlineGeneratorOptions, AssetParserDataUrl, AssetParserDataUrlOptions, AssetParserOptions,
-  AssetResourceGeneratorOptions, BoxLoader, DescriptionData, FuncUseCtx, GeneratorOptions,
-  GeneratorOptionsByModuleType, ModuleOptions, ModuleRule, ModuleRuleEnforce, ModuleRuleUse,
-  ModuleRuleUseLoader, ModuleType, ParserOptions, ParserOptionsByModuleType,
+  register_builtin_loader, AssetGeneratorDataUrl, AssetGeneratorDataUrlFnArgs,
+  AssetGeneratorDataUrlOptions, AssetGeneratorOptions, AssetInlineGeneratorOptions,
+  AssetParserDataUrl, AssetParserDataUrlOptions, AssetParserOptions, AssetResourceGeneratorOptions,
+  BoxLoader, DescriptionData, FuncUseCtx, GeneratorOptions, GeneratorOptionsByModuleType,
+  ModuleOptions, ModuleRule, ModuleRuleEnforce, ModuleRuleUse, ModuleRuleUseLoader, ModuleType,
+  ParserOptions, ParserOptionsByModuleType,
 };
 use rspack_error::internal_error;
 use rspack_loader_sass::SASS_LOADER_IDENTIFIER;
@@ -25,25 +30,38 @@ use {
 
 use crate::{RawOptionsApply, RawResolveOptions};
 
-pub fn get_builtin_loader(builtin: &str, options: Option<&str>) -> BoxLoader {
-  if builtin.starts_with(SASS_LOADER_IDENTIFIER) {
-    return Arc::new(rspack_loader_sass::SassLoader::new(
-      serde_json::from_str(options.unwrap_or("{}")).unwrap_or_else(|e| {
-        panic!("Could not parse builtin:sass-loader options: {options:?}, error: {e:?}")
-      }),
-    ));
-  }
+fn create_sass_loader(request: &str, options: Option<&str>) -> rspack_error::Result<BoxLoader> {
+  let options = serde_json::from_str(options.unwrap_or("{}")).map_err(|e| {
+    internal_error!("Could not parse builtin:sass-loader options: {options:?}, error: {e:?}")
+  })?;
+  Ok(Arc::new(rspack_loader_sass::SassLoader::new(
+    options,
+    Some(request.into()),
+  )))
+}
 
-  if builtin.starts_with(SWC_LOADER_IDENTIFIER) {
-    return Arc::new(rspack_loader_swc::SwcLoader::new(
-      serde_json::from_str(options.unwrap_or("{}")).unwrap_or_else(|e| {
-        panic!("Could not parse builtin:swc-loader options:{options:?},error: {e:?}")
-      }),
-      Some(builtin.into()),
-    ));
-  }
+fn create_swc_loader(request: &str, options: Option<&str>) -> rspack_error::Result<BoxLoader> {
+  let options = serde_json::from_str(options.unwrap_or("{}")).map_err(|e| {
+    internal_error!("Could not parse builtin:swc-loader options: {options:?}, error: {e:?}")
+  })?;
+  Ok(Arc::new(rspack_loader_swc::SwcLoader::new(
+    options,
+    Some(request.into()),
+  )))
+}
 
-  unreachable!("Unexpected builtin loader: {builtin}")
+/// Register the builtin loaders shipped with rspack, it's a no-op after the first call.
+pub fn register_builtin_loaders() {
+  static REGISTER: Once = Once::new();
+  REGISTER.call_once(|| {
+    register_builtin_loader(SASS_LOADER_IDENTIFIER, create_sass_loader);
+    register_builtin_loader(SWC_LOADER_IDENTIFIER, create_swc_loader);
+  });
+}
+
+pub fn get_builtin_loader(builtin: &str, options: Option<&str>) -> rspack_error::Result<BoxLoader> {
+  register_builtin_loaders();
+  rspack_core::get_builtin_loader(builtin, options)
 }
 
 /// `loader` is for js side loader, `builtin_loader` is for rust side loader,
@@ -371,37 +389,46 @@ pub struct RawGeneratorOptions {
   pub asset_resource: Option<RawAssetResourceGeneratorOptions>,
 }
 
-impl From<RawGeneratorOptions> for GeneratorOptions {
-  fn from(value: RawGeneratorOptions) -> Self {
-    match value.r#type.as_str() {
+impl TryFrom<RawGeneratorOptions> for GeneratorOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawGeneratorOptions) -> rspack_error::Result<Self> {
+    Ok(match value.r#type.as_str() {
       "asset" => Self::Asset(
         value
           .asset
-          .expect("should have an \"asset\" when RawGeneratorOptions.type is \"asset\"")
-          .into(),
+          .ok_or_else(|| {
+            internal_error!("should have an \"asset\" when RawGeneratorOptions.type is \"asset\"")
+          })?
+          .try_into()?,
       ),
       "asset/inline" => Self::AssetInline(
         value
           .asset_inline
-          .expect(
-            "should have an \"asset_inline\" when RawGeneratorOptions.type is \"asset/inline\"",
-          )
-          .into(),
+          .ok_or_else(|| {
+            internal_error!(
+              "should have an \"asset_inline\" when RawGeneratorOptions.type is \"asset/inline\""
+            )
+          })?
+          .try_into()?,
       ),
       "asset/resource" => Self::AssetResource(
         value
           .asset_resource
-          .expect(
-            "should have an \"asset_resource\" when RawGeneratorOptions.type is \"asset/resource\"",
-          )
+          .ok_or_else(|| {
+            internal_error!(
+              "should have an \"asset_resource\" when RawGeneratorOptions.type is \"asset/resource\""
+            )
+          })?
           .into(),
       ),
       "unknown" => Self::Unknown,
-      _ => panic!(
-        "Failed to resolve the RawGeneratorOptions.type {}. Expected type is \"asset\", \"asset/inline\", \"asset/resource\", \"unknown\".",
-        value.r#type
-      ),
-    }
+      r#type => {
+        return Err(internal_error!(
+          "Failed to resolve the RawGeneratorOptions.type {type}. Expected type is \"asset\", \"asset/inline\", \"asset/resource\", \"unknown\"."
+        ))
+      }
+    })
   }
 }
 
@@ -414,13 +441,15 @@ pub struct RawAssetGeneratorOptions {
   pub data_url: Option<RawAssetGeneratorDataUrl>,
 }
 
-impl From<RawAssetGeneratorOptions> for AssetGeneratorOptions {
-  fn from(value: RawAssetGeneratorOptions) -> Self {
-    Self {
+impl TryFrom<RawAssetGeneratorOptions> for AssetGeneratorOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawAssetGeneratorOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
       filename: value.filename.map(|i| i.into()),
       public_path: value.public_path.map(|i| i.into()),
-      data_url: value.data_url.map(|i| i.into()),
-    }
+      data_url: value.data_url.map(|i| i.try_into()).transpose()?,
+    })
   }
 }
 
@@ -431,11 +460,13 @@ pub struct RawAssetInlineGeneratorOptions {
   pub data_url: Option<RawAssetGeneratorDataUrl>,
 }
 
-impl From<RawAssetInlineGeneratorOptions> for AssetInlineGeneratorOptions {
-  fn from(value: RawAssetInlineGeneratorOptions) -> Self {
-    Self {
-      data_url: value.data_url.map(|i| i.into()),
-    }
+impl TryFrom<RawAssetInlineGeneratorOptions> for AssetInlineGeneratorOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawAssetInlineGeneratorOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
+      data_url: value.data_url.map(|i| i.try_into()).transpose()?,
+    })
   }
 }
 
@@ -456,29 +487,88 @@ impl From<RawAssetResourceGeneratorOptions> for AssetResourceGeneratorOptions {
   }
 }
 
-#[derive(Debug, Deserialize, Default)]
+#[derive(Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawAssetGeneratorDataUrl {
-  #[napi(ts_type = r#""options""#)]
+  #[napi(ts_type = r#""options" | "function""#)]
   pub r#type: String,
   pub options: Option<RawAssetGeneratorDataUrlOptions>,
-  // TODO: pub function
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = r#"(args: RawAssetGeneratorDataUrlFnArgs) => string"#)]
+  pub function: Option<JsFunction>,
+}
+
+impl Debug for RawAssetGeneratorDataUrl {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("RawAssetGeneratorDataUrl")
+      .field("r#type", &self.r#type)
+      .field("options", &self.options)
+      .field("function", &"...")
+      .finish()
+  }
+}
+
+#[napi(object)]
+pub struct RawAssetGeneratorDataUrlFnArgs {
+  pub filename: String,
+  pub content: Buffer,
 }
 
-impl From<RawAssetGeneratorDataUrl> for AssetGeneratorDataUrl {
-  fn from(value: RawAssetGeneratorDataUrl) -> Self {
+impl From<AssetGeneratorDataUrlFnArgs> for RawAssetGeneratorDataUrlFnArgs {
+  fn from(value: AssetGeneratorDataUrlFnArgs) -> Self {
+    Self {
+      filename: value.filename,
+      content: value.content.into(),
+    }
+  }
+}
+
+impl TryFrom<RawAssetGeneratorDataUrl> for AssetGeneratorDataUrl {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawAssetGeneratorDataUrl) -> rspack_error::Result<Self> {
     match value.r#type.as_str() {
-      "options" => Self::Options(
+      "options" => Ok(Self::Options(
         value
           .options
-          .expect("should have an \"options\" when RawAssetGeneratorDataUrl.type is \"options\"")
+          .ok_or_else(|| {
+            internal_error!(
+              "should have an \"options\" when RawAssetGeneratorDataUrl.type is \"options\""
+            )
+          })?
           .into(),
-      ),
-      _ => panic!(
-        "Failed to resolve the RawAssetGeneratorDataUrl.type {}. Expected type is `options`.",
-        value.r#type
-      ),
+      )),
+      "function" => {
+        let function = value.function.ok_or_else(|| {
+          internal_error!(
+            "should have a \"function\" when RawAssetGeneratorDataUrl.type is \"function\""
+          )
+        })?;
+        let function: ThreadsafeFunction<RawAssetGeneratorDataUrlFnArgs, String> =
+          NAPI_ENV.with(|env| -> anyhow::Result<_> {
+            let env = env.borrow().expect("Failed to get env with dataUrl");
+            let function =
+              rspack_binding_macros::js_fn_into_threadsafe_fn!(function, &Env::from(env));
+            Ok(function)
+          })?;
+        let function = Arc::new(function);
+        Ok(Self::Func(Arc::new(
+          move |args: AssetGeneratorDataUrlFnArgs| {
+            let function = function.clone();
+            Box::pin(async move {
+              function
+                .call(args.into(), ThreadsafeFunctionCallMode::NonBlocking)
+                .into_rspack_result()?
+                .await
+                .map_err(|err| internal_error!("Failed to call dataUrl function: {err}"))?
+            })
+          },
+        )))
+      }
+      r#type => Err(internal_error!(
+        "Failed to resolve the RawAssetGeneratorDataUrl.type {type}. Expected type is `options` or `function`."
+      )),
     }
   }
 }
@@ -652,7 +742,7 @@ impl RawOptionsApply for RawModuleRule {
       r#use: uses.transpose()?.unwrap_or_default(),
       r#type: module_type,
       parser: self.parser.map(|raw| raw.into()),
-      generator: self.generator.map(|raw| raw.into()),
+      generator: self.generator.map(|raw| raw.try_into()).transpose()?,
       resolve: self.resolve.map(|raw| raw.try_into()).transpose()?,
       side_effects: self.side_effects,
       issuer: self.issuer.map(|raw| raw.try_into()).transpose()?,
@@ -692,7 +782,7 @@ impl RawOptionsApply for RawModuleOptions {
         .generator
         .map(|x| {
           x.into_iter()
-            .map(|(k, v)| Ok((ModuleType::try_from(k.as_str())?, v.into())))
+            .map(|(k, v)| Ok((ModuleType::try_from(k.as_str())?, v.try_into()?)))
             .collect::<std::result::Result<GeneratorOptionsByModuleType, rspack_error::Error>>()
         })
         .transpose()?,
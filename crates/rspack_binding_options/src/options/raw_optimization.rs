@@ -1,10 +1,19 @@
+use std::{fmt::Debug, sync::Arc};
+
 use better_scoped_tls::scoped_tls;
+use napi::{Env, JsFunction};
 use napi_derive::napi;
-use rspack_core::{Optimization, PluginExt, SideEffectOption};
+use rspack_core::{
+  Optimization, PluginExt, RuntimeChunkName, RuntimeChunkOptions, SideEffectOption,
+};
 use rspack_error::internal_error;
 use rspack_ids::{
   DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, NamedChunkIdsPlugin,
-  NamedModuleIdsPlugin,
+  NamedModuleIdsPlugin, NaturalChunkIdsPlugin, NaturalModuleIdsPlugin,
+};
+use rspack_napi_shared::{
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  NapiResultExt, NAPI_ENV,
 };
 use rspack_plugin_split_chunks::SplitChunksPlugin;
 use serde::Deserialize;
@@ -13,6 +22,82 @@ use crate::{RawOptionsApply, RawSplitChunksOptions};
 
 scoped_tls!(pub(crate) static IS_ENABLE_NEW_SPLIT_CHUNKS: bool);
 
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawRuntimeChunkOptions {
+  #[napi(ts_type = r#""single" | "multiple" | "string" | "function""#)]
+  pub r#type: String,
+  pub string_payload: Option<String>,
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = r#"(ctx: RawRuntimeChunkNameFnCtx) => string | undefined"#)]
+  pub fn_payload: Option<JsFunction>,
+}
+
+impl Debug for RawRuntimeChunkOptions {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("RawRuntimeChunkOptions")
+      .field("r#type", &self.r#type)
+      .field("string_payload", &self.string_payload)
+      .field("fn_payload", &"Function")
+      .finish()
+  }
+}
+
+#[napi(object)]
+pub struct RawRuntimeChunkNameFnCtx {
+  pub name: String,
+}
+
+impl TryFrom<RawRuntimeChunkOptions> for RuntimeChunkOptions {
+  type Error = rspack_error::Error;
+
+  #[allow(clippy::unwrap_in_result)]
+  fn try_from(value: RawRuntimeChunkOptions) -> Result<Self, Self::Error> {
+    let name = match value.r#type.as_str() {
+      "single" => RuntimeChunkName::Single,
+      "multiple" => RuntimeChunkName::Multiple,
+      "string" => RuntimeChunkName::String(
+        value
+          .string_payload
+          .expect("should have a string_payload when RawRuntimeChunkOptions.type is \"string\""),
+      ),
+      "function" => {
+        let fn_payload = value
+          .fn_payload
+          .expect("should have a fn_payload when RawRuntimeChunkOptions.type is \"function\"");
+        let fn_payload: ThreadsafeFunction<RawRuntimeChunkNameFnCtx, Option<String>> = NAPI_ENV
+          .with(|env| -> anyhow::Result<_> {
+            let env = env.borrow().expect("Failed to get env with runtime chunk");
+            let fn_payload =
+              rspack_binding_macros::js_fn_into_threadsafe_fn!(fn_payload, &Env::from(env));
+            Ok(fn_payload)
+          })?;
+        let fn_payload = Arc::new(fn_payload);
+        RuntimeChunkName::Fn(Box::new(move |name| {
+          let fn_payload = fn_payload.clone();
+          Box::pin(async move {
+            fn_payload
+              .call(
+                RawRuntimeChunkNameFnCtx { name },
+                ThreadsafeFunctionCallMode::NonBlocking,
+              )
+              .into_rspack_result()?
+              .await
+              .map_err(|err| internal_error!("Failed to call runtime chunk name function: {err}"))?
+          })
+        }))
+      }
+      ty => {
+        return Err(internal_error!(
+          "'runtime_chunk' should be 'single', 'multiple', 'string' or 'function', got '{ty}'."
+        ))
+      }
+    };
+    Ok(Self { name })
+  }
+}
+
 #[derive(Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
@@ -24,6 +109,10 @@ pub struct RawOptimizationOptions {
   pub remove_empty_chunks: bool,
   pub side_effects: String,
   pub real_content_hash: bool,
+  pub runtime_chunk: Option<RawRuntimeChunkOptions>,
+  pub concatenate_modules: bool,
+  #[napi(ts_type = r#""false" | "deterministic" | "size""#)]
+  pub mangle_exports: String,
 }
 
 impl RawOptionsApply for RawOptimizationOptions {
@@ -46,20 +135,22 @@ impl RawOptionsApply for RawOptimizationOptions {
     }
     let chunk_ids_plugin = match self.chunk_ids.as_ref() {
       "named" => NamedChunkIdsPlugin::new(None, None).boxed(),
+      "natural" => NaturalChunkIdsPlugin::default().boxed(),
       "deterministic" => DeterministicChunkIdsPlugin::default().boxed(),
       _ => {
         return Err(internal_error!(
-          "'chunk_ids' should be 'named' or 'deterministic'."
+          "'chunk_ids' should be 'named', 'natural' or 'deterministic'."
         ))
       }
     };
     plugins.push(chunk_ids_plugin);
     let module_ids_plugin = match self.module_ids.as_ref() {
       "named" => NamedModuleIdsPlugin::default().boxed(),
+      "natural" => NaturalModuleIdsPlugin::default().boxed(),
       "deterministic" => DeterministicModuleIdsPlugin::default().boxed(),
       _ => {
         return Err(internal_error!(
-          "'module_ids' should be 'named' or 'deterministic'."
+          "'module_ids' should be 'named', 'natural' or 'deterministic'."
         ))
       }
     };
@@ -67,10 +158,27 @@ impl RawOptionsApply for RawOptimizationOptions {
     if self.real_content_hash {
       plugins.push(rspack_plugin_real_content_hash::RealContentHashPlugin.boxed());
     }
+    if self.concatenate_modules {
+      plugins.push(rspack_plugin_javascript::ModuleConcatenationPlugin.boxed());
+    }
+    match self.mangle_exports.as_ref() {
+      "false" => {}
+      "deterministic" => {
+        plugins.push(rspack_plugin_javascript::MangleExportsPlugin::new(true).boxed())
+      }
+      "size" => plugins.push(rspack_plugin_javascript::MangleExportsPlugin::new(false).boxed()),
+      _ => {
+        return Err(internal_error!(
+          "'mangle_exports' should be 'false', 'deterministic' or 'size'."
+        ))
+      }
+    }
     Ok(Optimization {
       remove_available_modules: self.remove_available_modules,
       remove_empty_chunks: self.remove_empty_chunks,
       side_effects: SideEffectOption::from(self.side_effects.as_str()),
+      runtime_chunk: self.runtime_chunk.map(TryInto::try_into).transpose()?,
+      concatenate_modules: self.concatenate_modules,
     })
   }
 }
@@ -1,9 +1,12 @@
 use napi_derive::napi;
 use rspack_core::{
-  to_identifier, BoxPlugin, CrossOriginLoading, LibraryAuxiliaryComment, LibraryName,
-  LibraryOptions, OutputOptions, PluginExt, TrustedTypes,
+  is_valid_member_expression, to_identifier, BoxPlugin, CleanKeep, CleanOptions,
+  CrossOriginLoading, Environment, LibraryAuxiliaryComment, LibraryName, LibraryOptions,
+  OutputOptions, PluginExt, TrustedTypes,
 };
 use rspack_error::internal_error;
+use rspack_plugin_devtool::IgnoreList;
+use rspack_regex::RspackRegex;
 use serde::Deserialize;
 
 use crate::RawOptionsApply;
@@ -111,12 +114,110 @@ impl From<RawCrossOriginLoading> for CrossOriginLoading {
   }
 }
 
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawEnvironment {
+  pub arrow_function: bool,
+  pub r#const: bool,
+}
+
+impl From<RawEnvironment> for Environment {
+  fn from(value: RawEnvironment) -> Self {
+    Self {
+      arrow_function: value.arrow_function,
+      r#const: value.r#const,
+    }
+  }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawCleanKeep {
+  #[napi(ts_type = r#""string" | "regexp""#)]
+  pub r#type: String,
+  pub string_matcher: Option<String>,
+  pub regexp_matcher: Option<String>,
+}
+
+impl TryFrom<RawCleanKeep> for CleanKeep {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawCleanKeep) -> rspack_error::Result<Self> {
+    match value.r#type.as_str() {
+      "string" => Ok(Self::String(value.string_matcher.ok_or_else(|| {
+        internal_error!("should have a string_matcher when RawCleanKeep.type is \"string\"")
+      })?)),
+      "regexp" => Ok(Self::Regexp(RspackRegex::new(
+        &value.regexp_matcher.ok_or_else(|| {
+          internal_error!("should have a regexp_matcher when RawCleanKeep.type is \"regexp\"")
+        })?,
+      )?)),
+      r#type => Err(internal_error!(
+        "Invalid output.clean.keep type {type}, expected \"string\" or \"regexp\""
+      )),
+    }
+  }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawDevtoolIgnoreList {
+  #[napi(ts_type = r#""string" | "regexp""#)]
+  pub r#type: String,
+  pub string_matcher: Option<String>,
+  pub regexp_matcher: Option<String>,
+}
+
+impl TryFrom<RawDevtoolIgnoreList> for IgnoreList {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawDevtoolIgnoreList) -> rspack_error::Result<Self> {
+    match value.r#type.as_str() {
+      "string" => Ok(Self::String(value.string_matcher.ok_or_else(|| {
+        internal_error!("should have a string_matcher when RawDevtoolIgnoreList.type is \"string\"")
+      })?)),
+      "regexp" => Ok(Self::Regexp(RspackRegex::new(
+        &value.regexp_matcher.ok_or_else(|| {
+          internal_error!(
+            "should have a regexp_matcher when RawDevtoolIgnoreList.type is \"regexp\""
+          )
+        })?,
+      )?)),
+      r#type => Err(internal_error!(
+        "Invalid output.devtoolIgnoreList type {type}, expected \"string\" or \"regexp\""
+      )),
+    }
+  }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawCleanOptions {
+  pub enable: bool,
+  pub keep: Option<RawCleanKeep>,
+}
+
+impl TryFrom<RawCleanOptions> for CleanOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawCleanOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
+      enable: value.enable,
+      keep: value.keep.map(TryInto::try_into).transpose()?,
+    })
+  }
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawOutputOptions {
   pub path: String,
-  pub clean: bool,
+  pub clean: RawCleanOptions,
   pub public_path: String,
   pub asset_module_filename: String,
   pub wasm_loading: String,
@@ -131,11 +232,13 @@ pub struct RawOutputOptions {
   pub hot_update_chunk_filename: String,
   pub unique_name: String,
   pub chunk_loading_global: String,
+  pub hot_update_global: String,
   pub library: Option<RawLibraryOptions>,
   pub strict_module_error_handling: bool,
   pub enabled_library_types: Option<Vec<String>>,
   pub global_object: String,
   pub import_function_name: String,
+  pub import_meta_name: String,
   pub iife: bool,
   pub module: bool,
   pub chunk_format: String,
@@ -143,6 +246,8 @@ pub struct RawOutputOptions {
   pub enabled_chunk_loading_types: Option<Vec<String>>,
   pub trusted_types: Option<RawTrustedTypes>,
   pub source_map_filename: String,
+  /// The sources listed in the `ignoreList` of the source maps, taken by the devtool plugin
+  pub devtool_ignore_list: Option<RawDevtoolIgnoreList>,
   pub hash_function: String,
   pub hash_digest: String,
   pub hash_digest_length: u32,
@@ -151,11 +256,22 @@ pub struct RawOutputOptions {
   pub worker_chunk_loading: String,
   pub worker_wasm_loading: String,
   pub worker_public_path: String,
+  pub environment: RawEnvironment,
 }
 
 impl RawOptionsApply for RawOutputOptions {
   type Options = OutputOptions;
   fn apply(self, plugins: &mut Vec<BoxPlugin>) -> Result<OutputOptions, rspack_error::Error> {
+    for (name, value) in [
+      ("importFunctionName", &self.import_function_name),
+      ("importMetaName", &self.import_meta_name),
+    ] {
+      if !is_valid_member_expression(value) {
+        return Err(internal_error!(
+          "Invalid output.{name} '{value}', expected an identifier or a member expression"
+        ));
+      }
+    }
     self.apply_chunk_format_plugin(plugins)?;
     if let Some(chunk_loading_types) = self.enabled_chunk_loading_types.as_ref() {
       for chunk_loading_type in chunk_loading_types {
@@ -174,7 +290,7 @@ impl RawOptionsApply for RawOutputOptions {
 
     Ok(OutputOptions {
       path: self.path.into(),
-      clean: self.clean,
+      clean: self.clean.try_into()?,
       public_path: self.public_path.into(),
       asset_module_filename: self.asset_module_filename.into(),
       wasm_loading: self.wasm_loading.as_str().into(),
@@ -182,6 +298,7 @@ impl RawOptionsApply for RawOutputOptions {
       unique_name: self.unique_name,
       chunk_loading: self.chunk_loading.as_str().into(),
       chunk_loading_global: to_identifier(&self.chunk_loading_global),
+      hot_update_global: to_identifier(&self.hot_update_global),
       filename: self.filename.into(),
       chunk_filename: self.chunk_filename.into(),
       cross_origin_loading: self.cross_origin_loading.into(),
@@ -192,20 +309,31 @@ impl RawOptionsApply for RawOutputOptions {
       library: self.library.map(Into::into),
       strict_module_error_handling: self.strict_module_error_handling,
       enabled_library_types: self.enabled_library_types,
+      enabled_chunk_loading_types: self.enabled_chunk_loading_types,
       global_object: self.global_object,
       import_function_name: self.import_function_name,
+      import_meta_name: self.import_meta_name,
       iife: self.iife,
       module: self.module,
       trusted_types: self.trusted_types.map(Into::into),
       source_map_filename: self.source_map_filename.into(),
-      hash_function: self.hash_function.as_str().into(),
-      hash_digest: self.hash_digest.as_str().into(),
+      hash_function: self
+        .hash_function
+        .as_str()
+        .try_into()
+        .map_err(|error: String| internal_error!(error))?,
+      hash_digest: self
+        .hash_digest
+        .as_str()
+        .try_into()
+        .map_err(|error: String| internal_error!(error))?,
       hash_digest_length: self.hash_digest_length as usize,
       hash_salt: self.hash_salt.into(),
       async_chunks: self.async_chunks,
       worker_chunk_loading: self.worker_chunk_loading.as_str().into(),
       worker_wasm_loading: self.worker_wasm_loading.as_str().into(),
       worker_public_path: self.worker_public_path,
+      environment: self.environment.into(),
     })
   }
 }
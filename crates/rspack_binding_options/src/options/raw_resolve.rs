@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 
 use napi_derive::napi;
-use rspack_core::{Alias, AliasMap, ByDependency, DependencyCategory, Resolve};
+use rspack_core::{
+  Alias, AliasMap, ByDependency, DependencyCategory, Resolve, TsconfigOptions, TsconfigReferences,
+};
 use serde::Deserialize;
 
 pub type AliasValue = serde_json::Value;
@@ -25,6 +27,7 @@ pub struct RawResolveOptions {
   pub fallback: Option<RawAliasOption>,
   pub symlinks: Option<bool>,
   pub ts_config_path: Option<String>,
+  pub tsconfig: Option<RawResolveTsconfigOptions>,
   pub modules: Option<Vec<String>>,
   pub by_dependency: Option<HashMap<String, RawResolveOptions>>,
   pub fully_specified: Option<bool>,
@@ -34,6 +37,46 @@ pub struct RawResolveOptions {
   pub extension_alias: Option<HashMap<String, Vec<String>>>,
 }
 
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawResolveTsconfigOptions {
+  pub config_file: String,
+  #[napi(ts_type = "\"auto\" | \"manual\" | \"disabled\"")]
+  pub references_type: String,
+  /// Only used when `referencesType` is `manual`
+  pub references: Option<Vec<String>>,
+}
+
+impl TryFrom<RawResolveTsconfigOptions> for TsconfigOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawResolveTsconfigOptions) -> Result<Self, Self::Error> {
+    let references = match value.references_type.as_str() {
+      "auto" => TsconfigReferences::Auto,
+      "manual" => TsconfigReferences::Paths(
+        value
+          .references
+          .unwrap_or_default()
+          .into_iter()
+          .map(std::path::PathBuf::from)
+          .collect(),
+      ),
+      "disabled" => TsconfigReferences::Disabled,
+      _ => {
+        return Err(rspack_error::internal_error!(
+          "Failed to resolve the tsconfig references type {}. Expected type is `auto`, `manual` or `disabled`.",
+          value.references_type
+        ))
+      }
+    };
+    Ok(Self {
+      config_file: value.config_file.into(),
+      references,
+    })
+  }
+}
+
 fn normalize_alias(alias: Option<RawAliasOption>) -> anyhow::Result<Option<Alias>> {
   alias
     .map(|alias| {
@@ -82,7 +125,13 @@ impl TryFrom<RawResolveOptions> for Resolve {
     let alias = normalize_alias(value.alias)?;
     let fallback = normalize_alias(value.fallback)?;
     let modules = value.modules;
-    let tsconfig = value.ts_config_path.map(std::path::PathBuf::from);
+    let tsconfig = match value.tsconfig {
+      Some(tsconfig) => Some(tsconfig.try_into()?),
+      None => value.ts_config_path.map(|config_file| TsconfigOptions {
+        config_file: config_file.into(),
+        references: TsconfigReferences::Disabled,
+      }),
+    };
     let by_dependency = value
       .by_dependency
       .map(|i| {
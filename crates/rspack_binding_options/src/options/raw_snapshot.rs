@@ -1,5 +1,6 @@
 use napi_derive::napi;
-use rspack_core::{SnapshotOptions, SnapshotStrategy};
+use rspack_core::{SnapshotOptions, SnapshotPath, SnapshotStrategy};
+use rspack_error::internal_error;
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug, Default)]
@@ -19,21 +20,72 @@ impl From<RawSnapshotStrategy> for SnapshotStrategy {
   }
 }
 
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawSnapshotPath {
+  #[napi(ts_type = r#""string" | "regexp""#)]
+  pub r#type: String,
+  pub string_matcher: Option<String>,
+  pub regexp_matcher: Option<String>,
+}
+
+impl TryFrom<RawSnapshotPath> for SnapshotPath {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawSnapshotPath) -> rspack_error::Result<Self> {
+    match value.r#type.as_str() {
+      "string" => Ok(Self::String(value.string_matcher.ok_or_else(|| {
+        internal_error!("should have a string_matcher when RawSnapshotPath.type is \"string\"")
+      })?)),
+      "regexp" => {
+        let regexp_matcher = value.regexp_matcher.ok_or_else(|| {
+          internal_error!("should have a regexp_matcher when RawSnapshotPath.type is \"regexp\"")
+        })?;
+        Ok(Self::Regexp(rspack_regex::RspackRegex::new(
+          &regexp_matcher,
+        )?))
+      }
+      _ => Err(internal_error!(
+        "Failed to resolve the snapshot path type {}. Expected type is `string` or `regexp`.",
+        value.r#type
+      )),
+    }
+  }
+}
+
 #[derive(Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawSnapshotOptions {
   pub resolve: RawSnapshotStrategy,
   pub module: RawSnapshotStrategy,
+  pub immutable_paths: Vec<RawSnapshotPath>,
+  pub managed_paths: Vec<RawSnapshotPath>,
 }
 
-impl From<RawSnapshotOptions> for SnapshotOptions {
-  fn from(value: RawSnapshotOptions) -> Self {
-    let RawSnapshotOptions { resolve, module } = value;
+impl TryFrom<RawSnapshotOptions> for SnapshotOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawSnapshotOptions) -> rspack_error::Result<Self> {
+    let RawSnapshotOptions {
+      resolve,
+      module,
+      immutable_paths,
+      managed_paths,
+    } = value;
 
-    SnapshotOptions {
+    Ok(SnapshotOptions {
       resolve: resolve.into(),
       module: module.into(),
-    }
+      immutable_paths: immutable_paths
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<rspack_error::Result<_>>()?,
+      managed_paths: managed_paths
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<rspack_error::Result<_>>()?,
+    })
   }
 }
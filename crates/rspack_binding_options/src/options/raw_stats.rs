@@ -7,12 +7,18 @@ use serde::Deserialize;
 #[napi(object)]
 pub struct RawStatsOptions {
   pub colors: bool,
+  pub similar_diagnostics_threshold: Option<u32>,
+  pub module_trace_depth: u32,
 }
 
 impl From<RawStatsOptions> for StatsOptions {
   fn from(value: RawStatsOptions) -> Self {
     Self {
       colors: value.colors,
+      similar_diagnostics_threshold: value
+        .similar_diagnostics_threshold
+        .map(|threshold| threshold as usize),
+      module_trace_depth: value.module_trace_depth as usize,
     }
   }
 }
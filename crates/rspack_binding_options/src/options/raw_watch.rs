@@ -0,0 +1,32 @@
+use std::time::Duration;
+
+use napi_derive::napi;
+use rspack_core::WatchOptions;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawWatchOptions {
+  pub aggregate_timeout: Option<u32>,
+  /// The interval of polling in milliseconds, the native watcher is used if it's `undefined`
+  pub poll: Option<u32>,
+  pub ignored: Option<Vec<String>>,
+}
+
+impl From<RawWatchOptions> for WatchOptions {
+  fn from(value: RawWatchOptions) -> Self {
+    let default = WatchOptions::default();
+    Self {
+      aggregate_timeout: value
+        .aggregate_timeout
+        .map_or(default.aggregate_timeout, |timeout| {
+          Duration::from_millis(timeout.into())
+        }),
+      poll: value
+        .poll
+        .map(|interval| Duration::from_millis(interval.into())),
+      ignored: value.ignored.unwrap_or_default(),
+    }
+  }
+}
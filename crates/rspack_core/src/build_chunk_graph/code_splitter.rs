@@ -1,14 +1,14 @@
 use std::sync::Arc;
 
 use anyhow::anyhow;
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result};
 use rspack_identifier::{IdentifierMap, IdentifierSet};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use super::remove_parent_modules::RemoveParentModulesContext;
 use crate::{
   ChunkGroup, ChunkGroupInfo, ChunkGroupKind, ChunkGroupOptions, ChunkGroupUkey, ChunkLoading,
-  ChunkUkey, Compilation, Logger, ModuleIdentifier, RuntimeSpec,
+  ChunkUkey, Compilation, Entry, Logger, ModuleIdentifier, RuntimeSpec,
 };
 
 pub(super) struct CodeSplitter<'me> {
@@ -45,13 +45,56 @@ impl<'me> CodeSplitter<'me> {
     let mut input_entrypoints_and_modules: HashMap<ChunkGroupUkey, Vec<ModuleIdentifier>> =
       HashMap::default();
 
+    // Entries with an invalid `dependOn` are reported, and then built as if they didn't depend on others.
+    let mut invalid_depend_on = HashSet::default();
+    let mut diagnostics = vec![];
+    for (name, entry_data) in &compilation.entries {
+      let Some(depend_on) = &entry_data.options.depend_on else {
+        continue;
+      };
+      let error = if entry_data.options.runtime.is_some() {
+        Some(format!(
+          "Entrypoint '{name}' has 'dependOn' and 'runtime' specified. This is not valid.\nEntrypoints that depend on other entrypoints do not have their own runtime.\nThey will use the runtime(s) from referenced entrypoints instead.\nRemove the 'runtime' option from the entrypoint."
+        ))
+      } else if let Some(dep) = depend_on
+        .iter()
+        .find(|dep| !compilation.entries.contains_key(*dep))
+      {
+        Some(format!(
+          "Entry {name} depends on {dep}, but this entry was not found"
+        ))
+      } else {
+        find_depend_on_cycle(name, &compilation.entries).map(|cycle| {
+          format!(
+            "Entrypoints have a circular dependOn: {}",
+            cycle.join(" -> ")
+          )
+        })
+      };
+      if let Some(error) = error {
+        invalid_depend_on.insert(name.clone());
+        diagnostics.push(Diagnostic::error(
+          "Configuration error".to_string(),
+          error,
+          0,
+          0,
+        ));
+      }
+    }
+
     for (name, entry_data) in &compilation.entries {
       let options = &entry_data.options;
       let dependencies = &entry_data.dependencies;
+      let depends_on_others = options.depend_on.is_some() && !invalid_depend_on.contains(name);
       let module_identifiers = dependencies
         .iter()
         .filter_map(|dep| module_graph.module_identifier_by_dependency_id(dep))
         .collect::<Vec<_>>();
+      let include_module_identifiers = entry_data
+        .include_dependencies
+        .iter()
+        .filter_map(|dep| module_graph.module_identifier_by_dependency_id(dep))
+        .collect::<Vec<_>>();
 
       let chunk = Compilation::add_named_chunk(
         name.to_string(),
@@ -62,17 +105,17 @@ impl<'me> CodeSplitter<'me> {
         chunk.filename_template = Some(filename.clone());
       }
       chunk.chunk_reasons.push(format!("Entrypoint({name})",));
-      self
-        .remove_parent_modules_context
-        .add_root_chunk(chunk.ukey);
+      if !depends_on_others {
+        self
+          .remove_parent_modules_context
+          .add_root_chunk(chunk.ukey);
+      }
 
       compilation.chunk_graph.add_chunk(chunk.ukey);
 
       let mut entrypoint = ChunkGroup::new(
         ChunkGroupKind::new_entrypoint(true),
-        HashSet::from_iter([Arc::from(
-          options.runtime.clone().unwrap_or_else(|| name.to_string()),
-        )]),
+        get_entry_runtime(name, &compilation.entries, &invalid_depend_on),
         ChunkGroupOptions::default()
           .name(name)
           .entry_options(options.clone()),
@@ -89,7 +132,7 @@ impl<'me> CodeSplitter<'me> {
             .unwrap_or(compilation.options.output.async_chunks),
         },
       );
-      if options.runtime.is_none() {
+      if options.runtime.is_none() && !depends_on_others {
         entrypoint.set_runtime_chunk(chunk.ukey);
       }
       entrypoint.set_entry_point_chunk(chunk.ukey);
@@ -127,6 +170,16 @@ impl<'me> CodeSplitter<'me> {
           entrypoint.ukey,
         );
       }
+
+      // Included modules are added to the entry chunk, but not executed on startup
+      for module_identifier in include_module_identifiers {
+        compilation.chunk_graph.add_module(*module_identifier);
+
+        input_entrypoints_and_modules
+          .entry(entrypoint.ukey)
+          .or_default()
+          .push(*module_identifier);
+      }
     }
 
     for (name, entry_data) in &compilation.entries {
@@ -164,6 +217,54 @@ impl<'me> CodeSplitter<'me> {
         entry_point.set_runtime_chunk(chunk.ukey);
       }
     }
+
+    // The entries which are depended on are the parents of the dependent entry, so modules of them
+    // are removed from the dependent entry, and the runtime chunk of them is used by the dependent entry.
+    for (name, entry_data) in &compilation.entries {
+      let Some(depend_on) = &entry_data.options.depend_on else {
+        continue;
+      };
+      if invalid_depend_on.contains(name) {
+        continue;
+      }
+      let ukey = *compilation
+        .entrypoints
+        .get(name)
+        .ok_or_else(|| anyhow!("no entrypoints found"))?;
+      let chunk = compilation
+        .chunk_group_by_ukey
+        .get(&ukey)
+        .ok_or_else(|| anyhow!("no chunk group found"))?
+        .get_entry_point_chunk();
+      for dep in depend_on {
+        let dep_ukey = *compilation
+          .entrypoints
+          .get(dep)
+          .ok_or_else(|| anyhow!("no entrypoints found"))?;
+        let dep_entrypoint = compilation
+          .chunk_group_by_ukey
+          .get_mut(&dep_ukey)
+          .ok_or_else(|| anyhow!("no chunk group found"))?;
+        dep_entrypoint.children.insert(ukey);
+        self
+          .remove_parent_modules_context
+          .add_chunk_relation(dep_entrypoint.get_entry_point_chunk(), chunk);
+        compilation
+          .chunk_group_by_ukey
+          .get_mut(&ukey)
+          .ok_or_else(|| anyhow!("no chunk group found"))?
+          .parents
+          .insert(dep_ukey);
+      }
+      let runtime_chunk = get_depended_runtime_chunk(name, compilation, &invalid_depend_on)
+        .ok_or_else(|| anyhow!("no runtime chunk found for entry {name}"))?;
+      compilation
+        .chunk_group_by_ukey
+        .get_mut(&ukey)
+        .ok_or_else(|| anyhow!("no chunk group found"))?
+        .set_runtime_chunk(runtime_chunk);
+    }
+    compilation.push_batch_diagnostic(diagnostics);
     Ok(input_entrypoints_and_modules)
   }
 
@@ -422,6 +523,9 @@ impl<'me> CodeSplitter<'me> {
             .expect("chunk group not found");
           chunk_group.parents.insert(item.chunk_group);
           chunk_group.runtime.extend(runtime.clone());
+          if let Some(group_options) = group_options {
+            chunk_group.add_options(group_options);
+          }
         }
         continue;
       } else {
@@ -482,7 +586,11 @@ impl<'me> CodeSplitter<'me> {
         ChunkGroup::new(
           ChunkGroupKind::Normal,
           item_chunk_group.runtime.clone(),
-          ChunkGroupOptions::default().name_optional(group_options.and_then(|x| x.name.as_deref())),
+          ChunkGroupOptions::default()
+            .name_optional(group_options.and_then(|x| x.name.as_deref()))
+            .preload_order_optional(group_options.and_then(|x| x.preload_order))
+            .prefetch_order_optional(group_options.and_then(|x| x.prefetch_order))
+            .fetch_priority_optional(group_options.and_then(|x| x.fetch_priority)),
           ChunkGroupInfo {
             chunk_loading: item_chunk_group.info.chunk_loading,
             async_chunks: item_chunk_group.info.async_chunks,
@@ -545,3 +653,86 @@ enum QueueAction {
 }
 
 // struct chunkGroupInfoMap {}
+
+/// Returns the entries in the cycle if the `dependOn` of the entry `name` leads back to itself.
+fn find_depend_on_cycle(name: &str, entries: &Entry) -> Option<Vec<String>> {
+  fn visit<'a>(
+    current: &'a str,
+    target: &str,
+    entries: &'a Entry,
+    path: &mut Vec<&'a str>,
+    visited: &mut HashSet<&'a str>,
+  ) -> bool {
+    let Some(depend_on) = entries
+      .get(current)
+      .and_then(|entry| entry.options.depend_on.as_ref())
+    else {
+      return false;
+    };
+    for dep in depend_on {
+      if dep == target {
+        path.push(dep);
+        return true;
+      }
+      if !visited.insert(dep) {
+        continue;
+      }
+      path.push(dep);
+      if visit(dep, target, entries, path, visited) {
+        return true;
+      }
+      path.pop();
+    }
+    false
+  }
+
+  let mut path = vec![name];
+  visit(name, name, entries, &mut path, &mut HashSet::default())
+    .then(|| path.into_iter().map(ToString::to_string).collect())
+}
+
+/// Entries depending on other entries share the runtime of them.
+fn get_entry_runtime(
+  name: &str,
+  entries: &Entry,
+  invalid_depend_on: &HashSet<String>,
+) -> RuntimeSpec {
+  let options = entries.get(name).map(|entry| &entry.options);
+  if let Some(depend_on) = options.and_then(|options| options.depend_on.as_ref())
+    && !invalid_depend_on.contains(name)
+  {
+    return depend_on
+      .iter()
+      .flat_map(|dep| get_entry_runtime(dep, entries, invalid_depend_on))
+      .collect();
+  }
+  HashSet::from_iter([Arc::from(
+    options
+      .and_then(|options| options.runtime.clone())
+      .unwrap_or_else(|| name.to_string()),
+  )])
+}
+
+fn get_depended_runtime_chunk(
+  name: &str,
+  compilation: &Compilation,
+  invalid_depend_on: &HashSet<String>,
+) -> Option<ChunkUkey> {
+  let entrypoint = compilation
+    .chunk_group_by_ukey
+    .get(compilation.entrypoints.get(name)?)?;
+  if let Some(runtime_chunk) = entrypoint.runtime_chunk {
+    return Some(runtime_chunk);
+  }
+  if invalid_depend_on.contains(name) {
+    return None;
+  }
+  compilation
+    .entries
+    .get(name)?
+    .options
+    .depend_on
+    .as_ref()?
+    .iter()
+    .find_map(|dep| get_depended_runtime_chunk(dep, compilation, invalid_depend_on))
+}
@@ -16,13 +16,13 @@ pub use local::*;
 use occasion::{
   BuildModuleOccasion, CodeGenerateOccasion, CreateChunkAssetsOccasion, ResolveModuleOccasion,
 };
-use snapshot::SnapshotManager;
+pub use snapshot::{Snapshot, SnapshotManager};
 use storage::new_storage;
 
 #[derive(Debug)]
 pub struct Cache {
   is_idle: AtomicBool,
-  snapshot_manager: Arc<SnapshotManager>,
+  pub snapshot_manager: Arc<SnapshotManager>,
   pub resolve_module_occasion: ResolveModuleOccasion,
   pub build_module_occasion: BuildModuleOccasion,
   pub code_generate_occasion: CodeGenerateOccasion,
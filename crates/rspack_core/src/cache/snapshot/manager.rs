@@ -1,12 +1,13 @@
 use std::{
   hash::BuildHasherDefault,
-  path::{Path, PathBuf},
+  io::ErrorKind,
+  path::{Path, PathBuf, MAIN_SEPARATOR},
   time::SystemTime,
 };
 
 use dashmap::{DashMap, DashSet};
 use rspack_error::Result;
-use rustc_hash::{FxHashMap as HashMap, FxHasher};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
 
 use super::Snapshot;
 use crate::{calc_hash, SnapshotOptions, SnapshotStrategy};
@@ -20,9 +21,11 @@ pub struct SnapshotManager {
   /// global snapshot options
   options: SnapshotOptions,
   /// cache file update time
-  update_time_cache: DashMap<PathBuf, SystemTime, BuildHasherDefault<FxHasher>>,
+  update_time_cache: DashMap<PathBuf, Option<SystemTime>, BuildHasherDefault<FxHasher>>,
   /// cache file hash
   hash_cache: DashMap<PathBuf, u64, BuildHasherDefault<FxHasher>>,
+  /// cache package version of managed paths
+  package_version_cache: DashMap<PathBuf, Option<String>, BuildHasherDefault<FxHasher>>,
   /// has modified file
   modified_files: DashSet<PathBuf>,
 }
@@ -33,6 +36,7 @@ impl SnapshotManager {
       options,
       update_time_cache: Default::default(),
       hash_cache: Default::default(),
+      package_version_cache: Default::default(),
       modified_files: Default::default(),
     }
   }
@@ -41,45 +45,45 @@ impl SnapshotManager {
   where
     F: FnOnce(&SnapshotOptions) -> &SnapshotStrategy,
   {
-    // TODO file_paths deduplication && calc immutable path
     let strategy = f(&self.options);
     let mut file_update_times = HashMap::default();
-    file_update_times.reserve(paths.len());
     let mut file_hashes = HashMap::default();
-    file_hashes.reserve(paths.len());
-    if strategy.timestamp {
-      for &path in paths {
+    let mut package_versions = HashMap::default();
+    let mut missing_files = HashSet::default();
+    for &path in paths {
+      if self.is_immutable(path) {
+        continue;
+      }
+      if let Some(package) = self.managed_package(path) {
+        if package_versions.contains_key(&package) {
+          continue;
+        }
+        // Fallback to the strategy if the version of the package is unknown
+        if let Some(version) = self.package_version(&package).await {
+          package_versions.insert(package, version);
+          continue;
+        }
+      }
+      if !strategy.timestamp && !strategy.hash {
+        continue;
+      }
+      if self.update_time(path).await?.is_none() {
+        missing_files.insert(path.to_owned());
+        continue;
+      }
+      if strategy.timestamp {
         file_update_times.insert(path.to_owned(), SystemTime::now());
       }
-    }
-    if strategy.hash {
-      let hash_cache = &self.hash_cache;
-      for &path in paths {
-        let hash = match hash_cache.get(path) {
-          Some(hash) => *hash,
-          None => {
-            let res = if path.is_dir() {
-              let dir = &mut tokio::fs::read_dir(path).await?;
-              let mut sub_files = vec![];
-              while let Some(entry) = dir.next_entry().await? {
-                let dir_u8 = entry.path().as_os_str().to_string_lossy().to_string();
-                sub_files.push(dir_u8);
-              }
-              calc_hash(&sub_files)
-            } else {
-              calc_hash(&tokio::fs::read(path).await?)
-            };
-            hash_cache.insert(path.to_owned(), res);
-            res
-          }
-        };
-        file_hashes.insert(path.to_owned(), hash);
+      if strategy.hash {
+        file_hashes.insert(path.to_owned(), self.hash(path).await?);
       }
     }
 
     Ok(Snapshot {
       file_update_times,
       file_hashes,
+      package_versions,
+      missing_files,
     })
   }
 
@@ -87,50 +91,45 @@ impl SnapshotManager {
     let Snapshot {
       file_update_times,
       file_hashes,
-      ..
+      package_versions,
+      missing_files,
     } = snapshot;
-    if !file_update_times.is_empty() {
-      // check update time
-      let update_time_cache = &self.update_time_cache;
-      for (path, snapshot_time) in file_update_times {
-        if self.modified_files.contains(path) {
-          return Ok(false);
-        }
 
-        let update_time = match update_time_cache.get(path) {
-          Some(t) => *t,
-          None => {
-            let t = tokio::fs::metadata(path).await?.modified()?;
-            update_time_cache.insert(path.clone(), t);
-            t
-          }
-        };
+    for path in missing_files {
+      if self.update_time(path).await?.is_some() {
+        return Ok(false);
+      }
+    }
 
-        if snapshot_time < &update_time {
-          return Ok(false);
-        }
+    for (package, version) in package_versions {
+      if self.package_version(package).await.as_ref() != Some(version) {
+        return Ok(false);
       }
     }
 
-    if !file_hashes.is_empty() {
-      // check file hash
-      let hash_cache = &self.hash_cache;
-      for (path, snapshot_hash) in file_hashes {
-        if self.modified_files.contains(path) {
+    // check update time
+    let mut touched_files = HashSet::default();
+    for (path, snapshot_time) in file_update_times {
+      let Some(update_time) = self.update_time(path).await? else {
+        // The file is removed
+        return Ok(false);
+      };
+      if self.modified_files.contains(path) || snapshot_time < &update_time {
+        // A touched file is still valid if its content isn't changed with the hash strategy
+        if !file_hashes.contains_key(path) {
           return Ok(false);
         }
+        touched_files.insert(path);
+      }
+    }
 
-        let current_hash = match hash_cache.get(path) {
-          Some(h) => *h,
-          None => {
-            let res = calc_hash(&tokio::fs::read(path).await?);
-            hash_cache.insert(path.clone(), res);
-            res
-          }
-        };
-        if snapshot_hash != &current_hash {
-          return Ok(false);
-        }
+    // check file hash, only the touched files are checked with the timestamp strategy as well
+    for (path, snapshot_hash) in file_hashes {
+      if !file_update_times.is_empty() && !touched_files.contains(&path) {
+        continue;
+      }
+      if snapshot_hash != &self.hash(path).await? {
+        return Ok(false);
       }
     }
 
@@ -140,12 +139,233 @@ impl SnapshotManager {
   pub fn clear(&self) {
     self.update_time_cache.clear();
     self.hash_cache.clear();
+    self.package_version_cache.clear();
     self.modified_files.clear();
   }
 
   pub fn set_modified_files(&self, files: Vec<PathBuf>) {
     for item in files {
+      // The content of the modified files may be changed since they are hashed
+      self.hash_cache.remove(&item);
+      self.update_time_cache.remove(&item);
       self.modified_files.insert(item);
     }
   }
+
+  fn is_immutable(&self, path: &Path) -> bool {
+    let path = path.to_string_lossy();
+    self
+      .options
+      .immutable_paths
+      .iter()
+      .any(|immutable_path| immutable_path.is_match(&path))
+  }
+
+  /// The package directory of `path` in the managed paths, such as `/node_modules/@scope/name`.
+  fn managed_package(&self, path: &Path) -> Option<PathBuf> {
+    if self.options.managed_paths.is_empty() {
+      return None;
+    }
+    // From the root to `path`
+    let ancestors = path.ancestors().collect::<Vec<_>>();
+    let mut ancestors = ancestors.into_iter().rev();
+    while let Some(ancestor) = ancestors.next() {
+      let mut dir = ancestor.to_string_lossy().to_string();
+      if !dir.ends_with(MAIN_SEPARATOR) {
+        dir.push(MAIN_SEPARATOR);
+      }
+      if self
+        .options
+        .managed_paths
+        .iter()
+        .any(|managed_path| managed_path.is_match(&dir))
+      {
+        let package = ancestors.next()?;
+        let is_scoped = package
+          .file_name()
+          .map_or(false, |name| name.to_string_lossy().starts_with('@'));
+        let package = if is_scoped {
+          ancestors.next()?
+        } else {
+          package
+        };
+        return Some(package.to_path_buf());
+      }
+    }
+    None
+  }
+
+  async fn package_version(&self, package: &Path) -> Option<String> {
+    if let Some(version) = self.package_version_cache.get(package) {
+      return version.clone();
+    }
+    let version = match tokio::fs::read(package.join("package.json")).await {
+      Ok(content) => serde_json::from_slice::<serde_json::Value>(&content)
+        .ok()
+        .and_then(|json| json.get("version")?.as_str().map(ToOwned::to_owned)),
+      Err(_) => None,
+    };
+    self
+      .package_version_cache
+      .insert(package.to_owned(), version.clone());
+    version
+  }
+
+  /// Returns `None` if the file doesn't exist.
+  async fn update_time(&self, path: &Path) -> Result<Option<SystemTime>> {
+    if let Some(update_time) = self.update_time_cache.get(path) {
+      return Ok(*update_time);
+    }
+    let update_time = match tokio::fs::metadata(path).await {
+      Ok(metadata) => Some(metadata.modified()?),
+      Err(err) if err.kind() == ErrorKind::NotFound => None,
+      Err(err) => return Err(err.into()),
+    };
+    self.update_time_cache.insert(path.to_owned(), update_time);
+    Ok(update_time)
+  }
+
+  async fn hash(&self, path: &Path) -> Result<u64> {
+    if let Some(hash) = self.hash_cache.get(path) {
+      return Ok(*hash);
+    }
+    let hash = if path.is_dir() {
+      let dir = &mut tokio::fs::read_dir(path).await?;
+      let mut sub_files = vec![];
+      while let Some(entry) = dir.next_entry().await? {
+        let dir_u8 = entry.path().as_os_str().to_string_lossy().to_string();
+        sub_files.push(dir_u8);
+      }
+      // The order of the entries read from the directory is not guaranteed
+      sub_files.sort();
+      calc_hash(&sub_files)
+    } else {
+      calc_hash(&tokio::fs::read(path).await?)
+    };
+    self.hash_cache.insert(path.to_owned(), hash);
+    Ok(hash)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::SnapshotPath;
+
+  fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rspack_snapshot_{name}"));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("should create the temp dir");
+    dir
+  }
+
+  fn strategy(hash: bool, timestamp: bool) -> SnapshotOptions {
+    SnapshotOptions {
+      module: SnapshotStrategy { hash, timestamp },
+      ..Default::default()
+    }
+  }
+
+  /// Touch `file` in the next compilation, whose content isn't changed.
+  fn touch(manager: &SnapshotManager, file: &Path) {
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    std::fs::write(file, "a").expect("should write the file");
+    manager.clear();
+    manager.set_modified_files(vec![file.to_owned()]);
+  }
+
+  #[tokio::test]
+  async fn touched_file_is_valid_with_hash() {
+    let dir = temp_dir("hash");
+    let file = dir.join("a.js");
+    std::fs::write(&file, "a").expect("should write the file");
+
+    for (options, expected) in [
+      (strategy(true, false), true),
+      (strategy(true, true), true),
+      (strategy(false, true), false),
+    ] {
+      let manager = SnapshotManager::new(options);
+      let snapshot = manager
+        .create_snapshot(&[file.as_path()], |options| &options.module)
+        .await
+        .expect("should create the snapshot");
+      touch(&manager, &file);
+      assert_eq!(
+        manager
+          .check_snapshot_valid(&snapshot)
+          .await
+          .expect("should check the snapshot"),
+        expected
+      );
+    }
+  }
+
+  #[tokio::test]
+  async fn directory_hash_is_independent_of_entry_order() {
+    let dir = temp_dir("directory_hash");
+    for name in ["c.js", "a.js", "b.js"] {
+      std::fs::write(dir.join(name), "a").expect("should write the file");
+    }
+
+    let manager = SnapshotManager::new(strategy(true, false));
+    let hash = manager.hash(&dir).await.expect("should hash the directory");
+    let entries = ["a.js", "b.js", "c.js"]
+      .into_iter()
+      .map(|name| dir.join(name).to_string_lossy().to_string())
+      .collect::<Vec<_>>();
+    assert_eq!(hash, calc_hash(&entries));
+  }
+
+  #[tokio::test]
+  async fn managed_and_immutable_paths() {
+    let dir = temp_dir("managed");
+    let node_modules = dir.join("node_modules");
+    let package = node_modules.join("@scope").join("pkg");
+    std::fs::create_dir_all(&package).expect("should create the package");
+    std::fs::write(package.join("package.json"), r#"{"version":"1.0.0"}"#)
+      .expect("should write the package.json");
+    let file = package.join("index.js");
+    std::fs::write(&file, "a").expect("should write the file");
+
+    let manager = SnapshotManager::new(SnapshotOptions {
+      managed_paths: vec![SnapshotPath::String(
+        node_modules.to_string_lossy().to_string(),
+      )],
+      ..strategy(false, true)
+    });
+    let snapshot = manager
+      .create_snapshot(&[file.as_path()], |options| &options.module)
+      .await
+      .expect("should create the snapshot");
+    assert!(snapshot.file_update_times.is_empty());
+    assert_eq!(
+      snapshot.package_versions.get(&package),
+      Some(&"1.0.0".to_string())
+    );
+    touch(&manager, &file);
+    assert!(manager
+      .check_snapshot_valid(&snapshot)
+      .await
+      .expect("should check the snapshot"));
+    std::fs::write(package.join("package.json"), r#"{"version":"1.0.1"}"#)
+      .expect("should write the package.json");
+    manager.clear();
+    assert!(!manager
+      .check_snapshot_valid(&snapshot)
+      .await
+      .expect("should check the snapshot"));
+
+    let manager = SnapshotManager::new(SnapshotOptions {
+      immutable_paths: vec![SnapshotPath::String(
+        node_modules.to_string_lossy().to_string(),
+      )],
+      ..strategy(true, true)
+    });
+    let snapshot = manager
+      .create_snapshot(&[file.as_path()], |options| &options.module)
+      .await
+      .expect("should create the snapshot");
+    assert!(snapshot.file_update_times.is_empty() && snapshot.file_hashes.is_empty());
+  }
 }
@@ -1,6 +1,6 @@
 use std::{path::PathBuf, time::SystemTime};
 
-use rustc_hash::FxHashMap as HashMap;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 mod manager;
 pub use manager::SnapshotManager;
@@ -10,4 +10,8 @@ pub use manager::SnapshotManager;
 pub struct Snapshot {
   pub file_update_times: HashMap<PathBuf, SystemTime>,
   pub file_hashes: HashMap<PathBuf, u64>,
+  /// The versions of the packages in the managed paths
+  pub package_versions: HashMap<PathBuf, String>,
+  /// The files don't exist when the snapshot is created
+  pub missing_files: HashSet<PathBuf>,
 }
@@ -1,12 +1,12 @@
-use std::{fmt::Debug, hash::Hash, sync::Arc};
+use std::{collections::BTreeMap, fmt::Debug, hash::Hash, sync::Arc};
 
 use rspack_database::DatabaseItem;
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use crate::{
-  ChunkGraph, ChunkGroupByUkey, ChunkGroupUkey, ChunkUkey, Compilation, EntryOptions, Filename,
-  ModuleGraph, RuntimeSpec, SourceType,
+  ChunkGraph, ChunkGroupByUkey, ChunkGroupFetchPriority, ChunkGroupOrderKey, ChunkGroupUkey,
+  ChunkUkey, Compilation, EntryOptions, Filename, ModuleGraph, RuntimeSpec, SourceType,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -112,6 +112,19 @@ impl Chunk {
       .all(|group| group.is_initial())
   }
 
+  /// The highest `fetchPriority` requested by the chunk groups of this chunk.
+  pub fn get_fetch_priority(
+    &self,
+    chunk_group_by_ukey: &ChunkGroupByUkey,
+  ) -> Option<ChunkGroupFetchPriority> {
+    self
+      .groups
+      .iter()
+      .filter_map(|ukey| chunk_group_by_ukey.get(ukey))
+      .filter_map(|group| group.options.fetch_priority)
+      .max()
+  }
+
   pub fn has_entry_module(&self, chunk_graph: &ChunkGraph) -> bool {
     chunk_graph.get_number_of_entry_modules(&self.ukey) > 0
   }
@@ -312,6 +325,80 @@ impl Chunk {
 
   // pub fn get_all_referenced_async_entry_points() -> HashSet<ChunkUkey> {}
 
+  /// Returns the child chunk groups with the `key` order of the chunk groups which end with this chunk,
+  /// as pairs of the chunks of the parent chunk group and the chunks of the child chunk group,
+  /// sorted from the highest order to the lowest.
+  pub fn get_children_of_type_in_order(
+    &self,
+    key: &ChunkGroupOrderKey,
+    compilation: &Compilation,
+  ) -> Vec<(Vec<ChunkUkey>, Vec<ChunkUkey>)> {
+    let mut list = vec![];
+    for group_ukey in &self.groups {
+      let group = group_ukey.as_ref(&compilation.chunk_group_by_ukey);
+      if group.chunks.last() != Some(&self.ukey) {
+        continue;
+      }
+      for child_ukey in group.children_iterable() {
+        let child = child_ukey.as_ref(&compilation.chunk_group_by_ukey);
+        if let Some(order) = child.options.order(key) {
+          list.push((order, child.id(compilation), group, child));
+        }
+      }
+    }
+    // Sort by the id of chunk group to make it stable when the orders are equal
+    list.sort_by(|(a_order, a_id, ..), (b_order, b_id, ..)| {
+      b_order.cmp(a_order).then_with(|| a_id.cmp(b_id))
+    });
+    list
+      .into_iter()
+      .map(|(_, _, group, child)| (group.chunks.clone(), child.chunks.clone()))
+      .collect()
+  }
+
+  /// Returns the ids of the child chunks with the `key` order, see [Chunk::get_children_of_type_in_order].
+  pub fn get_child_ids_by_order(
+    &self,
+    key: &ChunkGroupOrderKey,
+    compilation: &Compilation,
+  ) -> Vec<String> {
+    let mut ids = vec![];
+    for (_, chunks) in self.get_children_of_type_in_order(key, compilation) {
+      for chunk_ukey in chunks {
+        let id = chunk_ukey.as_ref(&compilation.chunk_by_ukey).expect_id();
+        if !ids.iter().any(|i| i == id) {
+          ids.push(id.to_string());
+        }
+      }
+    }
+    ids
+  }
+
+  /// Returns the ids of the child chunks with the `key` order of every async chunk of this chunk,
+  /// keyed by the id of the parent chunk.
+  pub fn get_child_ids_by_order_map(
+    &self,
+    key: &ChunkGroupOrderKey,
+    include_direct_children: bool,
+    compilation: &Compilation,
+  ) -> BTreeMap<String, Vec<String>> {
+    let mut chunks = self.get_all_async_chunks(&compilation.chunk_group_by_ukey);
+    if include_direct_children {
+      for group_ukey in &self.groups {
+        let group = group_ukey.as_ref(&compilation.chunk_group_by_ukey);
+        chunks.extend(group.chunks.iter().copied());
+      }
+    }
+    chunks
+      .into_iter()
+      .filter_map(|chunk_ukey| {
+        let chunk = chunk_ukey.as_ref(&compilation.chunk_by_ukey);
+        let ids = chunk.get_child_ids_by_order(key, compilation);
+        (!ids.is_empty()).then(|| (chunk.expect_id().to_string(), ids))
+      })
+      .collect()
+  }
+
   pub fn get_render_hash(&self, length: usize) -> Option<&str> {
     self.hash.as_ref().map(|hash| hash.rendered(length))
   }
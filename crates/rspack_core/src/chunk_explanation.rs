@@ -0,0 +1,204 @@
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::{ChunkGroupKind, ChunkUkey, Compilation, ModuleIdentifier};
+
+/// The decision made by `splitChunks` for a module group of a cache group.
+#[derive(Debug, Clone, Default)]
+pub struct SplitChunksDecision {
+  /// The key of the cache group which the module group belongs to
+  pub cache_group: String,
+  pub name: Option<String>,
+  /// The chunks which the modules are split from
+  pub chunks: Vec<ChunkUkey>,
+  pub modules: Vec<ModuleIdentifier>,
+  /// Whether the cache group is `enforce: true`, which ignores the size and request limits
+  pub enforce: bool,
+  pub min_size_satisfied: bool,
+  /// Whether `maxInitialRequests` or `maxAsyncRequests` prevented splitting from some chunks
+  pub max_requests_limited: bool,
+  /// Candidate modules which are excluded from the module group, with the reason
+  pub excluded_modules: Vec<(ModuleIdentifier, String)>,
+  /// Why the module group doesn't produce a chunk, `None` if it does
+  pub rejected_reason: Option<String>,
+}
+
+/// The decision log of `splitChunks`, recorded for both produced and rejected module groups.
+#[derive(Debug, Default)]
+pub struct SplitChunksDecisions {
+  produced: HashMap<ChunkUkey, Vec<SplitChunksDecision>>,
+  rejected: Vec<SplitChunksDecision>,
+}
+
+impl SplitChunksDecisions {
+  pub fn add_produced(&mut self, chunk: ChunkUkey, decision: SplitChunksDecision) {
+    self.produced.entry(chunk).or_default().push(decision);
+  }
+
+  pub fn add_rejected(&mut self, mut decision: SplitChunksDecision, reason: impl Into<String>) {
+    decision.rejected_reason = Some(reason.into());
+    self.rejected.push(decision);
+  }
+
+  pub fn get_produced(&self, chunk: &ChunkUkey) -> &[SplitChunksDecision] {
+    self
+      .produced
+      .get(chunk)
+      .map(Vec::as_slice)
+      .unwrap_or_default()
+  }
+
+  pub fn rejected(&self) -> &[SplitChunksDecision] {
+    &self.rejected
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChunkCreationReason {
+  /// The entry chunk of an entrypoint
+  Entry(String),
+  /// The runtime chunk split by `optimization.runtimeChunk`
+  RuntimeChunk(String),
+  /// The entry chunk of an async entrypoint, such as a worker
+  AsyncEntry,
+  /// Created by a dynamic `import()`
+  DynamicImport,
+  /// Created by a cache group of `splitChunks`
+  SplitChunks(String),
+}
+
+impl std::fmt::Display for ChunkCreationReason {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ChunkCreationReason::Entry(name) => write!(f, "entry {name}"),
+      ChunkCreationReason::RuntimeChunk(name) => write!(f, "runtime chunk of {name}"),
+      ChunkCreationReason::AsyncEntry => write!(f, "async entry"),
+      ChunkCreationReason::DynamicImport => write!(f, "dynamic import"),
+      ChunkCreationReason::SplitChunks(cache_group) => {
+        write!(f, "split chunk (cache group: {cache_group})")
+      }
+    }
+  }
+}
+
+/// Why a chunk exists, see [Compilation::explain_chunk].
+#[derive(Debug, Clone)]
+pub struct ChunkExplanation {
+  pub chunk: ChunkUkey,
+  pub created_by: ChunkCreationReason,
+  /// Modules of the chunk with their sizes, sorted by identifier
+  pub modules: Vec<(ModuleIdentifier, f64)>,
+  /// The `splitChunks` decisions which produced or reused this chunk
+  pub decisions: Vec<SplitChunksDecision>,
+  /// The rejected `splitChunks` module groups which would have split modules from this chunk
+  pub rejected: Vec<SplitChunksDecision>,
+}
+
+impl ChunkExplanation {
+  /// A single line summary, used by the chunks section of stats.
+  pub fn summary(&self) -> String {
+    let mut summary = self.created_by.to_string();
+    for decision in &self.decisions {
+      let mut constraints = vec![];
+      if decision.enforce {
+        constraints.push("enforced");
+      } else if decision.min_size_satisfied {
+        constraints.push("minSize satisfied");
+      }
+      if decision.max_requests_limited {
+        constraints.push("limited by maxRequests");
+      }
+      if !decision.excluded_modules.is_empty() {
+        summary.push_str(&format!(
+          ", {} modules excluded",
+          decision.excluded_modules.len()
+        ));
+      }
+      if !constraints.is_empty() {
+        summary.push_str(&format!(", {}", constraints.join(", ")));
+      }
+    }
+    if !self.rejected.is_empty() {
+      summary.push_str(&format!(", {} cache groups rejected", self.rejected.len()));
+    }
+    summary
+  }
+}
+
+impl Compilation {
+  /// Explain why the chunk with the given id or name exists.
+  pub fn explain_chunk(&self, id_or_name: &str) -> Option<ChunkExplanation> {
+    let chunk_ukey = self.named_chunks.get(id_or_name).copied().or_else(|| {
+      self
+        .chunk_by_ukey
+        .values()
+        .find(|chunk| chunk.id.as_deref() == Some(id_or_name))
+        .map(|chunk| chunk.ukey)
+    })?;
+    Some(self.explain_chunk_by_ukey(&chunk_ukey))
+  }
+
+  pub fn explain_chunk_by_ukey(&self, chunk_ukey: &ChunkUkey) -> ChunkExplanation {
+    let chunk = self.chunk_by_ukey.expect_get(chunk_ukey);
+    let decisions = self
+      .split_chunks_decisions
+      .get_produced(chunk_ukey)
+      .to_vec();
+
+    let mut created_by = None;
+    for group in chunk
+      .groups
+      .iter()
+      .filter_map(|group| self.chunk_group_by_ukey.get(group))
+    {
+      if !matches!(group.kind, ChunkGroupKind::Entrypoint { .. }) {
+        continue;
+      }
+      let name = group.options.name.clone().unwrap_or_default();
+      if group.entry_point_chunk == Some(*chunk_ukey) {
+        created_by = Some(if group.is_initial() {
+          ChunkCreationReason::Entry(name)
+        } else {
+          ChunkCreationReason::AsyncEntry
+        });
+        break;
+      }
+      if group.runtime_chunk == Some(*chunk_ukey) {
+        created_by = Some(ChunkCreationReason::RuntimeChunk(name));
+      }
+    }
+    let created_by = created_by.unwrap_or_else(|| match decisions.first() {
+      Some(decision) => ChunkCreationReason::SplitChunks(decision.cache_group.clone()),
+      None => ChunkCreationReason::DynamicImport,
+    });
+
+    let mut modules = self
+      .chunk_graph
+      .get_chunk_modules(chunk_ukey, &self.module_graph)
+      .into_iter()
+      .map(|module| {
+        let size = module
+          .source_types()
+          .iter()
+          .fold(0.0, |acc, ty| acc + module.size(ty));
+        (module.identifier(), size)
+      })
+      .collect::<Vec<_>>();
+    modules.sort_unstable_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+
+    let rejected = self
+      .split_chunks_decisions
+      .rejected()
+      .iter()
+      .filter(|decision| decision.chunks.contains(chunk_ukey))
+      .cloned()
+      .collect();
+
+    ChunkExplanation {
+      chunk: *chunk_ukey,
+      created_by,
+      modules,
+      decisions,
+      rejected,
+    }
+  }
+}
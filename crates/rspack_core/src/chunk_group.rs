@@ -64,6 +64,27 @@ impl ChunkGroup {
     self.parents.iter()
   }
 
+  pub fn children_iterable(&self) -> impl Iterator<Item = &ChunkGroupUkey> {
+    self.children.iter()
+  }
+
+  /// Merge the options of another reference to this chunk group, the higher order and fetch
+  /// priority win.
+  pub fn add_options(&mut self, options: &ChunkGroupOptions) {
+    if self.options.name.is_none() {
+      self.options.name = options.name.clone();
+    }
+    self.options.fetch_priority = self.options.fetch_priority.max(options.fetch_priority);
+    for key in [ChunkGroupOrderKey::Preload, ChunkGroupOrderKey::Prefetch] {
+      if let Some(order) = options.order(&key) {
+        let current = self.options.order(&key);
+        if current.map_or(true, |current| order > current) {
+          self.options.set_order(&key, order);
+        }
+      }
+    }
+  }
+
   pub fn module_post_order_index(&self, module_identifier: &ModuleIdentifier) -> Option<usize> {
     // A module could split into another ChunkGroup, which doesn't have the module_post_order_indices of the module
     self
@@ -213,9 +234,45 @@ impl ChunkGroupKind {
 pub struct ChunkGroupOptions {
   pub name: Option<String>,
   pub entry_options: Option<EntryOptions>,
+  /// `webpackPreload` of `import()`, the chunks are preloaded in parallel to the parent chunk
+  pub preload_order: Option<i32>,
+  /// `webpackPrefetch` of `import()`, the chunks are prefetched after the parent chunk is loaded
+  pub prefetch_order: Option<i32>,
+  /// `webpackFetchPriority` of `import()`, set as the `fetchPriority` of the `<script>` and `<link>`
+  /// elements which load the chunks
+  pub fetch_priority: Option<ChunkGroupFetchPriority>,
 }
 
 impl ChunkGroupOptions {
+  pub fn order(&self, key: &ChunkGroupOrderKey) -> Option<i32> {
+    match key {
+      ChunkGroupOrderKey::Preload => self.preload_order,
+      ChunkGroupOrderKey::Prefetch => self.prefetch_order,
+    }
+  }
+
+  pub fn set_order(&mut self, key: &ChunkGroupOrderKey, order: i32) {
+    match key {
+      ChunkGroupOrderKey::Preload => self.preload_order = Some(order),
+      ChunkGroupOrderKey::Prefetch => self.prefetch_order = Some(order),
+    }
+  }
+
+  pub fn preload_order_optional(mut self, v: Option<i32>) -> Self {
+    self.preload_order = v;
+    self
+  }
+
+  pub fn prefetch_order_optional(mut self, v: Option<i32>) -> Self {
+    self.prefetch_order = v;
+    self
+  }
+
+  pub fn fetch_priority_optional(mut self, v: Option<ChunkGroupFetchPriority>) -> Self {
+    self.fetch_priority = v;
+    self
+  }
+
   pub fn name(mut self, v: impl Into<String>) -> Self {
     self.name = Some(v.into());
     self
@@ -242,3 +299,55 @@ pub struct ChunkGroupInfo {
   pub chunk_loading: bool,
   pub async_chunks: bool,
 }
+
+/// The `fetchPriority` of the elements which load the chunks, ordered from the lowest to the highest.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ChunkGroupFetchPriority {
+  Low,
+  Auto,
+  High,
+}
+
+impl ChunkGroupFetchPriority {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      ChunkGroupFetchPriority::Low => "low",
+      ChunkGroupFetchPriority::Auto => "auto",
+      ChunkGroupFetchPriority::High => "high",
+    }
+  }
+}
+
+impl TryFrom<&str> for ChunkGroupFetchPriority {
+  type Error = ();
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "low" => Ok(ChunkGroupFetchPriority::Low),
+      "auto" => Ok(ChunkGroupFetchPriority::Auto),
+      "high" => Ok(ChunkGroupFetchPriority::High),
+      _ => Err(()),
+    }
+  }
+}
+
+impl std::fmt::Display for ChunkGroupFetchPriority {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ChunkGroupOrderKey {
+  Preload,
+  Prefetch,
+}
+
+impl std::fmt::Display for ChunkGroupOrderKey {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(match self {
+      ChunkGroupOrderKey::Preload => "preload",
+      ChunkGroupOrderKey::Prefetch => "prefetch",
+    })
+  }
+}
@@ -3,7 +3,7 @@ use std::hash::Hash;
 use std::ops::{Deref, DerefMut};
 
 use anymap::CloneAny;
-use rspack_error::{internal_error, Result};
+use rspack_error::{internal_error, Diagnostic, Result};
 use rspack_hash::{HashDigest, HashFunction, HashSalt, RspackHash, RspackHashDigest};
 use rspack_identifier::IdentifierMap;
 use rspack_sources::BoxSource;
@@ -59,6 +59,21 @@ impl CodeGenerationDataAssetInfo {
   }
 }
 
+#[derive(Clone, Debug)]
+pub struct CodeGenerationDataContentHash {
+  inner: RspackHashDigest,
+}
+
+impl CodeGenerationDataContentHash {
+  pub fn new(inner: RspackHashDigest) -> Self {
+    Self { inner }
+  }
+
+  pub fn inner(&self) -> &RspackHashDigest {
+    &self.inner
+  }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct CodeGenerationData {
   inner: anymap::Map<dyn CloneAny + Send + Sync>,
@@ -86,6 +101,8 @@ pub struct CodeGenerationResult {
   pub chunk_init_fragments: ChunkInitFragments,
   pub runtime_requirements: RuntimeGlobals,
   pub hash: Option<RspackHashDigest>,
+  /// Diagnostics of the source types that failed to generate
+  pub diagnostics: Vec<Diagnostic>,
 }
 
 impl CodeGenerationResult {
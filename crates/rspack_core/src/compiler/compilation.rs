@@ -32,15 +32,17 @@ use crate::{
   is_source_equal,
   tree_shaking::{optimizer, visitor::SymbolRef, BailoutFlag, OptimizeDependencyResult},
   AddQueue, AddTask, AddTaskResult, AdditionalChunkRuntimeRequirementsArgs, BoxDependency,
-  BoxModule, BuildQueue, BuildTask, BuildTaskResult, Chunk, ChunkByUkey, ChunkContentHash,
-  ChunkGraph, ChunkGroup, ChunkGroupUkey, ChunkHashArgs, ChunkKind, ChunkUkey, CleanQueue,
-  CleanTask, CleanTaskResult, CodeGenerationResult, CodeGenerationResults, CompilationLogger,
-  CompilationLogging, CompilerOptions, ContentHashArgs, DependencyId, Entry, EntryData,
-  EntryOptions, Entrypoint, FactorizeQueue, FactorizeTask, FactorizeTaskResult, Filename, Logger,
-  Module, ModuleGraph, ModuleIdentifier, ModuleProfile, ModuleType, PathData, ProcessAssetsArgs,
-  ProcessDependenciesQueue, ProcessDependenciesResult, ProcessDependenciesTask, RenderManifestArgs,
-  Resolve, ResolverFactory, RuntimeGlobals, RuntimeModule, RuntimeSpec, SharedPluginDriver,
-  SourceType, Stats, TaskResult, WorkerTask,
+  BoxModule, BuildQueue, BuildResult, BuildTask, BuildTaskResult, Chunk, ChunkByUkey,
+  ChunkContentHash, ChunkGraph, ChunkGroup, ChunkGroupUkey, ChunkHashArgs, ChunkKind, ChunkUkey,
+  CleanQueue, CleanTask, CleanTaskResult, CodeGenerationResult, CodeGenerationResults,
+  CompilationLogger, CompilationLogging, CompilerOptions, ContentHashArgs, DependencyId, Entry,
+  EntryData, EntryDependency, EntryOptions, Entrypoint, FactorizeQueue, FactorizeTask,
+  FactorizeTaskResult, Filename, InfrastructureLogger, InfrastructureLogging, Logger, Module,
+  ModuleBuildMutation, ModuleGraph, ModuleGraphModule, ModuleIdentifier, ModuleProfile, ModuleType,
+  NormalModuleSource, PathData, ProcessAssetsArgs, ProcessDependenciesQueue,
+  ProcessDependenciesResult, ProcessDependenciesTask, RenderManifestArgs, Resolve, ResolverFactory,
+  RuntimeGlobals, RuntimeModule, RuntimeSpec, SharedPluginDriver, SourceType, SplitChunksDecisions,
+  Stats, TaskResult, WorkerTask,
 };
 use crate::{tree_shaking::visitor::OptimizeAnalyzeResult, Context};
 
@@ -49,6 +51,18 @@ pub type BuildDependency = (
   Option<ModuleIdentifier>, /* parent module */
 );
 
+/// An entry added by [Compilation::add_entry_runtime].
+#[derive(Debug)]
+pub struct RuntimeEntry {
+  /// The context to resolve the request, `options.context` is used if it's `None`
+  pub context: Option<Context>,
+  pub request: String,
+  pub name: String,
+  pub options: EntryOptions,
+  /// Whether the module is only included in the entry chunk without being executed on startup
+  pub is_include: bool,
+}
+
 #[derive(Debug)]
 pub struct Compilation {
   // Mark compilation status, because the hash of `[hash].hot-update.js/json` is previous compilation hash.
@@ -73,11 +87,16 @@ pub struct Compilation {
   assets: CompilationAssets,
   pub emitted_assets: DashSet<String, BuildHasherDefault<FxHasher>>,
   diagnostics: IndexSet<Diagnostic, BuildHasherDefault<FxHasher>>,
+  /// The number of diagnostics which are already checked by `ignoreWarnings`
+  checked_diagnostics: usize,
   logging: CompilationLogging,
+  pub(crate) infrastructure_logging: InfrastructureLogging,
   pub plugin_driver: SharedPluginDriver,
   pub resolver_factory: Arc<ResolverFactory>,
   pub loader_resolver_factory: Arc<ResolverFactory>,
   pub named_chunks: HashMap<String, ChunkUkey>,
+  /// The decision log of `splitChunks`, see [Compilation::explain_chunk]
+  pub split_chunks_decisions: SplitChunksDecisions,
   pub(crate) named_chunk_groups: HashMap<String, ChunkGroupUkey>,
   pub entry_module_identifiers: IdentifierSet,
   /// Collecting all used export symbol
@@ -100,6 +119,10 @@ pub struct Compilation {
   pub context_dependencies: IndexSet<PathBuf, BuildHasherDefault<FxHasher>>,
   pub missing_dependencies: IndexSet<PathBuf, BuildHasherDefault<FxHasher>>,
   pub build_dependencies: IndexSet<PathBuf, BuildHasherDefault<FxHasher>>,
+  /// The files changed since the last compilation, empty for the initial build
+  pub modified_files: HashSet<PathBuf>,
+  /// The files removed since the last compilation, empty for the initial build
+  pub removed_files: HashSet<PathBuf>,
   pub side_effects_free_modules: IdentifierSet,
   pub module_item_map: IdentifierMap<Vec<ModuleItem>>,
 }
@@ -113,6 +136,7 @@ impl Compilation {
     resolver_factory: Arc<ResolverFactory>,
     loader_resolver_factory: Arc<ResolverFactory>,
     cache: Arc<Cache>,
+    infrastructure_logging: InfrastructureLogging,
   ) -> Self {
     Self {
       hot_index: 0,
@@ -132,11 +156,14 @@ impl Compilation {
       assets: Default::default(),
       emitted_assets: Default::default(),
       diagnostics: Default::default(),
+      checked_diagnostics: 0,
       logging: Default::default(),
+      infrastructure_logging,
       plugin_driver,
       resolver_factory,
       loader_resolver_factory,
       named_chunks: Default::default(),
+      split_chunks_decisions: Default::default(),
       named_chunk_groups: Default::default(),
       entry_module_identifiers: IdentifierSet::default(),
       used_symbol_ref: HashSet::default(),
@@ -156,22 +183,149 @@ impl Compilation {
       context_dependencies: Default::default(),
       missing_dependencies: Default::default(),
       build_dependencies: Default::default(),
+      modified_files: Default::default(),
+      removed_files: Default::default(),
       side_effects_free_modules: IdentifierSet::default(),
       module_item_map: IdentifierMap::default(),
       include_module_ids: IdentifierSet::default(),
     }
   }
 
-  pub fn add_entry(&mut self, entry: DependencyId, name: String, options: EntryOptions) {
-    if let Some(data) = self.entries.get_mut(&name) {
-      data.dependencies.push(entry);
-    } else {
+  pub async fn add_entry(
+    &mut self,
+    entry: DependencyId,
+    name: String,
+    options: EntryOptions,
+  ) -> Result<()> {
+    self
+      .entry_data_mut(name, options)
+      .await?
+      .dependencies
+      .push(entry);
+    Ok(())
+  }
+
+  pub async fn add_include(
+    &mut self,
+    include: DependencyId,
+    name: String,
+    options: EntryOptions,
+  ) -> Result<()> {
+    self
+      .entry_data_mut(name, options)
+      .await?
+      .include_dependencies
+      .push(include);
+    Ok(())
+  }
+
+  async fn entry_data_mut(
+    &mut self,
+    name: String,
+    mut options: EntryOptions,
+  ) -> Result<&mut EntryData> {
+    if !self.entries.contains_key(&name) {
+      if options.runtime.is_none()
+        && options.depend_on.is_none()
+        && let Some(runtime_chunk) = &self.options.optimization.runtime_chunk
+      {
+        options.runtime = runtime_chunk.get_name(&name).await?;
+      }
       let data = EntryData {
-        dependencies: vec![entry],
+        dependencies: vec![],
+        include_dependencies: vec![],
         options,
       };
-      self.entries.insert(name.to_owned(), data);
+      self.entries.insert(name.clone(), data);
+    }
+    Ok(self.entries.get_mut(&name).expect("should have entry data"))
+  }
+
+  /// Add entries while the compilation is making, e.g. from the `make` hook of JS plugins.
+  /// The entries are built together and before returning, so the caller gets the identifier
+  /// of each entry module, or the error of the entry which can't be added.
+  pub async fn add_entry_runtime(
+    &mut self,
+    entries: Vec<RuntimeEntry>,
+  ) -> Result<Vec<Result<ModuleIdentifier>>> {
+    let mut dependency_ids = Vec::with_capacity(entries.len());
+    for entry in &entries {
+      dependency_ids.push(self.add_runtime_entry_dependency(entry).await?);
+    }
+
+    self
+      .update_module_graph(vec![MakeParam::ForceBuildDeps(
+        dependency_ids.iter().map(|id| (*id, None)).collect(),
+      )])
+      .await?;
+
+    let mut results = Vec::with_capacity(entries.len());
+    for (entry, dependency_id) in entries.into_iter().zip(dependency_ids) {
+      match self
+        .module_graph
+        .module_identifier_by_dependency_id(&dependency_id)
+      {
+        Some(module_identifier) => results.push(Ok(*module_identifier)),
+        None => {
+          // The reason is reported by the factorization, which doesn't know about the entry
+          let message = format!(
+            "Failed to add the module {} to entry \"{}\"",
+            entry.request, entry.name
+          );
+          self.push_diagnostic(Diagnostic::error(
+            "Entry error".to_string(),
+            message.clone(),
+            0,
+            0,
+          ));
+          results.push(Err(internal_error!(message)));
+        }
+      }
+    }
+    Ok(results)
+  }
+
+  async fn add_runtime_entry_dependency(&mut self, entry: &RuntimeEntry) -> Result<DependencyId> {
+    // The same request is added again when the `make` hook is called by a rebuild,
+    // the existing dependency is rebuilt instead of adding a duplicated one.
+    let existing = self.entries.get(&entry.name).and_then(|data| {
+      let dependencies = if entry.is_include {
+        &data.include_dependencies
+      } else {
+        &data.dependencies
+      };
+      dependencies.iter().copied().find(|id| {
+        self
+          .module_graph
+          .dependency_by_id(id)
+          .and_then(|dependency| dependency.as_module_dependency())
+          .map_or(false, |dependency| {
+            dependency.request() == entry.request
+              && dependency.get_context() == entry.context.as_ref()
+          })
+      })
+    });
+    if let Some(dependency_id) = existing {
+      return Ok(dependency_id);
     }
+
+    let mut dependency = EntryDependency::new(entry.request.clone());
+    if let Some(context) = &entry.context {
+      dependency = dependency.with_context(context.clone());
+    }
+    let dependency: BoxDependency = Box::new(dependency);
+    let dependency_id = *dependency.id();
+    if entry.is_include {
+      self
+        .add_include(dependency_id, entry.name.clone(), entry.options.clone())
+        .await?;
+    } else {
+      self
+        .add_entry(dependency_id, entry.name.clone(), entry.options.clone())
+        .await?;
+    }
+    self.module_graph.add_dependency(dependency);
+    Ok(dependency_id)
   }
 
   pub fn update_asset(
@@ -227,7 +381,8 @@ impl Compilation {
         self.assets.insert(filename, asset);
         return;
       }
-      original.info = asset.info;
+      // the same content is emitted by another owner, keep all of them as the origins
+      original.info.merge_another_asset(asset.info);
       self.assets.insert(filename, original);
     } else {
       self.assets.insert(filename, asset);
@@ -281,6 +436,24 @@ impl Compilation {
     self.diagnostics.extend(diagnostics);
   }
 
+  /// Push the diagnostics reported from the module, which is recorded to trace the importers
+  /// of the module in stats.
+  fn push_module_diagnostics(
+    &mut self,
+    module_identifier: Option<ModuleIdentifier>,
+    diagnostics: Vec<Diagnostic>,
+  ) {
+    self
+      .diagnostics
+      .extend(diagnostics.into_iter().map(|diagnostic| {
+        if diagnostic.module_identifier.is_some() {
+          diagnostic
+        } else {
+          diagnostic.with_module_identifier(module_identifier.map(|i| i.to_string()))
+        }
+      }));
+  }
+
   pub fn get_errors(&self) -> impl Iterator<Item = &Diagnostic> {
     self
       .diagnostics
@@ -295,6 +468,35 @@ impl Compilation {
       .filter(|d| matches!(d.severity, Severity::Warn))
   }
 
+  /// Remove the warnings matched by `ignoreWarnings`, only the diagnostics pushed since
+  /// the last call are checked.
+  pub async fn ignore_warnings(&mut self) -> Result<()> {
+    let mut ignored = HashSet::default();
+    for (index, diagnostic) in self
+      .diagnostics
+      .iter()
+      .enumerate()
+      .skip(self.checked_diagnostics)
+    {
+      for matcher in &self.options.ignore_warnings {
+        if matcher.is_match(diagnostic).await? {
+          ignored.insert(index);
+          break;
+        }
+      }
+    }
+    if !ignored.is_empty() {
+      let mut index = 0;
+      self.diagnostics.retain(|_| {
+        let keep = !ignored.contains(&index);
+        index += 1;
+        keep
+      });
+    }
+    self.checked_diagnostics = self.diagnostics.len();
+    Ok(())
+  }
+
   pub fn get_logging(&self) -> &CompilationLogging {
     &self.logging
   }
@@ -347,17 +549,64 @@ impl Compilation {
       MakeParam::ForceBuildModules(std::mem::take(&mut self.make_failed_module));
     let make_failed_dependencies =
       MakeParam::ForceBuildDeps(std::mem::take(&mut self.make_failed_dependencies));
-    self
+    let result = self
       .update_module_graph(vec![param, make_failed_module, make_failed_dependencies])
-      .await
+      .await;
+
+    // The resolution depends on the tsconfig files, rebuild once they are changed
+    let tsconfig_dependencies = self.resolver_factory.tsconfig_dependencies();
+    self
+      .file_dependencies
+      .extend(tsconfig_dependencies.iter().cloned());
+    self.build_dependencies.extend(tsconfig_dependencies);
+    result
   }
 
+  /// Rebuild the given modules and update the module graph with their new dependencies.
+  ///
+  /// A module that fails to rebuild is left intact, and the diagnostics of the failed build are
+  /// attached to the compilation. Returns whether the hash of each module changed after
+  /// rebuilding, or the error which failed its rebuild.
   pub async fn rebuild_module(
     &mut self,
     module_identifiers: HashSet<ModuleIdentifier>,
-  ) -> Result<Vec<&BoxModule>> {
+  ) -> Result<Vec<(ModuleIdentifier, Result<bool>)>> {
+    let mut previous_hashes = IdentifierMap::default();
     for id in &module_identifiers {
       self.cache.build_module_occasion.remove_cache(id);
+
+      let Some(mgm) = self.module_graph.module_graph_module_by_identifier(id) else {
+        continue;
+      };
+      let hash = mgm
+        .build_info
+        .as_ref()
+        .and_then(|build_info| build_info.hash.clone());
+      let fallback = BuildResult {
+        build_info: mgm.build_info.clone().unwrap_or_default(),
+        build_meta: mgm.build_meta.clone().unwrap_or_default(),
+        analyze_result: self
+          .optimize_analyze_result_map
+          .get(id)
+          .cloned()
+          .unwrap_or_default(),
+        dependencies: mgm
+          .dependencies
+          .iter()
+          .filter_map(|dependency_id| self.module_graph.dependency_by_id(dependency_id))
+          .cloned()
+          .collect(),
+      };
+      previous_hashes.insert(*id, hash);
+
+      if let Some(module) = self
+        .module_graph
+        .module_by_identifier_mut(id)
+        .and_then(|module| module.as_normal_module_mut())
+        && matches!(module.source(), NormalModuleSource::BuiltSucceed(_))
+      {
+        module.set_rebuild_fallback(fallback);
+      }
     }
 
     self
@@ -369,15 +618,82 @@ impl Compilation {
     Ok(
       module_identifiers
         .into_iter()
-        .filter_map(|id| self.module_graph.module_by_identifier(&id))
-        .collect::<Vec<_>>(),
+        .map(|id| {
+          let Some(previous_hash) = previous_hashes.get(&id) else {
+            return (id, Err(internal_error!("Failed to rebuild {id}, the module is not found")));
+          };
+          if let Some(errors) = self
+            .module_graph
+            .module_by_identifier_mut(&id)
+            .and_then(|module| module.as_normal_module_mut())
+            .and_then(|module| module.take_rebuild_errors())
+          {
+            return (
+              id,
+              Err(internal_error!(
+                "Failed to rebuild {id}:\n{}",
+                errors.join("\n")
+              )),
+            );
+          }
+          let hash = self
+            .module_graph
+            .module_graph_module_by_identifier(&id)
+            .and_then(|mgm| mgm.build_info.as_ref())
+            .and_then(|build_info| build_info.hash.as_ref());
+          (id, Ok(previous_hash.as_ref() != hash))
+        })
+        .collect(),
     )
   }
 
-  async fn update_module_graph(&mut self, params: Vec<MakeParam>) -> Result<()> {
+  async fn update_module_graph(&mut self, mut params: Vec<MakeParam>) -> Result<()> {
+    let mut rebuilt_modules = HashSet::default();
+    loop {
+      let upstream_modules = self
+        .update_module_graph_once(params, &mut rebuilt_modules)
+        .await?;
+      if upstream_modules.is_empty() {
+        return Ok(());
+      }
+      // The build results of the upstream modules are reused unless the exports of their
+      // dependencies are changed, they are built again even if their own files are unchanged
+      for id in &upstream_modules {
+        if let Some(module) = self
+          .module_graph
+          .module_by_identifier_mut(id)
+          .and_then(|module| module.as_normal_module_mut())
+        {
+          module.invalidate_build_snapshot();
+        }
+      }
+      params = vec![MakeParam::ForceBuildModules(upstream_modules)];
+    }
+  }
+
+  /// Returns the upstream modules of the rebuilt modules whose exports are changed,
+  /// `rebuilt_modules` are never returned so that a cycle is only rebuilt once.
+  async fn update_module_graph_once(
+    &mut self,
+    params: Vec<MakeParam>,
+    rebuilt_modules: &mut HashSet<ModuleIdentifier>,
+  ) -> Result<HashSet<ModuleIdentifier>> {
     let logger = self.get_logger("rspack.Compiler");
     let deps_builder = RebuildDepsBuilder::new(params, &self.module_graph);
     let mut origin_module_deps = HashMap::default();
+    let previous_exports_hashes = deps_builder
+      .get_force_build_modules()
+      .iter()
+      .filter_map(|id| {
+        let build_meta = self
+          .module_graph
+          .module_graph_module_by_identifier(id)?
+          .build_meta
+          .as_ref()?;
+        Some((*id, build_meta.exports_hash()))
+      })
+      .collect::<IdentifierMap<_>>();
+    rebuilt_modules.extend(deps_builder.get_force_build_modules().iter().copied());
 
     // collect origin_module_deps
     for module_id in deps_builder.get_force_build_modules() {
@@ -393,6 +709,16 @@ impl Compilation {
       origin_module_deps.insert(*module_id, deps);
     }
 
+    // Keep the instances of modules to rebuild, so that their builds can be skipped
+    // if none of their dependencies have changed since the last build
+    let mut previous_modules = deps_builder
+      .get_force_build_modules()
+      .iter()
+      .filter_map(|id| self.module_graph.take_module(id))
+      .filter(|module| module.as_normal_module().is_some())
+      .map(|module| (module.identifier(), module))
+      .collect::<IdentifierMap<_>>();
+
     let mut need_check_isolated_module_ids = HashSet::default();
     // rebuild module issuer mappings
     // save rebuild module issue to restore them
@@ -453,10 +779,15 @@ impl Compilation {
         );
       });
 
+    let mut built_module_count = 0usize;
+    let mut skipped_module_count = 0usize;
     let mut add_time = logger.time_aggregate("module add task");
     let mut process_deps_time = logger.time_aggregate("module process dependencies task");
     let mut factorize_time = logger.time_aggregate("module factorize task");
     let mut build_time = logger.time_aggregate("module build task");
+    let mut mutation_time = logger.time_aggregate("module graph mutation");
+    let mut build_mutations = vec![];
+    let mut built_dependencies = vec![];
     tokio::task::block_in_place(|| loop {
       let start = factorize_time.start();
       while let Some(task) = factorize_queue.get_task() {
@@ -598,22 +929,15 @@ impl Compilation {
                 is_entry,
                 original_module_identifier,
                 factory_result,
-                mut module_graph_module,
                 diagnostics,
                 dependencies,
                 current_profile,
               } = task_result;
-              let module_identifier = factory_result.module.identifier();
-
-              tracing::trace!("Module created: {}", &module_identifier);
               if !diagnostics.is_empty() {
                 make_failed_dependencies
                   .insert((*dependencies[0].id(), original_module_identifier));
               }
-
-              module_graph_module.set_issuer_if_unset(original_module_identifier);
-              module_graph_module.factory_meta = Some(factory_result.factory_meta);
-              self.push_batch_diagnostic(diagnostics);
+              self.push_module_diagnostics(original_module_identifier, diagnostics);
 
               self
                 .file_dependencies
@@ -625,14 +949,22 @@ impl Compilation {
                 .missing_dependencies
                 .extend(factory_result.missing_dependencies);
 
-              add_queue.add_task(AddTask {
-                original_module_identifier,
-                module: factory_result.module,
-                module_graph_module,
-                dependencies,
-                is_entry,
-                current_profile,
-              });
+              // No module is created if the dependency is skipped, e.g. by `beforeResolve`
+              if let Some(module) = factory_result.module {
+                tracing::trace!("Module created: {}", module.identifier());
+                let mut module_graph_module =
+                  ModuleGraphModule::new(module.identifier(), *module.module_type());
+                module_graph_module.set_issuer_if_unset(original_module_identifier);
+                module_graph_module.factory_meta = Some(factory_result.factory_meta);
+                add_queue.add_task(AddTask {
+                  original_module_identifier,
+                  module,
+                  module_graph_module: Box::new(module_graph_module),
+                  dependencies,
+                  is_entry,
+                  current_profile,
+                });
+              }
             }
             Ok(TaskResult::Add(box task_result)) => match task_result {
               AddTaskResult::ModuleAdded {
@@ -640,6 +972,9 @@ impl Compilation {
                 current_profile,
               } => {
                 tracing::trace!("Module added: {}", module.identifier());
+                let module = previous_modules
+                  .remove(&module.identifier())
+                  .unwrap_or(module);
                 build_queue.add_task(BuildTask {
                   module,
                   resolver_factory: self.resolver_factory.clone(),
@@ -671,7 +1006,15 @@ impl Compilation {
               }
 
               tracing::trace!("Module built: {}", module.identifier());
-              self.push_batch_diagnostic(diagnostics);
+              if module
+                .as_normal_module()
+                .map_or(false, |module| module.is_build_skipped())
+              {
+                skipped_module_count += 1;
+              } else {
+                built_module_count += 1;
+              }
+              self.push_module_diagnostics(Some(module.identifier()), diagnostics);
 
               self
                 .file_dependencies
@@ -686,37 +1029,27 @@ impl Compilation {
                 .build_dependencies
                 .extend(build_result.build_info.build_dependencies.clone());
 
-              let mut dep_ids = vec![];
-              for dependency in build_result.dependencies.iter() {
-                if let Some(dependency) = dependency.as_module_dependency() {
-                  self
-                    .module_graph
-                    .set_dependency_import_var(module.identifier(), dependency.request());
-                }
-                dep_ids.push(*dependency.id());
-              }
-
-              {
-                let mgm = self
-                  .module_graph
-                  .module_graph_module_by_identifier_mut(&module.identifier())
-                  .expect("Failed to get mgm");
-                mgm.dependencies = Box::new(dep_ids);
-                if let Some(current_profile) = current_profile {
-                  mgm.set_profile(current_profile);
-                }
-              }
-              process_dependencies_queue.add_task(ProcessDependenciesTask {
-                dependencies: build_result.dependencies,
-                original_module_identifier: module.identifier(),
-                resolve_options: module.get_resolve_options(),
+              let BuildResult {
+                build_info,
+                build_meta,
+                dependencies,
+                ..
+              } = *build_result;
+              let original_module_identifier = module.identifier();
+              let resolve_options = module.get_resolve_options();
+              // The graph changes are applied with the other modules built in this iteration
+              build_mutations.push(ModuleBuildMutation::new(
+                module,
+                &dependencies,
+                build_info,
+                build_meta,
+                current_profile,
+              ));
+              built_dependencies.push(ProcessDependenciesTask {
+                dependencies,
+                original_module_identifier,
+                resolve_options,
               });
-              self.module_graph.set_module_build_info_and_meta(
-                &module.identifier(),
-                build_result.build_info,
-                build_result.build_meta,
-              );
-              self.module_graph.add_module(module);
             }
             Ok(TaskResult::ProcessDependencies(task_result)) => {
               tracing::trace!(
@@ -739,7 +1072,20 @@ impl Compilation {
           break;
         }
         Err(TryRecvError::Empty) => {
-          if active_task_count == 0 {
+          if !build_mutations.is_empty() {
+            // Apply the graph changes of all modules built since the last batch at once
+            let start = mutation_time.start();
+            let mutation_result = self
+              .module_graph
+              .apply_build_mutations(std::mem::take(&mut build_mutations));
+            mutation_time.end(start);
+            if let Err(err) = mutation_result {
+              errored = Some(err);
+              is_expected_shutdown.store(true, Ordering::SeqCst);
+              break;
+            }
+            process_dependencies_queue.add_tasks(built_dependencies.drain(..));
+          } else if active_task_count == 0 {
             is_expected_shutdown.store(true, Ordering::SeqCst);
             break;
           }
@@ -750,6 +1096,14 @@ impl Compilation {
     logger.time_aggregate_end(process_deps_time);
     logger.time_aggregate_end(factorize_time);
     logger.time_aggregate_end(build_time);
+    logger.time_aggregate_end(mutation_time);
+    logger.log(format!(
+      "{built_module_count} modules are built, {skipped_module_count} modules are skipped as unchanged"
+    ));
+    logger.log(format!(
+      "{} resolved requests are reused from the cache",
+      self.resolver_factory.take_request_cache_hits()
+    ));
 
     // TODO @jerrykingxyz make update_module_graph a pure function
     self
@@ -844,10 +1198,27 @@ impl Compilation {
     }
 
     if let Some(err) = errored {
-      Err(err)
-    } else {
-      Ok(())
+      return Err(err);
     }
+
+    let upstream_modules = previous_exports_hashes
+      .into_iter()
+      .filter(|(id, exports_hash)| {
+        self
+          .module_graph
+          .module_graph_module_by_identifier(id)
+          .and_then(|mgm| mgm.build_meta.as_ref())
+          .map_or(false, |build_meta| {
+            build_meta.exports_hash() != *exports_hash
+          })
+      })
+      .filter_map(|(id, _)| self.module_graph.module_graph_module_by_identifier(&id))
+      .flat_map(|mgm| mgm.incoming_connections.iter())
+      .filter_map(|id| self.module_graph.connection_by_connection_id(id))
+      .filter_map(|connection| connection.original_module_identifier)
+      .filter(|id| !rebuilt_modules.contains(id))
+      .collect();
+    Ok(upstream_modules)
   }
 
   #[allow(clippy::too_many_arguments)]
@@ -890,7 +1261,7 @@ impl Compilation {
       compilation: &mut Compilation,
       filter_op: impl Fn(&(&ModuleIdentifier, &Box<dyn Module>)) -> bool + Sync + Send,
     ) -> Result<()> {
-      let results = compilation
+      let mut results = compilation
         .module_graph
         .modules()
         .par_iter()
@@ -909,9 +1280,12 @@ impl Compilation {
             .map(|result| (*module_identifier, result))
         })
         .collect::<Result<Vec<(ModuleIdentifier, CodeGenerationResult)>>>()?;
+      // The order of the module graph isn't stable, sort the results to keep the diagnostics deterministic
+      results.sort_unstable_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
 
       results.into_iter().for_each(|(module_identifier, result)| {
         compilation.code_generated_modules.insert(module_identifier);
+        compilation.push_batch_diagnostic(result.diagnostics.clone());
 
         let runtimes = compilation
           .chunk_graph
@@ -1037,6 +1411,7 @@ impl Compilation {
   }
 
   pub async fn done(&mut self, plugin_driver: SharedPluginDriver) -> Result<()> {
+    self.ignore_warnings().await?;
     let stats = &mut Stats::new(self);
     plugin_driver.done(stats).await?;
     Ok(())
@@ -1088,6 +1463,10 @@ impl Compilation {
     plugin_driver.chunk_ids(self)?;
     logger.time_end(start);
 
+    let start = logger.time("optimize code generation");
+    plugin_driver.optimize_code_generation(self).await?;
+    logger.time_end(start);
+
     let start = logger.time("code generation");
     self.code_generation().await?;
     logger.time_end(start);
@@ -1315,14 +1694,23 @@ impl Compilation {
     self.hot_index.hash(&mut compilation_hasher);
     self.hash = Some(compilation_hasher.digest(&self.options.output.hash_digest));
 
-    // here omit re-create full hash runtime module hash, only update compilation hash to runtime chunk content hash
+    // here omit re-create full hash runtime module hash, only update the hash it depends on to runtime chunk content hash
     let start = logger.time("hashing: process full hash chunks");
+    let dependent_hashes = runtime_chunk_ukeys
+      .iter()
+      .map(|chunk_ukey| {
+        (
+          *chunk_ukey,
+          self.get_runtime_chunk_dependent_hash(chunk_ukey),
+        )
+      })
+      .collect::<HashMap<_, _>>();
     self.chunk_by_ukey.values_mut().for_each(|chunk| {
-      if runtime_chunk_ukeys.contains(&chunk.ukey) {
+      if let Some(dependent_hash) = dependent_hashes.get(&chunk.ukey) {
         if let Some(chunk_hash) = &mut chunk.hash {
           let mut hasher = RspackHash::from(&self.options.output);
           chunk_hash.hash(&mut hasher);
-          self.hash.hash(&mut hasher);
+          dependent_hash.hash(&mut hasher);
           *chunk_hash = hasher.digest(&self.options.output.hash_digest);
           chunk.rendered_hash = Some(
             chunk_hash
@@ -1333,7 +1721,7 @@ impl Compilation {
         if let Some(content_hash) = chunk.content_hash.get_mut(&SourceType::JavaScript) {
           let mut hasher = RspackHash::from(&self.options.output);
           content_hash.hash(&mut hasher);
-          self.hash.hash(&mut hasher);
+          dependent_hash.hash(&mut hasher);
           *content_hash = hasher.digest(&self.options.output.hash_digest);
         }
       }
@@ -1342,6 +1730,49 @@ impl Compilation {
     Ok(())
   }
 
+  /// The hash a runtime chunk depends on besides its own content.
+  ///
+  /// The compilation hash is only used when the runtime references it, so a runtime chunk
+  /// that is split from its entries keeps its hash when only the entry modules changed.
+  /// Otherwise only the ids and hashes of the chunks that end up in the chunk-filename map of
+  /// the runtime are taken into account.
+  fn get_runtime_chunk_dependent_hash(&self, chunk_ukey: &ChunkUkey) -> Option<RspackHashDigest> {
+    let output = &self.options.output;
+    if self
+      .chunk_graph
+      .get_tree_runtime_requirements(chunk_ukey)
+      .contains(RuntimeGlobals::GET_FULL_HASH)
+      || output.chunk_filename.has_hash_placeholder()
+      || output.css_chunk_filename.has_hash_placeholder()
+    {
+      return self.hash.clone();
+    }
+    let chunk = self.chunk_by_ukey.get(chunk_ukey)?;
+    let mut hasher = RspackHash::from(output);
+    output.chunk_filename.template().hash(&mut hasher);
+    output.css_chunk_filename.template().hash(&mut hasher);
+    let mut referenced_chunks = chunk.get_all_async_chunks(&self.chunk_group_by_ukey);
+    referenced_chunks.extend(self.chunk_graph.get_chunk_entry_dependent_chunks_iterable(
+      chunk_ukey,
+      &self.chunk_by_ukey,
+      &self.chunk_group_by_ukey,
+    ));
+    referenced_chunks
+      .iter()
+      .filter_map(|ukey| self.chunk_by_ukey.get(ukey))
+      .sorted_unstable_by_key(|chunk| chunk.id.as_ref())
+      .for_each(|chunk| {
+        chunk.id.hash(&mut hasher);
+        chunk.hash.hash(&mut hasher);
+        chunk
+          .content_hash
+          .iter()
+          .sorted_unstable_by_key(|(source_type, _)| source_type.to_string())
+          .for_each(|content_hash| content_hash.hash(&mut hasher));
+      });
+    Some(hasher.digest(&output.hash_digest))
+  }
+
   async fn process_chunk_hash(
     &self,
     chunk_ukey: ChunkUkey,
@@ -1405,7 +1836,11 @@ impl Compilation {
         let source = module.generate(self);
         let mut hasher = RspackHash::from(&self.options.output);
         module.identifier().hash(&mut hasher);
-        source.source().hash(&mut hasher);
+        // Non-cacheable runtime modules, e.g. the chunk-filename map, are regenerated when the
+        // runtime chunk is rendered, what they depend on is added by the dependent hash instead.
+        if module.cacheable() {
+          source.source().hash(&mut hasher);
+        }
         (
           *identifier,
           (hasher.digest(&self.options.output.hash_digest), source),
@@ -1479,6 +1914,10 @@ impl Compilation {
   pub fn get_logger(&self, name: impl Into<String>) -> CompilationLogger {
     CompilationLogger::new(name.into(), self.logging.clone())
   }
+
+  pub fn get_infrastructure_logger(&self, name: impl Into<String>) -> InfrastructureLogger {
+    self.infrastructure_logging.get_logger(name)
+  }
 }
 
 pub type CompilationAssets = HashMap<String, CompilationAsset>;
@@ -1539,8 +1978,9 @@ pub struct AssetInfo {
   // pub module_hash:
   /// the value(s) of the content hash used for this asset
   pub content_hash: HashSet<String>,
-  /// when asset was created from a source file (potentially transformed), the original filename relative to compilation context
-  // pub source_filename:
+  /// when asset was created from source files (potentially transformed), the original filenames relative to compilation context,
+  /// identical content of multiple source files is emitted only once with all of them recorded
+  pub source_filename: HashSet<String>,
   /// size in bytes, only set after asset has been emitted
   // pub size: f64,
   /// when asset is only used for development and doesn't count towards user-facing assets
@@ -1554,6 +1994,8 @@ pub struct AssetInfo {
   /// the asset version, emit can be skipped when both filename and version are the same
   /// An empty string means no version, it will always emit
   pub version: String,
+  /// the banners added to the asset by the BannerPlugin, each of them is added only once
+  pub banners: HashSet<String>,
 }
 
 impl AssetInfo {
@@ -1595,12 +2037,41 @@ impl AssetInfo {
     self.chunk_hash.insert(v);
   }
 
+  pub fn set_source_filename(&mut self, v: String) {
+    self.source_filename.insert(v);
+  }
+
   pub fn set_immutable(&mut self, v: bool) {
     self.immutable = v;
   }
+
+  /// Merge the info of the same asset emitted or updated again, the flags are OR-ed and the
+  /// hashes and source filenames are collected from both.
+  pub fn merge_another_asset(&mut self, another: AssetInfo) {
+    self.immutable |= another.immutable;
+    self.minimized |= another.minimized;
+    self.development |= another.development;
+    self.hot_module_replacement |= another.hot_module_replacement;
+    self.chunk_hash.extend(another.chunk_hash);
+    self.content_hash.extend(another.content_hash);
+    self.source_filename.extend(another.source_filename);
+    self.banners.extend(another.banners);
+    self.related.merge_another(another.related);
+    if !another.version.is_empty() {
+      self.version = another.version;
+    }
+  }
 }
 
 #[derive(Debug, Default, Clone)]
 pub struct AssetInfoRelated {
   pub source_map: Option<String>,
 }
+
+impl AssetInfoRelated {
+  pub fn merge_another(&mut self, another: AssetInfoRelated) {
+    if let Some(source_map) = another.source_map {
+      self.source_map = Some(source_map);
+    }
+  }
+}
@@ -127,7 +127,10 @@ where
       self
         .cache
         .set_modified_files(modified_files.iter().cloned().collect::<Vec<_>>());
-      self.plugin_driver.resolver_factory.clear_entries();
+      let tsconfig_changed = self.plugin_driver.resolver_factory.invalidate_entries(
+        &changed_files.iter().map(PathBuf::from).collect(),
+        &removed_files.iter().map(PathBuf::from).collect(),
+      );
 
       let mut new_compilation = Compilation::new(
         self.options.clone(),
@@ -136,6 +139,7 @@ where
         self.resolver_factory.clone(),
         self.loader_resolver_factory.clone(),
         self.cache.clone(),
+        self.infrastructure_logging.clone(),
       );
 
       if let Some(state) = self.options.get_incremental_rebuild_make_state() {
@@ -143,6 +147,8 @@ where
       }
 
       new_compilation.hot_index = self.compilation.hot_index + 1;
+      new_compilation.modified_files = changed_files.iter().map(PathBuf::from).collect();
+      new_compilation.removed_files = removed_files.iter().map(PathBuf::from).collect();
 
       let is_incremental_rebuild_make = self.options.is_incremental_rebuild_make_enabled();
       if is_incremental_rebuild_make {
@@ -195,7 +201,20 @@ where
         .await?;
 
       let setup_make_params = if is_incremental_rebuild_make {
-        MakeParam::ModifiedFiles(modified_files)
+        if tsconfig_changed {
+          // The dependencies of all the modules are resolved again with the changed tsconfig
+          MakeParam::ForceBuildModules(
+            self
+              .compilation
+              .module_graph
+              .modules()
+              .keys()
+              .copied()
+              .collect(),
+          )
+        } else {
+          MakeParam::ModifiedFiles(modified_files)
+        }
       } else {
         MakeParam::ForceBuildDeps(Default::default())
       };
@@ -19,9 +19,16 @@ impl RebuildDepsBuilder {
       match item {
         MakeParam::ModifiedFiles(files) => {
           builder.extend_force_build_modules(module_graph.modules().values().filter_map(|module| {
-            // check has dependencies modified
-            if module_graph.has_dependencies(&module.identifier(), &files) {
-              Some(module.identifier())
+            let identifier = module.identifier();
+            // the normal modules which are not cacheable, e.g. `this.cacheable(false)` of loaders,
+            // are always rebuilt, the others only when their dependencies are modified
+            let is_uncacheable = module.as_normal_module().is_some()
+              && module_graph
+                .module_graph_module_by_identifier(&identifier)
+                .and_then(|mgm| mgm.build_info.as_ref())
+                .map_or(false, |build_info| !build_info.cacheable);
+            if is_uncacheable || module_graph.has_dependencies(&identifier, &files) {
+              Some(identifier)
             } else {
               None
             }
@@ -3,30 +3,45 @@ mod hmr;
 mod make;
 mod queue;
 mod resolver;
+mod tsconfig;
+mod watching;
 
 use std::collections::hash_map::Entry;
-use std::{path::Path, sync::Arc};
+use std::{
+  hash::Hash,
+  io::ErrorKind,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
 
 pub use compilation::*;
+use futures::{stream, StreamExt};
 pub use make::MakeParam;
 pub use queue::*;
 pub use resolver::*;
 use rspack_error::Result;
 use rspack_fs::AsyncWritableFileSystem;
-use rspack_futures::FuturesResults;
+use rspack_hash::RspackHash;
 use rspack_identifier::{IdentifierMap, IdentifierSet};
+use rspack_sources::BoxSource;
 use rustc_hash::FxHashMap as HashMap;
 use swc_core::ecma::atoms::JsWord;
 use tracing::instrument;
+pub(crate) use tsconfig::*;
+pub use watching::*;
 
 use crate::tree_shaking::symbol::{IndirectType, StarSymbolKind, DEFAULT_JS_WORD};
 use crate::tree_shaking::visitor::SymbolRef;
 use crate::{
-  cache::Cache, fast_set, AssetEmittedArgs, CompilerOptions, Logger, Plugin, PluginDriver,
+  cache::Cache, fast_set, AssetEmittedArgs, CompilerOptions, InfrastructureLogCallback,
+  InfrastructureLogger, InfrastructureLogging, Logger, Plugin, PluginDriver, Resolve,
   SharedPluginDriver,
 };
 use crate::{ExportInfo, UsageState};
 
+/// The maximum number of files written or removed at the same time
+const EMIT_ASSETS_CONCURRENCY: usize = 64;
+
 #[derive(Debug)]
 pub struct Compiler<T>
 where
@@ -39,9 +54,10 @@ where
   pub resolver_factory: Arc<ResolverFactory>,
   pub loader_resolver_factory: Arc<ResolverFactory>,
   pub cache: Arc<Cache>,
-  /// emitted asset versions
-  /// the key of HashMap is filename, the value of HashMap is version
-  pub emitted_asset_versions: HashMap<String, String>,
+  pub infrastructure_logging: InfrastructureLogging,
+  /// The files written by the previous emit and the hashes of their content,
+  /// the unchanged ones are not written again
+  pub emitted_asset_hashes: HashMap<String, String>,
 }
 
 impl<T> Compiler<T>
@@ -57,13 +73,16 @@ where
     let options = Arc::new(options);
 
     let resolver_factory = Arc::new(ResolverFactory::new(options.resolve.clone()));
-    let loader_resolver_factory = Arc::new(ResolverFactory::new(options.resolve_loader.clone()));
+    let loader_resolver_factory = Arc::new(ResolverFactory::new(
+      Resolve::loader_defaults().merge(options.resolve_loader.clone()),
+    ));
     let plugin_driver = Arc::new(PluginDriver::new(
       options.clone(),
       plugins,
       resolver_factory.clone(),
     ));
     let cache = Arc::new(Cache::new(options.clone()));
+    let infrastructure_logging = InfrastructureLogging::new(&options.infrastructure_logging);
 
     Self {
       options: options.clone(),
@@ -74,23 +93,47 @@ where
         resolver_factory.clone(),
         loader_resolver_factory.clone(),
         cache.clone(),
+        infrastructure_logging.clone(),
       ),
       output_filesystem,
       plugin_driver,
       resolver_factory,
       loader_resolver_factory,
       cache,
-      emitted_asset_versions: Default::default(),
+      infrastructure_logging,
+      emitted_asset_hashes: Default::default(),
     }
   }
 
+  /// Report the infrastructure logs to the callback instead of stderr.
+  pub fn set_infrastructure_log_callback(&mut self, callback: InfrastructureLogCallback) {
+    self.infrastructure_logging.set_callback(callback);
+    self.compilation.infrastructure_logging = self.infrastructure_logging.clone();
+  }
+
+  pub fn get_infrastructure_logger(&self, name: impl Into<String>) -> InfrastructureLogger {
+    self.infrastructure_logging.get_logger(name)
+  }
+
   pub async fn run(&mut self) -> Result<()> {
     self.build().await?;
     Ok(())
   }
 
-  #[instrument(name = "build", skip_all)]
   pub async fn build(&mut self) -> Result<()> {
+    self
+      .build_with_changes(&Default::default(), &Default::default())
+      .await
+  }
+
+  /// Build from scratch, the files changed since the last compilation are available to the
+  /// plugins as `compilation.modified_files` and `compilation.removed_files`.
+  #[instrument(name = "build", skip_all)]
+  pub async fn build_with_changes(
+    &mut self,
+    changed_files: &std::collections::HashSet<String>,
+    removed_files: &std::collections::HashSet<String>,
+  ) -> Result<()> {
     self.cache.end_idle();
     // TODO: clear the outdate cache entries in resolver,
     // TODO: maybe it's better to use external entries.
@@ -105,8 +148,11 @@ where
         self.resolver_factory.clone(),
         self.loader_resolver_factory.clone(),
         self.cache.clone(),
+        self.infrastructure_logging.clone(),
       ),
     );
+    self.compilation.modified_files = changed_files.iter().map(PathBuf::from).collect();
+    self.compilation.removed_files = removed_files.iter().map(PathBuf::from).collect();
 
     self.plugin_driver.before_compile().await?;
 
@@ -143,10 +189,18 @@ where
       .finish_make(&mut self.compilation)
       .await?;
     logger.time_end(start);
+    self.compilation.ignore_warnings().await?;
 
     let start = logger.time("finish compilation");
     self.compilation.finish(self.plugin_driver.clone()).await?;
     logger.time_end(start);
+
+    let start = logger.time("optimize dependencies");
+    self
+      .plugin_driver
+      .optimize_dependencies(&mut self.compilation)
+      .await?;
+    logger.time_end(start);
     // by default include all module in final chunk
     self.compilation.include_module_ids = self
       .compilation
@@ -255,6 +309,7 @@ where
     self
       .compilation
       .push_batch_diagnostic(plugin_driver_diagnostics);
+    self.compilation.ignore_warnings().await?;
 
     Ok(())
   }
@@ -276,101 +331,153 @@ where
 
   #[instrument(name = "emit_assets", skip_all)]
   pub async fn emit_assets(&mut self) -> Result<()> {
-    if self.options.output.clean {
-      if self.emitted_asset_versions.is_empty() {
-        self
-          .output_filesystem
-          .remove_dir_all(&self.options.output.path)
-          .await?;
+    let clean = &self.options.output.clean;
+    // Clean the output directory at the first emit, the later ones only remove the stale assets
+    if clean.enable && self.emitted_asset_hashes.is_empty() {
+      let output_path = &self.options.output.path;
+      if clean.keep.is_none() {
+        self.output_filesystem.remove_dir_all(output_path).await?;
       } else {
-        // clean unused file
-        let assets = self.compilation.assets();
-        let _ = self
-          .emitted_asset_versions
-          .iter()
-          .filter_map(|(filename, _version)| {
-            if !assets.contains_key(filename) {
-              let file_path = Path::new(&self.options.output.path).join(filename);
-              Some(self.output_filesystem.remove_file(file_path))
-            } else {
-              None
-            }
-          })
-          .collect::<FuturesResults<_>>();
+        let files = self.output_filesystem.read_dir_all(output_path).await?;
+        let output_filesystem = &self.output_filesystem;
+        stream::iter(files.iter().filter(|filename| !clean.is_kept(filename)))
+          .map(|filename| remove_file_if_exists(output_filesystem, output_path.join(filename)))
+          .buffer_unordered(EMIT_ASSETS_CONCURRENCY)
+          .collect::<Vec<Result<()>>>()
+          .await
+          .into_iter()
+          .collect::<Result<()>>()?;
       }
     }
 
     self.plugin_driver.emit(&mut self.compilation).await?;
 
-    let mut new_emitted_asset_versions = HashMap::default();
-    let results = self
+    let mut new_emitted_asset_hashes = HashMap::default();
+    let mut skipped = 0;
+    let assets = self
       .compilation
       .assets()
       .iter()
       .filter_map(|(filename, asset)| {
-        // collect version info to new_emitted_asset_versions
-        if self.options.is_incremental_rebuild_emit_asset_enabled() {
-          new_emitted_asset_versions.insert(filename.to_string(), asset.info.version.clone());
+        let source = asset.get_source()?;
+        let filename = filename
+          .split_once('?')
+          .map(|(filename, _query)| filename)
+          .unwrap_or(filename);
+        let hash = self.asset_hash(asset);
+        let unchanged = self.options.is_incremental_rebuild_emit_asset_enabled()
+          && self.emitted_asset_hashes.get(filename) == Some(&hash);
+        new_emitted_asset_hashes.insert(filename.to_string(), hash);
+        if unchanged {
+          skipped += 1;
+          return None;
         }
-
-        if let Some(old_version) = self.emitted_asset_versions.get(filename) {
-          if old_version.as_str() == asset.info.version && !old_version.is_empty() {
-            return None;
-          }
-        }
-
-        Some(self.emit_asset(&self.options.output.path, filename, asset))
+        Some((filename, source))
       })
-      .collect::<FuturesResults<_>>();
+      .collect::<Vec<_>>();
+    let written = assets.len();
 
-    self.emitted_asset_versions = new_emitted_asset_versions;
+    // The files emitted by the previous compilation but not by this one
+    let stale_files = if clean.enable {
+      self
+        .emitted_asset_hashes
+        .keys()
+        .filter(|filename| {
+          !new_emitted_asset_hashes.contains_key(filename.as_str()) && !clean.is_kept(filename)
+        })
+        .collect::<Vec<_>>()
+    } else {
+      vec![]
+    };
+    let deleted = stale_files.len();
+
+    let output_path = &self.options.output.path;
+    let output_filesystem = &self.output_filesystem;
+    let mut results = stream::iter(stale_files)
+      .map(|filename| remove_file_if_exists(output_filesystem, output_path.join(filename)))
+      .buffer_unordered(EMIT_ASSETS_CONCURRENCY)
+      .collect::<Vec<Result<()>>>()
+      .await;
+    let this = &*self;
+    let write_results = stream::iter(assets)
+      .map(|(filename, source)| async move {
+        (
+          filename,
+          this.emit_asset(output_path, filename, source).await,
+        )
+      })
+      .buffer_unordered(EMIT_ASSETS_CONCURRENCY)
+      .collect::<Vec<_>>()
+      .await;
+    for (filename, result) in write_results {
+      // Write the file again in the next emit
+      if result.is_err() {
+        new_emitted_asset_hashes.remove(filename);
+      }
+      results.push(result);
+    }
+    self.emitted_asset_hashes = new_emitted_asset_hashes;
     // return first error
-    for item in results.into_inner() {
+    for item in results {
       item?;
     }
 
+    self
+      .compilation
+      .get_logger("rspack.Compiler")
+      .log(format!(
+        "{written} assets written, {skipped} unchanged assets skipped, {deleted} stale assets deleted"
+      ));
+
     self.plugin_driver.after_emit(&mut self.compilation).await
   }
 
-  async fn emit_asset(
-    &self,
-    output_path: &Path,
-    filename: &str,
-    asset: &CompilationAsset,
-  ) -> Result<()> {
+  /// The hash of the content of an asset. The content hash in the asset info isn't reused, it
+  /// may be kept by a plugin modifying the source in `processAssets`.
+  fn asset_hash(&self, asset: &CompilationAsset) -> String {
+    let mut hasher = RspackHash::from(&self.options.output);
     if let Some(source) = asset.get_source() {
-      let filename = filename
-        .split_once('?')
-        .map(|(filename, _query)| filename)
-        .unwrap_or(filename);
-      let file_path = Path::new(&output_path).join(filename);
-      self
-        .output_filesystem
-        .create_dir_all(
-          file_path
-            .parent()
-            .unwrap_or_else(|| panic!("The parent of {} can't found", file_path.display())),
-        )
-        .await?;
-      self
-        .output_filesystem
-        .write(&file_path, source.buffer())
-        .await?;
-
-      self.compilation.emitted_assets.insert(filename.to_string());
-
-      let asset_emitted_args = AssetEmittedArgs {
-        filename,
-        output_path,
-        source: source.clone(),
-        target_path: file_path.as_path(),
-        compilation: &self.compilation,
-      };
-      self
-        .plugin_driver
-        .asset_emitted(&asset_emitted_args)
-        .await?;
+      source.buffer().hash(&mut hasher);
     }
-    Ok(())
+    hasher
+      .digest(&self.options.output.hash_digest)
+      .encoded()
+      .to_string()
+  }
+
+  async fn emit_asset(&self, output_path: &Path, filename: &str, source: &BoxSource) -> Result<()> {
+    let file_path = Path::new(&output_path).join(filename);
+    self
+      .output_filesystem
+      .create_dir_all(
+        file_path
+          .parent()
+          .unwrap_or_else(|| panic!("The parent of {} can't found", file_path.display())),
+      )
+      .await?;
+    self
+      .output_filesystem
+      .write(&file_path, source.buffer())
+      .await?;
+
+    self.compilation.emitted_assets.insert(filename.to_string());
+
+    let asset_emitted_args = AssetEmittedArgs {
+      filename,
+      output_path,
+      source: source.clone(),
+      target_path: file_path.as_path(),
+      compilation: &self.compilation,
+    };
+    self.plugin_driver.asset_emitted(&asset_emitted_args).await
+  }
+}
+
+/// Remove a file of the output directory, a file which is already removed, e.g. by the user,
+/// is ignored
+async fn remove_file_if_exists<T: AsyncWritableFileSystem>(fs: &T, file: PathBuf) -> Result<()> {
+  match fs.remove_file(file).await {
+    Err(rspack_fs::Error::Io(err)) if err.kind() == ErrorKind::NotFound => Ok(()),
+    result => result.map_err(Into::into),
   }
 }
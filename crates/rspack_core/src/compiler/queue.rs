@@ -45,7 +45,6 @@ pub struct FactorizeTask {
 pub struct FactorizeTaskResult {
   pub original_module_identifier: Option<ModuleIdentifier>,
   pub factory_result: ModuleFactoryResult,
-  pub module_graph_module: Box<ModuleGraphModule>,
   pub dependencies: Vec<BoxDependency>,
   pub diagnostics: Vec<Diagnostic>,
   pub is_entry: bool,
@@ -73,7 +72,7 @@ impl WorkerTask for FactorizeTask {
       DependencyType::ImportContext
       | DependencyType::CommonJSRequireContext
       | DependencyType::RequireContext => {
-        let factory = ContextModuleFactory::new(self.plugin_driver, self.cache);
+        let factory = ContextModuleFactory::new(self.plugin_driver, self.cache, self.issuer);
         factory
           .create(ModuleFactoryCreateData {
             resolve_options: self.resolve_options,
@@ -108,8 +107,6 @@ impl WorkerTask for FactorizeTask {
       }
     };
 
-    let mgm = ModuleGraphModule::new(result.module.identifier(), *result.module.module_type());
-
     if let Some(current_profile) = &self.current_profile {
       current_profile.mark_factory_end();
     }
@@ -118,7 +115,6 @@ impl WorkerTask for FactorizeTask {
       is_entry: self.is_entry,
       original_module_identifier: self.original_module_identifier,
       factory_result: result,
-      module_graph_module: Box::new(mgm),
       dependencies: self.dependencies,
       diagnostics,
       current_profile: self.current_profile,
@@ -260,9 +256,12 @@ impl WorkerTask for BuildTask {
             compiler_context: CompilerContext {
               options: compiler_options.clone(),
               resolver_factory: resolver_factory.clone(),
+              module_settings: Default::default(),
             },
             plugin_driver: plugin_driver.clone(),
             compiler_options: &compiler_options,
+            snapshot_manager: cache.snapshot_manager.clone(),
+            profile: self.current_profile.as_deref(),
           })
           .await;
 
@@ -1,14 +1,19 @@
 use std::{
-  hash::BuildHasherDefault,
+  hash::{BuildHasherDefault, Hash, Hasher},
   path::{Path, PathBuf},
-  sync::Arc,
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+  },
 };
 
 use dashmap::DashMap;
-use rustc_hash::FxHasher;
+use rustc_hash::{FxHashSet as HashSet, FxHasher};
+use sugar_path::SugarPath;
 
+use super::tsconfig::{load_tsconfig, normalize_tsconfig_path, Tsconfig};
 use crate::DependencyType;
-use crate::{DependencyCategory, Resolve};
+use crate::{DependencyCategory, Resolve, TsconfigOptions, TsconfigReferences};
 
 pub type ResolveResult = nodejs_resolver::ResolveResult<nodejs_resolver::Resource>;
 
@@ -17,7 +22,73 @@ pub struct ResolverFactory {
   cache: Arc<nodejs_resolver::Cache>,
   base_options: Resolve,
   pub resolver: Resolver,
-  resolvers: DashMap<ResolveOptionsWithDependencyType, Arc<Resolver>, BuildHasherDefault<FxHasher>>,
+  resolvers: DashMap<ResolverKey, Arc<Resolver>, BuildHasherDefault<FxHasher>>,
+  /// Resolved requests which are reused across compilations until one of their
+  /// dependencies is changed.
+  requests: DashMap<ResolveRequestKey, CachedResolve, BuildHasherDefault<FxHasher>>,
+  request_cache_hits: AtomicUsize,
+  /// The loaded tsconfig files, they are reloaded once one of their files is changed.
+  tsconfigs: DashMap<PathBuf, Arc<Result<Tsconfig, String>>, BuildHasherDefault<FxHasher>>,
+}
+
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+pub struct ResolveRequestKey {
+  options_hash: u64,
+  context: PathBuf,
+  request: String,
+}
+
+impl ResolveRequestKey {
+  pub fn new(resolver: &Resolver, context: &Path, request: &str) -> Self {
+    Self {
+      options_hash: resolver.options_hash,
+      context: context.to_path_buf(),
+      request: request.to_string(),
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct CachedResolve {
+  pub result: ResolveResult,
+  pub dependencies: ResolveDependencies,
+}
+
+impl CachedResolve {
+  fn resource(&self) -> Option<&Path> {
+    match &self.result {
+      ResolveResult::Resource(resource) => Some(&resource.path),
+      ResolveResult::Ignored => None,
+    }
+  }
+
+  /// Whether the change of `file` may change the resolution. The content of the resolved
+  /// resource doesn't matter unless it's removed.
+  fn is_affected_by(&self, file: &Path, removed: bool) -> bool {
+    if !removed && self.resource() == Some(file) {
+      return false;
+    }
+    let dependencies = &self.dependencies;
+    dependencies.file_dependencies.contains(file)
+      || dependencies.missing_dependencies.contains(file)
+      || file.parent().map_or(false, |parent| {
+        dependencies.context_dependencies.contains(parent)
+      })
+  }
+}
+
+/// The files and directories the resolution of a request depends on.
+#[derive(Debug, Clone, Default)]
+pub struct ResolveDependencies {
+  /// The resolved resource and its description file
+  pub file_dependencies: HashSet<PathBuf>,
+  /// The resolved directory of a context request
+  pub context_dependencies: HashSet<PathBuf>,
+  /// The candidates tried before the resolved resource, which are resolved instead once added
+  pub missing_dependencies: HashSet<PathBuf>,
+  /// `false` if the candidates are unknown, e.g. the request is mapped by an alias, a tsconfig
+  /// or a fallback, and the request is resolved again once any file is added or removed.
+  pub exhaustive: bool,
 }
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone)]
@@ -28,6 +99,16 @@ pub struct ResolveOptionsWithDependencyType {
   pub dependency_category: DependencyCategory,
 }
 
+/// The options a resolver is created with, the dependency types of the same category are
+/// resolved identically, and the category only matters when `byDependency` has the options of it.
+#[derive(Debug, Hash, Eq, PartialEq)]
+struct ResolverKey {
+  resolve_options: Option<Box<Resolve>>,
+  resolve_to_context: bool,
+  /// `None` if there are no `byDependency` options of the category
+  dependency_category: Option<DependencyCategory>,
+}
+
 impl Default for ResolverFactory {
   fn default() -> Self {
     Self::new(Default::default())
@@ -36,60 +117,522 @@ impl Default for ResolverFactory {
 
 impl ResolverFactory {
   pub fn clear_entries(&self) {
-    self.resolver.0.clear_entries();
+    self.resolver.inner.clear_entries();
+    self.requests.clear();
+    self.clear_tsconfigs();
+  }
+
+  /// Clear the entries of the resolver but keep the resolved requests if the changes
+  /// can't affect their resolution.
+  ///
+  /// A request is resolved again if one of its dependencies is changed, e.g. a candidate tried
+  /// before the resolved resource is added. The requests without the exhaustive dependencies
+  /// are only kept if the changed files are the resolved resources, which are only changed in
+  /// content.
+  ///
+  /// Returns `true` if one of the tsconfig files is changed.
+  pub fn invalidate_entries(
+    &self,
+    changed_files: &HashSet<PathBuf>,
+    removed_files: &HashSet<PathBuf>,
+  ) -> bool {
+    self.resolver.inner.clear_entries();
+    if self
+      .tsconfig_dependencies()
+      .iter()
+      .any(|file| changed_files.contains(file) || removed_files.contains(file))
+    {
+      self.requests.clear();
+      self.clear_tsconfigs();
+      return true;
+    }
+    let structural_change = !removed_files.is_empty() || !self.are_resources(changed_files);
+    self.requests.retain(|_, cached| {
+      if !cached.dependencies.exhaustive {
+        return !structural_change;
+      }
+      !changed_files
+        .iter()
+        .map(|file| (file, false))
+        .chain(removed_files.iter().map(|file| (file, true)))
+        .any(|(file, removed)| cached.is_affected_by(file, removed))
+    });
+    false
+  }
+
+  /// Whether all the files are the resolved resources and none of them is a description file.
+  fn are_resources(&self, files: &HashSet<PathBuf>) -> bool {
+    if files.is_empty() {
+      return true;
+    }
+    if files
+      .iter()
+      .any(|file| file.file_name().map_or(true, |name| name == "package.json"))
+    {
+      return false;
+    }
+    let mut unknown_files: HashSet<&Path> = files.iter().map(|file| file.as_path()).collect();
+    for entry in self.requests.iter() {
+      if let Some(resource) = entry.value().resource() {
+        unknown_files.remove(resource);
+      }
+    }
+    unknown_files.is_empty()
+  }
+
+  fn clear_tsconfigs(&self) {
+    if !self.tsconfigs.is_empty() {
+      self.tsconfigs.clear();
+      // The resolvers may keep the parsed tsconfig
+      self.resolvers.clear();
+    }
+  }
+
+  /// The tsconfig files and the configs they extend, which are loaded by the resolvers.
+  pub fn tsconfig_dependencies(&self) -> Vec<PathBuf> {
+    let mut files = vec![];
+    for entry in self.tsconfigs.iter() {
+      match entry.value().as_ref() {
+        Ok(tsconfig) => files.extend(tsconfig.files.iter().cloned()),
+        // Watch the file which fails to load, so that fixing it triggers a rebuild
+        Err(_) => files.push(entry.key().clone()),
+      }
+    }
+    files
+  }
+
+  fn load_tsconfig(&self, path: &Path) -> Arc<Result<Tsconfig, String>> {
+    self
+      .tsconfigs
+      .entry(path.to_path_buf())
+      .or_insert_with(|| Arc::new(load_tsconfig(path)))
+      .clone()
+  }
+
+  pub fn get_cached_request(&self, key: &ResolveRequestKey) -> Option<CachedResolve> {
+    let cached = self.requests.get(key)?.clone();
+    self.request_cache_hits.fetch_add(1, Ordering::Relaxed);
+    Some(cached)
+  }
+
+  pub fn cache_request(&self, key: ResolveRequestKey, cached: CachedResolve) {
+    self.requests.insert(key, cached);
+  }
+
+  /// Returns the cache hits of the resolved requests since the last call.
+  pub fn take_request_cache_hits(&self) -> usize {
+    self.request_cache_hits.swap(0, Ordering::Relaxed)
   }
 
   pub fn new(base_options: Resolve) -> Self {
     let cache = Arc::new(nodejs_resolver::Cache::default());
-    let resolver = Resolver(nodejs_resolver::Resolver::new(
-      base_options
-        .clone()
-        .to_inner_options(cache.clone(), false, DependencyCategory::Unknown),
-    ));
+    let resolver = Resolver {
+      inner: nodejs_resolver::Resolver::new(base_options.clone().to_inner_options(
+        cache.clone(),
+        false,
+        DependencyCategory::Unknown,
+      )),
+      options_hash: 0,
+      tsconfig: base_options.tsconfig.clone(),
+    };
     Self {
       cache,
       base_options,
       resolvers: Default::default(),
       resolver,
+      requests: Default::default(),
+      request_cache_hits: Default::default(),
+      tsconfigs: Default::default(),
     }
   }
 
   pub fn get(&self, options: ResolveOptionsWithDependencyType) -> Arc<Resolver> {
-    if let Some(r) = self.resolvers.get(&options) {
+    let dependency_category = options.dependency_category;
+    let key = self.resolver_key(options);
+    if let Some(r) = self.resolvers.get(&key) {
       r.clone()
     } else {
       let base_options = self.base_options.clone();
-      let merged_options = match &options.resolve_options {
+      let merged_options = match &key.resolve_options {
         Some(o) => base_options.merge(*o.clone()),
         None => base_options,
       };
+      let tsconfig = merged_options.tsconfig.clone();
       let normalized = merged_options.to_inner_options(
         self.cache.clone(),
-        options.resolve_to_context,
-        options.dependency_category,
+        key.resolve_to_context,
+        dependency_category,
       );
-      let resolver = Arc::new(Resolver(nodejs_resolver::Resolver::new(normalized)));
-      self.resolvers.insert(options, resolver.clone());
+      let mut hasher = FxHasher::default();
+      key.hash(&mut hasher);
+      let resolver = Arc::new(Resolver {
+        inner: nodejs_resolver::Resolver::new(normalized),
+        options_hash: hasher.finish(),
+        tsconfig,
+      });
+      self.resolvers.insert(key, resolver.clone());
       resolver
     }
   }
+
+  fn resolver_key(&self, options: ResolveOptionsWithDependencyType) -> ResolverKey {
+    let category = options.dependency_category;
+    let has_by_dependency = self.base_options.has_by_dependency(&category)
+      || options
+        .resolve_options
+        .as_ref()
+        .map_or(false, |resolve_options| {
+          resolve_options.has_by_dependency(&category)
+        });
+    ResolverKey {
+      resolve_options: options.resolve_options,
+      resolve_to_context: options.resolve_to_context,
+      dependency_category: has_by_dependency.then_some(category),
+    }
+  }
+
+  /// Same as [ResolverFactory::get], but the requests from a referenced project of the
+  /// tsconfig are resolved with the tsconfig of that project.
+  ///
+  /// Returns the resolver with the tsconfig files it depends on, or the error if
+  /// one of the tsconfig files is missing or malformed.
+  pub fn get_by_context(
+    &self,
+    mut options: ResolveOptionsWithDependencyType,
+    context: &Path,
+  ) -> Result<(Arc<Resolver>, Vec<PathBuf>), String> {
+    let resolver = self.get(options.clone());
+    let Some(tsconfig_options) = resolver.tsconfig.clone() else {
+      return Ok((resolver, vec![]));
+    };
+    let config_file = normalize_tsconfig_path(&tsconfig_options.config_file);
+    let tsconfig = self.load_tsconfig(&config_file);
+    let tsconfig = tsconfig.as_ref().as_ref().map_err(Clone::clone)?;
+    let mut files = tsconfig.files.clone();
+
+    let references = match &tsconfig_options.references {
+      TsconfigReferences::Disabled => vec![],
+      TsconfigReferences::Auto => tsconfig.references.clone(),
+      TsconfigReferences::Paths(paths) => paths
+        .iter()
+        .map(|path| normalize_tsconfig_path(path))
+        .collect(),
+    };
+    for reference in references {
+      let project = reference.parent().unwrap_or(&reference);
+      if !context.starts_with(project) {
+        continue;
+      }
+      let referenced = self.load_tsconfig(&reference);
+      let referenced = referenced.as_ref().as_ref().map_err(Clone::clone)?;
+      files.extend(referenced.files.iter().cloned());
+      let mut resolve_options = options
+        .resolve_options
+        .map(|resolve_options| *resolve_options)
+        .unwrap_or_default();
+      resolve_options.tsconfig = Some(TsconfigOptions {
+        config_file: reference,
+        references: TsconfigReferences::Disabled,
+      });
+      options.resolve_options = Some(Box::new(resolve_options));
+      return Ok((self.get(options), files));
+    }
+    Ok((resolver, files))
+  }
 }
 
 #[derive(Debug)]
-pub struct Resolver(pub(crate) nodejs_resolver::Resolver);
+pub struct Resolver {
+  pub(crate) inner: nodejs_resolver::Resolver,
+  /// The hash of the options, which is a part of [ResolveRequestKey].
+  options_hash: u64,
+  /// The tsconfig of the resolver, used to look up the referenced projects.
+  tsconfig: Option<TsconfigOptions>,
+}
 
 impl Resolver {
   pub fn resolve(&self, path: &Path, request: &str) -> nodejs_resolver::RResult<ResolveResult> {
-    self.0.resolve(path, request)
+    self.inner.resolve(path, request)
   }
 
   pub fn options(&self) -> &nodejs_resolver::Options {
-    &self.0.options
+    &self.inner.options
+  }
+
+  /// The dependencies of the resolution of `request` in `context`, the candidates are
+  /// derived from the options in the order they are tried by the resolver.
+  pub fn dependencies(
+    &self,
+    context: &Path,
+    request: &str,
+    result: &nodejs_resolver::RResult<ResolveResult>,
+  ) -> ResolveDependencies {
+    let options = self.options();
+    let mut dependencies = ResolveDependencies::default();
+    let resource = match result {
+      Ok(ResolveResult::Resource(resource)) => Some(resource),
+      _ => None,
+    };
+    if let Some(resource) = resource {
+      dependencies.file_dependencies.insert(resource.path.clone());
+      if let Some(description) = &resource.description {
+        dependencies
+          .file_dependencies
+          .insert(description.dir().as_ref().join(&options.description_file));
+      }
+      if options.resolve_to_context {
+        dependencies
+          .context_dependencies
+          .insert(resource.path.clone());
+      }
+    }
+
+    let path = request.split(['?', '#']).next().unwrap_or_default();
+    let is_aliased = options
+      .alias
+      .iter()
+      .any(|(key, _)| match key.strip_suffix('$') {
+        Some(exact) => path == exact,
+        None => {
+          path == key
+            || path
+              .strip_prefix(key.as_str())
+              .map_or(false, |rest| rest.starts_with('/'))
+        }
+      });
+    if path.is_empty() || is_aliased {
+      return dependencies;
+    }
+    let targets = if path.starts_with('.') || Path::new(path).is_absolute() {
+      vec![context.join(path).normalize()]
+    } else if options.tsconfig.is_some() {
+      // The bare requests may be mapped by the paths of the tsconfig
+      return dependencies;
+    } else {
+      let mut targets = self
+        .module_directories(context)
+        .into_iter()
+        .map(|directory| directory.join(path))
+        .collect::<Vec<_>>();
+      if options.prefer_relative {
+        targets.insert(0, context.join(path).normalize());
+      }
+      targets
+    };
+
+    let Some(resolved) = resource.map(|resource| resource.path.as_path()) else {
+      return dependencies;
+    };
+    for target in targets {
+      for candidate in self.candidates(&target) {
+        if candidate == resolved {
+          dependencies.exhaustive = true;
+          return dependencies;
+        }
+        dependencies.missing_dependencies.insert(candidate);
+      }
+      // Resolved by the description file, e.g. the `main` or `exports` of a package
+      if resolved.starts_with(&target) {
+        dependencies.exhaustive = true;
+        return dependencies;
+      }
+    }
+    // Resolved by a fallback or a symlink
+    dependencies
   }
 
-  pub fn dependencies(&self) -> (Vec<PathBuf>, Vec<PathBuf>) {
-    // There are some issues with this method
-    // self.0.get_dependency_from_entry()
-    (vec![], vec![])
+  /// The directories the bare requests are looked up in. The consecutive relative names of
+  /// `resolve.modules` are looked up together in each ancestor of `context`.
+  fn module_directories(&self, context: &Path) -> Vec<PathBuf> {
+    let modules = &self.options().modules;
+    let mut directories = vec![];
+    let mut index = 0;
+    while index < modules.len() {
+      if Path::new(&modules[index]).is_absolute() {
+        directories.push(PathBuf::from(&modules[index]));
+        index += 1;
+        continue;
+      }
+      let end = modules[index..]
+        .iter()
+        .position(|module| Path::new(module).is_absolute())
+        .map_or(modules.len(), |position| index + position);
+      for ancestor in context.ancestors() {
+        directories.extend(modules[index..end].iter().map(|name| ancestor.join(name)));
+      }
+      index = end;
+    }
+    directories
+  }
+
+  /// The files `target` may be resolved to, as a file with or without an extension, or as a
+  /// directory with a description file or a main file.
+  fn candidates(&self, target: &Path) -> Vec<PathBuf> {
+    let options = self.options();
+    let mut candidates = vec![target.to_path_buf()];
+    if !options.fully_specified {
+      candidates.extend(options.extensions.iter().map(|extension| {
+        let mut candidate = target.as_os_str().to_os_string();
+        candidate.push(extension);
+        PathBuf::from(candidate)
+      }));
+    }
+    candidates.push(target.join(&options.description_file));
+    for main_file in &options.main_files {
+      candidates.extend(
+        options
+          .extensions
+          .iter()
+          .map(|extension| target.join(format!("{main_file}{extension}"))),
+      );
+    }
+    candidates
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::ByDependency;
+
+  fn options(
+    dependency_type: DependencyType,
+    dependency_category: DependencyCategory,
+  ) -> ResolveOptionsWithDependencyType {
+    ResolveOptionsWithDependencyType {
+      resolve_options: None,
+      resolve_to_context: false,
+      dependency_type,
+      dependency_category,
+    }
+  }
+
+  #[test]
+  fn share_resolver_across_dependency_types() {
+    let factory = ResolverFactory::default();
+    let import = factory.get(options(DependencyType::EsmImport, DependencyCategory::Esm));
+    let export = factory.get(options(DependencyType::EsmExport, DependencyCategory::Esm));
+    let dynamic = factory.get(options(
+      DependencyType::DynamicImport,
+      DependencyCategory::Esm,
+    ));
+    assert!(Arc::ptr_eq(&import, &export));
+    assert!(Arc::ptr_eq(&import, &dynamic));
+    // Without `byDependency`, the categories are resolved identically as well
+    let require = factory.get(options(
+      DependencyType::CjsRequire,
+      DependencyCategory::CommonJS,
+    ));
+    assert!(Arc::ptr_eq(&import, &require));
+  }
+
+  #[test]
+  fn split_resolver_by_dependency() {
+    let factory = ResolverFactory::new(Resolve {
+      by_dependency: Some(ByDependency::from_iter([(
+        DependencyCategory::Esm,
+        Resolve {
+          fully_specified: Some(true),
+          ..Default::default()
+        },
+      )])),
+      ..Default::default()
+    });
+    let import = factory.get(options(DependencyType::EsmImport, DependencyCategory::Esm));
+    let export = factory.get(options(DependencyType::EsmExport, DependencyCategory::Esm));
+    let require = factory.get(options(
+      DependencyType::CjsRequire,
+      DependencyCategory::CommonJS,
+    ));
+    assert!(Arc::ptr_eq(&import, &export));
+    assert!(!Arc::ptr_eq(&import, &require));
+    assert!(import.options().fully_specified);
+    assert!(!require.options().fully_specified);
+  }
+
+  #[test]
+  fn reuse_resolved_requests_until_invalidated() {
+    let factory = ResolverFactory::default();
+    let context = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let resolver = factory.get(options(DependencyType::EsmImport, DependencyCategory::Esm));
+    let key = ResolveRequestKey::new(&resolver, &context, "./lib.rs");
+    let result = resolver.resolve(&context, "./lib.rs");
+    let dependencies = resolver.dependencies(&context, "./lib.rs", &result);
+    assert!(dependencies.exhaustive);
+    assert!(dependencies
+      .file_dependencies
+      .contains(&context.join("lib.rs")));
+    let result = result.expect("should resolve");
+    factory.cache_request(
+      key.clone(),
+      CachedResolve {
+        result,
+        dependencies,
+      },
+    );
+
+    // The content of the resolved file doesn't change the resolution
+    factory.invalidate_entries(
+      &HashSet::from_iter([context.join("lib.rs")]),
+      &Default::default(),
+    );
+    assert!(factory.get_cached_request(&key).is_some());
+    assert_eq!(factory.take_request_cache_hits(), 1);
+
+    // The resolved file is removed
+    factory.invalidate_entries(
+      &Default::default(),
+      &HashSet::from_iter([context.join("lib.rs")]),
+    );
+    assert!(factory.get_cached_request(&key).is_none());
+    assert_eq!(factory.take_request_cache_hits(), 0);
+  }
+
+  #[test]
+  fn invalidate_requests_by_module_directories() {
+    let dir = std::env::temp_dir().join(format!("rspack-resolver-{}", std::process::id()));
+    let (first, second) = (dir.join("first"), dir.join("second"));
+    std::fs::create_dir_all(&second).expect("should create dir");
+    std::fs::write(second.join("value.js"), "").expect("should write file");
+    let factory = ResolverFactory::new(Resolve {
+      modules: Some(vec![
+        first.to_string_lossy().to_string(),
+        second.to_string_lossy().to_string(),
+      ]),
+      ..Default::default()
+    });
+    let resolver = factory.get(options(
+      DependencyType::CjsRequire,
+      DependencyCategory::CommonJS,
+    ));
+    let key = ResolveRequestKey::new(&resolver, &dir, "value");
+    let result = resolver.resolve(&dir, "value");
+    let dependencies = resolver.dependencies(&dir, "value", &result);
+    assert!(dependencies.exhaustive);
+    assert!(dependencies
+      .missing_dependencies
+      .contains(&first.join("value.js")));
+    factory.cache_request(
+      key.clone(),
+      CachedResolve {
+        result: result.expect("should resolve"),
+        dependencies,
+      },
+    );
+
+    // A file added elsewhere doesn't change the resolution
+    factory.invalidate_entries(
+      &HashSet::from_iter([dir.join("other.js")]),
+      &Default::default(),
+    );
+    assert!(factory.get_cached_request(&key).is_some());
+
+    // The file in the first module directory is resolved instead
+    factory.invalidate_entries(
+      &HashSet::from_iter([first.join("value.js")]),
+      &Default::default(),
+    );
+    assert!(factory.get_cached_request(&key).is_none());
+    std::fs::remove_dir_all(&dir).expect("should remove dir");
   }
 }
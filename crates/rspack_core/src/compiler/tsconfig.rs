@@ -0,0 +1,98 @@
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use sugar_path::SugarPath;
+
+/// The files of a tsconfig which affect the resolution, `paths` and `baseUrl` are handled by the resolver.
+#[derive(Debug, Default)]
+pub(crate) struct Tsconfig {
+  /// The tsconfig file and the configs it extends.
+  pub files: Vec<PathBuf>,
+  /// The tsconfig files of the referenced projects.
+  pub references: Vec<PathBuf>,
+}
+
+/// Normalize the path of a tsconfig, the directory of a project points to its `tsconfig.json`.
+pub(crate) fn normalize_tsconfig_path(path: &Path) -> PathBuf {
+  if path
+    .extension()
+    .map_or(false, |extension| extension == "json")
+  {
+    path.to_path_buf()
+  } else {
+    path.join("tsconfig.json")
+  }
+}
+
+/// Load the tsconfig and follow its `extends` chain.
+pub(crate) fn load_tsconfig(path: &Path) -> Result<Tsconfig, String> {
+  let mut tsconfig = Tsconfig::default();
+  let value = load_extends(path, &mut tsconfig.files)?;
+  let dir = path.parent().unwrap_or(path);
+  tsconfig.references = value
+    .get("references")
+    .and_then(|references| references.as_array())
+    .into_iter()
+    .flatten()
+    .filter_map(|reference| reference.get("path")?.as_str())
+    .map(|reference| normalize_tsconfig_path(&dir.join(reference).absolutize()))
+    .collect();
+  Ok(tsconfig)
+}
+
+fn load_extends(path: &Path, files: &mut Vec<PathBuf>) -> Result<Value, String> {
+  if files.iter().any(|file| file == path) {
+    return Err(format!(
+      "The tsconfig {} extends itself circularly",
+      path.display()
+    ));
+  }
+  files.push(path.to_path_buf());
+
+  let content = std::fs::read_to_string(path)
+    .map_err(|error| format!("Can't read the tsconfig {}: {error}", path.display()))?;
+  let value = jsonc_parser::parse_to_serde_value(&content, &Default::default())
+    .map_err(|error| format!("Failed to parse the tsconfig {}: {error}", path.display()))?
+    .unwrap_or_default();
+
+  let dir = path.parent().unwrap_or(path);
+  let extends = match value.get("extends") {
+    Some(Value::String(extends)) => vec![extends.as_str()],
+    Some(Value::Array(extends)) => extends.iter().filter_map(|item| item.as_str()).collect(),
+    _ => vec![],
+  };
+  for extends in extends {
+    let extended = resolve_extends(dir, extends).ok_or_else(|| {
+      format!(
+        "Can't find the tsconfig {extends} extended by {}",
+        path.display()
+      )
+    })?;
+    load_extends(&extended, files)?;
+  }
+  Ok(value)
+}
+
+/// Resolve `extends` like TypeScript, a relative path or a package in `node_modules`.
+fn resolve_extends(dir: &Path, extends: &str) -> Option<PathBuf> {
+  let with_json = |path: PathBuf| {
+    if path.is_file() {
+      Some(path)
+    } else {
+      let mut with_extension = path.into_os_string();
+      with_extension.push(".json");
+      Some(PathBuf::from(with_extension)).filter(|path| path.is_file())
+    }
+  };
+  if extends.starts_with("./") || extends.starts_with("../") || Path::new(extends).is_absolute() {
+    return with_json(dir.join(extends).absolutize());
+  }
+  dir.ancestors().find_map(|ancestor| {
+    let path = ancestor.join("node_modules").join(extends);
+    if path.is_dir() {
+      with_json(path.join("tsconfig.json"))
+    } else {
+      with_json(path)
+    }
+  })
+}
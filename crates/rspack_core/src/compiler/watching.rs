@@ -0,0 +1,567 @@
+use std::{
+  collections::HashSet,
+  path::{Path, PathBuf},
+  sync::{Mutex, MutexGuard},
+  time::{Duration, SystemTime},
+};
+
+use futures::future::join_all;
+use rspack_error::Result;
+use rspack_fs::AsyncWritableFileSystem;
+use rustc_hash::FxHashMap as HashMap;
+use tokio::sync::{futures::Notified, Notify};
+
+use crate::{Compilation, Compiler};
+
+/// See https://webpack.js.org/configuration/watch/#watchoptions
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+  /// Collect the file events for this duration before compiling, every new event restarts it
+  pub aggregate_timeout: Duration,
+  /// Check the modification time of the watched files at this interval. The file events are
+  /// reported by the host with [Watching::notify] if it's `None`, which is the native watcher.
+  pub poll: Option<Duration>,
+  /// Glob patterns of the paths that never trigger a compilation, e.g. `**/node_modules/**`
+  pub ignored: Vec<String>,
+}
+
+impl Default for WatchOptions {
+  fn default() -> Self {
+    Self {
+      aggregate_timeout: Duration::from_millis(20),
+      poll: None,
+      ignored: vec![],
+    }
+  }
+}
+
+impl WatchOptions {
+  pub fn is_ignored(&self, path: &str) -> bool {
+    self
+      .ignored
+      .iter()
+      .any(|pattern| glob_match::glob_match(pattern, path))
+  }
+}
+
+/// How the aggregated changes are compiled, decided by [WatchingHooks::watch_run]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchRunResult {
+  /// Compile from scratch with [Compiler::build_with_changes]
+  Build,
+  /// Compile the changes incrementally with [Compiler::rebuild]
+  Rebuild,
+  /// Don't compile now, the changes are kept until the next [Watching::invalidate]
+  Blocked,
+}
+
+/// The hooks of the host around each compilation of a [Watching].
+#[async_trait::async_trait]
+pub trait WatchingHooks: Send + Sync {
+  /// Called with the aggregated changes before compiling them, like `compiler.hooks.watchRun`.
+  async fn watch_run(
+    &self,
+    changed_files: &HashSet<String>,
+    removed_files: &HashSet<String>,
+  ) -> Result<WatchRunResult>;
+
+  /// Called with the result of each compilation. The next compilation is not started before it
+  /// returns, an error stops the watching.
+  async fn done(&self, result: Result<()>) -> Result<()>;
+}
+
+#[derive(Debug, Default)]
+struct WatchingState {
+  /// The files changed since the last compilation
+  changed_files: HashSet<String>,
+  /// The files removed since the last compilation
+  removed_files: HashSet<String>,
+  /// A compilation is needed, set by the file events and `invalidate()`
+  invalid: bool,
+  /// Bumped by every file event to restart the aggregation
+  version: u64,
+  running: bool,
+  suspended: bool,
+  closed: bool,
+  /// The compilations are stopped after `close()`, the compiler is no longer used
+  stopped: bool,
+  /// The watched files and their modification time, only used by polling
+  mtimes: HashMap<PathBuf, Option<SystemTime>>,
+}
+
+/// Run the compilations of a compiler whenever the watched files change.
+///
+/// The events arriving during a compilation are collected into a single follow-up
+/// compilation with the union of the changed and removed files.
+#[derive(Debug)]
+pub struct Watching {
+  options: WatchOptions,
+  state: Mutex<WatchingState>,
+  /// Wakes the watching up for the file events, `invalidate()`, `resume()` and `close()`
+  wake: Notify,
+  /// Notified once the compilations are stopped, which `close()` waits for
+  stopped: Notify,
+}
+
+impl Watching {
+  pub fn new(options: WatchOptions) -> Self {
+    Self {
+      options,
+      // The initial build
+      state: Mutex::new(WatchingState {
+        invalid: true,
+        ..Default::default()
+      }),
+      wake: Notify::new(),
+      stopped: Notify::new(),
+    }
+  }
+
+  fn state(&self) -> MutexGuard<'_, WatchingState> {
+    self.state.lock().expect("should lock the watching state")
+  }
+
+  /// Report the file events, which are merged into the files of the next compilation.
+  pub fn notify(
+    &self,
+    changed: impl IntoIterator<Item = String>,
+    removed: impl IntoIterator<Item = String>,
+  ) {
+    let mut state = self.state();
+    let mut has_events = false;
+    for file in changed {
+      if self.options.is_ignored(&file) {
+        continue;
+      }
+      state.removed_files.remove(&file);
+      state.changed_files.insert(file);
+      has_events = true;
+    }
+    for file in removed {
+      if self.options.is_ignored(&file) {
+        continue;
+      }
+      state.changed_files.remove(&file);
+      state.removed_files.insert(file);
+      has_events = true;
+    }
+    if has_events {
+      state.invalid = true;
+      state.version += 1;
+      drop(state);
+      self.wake.notify_one();
+    }
+  }
+
+  /// Start a compilation even if no file is changed.
+  pub fn invalidate(&self) {
+    let mut state = self.state();
+    state.invalid = true;
+    state.version += 1;
+    drop(state);
+    self.wake.notify_one();
+  }
+
+  /// Stop compiling, the file events are still collected and compiled after `resume()`.
+  pub fn suspend(&self) {
+    self.state().suspended = true;
+  }
+
+  pub fn resume(&self) {
+    self.state().suspended = false;
+    self.wake.notify_one();
+  }
+
+  pub fn is_running(&self) -> bool {
+    self.state().running
+  }
+
+  /// Returns `true` once the compilations are stopped and the compiler can be used again.
+  pub fn is_stopped(&self) -> bool {
+    self.state().stopped
+  }
+
+  /// Stop watching. A running compilation is waited for rather than aborted in the middle of
+  /// emitting the assets.
+  pub async fn close(&self) {
+    loop {
+      let stopped = self.stopped.notified();
+      {
+        let mut state = self.state();
+        state.closed = true;
+        if state.stopped {
+          return;
+        }
+      }
+      self.wake.notify_one();
+      stopped.await;
+    }
+  }
+
+  /// Run the compilations until the watching is closed.
+  pub async fn run<T>(&self, compiler: &mut Compiler<T>, hooks: &dyn WatchingHooks) -> Result<()>
+  where
+    T: AsyncWritableFileSystem + Send + Sync,
+  {
+    let result = self.run_compilations(compiler, hooks).await;
+    {
+      let mut state = self.state();
+      state.running = false;
+      state.closed = true;
+      state.stopped = true;
+    }
+    self.stopped.notify_waiters();
+    result
+  }
+
+  async fn run_compilations<T>(
+    &self,
+    compiler: &mut Compiler<T>,
+    hooks: &dyn WatchingHooks,
+  ) -> Result<()>
+  where
+    T: AsyncWritableFileSystem + Send + Sync,
+  {
+    while let Some((changed_files, removed_files)) = self.wait_for_changes().await {
+      let start_time = SystemTime::now();
+      let result = match hooks.watch_run(&changed_files, &removed_files).await {
+        Ok(WatchRunResult::Blocked) => {
+          self.block(changed_files, removed_files);
+          continue;
+        }
+        Ok(WatchRunResult::Rebuild) if !changed_files.is_empty() || !removed_files.is_empty() => {
+          compiler.rebuild(changed_files, removed_files).await
+        }
+        // `invalidate()` without any file event needs a full build
+        Ok(_) => {
+          compiler
+            .build_with_changes(&changed_files, &removed_files)
+            .await
+        }
+        Err(err) => Err(err),
+      };
+      if self.options.poll.is_some() {
+        self.watch_files(&compiler.compilation, start_time).await;
+      }
+      hooks.done(result).await?;
+      self.state().running = false;
+    }
+    Ok(())
+  }
+
+  /// Wait for the aggregated file events, returns `None` once the watching is closed.
+  async fn wait_for_changes(&self) -> Option<(HashSet<String>, HashSet<String>)> {
+    loop {
+      let wake = self.wake.notified();
+      let version = {
+        let state = self.state();
+        if state.closed {
+          return None;
+        }
+        (state.invalid && !state.suspended).then_some(state.version)
+      };
+      let Some(version) = version else {
+        self.wait(wake).await;
+        continue;
+      };
+
+      tokio::time::sleep(self.options.aggregate_timeout).await;
+      let mut state = self.state();
+      if state.closed {
+        return None;
+      }
+      // Aggregate again if there are new events in the meanwhile
+      if state.version != version || state.suspended {
+        continue;
+      }
+      state.invalid = false;
+      state.running = true;
+      return Some((
+        std::mem::take(&mut state.changed_files),
+        std::mem::take(&mut state.removed_files),
+      ));
+    }
+  }
+
+  /// Keep the changes of a blocked compilation until the next `invalidate()`, the newer events
+  /// take precedence.
+  fn block(&self, changed_files: HashSet<String>, removed_files: HashSet<String>) {
+    let mut state = self.state();
+    state.running = false;
+    for file in changed_files {
+      if !state.removed_files.contains(&file) {
+        state.changed_files.insert(file);
+      }
+    }
+    for file in removed_files {
+      if !state.changed_files.contains(&file) {
+        state.removed_files.insert(file);
+      }
+    }
+  }
+
+  async fn wait(&self, wake: Notified<'_>) {
+    match self.options.poll {
+      Some(interval) => {
+        tokio::select! {
+          _ = wake => {}
+          _ = tokio::time::sleep(interval) => self.poll().await,
+        }
+      }
+      None => wake.await,
+    }
+  }
+
+  /// Watch the dependencies of the compilation, only used by polling.
+  async fn watch_files(&self, compilation: &Compilation, start_time: SystemTime) {
+    let files = compilation
+      .file_dependencies
+      .iter()
+      .chain(compilation.context_dependencies.iter())
+      .chain(compilation.missing_dependencies.iter())
+      .cloned()
+      .collect::<Vec<_>>();
+    let mtimes = join_all(files.into_iter().map(|file| async move {
+      let mtime = modified_time(&file).await;
+      // The file is changed during the compilation, report it at the next poll
+      match mtime {
+        Some(mtime) if mtime > start_time => (file, Some(SystemTime::UNIX_EPOCH)),
+        _ => (file, mtime),
+      }
+    }))
+    .await;
+    self.state().mtimes = mtimes.into_iter().collect();
+  }
+
+  /// Compare the modification time of the watched files with the last poll.
+  async fn poll(&self) {
+    let files = self.state().mtimes.keys().cloned().collect::<Vec<_>>();
+    let current = join_all(files.into_iter().map(|file| async move {
+      let mtime = modified_time(&file).await;
+      (file, mtime)
+    }))
+    .await;
+
+    let mut changed = vec![];
+    let mut removed = vec![];
+    {
+      let mut state = self.state();
+      for (file, current) in current {
+        // The files are watched again by a compilation in the meanwhile
+        let Some(mtime) = state.mtimes.get_mut(&file) else {
+          continue;
+        };
+        if current == *mtime {
+          continue;
+        }
+        *mtime = current;
+        let file = file.to_string_lossy().to_string();
+        match current {
+          Some(_) => changed.push(file),
+          None => removed.push(file),
+        }
+      }
+    }
+    self.notify(changed, removed);
+  }
+}
+
+async fn modified_time(file: &Path) -> Option<SystemTime> {
+  tokio::fs::metadata(file)
+    .await
+    .and_then(|metadata| metadata.modified())
+    .ok()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn watching(ignored: Vec<String>) -> Watching {
+    Watching::new(WatchOptions {
+      aggregate_timeout: Duration::ZERO,
+      poll: None,
+      ignored,
+    })
+  }
+
+  fn set(files: &[&str]) -> HashSet<String> {
+    files.iter().map(|file| file.to_string()).collect()
+  }
+
+  /// The compilation of the changes is finished
+  fn finish(watching: &Watching) {
+    watching.state().running = false;
+  }
+
+  /// The initial build and the compilation finished
+  async fn build(watching: &Watching) {
+    watching.wait_for_changes().await;
+    finish(watching);
+  }
+
+  async fn has_changes(watching: &Watching) -> bool {
+    tokio::time::timeout(Duration::from_millis(50), watching.wait_for_changes())
+      .await
+      .is_ok()
+  }
+
+  #[tokio::test]
+  async fn union_of_events() {
+    let watching = watching(vec![]);
+    build(&watching).await;
+    watching.notify(vec!["a.js".to_string()], vec![]);
+    watching.notify(vec!["b.js".to_string()], vec!["a.js".to_string()]);
+    watching.notify(vec!["c.js".to_string()], vec![]);
+    assert_eq!(
+      watching.wait_for_changes().await,
+      Some((set(&["b.js", "c.js"]), set(&["a.js"])))
+    );
+  }
+
+  #[tokio::test]
+  async fn single_compilation_for_events_during_compilation() {
+    let watching = watching(vec![]);
+    build(&watching).await;
+    watching.notify(vec!["a.js".to_string()], vec![]);
+    assert_eq!(
+      watching.wait_for_changes().await,
+      Some((set(&["a.js"]), set(&[])))
+    );
+    // The compilation of a.js is running
+    watching.notify(vec!["b.js".to_string()], vec![]);
+    watching.notify(vec!["c.js".to_string()], vec![]);
+    finish(&watching);
+    assert_eq!(
+      watching.wait_for_changes().await,
+      Some((set(&["b.js", "c.js"]), set(&[])))
+    );
+    finish(&watching);
+    assert!(!has_changes(&watching).await);
+  }
+
+  #[tokio::test]
+  async fn ignored_events() {
+    let watching = watching(vec!["**/node_modules/**".to_string()]);
+    build(&watching).await;
+    watching.notify(vec!["/app/node_modules/a/index.js".to_string()], vec![]);
+    assert!(!watching.state().invalid);
+    watching.notify(vec!["/app/src/index.js".to_string()], vec![]);
+    assert_eq!(
+      watching.wait_for_changes().await,
+      Some((set(&["/app/src/index.js"]), set(&[])))
+    );
+  }
+
+  #[tokio::test]
+  async fn aggregate_timeout_restarts() {
+    let watching = Watching::new(WatchOptions {
+      aggregate_timeout: Duration::from_millis(100),
+      ..Default::default()
+    });
+    build(&watching).await;
+    watching.notify(vec!["a.js".to_string()], vec![]);
+    let changes = watching.wait_for_changes();
+    tokio::pin!(changes);
+    assert!(
+      tokio::time::timeout(Duration::from_millis(60), &mut changes)
+        .await
+        .is_err()
+    );
+    // The aggregation started by a.js is restarted
+    watching.notify(vec!["b.js".to_string()], vec![]);
+    assert!(
+      tokio::time::timeout(Duration::from_millis(60), &mut changes)
+        .await
+        .is_err()
+    );
+    assert_eq!(changes.await, Some((set(&["a.js", "b.js"]), set(&[]))));
+  }
+
+  #[tokio::test]
+  async fn blocked_changes() {
+    let watching = watching(vec![]);
+    build(&watching).await;
+    watching.notify(vec!["a.js".to_string()], vec!["b.js".to_string()]);
+    let (changed, removed) = watching.wait_for_changes().await.expect("should change");
+    watching.block(changed, removed);
+    watching.notify(vec!["b.js".to_string()], vec![]);
+    assert_eq!(
+      watching.wait_for_changes().await,
+      Some((set(&["a.js", "b.js"]), set(&[])))
+    );
+    finish(&watching);
+    watching.notify(vec!["c.js".to_string()], vec![]);
+    let (changed, removed) = watching.wait_for_changes().await.expect("should change");
+    watching.block(changed, removed);
+    // The blocked changes wait for `invalidate()`
+    assert!(!has_changes(&watching).await);
+    watching.invalidate();
+    assert_eq!(
+      watching.wait_for_changes().await,
+      Some((set(&["c.js"]), set(&[])))
+    );
+  }
+
+  #[tokio::test]
+  async fn suspend_and_resume() {
+    let watching = watching(vec![]);
+    build(&watching).await;
+    watching.suspend();
+    watching.notify(vec!["a.js".to_string()], vec![]);
+    assert!(!has_changes(&watching).await);
+    watching.notify(vec!["b.js".to_string()], vec![]);
+    watching.resume();
+    assert_eq!(
+      watching.wait_for_changes().await,
+      Some((set(&["a.js", "b.js"]), set(&[])))
+    );
+  }
+
+  #[tokio::test]
+  async fn close_waits_for_running_compilation() {
+    let watching = watching(vec![]);
+    // The initial build is running
+    watching.wait_for_changes().await;
+    {
+      let close = watching.close();
+      tokio::pin!(close);
+      assert!(tokio::time::timeout(Duration::from_millis(50), &mut close)
+        .await
+        .is_err());
+      // The end of `run()`
+      finish(&watching);
+      watching.state().stopped = true;
+      watching.stopped.notify_waiters();
+      close.await;
+    }
+    watching.invalidate();
+    assert_eq!(watching.wait_for_changes().await, None);
+  }
+
+  #[tokio::test]
+  async fn poll_removed_files() {
+    let dir = std::env::temp_dir().join(format!("rspack-watching-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("should create the directory");
+    let file = dir.join("a.js");
+    std::fs::write(&file, "a").expect("should write the file");
+
+    let watching = Watching::new(WatchOptions {
+      aggregate_timeout: Duration::ZERO,
+      poll: Some(Duration::from_millis(10)),
+      ignored: vec![],
+    });
+    build(&watching).await;
+    let mtime = modified_time(&file).await;
+    watching.state().mtimes = HashMap::from_iter([(file.clone(), mtime)]);
+    assert!(!has_changes(&watching).await);
+
+    std::fs::remove_file(&file).expect("should remove the file");
+    let file = file.to_string_lossy().to_string();
+    assert_eq!(
+      watching.wait_for_changes().await,
+      Some((set(&[]), HashSet::from_iter([file])))
+    );
+    let _ = std::fs::remove_dir(&dir);
+  }
+}
@@ -0,0 +1,124 @@
+use rspack_identifier::IdentifierMap;
+use swc_core::ecma::atoms::JsWord;
+
+use crate::ModuleIdentifier;
+
+const MODULE_REFERENCE_PREFIX: &str = "__WEBPACK_MODULE_REFERENCE__";
+
+/// A reference to an export of another module in the same concatenated module,
+/// e.g. `a.b` of `import { a } from "./a"` is `{ ids: ["a", "b"] }`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ModuleReference {
+  /// The index of the referenced module in the concatenated module
+  pub index: usize,
+  pub ids: Vec<JsWord>,
+  pub call: bool,
+}
+
+/// The scope of a module which is generated as a part of a concatenated module.
+///
+/// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/ConcatenationScope.js
+#[derive(Debug)]
+pub struct ConcatenationScope {
+  /// The modules in the concatenated module and their indices
+  modules: IdentifierMap<usize>,
+  /// The exports of the current module, i.e. the export names and their local names
+  exports: Vec<(JsWord, JsWord)>,
+}
+
+impl ConcatenationScope {
+  pub fn new(modules: IdentifierMap<usize>) -> Self {
+    Self {
+      modules,
+      exports: vec![],
+    }
+  }
+
+  pub fn is_module_in_scope(&self, module: &ModuleIdentifier) -> bool {
+    self.modules.contains_key(module)
+  }
+
+  pub fn register_export(&mut self, export_name: JsWord, symbol: JsWord) {
+    if !self.exports.iter().any(|(name, _)| name == &export_name) {
+      self.exports.push((export_name, symbol));
+    }
+  }
+
+  pub fn take_exports(&mut self) -> Vec<(JsWord, JsWord)> {
+    std::mem::take(&mut self.exports)
+  }
+
+  /// Create a placeholder identifier for the export of `module`, which is replaced with the
+  /// final name of the export once all the modules are generated.
+  pub fn create_module_reference(
+    &self,
+    module: &ModuleIdentifier,
+    ids: &[JsWord],
+    call: bool,
+  ) -> String {
+    let index = self
+      .modules
+      .get(module)
+      .expect("should be a module in the concatenation scope");
+    let ids = serde_json::to_string(&ids.iter().map(|id| id.as_ref()).collect::<Vec<_>>())
+      .expect("should serialize the ids");
+    let ids = ids
+      .bytes()
+      .map(|byte| format!("{byte:02x}"))
+      .collect::<String>();
+    format!(
+      "{MODULE_REFERENCE_PREFIX}{index}_{ids}{}__",
+      if call { "_call" } else { "" }
+    )
+  }
+
+  pub fn is_module_reference(name: &str) -> bool {
+    name.starts_with(MODULE_REFERENCE_PREFIX)
+  }
+
+  pub fn match_module_reference(name: &str) -> Option<ModuleReference> {
+    let reference = name
+      .strip_prefix(MODULE_REFERENCE_PREFIX)?
+      .strip_suffix("__")?;
+    let (reference, call) = match reference.strip_suffix("_call") {
+      Some(reference) => (reference, true),
+      None => (reference, false),
+    };
+    let (index, ids) = reference.split_once('_')?;
+    let ids = (0..ids.len())
+      .step_by(2)
+      .map(|i| u8::from_str_radix(ids.get(i..i + 2)?, 16).ok())
+      .collect::<Option<Vec<u8>>>()?;
+    let ids: Vec<String> = serde_json::from_slice(&ids).ok()?;
+    Some(ModuleReference {
+      index: index.parse().ok()?,
+      ids: ids.into_iter().map(JsWord::from).collect(),
+      call,
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn module_reference() {
+    let module = ModuleIdentifier::from("javascript/esm|/src/a.js");
+    let scope = ConcatenationScope::new(IdentifierMap::from_iter([(module, 3)]));
+    let reference = scope.create_module_reference(&module, &["default".into(), "a_b".into()], true);
+    assert!(ConcatenationScope::is_module_reference(&reference));
+    assert_eq!(
+      ConcatenationScope::match_module_reference(&reference),
+      Some(ModuleReference {
+        index: 3,
+        ids: vec!["default".into(), "a_b".into()],
+        call: true,
+      })
+    );
+    assert_eq!(
+      ConcatenationScope::match_module_reference("__WEBPACK_MODULE_REFERENCE__3_zz__"),
+      None
+    );
+  }
+}
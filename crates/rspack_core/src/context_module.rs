@@ -8,6 +8,8 @@ use std::{
 };
 
 use nodejs_resolver::EnforceExtension;
+use once_cell::sync::Lazy;
+use regex::Regex;
 use rspack_error::{internal_error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_hash::RspackHash;
 use rspack_identifier::{Identifiable, Identifier};
@@ -18,10 +20,10 @@ use rustc_hash::FxHashSet as HashSet;
 
 use crate::{
   contextify, get_exports_type_with_strict, stringify_map, BoxDependency, BuildContext, BuildInfo,
-  BuildMeta, BuildResult, ChunkGraph, CodeGenerationResult, Compilation, ContextElementDependency,
-  DependencyCategory, DependencyId, DependencyType, ExportsType, FakeNamespaceObjectMode,
-  LibIdentOptions, Module, ModuleType, Resolve, ResolveOptionsWithDependencyType, ResolverFactory,
-  RuntimeGlobals, SourceType,
+  BuildMeta, BuildResult, ChunkGraph, ChunkGroupOptions, CodeGenerationResult, Compilation,
+  ContextElementDependency, DependencyCategory, DependencyId, DependencyType, ExportsType,
+  FakeNamespaceObjectMode, LibIdentOptions, Module, ModuleType, Resolve,
+  ResolveOptionsWithDependencyType, ResolverFactory, RuntimeGlobals, SourceType,
 };
 
 #[derive(Debug, Clone)]
@@ -71,13 +73,16 @@ pub struct ContextOptions {
   pub category: DependencyCategory,
   pub request: String,
   pub namespace_object: ContextNameSpaceObject,
+  /// `webpackChunkName` of the lazy context, `[request]` and `[index]` are replaced for each
+  /// element chunk
+  pub chunk_name: Option<String>,
 }
 
 impl Display for ContextOptions {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     write!(
       f,
-      "({:?}, {}, {},  {:?}, {:?},  {:?}, {}, {:?})",
+      "({:?}, {}, {},  {:?}, {:?},  {:?}, {}, {:?}, {:?})",
       self.mode,
       self.recursive,
       self.reg_str,
@@ -85,7 +90,8 @@ impl Display for ContextOptions {
       self.exclude,
       self.category,
       self.request,
-      self.namespace_object
+      self.namespace_object,
+      self.chunk_name
     )
   }
 }
@@ -100,6 +106,7 @@ impl PartialEq for ContextOptions {
       && self.category == other.category
       && self.request == other.request
       && self.namespace_object == other.namespace_object
+      && self.chunk_name == other.chunk_name
   }
 }
 
@@ -115,6 +122,7 @@ impl Hash for ContextOptions {
     self.category.hash(state);
     self.request.hash(state);
     self.namespace_object.hash(state);
+    self.chunk_name.hash(state);
   }
 }
 
@@ -561,17 +569,30 @@ impl ContextModule {
     fn visit_dirs(
       ctx: &str,
       dir: &Path,
-      dependencies: &mut Vec<BoxDependency>,
+      dependencies: &mut Vec<ContextElementDependency>,
+      context_dependencies: &mut HashSet<PathBuf>,
       options: &ContextModuleOptions,
       resolve_options: &nodejs_resolver::Options,
     ) -> Result<()> {
       if dir.is_dir() {
-        for entry in fs::read_dir(dir)? {
-          let entry = entry?;
-          let path = entry.path();
+        // Watch every visited directory, so that adding a matching file triggers a rebuild
+        context_dependencies.insert(dir.to_path_buf());
+        // Sort the entries to keep the generated context map stable across platforms
+        let mut paths = fs::read_dir(dir)?
+          .map(|entry| entry.map(|entry| entry.path()))
+          .collect::<std::io::Result<Vec<_>>>()?;
+        paths.sort();
+        for path in paths {
           if path.is_dir() {
             if options.context_options.recursive {
-              visit_dirs(ctx, &path, dependencies, options, resolve_options)?;
+              visit_dirs(
+                ctx,
+                &path,
+                dependencies,
+                context_dependencies,
+                options,
+                resolve_options,
+              )?;
             }
           } else if path
             .file_name()
@@ -601,7 +622,7 @@ impl ContextModule {
 
             requests.iter().for_each(|r| {
               if options.context_options.reg_exp.test(&r.request) {
-                dependencies.push(Box::new(ContextElementDependency {
+                dependencies.push(ContextElementDependency {
                   id: DependencyId::new(),
                   request: format!(
                     "{}{}{}",
@@ -618,7 +639,8 @@ impl ContextModule {
                     &options.resource,
                     path.to_string_lossy()
                   ),
-                }));
+                  group_options: None,
+                });
               }
             })
           }
@@ -634,24 +656,44 @@ impl ContextModule {
       dependency_category: self.options.context_options.category,
     });
 
+    let mut context_dependencies: HashSet<PathBuf> = Default::default();
+    context_dependencies.insert(PathBuf::from(&self.options.resource));
     visit_dirs(
       &self.options.resource,
       Path::new(&self.options.resource),
       &mut dependencies,
+      &mut context_dependencies,
       &self.options,
       resolver.options(),
     )?;
 
+    if matches!(self.options.context_options.mode, ContextMode::Lazy)
+      && let Some(chunk_name) = &self.options.context_options.chunk_name
+    {
+      let chunk_name = if ELEMENT_CHUNK_NAME_PLACEHOLDER_REGEX.is_match(chunk_name) {
+        Cow::Borrowed(chunk_name)
+      } else {
+        Cow::Owned(format!("{chunk_name}[index]"))
+      };
+      for (index, dependency) in dependencies.iter_mut().enumerate() {
+        let name = chunk_name
+          .replace("[index]", &index.to_string())
+          .replace("[request]", &to_path(&dependency.user_request));
+        dependency.group_options = Some(ChunkGroupOptions::default().name(name));
+      }
+    }
+    let dependencies = dependencies
+      .into_iter()
+      .map(|dependency| Box::new(dependency) as BoxDependency)
+      .collect::<Vec<_>>();
+
     tracing::trace!("resolving dependencies for {:?}", dependencies);
 
     let mut hasher = RspackHash::from(&build_context.compiler_options.output);
     self.update_hash(&mut hasher);
 
-    let mut context_dependencies: HashSet<PathBuf> = Default::default();
-    context_dependencies.insert(PathBuf::from(&self.options.resource));
-
     let build_info = BuildInfo {
-      hash: Some(hasher.digest(&build_context.compiler_options.output.hash_digest)),
+      hash: Some(build_context.compiler_options.output.digest(hasher)),
       context_dependencies,
       ..Default::default()
     };
@@ -668,6 +710,21 @@ impl ContextModule {
   }
 }
 
+static ELEMENT_CHUNK_NAME_PLACEHOLDER_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"\[(index|request)\]").expect("should be a valid regex"));
+
+static PATH_NAME_NORMALIZE_REPLACE_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"[^a-zA-Z0-9_!§$()=\-^°]+").expect("should be a valid regex"));
+
+/// Turn a request into a name usable in a path, e.g. `./pages/home.vue` to `pages-home-vue`.
+///
+/// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/Template.js#L89
+fn to_path(request: &str) -> String {
+  let path = PATH_NAME_NORMALIZE_REPLACE_REGEX.replace_all(request, "-");
+  let path = path.strip_prefix('-').unwrap_or(&path);
+  path.strip_suffix('-').unwrap_or(path).to_string()
+}
+
 fn create_identifier(options: &ContextModuleOptions) -> Identifier {
   Identifier::from(format!("{options}"))
 }
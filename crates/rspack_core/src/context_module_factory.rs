@@ -13,6 +13,8 @@ use crate::{
 pub struct ContextModuleFactory {
   plugin_driver: SharedPluginDriver,
   cache: Arc<Cache>,
+  /// The resource path of the module which issues the request
+  issuer: Option<Box<str>>,
 }
 
 #[async_trait::async_trait]
@@ -30,10 +32,15 @@ impl ModuleFactory for ContextModuleFactory {
 }
 
 impl ContextModuleFactory {
-  pub fn new(plugin_driver: SharedPluginDriver, cache: Arc<Cache>) -> Self {
+  pub fn new(
+    plugin_driver: SharedPluginDriver,
+    cache: Arc<Cache>,
+    issuer: Option<Box<str>>,
+  ) -> Self {
     Self {
       plugin_driver,
       cache,
+      issuer,
     }
   }
 
@@ -48,26 +55,15 @@ impl ContextModuleFactory {
     let mut before_resolve_args = NormalModuleBeforeResolveArgs {
       request: dependency.request().to_string(),
       context: data.context.to_string(),
+      issuer: self.issuer.as_deref().map(ToOwned::to_owned),
     };
     if let Ok(Some(false)) = self
       .plugin_driver
       .context_module_before_resolve(&mut before_resolve_args)
       .await
     {
-      let specifier = dependency.request();
-      let ident = format!("{}{specifier}", data.context);
-
-      let module_identifier = ModuleIdentifier::from(format!("missing|{ident}"));
-
-      let missing_module = MissingModule::new(
-        module_identifier,
-        format!("{ident} (missing)"),
-        format!("Failed to resolve {specifier}"),
-      )
-      .boxed();
-      return Ok(Some(
-        ModuleFactoryResult::new(missing_module).with_empty_diagnostic(),
-      ));
+      // The request is skipped by plugins, no module is created for it.
+      return Ok(Some(ModuleFactoryResult::default().with_empty_diagnostic()));
     }
     data.context = before_resolve_args.context.into();
     dependency.set_request(before_resolve_args.request);
@@ -85,7 +81,7 @@ impl ContextModuleFactory {
     let factory_meta = Default::default();
     let mut file_dependencies = Default::default();
     let mut missing_dependencies = Default::default();
-    let context_dependencies = Default::default();
+    let mut context_dependencies = Default::default();
     let specifier = dependency.request();
     let resolve_args = ResolveArgs {
       context: data.context.clone(),
@@ -98,6 +94,7 @@ impl ContextModuleFactory {
       resolve_to_context: true,
       optional: false,
       file_dependencies: &mut file_dependencies,
+      context_dependencies: &mut context_dependencies,
       missing_dependencies: &mut missing_dependencies,
     };
     let plugin_driver = &self.plugin_driver;
@@ -149,7 +146,7 @@ impl ContextModuleFactory {
 
     Ok(
       ModuleFactoryResult {
-        module,
+        module: Some(module),
         file_dependencies,
         missing_dependencies,
         context_dependencies,
@@ -1,9 +1,9 @@
 use crate::{
-  AsDependencyTemplate, Context, ContextMode, ContextOptions, Dependency, DependencyCategory,
-  DependencyId, DependencyType, ModuleDependency,
+  AsDependencyTemplate, ChunkGroupOptions, Context, ContextMode, ContextOptions, Dependency,
+  DependencyCategory, DependencyId, DependencyType, ModuleDependency,
 };
 
-#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct ContextElementDependency {
   pub id: DependencyId,
   // TODO remove this async dependency mark
@@ -13,6 +13,8 @@ pub struct ContextElementDependency {
   pub category: DependencyCategory,
   pub context: Context,
   pub resource_identifier: String,
+  /// The options of the chunk group of a lazy context element, e.g. the chunk name
+  pub group_options: Option<ChunkGroupOptions>,
 }
 
 impl Dependency for ContextElementDependency {
@@ -64,6 +66,10 @@ impl ModuleDependency for ContextElementDependency {
   fn resource_identifier(&self) -> Option<&str> {
     Some(&self.resource_identifier)
   }
+
+  fn group_options(&self) -> Option<&ChunkGroupOptions> {
+    self.group_options.as_ref()
+  }
 }
 
 impl AsDependencyTemplate for ContextElementDependency {}
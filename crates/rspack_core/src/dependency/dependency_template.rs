@@ -2,13 +2,15 @@ use std::fmt::Debug;
 
 use rspack_sources::{BoxSource, ReplaceSource};
 
-use crate::{Compilation, InitFragment, Module, RuntimeGlobals};
+use crate::{Compilation, ConcatenationScope, InitFragment, Module, RuntimeGlobals};
 
 pub struct TemplateContext<'a> {
   pub compilation: &'a Compilation,
   pub module: &'a dyn Module,
   pub runtime_requirements: &'a mut RuntimeGlobals,
   pub init_fragments: &'a mut Vec<InitFragment>,
+  /// Set if the module is generated as a part of a concatenated module
+  pub concatenation_scope: Option<&'a mut ConcatenationScope>,
 }
 
 pub type TemplateReplaceSource = ReplaceSource<BoxSource>;
@@ -1,12 +1,14 @@
 use crate::{
-  AsDependencyTemplate, Dependency, DependencyCategory, DependencyId, DependencyType, ErrorSpan,
-  ModuleDependency,
+  AsDependencyTemplate, Context, Dependency, DependencyCategory, DependencyId, DependencyType,
+  ErrorSpan, ModuleDependency,
 };
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 pub struct EntryDependency {
   id: DependencyId,
   request: String,
+  /// Resolve the request from this context instead of the `context` option
+  context: Option<Context>,
 }
 
 impl EntryDependency {
@@ -14,8 +16,14 @@ impl EntryDependency {
     Self {
       request,
       id: DependencyId::new(),
+      context: None,
     }
   }
+
+  pub fn with_context(mut self, context: Context) -> Self {
+    self.context = Some(context);
+    self
+  }
 }
 
 impl Dependency for EntryDependency {
@@ -30,6 +38,10 @@ impl Dependency for EntryDependency {
   fn dependency_type(&self) -> &DependencyType {
     &DependencyType::Entry
   }
+
+  fn get_context(&self) -> Option<&Context> {
+    self.context.as_ref()
+  }
 }
 
 impl ModuleDependency for EntryDependency {
@@ -1,5 +1,6 @@
 mod entry;
 mod span;
+use std::cell::Cell;
 use std::sync::atomic::AtomicU32;
 use std::sync::atomic::Ordering::Relaxed;
 
@@ -16,6 +17,8 @@ pub use runtime_requirements_dependency::RuntimeRequirementsDependency;
 mod context_element_dependency;
 mod dependency_macro;
 pub use context_element_dependency::*;
+mod remote_to_external_dependency;
+pub use remote_to_external_dependency::*;
 mod const_dependency;
 use std::{
   any::Any,
@@ -86,6 +89,14 @@ pub enum DependencyType {
   WasmExportImported,
   /// static exports
   StaticExports,
+  /// loader request, resolved with `resolveLoader`
+  LoaderImport,
+  /// free variable shimmed by `builtins.provide`
+  Provided,
+  /// item of the dependency array of amd `define()`
+  AmdRequireItem,
+  /// the container of a remote module
+  RemoteToExternal,
   Custom(Box<str>), // TODO it will increase large layout size
 }
 
@@ -118,6 +129,10 @@ impl Display for DependencyType {
       DependencyType::WasmImport => write!(f, "wasm import"),
       DependencyType::WasmExportImported => write!(f, "wasm export imported"),
       DependencyType::StaticExports => write!(f, "static exports"),
+      DependencyType::LoaderImport => write!(f, "loader import"),
+      DependencyType::Provided => write!(f, "provided"),
+      DependencyType::AmdRequireItem => write!(f, "amd require item"),
+      DependencyType::RemoteToExternal => write!(f, "remote to external"),
       DependencyType::Custom(ty) => write!(f, "custom {ty}"),
       DependencyType::ExportInfoApi => write!(f, "export info api"),
     }
@@ -130,6 +145,7 @@ pub enum DependencyCategory {
   Unknown,
   Esm,
   CommonJS,
+  Amd,
   Url,
   CssImport,
   CssCompose,
@@ -142,6 +158,7 @@ impl From<&str> for DependencyCategory {
     match value {
       "esm" => Self::Esm,
       "commonjs" => Self::CommonJS,
+      "amd" => Self::Amd,
       "url" => Self::Url,
       "wasm" => Self::Wasm,
       "css-import" => Self::CssImport,
@@ -159,6 +176,7 @@ impl Display for DependencyCategory {
       DependencyCategory::Unknown => write!(f, "unknown"),
       DependencyCategory::Esm => write!(f, "esm"),
       DependencyCategory::CommonJS => write!(f, "commonjs"),
+      DependencyCategory::Amd => write!(f, "amd"),
       DependencyCategory::Url => write!(f, "url"),
       DependencyCategory::CssImport => write!(f, "css-import"),
       DependencyCategory::CssCompose => write!(f, "css-compose"),
@@ -370,9 +388,26 @@ pub struct DependencyId(u32);
 
 pub static DEPENDENCY_ID: Lazy<AtomicU32> = Lazy::new(|| AtomicU32::new(0));
 
+/// Number of ids a thread reserves from [DEPENDENCY_ID] at once,
+/// so that parallel module builds don't contend on the same atomic.
+const DEPENDENCY_ID_RANGE_SIZE: u32 = 1024;
+
+thread_local! {
+  /// The `[next, end)` range of ids reserved by the current thread.
+  static DEPENDENCY_ID_RANGE: Cell<(u32, u32)> = Cell::new((0, 0));
+}
+
 impl DependencyId {
   pub fn new() -> Self {
-    Self(DEPENDENCY_ID.fetch_add(1, Relaxed))
+    DEPENDENCY_ID_RANGE.with(|range| {
+      let (mut next, mut end) = range.get();
+      if next == end {
+        next = DEPENDENCY_ID.fetch_add(DEPENDENCY_ID_RANGE_SIZE, Relaxed);
+        end = next + DEPENDENCY_ID_RANGE_SIZE;
+      }
+      range.set((next + 1, end));
+      Self(next)
+    })
   }
 }
 impl Default for DependencyId {
@@ -511,8 +546,12 @@ pub mod needs_refactor {
     caps: Vec<(&'a str, &'a str)>,
   }
 
-  pub const DEFAULT_WORKER_SYNTAX: &[&str] =
-    &["Worker", "SharedWorker", "Worker from worker_threads"];
+  pub const DEFAULT_WORKER_SYNTAX: &[&str] = &[
+    "Worker",
+    "SharedWorker",
+    "Worklet",
+    "Worker from worker_threads",
+  ];
 
   impl<'a> WorkerSyntaxScanner<'a> {
     pub fn new(syntax: &'a [&'a str]) -> Self {
@@ -572,3 +611,29 @@ pub mod needs_refactor {
     }
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn dependency_id_should_be_unique_across_threads() {
+    let handles = (0..8)
+      .map(|_| {
+        std::thread::spawn(|| {
+          (0..DEPENDENCY_ID_RANGE_SIZE * 3)
+            .map(|_| DependencyId::new())
+            .collect::<Vec<_>>()
+        })
+      })
+      .collect::<Vec<_>>();
+    let mut ids = HashSet::default();
+    let mut total = 0;
+    for handle in handles {
+      let thread_ids = handle.join().expect("should join thread");
+      total += thread_ids.len();
+      ids.extend(thread_ids);
+    }
+    assert_eq!(ids.len(), total);
+  }
+}
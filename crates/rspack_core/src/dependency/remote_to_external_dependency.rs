@@ -0,0 +1,54 @@
+use crate::{
+  AsDependencyTemplate, Dependency, DependencyCategory, DependencyId, DependencyType, ErrorSpan,
+  ModuleDependency,
+};
+
+/// The dependency from a [crate::RemoteModule] to the external module of its container.
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub struct RemoteToExternalDependency {
+  id: DependencyId,
+  request: String,
+}
+
+impl RemoteToExternalDependency {
+  pub fn new(request: String) -> Self {
+    Self {
+      request,
+      id: DependencyId::new(),
+    }
+  }
+}
+
+impl Dependency for RemoteToExternalDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::RemoteToExternal
+  }
+}
+
+impl ModuleDependency for RemoteToExternalDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    None
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+}
+
+impl AsDependencyTemplate for RemoteToExternalDependency {}
@@ -41,6 +41,14 @@ pub fn export_from_import(
         }
       } else if !export_name.is_empty() {
         if matches!(exports_type, ExportsType::DefaultOnly) {
+          if is_strict_harmony_module(&compilation.module_graph, &module.identifier()) {
+            // strict ESM semantics: importing a named export which doesn't exist throws
+            return format!(
+              "/* non-default import from non-esm module */(function () {{ throw new Error({}); }}())",
+              serde_json::to_string(&missing_named_export_message(compilation, id, &module.identifier(), &export_name[0]))
+                .expect("should render message")
+            );
+          }
           return format!("/* non-default import from non-esm module */undefined\n{}", property_access(&export_name, 1));
         } else if !matches!(exports_type, ExportsType::Namespace)  && let Some(first_export_name) = export_name.get(0) && first_export_name == "__esModule" {
           return "/* __esModule */true".to_string();
@@ -60,6 +68,16 @@ pub fn export_from_import(
 
   if !export_name.is_empty() {
     // TODO check used
+    // The export may be renamed by `optimization.mangleExports`
+    if let Some(ref_module) = compilation
+      .module_graph
+      .module_identifier_by_dependency_id(id)
+    {
+      export_name[0] = compilation
+        .module_graph
+        .get_exports_info(ref_module)
+        .get_used_name(&export_name[0]);
+    }
     let access = format!("{import_var}{}", property_access(&export_name, 0));
     if is_call && !call_context {
       return format!("(0, {access})");
@@ -78,16 +96,49 @@ pub fn get_exports_type(
   let module = module_graph
     .module_identifier_by_dependency_id(id)
     .expect("should have module");
-  let strict = module_graph
-    .module_graph_module_by_identifier(parent_module)
-    .expect("should have mgm")
-    .get_strict_harmony_module();
+  let strict = is_strict_harmony_module(module_graph, parent_module);
   module_graph
     .module_graph_module_by_identifier(module)
     .expect("should have mgm")
     .get_exports_type(strict)
 }
 
+pub fn is_strict_harmony_module(module_graph: &ModuleGraph, module: &ModuleIdentifier) -> bool {
+  module_graph
+    .module_graph_module_by_identifier(module)
+    .expect("should have mgm")
+    .get_strict_harmony_module()
+}
+
+/// The message of importing a named export from a default-only module in a strict ESM module.
+pub fn missing_named_export_message(
+  compilation: &Compilation,
+  id: &DependencyId,
+  parent_module: &ModuleIdentifier,
+  export_name: &JsWord,
+) -> String {
+  let readable_identifier = |identifier: &ModuleIdentifier| {
+    compilation
+      .module_graph
+      .module_by_identifier(identifier)
+      .map(|module| {
+        module
+          .readable_identifier(&compilation.options.context)
+          .to_string()
+      })
+      .unwrap_or_else(|| identifier.to_string())
+  };
+  let module = compilation
+    .module_graph
+    .module_identifier_by_dependency_id(id)
+    .expect("should have module");
+  format!(
+    "Can't import the named export '{export_name}' from default-exporting module '{}' (only default export is available) in strict ESM module '{}'",
+    readable_identifier(module),
+    readable_identifier(parent_module)
+  )
+}
+
 pub fn get_exports_type_with_strict(
   module_graph: &ModuleGraph,
   id: &DependencyId,
@@ -35,6 +35,15 @@ impl ExportsInfo {
     self.exports.keys().collect::<HashSet<_>>()
   }
 
+  /// The name of the export in the exports object, which differs from `name` if it's mangled
+  pub fn get_used_name(&self, name: &JsWord) -> JsWord {
+    self
+      .exports
+      .get(name)
+      .and_then(|info| info.used_name.clone())
+      .unwrap_or_else(|| name.clone())
+  }
+
   pub fn get_used(
     &self,
     name: UsedName,
@@ -90,6 +99,8 @@ pub struct ExportInfo {
   _name: JsWord,
   module_identifier: Option<ModuleIdentifier>,
   pub usage_state: UsageState,
+  /// Set by `optimization.mangleExports`
+  used_name: Option<JsWord>,
 }
 
 impl ExportInfo {
@@ -98,9 +109,18 @@ impl ExportInfo {
       _name,
       module_identifier: None,
       usage_state,
+      used_name: None,
     }
   }
 
+  pub fn get_used_name(&self) -> Option<&JsWord> {
+    self.used_name.as_ref()
+  }
+
+  pub fn set_used_name(&mut self, used_name: JsWord) {
+    self.used_name = Some(used_name);
+  }
+
   // TODO
   pub fn get_used(&self, _runtime: &RuntimeSpec) -> UsageState {
     UsageState::Unused
@@ -59,6 +59,15 @@ fn get_source_for_default_case(_optional: bool, request: &ExternalRequest) -> St
   format!("{variable_name}{object_lookup}")
 }
 
+/// Split the request of a script external, i.e. `global@url`, to the url and the global.
+fn extract_url_and_global(url_and_global: &str) -> Option<(&str, &str)> {
+  let (global, url) = url_and_global.split_once('@')?;
+  if global.is_empty() || url.is_empty() {
+    return None;
+  }
+  Some((url, global))
+}
+
 #[derive(Debug)]
 pub struct ExternalModule {
   id: Identifier,
@@ -92,6 +101,42 @@ impl ExternalModule {
     )
   }
 
+  /// The url and the global of a script external, either `global@url` or `[url, global, ...properties]`.
+  fn get_url_and_global(&self) -> Option<(&str, &str, usize)> {
+    let request = self.request.as_array();
+    if request.len() == 1 {
+      extract_url_and_global(self.request.as_str()).map(|(url, global)| (url, global, 1))
+    } else {
+      Some((request.get(0)?.as_str(), request.get(1)?.as_str(), 2))
+    }
+  }
+
+  fn get_source_for_script(&self) -> String {
+    let (url, global, start) = self
+      .get_url_and_global()
+      .expect("should have been validated in build");
+    format!(
+      r#"var __webpack_error__ = new Error();
+module.exports = new Promise(function(resolve, reject) {{
+  if(typeof {global} !== "undefined") return resolve();
+  {load_script}({url}, function(event) {{
+    if(typeof {global} !== "undefined") return resolve();
+    var errorType = event && (event.type === 'load' ? 'missing' : event.type);
+    var realSrc = event && event.target && event.target.src;
+    __webpack_error__.message = 'Loading script failed.\n(' + errorType + ': ' + realSrc + ')';
+    __webpack_error__.name = 'ScriptExternalLoadError';
+    __webpack_error__.type = errorType;
+    __webpack_error__.request = realSrc;
+    reject(__webpack_error__);
+  }}, {global_name});
+}}).then(function() {{ return {global}{object_lookup}; }})"#,
+      load_script = RuntimeGlobals::LOAD_SCRIPT,
+      url = serde_json::to_string(url).expect("invalid json to_string"),
+      global_name = serde_json::to_string(global).expect("invalid json to_string"),
+      object_lookup = property_access(self.request.as_array(), start),
+    )
+  }
+
   fn get_source_for_import(&self, compilation: &Compilation) -> String {
     format!(
       "module.exports = {}({})",
@@ -136,7 +181,8 @@ impl ExternalModule {
               None,
             ));
           format!(
-            "__WEBPACK_EXTERNAL_createRequire(import.meta.url)('{}')",
+            "__WEBPACK_EXTERNAL_createRequire({}.url)('{}')",
+            compilation.options.output.import_meta_name,
             self.request.as_str()
           )
         } else {
@@ -190,7 +236,10 @@ impl ExternalModule {
           self.get_source_for_import(compilation)
         }
       }
-      // TODO "script"
+      "script" => {
+        runtime_requirements.insert(RuntimeGlobals::LOAD_SCRIPT);
+        self.get_source_for_script()
+      }
       _ => "".to_string(),
     };
     runtime_requirements.insert(RuntimeGlobals::MODULE);
@@ -261,7 +310,7 @@ impl Module for ExternalModule {
     self.update_hash(&mut hasher);
 
     let build_info = BuildInfo {
-      hash: Some(hasher.digest(&build_context.compiler_options.output.hash_digest)),
+      hash: Some(build_context.compiler_options.output.digest(hasher)),
       ..Default::default()
     };
 
@@ -276,7 +325,16 @@ impl Module for ExternalModule {
       "this" => build_result.build_info.strict = false,
       "system" => build_result.build_meta.exports_type = BuildMetaExportsType::Namespace,
       "module" => build_result.build_meta.exports_type = BuildMetaExportsType::Namespace,
-      "script" | "promise" => build_result.build_meta.is_async = true,
+      "script" => {
+        if self.get_url_and_global().is_none() {
+          return Err(internal_error!(
+            "Invalid request {} of the script external, expected `global@url`",
+            serde_json::to_string(&self.request).map_err(|e| internal_error!(e.to_string()))?
+          ));
+        }
+        build_result.build_meta.is_async = true;
+      }
+      "promise" => build_result.build_meta.is_async = true,
       "import" => {
         build_result.build_meta.is_async = true;
         build_result.build_meta.exports_type = BuildMetaExportsType::Namespace;
@@ -0,0 +1,159 @@
+use rspack_error::{Error, Severity};
+use rustc_hash::FxHashMap as HashMap;
+use swc_core::{
+  common::SyntaxContext,
+  ecma::ast::{CallExpr, Callee, Expr, MemberProp, MetaPropKind},
+};
+
+use crate::{BoxDependency, BoxDependencyTemplate, BuildMeta, ErrorSpan};
+
+/// The module being scanned by the JavaScript parser, passed to the handlers of [JsParserHandlers].
+///
+/// The dependencies added by the handlers are treated the same as the ones of the built-in scanners.
+pub struct JsParserHandlerContext<'a> {
+  pub unresolved_ctxt: &'a SyntaxContext,
+  pub dependencies: &'a mut Vec<BoxDependency>,
+  pub presentational_dependencies: &'a mut Vec<BoxDependencyTemplate>,
+  pub build_meta: &'a mut BuildMeta,
+  pub errors: &'a mut Vec<Error>,
+  pub resource_path: &'a str,
+  pub source: &'a str,
+}
+
+impl JsParserHandlerContext<'_> {
+  /// Report an error of the module, rendered with the excerpt of the source covered by `span`.
+  pub fn error(&mut self, span: impl Into<ErrorSpan>, title: String, message: String) {
+    self.report(span.into(), title, message, Severity::Error);
+  }
+
+  /// Report a warning of the module, rendered with the excerpt of the source covered by `span`.
+  pub fn warn(&mut self, span: impl Into<ErrorSpan>, title: String, message: String) {
+    self.report(span.into(), title, message, Severity::Warn);
+  }
+
+  fn report(&mut self, span: ErrorSpan, title: String, message: String, severity: Severity) {
+    self.errors.push(Error::TraceableError(
+      span
+        .to_traceable_error(self.resource_path, self.source, title, message)
+        .with_severity(severity),
+    ));
+  }
+}
+
+/// Handle a call of the JavaScript parser, returns `true` to claim the call, then the built-in
+/// scanners skip it as well as its arguments.
+pub type JsParserCallHandler =
+  Box<dyn Fn(&CallExpr, &mut JsParserHandlerContext) -> bool + Send + Sync>;
+
+/// The handlers of the expressions registered by the plugins with
+/// [crate::Plugin::register_js_parser_handlers], same as `parser.hooks.call.for(name)` of webpack.
+#[derive(Default)]
+pub struct JsParserHandlers {
+  calls: HashMap<String, Vec<JsParserCallHandler>>,
+}
+
+impl std::fmt::Debug for JsParserHandlers {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("JsParserHandlers")
+      .field("calls", &self.calls.keys().collect::<Vec<_>>())
+      .finish()
+  }
+}
+
+impl JsParserHandlers {
+  /// Handle the calls of `callee`, which is a free variable or a member expression of it,
+  /// e.g. `__loadRemoteConfig`, `require` and `import.meta.env.load`.
+  ///
+  /// The handlers of the same callee are called in the order of registration until one claims the call.
+  pub fn call(
+    &mut self,
+    callee: impl Into<String>,
+    handler: impl Fn(&CallExpr, &mut JsParserHandlerContext) -> bool + Send + Sync + 'static,
+  ) {
+    self
+      .calls
+      .entry(callee.into())
+      .or_default()
+      .push(Box::new(handler));
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.calls.is_empty()
+  }
+
+  /// Call the handlers of the callee of `call_expr`, returns whether the call is claimed.
+  pub fn handle_call(&self, call_expr: &CallExpr, context: &mut JsParserHandlerContext) -> bool {
+    let Callee::Expr(callee) = &call_expr.callee else {
+      return false;
+    };
+    let Some(handlers) =
+      get_free_name(callee, context.unresolved_ctxt).and_then(|name| self.calls.get(&name))
+    else {
+      return false;
+    };
+    handlers.iter().any(|handler| handler(call_expr, context))
+  }
+}
+
+/// The name of a free variable or a member expression of it, e.g. `a.b.c` of `a.b.c`,
+/// `None` if `a` is declared in the module.
+fn get_free_name(expr: &Expr, unresolved_ctxt: &SyntaxContext) -> Option<String> {
+  match expr {
+    Expr::Ident(ident) if ident.span.ctxt == *unresolved_ctxt => Some(ident.sym.to_string()),
+    Expr::MetaProp(meta_prop) if matches!(meta_prop.kind, MetaPropKind::ImportMeta) => {
+      Some("import.meta".to_string())
+    }
+    Expr::Member(member) => {
+      let MemberProp::Ident(prop) = &member.prop else {
+        return None;
+      };
+      let object = get_free_name(&member.obj, unresolved_ctxt)?;
+      Some(format!("{object}.{}", prop.sym))
+    }
+    Expr::Paren(paren) => get_free_name(&paren.expr, unresolved_ctxt),
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use swc_core::common::DUMMY_SP;
+  use swc_core::ecma::ast::{Ident, MemberExpr, MetaPropExpr};
+
+  use super::*;
+
+  fn member(obj: Expr, prop: &str) -> Expr {
+    Expr::Member(MemberExpr {
+      span: DUMMY_SP,
+      obj: Box::new(obj),
+      prop: MemberProp::Ident(Ident::new(prop.into(), DUMMY_SP)),
+    })
+  }
+
+  #[test]
+  fn free_name() {
+    let unresolved_ctxt = SyntaxContext::empty();
+    let ident = Expr::Ident(Ident::new("__loadRemoteConfig".into(), DUMMY_SP));
+    assert_eq!(
+      get_free_name(&ident, &unresolved_ctxt).as_deref(),
+      Some("__loadRemoteConfig")
+    );
+    assert_eq!(
+      get_free_name(&member(ident, "sync"), &unresolved_ctxt).as_deref(),
+      Some("__loadRemoteConfig.sync")
+    );
+
+    let import_meta = Expr::MetaProp(MetaPropExpr {
+      span: DUMMY_SP,
+      kind: MetaPropKind::ImportMeta,
+    });
+    assert_eq!(
+      get_free_name(
+        &member(member(import_meta, "env"), "load"),
+        &unresolved_ctxt
+      )
+      .as_deref(),
+      Some("import.meta.env.load")
+    );
+  }
+}
@@ -20,12 +20,16 @@ pub use missing_module::*;
 mod normal_module;
 mod raw_module;
 pub use raw_module::*;
+mod remote_module;
+pub use remote_module::*;
 mod exports_info;
 pub use exports_info::*;
 pub mod module;
 pub mod parser_and_generator;
 pub use module::*;
 pub use parser_and_generator::*;
+mod js_parser_handlers;
+pub use js_parser_handlers::*;
 mod runtime_globals;
 pub use normal_module::*;
 pub use runtime_globals::RuntimeGlobals;
@@ -49,6 +53,8 @@ mod module_graph;
 pub use module_graph::*;
 mod chunk;
 pub use chunk::*;
+mod chunk_explanation;
+pub use chunk_explanation::*;
 mod dependency;
 pub use dependency::*;
 mod utils;
@@ -63,7 +69,9 @@ mod runtime_module;
 pub use runtime::*;
 pub use runtime_module::*;
 mod code_generation_results;
+mod concatenation_scope;
 pub use code_generation_results::*;
+pub use concatenation_scope::*;
 mod entrypoint;
 pub use entrypoint::*;
 mod loader;
@@ -78,7 +86,9 @@ mod module_graph_module;
 pub use module_graph_module::*;
 pub mod tree_shaking;
 
-pub use rspack_loader_runner::{get_scheme, ResourceData, Scheme, BUILTIN_LOADER_PREFIX};
+pub use rspack_loader_runner::{
+  get_scheme, BuildInfoExtras, ResourceData, Scheme, BUILTIN_LOADER_PREFIX,
+};
 pub use rspack_sources;
 
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -91,6 +101,18 @@ pub enum SourceType {
   Unknown,
 }
 
+impl fmt::Display for SourceType {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      SourceType::JavaScript => write!(f, "javascript"),
+      SourceType::Css => write!(f, "css"),
+      SourceType::Wasm => write!(f, "wasm"),
+      SourceType::Asset => write!(f, "asset"),
+      SourceType::Unknown => write!(f, "unknown"),
+    }
+  }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ModuleType {
   Json,
@@ -0,0 +1,84 @@
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+use rspack_error::{internal_error, Result};
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::BoxLoader;
+
+/// Create a native loader from the loader request, e.g. `builtin:swc-loader`, and its options
+/// serialized as JSON.
+pub type BuiltinLoaderFactory = fn(request: &str, options: Option<&str>) -> Result<BoxLoader>;
+
+static BUILTIN_LOADERS: Lazy<RwLock<HashMap<String, BuiltinLoaderFactory>>> =
+  Lazy::new(Default::default);
+
+/// Register a native loader for the requests starting with `name`, e.g. `builtin:swc-loader`.
+/// The loader previously registered with the same name is replaced.
+pub fn register_builtin_loader(name: impl Into<String>, factory: BuiltinLoaderFactory) {
+  let name = name.into();
+  debug_assert!(name.starts_with(crate::BUILTIN_LOADER_PREFIX));
+  BUILTIN_LOADERS
+    .write()
+    .expect("should lock the builtin loaders")
+    .insert(name, factory);
+}
+
+/// The names of the registered builtin loaders, sorted alphabetically.
+pub fn builtin_loader_names() -> Vec<String> {
+  let mut names = BUILTIN_LOADERS
+    .read()
+    .expect("should lock the builtin loaders")
+    .keys()
+    .cloned()
+    .collect::<Vec<_>>();
+  names.sort();
+  names
+}
+
+/// Create the builtin loader of `request` by the registered loader with the longest matched name.
+pub fn get_builtin_loader(request: &str, options: Option<&str>) -> Result<BoxLoader> {
+  let factory = BUILTIN_LOADERS
+    .read()
+    .expect("should lock the builtin loaders")
+    .iter()
+    .filter(|(name, _)| request.starts_with(name.as_str()))
+    .max_by_key(|(name, _)| name.len())
+    .map(|(_, factory)| *factory);
+  match factory {
+    Some(factory) => factory(request, options),
+    None => Err(internal_error!(
+      "Unknown builtin loader: {request}, the registered builtin loaders are: {}",
+      builtin_loader_names().join(", ")
+    )),
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn unsupported_loader(request: &str, _options: Option<&str>) -> Result<BoxLoader> {
+    Err(internal_error!("{request}"))
+  }
+
+  #[test]
+  fn longest_matched_loader() {
+    register_builtin_loader("builtin:test-loader", unsupported_loader);
+    register_builtin_loader("builtin:test-loader-extra", |_, _| {
+      Err(internal_error!("extra"))
+    });
+    assert!(builtin_loader_names().contains(&"builtin:test-loader".to_string()));
+    let error = get_builtin_loader("builtin:test-loader-extra?a=1", None)
+      .err()
+      .expect("should fail");
+    assert!(error.to_string().contains("extra"));
+    let error = get_builtin_loader("builtin:unknown-loader", None)
+      .err()
+      .expect("should fail");
+    assert!(error
+      .to_string()
+      .contains("Unknown builtin loader: builtin:unknown-loader"));
+    assert!(error.to_string().contains("builtin:test-loader"));
+  }
+}
@@ -2,12 +2,14 @@ use std::sync::Arc;
 
 pub use rspack_loader_runner::{run_loaders, Content, Loader, LoaderContext, ResourceData};
 
-use crate::{CompilerOptions, ResolverFactory};
+use crate::{CompilerOptions, ModuleSettings, ResolverFactory};
 
 #[derive(Debug, Clone)]
 pub struct CompilerContext {
   pub options: Arc<CompilerOptions>,
   pub resolver_factory: Arc<ResolverFactory>,
+  /// Settings of the module being built
+  pub module_settings: Arc<ModuleSettings>,
 }
 
 pub type LoaderRunnerContext = CompilerContext;
@@ -1,3 +1,5 @@
+mod builtin_loader;
+pub use builtin_loader::*;
 mod loader_runner;
 pub use loader_runner::*;
 mod process_resource;
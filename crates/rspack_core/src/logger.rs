@@ -8,6 +8,8 @@ use std::{
 use dashmap::DashMap;
 use rustc_hash::FxHasher;
 
+use crate::InfrastructureLoggingOptions;
+
 #[derive(Debug, Clone)]
 pub enum LogType {
   Error {
@@ -56,22 +58,37 @@ pub enum LogType {
 }
 
 impl LogType {
+  pub const ERROR: u32 = 1 << 0;
+  pub const WARN: u32 = 1 << 1;
+  pub const INFO: u32 = 1 << 2;
+  pub const LOG: u32 = 1 << 3;
+  pub const DEBUG: u32 = 1 << 4;
+  pub const TRACE: u32 = 1 << 5;
+  pub const GROUP: u32 = 1 << 6;
+  pub const GROUP_COLLAPSED: u32 = 1 << 7;
+  pub const GROUP_END: u32 = 1 << 8;
+  pub const PROFILE: u32 = 1 << 9;
+  pub const PROFILE_END: u32 = 1 << 10;
+  pub const TIME: u32 = 1 << 11;
+  pub const CLEAR: u32 = 1 << 12;
+  pub const STATUS: u32 = 1 << 13;
+
   pub fn to_bit_flag(&self) -> u32 {
     match self {
-      LogType::Error { .. } => 1 << 0,
-      LogType::Warn { .. } => 1 << 1,
-      LogType::Info { .. } => 1 << 2,
-      LogType::Log { .. } => 1 << 3,
-      LogType::Debug { .. } => 1 << 4,
-      LogType::Trace { .. } => 1 << 5,
-      LogType::Group { .. } => 1 << 6,
-      LogType::GroupCollapsed { .. } => 1 << 7,
-      LogType::GroupEnd => 1 << 8,
-      LogType::Profile { .. } => 1 << 9,
-      LogType::ProfileEnd { .. } => 1 << 10,
-      LogType::Time { .. } => 1 << 11,
-      LogType::Clear => 1 << 12,
-      LogType::Status { .. } => 1 << 13,
+      LogType::Error { .. } => Self::ERROR,
+      LogType::Warn { .. } => Self::WARN,
+      LogType::Info { .. } => Self::INFO,
+      LogType::Log { .. } => Self::LOG,
+      LogType::Debug { .. } => Self::DEBUG,
+      LogType::Trace { .. } => Self::TRACE,
+      LogType::Group { .. } => Self::GROUP,
+      LogType::GroupCollapsed { .. } => Self::GROUP_COLLAPSED,
+      LogType::GroupEnd => Self::GROUP_END,
+      LogType::Profile { .. } => Self::PROFILE,
+      LogType::ProfileEnd { .. } => Self::PROFILE_END,
+      LogType::Time { .. } => Self::TIME,
+      LogType::Clear => Self::CLEAR,
+      LogType::Status { .. } => Self::STATUS,
     }
   }
 }
@@ -91,7 +108,16 @@ fn capture_trace() -> Vec<String> {
 pub trait Logger {
   fn raw(&self, log_type: LogType);
 
+  /// Whether the logs of the type are reported, checked before the log is built
+  /// so that the filtered logs cost nearly nothing, i.e. no backtrace is captured.
+  fn accepts(&self, _bit_flag: u32) -> bool {
+    true
+  }
+
   fn error(&self, message: impl Into<String>) {
+    if !self.accepts(LogType::ERROR) {
+      return;
+    }
     self.raw(LogType::Error {
       message: message.into(),
       trace: capture_trace(),
@@ -99,6 +125,9 @@ pub trait Logger {
   }
 
   fn warn(&self, message: impl Into<String>) {
+    if !self.accepts(LogType::WARN) {
+      return;
+    }
     self.raw(LogType::Warn {
       message: message.into(),
       trace: capture_trace(),
@@ -106,18 +135,27 @@ pub trait Logger {
   }
 
   fn info(&self, message: impl Into<String>) {
+    if !self.accepts(LogType::INFO) {
+      return;
+    }
     self.raw(LogType::Info {
       message: message.into(),
     })
   }
 
   fn log(&self, message: impl Into<String>) {
+    if !self.accepts(LogType::LOG) {
+      return;
+    }
     self.raw(LogType::Log {
       message: message.into(),
     })
   }
 
   fn debug(&self, message: impl Into<String>) {
+    if !self.accepts(LogType::DEBUG) {
+      return;
+    }
     self.raw(LogType::Debug {
       message: message.into(),
     })
@@ -130,6 +168,9 @@ pub trait Logger {
   }
 
   fn trace(&self) {
+    if !self.accepts(LogType::TRACE) {
+      return;
+    }
     self.raw(LogType::Trace {
       message: "Trace".to_string(),
       trace: capture_trace(),
@@ -137,36 +178,57 @@ pub trait Logger {
   }
 
   fn clear(&self) {
+    if !self.accepts(LogType::CLEAR) {
+      return;
+    }
     self.raw(LogType::Clear)
   }
 
   fn status(&self, message: impl Into<String>) {
+    if !self.accepts(LogType::STATUS) {
+      return;
+    }
     self.raw(LogType::Status {
       message: message.into(),
     })
   }
 
   fn profile(&self, label: &'static str) {
+    if !self.accepts(LogType::PROFILE) {
+      return;
+    }
     self.raw(LogType::Profile { label })
   }
 
   fn profile_end(&self, label: &'static str) {
+    if !self.accepts(LogType::PROFILE_END) {
+      return;
+    }
     self.raw(LogType::ProfileEnd { label })
   }
 
   fn group(&self, message: impl Into<String>) {
+    if !self.accepts(LogType::GROUP) {
+      return;
+    }
     self.raw(LogType::Group {
       message: message.into(),
     })
   }
 
   fn group_collapsed(&self, message: impl Into<String>) {
+    if !self.accepts(LogType::GROUP_COLLAPSED) {
+      return;
+    }
     self.raw(LogType::GroupCollapsed {
       message: message.into(),
     })
   }
 
   fn group_end(&self) {
+    if !self.accepts(LogType::GROUP_END) {
+      return;
+    }
     self.raw(LogType::GroupEnd)
   }
 
@@ -178,6 +240,9 @@ pub trait Logger {
   }
 
   fn time_log(&self, start: &StartTime) {
+    if !self.accepts(LogType::TIME) {
+      return;
+    }
     let elapsed = start.elapsed();
     let secs = elapsed.as_secs();
     let subsec_nanos = elapsed.subsec_nanos();
@@ -200,6 +265,9 @@ pub trait Logger {
   }
 
   fn time_aggregate_end(&self, start: StartTimeAggregate) {
+    if !self.accepts(LogType::TIME) {
+      return;
+    }
     let secs = start.duration.as_secs();
     let subsec_nanos = start.duration.subsec_nanos();
     self.raw(LogType::Time {
@@ -248,6 +316,7 @@ impl StartTimeAggregate {
 
 pub type CompilationLogging = Arc<DashMap<String, Vec<LogType>, BuildHasherDefault<FxHasher>>>;
 
+#[derive(Debug)]
 pub struct CompilationLogger {
   logging: CompilationLogging,
   name: String,
@@ -268,3 +337,161 @@ impl Logger for CompilationLogger {
     }
   }
 }
+
+/// Report a log of the logger with the name, i.e. `(name, log)`.
+pub type InfrastructureLogCallback = Arc<dyn Fn(&str, LogType) + Send + Sync>;
+
+/// The channel of the logs which aren't related to a compilation, e.g. the cache and the resolver,
+/// i.e. `compiler.getInfrastructureLogger()`.
+///
+/// The logs are filtered by `infrastructureLogging.level`, and then reported to the JS logger
+/// if one is registered, otherwise written to stderr.
+#[derive(Clone)]
+pub struct InfrastructureLogging {
+  accepted_types: u32,
+  callback: Option<InfrastructureLogCallback>,
+}
+
+impl std::fmt::Debug for InfrastructureLogging {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("InfrastructureLogging")
+      .field("accepted_types", &self.accepted_types)
+      .field("callback", &self.callback.as_ref().map(|_| "Fn"))
+      .finish()
+  }
+}
+
+impl Default for InfrastructureLogging {
+  fn default() -> Self {
+    Self::new(&Default::default())
+  }
+}
+
+impl InfrastructureLogging {
+  pub fn new(options: &InfrastructureLoggingOptions) -> Self {
+    Self {
+      accepted_types: options.accepted_types(),
+      callback: None,
+    }
+  }
+
+  pub fn set_callback(&mut self, callback: InfrastructureLogCallback) {
+    self.callback = Some(callback);
+  }
+
+  pub fn get_logger(&self, name: impl Into<String>) -> InfrastructureLogger {
+    InfrastructureLogger {
+      name: name.into(),
+      logging: self.clone(),
+    }
+  }
+}
+
+pub struct InfrastructureLogger {
+  name: String,
+  logging: InfrastructureLogging,
+}
+
+impl Logger for InfrastructureLogger {
+  fn raw(&self, log_type: LogType) {
+    if !self.accepts(log_type.to_bit_flag()) {
+      return;
+    }
+    if let Some(callback) = &self.logging.callback {
+      callback(&self.name, log_type);
+    } else {
+      write_to_stderr(&self.name, log_type);
+    }
+  }
+
+  fn accepts(&self, bit_flag: u32) -> bool {
+    self.logging.accepted_types & bit_flag != 0
+  }
+}
+
+/// Same as the output of `createConsoleLogger` with the node console.
+fn write_to_stderr(name: &str, log_type: LogType) {
+  let (prefix, message) = match log_type {
+    LogType::Error { message, .. } => ("<e>", message),
+    LogType::Warn { message, .. } => ("<w>", message),
+    LogType::Info { message } => ("<i>", message),
+    LogType::Log { message } | LogType::Group { message } | LogType::GroupCollapsed { message } => {
+      ("", message)
+    }
+    LogType::Debug { message } | LogType::Trace { message, .. } => ("<d>", message),
+    LogType::Time {
+      label,
+      secs,
+      subsec_nanos,
+    } => (
+      "<t>",
+      format!(
+        "{}: {} ms",
+        label,
+        secs * 1000 + subsec_nanos as u64 / 1000000
+      ),
+    ),
+    LogType::Status { message } => ("<s>", message),
+    LogType::GroupEnd | LogType::Profile { .. } | LogType::ProfileEnd { .. } | LogType::Clear => {
+      return
+    }
+  };
+  if prefix.is_empty() {
+    eprintln!("    [{name}] {message}");
+  } else {
+    eprintln!("{prefix} [{name}] {message}");
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Mutex;
+
+  use super::*;
+  use crate::InfrastructureLoggingLevel;
+
+  fn collect(
+    options: InfrastructureLoggingOptions,
+  ) -> (InfrastructureLogger, Arc<Mutex<Vec<u32>>>) {
+    let logs = Arc::new(Mutex::new(vec![]));
+    let mut logging = InfrastructureLogging::new(&options);
+    let collected = logs.clone();
+    logging.set_callback(Arc::new(move |_, log_type| {
+      collected
+        .lock()
+        .expect("should lock")
+        .push(log_type.to_bit_flag())
+    }));
+    (logging.get_logger("test"), logs)
+  }
+
+  #[test]
+  fn filter_by_level() {
+    let (logger, logs) = collect(InfrastructureLoggingOptions {
+      level: InfrastructureLoggingLevel::Warn,
+      debug: false,
+    });
+    logger.error("error");
+    logger.warn("warn");
+    logger.info("info");
+    logger.debug("debug");
+    assert_eq!(
+      *logs.lock().expect("should lock"),
+      vec![LogType::ERROR, LogType::WARN]
+    );
+  }
+
+  #[test]
+  fn debug_reports_all() {
+    let (logger, logs) = collect(InfrastructureLoggingOptions {
+      level: InfrastructureLoggingLevel::None,
+      debug: true,
+    });
+    logger.info("info");
+    logger.debug("debug");
+    assert_eq!(
+      *logs.lock().expect("should lock"),
+      vec![LogType::INFO, LogType::DEBUG]
+    );
+  }
+}
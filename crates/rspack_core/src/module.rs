@@ -1,6 +1,7 @@
 use std::fmt::Display;
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::{any::Any, borrow::Cow, fmt::Debug};
 
 use async_trait::async_trait;
@@ -9,19 +10,25 @@ use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_identifier::{Identifiable, Identifier};
 use rspack_sources::Source;
 use rspack_util::ext::{AsAny, DynEq, DynHash};
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::{FxHashSet as HashSet, FxHasher};
+use swc_core::ecma::atoms::JsWord;
 
+use crate::cache::SnapshotManager;
 use crate::tree_shaking::visitor::OptimizeAnalyzeResult;
 use crate::{
-  BoxDependency, ChunkUkey, CodeGenerationResult, Compilation, CompilerContext, CompilerOptions,
-  ConnectionState, Context, ContextModule, DependencyTemplate, ExternalModule, ModuleDependency,
-  ModuleGraph, ModuleType, NormalModule, RawModule, Resolve, SharedPluginDriver, SourceType,
+  BoxDependency, BuildInfoExtras, ChunkUkey, CodeGenerationResult, Compilation, CompilerContext,
+  CompilerOptions, ConnectionState, Context, ContextModule, DependencyTemplate, ExternalModule,
+  ModuleDependency, ModuleGraph, ModuleProfile, ModuleType, NormalModule, RawModule, RemoteModule,
+  Resolve, SharedPluginDriver, SourceType,
 };
 
 pub struct BuildContext<'a> {
   pub compiler_context: CompilerContext,
   pub plugin_driver: SharedPluginDriver,
   pub compiler_options: &'a CompilerOptions,
+  pub snapshot_manager: Arc<SnapshotManager>,
+  /// Profile of the module being built, only available with `profile: true`
+  pub profile: Option<&'a ModuleProfile>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -35,6 +42,10 @@ pub struct BuildInfo {
   pub missing_dependencies: HashSet<PathBuf>,
   pub build_dependencies: HashSet<PathBuf>,
   pub asset_filenames: HashSet<String>,
+  /// The reason why the module can't be concatenated with others, e.g. `eval()` is used
+  pub module_concatenation_bailout: Option<String>,
+  /// Metadata stashed by loaders, e.g. for a loader to communicate with its plugin
+  pub build_info_extras: BuildInfoExtras,
 }
 
 #[derive(Debug, Default, Clone, Hash)]
@@ -47,6 +58,18 @@ pub enum BuildMetaExportsType {
   Dynamic,
 }
 
+/// The exports of a CommonJS module collected by the parser.
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
+pub enum BuildMetaProvidedExports {
+  /// The exports can't be known statically
+  #[default]
+  Unknown,
+  /// All the exports are known
+  Names(Vec<JsWord>),
+  /// Some of the exports are known, e.g. `module.exports = { ...base, extra }`
+  Partial(Vec<JsWord>),
+}
+
 #[derive(Debug, Clone)]
 pub enum ExportsType {
   DefaultOnly,
@@ -100,12 +123,28 @@ pub struct BuildMeta {
   pub strict: bool,
   pub strict_harmony_module: bool,
   pub is_async: bool,
+  pub has_top_level_await: bool,
   pub esm: bool,
   pub exports_type: BuildMetaExportsType,
   pub default_object: BuildMetaDefaultObject,
   pub module_argument: ModuleArgument,
   pub exports_argument: ExportsArgument,
   pub side_effect_free: Option<bool>,
+  pub provided_exports: BuildMetaProvidedExports,
+}
+
+impl BuildMeta {
+  /// The hash of the fields which affect how the module is imported by the other modules.
+  pub fn exports_hash(&self) -> u64 {
+    let mut hasher = FxHasher::default();
+    self.esm.hash(&mut hasher);
+    self.is_async.hash(&mut hasher);
+    self.exports_type.hash(&mut hasher);
+    self.default_object.hash(&mut hasher);
+    self.side_effect_free.hash(&mut hasher);
+    self.provided_exports.hash(&mut hasher);
+    hasher.finish()
+  }
 }
 
 // webpack build info
@@ -153,7 +192,7 @@ pub trait Module: Debug + Send + Sync + AsAny + DynHash + DynEq + Identifiable {
     self.update_hash(&mut hasher);
 
     let build_info = BuildInfo {
-      hash: Some(hasher.digest(&build_context.compiler_options.output.hash_digest)),
+      hash: Some(build_context.compiler_options.output.digest(hasher)),
       ..Default::default()
     };
 
@@ -274,6 +313,10 @@ impl dyn Module + '_ {
   pub fn downcast_mut<T: Module + Any>(&mut self) -> Option<&mut T> {
     self.as_any_mut().downcast_mut::<T>()
   }
+
+  pub fn downcast<T: Module + Any>(self: Box<Self>) -> Option<Box<T>> {
+    self.into_any().downcast::<T>().ok()
+  }
 }
 
 macro_rules! impl_module_downcast_helpers {
@@ -314,11 +357,12 @@ impl_module_downcast_helpers!(NormalModule, normal_module);
 impl_module_downcast_helpers!(RawModule, raw_module);
 impl_module_downcast_helpers!(ContextModule, context_module);
 impl_module_downcast_helpers!(ExternalModule, external_module);
+impl_module_downcast_helpers!(RemoteModule, remote_module);
 
 #[cfg(test)]
 mod test {
   use std::borrow::Cow;
-  use std::hash::Hash;
+  use std::hash::{Hash, Hasher};
 
   use rspack_error::{Result, TWithDiagnosticArray};
   use rspack_identifier::{Identifiable, Identifier};
@@ -12,9 +12,10 @@ pub struct ModuleFactoryCreateData {
   pub dependency: BoxDependency,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct ModuleFactoryResult {
-  pub module: BoxModule,
+  /// `None` if no module is created for the dependency, e.g. it's skipped by `beforeResolve`
+  pub module: Option<BoxModule>,
   pub file_dependencies: HashSet<PathBuf>,
   pub context_dependencies: HashSet<PathBuf>,
   pub missing_dependencies: HashSet<PathBuf>,
@@ -24,7 +25,7 @@ pub struct ModuleFactoryResult {
 impl ModuleFactoryResult {
   pub fn new(module: BoxModule) -> Self {
     Self {
-      module,
+      module: Some(module),
       file_dependencies: Default::default(),
       context_dependencies: Default::default(),
       missing_dependencies: Default::default(),
@@ -13,12 +13,52 @@ pub use connection::*;
 use crate::{
   to_identifier, BoxDependency, BoxModule, BuildDependency, BuildInfo, BuildMeta,
   DependencyCondition, DependencyId, ExportsInfo, Module, ModuleGraphModule, ModuleIdentifier,
-  ModuleProfile,
+  ModuleIssuer, ModuleProfile,
 };
 
 // TODO Here request can be used JsWord
 pub type ImportVarMap = HashMap<String /* request */, String /* import_var */>;
 
+/// Module graph changes caused by a finished module build.
+/// They are collected up front and applied to the graph in one go by [ModuleGraph::apply_build_mutations].
+#[derive(Debug)]
+pub struct ModuleBuildMutation {
+  pub module: BoxModule,
+  pub dependencies: Vec<DependencyId>,
+  /// Requests of module dependencies in source order, used to allocate import vars.
+  pub requests: Vec<String>,
+  pub build_info: BuildInfo,
+  pub build_meta: BuildMeta,
+  pub profile: Option<Box<ModuleProfile>>,
+}
+
+impl ModuleBuildMutation {
+  pub fn new(
+    module: BoxModule,
+    dependencies: &[BoxDependency],
+    build_info: BuildInfo,
+    build_meta: BuildMeta,
+    profile: Option<Box<ModuleProfile>>,
+  ) -> Self {
+    let requests = dependencies
+      .iter()
+      .filter_map(|dependency| dependency.as_module_dependency())
+      .map(|dependency| dependency.request().to_string())
+      .collect();
+    Self {
+      module,
+      dependencies: dependencies
+        .iter()
+        .map(|dependency| *dependency.id())
+        .collect(),
+      requests,
+      build_info,
+      build_meta,
+      profile,
+    }
+  }
+}
+
 #[derive(Debug, Default)]
 pub struct ModuleGraph {
   dependency_id_to_module_identifier: HashMap<DependencyId, ModuleIdentifier>,
@@ -325,6 +365,136 @@ impl ModuleGraph {
     removed
   }
 
+  /// Remove the connection of the dependency, unlike [ModuleGraph::remove_connection_by_dependency]
+  /// the dependency is kept, so it's still rendered by its parent module, as a missing module.
+  pub fn remove_connection(
+    &mut self,
+    dependency_id: &DependencyId,
+  ) -> Option<ModuleGraphConnection> {
+    let connection_id = *self.dependency_id_to_connection_id.get(dependency_id)?;
+    let connection = self.connections[*connection_id].clone()?;
+    self.revoke_connection(connection_id);
+    self.connection_id_to_dependency_id.remove(&connection_id);
+    self.connection_to_condition.remove(&connection_id);
+    self.reset_issuer(&connection.module_identifier);
+    Some(connection)
+  }
+
+  /// Remove a module which is not referenced by any other module anymore, along with its outgoing
+  /// connections and dependencies. Returns the modules referenced by the removed module, which
+  /// might become orphans as well.
+  pub fn remove_module_if_orphan(
+    &mut self,
+    module_identifier: &ModuleIdentifier,
+  ) -> Result<Vec<ModuleIdentifier>> {
+    let mgm = self
+      .module_graph_module_by_identifier(module_identifier)
+      .ok_or_else(|| {
+        internal_error!("Failed to remove module: {module_identifier} is not found")
+      })?;
+    if !mgm.incoming_connections.is_empty() {
+      return Err(internal_error!(
+        "Failed to remove module: {module_identifier} is still referenced by {} connection(s)",
+        mgm.incoming_connections.len()
+      ));
+    }
+    let mgm = self
+      .module_identifier_to_module_graph_module
+      .remove(module_identifier)
+      .expect("should have mgm");
+    self.module_identifier_to_module.remove(module_identifier);
+    self.import_var_map.remove(module_identifier);
+
+    let mut referenced: Vec<ModuleIdentifier> = vec![];
+    for connection_id in mgm.outgoing_connections {
+      if let Some(connection) = &self.connections[*connection_id]
+        && !referenced.contains(&connection.module_identifier)
+      {
+        referenced.push(connection.module_identifier);
+      }
+      self.revoke_connection(connection_id);
+      self.connection_id_to_dependency_id.remove(&connection_id);
+      self.connection_to_condition.remove(&connection_id);
+    }
+    for dependency_id in mgm.dependencies.iter() {
+      self
+        .dependency_id_to_module_identifier
+        .remove(dependency_id);
+      self.remove_dependency(dependency_id);
+    }
+    for module_identifier in &referenced {
+      self.reset_issuer(module_identifier);
+    }
+    Ok(referenced)
+  }
+
+  /// Rewire the incoming connections of the module `from` to the module `to`.
+  pub fn replace_module(&mut self, from: &ModuleIdentifier, to: &ModuleIdentifier) -> Result<()> {
+    if from == to {
+      return Ok(());
+    }
+    if self.module_graph_module_by_identifier(to).is_none() {
+      return Err(internal_error!(
+        "Failed to replace module: {to} is not found"
+      ));
+    }
+    let from_mgm = self
+      .module_graph_module_by_identifier_mut(from)
+      .ok_or_else(|| internal_error!("Failed to replace module: {from} is not found"))?;
+    let incoming_connections = std::mem::take(&mut from_mgm.incoming_connections);
+    let issuer = from_mgm.get_issuer().clone();
+    for connection_id in &incoming_connections {
+      let Some(connection) = self.connections[**connection_id].as_mut() else {
+        continue;
+      };
+      let previous = connection.clone();
+      connection.module_identifier = *to;
+      let connection = connection.clone();
+      self.connections_map.remove(&previous);
+      self.connections_map.insert(connection, *connection_id);
+      self
+        .dependency_id_to_module_identifier
+        .insert(previous.dependency_id, *to);
+    }
+    let to_mgm = self
+      .module_graph_module_by_identifier_mut(to)
+      .expect("should have mgm");
+    to_mgm.incoming_connections.extend(incoming_connections);
+    if matches!(to_mgm.get_issuer(), ModuleIssuer::Unset) {
+      to_mgm.set_issuer(issuer);
+    }
+    self.reset_issuer(from);
+    Ok(())
+  }
+
+  /// The issuer of the module is reset to the origin of one of its remaining incoming connections,
+  /// when the connections from the issuer are removed.
+  fn reset_issuer(&mut self, module_identifier: &ModuleIdentifier) {
+    let Some(mgm) = self.module_graph_module_by_identifier(module_identifier) else {
+      return;
+    };
+    let Some(issuer) = mgm.get_issuer().identifier() else {
+      return;
+    };
+    let origins = mgm
+      .incoming_connections
+      .iter()
+      .filter_map(|id| self.connection_by_connection_id(id))
+      .map(|connection| connection.original_module_identifier)
+      .collect::<Vec<_>>();
+    if origins.iter().any(|origin| origin.as_ref() == Some(issuer)) {
+      return;
+    }
+    let issuer = match origins.first() {
+      Some(origin) => ModuleIssuer::from_identifier(*origin),
+      None => ModuleIssuer::Unset,
+    };
+    self
+      .module_graph_module_by_identifier_mut(module_identifier)
+      .expect("should have mgm")
+      .set_issuer(issuer);
+  }
+
   pub fn get_pre_order_index(&self, module_identifier: &ModuleIdentifier) -> Option<u32> {
     self
       .module_graph_module_by_identifier(module_identifier)
@@ -435,6 +605,11 @@ impl ModuleGraph {
     Some((dependency_id, original_module_identifier))
   }
 
+  /// Remove module from module graph and return it, its module graph module is kept
+  pub fn take_module(&mut self, module_identifier: &ModuleIdentifier) -> Option<BoxModule> {
+    self.module_identifier_to_module.remove(module_identifier)
+  }
+
   /// Remove module from module graph and return parent module identifier and dependency pair
   pub fn revoke_module(&mut self, module_identifier: &ModuleIdentifier) -> Vec<BuildDependency> {
     self.module_identifier_to_module.remove(module_identifier);
@@ -457,6 +632,54 @@ impl ModuleGraph {
     }
   }
 
+  /// Apply the build results of a batch of modules. Nothing is applied if the module graph
+  /// module of any of them is missing.
+  pub fn apply_build_mutations(&mut self, mutations: Vec<ModuleBuildMutation>) -> Result<()> {
+    if let Some(mutation) = mutations.iter().find(|mutation| {
+      self
+        .module_graph_module_by_identifier(&mutation.module.identifier())
+        .is_none()
+    }) {
+      return Err(internal_error!(
+        "Failed to apply the build result of {}, its module graph module is not found",
+        mutation.module.identifier()
+      ));
+    }
+    self.module_identifier_to_module.reserve(mutations.len());
+    for mutation in mutations {
+      self.apply_build_mutation(mutation)?;
+    }
+    Ok(())
+  }
+
+  pub fn apply_build_mutation(&mut self, mutation: ModuleBuildMutation) -> Result<()> {
+    let ModuleBuildMutation {
+      module,
+      dependencies,
+      requests,
+      build_info,
+      build_meta,
+      profile,
+    } = mutation;
+    let module_identifier = module.identifier();
+    let Some(mgm) = self.module_graph_module_by_identifier_mut(&module_identifier) else {
+      return Err(internal_error!(
+        "Failed to apply the build result of {module_identifier}, its module graph module is not found"
+      ));
+    };
+    mgm.dependencies = Box::new(dependencies);
+    if let Some(profile) = profile {
+      mgm.set_profile(profile);
+    }
+    mgm.build_info = Some(build_info);
+    mgm.build_meta = Some(build_meta);
+    for request in &requests {
+      self.set_dependency_import_var(module_identifier, request);
+    }
+    self.add_module(module);
+    Ok(())
+  }
+
   pub fn set_module_build_info_and_meta(
     &mut self,
     module_identifier: &ModuleIdentifier,
@@ -488,8 +711,12 @@ impl ModuleGraph {
       for item in files {
         if build_info.file_dependencies.contains(item)
           || build_info.build_dependencies.contains(item)
-          || build_info.context_dependencies.contains(item)
           || build_info.missing_dependencies.contains(item)
+          // a file added to or removed from the directories of the context dependencies
+          || build_info
+            .context_dependencies
+            .iter()
+            .any(|context| item.starts_with(context))
         {
           return true;
         }
@@ -544,9 +771,9 @@ mod test {
   use rspack_sources::Source;
 
   use crate::{
-    BoxDependency, BuildContext, BuildResult, CodeGenerationResult, Compilation, Context,
-    Dependency, DependencyId, Module, ModuleDependency, ModuleGraph, ModuleGraphModule,
-    ModuleIdentifier, ModuleType, SourceType,
+    BoxDependency, BuildContext, BuildInfo, BuildMeta, BuildResult, CodeGenerationResult,
+    Compilation, Context, Dependency, DependencyId, Module, ModuleBuildMutation, ModuleDependency,
+    ModuleGraph, ModuleGraphModule, ModuleIdentifier, ModuleType, SourceType,
   };
 
   // Define a detailed node type for `ModuleGraphModule`s
@@ -729,4 +956,171 @@ mod test {
     assert!(mgm_b.outgoing_connections.is_empty());
     assert!(mgm_c.incoming_connections.is_empty());
   }
+
+  #[test]
+  fn test_remove_and_replace_module() {
+    let mut mg = ModuleGraph::default();
+    let a = node!("a");
+    let b = node!("b");
+    let c = node!("c");
+    let d = node!("d");
+    let (a_id, b_id, c_id, d_id) = (
+      a.identifier(),
+      b.identifier(),
+      c.identifier(),
+      d.identifier(),
+    );
+    let a_to_b = edge!(Some(a_id), b_id.as_str());
+    let b_to_c = edge!(Some(b_id), c_id.as_str());
+    for m in [a, b, c, d] {
+      add_module_to_graph(&mut mg, Box::new(m));
+    }
+    let a_to_b_id = link_modules_with_dependency(&mut mg, Some(&a_id), &b_id, Box::new(a_to_b));
+    link_modules_with_dependency(&mut mg, Some(&b_id), &c_id, Box::new(b_to_c));
+
+    // b is still referenced by a
+    assert!(mg.remove_module_if_orphan(&b_id).is_err());
+
+    mg.replace_module(&b_id, &d_id).expect("should replace");
+    assert_eq!(
+      mg.module_identifier_by_dependency_id(&a_to_b_id),
+      Some(&d_id)
+    );
+    assert!(mgm(&mg, &b_id).incoming_connections.is_empty());
+    assert_eq!(mgm(&mg, &d_id).incoming_connections.len(), 1);
+
+    let referenced = mg.remove_module_if_orphan(&b_id).expect("should remove");
+    assert_eq!(referenced, vec![c_id]);
+    assert!(mg.module_by_identifier(&b_id).is_none());
+    assert!(mgm(&mg, &c_id).incoming_connections.is_empty());
+    mg.remove_module_if_orphan(&c_id).expect("should remove");
+
+    let connection = mg.remove_connection(&a_to_b_id).expect("should remove");
+    assert_eq!(connection.module_identifier, d_id);
+    assert!(mg.dependency_by_id(&a_to_b_id).is_some());
+    assert!(mgm(&mg, &a_id).outgoing_connections.is_empty());
+    assert!(mgm(&mg, &d_id).incoming_connections.is_empty());
+  }
+
+  #[test]
+  fn test_apply_build_mutation() {
+    let mut mg = ModuleGraph::default();
+    let a = node!("a");
+    let a_id = a.identifier();
+    let a_to_b: BoxDependency = Box::new(edge!(Some(a_id), "./b"));
+    let a_to_c: BoxDependency = Box::new(edge!(Some(a_id), "./c"));
+    let dependencies = vec![a_to_b, a_to_c];
+    let dependency_ids = dependencies.iter().map(|d| *d.id()).collect::<Vec<_>>();
+    mg.add_module_graph_module(ModuleGraphModule::new(a_id, ModuleType::Js));
+    mg.apply_build_mutation(ModuleBuildMutation::new(
+      Box::new(a),
+      &dependencies,
+      BuildInfo::default(),
+      BuildMeta::default(),
+      None,
+    ))
+    .expect("should apply");
+
+    let mgm_a = mgm(&mg, &a_id);
+    assert_eq!(*mgm_a.dependencies, dependency_ids);
+    assert!(mgm_a.build_info.is_some());
+    assert!(mgm_a.build_meta.is_some());
+    assert!(mg.module_by_identifier(&a_id).is_some());
+    // Import vars are allocated in source order
+    assert!(mg.get_import_var(&a_id, "./b").ends_with("_0_"));
+    assert!(mg.get_import_var(&a_id, "./c").ends_with("_1_"));
+
+    // The module graph module of `d` is missing
+    let d = node!("d");
+    let d_id = d.identifier();
+    assert!(mg
+      .apply_build_mutation(ModuleBuildMutation::new(
+        Box::new(d),
+        &[],
+        BuildInfo::default(),
+        BuildMeta::default(),
+        None,
+      ))
+      .is_err());
+    assert!(mg.module_by_identifier(&d_id).is_none());
+  }
+
+  fn serialize(mg: &ModuleGraph) -> Vec<String> {
+    let mut serialized = mg
+      .module_identifier_to_module_graph_module
+      .iter()
+      .map(|(id, mgm)| {
+        let mut import_vars = mg
+          .import_var_map
+          .get(id)
+          .map(|vars| vars.iter().map(|v| format!("{v:?}")).collect::<Vec<_>>())
+          .unwrap_or_default();
+        import_vars.sort();
+        format!(
+          "{id} module: {} dependencies: {} build info: {} build meta: {} import vars: {import_vars:?}",
+          mg.module_by_identifier(id).is_some(),
+          // the dependency ids are allocated globally
+          mgm.dependencies.len(),
+          mgm.build_info.is_some(),
+          mgm.build_meta.is_some(),
+        )
+      })
+      .collect::<Vec<_>>();
+    serialized.sort();
+    serialized
+  }
+
+  #[test]
+  fn test_apply_build_mutations() {
+    let build = || {
+      let mut mg = ModuleGraph::default();
+      let mut mutations = vec![];
+      for (name, requests) in [
+        ("a", vec!["./b", "./c"]),
+        ("b", vec!["./c"]),
+        ("c", vec![]),
+        ("d", vec!["./a", "./a", "./b"]),
+      ] {
+        let module = Node(name);
+        let id = module.identifier();
+        mg.add_module_graph_module(ModuleGraphModule::new(id, ModuleType::Js));
+        let dependencies = requests
+          .into_iter()
+          .map(|request| Box::new(edge!(Some(id), request)) as BoxDependency)
+          .collect::<Vec<_>>();
+        mutations.push(ModuleBuildMutation::new(
+          Box::new(module),
+          &dependencies,
+          BuildInfo::default(),
+          BuildMeta::default(),
+          None,
+        ));
+      }
+      (mg, mutations)
+    };
+
+    let (mut batched, mutations) = build();
+    batched
+      .apply_build_mutations(mutations)
+      .expect("should apply");
+    let (mut one_by_one, mutations) = build();
+    for mutation in mutations {
+      one_by_one
+        .apply_build_mutation(mutation)
+        .expect("should apply");
+    }
+    assert_eq!(serialize(&batched), serialize(&one_by_one));
+
+    // Nothing is applied if a module graph module of the batch is missing
+    let (mut mg, mut mutations) = build();
+    mutations.push(ModuleBuildMutation::new(
+      Box::new(node!("e")),
+      &[],
+      BuildInfo::default(),
+      BuildMeta::default(),
+      None,
+    ));
+    assert!(mg.apply_build_mutations(mutations).is_err());
+    assert!(mg.module_identifier_to_module.is_empty());
+  }
 }
@@ -29,6 +29,8 @@ pub struct ModuleGraphModule {
   pub build_meta: Option<BuildMeta>,
   pub exports: Box<ExportsInfo>,
   pub profile: Option<Box<ModuleProfile>>,
+  /// The reasons why the module isn't optimized, e.g. concatenated, shown in the stats
+  pub optimization_bailout: Vec<String>,
 }
 
 impl ModuleGraphModule {
@@ -50,6 +52,7 @@ impl ModuleGraphModule {
       build_meta: None,
       exports: Box::new(ExportsInfo::new()),
       profile: None,
+      optimization_bailout: vec![],
     }
   }
 
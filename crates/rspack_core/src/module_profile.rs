@@ -44,12 +44,22 @@ impl ModulePhaseProfile {
   }
 }
 
+#[derive(Debug)]
+pub struct LoaderProfile {
+  pub identifier: String,
+  pub duration: Duration,
+}
+
 #[derive(Debug, Default)]
 pub struct ModuleProfile {
   pub factory: ModulePhaseProfile,
   // pub restoring: ModulePhaseProfile,
   pub integration: ModulePhaseProfile,
   pub building: ModulePhaseProfile,
+  /// Time spent in each loader while building, in the order of loaders
+  pub loaders: OnceCell<Vec<LoaderProfile>>,
+  /// Parsing phase of building, after all loaders are run
+  pub parsing: ModulePhaseProfile,
   // pub storing: ModulePhaseProfile,
 
   // pub additional_factory_times: Vec<TimeRange>,
@@ -131,6 +141,28 @@ impl ModuleProfile {
       .expect("should only call once");
   }
 
+  pub fn mark_parsing_start(&self) {
+    self
+      .parsing
+      .range
+      .start
+      .set(Instant::now())
+      .expect("should only call once");
+  }
+
+  pub fn mark_parsing_end(&self) {
+    self
+      .parsing
+      .range
+      .end
+      .set(Instant::now())
+      .expect("should only call once");
+  }
+
+  pub fn set_loaders(&self, loaders: Vec<LoaderProfile>) {
+    self.loaders.set(loaders).expect("should only call once");
+  }
+
   // TODO: store module to cache is not implemented yet
   // pub fn mark_storing_start(&self) {
   //   self
@@ -16,7 +16,7 @@ use rspack_error::{
 };
 use rspack_hash::RspackHash;
 use rspack_identifier::Identifiable;
-use rspack_loader_runner::{run_loaders, Content, ResourceData};
+use rspack_loader_runner::{run_loaders_with_profile, Content, LoaderError, ResourceData};
 use rspack_sources::{
   BoxSource, CachedSource, OriginalSource, RawSource, Source, SourceExt, SourceMap,
   SourceMapSource, WithoutOriginalOptions,
@@ -26,11 +26,15 @@ use rustc_hash::FxHasher;
 use serde_json::json;
 
 use crate::{
-  add_connection_states, contextify, get_context, BoxLoader, BoxModule, BuildContext, BuildInfo,
-  BuildMeta, BuildResult, CodeGenerationResult, Compilation, CompilerOptions, ConnectionState,
-  Context, DependencyTemplate, GenerateContext, GeneratorOptions, LibIdentOptions,
+  add_connection_states,
+  cache::{Snapshot, SnapshotManager},
+  contextify, get_context, readable_request, BoxLoader, BoxModule, BuildContext, BuildInfo,
+  BuildMeta, BuildResult, CodeGenerationData, CodeGenerationResult, Compilation, CompilerContext,
+  CompilerOptions, ConcatenationScope, ConnectionState, Context, DependencyTemplate,
+  DependencyType, GenerateContext, GeneratorOptions, LibIdentOptions, LoaderProfile,
   LoaderRunnerPluginProcessResource, Module, ModuleDependency, ModuleGraph, ModuleIdentifier,
-  ModuleType, ParseContext, ParseResult, ParserAndGenerator, ParserOptions, Resolve, SourceType,
+  ModuleType, ParseContext, ParseResult, ParserAndGenerator, ParserOptions, Resolve,
+  RuntimeGlobals, SnapshotStrategy, SourceType,
 };
 
 bitflags! {
@@ -72,6 +76,33 @@ impl ModuleIssuer {
   }
 }
 
+/// Where a loader of a module comes from.
+///
+/// Loaders of a module are kept in the pitching order, that is post loaders first,
+/// then inline and normal loaders, and pre loaders last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoaderItemOrigin {
+  /// From a rule with `enforce: "pre"`
+  Pre,
+  /// From a rule without `enforce`
+  Normal,
+  /// From a rule with `enforce: "post"`
+  Post,
+  /// From the request, e.g. `import "loader!./file"`
+  Inline,
+}
+
+impl LoaderItemOrigin {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      LoaderItemOrigin::Pre => "pre",
+      LoaderItemOrigin::Normal => "normal",
+      LoaderItemOrigin::Post => "post",
+      LoaderItemOrigin::Inline => "inline",
+    }
+  }
+}
+
 #[derive(Derivative)]
 #[derivative(Debug)]
 pub struct NormalModule {
@@ -92,11 +123,13 @@ pub struct NormalModule {
   match_resource: Option<ResourceData>,
   /// Resource data (path, query, fragment etc.)
   resource_data: ResourceData,
-  /// Loaders for the module
+  /// Loaders for the module with their origins, in the pitching order
   #[derivative(Debug = "ignore")]
-  loaders: Vec<BoxLoader>,
+  loaders: Vec<(BoxLoader, LoaderItemOrigin)>,
   /// Whether loaders list contains inline loader
   contains_inline_loader: bool,
+  /// Settings attached by plugins while creating the module, exposed to loaders
+  settings: ModuleSettings,
 
   /// Original content of this module, will be available after module build
   original_source: Option<BoxSource>,
@@ -117,13 +150,26 @@ pub struct NormalModule {
 
   code_generation_dependencies: Option<Vec<Box<dyn ModuleDependency>>>,
   presentational_dependencies: Option<Vec<Box<dyn DependencyTemplate>>>,
+
+  /// Snapshot of the last successful build, used to skip the next build of this module
+  build_snapshot: Option<Box<BuildSnapshot>>,
+  /// Whether the last build reused the previous build result
+  build_skipped: bool,
+  /// Result of the previous successful build, returned instead if the next build fails
+  rebuild_fallback: Option<Box<BuildResult>>,
+  /// The error messages of the last build if it failed and `rebuild_fallback` is returned
+  rebuild_errors: Option<Vec<String>>,
+  /// The dependencies of the last successful build, which are kept while the builds fail
+  /// before they are collected, so the module can be recovered by HMR once it's fixed
+  last_dependencies: Option<Vec<BoxDependency>>,
 }
 
 #[derive(Debug)]
 pub enum NormalModuleSource {
   Unbuild,
   BuiltSucceed(BoxSource),
-  BuiltFailed(String),
+  /// The messages of the errors which fail the build
+  BuiltFailed(Vec<String>),
 }
 
 impl NormalModuleSource {
@@ -134,8 +180,7 @@ impl NormalModuleSource {
           .iter()
           .filter(|d| d.severity == Severity::Error)
           .map(|d| d.message.clone())
-          .collect::<Vec<String>>()
-          .join("\n"),
+          .collect(),
       )
     } else {
       NormalModuleSource::BuiltSucceed(source)
@@ -143,6 +188,8 @@ impl NormalModuleSource {
   }
 }
 
+pub type ModuleSettings = std::collections::HashMap<String, serde_json::Value>;
+
 pub static DEBUG_ID: AtomicUsize = AtomicUsize::new(1);
 
 impl NormalModule {
@@ -158,9 +205,10 @@ impl NormalModule {
     match_resource: Option<ResourceData>,
     resource_data: ResourceData,
     resolve_options: Option<Box<Resolve>>,
-    loaders: Vec<BoxLoader>,
+    loaders: Vec<(BoxLoader, LoaderItemOrigin)>,
     options: Arc<CompilerOptions>,
     contains_inline_loader: bool,
+    settings: ModuleSettings,
   ) -> Self {
     let module_type = module_type.into();
     let identifier = if module_type == ModuleType::Js {
@@ -183,6 +231,7 @@ impl NormalModule {
       resolve_options,
       loaders,
       contains_inline_loader,
+      settings,
       original_source: None,
       source: NormalModuleSource::Unbuild,
       debug_id: DEBUG_ID.fetch_add(1, Ordering::Relaxed),
@@ -191,6 +240,11 @@ impl NormalModule {
       cached_source_sizes: DashMap::default(),
       code_generation_dependencies: None,
       presentational_dependencies: None,
+      build_snapshot: None,
+      last_dependencies: None,
+      build_skipped: false,
+      rebuild_fallback: None,
+      rebuild_errors: None,
     }
   }
 
@@ -222,13 +276,137 @@ impl NormalModule {
     &mut self.source
   }
 
-  pub fn loaders_mut_vec(&mut self) -> &mut Vec<BoxLoader> {
+  pub fn loaders_mut_vec(&mut self) -> &mut Vec<(BoxLoader, LoaderItemOrigin)> {
     &mut self.loaders
   }
 
+  /// Insert `loader` right before the first loader matched by `matcher`.
+  /// Returns `false` if no loader is matched, in which case the loader is not inserted.
+  pub fn insert_loader_before(
+    &mut self,
+    matcher: impl Fn(&BoxLoader, LoaderItemOrigin) -> bool,
+    loader: BoxLoader,
+    origin: LoaderItemOrigin,
+  ) -> bool {
+    let Some(index) = self.loaders.iter().position(|(l, o)| matcher(l, *o)) else {
+      return false;
+    };
+    self.loaders.insert(index, (loader, origin));
+    true
+  }
+
+  /// Insert `loader` right after the last loader matched by `matcher`.
+  /// Returns `false` if no loader is matched, in which case the loader is not inserted.
+  pub fn insert_loader_after(
+    &mut self,
+    matcher: impl Fn(&BoxLoader, LoaderItemOrigin) -> bool,
+    loader: BoxLoader,
+    origin: LoaderItemOrigin,
+  ) -> bool {
+    let Some(index) = self.loaders.iter().rposition(|(l, o)| matcher(l, *o)) else {
+      return false;
+    };
+    self.loaders.insert(index + 1, (loader, origin));
+    true
+  }
+
+  pub fn generator_options(&self) -> Option<&GeneratorOptions> {
+    self.generator_options.as_ref()
+  }
+
+  pub fn settings(&self) -> &ModuleSettings {
+    &self.settings
+  }
+
   pub fn contains_inline_loader(&self) -> bool {
     self.contains_inline_loader
   }
+
+  /// Whether the last build skipped loaders and parsing because all of its dependencies were unchanged
+  pub fn is_build_skipped(&self) -> bool {
+    self.build_skipped
+  }
+
+  /// Build the module again next time even if none of its dependencies have changed.
+  pub fn invalidate_build_snapshot(&mut self) {
+    self.build_snapshot = None;
+  }
+
+  /// Keep the module intact if the next build fails, `build_result` is returned along with
+  /// the diagnostics of the failed build instead. See [crate::Compilation::rebuild_module].
+  pub fn set_rebuild_fallback(&mut self, build_result: BuildResult) {
+    self.rebuild_fallback = Some(Box::new(build_result));
+  }
+
+  /// Take the error messages of the last build if it failed and the fallback set by
+  /// [NormalModule::set_rebuild_fallback] is returned instead.
+  pub fn take_rebuild_errors(&mut self) -> Option<Vec<String>> {
+    self.rebuild_errors.take()
+  }
+
+  /// Generate the JavaScript source as a part of a concatenated module, the exports are
+  /// registered to `concatenation_scope` rather than defined on the exports object.
+  pub fn generate_concatenated(
+    &self,
+    compilation: &Compilation,
+    runtime_requirements: &mut RuntimeGlobals,
+    concatenation_scope: &mut ConcatenationScope,
+  ) -> Result<BoxSource> {
+    let NormalModuleSource::BuiltSucceed(source) = &self.source else {
+      return Err(internal_error!(
+        "Failed to concatenate module {} because it isn't built",
+        self.readable_identifier(&compilation.options.context)
+      ));
+    };
+    self.parser_and_generator.generate(
+      source,
+      self,
+      &mut GenerateContext {
+        compilation,
+        module_generator_options: self.generator_options.as_ref(),
+        runtime_requirements,
+        data: &mut CodeGenerationData::default(),
+        requested_source_type: SourceType::JavaScript,
+        concatenation_scope: Some(concatenation_scope),
+      },
+    )
+  }
+}
+
+/// Generate the source of each source type. A failure of one source type is reported as a
+/// diagnostic rather than failing the whole module, so the other source types are still emitted.
+/// If the JavaScript source fails, a stub throwing the error is emitted to make it visible at runtime.
+fn generate_source_types(
+  source_types: &[SourceType],
+  module_name: &str,
+  generator_name: &str,
+  mut generate: impl FnMut(SourceType) -> Result<BoxSource>,
+) -> (Vec<(SourceType, BoxSource)>, Vec<Diagnostic>) {
+  let mut sources = vec![];
+  let mut diagnostics = vec![];
+  for source_type in source_types {
+    match generate(*source_type) {
+      Ok(source) => sources.push((*source_type, CachedSource::new(source).boxed())),
+      Err(err) => {
+        let message = format!(
+          "Failed to generate {source_type} source of module {module_name} with the {generator_name} generator: {err}"
+        );
+        if *source_type == SourceType::JavaScript {
+          sources.push((
+            *source_type,
+            RawSource::from(format!("throw new Error({});\n", json!(message))).boxed(),
+          ));
+        }
+        diagnostics.push(Diagnostic::error(
+          "Code generation error".to_string(),
+          message,
+          0,
+          0,
+        ));
+      }
+    }
+  }
+  (sources, diagnostics)
 }
 
 impl Identifiable for NormalModule {
@@ -253,7 +431,7 @@ impl Module for NormalModule {
   }
 
   fn readable_identifier(&self, context: &Context) -> Cow<str> {
-    Cow::Owned(context.shorten(&self.user_request))
+    Cow::Owned(readable_request(context, &self.user_request))
   }
 
   fn size(&self, source_type: &SourceType) -> f64 {
@@ -270,41 +448,114 @@ impl Module for NormalModule {
     &mut self,
     build_context: BuildContext<'_>,
   ) -> Result<TWithDiagnosticArray<BuildResult>> {
+    let rebuild_fallback = self.rebuild_fallback.take();
+    self.rebuild_errors = None;
+    let snapshot_strategy = &build_context.compiler_options.snapshot.module;
+    if let Some(snapshot) = self.build_snapshot.take() {
+      if snapshot.is_valid(&build_context.snapshot_manager).await {
+        self.build_skipped = true;
+        let build_result = snapshot.build_result.clone();
+        self.build_snapshot = Some(snapshot);
+        return Ok(build_result.with_empty_diagnostic());
+      }
+    }
+    self.build_skipped = false;
+    // The source may change on rebuild, sizes are computed again lazily.
+    self.cached_source_sizes.clear();
+
     let mut build_info = BuildInfo::default();
     let mut build_meta = BuildMeta::default();
     let mut diagnostics = Vec::new();
 
     build_context.plugin_driver.before_loaders(self).await?;
 
-    let loader_result = run_loaders(
-      &self.loaders,
+    let loaders = self
+      .loaders
+      .iter()
+      .map(|(loader, _)| loader.clone())
+      .collect::<Vec<_>>();
+    let loader_result = run_loaders_with_profile(
+      &loaders,
       &self.resource_data,
       &[Box::new(LoaderRunnerPluginProcessResource {
         plugin_driver: build_context.plugin_driver.clone(),
       })],
-      build_context.compiler_context,
+      CompilerContext {
+        module_settings: Arc::new(self.settings.clone()),
+        ..build_context.compiler_context
+      },
+      build_context.profile.is_some(),
     )
     .await;
-    let (loader_result, ds) = match loader_result {
+    let (mut loader_result, ds) = match loader_result {
       Ok(r) => r.split_into_parts(),
-      Err(e) => {
-        self.source = NormalModuleSource::BuiltFailed(e.to_string());
+      Err(LoaderError {
+        error: e,
+        cacheable,
+        file_dependencies,
+        context_dependencies,
+        missing_dependencies,
+        build_dependencies,
+        diagnostics: ds,
+      }) => {
+        if let Some(rebuild_fallback) = rebuild_fallback {
+          // The dependencies recorded by the failing run are watched as well,
+          // e.g. the files imported by a stylesheet before its syntax error
+          let mut build_result = *rebuild_fallback;
+          let fallback_info = &mut build_result.build_info;
+          fallback_info.cacheable &= cacheable;
+          fallback_info.file_dependencies.extend(file_dependencies);
+          fallback_info
+            .context_dependencies
+            .extend(context_dependencies);
+          fallback_info
+            .missing_dependencies
+            .extend(missing_dependencies);
+          fallback_info.build_dependencies.extend(build_dependencies);
+          self.rebuild_errors = Some(vec![e.to_string()]);
+          diagnostics.extend(ds);
+          diagnostics.extend(Vec::<Diagnostic>::from(e));
+          return Ok(build_result.with_diagnostic(diagnostics));
+        }
+        self.source = NormalModuleSource::BuiltFailed(vec![e.to_string()]);
         let mut hasher = RspackHash::from(&build_context.compiler_options.output);
         self.update_hash(&mut hasher);
         build_meta.hash(&mut hasher);
-        build_info.hash = Some(hasher.digest(&build_context.compiler_options.output.hash_digest));
+        build_info.hash = Some(build_context.compiler_options.output.digest(hasher));
+        // Keep the dependencies recorded before the failure, so fixing it triggers a rebuild
+        build_info.cacheable = cacheable;
+        build_info.file_dependencies = file_dependencies;
+        build_info.context_dependencies = context_dependencies;
+        build_info.missing_dependencies = missing_dependencies;
+        build_info.build_dependencies = build_dependencies;
+        diagnostics.extend(ds);
+        diagnostics.extend(Vec::<Diagnostic>::from(e));
         return Ok(
           BuildResult {
             build_info,
             build_meta: Default::default(),
-            dependencies: Vec::new(),
+            dependencies: self.last_dependencies.clone().unwrap_or_default(),
             analyze_result: Default::default(),
           }
-          .with_diagnostic(e.into()),
+          .with_diagnostic(diagnostics),
         );
       }
     };
     diagnostics.extend(ds);
+    if let Some(profile) = build_context.profile
+      && let Some(durations) = loader_result.loader_durations.take()
+    {
+      profile.set_loaders(
+        loaders
+          .iter()
+          .zip(durations)
+          .map(|(loader, duration)| LoaderProfile {
+            identifier: loader.identifier().to_string(),
+            duration,
+          })
+          .collect(),
+      );
+    }
 
     let content = if self.module_type().is_binary() {
       Content::Buffer(loader_result.content.into_bytes())
@@ -314,6 +565,9 @@ impl Module for NormalModule {
     let original_source = self.create_source(content, loader_result.source_map)?;
     let mut code_generation_dependencies: Vec<Box<dyn ModuleDependency>> = Vec::new();
 
+    if let Some(profile) = build_context.profile {
+      profile.mark_parsing_start();
+    }
     let (
       ParseResult {
         source,
@@ -328,6 +582,7 @@ impl Module for NormalModule {
         source: original_source.clone(),
         module_identifier: self.identifier(),
         module_parser_options: self.parser_options.as_ref(),
+        module_generator_options: self.generator_options.as_ref(),
         module_type: &self.module_type,
         module_user_request: &self.user_request,
         resource_data: &self.resource_data,
@@ -336,9 +591,37 @@ impl Module for NormalModule {
         code_generation_dependencies: &mut code_generation_dependencies,
         build_info: &mut build_info,
         build_meta: &mut build_meta,
+        js_parser_handlers: &build_context.plugin_driver.js_parser_handlers,
       })?
       .split_into_parts();
+    if let Some(profile) = build_context.profile {
+      profile.mark_parsing_end();
+    }
     diagnostics.extend(ds);
+    let failed = diagnostics
+      .iter()
+      .any(|d| matches!(d.severity, Severity::Error));
+    if let Some(rebuild_fallback) = rebuild_fallback
+      && failed
+    {
+      self.rebuild_errors = Some(
+        diagnostics
+          .iter()
+          .filter(|d| d.severity == Severity::Error)
+          .map(|d| d.message.clone())
+          .collect(),
+      );
+      return Ok((*rebuild_fallback).with_diagnostic(diagnostics));
+    }
+    let dependencies = if !failed {
+      self.last_dependencies = Some(dependencies.clone());
+      dependencies
+    } else {
+      match &self.last_dependencies {
+        Some(last_dependencies) if dependencies.is_empty() => last_dependencies.clone(),
+        _ => dependencies,
+      }
+    };
     // Only side effects used in code_generate can stay here
     // Other side effects should be set outside use_cache
     self.original_source = Some(original_source);
@@ -349,58 +632,90 @@ impl Module for NormalModule {
     let mut hasher = RspackHash::from(&build_context.compiler_options.output);
     self.update_hash(&mut hasher);
     build_meta.hash(&mut hasher);
+    loader_result.build_info_extras.hash(&mut hasher);
 
-    build_info.hash = Some(hasher.digest(&build_context.compiler_options.output.hash_digest));
+    build_info.hash = Some(build_context.compiler_options.output.digest(hasher));
     build_info.cacheable = loader_result.cacheable;
     build_info.file_dependencies = loader_result.file_dependencies;
     build_info.context_dependencies = loader_result.context_dependencies;
     build_info.missing_dependencies = loader_result.missing_dependencies;
     build_info.build_dependencies = loader_result.build_dependencies;
     build_info.asset_filenames = loader_result.asset_filenames;
+    build_info.build_info_extras = loader_result.build_info_extras;
 
-    Ok(
-      BuildResult {
-        build_info,
-        build_meta,
-        dependencies,
-        analyze_result,
-      }
-      .with_diagnostic(diagnostics),
-    )
+    let build_result = BuildResult {
+      build_info,
+      build_meta,
+      dependencies,
+      analyze_result,
+    };
+    // The fast path only makes sense when modules are rebuilt within the same session
+    if build_context
+      .compiler_options
+      .is_incremental_rebuild_make_enabled()
+      && build_result.build_info.cacheable
+      && diagnostics.is_empty()
+    {
+      self.build_snapshot = BuildSnapshot::create(
+        &build_result,
+        &build_context.snapshot_manager,
+        snapshot_strategy,
+      )
+      .await
+      .map(Box::new);
+    }
+
+    Ok(build_result.with_diagnostic(diagnostics))
   }
 
   fn code_generation(&self, compilation: &Compilation) -> Result<CodeGenerationResult> {
     if let NormalModuleSource::BuiltSucceed(source) = &self.source {
       let mut code_generation_result = CodeGenerationResult::default();
-      for source_type in self.source_types() {
-        let generation_result = self.parser_and_generator.generate(
-          source,
-          self,
-          &mut GenerateContext {
-            compilation,
-            module_generator_options: self.generator_options.as_ref(),
-            runtime_requirements: &mut code_generation_result.runtime_requirements,
-            data: &mut code_generation_result.data,
-            requested_source_type: *source_type,
-          },
-        )?;
-        code_generation_result.add(*source_type, CachedSource::new(generation_result).boxed());
+      let (sources, diagnostics) = generate_source_types(
+        self.source_types(),
+        &self.readable_identifier(&compilation.options.context),
+        self.parser_and_generator.name(),
+        |source_type| {
+          self.parser_and_generator.generate(
+            source,
+            self,
+            &mut GenerateContext {
+              compilation,
+              module_generator_options: self.generator_options.as_ref(),
+              runtime_requirements: &mut code_generation_result.runtime_requirements,
+              data: &mut code_generation_result.data,
+              requested_source_type: source_type,
+              concatenation_scope: None,
+            },
+          )
+        },
+      );
+      for (source_type, source) in sources {
+        code_generation_result.add(source_type, source);
       }
+      code_generation_result.diagnostics = diagnostics;
       code_generation_result.set_hash(
         &compilation.options.output.hash_function,
         &compilation.options.output.hash_digest,
         &compilation.options.output.hash_salt,
       );
       Ok(code_generation_result)
-    } else if let NormalModuleSource::BuiltFailed(error_message) = &self.source {
+    } else if let NormalModuleSource::BuiltFailed(error_messages) = &self.source {
       let mut code_generation_result = CodeGenerationResult::default();
 
       // If the module build failed and the module is able to emit JavaScript source,
-      // we should emit an error message to the runtime, otherwise we do nothing.
+      // we should emit an error to the runtime, otherwise we do nothing.
+      // The error is marked by `__rspack_build_error__` for the error overlays and HMR.
       if self.source_types().contains(&SourceType::JavaScript) {
         code_generation_result.add(
           SourceType::JavaScript,
-          RawSource::from(format!("throw new Error({});\n", json!(error_message))).boxed(),
+          RawSource::from(format!(
+            "var error = new Error({});\nerror.module = {};\nerror.details = {};\nerror.__rspack_build_error__ = true;\nthrow error;\n",
+            json!(error_messages.join("\n")),
+            json!(self.readable_identifier(&compilation.options.context)),
+            json!(error_messages),
+          ))
+          .boxed(),
         );
       }
       code_generation_result.set_hash(
@@ -411,8 +726,8 @@ impl Module for NormalModule {
       Ok(code_generation_result)
     } else {
       Err(internal_error!(
-        "Failed to generate code because ast or source is not set for module {}",
-        self.request
+        "Failed to generate code for module {} because the build phase never ran for it",
+        self.readable_identifier(&compilation.options.context)
       ))
     }
   }
@@ -465,7 +780,34 @@ impl Module for NormalModule {
   ) -> ConnectionState {
     if let Some(mgm) = module_graph.module_graph_module_by_identifier(&self.identifier()) {
       if let Some(side_effect) = mgm.factory_meta.as_ref().and_then(|m| m.side_effects) {
-        return ConnectionState::Bool(side_effect);
+        if side_effect {
+          return ConnectionState::Bool(true);
+        }
+        // Only the evaluation of the module itself is flagged as side effects free,
+        // the modules it requires with side effects still need to be evaluated.
+        if !module_chain.insert(self.identifier()) {
+          return ConnectionState::CircularConnection;
+        }
+        let has_side_effects_require = mgm.dependencies.iter().any(|dependency_id| {
+          module_graph
+            .dependency_by_id(dependency_id)
+            .and_then(|dependency| dependency.as_module_dependency())
+            .map_or(false, |dependency| {
+              matches!(dependency.dependency_type(), DependencyType::CjsRequire)
+                && module_graph
+                  .module_identifier_by_dependency_id(dependency_id)
+                  .and_then(|module_identifier| {
+                    module_graph.module_by_identifier(module_identifier)
+                  })
+                  .map_or(false, |module| {
+                    matches!(
+                      module.get_side_effects_connection_state(module_graph, module_chain),
+                      ConnectionState::Bool(true)
+                    )
+                  })
+            })
+        });
+        return ConnectionState::Bool(has_side_effects_require);
       }
       if let Some(side_effect_free) = mgm.build_meta.as_ref().and_then(|m| m.side_effect_free) && side_effect_free {
         // use module chain instead of is_evaluating_side_effects to mut module graph
@@ -523,6 +865,50 @@ impl NormalModule {
   }
 }
 
+/// Dependencies state of a successful build along with its result
+#[derive(Debug)]
+struct BuildSnapshot {
+  snapshot: Snapshot,
+  build_result: BuildResult,
+}
+
+impl BuildSnapshot {
+  /// Returns `None` if the strategy is disabled or the state of any dependency is not available.
+  async fn create(
+    build_result: &BuildResult,
+    snapshot_manager: &SnapshotManager,
+    strategy: &SnapshotStrategy,
+  ) -> Option<Self> {
+    if !strategy.hash && !strategy.timestamp {
+      return None;
+    }
+    let build_info = &build_result.build_info;
+    let paths = build_info
+      .file_dependencies
+      .iter()
+      .chain(build_info.context_dependencies.iter())
+      .chain(build_info.missing_dependencies.iter())
+      .chain(build_info.build_dependencies.iter())
+      .map(|path| path.as_path())
+      .collect::<Vec<_>>();
+    let snapshot = snapshot_manager
+      .create_snapshot(&paths, |options| &options.module)
+      .await
+      .ok()?;
+    Some(Self {
+      snapshot,
+      build_result: build_result.clone(),
+    })
+  }
+
+  async fn is_valid(&self, snapshot_manager: &SnapshotManager) -> bool {
+    snapshot_manager
+      .check_snapshot_valid(&self.snapshot)
+      .await
+      .unwrap_or(false)
+  }
+}
+
 impl Hash for NormalModule {
   fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
     "__rspack_internal__NormalModule".hash(state);
@@ -531,3 +917,48 @@ impl Hash for NormalModule {
     }
   }
 }
+
+#[cfg(test)]
+mod test {
+  use rspack_error::{internal_error, Severity};
+  use rspack_sources::{RawSource, Source, SourceExt};
+
+  use super::generate_source_types;
+  use crate::SourceType;
+
+  #[test]
+  fn should_emit_other_source_types_when_one_fails() {
+    let (sources, diagnostics) = generate_source_types(
+      &[SourceType::JavaScript, SourceType::Css],
+      "./style.css",
+      "css",
+      |source_type| match source_type {
+        SourceType::Css => Err(internal_error!("unexpected token")),
+        _ => Ok(RawSource::from("// js stub").boxed()),
+      },
+    );
+    assert_eq!(sources.len(), 1);
+    assert_eq!(sources[0].0, SourceType::JavaScript);
+    assert_eq!(sources[0].1.source(), "// js stub");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, Severity::Error);
+    let message = &diagnostics[0].message;
+    assert!(message.contains("./style.css"));
+    assert!(message.contains("css source"));
+    assert!(message.contains("css generator"));
+    assert!(message.contains("unexpected token"));
+  }
+
+  #[test]
+  fn should_emit_throwing_stub_when_javascript_fails() {
+    let (sources, diagnostics) = generate_source_types(
+      &[SourceType::JavaScript],
+      "./index.js",
+      "javascript",
+      |_| Err(internal_error!("unexpected token")),
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(sources.len(), 1);
+    assert!(sources[0].1.source().starts_with("throw new Error("));
+  }
+}
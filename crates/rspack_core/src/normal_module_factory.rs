@@ -4,6 +4,7 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_error::{
   internal_error, Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray,
+  TraceableError,
 };
 use rspack_identifier::Identifiable;
 use rspack_loader_runner::{get_scheme, DescriptionData, Loader, Scheme};
@@ -12,12 +13,14 @@ use swc_core::common::Span;
 
 use crate::{
   cache::Cache,
-  module_rules_matcher, parse_resource, resolve, stringify_loaders_and_resource,
+  floor_char_boundary, module_rules_matcher, parse_resource, resolve,
+  stringify_loaders_and_resource,
   tree_shaking::visitor::{get_side_effects_from_package_json, SideEffects},
-  BoxLoader, CompilerContext, CompilerOptions, DependencyCategory, DependencyType, FactorizeArgs,
-  FactoryMeta, FuncUseCtx, GeneratorOptions, MissingModule, ModuleArgs, ModuleExt, ModuleFactory,
-  ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier, ModuleRule, ModuleRuleEnforce,
-  ModuleRuleUse, ModuleRuleUseLoader, ModuleType, NormalModule, NormalModuleAfterResolveArgs,
+  BoxLoader, CompilerContext, CompilerOptions, CreateModuleData, DependencyCategory,
+  DependencyType, FactorizeArgs, FactoryMeta, FuncUseCtx, GeneratorOptions, LoaderItemOrigin,
+  MissingModule, ModuleArgs, ModuleExt, ModuleFactory, ModuleFactoryCreateData,
+  ModuleFactoryResult, ModuleIdentifier, ModuleRule, ModuleRuleEnforce, ModuleRuleUse,
+  ModuleRuleUseLoader, ModuleType, NormalModule, NormalModuleAfterResolveArgs,
   NormalModuleBeforeResolveArgs, ParserOptions, RawModule, Resolve, ResolveArgs, ResolveError,
   ResolveOptionsWithDependencyType, ResolveResult, Resolver, ResolverFactory, ResourceData,
   ResourceParsedData, SharedPluginDriver,
@@ -79,26 +82,15 @@ impl NormalModuleFactory {
     let mut before_resolve_args = NormalModuleBeforeResolveArgs {
       request: dependency.request().to_string(),
       context: data.context.to_string(),
+      issuer: self.context.issuer.as_deref().map(ToOwned::to_owned),
     };
     if let Ok(Some(false)) = self
       .plugin_driver
       .before_resolve(&mut before_resolve_args)
       .await
     {
-      let request_without_match_resource = dependency.request();
-      let ident = format!("{}/{request_without_match_resource}", &data.context);
-      let module_identifier = ModuleIdentifier::from(format!("missing|{ident}"));
-
-      let missing_module = MissingModule::new(
-        module_identifier,
-        format!("{ident} (missing)"),
-        format!("Failed to resolve {request_without_match_resource}"),
-      )
-      .boxed();
-      self.context.module_type = Some(*missing_module.module_type());
-      return Ok(Some(
-        ModuleFactoryResult::new(missing_module).with_empty_diagnostic(),
-      ));
+      // The request is skipped by plugins, no module is created for it.
+      return Ok(Some(ModuleFactoryResult::default().with_empty_diagnostic()));
     }
 
     data.context = before_resolve_args.context.into();
@@ -120,6 +112,7 @@ impl NormalModuleFactory {
       .after_resolve(NormalModuleAfterResolveArgs {
         request: dependency.request(),
         context: data.context.as_ref(),
+        issuer: self.context.issuer.as_deref(),
         file_dependencies: &factory_result.file_dependencies,
         context_dependencies: &factory_result.context_dependencies,
         missing_dependencies: &factory_result.missing_dependencies,
@@ -151,7 +144,7 @@ impl NormalModuleFactory {
       .get(ResolveOptionsWithDependencyType {
         resolve_options: None,
         resolve_to_context: false,
-        dependency_type: DependencyType::CjsRequire,
+        dependency_type: DependencyType::LoaderImport,
         dependency_category: DependencyCategory::CommonJS,
       })
   }
@@ -168,6 +161,7 @@ impl NormalModuleFactory {
     let mut request_without_match_resource = dependency.request();
 
     let mut file_dependencies = Default::default();
+    let mut context_dependencies = Default::default();
     let mut missing_dependencies = Default::default();
 
     let scheme = get_scheme(request_without_match_resource);
@@ -308,6 +302,7 @@ impl NormalModuleFactory {
         resolve_to_context: false,
         optional,
         file_dependencies: &mut file_dependencies,
+        context_dependencies: &mut context_dependencies,
         missing_dependencies: &mut missing_dependencies,
       };
 
@@ -349,6 +344,7 @@ impl NormalModuleFactory {
           let ident = format!("{}/{request_without_match_resource}", &data.context);
           let module_identifier = ModuleIdentifier::from(format!("missing|{ident}"));
           let mut file_dependencies = Default::default();
+          let mut context_dependencies = Default::default();
           let mut missing_dependencies = Default::default();
           let diagnostics: Vec<Diagnostic> = internal_error.into();
           let mut diagnostic = diagnostics[0].clone();
@@ -373,6 +369,7 @@ impl NormalModuleFactory {
               resolve_to_context: false,
               optional,
               missing_dependencies: &mut missing_dependencies,
+              context_dependencies: &mut context_dependencies,
               file_dependencies: &mut file_dependencies,
             };
             let resource_data = self
@@ -409,6 +406,45 @@ impl NormalModuleFactory {
         }
       }
     };
+    let stringify_user_request = |match_resource_data: Option<&ResourceData>,
+                                  resource_data: &ResourceData| {
+      let suffix = stringify_loaders_and_resource(&inline_loaders, &resource_data.resource);
+      if let Some(ResourceData { resource, .. }) = match_resource_data {
+        let mut resource = resource.to_owned();
+        resource += "!=!";
+        resource += &*suffix;
+        resource
+      } else {
+        suffix.into_owned()
+      }
+    };
+
+    let user_request = stringify_user_request(match_resource_data.as_ref(), &resource_data);
+    let mut create_data = CreateModuleData {
+      raw_request: dependency.request().to_owned(),
+      user_request: user_request.clone(),
+      resource_data,
+      match_resource: match_resource_data,
+      settings: Default::default(),
+    };
+    self
+      .plugin_driver
+      .create_module_data(&mut create_data)
+      .await?;
+    let CreateModuleData {
+      raw_request,
+      user_request: hooked_user_request,
+      resource_data,
+      match_resource: match_resource_data,
+      settings,
+    } = create_data;
+    // Keep the user request in sync with the resource, unless a plugin rewrote it explicitly
+    let user_request = if hooked_user_request != user_request {
+      hooked_user_request
+    } else {
+      stringify_user_request(match_resource_data.as_ref(), &resource_data)
+    };
+
     //TODO: with contextScheme
     let resolved_module_rules = self
       .calculate_module_rules(
@@ -421,22 +457,11 @@ impl NormalModuleFactory {
       )
       .await?;
 
-    let user_request = {
-      let suffix = stringify_loaders_and_resource(&inline_loaders, &resource_data.resource);
-      if let Some(ResourceData { resource, .. }) = match_resource_data.as_ref() {
-        let mut resource = resource.to_owned();
-        resource += "!=!";
-        resource += &*suffix;
-        resource
-      } else {
-        suffix.into_owned()
-      }
-    };
     let contains_inline = !inline_loaders.is_empty();
 
     // dbg!(&user_request);
 
-    let loaders: Vec<BoxLoader> = {
+    let loaders: Vec<(BoxLoader, LoaderItemOrigin)> = {
       let mut pre_loaders: Vec<ModuleRuleUseLoader> = vec![];
       let mut post_loaders: Vec<ModuleRuleUseLoader> = vec![];
       let mut normal_loaders: Vec<ModuleRuleUseLoader> = vec![];
@@ -479,7 +504,7 @@ impl NormalModuleFactory {
       );
 
       for l in post_loaders {
-        all_loaders.push(
+        all_loaders.push((
           resolve_each(
             plugin_driver,
             &self.context.options,
@@ -489,14 +514,15 @@ impl NormalModuleFactory {
             l.options.as_deref(),
           )
           .await?,
-        )
+          LoaderItemOrigin::Post,
+        ))
       }
 
       let mut resolved_inline_loaders = vec![];
       let mut resolved_normal_loaders = vec![];
 
       for l in inline_loaders {
-        resolved_inline_loaders.push(
+        resolved_inline_loaders.push((
           resolve_each(
             plugin_driver,
             &self.context.options,
@@ -506,11 +532,12 @@ impl NormalModuleFactory {
             l.options.as_deref(),
           )
           .await?,
-        )
+          LoaderItemOrigin::Inline,
+        ))
       }
 
       for l in normal_loaders {
-        resolved_normal_loaders.push(
+        resolved_normal_loaders.push((
           resolve_each(
             plugin_driver,
             &self.context.options,
@@ -520,7 +547,8 @@ impl NormalModuleFactory {
             l.options.as_deref(),
           )
           .await?,
-        )
+          LoaderItemOrigin::Normal,
+        ))
       }
 
       if match_resource_data.is_some() {
@@ -532,7 +560,7 @@ impl NormalModuleFactory {
       }
 
       for l in pre_loaders {
-        all_loaders.push(
+        all_loaders.push((
           resolve_each(
             plugin_driver,
             &self.context.options,
@@ -542,7 +570,8 @@ impl NormalModuleFactory {
             l.options.as_deref(),
           )
           .await?,
-        )
+          LoaderItemOrigin::Pre,
+        ))
       }
 
       async fn resolve_each(
@@ -571,7 +600,7 @@ impl NormalModuleFactory {
     let request = if !loaders.is_empty() {
       let s = loaders
         .iter()
-        .map(|i| i.identifier().as_str())
+        .map(|(i, _)| i.identifier().as_str())
         .collect::<Vec<_>>()
         .join("!");
       format!("{s}!{}", resource_data.resource)
@@ -586,7 +615,7 @@ impl NormalModuleFactory {
       self.calculate_module_type(&resolved_module_rules, self.context.module_type);
     let resolved_resolve_options = self.calculate_resolve_options(&resolved_module_rules);
     let (resolved_parser_options, resolved_generator_options) =
-      self.calculate_parser_and_generator_options(&resolved_module_rules);
+      self.calculate_parser_and_generator_options(&resolved_module_rules, &resolved_module_type);
     let factory_meta = FactoryMeta {
       side_effects: self.calculate_side_effects(&resolved_module_rules, &resource_data),
     };
@@ -606,7 +635,7 @@ impl NormalModuleFactory {
     let normal_module = NormalModule::new(
       request,
       user_request,
-      dependency.request().to_owned(),
+      raw_request,
       resolved_module_type,
       resolved_parser_and_generator,
       resolved_parser_options,
@@ -617,6 +646,7 @@ impl NormalModuleFactory {
       loaders,
       self.context.options.clone(),
       contains_inline,
+      settings,
     );
 
     let module = if let Some(module) = self
@@ -637,6 +667,7 @@ impl NormalModuleFactory {
       ModuleFactoryResult::new(module)
         .file_dependency(file_dependency)
         .file_dependencies(file_dependencies)
+        .context_dependencies(context_dependencies)
         .missing_dependencies(missing_dependencies)
         .factory_meta(factory_meta)
         .with_empty_diagnostic(),
@@ -660,14 +691,19 @@ impl NormalModuleFactory {
     Ok(rules)
   }
 
+  /// The resolve options of the matched rules are merged in order, so a later rule replaces the
+  /// arrays, e.g. `conditionNames`, of a former one unless `"..."` is present in the array.
   fn calculate_resolve_options(&self, module_rules: &[&ModuleRule]) -> Option<Box<Resolve>> {
-    let mut resolved = None;
+    let mut resolved: Option<Resolve> = None;
     module_rules.iter().for_each(|rule| {
       if let Some(resolve) = rule.resolve.as_ref() {
-        resolved = Some(Box::new(resolve.to_owned()));
+        resolved = Some(match resolved.take() {
+          Some(resolved) => resolved.merge(resolve.to_owned()),
+          None => resolve.to_owned(),
+        });
       }
     });
-    resolved
+    resolved.map(Box::new)
   }
 
   fn calculate_side_effects(
@@ -702,9 +738,18 @@ impl NormalModuleFactory {
   fn calculate_parser_and_generator_options(
     &self,
     module_rules: &[&ModuleRule],
+    module_type: &ModuleType,
   ) -> (Option<ParserOptions>, Option<GeneratorOptions>) {
     let mut resolved_parser = None;
-    let mut resolved_generator = None;
+    // Rule level generator options are merged with `module.generator` of the module type
+    let mut resolved_generator = self
+      .context
+      .options
+      .module
+      .generator
+      .as_ref()
+      .and_then(|generator| generator.get(module_type))
+      .cloned();
 
     module_rules.iter().for_each(|rule| {
       // TODO: should deep merge
@@ -712,7 +757,10 @@ impl NormalModuleFactory {
         resolved_parser = Some(parser.to_owned());
       }
       if let Some(generator) = rule.generator.as_ref() {
-        resolved_generator = Some(generator.to_owned());
+        resolved_generator = Some(match resolved_generator.take() {
+          Some(resolved) => resolved.merge(generator.to_owned()),
+          None => generator.to_owned(),
+        });
       }
     });
 
@@ -756,7 +804,7 @@ impl NormalModuleFactory {
       .await?;
 
     if let Some(result) = result {
-      self.context.module_type = Some(*result.module.module_type());
+      self.context.module_type = result.module.as_ref().map(|module| *module.module_type());
       return Ok(result.with_empty_diagnostic());
     }
 
@@ -795,6 +843,30 @@ impl ErrorSpan {
   pub fn new(start: u32, end: u32) -> Self {
     Self { start, end }
   }
+
+  /// Create a [TraceableError] which renders the excerpt of `source` covered by the span.
+  ///
+  /// The span is the byte offsets of `source`, the offsets which are out of bounds or in the middle
+  /// of a multi-byte character are moved back to the nearest character boundary, so that the
+  /// columns are computed by the characters of the line.
+  pub fn to_traceable_error(
+    &self,
+    resource_path: &str,
+    source: &str,
+    title: String,
+    message: String,
+  ) -> TraceableError {
+    let start = floor_char_boundary(source, self.start as usize);
+    let end = floor_char_boundary(source, self.end as usize).max(start);
+    TraceableError::from_file(
+      resource_path.to_string(),
+      source.to_string(),
+      start,
+      end,
+      title,
+      message,
+    )
+  }
 }
 
 impl From<Span> for ErrorSpan {
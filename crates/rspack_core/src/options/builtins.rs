@@ -1,8 +1,10 @@
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::sync::Arc;
 use std::{collections::HashMap, fmt::Display, path::PathBuf};
 
 use async_recursion::async_recursion;
+use futures::future::BoxFuture;
 use glob::Pattern as GlobPattern;
 use rspack_regex::RspackRegex;
 use swc_core::ecma::transforms::react::Runtime;
@@ -181,10 +183,37 @@ impl Display for ToType {
   }
 }
 
+/// The argument of [CopyTo::Fn].
+#[derive(Debug)]
+pub struct CopyToFnCtx {
+  pub context: PathBuf,
+  pub absolute_filename: PathBuf,
+}
+
+pub type CopyToFn =
+  Arc<dyn Fn(CopyToFnCtx) -> BoxFuture<'static, rspack_error::Result<String>> + Sync + Send>;
+
+#[derive(Clone)]
+pub enum CopyTo {
+  String(String),
+  /// Evaluated for each copied file, the result is handled the same as [CopyTo::String].
+  Fn(CopyToFn),
+}
+
+impl Debug for CopyTo {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::String(to) => f.debug_tuple("String").field(to).finish(),
+      Self::Fn(_) => f.debug_tuple("Fn").field(&"...").finish(),
+    }
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct Pattern {
   pub from: String,
-  pub to: Option<String>,
+  /// An absolute target outside of `output.path` is written directly instead of emitted as an asset.
+  pub to: Option<CopyTo>,
   pub context: Option<PathBuf>,
   pub to_type: Option<ToType>,
   pub no_error_on_missing: bool,
@@ -1,7 +1,7 @@
 use crate::{
-  Builtins, CacheOptions, Context, DevServerOptions, Devtool, Experiments,
-  IncrementalRebuildMakeState, Mode, ModuleOptions, NodeOption, Optimization, OutputOptions,
-  Resolve, SnapshotOptions, StatsOptions, Target,
+  Builtins, CacheOptions, Context, DevServerOptions, Devtool, Experiments, IgnoreWarning,
+  IncrementalRebuildMakeState, InfrastructureLoggingOptions, Mode, ModuleOptions, NodeOption,
+  Optimization, OutputOptions, Resolve, SnapshotOptions, StatsOptions, Target,
 };
 
 #[derive(Debug)]
@@ -22,8 +22,14 @@ pub struct CompilerOptions {
   pub cache: CacheOptions,
   pub experiments: Experiments,
   pub node: Option<NodeOption>,
+  /// Whether to parse the AMD `define()` calls
+  pub amd: bool,
+  /// Whether `require.cache` is the module cache, only for the node targets
+  pub require_cache: bool,
   pub optimization: Optimization,
   pub profile: bool,
+  pub ignore_warnings: Vec<IgnoreWarning>,
+  pub infrastructure_logging: InfrastructureLoggingOptions,
 }
 
 impl CompilerOptions {
@@ -1,4 +1,5 @@
 use bitflags::bitflags;
+use rspack_error::internal_error;
 
 bitflags! {
   struct DevtoolFlags: u8 {
@@ -8,7 +9,6 @@ bitflags! {
     const NO_SOURCES = 0b00001000;
     const HIDDEN = 0b00010000;
     const MODULE = 0b00100000;
-    // TODO: eval source map for better development (re)builds performance.
     const EVAL = 0b01000000;
   }
 }
@@ -77,31 +77,82 @@ impl Devtool {
   }
 }
 
-impl From<String> for Devtool {
-  fn from(s: String) -> Self {
-    let mut devtool = Self::default();
+impl TryFrom<String> for Devtool {
+  type Error = rspack_error::Error;
 
-    if s.contains("source-map") {
-      devtool.add_source_map();
+  /// Parse `false | eval | [inline-|hidden-|eval-][nosources-][cheap-[module-]]source-map`,
+  /// `false` is passed as an empty string.
+  fn try_from(s: String) -> Result<Self, Self::Error> {
+    let mut devtool = Self::default();
+    if s.is_empty() || s == "false" {
+      return Ok(devtool);
+    }
+    if s == "eval" {
+      devtool.add_eval();
+      return Ok(devtool);
     }
-    if s.contains("inline") {
+
+    let mut rest = s.as_str();
+    if let Some(r) = rest.strip_prefix("inline-") {
       devtool.add_inline();
+      rest = r;
+    } else if let Some(r) = rest.strip_prefix("hidden-") {
+      devtool.add_hidden();
+      rest = r;
+    } else if let Some(r) = rest.strip_prefix("eval-") {
+      devtool.add_eval();
+      rest = r;
     }
-    if s.contains("nosources") {
+    if let Some(r) = rest.strip_prefix("nosources-") {
       devtool.add_no_sources();
+      rest = r;
     }
-    if s.contains("hidden") {
-      devtool.add_hidden();
-    }
-    if s.contains("cheap") {
+    if let Some(r) = rest.strip_prefix("cheap-") {
       devtool.add_cheap();
-    }
-    if s.contains("module") || !s.contains("cheap") {
+      rest = r;
+      if let Some(r) = rest.strip_prefix("module-") {
+        devtool.add_module();
+        rest = r;
+      }
+    } else {
+      // Loader source maps are always chained unless `cheap` is used
       devtool.add_module();
     }
-    if s.contains("eval") {
-      devtool.add_eval();
+    if rest != "source-map" {
+      return Err(internal_error!(
+        "Invalid devtool \"{s}\", expected false, eval or [inline-|hidden-|eval-][nosources-][cheap-[module-]]source-map"
+      ));
     }
-    devtool
+    devtool.add_source_map();
+    Ok(devtool)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn parse(s: &str) -> Devtool {
+    Devtool::try_from(s.to_string()).expect("should be a valid devtool")
+  }
+
+  #[test]
+  fn parse_devtool() {
+    assert!(!parse("").source_map());
+    assert!(parse("eval").eval() && !parse("eval").source_map());
+
+    let devtool = parse("eval-cheap-module-source-map");
+    assert!(devtool.eval() && devtool.cheap() && devtool.module() && devtool.source_map());
+    assert!(!devtool.inline() && !devtool.hidden() && !devtool.no_sources());
+
+    let devtool = parse("hidden-nosources-cheap-source-map");
+    assert!(devtool.hidden() && devtool.no_sources() && devtool.cheap());
+    assert!(!devtool.module() && !devtool.enabled());
+
+    let devtool = parse("inline-source-map");
+    assert!(devtool.inline() && devtool.module() && devtool.enabled());
+
+    assert!(Devtool::try_from("cheap-inline-source-map".to_string()).is_err());
+    assert!(Devtool::try_from("source-maps".to_string()).is_err());
   }
 }
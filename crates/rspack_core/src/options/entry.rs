@@ -15,11 +15,28 @@ pub struct EntryDescription {
   pub public_path: Option<PublicPath>,
   pub base_uri: Option<String>,
   pub filename: Option<Filename>,
+  pub depend_on: Option<Vec<String>>,
+}
+
+impl EntryDescription {
+  pub fn entry_options(&self) -> EntryOptions {
+    EntryOptions {
+      runtime: self.runtime.clone(),
+      chunk_loading: self.chunk_loading.clone(),
+      async_chunks: self.async_chunks,
+      public_path: self.public_path.clone(),
+      base_uri: self.base_uri.clone(),
+      filename: self.filename.clone(),
+      depend_on: self.depend_on.clone(),
+    }
+  }
 }
 
 #[derive(Debug)]
 pub struct EntryData {
   pub dependencies: Vec<DependencyId>,
+  /// Modules included in the entry chunk, which are not executed on startup
+  pub include_dependencies: Vec<DependencyId>,
   pub options: EntryOptions,
 }
 
@@ -31,4 +48,6 @@ pub struct EntryOptions {
   pub public_path: Option<PublicPath>,
   pub base_uri: Option<String>,
   pub filename: Option<Filename>,
+  /// The entries which must be loaded before this entry, modules of them are excluded from this entry
+  pub depend_on: Option<Vec<String>>,
 }
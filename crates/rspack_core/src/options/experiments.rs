@@ -28,4 +28,5 @@ pub struct Experiments {
   pub async_web_assembly: bool,
   pub new_split_chunks: bool,
   pub css: bool,
+  pub top_level_await: bool,
 }
@@ -0,0 +1,62 @@
+use std::fmt::Debug;
+
+use futures::future::BoxFuture;
+use rspack_error::{Diagnostic, Result, Severity};
+use rspack_regex::RspackRegex;
+
+/// The warning passed to [IgnoreWarning::Fn].
+#[derive(Debug)]
+pub struct IgnoreWarningFnCtx {
+  pub message: String,
+  /// The resource of the module which the warning comes from
+  pub module_identifier: Option<String>,
+  /// The asset which the warning comes from, which isn't tracked by diagnostics yet
+  pub file: Option<String>,
+}
+
+pub type IgnoreWarningFn =
+  Box<dyn Fn(IgnoreWarningFnCtx) -> BoxFuture<'static, Result<bool>> + Sync + Send>;
+
+/// A matcher of `ignoreWarnings`, the matched warnings are removed from the compilation.
+pub enum IgnoreWarning {
+  /// Matches the message of the warning
+  Message(RspackRegex),
+  /// Matches the resource of the module which the warning comes from
+  Module(RspackRegex),
+  Fn(IgnoreWarningFn),
+}
+
+impl Debug for IgnoreWarning {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Message(regex) => f.debug_tuple("Message").field(regex).finish(),
+      Self::Module(regex) => f.debug_tuple("Module").field(regex).finish(),
+      Self::Fn(_) => f.debug_tuple("Fn").field(&"...").finish(),
+    }
+  }
+}
+
+impl IgnoreWarning {
+  /// Errors are never matched.
+  pub async fn is_match(&self, diagnostic: &Diagnostic) -> Result<bool> {
+    if !matches!(diagnostic.severity, Severity::Warn) {
+      return Ok(false);
+    }
+    let module = diagnostic
+      .source_info
+      .as_ref()
+      .map(|source_info| source_info.path.as_str());
+    match self {
+      Self::Message(regex) => Ok(regex.test(&diagnostic.message)),
+      Self::Module(regex) => Ok(module.map_or(false, |module| regex.test(module))),
+      Self::Fn(func) => {
+        func(IgnoreWarningFnCtx {
+          message: diagnostic.message.clone(),
+          module_identifier: module.map(ToOwned::to_owned),
+          file: None,
+        })
+        .await
+      }
+    }
+  }
+}
@@ -0,0 +1,73 @@
+use crate::LogType;
+
+/// The most verbose level of the infrastructure logs which are reported, i.e. `infrastructureLogging.level`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum InfrastructureLoggingLevel {
+  None,
+  Error,
+  Warn,
+  #[default]
+  Info,
+  Log,
+  Verbose,
+}
+
+impl From<&str> for InfrastructureLoggingLevel {
+  fn from(value: &str) -> Self {
+    match value {
+      "none" => Self::None,
+      "error" => Self::Error,
+      "warn" => Self::Warn,
+      "log" => Self::Log,
+      "verbose" => Self::Verbose,
+      _ => Self::Info,
+    }
+  }
+}
+
+impl InfrastructureLoggingLevel {
+  /// The bit flags of [LogType] which are reported at this level.
+  ///
+  /// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/logging/createConsoleLogger.js
+  pub fn accepted_types(&self) -> u32 {
+    let error = LogType::ERROR;
+    let warn = error | LogType::WARN;
+    let info = warn | LogType::INFO;
+    let log = info
+      | LogType::LOG
+      | LogType::GROUP
+      | LogType::GROUP_COLLAPSED
+      | LogType::GROUP_END
+      | LogType::PROFILE
+      | LogType::PROFILE_END
+      | LogType::TIME
+      | LogType::CLEAR
+      | LogType::STATUS;
+    match self {
+      Self::None => 0,
+      Self::Error => error,
+      Self::Warn => warn,
+      Self::Info => info,
+      Self::Log => log,
+      Self::Verbose => log | LogType::DEBUG | LogType::TRACE,
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct InfrastructureLoggingOptions {
+  pub level: InfrastructureLoggingLevel,
+  /// Report the debug logs of all the loggers regardless of `level`, the loggers are
+  /// filtered by name on the JS side when a filter is configured.
+  pub debug: bool,
+}
+
+impl InfrastructureLoggingOptions {
+  pub fn accepted_types(&self) -> u32 {
+    if self.debug {
+      InfrastructureLoggingLevel::Verbose.accepted_types()
+    } else {
+      self.level.accepted_types()
+    }
+  }
+}
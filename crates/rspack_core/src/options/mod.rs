@@ -37,3 +37,7 @@ mod experiments;
 pub use experiments::*;
 mod node;
 pub use node::*;
+mod ignore_warnings;
+pub use ignore_warnings::*;
+mod infrastructure_logging;
+pub use infrastructure_logging::*;
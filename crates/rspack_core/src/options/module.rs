@@ -1,6 +1,7 @@
 use std::{
   fmt::{self, Debug},
   future::Future,
+  sync::Arc,
 };
 
 use async_recursion::async_recursion;
@@ -121,6 +122,8 @@ impl GeneratorOptions {
       })
   }
 
+  /// An `auto` public path falls back to `output.publicPath`,
+  /// so that it's resolved the same for the assets referenced by JavaScript and css.
   pub fn asset_public_path(&self, module_type: &ModuleType) -> Option<&PublicPath> {
     self
       .get_asset(module_type)
@@ -130,6 +133,7 @@ impl GeneratorOptions {
           .get_asset_resource(module_type)
           .and_then(|x| x.public_path.as_ref())
       })
+      .filter(|public_path| !matches!(public_path, PublicPath::Auto))
   }
 
   pub fn asset_data_url(&self, module_type: &ModuleType) -> Option<&AssetGeneratorDataUrl> {
@@ -142,6 +146,41 @@ impl GeneratorOptions {
           .and_then(|x| x.data_url.as_ref())
       })
   }
+
+  /// Merge the options of a more specific source, e.g. a rule over `module.generator`
+  /// or a later matched rule over the earlier ones. Fields of `other` take precedence.
+  pub fn merge(self, other: Self) -> Self {
+    fn merge_data_url(
+      base: Option<AssetGeneratorDataUrl>,
+      other: Option<AssetGeneratorDataUrl>,
+    ) -> Option<AssetGeneratorDataUrl> {
+      match (base, other) {
+        (Some(base), Some(other)) => Some(base.merge(other)),
+        (base, other) => other.or(base),
+      }
+    }
+
+    match (self, other) {
+      (Self::Asset(base), Self::Asset(other)) => Self::Asset(AssetGeneratorOptions {
+        filename: other.filename.or(base.filename),
+        public_path: other.public_path.or(base.public_path),
+        data_url: merge_data_url(base.data_url, other.data_url),
+      }),
+      (Self::AssetInline(base), Self::AssetInline(other)) => {
+        Self::AssetInline(AssetInlineGeneratorOptions {
+          data_url: merge_data_url(base.data_url, other.data_url),
+        })
+      }
+      (Self::AssetResource(base), Self::AssetResource(other)) => {
+        Self::AssetResource(AssetResourceGeneratorOptions {
+          filename: other.filename.or(base.filename),
+          public_path: other.public_path.or(base.public_path),
+        })
+      }
+      (base, Self::Unknown) => base,
+      (_, other) => other,
+    }
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -162,10 +201,32 @@ pub struct AssetGeneratorOptions {
   pub data_url: Option<AssetGeneratorDataUrl>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub enum AssetGeneratorDataUrl {
   Options(AssetGeneratorDataUrlOptions),
-  // TODO: Function
+  Func(AssetGeneratorDataUrlFn),
+}
+
+impl fmt::Debug for AssetGeneratorDataUrl {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Options(i) => f.debug_tuple("Options").field(i).finish(),
+      Self::Func(_) => "Func(...)".fmt(f),
+    }
+  }
+}
+
+impl AssetGeneratorDataUrl {
+  /// Fields of `other` take precedence, a function replaces the options entirely.
+  fn merge(self, other: Self) -> Self {
+    match (self, other) {
+      (Self::Options(base), Self::Options(other)) => Self::Options(AssetGeneratorDataUrlOptions {
+        encoding: other.encoding.or(base.encoding),
+        mimetype: other.mimetype.or(base.mimetype),
+      }),
+      (_, other) => other,
+    }
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -174,6 +235,18 @@ pub struct AssetGeneratorDataUrlOptions {
   pub mimetype: Option<String>,
 }
 
+#[derive(Debug)]
+pub struct AssetGeneratorDataUrlFnArgs {
+  /// The resource path of the asset module
+  pub filename: String,
+  pub content: Vec<u8>,
+}
+
+/// Returns the data url of the asset. It's called once the modules are built, before the size
+/// and the code of the module are required.
+pub type AssetGeneratorDataUrlFn =
+  Arc<dyn Fn(AssetGeneratorDataUrlFnArgs) -> BoxFuture<'static, Result<String>> + Sync + Send>;
+
 #[derive(Debug, Clone)]
 pub enum DataUrlEncoding {
   None,
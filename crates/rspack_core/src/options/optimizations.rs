@@ -1,3 +1,8 @@
+use std::fmt::Debug;
+
+use futures::future::BoxFuture;
+use rspack_error::Result;
+
 #[derive(Debug, Clone, Copy)]
 pub enum SideEffectOption {
   False,
@@ -44,9 +49,53 @@ impl SideEffectOption {
   }
 }
 
+pub type RuntimeChunkNameFn =
+  Box<dyn Fn(String) -> BoxFuture<'static, Result<Option<String>>> + Sync + Send>;
+
+pub enum RuntimeChunkName {
+  /// A single runtime chunk named `runtime` shared by all entries
+  Single,
+  /// A runtime chunk named `runtime~[entry name]` for each entry
+  Multiple,
+  String(String),
+  Fn(RuntimeChunkNameFn),
+}
+
+impl Debug for RuntimeChunkName {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Single => write!(f, "Single"),
+      Self::Multiple => write!(f, "Multiple"),
+      Self::String(name) => f.debug_tuple("String").field(name).finish(),
+      Self::Fn(_) => write!(f, "Fn(...)"),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct RuntimeChunkOptions {
+  pub name: RuntimeChunkName,
+}
+
+impl RuntimeChunkOptions {
+  /// Returns the name of the runtime chunk for the entry named `entry_name`.
+  pub async fn get_name(&self, entry_name: &str) -> Result<Option<String>> {
+    Ok(match &self.name {
+      RuntimeChunkName::Single => Some("runtime".to_string()),
+      RuntimeChunkName::Multiple => Some(format!("runtime~{entry_name}")),
+      RuntimeChunkName::String(name) => Some(name.to_owned()),
+      RuntimeChunkName::Fn(f) => f(entry_name.to_string()).await?,
+    })
+  }
+}
+
 #[derive(Debug)]
 pub struct Optimization {
   pub remove_available_modules: bool,
   pub remove_empty_chunks: bool,
   pub side_effects: SideEffectOption,
+  /// Extract the runtime of entries into separate chunks
+  pub runtime_chunk: Option<RuntimeChunkOptions>,
+  /// Concatenate the modules into a single scope if it's possible, i.e. scope hoisting
+  pub concatenate_modules: bool,
 }
@@ -7,8 +7,9 @@ use std::{
 use derivative::Derivative;
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
-use rspack_hash::RspackHash;
 pub use rspack_hash::{HashDigest, HashFunction, HashSalt};
+use rspack_hash::{RspackHash, RspackHashDigest};
+use rspack_regex::RspackRegex;
 use sugar_path::SugarPath;
 
 use crate::{
@@ -19,7 +20,7 @@ use crate::{
 #[derive(Debug)]
 pub struct OutputOptions {
   pub path: PathBuf,
-  pub clean: bool,
+  pub clean: CleanOptions,
   pub public_path: PublicPath,
   pub asset_module_filename: Filename,
   pub wasm_loading: WasmLoading,
@@ -27,6 +28,7 @@ pub struct OutputOptions {
   pub unique_name: String,
   pub chunk_loading: ChunkLoading,
   pub chunk_loading_global: String,
+  pub hot_update_global: String,
   pub filename: Filename,
   pub chunk_filename: Filename,
   pub cross_origin_loading: CrossOriginLoading,
@@ -36,9 +38,11 @@ pub struct OutputOptions {
   pub hot_update_chunk_filename: Filename,
   pub library: Option<LibraryOptions>,
   pub enabled_library_types: Option<Vec<String>>,
+  pub enabled_chunk_loading_types: Option<Vec<String>>,
   pub strict_module_error_handling: bool,
   pub global_object: String,
   pub import_function_name: String,
+  pub import_meta_name: String,
   pub iife: bool,
   pub module: bool,
   pub trusted_types: Option<TrustedTypes>,
@@ -51,6 +55,7 @@ pub struct OutputOptions {
   pub worker_chunk_loading: ChunkLoading,
   pub worker_wasm_loading: WasmLoading,
   pub worker_public_path: String,
+  pub environment: Environment,
 }
 
 impl From<&OutputOptions> for RspackHash {
@@ -59,6 +64,58 @@ impl From<&OutputOptions> for RspackHash {
   }
 }
 
+impl OutputOptions {
+  /// Digest `hasher` with `hashDigest` and truncate it to `hashDigestLength`,
+  /// i.e. the `build_info.hash` of the modules.
+  pub fn digest(&self, hasher: RspackHash) -> RspackHashDigest {
+    hasher
+      .digest(&self.hash_digest)
+      .truncated(self.hash_digest_length)
+  }
+}
+
+/// The ECMAScript features which can be used in the generated runtime code.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Environment {
+  pub arrow_function: bool,
+  pub r#const: bool,
+}
+
+impl Environment {
+  pub fn supports_arrow_function(&self) -> bool {
+    self.arrow_function
+  }
+
+  pub fn supports_const(&self) -> bool {
+    self.r#const
+  }
+}
+
+/// See https://webpack.js.org/configuration/output/#outputclean
+#[derive(Debug, Default)]
+pub struct CleanOptions {
+  pub enable: bool,
+  /// The files in the output directory which are never removed
+  pub keep: Option<CleanKeep>,
+}
+
+impl CleanOptions {
+  /// Whether `filename`, relative to the output directory, is kept from cleaning
+  pub fn is_kept(&self, filename: &str) -> bool {
+    match &self.keep {
+      Some(CleanKeep::String(prefix)) => filename.starts_with(prefix.as_str()),
+      Some(CleanKeep::Regexp(regexp)) => regexp.test(filename),
+      None => false,
+    }
+  }
+}
+
+#[derive(Debug)]
+pub enum CleanKeep {
+  String(String),
+  Regexp(RspackRegex),
+}
+
 #[derive(Debug)]
 pub struct TrustedTypes {
   pub policy_name: Option<String>,
@@ -33,8 +33,8 @@ pub struct Resolve {
   /// related to the order in which the export field
   /// fields are written.
   pub condition_names: Option<Vec<String>>,
-  /// the path of tsconfig.
-  pub tsconfig: Option<PathBuf>,
+  /// The tsconfig whose `paths` and `baseUrl` are used to map the requests.
+  pub tsconfig: Option<TsconfigOptions>,
   /// A list of directories to resolve modules from, can be absolute path or folder name.
   /// Default is `["node_modules"]`
   pub modules: Option<Vec<String>>,
@@ -54,7 +54,41 @@ pub struct Resolve {
   pub by_dependency: Option<ByDependency>,
 }
 
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct TsconfigOptions {
+  /// The path of the tsconfig file, or the directory containing `tsconfig.json`.
+  pub config_file: PathBuf,
+  /// How the referenced projects are mapped.
+  pub references: TsconfigReferences,
+}
+
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq)]
+pub enum TsconfigReferences {
+  /// Only the `paths` of the tsconfig itself are used.
+  #[default]
+  Disabled,
+  /// The projects listed in the `references` field of the tsconfig.
+  Auto,
+  /// The given projects, each one is a tsconfig file or the directory of a project.
+  Paths(Vec<PathBuf>),
+}
+
 impl Resolve {
+  /// The defaults of `resolveLoader`, loaders are resolved like CommonJS modules in node.
+  pub fn loader_defaults() -> Self {
+    fn to_strings(items: &[&str]) -> Option<Vec<String>> {
+      Some(items.iter().map(|item| item.to_string()).collect())
+    }
+    Self {
+      extensions: to_strings(&[".js"]),
+      main_fields: to_strings(&["loader", "main"]),
+      main_files: to_strings(&["index"]),
+      condition_names: to_strings(&["loader", "require", "node"]),
+      exports_field: Some(vec![vec!["exports".to_string()]]),
+      ..Default::default()
+    }
+  }
+
   pub fn to_inner_options(
     self,
     cache: Arc<nodejs_resolver::Cache>,
@@ -62,7 +96,9 @@ impl Resolve {
     dependency_type: DependencyCategory,
   ) -> nodejs_resolver::Options {
     let options = self.merge_by_dependency(dependency_type);
-    let tsconfig = options.tsconfig;
+    let tsconfig = options
+      .tsconfig
+      .map(|tsconfig| crate::normalize_tsconfig_path(&tsconfig.config_file));
     let enforce_extension = nodejs_resolver::EnforceExtension::Auto;
     let external_cache = Some(cache);
     let description_file = String::from("package.json");
@@ -118,6 +154,15 @@ impl Resolve {
     }
   }
 
+  /// Whether `byDependency` has the options of `category`, otherwise the options
+  /// are the same for all the categories.
+  pub fn has_by_dependency(&self, category: &DependencyCategory) -> bool {
+    self
+      .by_dependency
+      .as_ref()
+      .map_or(false, |by_dependency| by_dependency.get(category).is_some())
+  }
+
   fn merge_by_dependency(mut self, dependency_type: DependencyCategory) -> Self {
     let by_dependency = self
       .by_dependency
@@ -312,6 +357,51 @@ mod test {
     assert_eq!(options.condition_names.expect("should be Ok").len(), 3);
   }
 
+  #[test]
+  fn test_merge_condition_names_by_dependency() {
+    let by_dependency = ByDependency::from_iter([(
+      DependencyCategory::Esm,
+      Resolve {
+        condition_names: Some(to_string(vec!["import", "module", "..."])),
+        ..Default::default()
+      },
+    )]);
+    let base = Resolve {
+      condition_names: Some(to_string(vec!["webpack", "browser"])),
+      by_dependency: Some(by_dependency),
+      ..Default::default()
+    };
+    let rule = Resolve {
+      condition_names: Some(to_string(vec!["worker"])),
+      ..Default::default()
+    };
+    let options = base
+      .clone()
+      .merge(rule)
+      .merge_by_dependency(DependencyCategory::Esm);
+    assert_eq!(
+      options.condition_names.expect("should be Ok"),
+      to_string(vec!["import", "module", "worker"])
+    );
+    let rule = Resolve {
+      condition_names: Some(to_string(vec!["worker", "..."])),
+      ..Default::default()
+    };
+    let options = base
+      .clone()
+      .merge(rule)
+      .merge_by_dependency(DependencyCategory::Esm);
+    assert_eq!(
+      options.condition_names.expect("should be Ok"),
+      to_string(vec!["import", "module", "worker", "webpack", "browser"])
+    );
+    let options = base.merge_by_dependency(DependencyCategory::CommonJS);
+    assert_eq!(
+      options.condition_names.expect("should be Ok"),
+      to_string(vec!["webpack", "browser"])
+    );
+  }
+
   #[test]
   fn test_normalize_string_array() {
     let base = to_string(vec!["base0", "base1"]);
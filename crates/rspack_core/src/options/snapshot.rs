@@ -1,9 +1,28 @@
+use rspack_regex::RspackRegex;
+
 #[derive(Debug, Clone, Default)]
 pub struct SnapshotStrategy {
   pub hash: bool,
   pub timestamp: bool,
 }
 
+/// A path of [SnapshotOptions::managed_paths] or [SnapshotOptions::immutable_paths].
+#[derive(Debug, Clone)]
+pub enum SnapshotPath {
+  /// An absolute path, the paths start with it are matched
+  String(String),
+  Regexp(RspackRegex),
+}
+
+impl SnapshotPath {
+  pub fn is_match(&self, path: &str) -> bool {
+    match self {
+      Self::String(prefix) => path.starts_with(prefix.as_str()),
+      Self::Regexp(regexp) => regexp.test(path),
+    }
+  }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct SnapshotOptions {
   // Snapshots for resolving of build dependencies when using the persistent cache.
@@ -14,8 +33,10 @@ pub struct SnapshotOptions {
   pub resolve: SnapshotStrategy,
   /// Snapshots for building modules.
   pub module: SnapshotStrategy,
-  // An array of paths that are managed by a package manager and contain a version or a hash in their paths.
-  // immutable_paths: Vec<String>,
-  // An array of paths that are managed by a package manager.
-  // managed_paths: Vec<String>,
+  /// Paths that are managed by a package manager and contain a version or a hash in their paths,
+  /// the files in them are never checked.
+  pub immutable_paths: Vec<SnapshotPath>,
+  /// Paths that are managed by a package manager, the versions of the packages in them are
+  /// snapshotted instead of the files.
+  pub managed_paths: Vec<SnapshotPath>,
 }
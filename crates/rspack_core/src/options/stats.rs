@@ -1,4 +1,9 @@
 #[derive(Debug, Default)]
 pub struct StatsOptions {
   pub colors: bool,
+  /// Diagnostics with the same message are merged into one when they are reported
+  /// more than this number of times, `None` to never merge them.
+  pub similar_diagnostics_threshold: Option<usize>,
+  /// At most this number of importers are listed in the module trace of a diagnostic
+  pub module_trace_depth: usize,
 }
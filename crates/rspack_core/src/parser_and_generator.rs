@@ -6,8 +6,9 @@ use rspack_sources::BoxSource;
 
 use crate::{
   tree_shaking::visitor::OptimizeAnalyzeResult, BoxDependency, BuildInfo, BuildMeta,
-  CodeGenerationData, Compilation, CompilerOptions, DependencyTemplate, GeneratorOptions, Module,
-  ModuleDependency, ModuleIdentifier, ModuleType, ParserOptions, RuntimeGlobals, SourceType,
+  CodeGenerationData, Compilation, CompilerOptions, ConcatenationScope, DependencyTemplate,
+  GeneratorOptions, JsParserHandlers, Module, ModuleDependency, ModuleIdentifier, ModuleType,
+  ParserOptions, RuntimeGlobals, SourceType,
 };
 
 #[derive(Debug)]
@@ -17,12 +18,15 @@ pub struct ParseContext<'a> {
   pub module_type: &'a ModuleType,
   pub module_user_request: &'a str,
   pub module_parser_options: Option<&'a ParserOptions>,
+  pub module_generator_options: Option<&'a GeneratorOptions>,
   pub resource_data: &'a ResourceData,
   pub compiler_options: &'a CompilerOptions,
   pub additional_data: Option<String>,
   pub code_generation_dependencies: &'a mut Vec<Box<dyn ModuleDependency>>,
   pub build_info: &'a mut BuildInfo,
   pub build_meta: &'a mut BuildMeta,
+  /// The handlers of the JavaScript parser registered by the plugins
+  pub js_parser_handlers: &'a JsParserHandlers,
 }
 
 #[derive(Debug)]
@@ -40,9 +44,13 @@ pub struct GenerateContext<'a> {
   pub runtime_requirements: &'a mut RuntimeGlobals,
   pub data: &'a mut CodeGenerationData,
   pub requested_source_type: SourceType,
+  /// Set if the module is generated as a part of a concatenated module
+  pub concatenation_scope: Option<&'a mut ConcatenationScope>,
 }
 
 pub trait ParserAndGenerator: Send + Sync + Debug {
+  /// The name of the generator, used in diagnostics
+  fn name(&self) -> &'static str;
   /// The source types that the generator can generate (the source types you can make requests for)
   fn source_types(&self) -> &[SourceType];
   /// Parse the source and return the dependencies and the ast or source
@@ -9,11 +9,12 @@ use rspack_sources::BoxSource;
 use crate::{
   AdditionalChunkRuntimeRequirementsArgs, AssetEmittedArgs, AssetInfo, BoxLoader, BoxModule,
   ChunkAssetArgs, ChunkHashArgs, Compilation, CompilationArgs, CompilerOptions, ContentHashArgs,
-  DoneArgs, FactorizeArgs, JsChunkHashArgs, MakeParam, Module, ModuleArgs, ModuleFactoryResult,
-  ModuleType, NormalModule, NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs,
-  NormalModuleFactoryContext, OptimizeChunksArgs, ParserAndGenerator, PluginContext,
-  ProcessAssetsArgs, RenderArgs, RenderChunkArgs, RenderManifestArgs, RenderModuleContentArgs,
-  RenderStartupArgs, Resolver, SourceType, ThisCompilationArgs,
+  CreateModuleData, DoneArgs, FactorizeArgs, JsChunkHashArgs, JsParserHandlers, MakeParam, Module,
+  ModuleArgs, ModuleFactoryResult, ModuleType, NormalModule, NormalModuleAfterResolveArgs,
+  NormalModuleBeforeResolveArgs, NormalModuleFactoryContext, OptimizeChunksArgs,
+  ParserAndGenerator, PluginContext, ProcessAssetsArgs, RenderArgs, RenderChunkArgs,
+  RenderManifestArgs, RenderModuleContentArgs, RenderStartupArgs, Resolver, SourceType,
+  ThisCompilationArgs,
 };
 
 // use anyhow::{Context, Result};
@@ -28,6 +29,7 @@ pub type PluginModuleHookOutput = Result<Option<BoxModule>>;
 pub type PluginNormalModuleFactoryResolveForSchemeOutput = Result<(ResourceData, bool)>;
 pub type PluginNormalModuleFactoryBeforeResolveOutput = Result<Option<bool>>;
 pub type PluginNormalModuleFactoryAfterResolveOutput = Result<Option<bool>>;
+pub type PluginNormalModuleFactoryCreateModuleDataOutput = Result<()>;
 pub type PluginContentHashHookOutput = Result<Option<(SourceType, RspackHashDigest)>>;
 pub type PluginChunkHashHookOutput = Result<()>;
 pub type PluginRenderManifestHookOutput = Result<Vec<RenderManifestEntry>>;
@@ -50,6 +52,10 @@ pub trait Plugin: Debug + Send + Sync {
     Ok(())
   }
 
+  /// Register the handlers of the expressions of the JavaScript parser,
+  /// which are consulted before the built-in scanners.
+  fn register_js_parser_handlers(&self, _handlers: &mut JsParserHandlers) {}
+
   async fn compilation(&self, _args: CompilationArgs<'_>) -> PluginCompilationHookOutput {
     Ok(())
   }
@@ -120,6 +126,15 @@ pub trait Plugin: Debug + Send + Sync {
     Ok(None)
   }
 
+  /// Mutate the data of a normal module after resolution but before rules are matched
+  async fn create_module_data(
+    &self,
+    _ctx: PluginContext,
+    _args: &mut CreateModuleData,
+  ) -> PluginNormalModuleFactoryCreateModuleDataOutput {
+    Ok(())
+  }
+
   async fn module(&self, _ctx: PluginContext, _args: &ModuleArgs) -> PluginModuleHookOutput {
     Ok(None)
   }
@@ -362,6 +377,12 @@ pub trait Plugin: Debug + Send + Sync {
     Ok(())
   }
 
+  /// Called after the make stage and before tree shaking and chunk creation, the module graph
+  /// can be mutated by the helpers of [crate::ModuleGraph], e.g. [crate::ModuleGraph::replace_module]
+  async fn optimize_dependencies(&self, _compilation: &mut Compilation) -> Result<()> {
+    Ok(())
+  }
+
   async fn optimize_modules(&self, _compilation: &mut Compilation) -> Result<()> {
     Ok(())
   }
@@ -370,6 +391,11 @@ pub trait Plugin: Debug + Send + Sync {
     Ok(())
   }
 
+  /// Called after the module ids and chunk ids are assigned and before the code generation
+  async fn optimize_code_generation(&self, _compilation: &mut Compilation) -> Result<()> {
+    Ok(())
+  }
+
   async fn before_compile(&self) -> Result<()> {
     Ok(())
   }
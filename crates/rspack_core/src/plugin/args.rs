@@ -3,6 +3,7 @@ use std::path::{Path, PathBuf};
 
 use rspack_error::{internal_error, Result};
 use rspack_hash::RspackHash;
+use rspack_loader_runner::ResourceData;
 use rspack_sources::BoxSource;
 use rustc_hash::FxHashSet as HashSet;
 
@@ -10,8 +11,8 @@ use crate::ast::css::Ast as CssAst;
 use crate::ast::javascript::Ast as JsAst;
 use crate::{
   Chunk, ChunkUkey, Compilation, Context, DependencyCategory, DependencyType, ErrorSpan,
-  FactoryMeta, ModuleDependency, ModuleIdentifier, Resolve, RuntimeGlobals, SharedPluginDriver,
-  Stats,
+  FactoryMeta, ModuleDependency, ModuleIdentifier, ModuleSettings, Resolve, RuntimeGlobals,
+  SharedPluginDriver, Stats,
 };
 // #[derive(Debug)]
 // pub struct ParseModuleArgs<'a> {
@@ -103,11 +104,29 @@ pub struct ModuleArgs {
 pub struct NormalModuleBeforeResolveArgs {
   pub request: String,
   pub context: String,
+  /// The resource path of the module which issues the request
+  pub issuer: Option<String>,
 }
+#[derive(Debug)]
+pub struct CreateModuleData {
+  /// Request without resolving
+  pub raw_request: String,
+  /// Request intended by user, inline loaders are included
+  pub user_request: String,
+  /// Resolved resource, used for rule matching if `match_resource` is `None`
+  pub resource_data: ResourceData,
+  /// Resource matched with inline match resource, (`!=!` syntax)
+  pub match_resource: Option<ResourceData>,
+  /// Settings stored on the resulting module, exposed to loaders
+  pub settings: ModuleSettings,
+}
+
 #[derive(Debug, Clone)]
 pub struct NormalModuleAfterResolveArgs<'a> {
   pub request: &'a str,
   pub context: &'a str,
+  /// The resource path of the module which issues the request
+  pub issuer: Option<&'a str>,
   pub file_dependencies: &'a HashSet<PathBuf>,
   pub context_dependencies: &'a HashSet<PathBuf>,
   pub missing_dependencies: &'a HashSet<PathBuf>,
@@ -126,6 +145,7 @@ pub struct ResolveArgs<'a> {
   pub resolve_to_context: bool,
   pub optional: bool,
   pub file_dependencies: &'a mut HashSet<PathBuf>,
+  pub context_dependencies: &'a mut HashSet<PathBuf>,
   pub missing_dependencies: &'a mut HashSet<PathBuf>,
 }
 
@@ -237,6 +257,7 @@ impl<'me> RenderChunkArgs<'me> {
 pub struct RenderModuleContentArgs<'a> {
   pub module_source: &'a BoxSource,
   pub compilation: &'a Compilation,
+  pub module: ModuleIdentifier,
 }
 
 #[derive(Debug)]
@@ -12,14 +12,15 @@ use tracing::instrument;
 use crate::{
   AdditionalChunkRuntimeRequirementsArgs, ApplyContext, AssetEmittedArgs, BoxLoader,
   BoxedParserAndGeneratorBuilder, Chunk, ChunkAssetArgs, ChunkContentHash, ChunkHashArgs,
-  Compilation, CompilationArgs, CompilerOptions, Content, ContentHashArgs, DoneArgs, FactorizeArgs,
-  JsChunkHashArgs, MakeParam, Module, ModuleArgs, ModuleType, NormalModule,
-  NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs, NormalModuleFactoryContext,
-  OptimizeChunksArgs, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput,
-  PluginBuildEndHookOutput, PluginChunkHashHookOutput, PluginCompilationHookOutput, PluginContext,
-  PluginFactorizeHookOutput, PluginJsChunkHashHookOutput, PluginMakeHookOutput,
-  PluginModuleHookOutput, PluginNormalModuleFactoryAfterResolveOutput,
-  PluginNormalModuleFactoryBeforeResolveOutput, PluginProcessAssetsOutput,
+  Compilation, CompilationArgs, CompilerOptions, Content, ContentHashArgs, CreateModuleData,
+  DoneArgs, FactorizeArgs, JsChunkHashArgs, JsParserHandlers, MakeParam, Module, ModuleArgs,
+  ModuleType, NormalModule, NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs,
+  NormalModuleFactoryContext, OptimizeChunksArgs, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginBuildEndHookOutput,
+  PluginChunkHashHookOutput, PluginCompilationHookOutput, PluginContext, PluginFactorizeHookOutput,
+  PluginJsChunkHashHookOutput, PluginMakeHookOutput, PluginModuleHookOutput,
+  PluginNormalModuleFactoryAfterResolveOutput, PluginNormalModuleFactoryBeforeResolveOutput,
+  PluginNormalModuleFactoryCreateModuleDataOutput, PluginProcessAssetsOutput,
   PluginRenderChunkHookOutput, PluginRenderHookOutput, PluginRenderManifestHookOutput,
   PluginRenderModuleContentOutput, PluginRenderStartupHookOutput, PluginThisCompilationHookOutput,
   ProcessAssetsArgs, RenderArgs, RenderChunkArgs, RenderManifestArgs, RenderModuleContentArgs,
@@ -32,6 +33,8 @@ pub struct PluginDriver {
   pub resolver_factory: Arc<ResolverFactory>,
   // pub registered_parser: HashMap<ModuleType, BoxedParser>,
   pub registered_parser_and_generator_builder: HashMap<ModuleType, BoxedParserAndGeneratorBuilder>,
+  /// The handlers of the JavaScript parser registered by the plugins
+  pub js_parser_handlers: JsParserHandlers,
   /// Collecting error generated by plugin phase, e.g., `Syntax Error`
   pub diagnostics: Arc<Mutex<Vec<Diagnostic>>>,
 }
@@ -43,6 +46,7 @@ impl std::fmt::Debug for PluginDriver {
       .field("plugins", &self.plugins)
       // field("registered_parser", &self.registered_parser)
       .field("registered_parser_and_generator_builder", &"{..}")
+      .field("js_parser_handlers", &self.js_parser_handlers)
       .field("diagnostics", &self.diagnostics)
       .finish()
   }
@@ -71,12 +75,18 @@ impl PluginDriver {
       })
       .collect::<HashMap<ModuleType, BoxedParserAndGeneratorBuilder>>();
 
+    let mut js_parser_handlers = JsParserHandlers::default();
+    for plugin in &plugins {
+      plugin.register_js_parser_handlers(&mut js_parser_handlers);
+    }
+
     Self {
       options,
       plugins,
       resolver_factory,
       // registered_parser,
       registered_parser_and_generator_builder,
+      js_parser_handlers,
       diagnostics: Arc::new(Mutex::new(vec![])),
     }
   }
@@ -350,6 +360,19 @@ impl PluginDriver {
     }
     Ok(None)
   }
+  pub async fn create_module_data(
+    &self,
+    args: &mut CreateModuleData,
+  ) -> PluginNormalModuleFactoryCreateModuleDataOutput {
+    for plugin in &self.plugins {
+      tracing::trace!("running create module data:{}", plugin.name());
+      plugin
+        .create_module_data(PluginContext::new(), args)
+        .await?;
+    }
+    Ok(())
+  }
+
   pub async fn context_module_before_resolve(
     &self,
     args: &mut NormalModuleBeforeResolveArgs,
@@ -486,6 +509,14 @@ impl PluginDriver {
     Ok(())
   }
 
+  #[instrument(name = "plugin:optimize_dependencies", skip_all)]
+  pub async fn optimize_dependencies(&self, compilation: &mut Compilation) -> Result<()> {
+    for plugin in &self.plugins {
+      plugin.optimize_dependencies(compilation).await?;
+    }
+    Ok(())
+  }
+
   #[instrument(name = "plugin:optimize_modules", skip_all)]
   pub async fn optimize_modules(&self, compilation: &mut Compilation) -> Result<()> {
     for plugin in &self.plugins {
@@ -504,6 +535,14 @@ impl PluginDriver {
     Ok(())
   }
 
+  #[instrument(name = "plugin:optimize_code_generation", skip_all)]
+  pub async fn optimize_code_generation(&self, compilation: &mut Compilation) -> Result<()> {
+    for plugin in &self.plugins {
+      plugin.optimize_code_generation(compilation).await?;
+    }
+    Ok(())
+  }
+
   #[instrument(name = "plugin:finish_modules", skip_all)]
   pub async fn finish_modules(&self, modules: &mut Compilation) -> Result<()> {
     for plugin in &self.plugins {
@@ -75,7 +75,7 @@ impl Module for RawModule {
     Ok(
       BuildResult {
         build_info: BuildInfo {
-          hash: Some(hasher.digest(&build_context.compiler_options.output.hash_digest)),
+          hash: Some(build_context.compiler_options.output.digest(hasher)),
           cacheable: true,
           ..Default::default()
         },
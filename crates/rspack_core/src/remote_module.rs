@@ -0,0 +1,180 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_hash::RspackHash;
+use rspack_identifier::{Identifiable, Identifier};
+
+use crate::{
+  miss_module, module_id,
+  rspack_sources::{RawSource, Source, SourceExt},
+  BuildContext, BuildInfo, BuildMeta, BuildMetaExportsType, BuildResult, CodeGenerationResult,
+  Compilation, Context, LibIdentOptions, Module, ModuleType, RemoteToExternalDependency,
+  RuntimeGlobals, SourceType,
+};
+
+static REMOTE_MODULE_SOURCE_TYPES: &[SourceType] = &[SourceType::JavaScript];
+
+/// A module exposed by a remote container, i.e. `app2/Button` of `remotes: { app2: "app2@https://cdn/remoteEntry.js" }`.
+///
+/// The module depends on the external module of the remote container, once the container is loaded
+/// and initialized with the share scope, the module is got from the container.
+///
+/// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/container/RemoteModule.js
+#[derive(Debug)]
+pub struct RemoteModule {
+  id: Identifier,
+  /// The full request, i.e. `app2/Button`
+  request: String,
+  /// The request of the external module of the container, i.e. `webpack/container/reference/app2`
+  external_request: String,
+  /// The request of the module inside the container, i.e. `./Button`
+  internal_request: String,
+  share_scope: String,
+}
+
+impl RemoteModule {
+  pub fn new(
+    request: String,
+    external_request: String,
+    internal_request: String,
+    share_scope: String,
+  ) -> Self {
+    Self {
+      id: Identifier::from(format!("remote {external_request} {internal_request}")),
+      request,
+      external_request,
+      internal_request,
+      share_scope,
+    }
+  }
+
+  pub fn share_scope(&self) -> &str {
+    &self.share_scope
+  }
+}
+
+impl Identifiable for RemoteModule {
+  fn identifier(&self) -> Identifier {
+    self.id
+  }
+}
+
+#[async_trait::async_trait]
+impl Module for RemoteModule {
+  fn module_type(&self) -> &ModuleType {
+    &ModuleType::Js
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    REMOTE_MODULE_SOURCE_TYPES
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    None
+  }
+
+  fn readable_identifier(&self, _context: &Context) -> Cow<str> {
+    Cow::Owned(format!("remote {}", self.request))
+  }
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    6.0
+  }
+
+  async fn build(
+    &mut self,
+    build_context: BuildContext<'_>,
+  ) -> Result<TWithDiagnosticArray<BuildResult>> {
+    let mut hasher = RspackHash::from(&build_context.compiler_options.output);
+    self.update_hash(&mut hasher);
+
+    Ok(
+      BuildResult {
+        build_info: BuildInfo {
+          hash: Some(build_context.compiler_options.output.digest(hasher)),
+          ..Default::default()
+        },
+        // The container is loaded asynchronously
+        build_meta: BuildMeta {
+          is_async: true,
+          exports_type: BuildMetaExportsType::Dynamic,
+          ..Default::default()
+        },
+        dependencies: vec![Box::new(RemoteToExternalDependency::new(
+          self.external_request.clone(),
+        ))],
+        analyze_result: Default::default(),
+      }
+      .with_empty_diagnostic(),
+    )
+  }
+
+  fn code_generation(&self, compilation: &Compilation) -> Result<CodeGenerationResult> {
+    let external_module_id = compilation
+      .module_graph
+      .dependencies_by_module_identifier(&self.identifier())
+      .and_then(|dependencies| dependencies.first())
+      .map(|dependency_id| module_id(compilation, dependency_id, &self.external_request, false))
+      .unwrap_or_else(|| miss_module(&self.external_request));
+    let internal_request =
+      serde_json::to_string(&self.internal_request).expect("should render request");
+    let source = format!(
+      r#"module.exports = Promise.resolve({initialize_sharing}({share_scope})).then(function() {{
+  return {require}({external_module_id});
+}}).then(function(container) {{
+  if (!container) throw new Error("Container missing");
+  return container.get({internal_request});
+}}).then(function(factory) {{
+  return factory();
+}}, function(error) {{
+  if (!error) error = new Error("Container missing");
+  if (typeof error.message === "string") error.message += '\nwhile loading ' + {internal_request_message} + ' from ' + {external_request};
+  throw error;
+}});"#,
+      initialize_sharing = RuntimeGlobals::INITIALIZE_SHARING,
+      share_scope = serde_json::to_string(&self.share_scope).expect("should render share scope"),
+      require = RuntimeGlobals::REQUIRE,
+      internal_request_message =
+        serde_json::to_string(&internal_request).expect("should render request"),
+      external_request =
+        serde_json::to_string(&self.external_request).expect("should render request"),
+    );
+
+    let mut cgr = CodeGenerationResult::default();
+    cgr.add(SourceType::JavaScript, RawSource::from(source).boxed());
+    cgr.runtime_requirements.insert(RuntimeGlobals::MODULE);
+    cgr.runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+    cgr
+      .runtime_requirements
+      .insert(RuntimeGlobals::INITIALIZE_SHARING);
+    cgr.set_hash(
+      &compilation.options.output.hash_function,
+      &compilation.options.output.hash_digest,
+      &compilation.options.output.hash_salt,
+    );
+    Ok(cgr)
+  }
+
+  fn lib_ident(&self, _options: LibIdentOptions) -> Option<Cow<str>> {
+    Some(Cow::Owned(format!(
+      "webpack/container/remote/{}",
+      self.request
+    )))
+  }
+}
+
+impl Hash for RemoteModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__RemoteModule".hash(state);
+    self.identifier().hash(state);
+  }
+}
+
+impl PartialEq for RemoteModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier() == other.identifier()
+  }
+}
+
+impl Eq for RemoteModule {}
@@ -215,6 +215,42 @@ bitflags! {
     const NODE_MODULE_DECORATOR = 1 << 47;
 
     const HARMONY_MODULE_DECORATOR = 1 << 48;
+
+    /**
+     * the chunk prefetch function
+     */
+    const PREFETCH_CHUNK = 1 << 49;
+
+    /**
+     * an object with handlers to prefetch a chunk
+     */
+    const PREFETCH_CHUNK_HANDLERS = 1 << 50;
+
+    /**
+     * the chunk preload function
+     */
+    const PRELOAD_CHUNK = 1 << 51;
+
+    /**
+     * an object with handlers to preload a chunk
+     */
+    const PRELOAD_CHUNK_HANDLERS = 1 << 52;
+
+    /**
+     * the nonce set on the script and link tags created by the runtime
+     */
+    const SCRIPT_NONCE = 1 << 53;
+
+    /**
+     * an object containing all installed share scopes
+     */
+    const SHARE_SCOPE_MAP = 1 << 54;
+
+    /**
+     * initialize a share scope and the remote containers using it
+     * Arguments: (shareScope: string, initScope?: object[]) => Promise<void> | 1 | void
+     */
+    const INITIALIZE_SHARING = 1 << 55;
   }
 }
 
@@ -284,6 +320,13 @@ impl RuntimeGlobals {
       R::CREATE_FAKE_NAMESPACE_OBJECT => "__webpack_require__.t",
       R::HARMONY_MODULE_DECORATOR => "__webpack_require__.hmd",
       R::NODE_MODULE_DECORATOR => "__webpack_require__.nmd",
+      R::PREFETCH_CHUNK => "__webpack_require__.E",
+      R::PREFETCH_CHUNK_HANDLERS => "__webpack_require__.F",
+      R::PRELOAD_CHUNK => "__webpack_require__.G",
+      R::PRELOAD_CHUNK_HANDLERS => "__webpack_require__.H",
+      R::SCRIPT_NONCE => "__webpack_require__.nc",
+      R::SHARE_SCOPE_MAP => "__webpack_require__.S",
+      R::INITIALIZE_SHARING => "__webpack_require__.I",
       r => panic!(
         "Unexpected flag `{r:?}`. RuntimeGlobals should only be printed for one single flag."
       ),
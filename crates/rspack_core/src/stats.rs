@@ -1,17 +1,25 @@
+use std::time::Duration;
+
 use itertools::Itertools;
 use rspack_error::{
   emitter::{
     DiagnosticDisplay, DiagnosticDisplayer, StdioDiagnosticDisplay, StringDiagnosticDisplay,
   },
-  Result,
+  Diagnostic, Result, Severity,
 };
 use rspack_sources::Source;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use crate::{
-  BoxModule, Chunk, ChunkGroupUkey, Compilation, LogType, ModuleIdentifier, ModuleType, SourceType,
+  floor_char_boundary, AssetInfo, BoxModule, Chunk, ChunkGroupFetchPriority, ChunkGroupUkey,
+  Compilation, Context, ErrorSpan, LogType, ModuleIdentifier, ModuleType, SourceType,
 };
 
+/// At most this number of modules are listed in a merged diagnostic
+const MAX_SIMILAR_DIAGNOSTIC_MODULES: usize = 5;
+/// At most this number of modules and loaders are listed in the profile summary
+const PROFILE_SUMMARY_SIZE: usize = 10;
+
 #[derive(Debug, Clone)]
 pub struct Stats<'compilation> {
   pub compilation: &'compilation Compilation,
@@ -24,16 +32,32 @@ impl<'compilation> Stats<'compilation> {
 
   pub fn emit_diagnostics(&self) -> Result<()> {
     let mut displayer = StdioDiagnosticDisplay::default();
-    displayer.emit_batch_diagnostic(self.compilation.get_warnings())?;
-    displayer.emit_batch_diagnostic(self.compilation.get_errors())
+    displayer.emit_batch_diagnostic(self.normalized_warnings().iter())?;
+    displayer.emit_batch_diagnostic(self.normalized_errors().iter())
   }
 
   pub fn emit_diagnostics_string(&self, sorted: bool) -> Result<String> {
     let mut displayer = StringDiagnosticDisplay::default().with_sorted(sorted);
-    let warnings = displayer.emit_batch_diagnostic(self.compilation.get_warnings())?;
-    let errors = displayer.emit_batch_diagnostic(self.compilation.get_errors())?;
+    let warnings = displayer.emit_batch_diagnostic(self.normalized_warnings().iter())?;
+    let errors = displayer.emit_batch_diagnostic(self.normalized_errors().iter())?;
     Ok(format!("{warnings}{errors}"))
   }
+
+  fn normalized_errors(&self) -> Vec<Diagnostic> {
+    normalize_diagnostics(
+      self.compilation.get_errors(),
+      &self.compilation.options.context,
+      self.compilation.options.stats.similar_diagnostics_threshold,
+    )
+  }
+
+  fn normalized_warnings(&self) -> Vec<Diagnostic> {
+    normalize_diagnostics(
+      self.compilation.get_warnings(),
+      &self.compilation.options.context,
+      self.compilation.options.stats.similar_diagnostics_threshold,
+    )
+  }
 }
 
 impl Stats<'_> {
@@ -66,10 +90,7 @@ impl Stats<'_> {
                 size: source.size() as f64,
                 chunks: Vec::new(),
                 chunk_names: Vec::new(),
-                info: StatsAssetInfo {
-                  development: asset.info.development,
-                  hot_module_replacement: asset.info.hot_module_replacement,
-                },
+                info: StatsAssetInfo::from(&asset.info),
                 emitted: self.compilation.emitted_assets.contains(name),
               },
             )
@@ -147,10 +168,12 @@ impl Stats<'_> {
     Ok(modules)
   }
 
+  #[allow(clippy::too_many_arguments)]
   pub fn get_chunks(
     &self,
     chunk_modules: bool,
     chunk_relations: bool,
+    chunk_explanations: bool,
     reasons: bool,
     module_assets: bool,
     nested_modules: bool,
@@ -167,20 +190,9 @@ impl Stats<'_> {
         let mut auxiliary_files = Vec::from_iter(c.auxiliary_files.iter().cloned());
         auxiliary_files.sort_unstable();
 
-        let chunk_modules = if chunk_modules {
-          let chunk_modules = self
-            .compilation
-            .chunk_graph
-            .get_chunk_modules(&c.ukey, &self.compilation.module_graph);
-          let mut chunk_modules = chunk_modules
-            .into_iter()
-            .map(|m| self.get_module(m, reasons, module_assets, nested_modules, source))
-            .collect::<Result<Vec<_>>>()?;
-          Self::sort_modules(&mut chunk_modules);
-          Some(chunk_modules)
-        } else {
-          None
-        };
+        let chunk_modules = chunk_modules
+          .then(|| self.get_modules_of_chunk(c, reasons, module_assets, nested_modules, source))
+          .transpose()?;
         let (parents, children, siblings) = if let Some((parents, children, siblings)) =
           chunk_relations.then(|| self.get_chunk_relations(c))
         {
@@ -204,6 +216,8 @@ impl Stats<'_> {
           parents,
           children,
           siblings,
+          reason: chunk_explanations
+            .then(|| self.compilation.explain_chunk_by_ukey(&c.ukey).summary()),
         })
       })
       .collect::<Result<_>>()?;
@@ -211,6 +225,44 @@ impl Stats<'_> {
     Ok(chunks)
   }
 
+  /// The modules of the chunk with the given id, `None` if the chunk is not found.
+  /// Requested chunk by chunk, so the modules of all chunks aren't serialized at once.
+  pub fn get_chunk_modules(
+    &self,
+    chunk_id: &str,
+    reasons: bool,
+    module_assets: bool,
+    nested_modules: bool,
+    source: bool,
+  ) -> Result<Option<Vec<StatsModule>>> {
+    self
+      .compilation
+      .chunk_by_ukey
+      .values()
+      .find(|c| c.id.as_deref() == Some(chunk_id))
+      .map(|c| self.get_modules_of_chunk(c, reasons, module_assets, nested_modules, source))
+      .transpose()
+  }
+
+  fn get_modules_of_chunk(
+    &self,
+    chunk: &Chunk,
+    reasons: bool,
+    module_assets: bool,
+    nested_modules: bool,
+    source: bool,
+  ) -> Result<Vec<StatsModule>> {
+    let mut modules = self
+      .compilation
+      .chunk_graph
+      .get_chunk_modules(&chunk.ukey, &self.compilation.module_graph)
+      .into_iter()
+      .map(|m| self.get_module(m, reasons, module_assets, nested_modules, source))
+      .collect::<Result<Vec<_>>>()?;
+    Self::sort_modules(&mut modules);
+    Ok(modules)
+  }
+
   fn get_chunk_group(&self, name: &str, ukey: &ChunkGroupUkey) -> StatsChunkGroup {
     let cg = self
       .compilation
@@ -254,6 +306,7 @@ impl Stats<'_> {
       chunks,
       assets_size: assets.iter().map(|i| i.size).sum(),
       assets,
+      fetch_priority: cg.options.fetch_priority,
     }
   }
 
@@ -280,12 +333,19 @@ impl Stats<'_> {
   pub fn get_errors(&self) -> Vec<StatsError> {
     let mut diagnostic_displayer = DiagnosticDisplayer::new(self.compilation.options.stats.colors);
     self
-      .compilation
-      .get_errors()
-      .map(|d| StatsError {
-        title: d.title.clone(),
-        message: d.message.clone(),
-        formatted: diagnostic_displayer.emit_diagnostic(d).expect("TODO:"),
+      .normalized_errors()
+      .iter()
+      .map(|d| {
+        let (module_identifier, module_name, module_trace) = self.get_diagnostic_module(d);
+        StatsError {
+          title: d.title.clone(),
+          message: d.message.clone(),
+          formatted: diagnostic_displayer.emit_diagnostic(d).expect("TODO:"),
+          module_identifier,
+          module_name,
+          module_trace,
+          details: get_diagnostic_details(d),
+        }
       })
       .collect()
   }
@@ -293,15 +353,85 @@ impl Stats<'_> {
   pub fn get_warnings(&self) -> Vec<StatsWarning> {
     let mut diagnostic_displayer = DiagnosticDisplayer::new(self.compilation.options.stats.colors);
     self
-      .compilation
-      .get_warnings()
-      .map(|d| StatsWarning {
-        message: d.message.clone(),
-        formatted: diagnostic_displayer.emit_diagnostic(d).expect("TODO:"),
+      .normalized_warnings()
+      .iter()
+      .map(|d| {
+        let (module_identifier, module_name, module_trace) = self.get_diagnostic_module(d);
+        StatsWarning {
+          message: d.message.clone(),
+          formatted: diagnostic_displayer.emit_diagnostic(d).expect("TODO:"),
+          module_identifier,
+          module_name,
+          module_trace,
+          details: get_diagnostic_details(d),
+        }
       })
       .collect()
   }
 
+  /// The module which the diagnostic is reported from, and the trail of its importers.
+  fn get_diagnostic_module(
+    &self,
+    diagnostic: &Diagnostic,
+  ) -> (Option<String>, Option<String>, Vec<StatsModuleTrace>) {
+    let Some(module) = diagnostic.module_identifier.as_ref().and_then(|identifier| {
+      self
+        .compilation
+        .module_graph
+        .module_by_identifier(&ModuleIdentifier::from(identifier.as_str()))
+    }) else {
+      return (diagnostic.module_identifier.clone(), None, vec![]);
+    };
+    let (module_name, _) = get_stats_module_name_and_id(module, self.compilation);
+    (
+      Some(module.identifier().to_string()),
+      Some(module_name),
+      self.get_module_trace(module),
+    )
+  }
+
+  /// Walk the issuers of the module until an entry module, or the trail is as long as
+  /// `stats.moduleTraceDepth`.
+  fn get_module_trace(&self, module: &BoxModule) -> Vec<StatsModuleTrace> {
+    let module_graph = &self.compilation.module_graph;
+    let depth = self.compilation.options.stats.module_trace_depth;
+    let mut trace = vec![];
+    let mut visited = HashSet::default();
+    let mut current = module;
+    while trace.len() < depth
+      && !self
+        .compilation
+        .entry_module_identifiers
+        .contains(&current.identifier())
+      && let Some(issuer) = module_graph.get_issuer(current)
+      && visited.insert(issuer.identifier())
+    {
+      let loc = module_graph
+        .module_graph_module_by_identifier(&current.identifier())
+        .and_then(|mgm| mgm.incoming_connections_unordered(module_graph).ok())
+        .into_iter()
+        .flatten()
+        .filter(|connection| connection.original_module_identifier == Some(issuer.identifier()))
+        .filter_map(|connection| {
+          module_graph
+            .dependency_by_id(&connection.dependency_id)
+            .and_then(|dependency| dependency.as_module_dependency())
+            .and_then(|dependency| dependency.span())
+        })
+        .min_by_key(|span| (span.start, span.end))
+        .zip(issuer.original_source())
+        .map(|(span, source)| get_stats_location(&source.source(), span));
+      let (module_name, _) = get_stats_module_name_and_id(issuer, self.compilation);
+      trace.push(StatsModuleTrace {
+        module_identifier: issuer.identifier().to_string(),
+        module_name,
+        loc,
+      });
+      current = issuer;
+    }
+    trace
+  }
+
   pub fn get_logging(&self) -> Vec<(String, LogType)> {
     self
       .compilation
@@ -320,6 +450,58 @@ impl Stats<'_> {
     self.compilation.get_hash()
   }
 
+  /// The slowest modules to build and the slowest loaders in total, only available with `profile: true`.
+  pub fn get_profile_summary(&self) -> StatsProfileSummary {
+    let module_graph = &self.compilation.module_graph;
+    let mut modules = vec![];
+    let mut loaders: HashMap<&str, (Duration, usize)> = HashMap::default();
+    for module in module_graph.modules().values() {
+      let Some(profile) = module_graph.get_profile(module) else {
+        continue;
+      };
+      if let Some(building) = profile.building.duration() {
+        modules.push((module, building));
+      }
+      for loader in profile.loaders.get().into_iter().flatten() {
+        let (duration, count) = loaders
+          .entry(loader.identifier.as_str())
+          .or_insert((Duration::ZERO, 0));
+        *duration += loader.duration;
+        *count += 1;
+      }
+    }
+
+    let modules = modules
+      .into_iter()
+      .sorted_by(|a, b| {
+        b.1
+          .cmp(&a.1)
+          .then_with(|| a.0.identifier().cmp(&b.0.identifier()))
+      })
+      .take(PROFILE_SUMMARY_SIZE)
+      .map(|(module, building)| StatsProfileSummaryModule {
+        identifier: module.identifier(),
+        name: module
+          .readable_identifier(&self.compilation.options.context)
+          .into(),
+        building: building.into(),
+      })
+      .collect();
+    let loaders = loaders
+      .into_iter()
+      .sorted_by(|a, b| b.1 .0.cmp(&a.1 .0).then_with(|| a.0.cmp(b.0)))
+      .take(PROFILE_SUMMARY_SIZE)
+      .map(
+        |(identifier, (duration, modules))| StatsProfileSummaryLoader {
+          identifier: identifier.to_string(),
+          duration: duration.into(),
+          modules,
+        },
+      )
+      .collect();
+    StatsProfileSummary { modules, loaders }
+  }
+
   fn sort_modules(modules: &mut [StatsModule]) {
     // TODO: sort by module.depth
     modules.sort_unstable_by(|a, b| {
@@ -368,26 +550,40 @@ impl Stats<'_> {
         let mut reasons: Vec<StatsModuleReason> = mgm
           .incoming_connections_unordered(&self.compilation.module_graph)?
           .map(|connection| {
-            let (module_name, module_id) = connection
+            let original_module = connection
               .original_module_identifier
-              .and_then(|i| self.compilation.module_graph.module_by_identifier(&i))
-              .map(|m| get_stats_module_name_and_id(m, self.compilation))
-              .unzip();
+              .and_then(|i| self.compilation.module_graph.module_by_identifier(&i));
+            let (module_name, module_id) =
+              match (original_module, connection.original_module_identifier) {
+                (Some(m), _) => {
+                  let (name, id) = get_stats_module_name_and_id(m, self.compilation);
+                  (Some(name), id)
+                }
+                // The origin is a synthetic module (e.g. a concatenated or runtime module) which is not
+                // in the module graph, name it with its identifier instead of omitting it.
+                (None, Some(i)) => (Some(i.to_string()), None),
+                (None, None) => (None, None),
+              };
             let dependency = self
               .compilation
               .module_graph
-              .dependency_by_id(&connection.dependency_id)
-              .and_then(|d| d.as_module_dependency());
+              .dependency_by_id(&connection.dependency_id);
+            let module_dependency = dependency.and_then(|d| d.as_module_dependency());
 
             let r#type = dependency.map(|d| d.dependency_type().to_string());
 
-            let user_request = dependency.map(|d| d.user_request().to_string());
+            let user_request = module_dependency.map(|d| d.user_request().to_string());
+            let loc = module_dependency
+              .and_then(|d| d.span())
+              .zip(original_module.and_then(|m| m.original_source()))
+              .map(|(span, source)| get_stats_location(&source.source(), span));
             StatsModuleReason {
               module_identifier: connection.original_module_identifier.map(|i| i.to_string()),
               module_name,
-              module_id: module_id.and_then(|i| i),
+              module_id,
               r#type,
               user_request,
+              loc,
             }
           })
           .collect();
@@ -434,6 +630,17 @@ impl Stats<'_> {
         factory: StatsMillisecond::new(factory.as_secs(), factory.subsec_millis()),
         integration: StatsMillisecond::new(integration.as_secs(), integration.subsec_millis()),
         building: StatsMillisecond::new(building.as_secs(), building.subsec_millis()),
+        loaders: p
+          .loaders
+          .get()
+          .into_iter()
+          .flatten()
+          .map(|loader| StatsLoaderProfile {
+            identifier: loader.identifier.clone(),
+            duration: loader.duration.into(),
+          })
+          .collect(),
+        parsing: p.parsing.duration().map(Into::into),
       })
     } else {
       None
@@ -462,6 +669,7 @@ impl Stats<'_> {
       modules,
       source: source.then(|| module.original_source()).flatten(),
       profile,
+      optimization_bailout: mgm.optimization_bailout.clone(),
     })
   }
 
@@ -520,17 +728,137 @@ fn get_stats_module_name_and_id(
   (name.to_string(), id)
 }
 
+/// The notes of the diagnostic, which are shown with `stats.errorDetails`
+fn get_diagnostic_details(diagnostic: &Diagnostic) -> Option<String> {
+  (!diagnostic.notes.is_empty()).then(|| diagnostic.notes.join("\n"))
+}
+
+/// Format the span as `line:column-column` or `line:column-line:column`,
+/// lines are 1-based and columns are 0-based, which is the same as webpack.
+fn get_stats_location(source: &str, span: &ErrorSpan) -> String {
+  let position = |offset: u32| {
+    let before = &source[..floor_char_boundary(source, offset as usize)];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (line, before[line_start..].chars().count())
+  };
+  let (start_line, start_column) = position(span.start);
+  let (end_line, end_column) = position(span.end);
+  if start_line == end_line {
+    format!("{start_line}:{start_column}-{end_column}")
+  } else {
+    format!("{start_line}:{start_column}-{end_line}:{end_column}")
+  }
+}
+
+/// Module builds run in parallel, so the diagnostics are sorted by (severity, module, span, message)
+/// to be deterministic. The duplicated diagnostics of the same module and span are removed, and
+/// the diagnostics with the same message reported more than `threshold` times are merged into
+/// the first of them, which lists the affected modules.
+fn normalize_diagnostics<'a>(
+  diagnostics: impl Iterator<Item = &'a Diagnostic>,
+  context: &Context,
+  threshold: Option<usize>,
+) -> Vec<Diagnostic> {
+  fn severity_order(severity: Severity) -> u8 {
+    match severity {
+      Severity::Error => 0,
+      Severity::Warn => 1,
+    }
+  }
+  fn path(diagnostic: &Diagnostic) -> Option<&str> {
+    diagnostic
+      .source_info
+      .as_ref()
+      .map(|source_info| source_info.path.as_str())
+  }
+
+  let mut diagnostics = diagnostics
+    .unique_by(|d| (&d.message, path(d), d.start, d.end))
+    .collect::<Vec<_>>();
+  diagnostics.sort_by(|a, b| {
+    (severity_order(a.severity), path(a), a.start, &a.message).cmp(&(
+      severity_order(b.severity),
+      path(b),
+      b.start,
+      &b.message,
+    ))
+  });
+
+  let Some(threshold) = threshold else {
+    return diagnostics.into_iter().cloned().collect();
+  };
+  let mut similar: HashMap<(Severity, &str), Vec<&Diagnostic>> = HashMap::default();
+  for diagnostic in &diagnostics {
+    similar
+      .entry((diagnostic.severity, diagnostic.message.as_str()))
+      .or_default()
+      .push(diagnostic);
+  }
+  let mut merged = HashSet::default();
+  diagnostics
+    .into_iter()
+    .filter_map(|diagnostic| {
+      let key = (diagnostic.severity, diagnostic.message.as_str());
+      let group = &similar[&key];
+      if group.len() <= threshold {
+        return Some(diagnostic.clone());
+      }
+      if !merged.insert(key) {
+        return None;
+      }
+      // Errors from different modules are never merged silently
+      let modules = group
+        .iter()
+        .filter_map(|d| path(d))
+        .unique()
+        .take(MAX_SIMILAR_DIAGNOSTIC_MODULES)
+        .map(|path| context.shorten(path))
+        .collect::<Vec<_>>();
+      let mut diagnostic = diagnostic.clone();
+      for module in &modules {
+        diagnostic.message.push_str(&format!("\n  in {module}"));
+      }
+      let rest = group.len() - modules.len().max(1);
+      if rest > 0 {
+        diagnostic
+          .message
+          .push_str(&format!("\n  ... and {rest} more similar"));
+      }
+      Some(diagnostic)
+    })
+    .collect()
+}
+
 #[derive(Debug)]
 pub struct StatsError {
   pub message: String,
   pub formatted: String,
   pub title: String,
+  pub module_identifier: Option<String>,
+  pub module_name: Option<String>,
+  /// The importers of the module, from the nearest one to the entry
+  pub module_trace: Vec<StatsModuleTrace>,
+  pub details: Option<String>,
 }
 
 #[derive(Debug)]
 pub struct StatsWarning {
   pub message: String,
   pub formatted: String,
+  pub module_identifier: Option<String>,
+  pub module_name: Option<String>,
+  /// The importers of the module, from the nearest one to the entry
+  pub module_trace: Vec<StatsModuleTrace>,
+  pub details: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct StatsModuleTrace {
+  pub module_identifier: String,
+  pub module_name: String,
+  /// The location of the dependency in the importer
+  pub loc: Option<String>,
 }
 
 #[derive(Debug)]
@@ -552,8 +880,35 @@ pub struct StatsAssetsByChunkName {
 
 #[derive(Debug)]
 pub struct StatsAssetInfo {
+  pub immutable: bool,
+  pub minimized: bool,
+  pub chunk_hash: Vec<String>,
+  pub content_hash: Vec<String>,
+  /// The source files the asset is created from, joined by `, `
+  pub source_filename: Option<String>,
   pub development: bool,
   pub hot_module_replacement: bool,
+  pub related_source_map: Option<String>,
+  /// The count of source files whose identical content is deduplicated into this asset
+  pub deduplicated: Option<u32>,
+}
+
+impl From<&AssetInfo> for StatsAssetInfo {
+  fn from(info: &AssetInfo) -> Self {
+    let sorted = |set: &HashSet<String>| set.iter().cloned().sorted().collect::<Vec<_>>();
+    let source_filename = sorted(&info.source_filename);
+    Self {
+      immutable: info.immutable,
+      minimized: info.minimized,
+      chunk_hash: sorted(&info.chunk_hash),
+      content_hash: sorted(&info.content_hash),
+      source_filename: (!source_filename.is_empty()).then(|| source_filename.join(", ")),
+      development: info.development,
+      hot_module_replacement: info.hot_module_replacement,
+      related_source_map: info.related.source_map.clone(),
+      deduplicated: (info.source_filename.len() > 1).then(|| info.source_filename.len() as u32 - 1),
+    }
+  }
 }
 
 #[derive(Debug)]
@@ -574,6 +929,7 @@ pub struct StatsModule<'a> {
   pub modules: Option<Vec<StatsModule<'a>>>,
   pub source: Option<&'a dyn Source>,
   pub profile: Option<StatsModuleProfile>,
+  pub optimization_bailout: Vec<String>,
 }
 
 #[derive(Debug)]
@@ -581,6 +937,35 @@ pub struct StatsModuleProfile {
   pub factory: StatsMillisecond,
   pub integration: StatsMillisecond,
   pub building: StatsMillisecond,
+  pub loaders: Vec<StatsLoaderProfile>,
+  pub parsing: Option<StatsMillisecond>,
+}
+
+#[derive(Debug)]
+pub struct StatsLoaderProfile {
+  pub identifier: String,
+  pub duration: StatsMillisecond,
+}
+
+#[derive(Debug)]
+pub struct StatsProfileSummary {
+  pub modules: Vec<StatsProfileSummaryModule>,
+  pub loaders: Vec<StatsProfileSummaryLoader>,
+}
+
+#[derive(Debug)]
+pub struct StatsProfileSummaryModule {
+  pub identifier: ModuleIdentifier,
+  pub name: String,
+  pub building: StatsMillisecond,
+}
+
+#[derive(Debug)]
+pub struct StatsProfileSummaryLoader {
+  pub identifier: String,
+  pub duration: StatsMillisecond,
+  /// Number of modules built with the loader
+  pub modules: usize,
 }
 
 #[derive(Debug)]
@@ -597,6 +982,7 @@ pub struct StatsChunk<'a> {
   pub parents: Option<Vec<String>>,
   pub children: Option<Vec<String>>,
   pub siblings: Option<Vec<String>>,
+  pub reason: Option<String>,
 }
 
 #[derive(Debug)]
@@ -611,6 +997,7 @@ pub struct StatsChunkGroup {
   pub assets: Vec<StatsChunkGroupAsset>,
   pub chunks: Vec<String>,
   pub assets_size: f64,
+  pub fetch_priority: Option<ChunkGroupFetchPriority>,
 }
 
 #[derive(Debug)]
@@ -627,6 +1014,8 @@ pub struct StatsModuleReason {
   pub module_id: Option<String>,
   pub r#type: Option<String>,
   pub user_request: Option<String>,
+  /// The location of the dependency in the origin module
+  pub loc: Option<String>,
 }
 
 #[derive(Debug)]
@@ -643,3 +1032,77 @@ impl StatsMillisecond {
     }
   }
 }
+
+impl From<Duration> for StatsMillisecond {
+  fn from(value: Duration) -> Self {
+    Self::new(value.as_secs(), value.subsec_millis())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use rspack_error::DiagnosticSourceInfo;
+
+  use super::*;
+
+  fn diagnostic(message: &str, path: &str, start: usize) -> Diagnostic {
+    Diagnostic::error("Error".to_string(), message.to_string(), start, start + 1).with_source_info(
+      DiagnosticSourceInfo {
+        path: path.to_string(),
+        source: String::new(),
+      },
+    )
+  }
+
+  #[test]
+  fn test_normalize_diagnostics() {
+    let context = Context::new("/project".to_string());
+    let diagnostics = vec![
+      diagnostic("b", "/project/b.js", 0),
+      diagnostic("a", "/project/b.js", 2),
+      diagnostic("a", "/project/a.js", 5),
+      diagnostic("a", "/project/a.js", 5),
+    ];
+    let normalized = normalize_diagnostics(diagnostics.iter(), &context, None);
+    assert_eq!(
+      normalized
+        .iter()
+        .map(|d| (d.message.as_str(), d.start))
+        .collect::<Vec<_>>(),
+      vec![("a", 5), ("b", 0), ("a", 2)]
+    );
+
+    let normalized = normalize_diagnostics(diagnostics.iter(), &context, Some(1));
+    assert_eq!(normalized.len(), 2);
+    assert_eq!(normalized[0].message, "a\n  in ./a.js\n  in ./b.js");
+    assert_eq!(normalized[1].message, "b");
+  }
+
+  #[test]
+  fn test_stats_location_of_multi_byte_source() {
+    let source = "const a = \"你好\";\nrequire(a + \"世界\");";
+    let start = source.find("a +").expect("should find") as u32;
+    let end = source.rfind(')').expect("should find") as u32;
+    assert_eq!(
+      get_stats_location(source, &ErrorSpan::new(start, end)),
+      "2:8-16"
+    );
+    // offsets in the middle of a character fall back to the start of it
+    assert_eq!(
+      get_stats_location(source, &ErrorSpan::new(12, 13)),
+      "1:11-11"
+    );
+  }
+
+  #[test]
+  fn test_stats_location_of_offsets_inside_characters() {
+    let source = "// 注释\nimport \"./a\";";
+    // both offsets are in the middle of a character, instead of falling back to 1:0
+    assert_eq!(get_stats_location(source, &ErrorSpan::new(4, 8)), "1:3-4");
+    // offsets out of bounds are moved to the end of the source
+    assert_eq!(
+      get_stats_location(source, &ErrorSpan::new(4, 100)),
+      "1:3-2:13"
+    );
+  }
+}
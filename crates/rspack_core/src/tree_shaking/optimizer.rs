@@ -516,6 +516,8 @@ impl<'a> CodeSizeOptimizer<'a> {
               | DependencyType::DynamicImport
               | DependencyType::CjsRequire
               | DependencyType::ImportContext
+              | DependencyType::Provided
+              | DependencyType::AmdRequireItem
           );
 
           if self.side_effects_free_modules.contains(module_identifier)
@@ -616,6 +618,8 @@ impl<'a> CodeSizeOptimizer<'a> {
       .module_graph_module_by_identifier(&cur)
       .unwrap_or_else(|| panic!("Failed to get mgm by module identifier {cur}"));
     let mut module_ident_list = vec![];
+    // Modules required by CommonJS `require`, they are evaluated by the module itself
+    let mut required_module_ident_list = vec![];
     for dep in mgm.dependencies.iter() {
       let Some(&module_ident) = module_graph.module_identifier_by_dependency_id(dep) else {
         let ast_or_source = module_graph
@@ -631,22 +635,38 @@ impl<'a> CodeSizeOptimizer<'a> {
         }
       };
       module_ident_list.push(module_ident);
+      if module_graph.dependency_by_id(dep).map_or(false, |dep| {
+        matches!(
+          dep.dependency_type(),
+          DependencyType::CjsRequire | DependencyType::Provided | DependencyType::AmdRequireItem
+        )
+      }) {
+        required_module_ident_list.push(module_ident);
+      }
       Self::normalize_side_effects(module_ident, module_graph, visited_module, side_effects_map);
     }
     // visited_module.remove(&cur);
 
+    let has_side_effects = |ident: &Identifier| {
+      matches!(
+        side_effects_map.get(ident),
+        Some(SideEffectType::Analyze(true)) | Some(SideEffectType::Configuration(true))
+      )
+    };
     let need_change_to_side_effects_true = match side_effects_map.get(&cur) {
       // skip no deps or user already specified side effect in package.json
-      Some(SideEffectType::Configuration(_)) | None => false,
+      Some(SideEffectType::Configuration(true)) | None => false,
+      // only the evaluation of the module itself is side effects free,
+      // the modules it requires with side effects still need to be evaluated
+      Some(SideEffectType::Configuration(false)) => {
+        required_module_ident_list.iter().any(has_side_effects)
+      }
       // already marked as side-effectful
       Some(SideEffectType::Analyze(true)) => false,
       Some(SideEffectType::Analyze(false)) => {
-        let mut side_effect_list = module_ident_list.into_iter().filter(|ident| {
-          matches!(
-            side_effects_map.get(ident),
-            Some(SideEffectType::Analyze(true)) | Some(SideEffectType::Configuration(true))
-          )
-        });
+        let mut side_effect_list = module_ident_list
+          .iter()
+          .filter(|ident| has_side_effects(ident));
         side_effect_list.next().is_some()
         // uncomment below for debugging side_effect_list
         // let side_effect_list = side_effect_list.collect::<Vec<_>>();
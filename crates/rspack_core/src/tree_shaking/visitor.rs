@@ -448,6 +448,19 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
   fn visit_program(&mut self, node: &Program) {
     assert!(GLOBALS.is_set());
     node.visit_children_with(self);
+    // Provided and amd modules are required as a whole, just like `require()`
+    for dep in self.dependencies.iter() {
+      if matches!(
+        dep.dependency_type(),
+        DependencyType::Provided | DependencyType::AmdRequireItem
+      ) {
+        self
+          .bail_out_module_identifiers
+          .entry(ModuleIdOrDepId::DepId(*dep.id()))
+          .and_modify(|flag| *flag |= BailoutFlag::COMMONJS_REQUIRE)
+          .or_insert(BailoutFlag::COMMONJS_REQUIRE);
+      }
+    }
     // calc reachable imports for each export symbol defined in current module
     for (_, symbol) in self.export_map.iter() {
       match symbol {
@@ -1678,6 +1691,17 @@ impl From<ModuleRefAnalyze<'_>> for OptimizeAnalyzeResult {
   }
 }
 
+impl OptimizeAnalyzeResult {
+  /// Whether the top level statements of the module are free of side effects,
+  /// `None` if the side effects come from configuration instead of analysis.
+  pub fn side_effect_free(&self) -> Option<bool> {
+    match self.side_effects {
+      SideEffectType::Analyze(side_effects) => Some(!side_effects),
+      SideEffectType::Configuration(_) => None,
+    }
+  }
+}
+
 fn first_ident_of_assign_lhs(node: &AssignExpr) -> Option<Id> {
   let mut visitor = FirstIdentVisitor::default();
   node.left.visit_with(&mut visitor);
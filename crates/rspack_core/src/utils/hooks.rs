@@ -1,9 +1,13 @@
 use std::path::Path;
 
 use rspack_error::{internal_error, Error, InternalError, Severity, TraceableError};
+use rustc_hash::FxHashSet as HashSet;
 use sugar_path::SugarPath;
 
-use crate::{ResolveArgs, ResolveOptionsWithDependencyType, ResolveResult, SharedPluginDriver};
+use crate::{
+  AliasMap, CachedResolve, ResolveArgs, ResolveOptionsWithDependencyType, ResolveRequestKey,
+  ResolveResult, SharedPluginDriver,
+};
 
 /// Tuple used to represent a resolve error.
 /// The first element is the error message for runtime and the second element is the error used for stats and so on.
@@ -24,18 +28,51 @@ pub async fn resolve(
     args.specifier
   );
 
-  let resolver = plugin_driver
-    .resolver_factory
-    .get(ResolveOptionsWithDependencyType {
-      resolve_options: args.resolve_options,
-      resolve_to_context: args.resolve_to_context,
-      dependency_type: args.dependency_type.clone(),
-      dependency_category: *args.dependency_category,
-    });
+  let resolver_factory = &plugin_driver.resolver_factory;
+  let options = ResolveOptionsWithDependencyType {
+    resolve_options: args.resolve_options,
+    resolve_to_context: args.resolve_to_context,
+    dependency_type: args.dependency_type.clone(),
+    dependency_category: *args.dependency_category,
+  };
+  let (resolver, tsconfig_dependencies) = resolver_factory
+    .get_by_context(options, base_dir)
+    .map_err(|error| ResolveError(error.clone(), internal_error!(error)))?;
+  args.file_dependencies.extend(tsconfig_dependencies);
+  let request_key = ResolveRequestKey::new(&resolver, base_dir, args.specifier);
+  if let Some(cached) = resolver_factory.get_cached_request(&request_key) {
+    args
+      .file_dependencies
+      .extend(cached.dependencies.file_dependencies);
+    args
+      .context_dependencies
+      .extend(cached.dependencies.context_dependencies);
+    args
+      .missing_dependencies
+      .extend(cached.dependencies.missing_dependencies);
+    return Ok(cached.result);
+  }
+
   let result = resolver.resolve(base_dir, args.specifier);
-  let (file_dependencies, missing_dependencies) = resolver.dependencies();
-  args.file_dependencies.extend(file_dependencies);
-  args.missing_dependencies.extend(missing_dependencies);
+  let dependencies = resolver.dependencies(base_dir, args.specifier, &result);
+  if let Ok(result) = &result {
+    resolver_factory.cache_request(
+      request_key,
+      CachedResolve {
+        result: result.clone(),
+        dependencies: dependencies.clone(),
+      },
+    );
+  }
+  args
+    .file_dependencies
+    .extend(dependencies.file_dependencies);
+  args
+    .context_dependencies
+    .extend(dependencies.context_dependencies);
+  args
+    .missing_dependencies
+    .extend(dependencies.missing_dependencies);
 
   result.map_err(|error| match error {
     nodejs_resolver::Error::Io(error) => {
@@ -65,6 +102,19 @@ pub async fn resolve(
         let span = args.span.unwrap_or_default();
         // Use relative path in runtime for stable hashing
         let (runtime_message, internal_message) = if let nodejs_resolver::Error::Overflow = error {
+          // Replay the alias expansion to report the actual cycle, the runtime message stays short.
+          let internal_message = match find_alias_cycle(&resolver.options().alias, args.specifier) {
+            Some(chain) => format!(
+              "Can't resolve {:?} in {} , it had cycle alias: {}",
+              args.specifier,
+              importer,
+              chain.join(" → ")
+            ),
+            None => format!(
+              "Can't resolve {:?} in {} , maybe it had cycle alias",
+              args.specifier, importer
+            ),
+          };
           (
             format!(
               "Can't resolve {:?} in {} , maybe it had cycle alias",
@@ -73,19 +123,23 @@ pub async fn resolve(
                 .relative(&plugin_driver.options.context)
                 .display()
             ),
-            format!(
-              "Can't resolve {:?} in {} , maybe it had cycle alias",
-              args.specifier, importer
-            ),
+            internal_message,
           )
         } else {
+          let suggestion =
+            suggest_for_not_found(base_dir, args.specifier, &resolver.options().extensions)
+              .map(|suggestion| format!(". {suggestion}"))
+              .unwrap_or_default();
           (
             format!(
-              "Failed to resolve {} in {}",
+              "Failed to resolve {} in {}{suggestion}",
               args.specifier,
               base_dir.display()
             ),
-            format!("Failed to resolve {} in {}", args.specifier, importer),
+            format!(
+              "Failed to resolve {} in {}{suggestion}",
+              args.specifier, importer
+            ),
           )
         };
         ResolveError(
@@ -126,3 +180,200 @@ pub async fn resolve(
     }
   })
 }
+
+/// Suggest a fix for the relative `specifier` which isn't found in `base_dir`,
+/// either a similar name of the first missing path segment, or an extension missing from `resolve.extensions`.
+pub fn suggest_for_not_found(
+  base_dir: &Path,
+  specifier: &str,
+  extensions: &[String],
+) -> Option<String> {
+  if !specifier.starts_with("./") && !specifier.starts_with("../") {
+    return None;
+  }
+  let path = specifier.split(['?', '#']).next()?;
+  let segments = path.split('/').collect::<Vec<_>>();
+  let mut dir = base_dir.to_path_buf();
+  for (index, &segment) in segments.iter().enumerate() {
+    let next = dir.join(segment);
+    if matches!(segment, "" | "." | "..") || next.exists() {
+      dir = next;
+      continue;
+    }
+
+    let entries = std::fs::read_dir(&dir)
+      .ok()?
+      .filter_map(|entry| Some(entry.ok()?.file_name().to_string_lossy().to_string()))
+      .collect::<Vec<_>>();
+    let with_segment = |name: &str| {
+      segments
+        .iter()
+        .enumerate()
+        .map(|(i, segment)| if i == index { name } else { segment })
+        .collect::<Vec<_>>()
+        .join("/")
+    };
+    let is_last = index == segments.len() - 1;
+    if is_last {
+      let unresolved = entries.iter().find_map(|entry| {
+        let entry_path = Path::new(entry);
+        let extension = format!(".{}", entry_path.extension()?.to_str()?);
+        (entry_path.file_stem()? == segment && !extensions.contains(&extension))
+          .then_some((entry, extension))
+      });
+      if let Some((entry, extension)) = unresolved {
+        return Some(format!(
+          "'{}' exists but '{extension}' is not in resolve.extensions",
+          with_segment(entry)
+        ));
+      }
+    }
+
+    let max_distance = std::cmp::max(1, segment.chars().count() / 3);
+    return entries
+      .iter()
+      .map(|entry| match resolvable_stem(entry, extensions) {
+        // `./Buttom` is resolved to `./Button.js`, so `./Button` is suggested
+        Some(stem) if is_last && Path::new(segment).extension().is_none() => stem,
+        _ => entry.as_str(),
+      })
+      .map(|candidate| (edit_distance(segment, candidate), candidate))
+      .filter(|(distance, _)| *distance <= max_distance)
+      .min()
+      .map(|(_, candidate)| format!("Did you mean '{}'?", with_segment(candidate)));
+  }
+  None
+}
+
+/// The file name without extension if the extension is in `resolve.extensions`.
+fn resolvable_stem<'a>(entry: &'a str, extensions: &[String]) -> Option<&'a str> {
+  let entry = Path::new(entry);
+  let extension = format!(".{}", entry.extension()?.to_str()?);
+  if extensions.contains(&extension) {
+    entry.file_stem()?.to_str()
+  } else {
+    None
+  }
+}
+
+/// Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+  let b = b.chars().collect::<Vec<_>>();
+  let mut prev = (0..=b.len()).collect::<Vec<_>>();
+  for (i, a) in a.chars().enumerate() {
+    let mut current = vec![i + 1; b.len() + 1];
+    for (j, b) in b.iter().enumerate() {
+      let cost = usize::from(a != *b);
+      current[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(current[j] + 1);
+    }
+    prev = current;
+  }
+  prev[b.len()]
+}
+
+/// Replay the alias expansion of `request` and return the chain of requests
+/// if it ends up in a cycle, e.g. `["@a", "@b", "@a"]`, or if an alias is applied
+/// again to its own expansion, e.g. `["a", "a/b", "a/b/b"]` for `a` aliased to `a/b`.
+pub fn find_alias_cycle(alias: &[(String, Vec<AliasMap>)], request: &str) -> Option<Vec<String>> {
+  let mut chain = vec![request.to_string()];
+  let mut applied = HashSet::default();
+  let mut current = request.to_string();
+  loop {
+    let (index, next) = alias
+      .iter()
+      .enumerate()
+      .find_map(|(index, (key, targets))| {
+        let rest = match key.strip_suffix('$') {
+          Some(exact) => (current == exact).then_some(""),
+          None if current == *key => Some(""),
+          None => current
+            .strip_prefix(key.as_str())
+            .filter(|rest| rest.starts_with('/')),
+        }?;
+        targets.iter().find_map(|target| match target {
+          AliasMap::Target(target) => Some((index, format!("{target}{rest}"))),
+          AliasMap::Ignored => None,
+        })
+      })?;
+    let is_cycle = chain.contains(&next) || !applied.insert(index);
+    chain.push(next.clone());
+    if is_cycle {
+      return Some(chain);
+    }
+    current = next;
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn alias(key: &str, target: &str) -> (String, Vec<AliasMap>) {
+    (key.to_string(), vec![AliasMap::Target(target.to_string())])
+  }
+
+  #[test]
+  fn test_find_alias_cycle() {
+    let aliases = vec![alias("@a", "@b"), alias("@b", "@c"), alias("@c", "@a")];
+    assert_eq!(
+      find_alias_cycle(&aliases, "@a").expect("should have cycle"),
+      vec!["@a", "@b", "@c", "@a"]
+    );
+    assert_eq!(
+      find_alias_cycle(&aliases, "@b/foo").expect("should have cycle"),
+      vec!["@b/foo", "@c/foo", "@a/foo", "@b/foo"]
+    );
+    assert_eq!(
+      find_alias_cycle(&aliases, "@b")
+        .expect("should have cycle")
+        .join(" → "),
+      "@b → @c → @a → @b"
+    );
+    assert!(find_alias_cycle(&aliases, "@d").is_none());
+    assert!(find_alias_cycle(&[alias("@a", "@b"), alias("@b", "./b")], "@a").is_none());
+  }
+
+  #[test]
+  fn test_find_self_extending_alias() {
+    assert_eq!(
+      find_alias_cycle(&[alias("a", "a/b")], "a").expect("should have cycle"),
+      vec!["a", "a/b", "a/b/b"]
+    );
+    assert_eq!(
+      find_alias_cycle(&[alias("a", "b/a"), alias("b", "a")], "a/c").expect("should have cycle"),
+      vec!["a/c", "b/a/c", "a/a/c", "b/a/a/c"]
+    );
+    assert!(find_alias_cycle(&[alias("a$", "a/b")], "a").is_none());
+  }
+
+  #[test]
+  fn test_suggest_for_not_found() {
+    let dir = std::env::temp_dir().join("rspack_suggest_for_not_found");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("components")).expect("should create the dir");
+    for file in ["components/Button.js", "util.ts", "style.css"] {
+      std::fs::write(dir.join(file), "").expect("should write the file");
+    }
+    let extensions = vec![".js".to_string(), ".json".to_string()];
+    let suggest = |specifier| suggest_for_not_found(&dir, specifier, &extensions);
+
+    assert_eq!(
+      suggest("./components/Buttom").as_deref(),
+      Some("Did you mean './components/Button'?")
+    );
+    assert_eq!(
+      suggest("./component/Button").as_deref(),
+      Some("Did you mean './components/Button'?")
+    );
+    assert_eq!(
+      suggest("./styles.css").as_deref(),
+      Some("Did you mean './style.css'?")
+    );
+    assert_eq!(
+      suggest("./util").as_deref(),
+      Some("'./util.ts' exists but '.ts' is not in resolve.extensions")
+    );
+    assert!(suggest("./unknown").is_none());
+    assert!(suggest("react").is_none());
+  }
+}
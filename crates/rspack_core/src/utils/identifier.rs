@@ -18,6 +18,64 @@ pub fn contextify(context: impl AsRef<Path>, request: &str) -> String {
     .join("!")
 }
 
+/// A human-facing form of a request with loaders, the loaders are shortened to their package names
+/// with the length of their queries, such as `babel-loader?(42 chars)!./src/index.js`,
+/// while the resource is contextified.
+pub fn readable_request(context: impl AsRef<Path>, request: &str) -> String {
+  let context = context.as_ref().to_string_lossy();
+  // The match resource of `<match resource>!=!<request>`
+  let (match_resource, request) = match request.split_once("!=!") {
+    Some((match_resource, request)) => (Some(match_resource), request),
+    None => (None, request),
+  };
+  let mut segments = request.split('!').collect::<Vec<_>>();
+  let resource = segments.pop().unwrap_or_default();
+  let mut readable = segments
+    .into_iter()
+    .map(|loader| {
+      if loader.is_empty() {
+        // Prefixes like `-!` and `!!`
+        return String::new();
+      }
+      let (path, query) = match loader.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (loader, None),
+      };
+      let name = loader_package_name(path)
+        .map(String::from)
+        .unwrap_or_else(|| absolute_to_request(&context, path).into_owned());
+      match query {
+        Some(query) => format!("{name}?({} chars)", query.len()),
+        None => name,
+      }
+    })
+    .collect::<Vec<_>>();
+  readable.push(absolute_to_request(&context, resource).into_owned());
+  let readable = readable.join("!");
+  match match_resource {
+    Some(match_resource) => format!(
+      "{}!=!{readable}",
+      absolute_to_request(&context, match_resource)
+    ),
+    None => readable,
+  }
+}
+
+/// The package name of a loader installed in `node_modules`, such as `@scope/some-loader`.
+fn loader_package_name(path: &str) -> Option<&str> {
+  let idx = path.rfind("node_modules")?;
+  let rest = path[idx + "node_modules".len()..].trim_start_matches(['/', '\\']);
+  let mut end = rest.find(['/', '\\']).unwrap_or(rest.len());
+  if rest.starts_with('@') && end < rest.len() {
+    end += 1
+      + rest[end + 1..]
+        .find(['/', '\\'])
+        .unwrap_or(rest.len() - end - 1);
+  }
+  let name = &rest[..end];
+  (!name.is_empty()).then_some(name)
+}
+
 static IDENTIFIER_NAME_REPLACE_REGEX: Lazy<Regex> =
   Lazy::new(|| Regex::new(r"^([^a-zA-Z$_])").expect("should init regex"));
 static IDENTIFIER_REGEXP: Lazy<Regex> =
@@ -29,6 +87,69 @@ pub fn to_identifier(v: &str) -> String {
   IDENTIFIER_REGEXP.replace_all(&id, "_").to_string()
 }
 
+const DELTA_A_TO_Z: u32 = 26;
+/// `a-z`, `A-Z`, `_` and `$`
+pub const NUMBER_OF_IDENTIFIER_START_CHARS: u32 = DELTA_A_TO_Z * 2 + 2;
+/// The start chars and `0-9`
+pub const NUMBER_OF_IDENTIFIER_CONTINUATION_CHARS: u32 = NUMBER_OF_IDENTIFIER_START_CHARS + 10;
+
+/// The shortest identifier of the `n`th, i.e. `a`, `b`, ..., `$`, `ab`, `bb`, ...
+///
+/// Port from https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/Template.js#L141
+pub fn number_to_identifier(n: u32) -> String {
+  if n >= NUMBER_OF_IDENTIFIER_START_CHARS {
+    return format!(
+      "{}{}",
+      number_to_identifier(n % NUMBER_OF_IDENTIFIER_START_CHARS),
+      number_to_identifier_continuation(n / NUMBER_OF_IDENTIFIER_START_CHARS)
+    );
+  }
+  identifier_char(n, false).to_string()
+}
+
+fn number_to_identifier_continuation(n: u32) -> String {
+  if n >= NUMBER_OF_IDENTIFIER_CONTINUATION_CHARS {
+    return format!(
+      "{}{}",
+      number_to_identifier_continuation(n % NUMBER_OF_IDENTIFIER_CONTINUATION_CHARS),
+      number_to_identifier_continuation(n / NUMBER_OF_IDENTIFIER_CONTINUATION_CHARS)
+    );
+  }
+  identifier_char(n, true).to_string()
+}
+
+fn identifier_char(mut n: u32, continuation: bool) -> char {
+  if n < DELTA_A_TO_Z {
+    return char::from_u32('a' as u32 + n).expect("should be a letter");
+  }
+  n -= DELTA_A_TO_Z;
+  if n < DELTA_A_TO_Z {
+    return char::from_u32('A' as u32 + n).expect("should be a letter");
+  }
+  n -= DELTA_A_TO_Z;
+  if continuation {
+    if n < 10 {
+      return char::from_digit(n, 10).expect("should be a digit");
+    }
+    n -= 10;
+  }
+  if n == 0 {
+    '_'
+  } else {
+    '$'
+  }
+}
+
+static MEMBER_EXPRESSION_REGEXP: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(r"^[a-zA-Z_$][a-zA-Z0-9_$]*(\.[a-zA-Z_$][a-zA-Z0-9_$]*)*$").expect("should init regex")
+});
+
+/// Whether `v` is a plain identifier or a dotted member expression like `import.meta` or `globalThis.load`.
+#[inline]
+pub fn is_valid_member_expression(v: &str) -> bool {
+  MEMBER_EXPRESSION_REGEXP.is_match(v)
+}
+
 static PATH_QUERY_FRAGMENT_REGEXP: Lazy<Regex> = Lazy::new(|| {
   Regex::new("^((?:\0.|[^?#\0])*)(\\?(?:\0.|[^#\0])*)?(#.*)?$")
     .expect("Failed to initialize `PATH_QUERY_FRAGMENT_REGEXP`")
@@ -66,3 +187,59 @@ pub fn stringify_loaders_and_resource<'a>(
     Cow::Borrowed(resource)
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_readable_request() {
+    let request = "/root/node_modules/style-loader/dist/cjs.js!/root/node_modules/.pnpm/css-loader@6.8.1/node_modules/css-loader/dist/cjs.js?{\"modules\":true}!/root/node_modules/@scope/sass-loader/dist/cjs.js?sourceMap!/root/src/index.scss";
+    assert_eq!(
+      readable_request("/root", request),
+      "style-loader!css-loader?(16 chars)!@scope/sass-loader?(9 chars)!./src/index.scss"
+    );
+    assert_eq!(
+      readable_request("/root", "-!/root/loaders/local.js?a=1!/root/src/index.js"),
+      "-!./loaders/local.js?(3 chars)!./src/index.js"
+    );
+    assert_eq!(
+      readable_request(
+        "/root",
+        "/root/src/a.css!=!/root/node_modules/css-loader/index.js!/root/src/a.js"
+      ),
+      "./src/a.css!=!css-loader!./src/a.js"
+    );
+    assert_eq!(
+      readable_request("/root", "/root/src/index.js"),
+      "./src/index.js"
+    );
+  }
+
+  #[test]
+  fn test_number_to_identifier() {
+    assert_eq!(number_to_identifier(0), "a");
+    assert_eq!(number_to_identifier(25), "z");
+    assert_eq!(number_to_identifier(26), "A");
+    assert_eq!(number_to_identifier(52), "_");
+    assert_eq!(number_to_identifier(53), "$");
+    assert_eq!(number_to_identifier(54), "ab");
+    assert_eq!(number_to_identifier(55), "bb");
+    assert_eq!(number_to_identifier(54 * 53), "a1");
+    assert_eq!(number_to_identifier(54 * 64 - 1), "$$");
+    assert_eq!(number_to_identifier(54 * 64), "aab");
+  }
+
+  #[test]
+  fn test_is_valid_member_expression() {
+    assert!(is_valid_member_expression("import"));
+    assert!(is_valid_member_expression("import.meta"));
+    assert!(is_valid_member_expression("__non_webpack_import__"));
+    assert!(is_valid_member_expression("globalThis.$shim.load"));
+    assert!(!is_valid_member_expression(""));
+    assert!(!is_valid_member_expression("import."));
+    assert!(!is_valid_member_expression("1import"));
+    assert!(!is_valid_member_expression("import(x)"));
+    assert!(!is_valid_member_expression("a..b"));
+  }
+}
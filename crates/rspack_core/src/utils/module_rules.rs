@@ -89,9 +89,9 @@ pub async fn module_rule_matcher<'a>(
     }
   }
 
+  // Entry modules don't have an issuer, which is matched as an empty string like webpack
   if let Some(issuer_rule) = &module_rule.issuer
-    && let Some(issuer) = issuer
-    && !issuer_rule.try_match(issuer).await? {
+    && !issuer_rule.try_match(issuer.unwrap_or_default()).await? {
     return Ok(false);
   }
 
@@ -116,6 +116,14 @@ pub async fn module_rule_matcher<'a>(
     }
   }
 
+  // The nested rules come after the parent, so their loaders run before the parent's ones
+  // and their options override the parent's ones, just like webpack.
+  matched_rules.push(module_rule);
+
+  if let Some(rules) = &module_rule.rules {
+    module_rules_matcher(rules, resource_data, issuer, dependency, matched_rules).await?;
+  }
+
   if let Some(one_of) = &module_rule.one_of {
     for rule in one_of {
       if module_rule_matcher(rule, resource_data, issuer, dependency, matched_rules).await? {
@@ -124,10 +132,144 @@ pub async fn module_rule_matcher<'a>(
     }
   }
 
-  if let Some(rules) = &module_rule.rules {
-    module_rules_matcher(rules, resource_data, issuer, dependency, matched_rules).await?;
+  Ok(true)
+}
+
+#[cfg(test)]
+mod test {
+  use std::path::PathBuf;
+
+  use rspack_regex::RspackRegex;
+
+  use super::*;
+  use crate::{ModuleRuleUse, ModuleRuleUseLoader, RuleSetCondition};
+
+  fn regexp(expr: &str) -> Option<RuleSetCondition> {
+    Some(RuleSetCondition::Regexp(
+      RspackRegex::new(expr).expect("should be a valid regexp"),
+    ))
   }
 
-  matched_rules.push(module_rule);
-  Ok(true)
+  fn use_loader(loader: &str) -> ModuleRuleUse {
+    ModuleRuleUse::Array(vec![ModuleRuleUseLoader {
+      loader: loader.to_string(),
+      options: None,
+    }])
+  }
+
+  async fn matched_loaders(
+    rules: &[ModuleRule],
+    resource: &str,
+    issuer: Option<&str>,
+  ) -> Vec<String> {
+    let (path, query) = match resource.split_once('?') {
+      Some((path, query)) => (path, Some(format!("?{query}"))),
+      None => (resource, None),
+    };
+    let resource_data =
+      ResourceData::new(resource.to_string(), PathBuf::from(path)).query_optional(query);
+    let mut matched_rules = vec![];
+    module_rules_matcher(
+      rules,
+      &resource_data,
+      issuer,
+      &DependencyCategory::Esm,
+      &mut matched_rules,
+    )
+    .await
+    .expect("should match rules");
+    matched_rules
+      .into_iter()
+      .filter_map(|rule| match &rule.r#use {
+        ModuleRuleUse::Array(loaders) => Some(loaders),
+        ModuleRuleUse::Func(_) => None,
+      })
+      .flatten()
+      .map(|loader| loader.loader.clone())
+      .collect()
+  }
+
+  #[tokio::test]
+  async fn one_of_with_issuer() {
+    let rules = vec![ModuleRule {
+      test: regexp(r"\.ts$"),
+      one_of: Some(vec![
+        ModuleRule {
+          issuer: regexp(r"\.css$"),
+          r#use: use_loader("css-issuer"),
+          ..Default::default()
+        },
+        ModuleRule {
+          resource_query: regexp("raw"),
+          r#use: use_loader("raw"),
+          ..Default::default()
+        },
+        ModuleRule {
+          r#use: use_loader("ts"),
+          ..Default::default()
+        },
+      ]),
+      ..Default::default()
+    }];
+
+    assert_eq!(
+      matched_loaders(&rules, "/a.ts", Some("/b.css")).await,
+      ["css-issuer"]
+    );
+    assert_eq!(
+      matched_loaders(&rules, "/a.ts?raw", Some("/b.css")).await,
+      ["css-issuer"]
+    );
+    assert_eq!(
+      matched_loaders(&rules, "/a.ts?raw", Some("/index.js")).await,
+      ["raw"]
+    );
+    assert_eq!(
+      matched_loaders(&rules, "/a.ts", Some("/index.js")).await,
+      ["ts"]
+    );
+    // An entry module doesn't have an issuer
+    assert_eq!(matched_loaders(&rules, "/a.ts", None).await, ["ts"]);
+    assert!(matched_loaders(&rules, "/a.js", Some("/b.css"))
+      .await
+      .is_empty());
+  }
+
+  #[tokio::test]
+  async fn nested_rules_after_parent() {
+    let rules = vec![ModuleRule {
+      test: regexp(r"\.js$"),
+      r#use: use_loader("parent"),
+      rules: Some(vec![
+        ModuleRule {
+          issuer: regexp(r"\.css$"),
+          r#use: use_loader("css-issuer"),
+          ..Default::default()
+        },
+        ModuleRule {
+          r#use: use_loader("child"),
+          ..Default::default()
+        },
+      ]),
+      one_of: Some(vec![
+        ModuleRule {
+          r#use: use_loader("first"),
+          ..Default::default()
+        },
+        ModuleRule {
+          r#use: use_loader("second"),
+          ..Default::default()
+        },
+      ]),
+      ..Default::default()
+    }];
+
+    assert_eq!(
+      matched_loaders(&rules, "/a.js", Some("/index.js")).await,
+      ["parent", "child", "first"]
+    );
+    assert!(matched_loaders(&rules, "/a.ts", Some("/index.js"))
+      .await
+      .is_empty());
+  }
 }
@@ -6,3 +6,13 @@ pub fn is_source_equal(a: &dyn Source, b: &dyn Source) -> bool {
 
   a_source == b_source
 }
+
+/// Move the byte `offset` of `source` back to the nearest character boundary,
+/// the offsets which are out of bounds are moved to the end of `source`.
+pub fn floor_char_boundary(source: &str, offset: usize) -> usize {
+  let mut offset = offset.min(source.len());
+  while !source.is_char_boundary(offset) {
+    offset -= 1;
+  }
+  offset
+}
@@ -39,6 +39,10 @@ pub struct Diagnostic {
   pub end: usize,
   pub kind: DiagnosticKind,
   pub notes: Vec<String>,
+  /// The identifier of the module which the diagnostic is reported from
+  pub module_identifier: Option<String>,
+  /// The identifier of the loader which the diagnostic is reported from
+  pub loader: Option<String>,
 }
 
 impl Diagnostic {
@@ -79,6 +83,16 @@ impl Diagnostic {
     self.notes = notes;
     self
   }
+
+  pub fn with_module_identifier(mut self, module_identifier: Option<String>) -> Self {
+    self.module_identifier = module_identifier;
+    self
+  }
+
+  pub fn with_loader(mut self, loader: Option<String>) -> Self {
+    self.loader = loader;
+    self
+  }
 }
 
 impl From<Error> for Vec<Diagnostic> {
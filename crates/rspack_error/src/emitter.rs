@@ -190,13 +190,18 @@ fn emit_diagnostic<T: Write + WriteColor>(
     None => (vec![], diagnostic.message.clone()),
   };
 
+  let mut notes = diagnostic.notes.clone();
+  if let Some(loader) = &diagnostic.loader {
+    notes.push(format!("from loader {loader}"));
+  }
+
   let diagnostic = Diagnostic::new(diagnostic.severity.into())
     .with_message(message)
     // Because we don't have error code now, and I don't think we have
     // enough energy to matain error code either in the future, so I use
     // this field to represent diagnostic kind, looks pretty neat.
     .with_code(diagnostic.kind.to_string())
-    .with_notes(diagnostic.notes.clone())
+    .with_notes(notes)
     .with_labels(labels);
 
   let config = Config {
@@ -0,0 +1,2 @@
+// 一些中文注释
+const 你 = ;
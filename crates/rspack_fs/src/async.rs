@@ -29,6 +29,10 @@ pub trait AsyncWritableFileSystem {
 
   /// Removes a directory at this path, after removing all its contents. Use carefully.
   fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>>;
+
+  /// Returns the paths of the files in a directory and its subdirectories, relative to it and
+  /// separated by `/`. A missing directory has no files.
+  fn read_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<Vec<String>>>;
 }
 
 pub trait AsyncReadableFileSystem {
@@ -3,6 +3,9 @@ mod macros;
 cfg_async! {
   pub mod r#async;
   pub use r#async::{AsyncFileSystem, AsyncReadableFileSystem, AsyncWritableFileSystem};
+
+  mod memory;
+  pub use memory::AsyncMemoryFileSystem;
 }
 pub mod sync;
 pub use sync::{FileSystem, ReadableFileSystem, WritableFileSystem};
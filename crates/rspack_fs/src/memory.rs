@@ -0,0 +1,231 @@
+use std::{
+  collections::{HashMap, HashSet},
+  io::ErrorKind,
+  path::{Path, PathBuf},
+  sync::RwLock,
+};
+
+use futures::future::BoxFuture;
+
+use crate::{
+  sync::{ReadableFileSystem, WritableFileSystem},
+  AsyncReadableFileSystem, AsyncWritableFileSystem, Error, Result,
+};
+
+/// An in-memory file system, so that the emitted files can be read back without touching the disk.
+#[derive(Debug, Default)]
+pub struct AsyncMemoryFileSystem {
+  files: RwLock<HashMap<PathBuf, Vec<u8>>>,
+  dirs: RwLock<HashSet<PathBuf>>,
+}
+
+fn io_error(kind: ErrorKind, path: &Path) -> Error {
+  Error::Io(std::io::Error::new(kind, path.display().to_string()))
+}
+
+impl AsyncMemoryFileSystem {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The paths of all the files, in no particular order.
+  pub fn files(&self) -> Vec<PathBuf> {
+    self
+      .files
+      .read()
+      .expect("should lock files")
+      .keys()
+      .cloned()
+      .collect()
+  }
+
+  fn is_dir(&self, path: &Path) -> bool {
+    // The root always exists
+    path.parent().is_none()
+      || path.as_os_str().is_empty()
+      || self.dirs.read().expect("should lock dirs").contains(path)
+  }
+
+  /// Removes a file, see [AsyncWritableFileSystem::remove_file].
+  pub fn remove_file_sync(&self, file: &Path) -> Result<()> {
+    self
+      .files
+      .write()
+      .expect("should lock files")
+      .remove(file)
+      .map(|_| ())
+      .ok_or_else(|| io_error(ErrorKind::NotFound, file))
+  }
+
+  /// Removes a directory and all its contents, a missing directory is ignored like `rm -rf`.
+  pub fn remove_dir_all_sync(&self, dir: &Path) {
+    self
+      .files
+      .write()
+      .expect("should lock files")
+      .retain(|file, _| !file.starts_with(dir));
+    self
+      .dirs
+      .write()
+      .expect("should lock dirs")
+      .retain(|d| !d.starts_with(dir));
+  }
+}
+
+impl WritableFileSystem for AsyncMemoryFileSystem {
+  fn create_dir<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    let dir = dir.as_ref();
+    if self.is_dir(dir) {
+      return Err(io_error(ErrorKind::AlreadyExists, dir));
+    }
+    if let Some(parent) = dir.parent().filter(|parent| !self.is_dir(parent)) {
+      return Err(io_error(ErrorKind::NotFound, parent));
+    }
+    self
+      .dirs
+      .write()
+      .expect("should lock dirs")
+      .insert(dir.to_path_buf());
+    Ok(())
+  }
+
+  fn create_dir_all<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    let mut dirs = self.dirs.write().expect("should lock dirs");
+    for ancestor in dir.as_ref().ancestors() {
+      if ancestor.parent().is_none() || !dirs.insert(ancestor.to_path_buf()) {
+        break;
+      }
+    }
+    Ok(())
+  }
+
+  fn write<P: AsRef<Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> Result<()> {
+    let file = file.as_ref();
+    if let Some(parent) = file.parent().filter(|parent| !self.is_dir(parent)) {
+      return Err(io_error(ErrorKind::NotFound, parent));
+    }
+    self
+      .files
+      .write()
+      .expect("should lock files")
+      .insert(file.to_path_buf(), data.as_ref().to_vec());
+    Ok(())
+  }
+}
+
+impl ReadableFileSystem for AsyncMemoryFileSystem {
+  fn read<P: AsRef<Path>>(&self, file: P) -> Result<Vec<u8>> {
+    let file = file.as_ref();
+    self
+      .files
+      .read()
+      .expect("should lock files")
+      .get(file)
+      .cloned()
+      .ok_or_else(|| io_error(ErrorKind::NotFound, file))
+  }
+}
+
+impl AsyncWritableFileSystem for AsyncMemoryFileSystem {
+  fn create_dir<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+    let result = WritableFileSystem::create_dir(self, dir);
+    Box::pin(async move { result })
+  }
+
+  fn create_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+    let result = WritableFileSystem::create_dir_all(self, dir);
+    Box::pin(async move { result })
+  }
+
+  fn write<P: AsRef<Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> BoxFuture<'_, Result<()>> {
+    let result = WritableFileSystem::write(self, file, data);
+    Box::pin(async move { result })
+  }
+
+  fn remove_file<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<()>> {
+    let result = self.remove_file_sync(file.as_ref());
+    Box::pin(async move { result })
+  }
+
+  fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+    self.remove_dir_all_sync(dir.as_ref());
+    Box::pin(async move { Ok(()) })
+  }
+
+  fn read_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<Vec<String>>> {
+    let dir = dir.as_ref();
+    let files = self
+      .files
+      .read()
+      .expect("should lock files")
+      .keys()
+      .filter_map(|file| {
+        let relative = file.strip_prefix(dir).ok()?;
+        Some(
+          relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/"),
+        )
+      })
+      .collect();
+    Box::pin(async move { Ok(files) })
+  }
+}
+
+impl AsyncReadableFileSystem for AsyncMemoryFileSystem {
+  fn read<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<Vec<u8>>> {
+    let result = ReadableFileSystem::read(self, file);
+    Box::pin(async move { result })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn write_and_remove() {
+    let fs = AsyncMemoryFileSystem::new();
+    let file = Path::new("/dist/js/main.js");
+    assert!(WritableFileSystem::write(&fs, file, "a").is_err());
+
+    WritableFileSystem::create_dir_all(&fs, "/dist/js").expect("should create dirs");
+    WritableFileSystem::write(&fs, file, "a").expect("should write the file");
+    assert_eq!(
+      ReadableFileSystem::read(&fs, file).expect("should read the file"),
+      b"a"
+    );
+    assert!(WritableFileSystem::create_dir(&fs, "/dist").is_err());
+
+    fs.remove_file_sync(file).expect("should remove the file");
+    assert!(ReadableFileSystem::read(&fs, file).is_err());
+    assert!(fs.remove_file_sync(file).is_err());
+
+    WritableFileSystem::write(&fs, file, "b").expect("should write the file");
+    WritableFileSystem::write(&fs, "/dist.js", "c").expect("should write the file");
+    fs.remove_dir_all_sync(Path::new("/dist"));
+    assert_eq!(fs.files(), [PathBuf::from("/dist.js")]);
+    assert!(WritableFileSystem::write(&fs, file, "a").is_err());
+  }
+
+  #[test]
+  fn read_dir_all() {
+    let fs = AsyncMemoryFileSystem::new();
+    WritableFileSystem::create_dir_all(&fs, "/dist/js").expect("should create dirs");
+    for file in ["/dist/main.js", "/dist/js/a.js", "/dist.js"] {
+      WritableFileSystem::write(&fs, file, "a").expect("should write the file");
+    }
+    let mut files =
+      futures::executor::block_on(AsyncWritableFileSystem::read_dir_all(&fs, "/dist"))
+        .expect("should read the dir");
+    files.sort();
+    assert_eq!(files, ["js/a.js", "main.js"]);
+    assert!(
+      futures::executor::block_on(AsyncWritableFileSystem::read_dir_all(&fs, "/missing"))
+        .expect("should read the dir")
+        .is_empty()
+    );
+  }
+}
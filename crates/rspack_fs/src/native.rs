@@ -69,6 +69,36 @@ cfg_async! {
       let fut = async move { tokio::fs::remove_dir_all(dir).await.map_err(Error::from) };
       Box::pin(fut)
     }
+
+    fn read_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<Vec<String>>> {
+      let dir = dir.as_ref().to_path_buf();
+      let fut = async move {
+        let mut files = vec![];
+        let mut pending = vec![String::new()];
+        while let Some(relative) = pending.pop() {
+          let mut entries = match tokio::fs::read_dir(dir.join(&relative)).await {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(Error::from(err)),
+          };
+          while let Some(entry) = entries.next_entry().await? {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let path = if relative.is_empty() {
+              name
+            } else {
+              format!("{relative}/{name}")
+            };
+            if entry.file_type().await?.is_dir() {
+              pending.push(path);
+            } else {
+              files.push(path);
+            }
+          }
+        }
+        Ok(files)
+      };
+      Box::pin(fut)
+    }
   }
 
   impl AsyncReadableFileSystem for AsyncNativeFileSystem {
@@ -101,10 +101,14 @@ impl AsyncWritableFileSystem for AsyncNodeWritableFileSystem {
         .await
         .expect("Failed to poll")
         .map_err(|e| {
-          rspack_fs::Error::Io(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            e.to_string(),
-          ))
+          let message = e.to_string();
+          // Let the caller tell a file which is already removed from the other failures
+          let kind = if message.contains("ENOENT") {
+            std::io::ErrorKind::NotFound
+          } else {
+            std::io::ErrorKind::Other
+          };
+          rspack_fs::Error::Io(std::io::Error::new(kind, message))
         })
         .map(|_| ())
     };
@@ -134,4 +138,27 @@ impl AsyncWritableFileSystem for AsyncNodeWritableFileSystem {
     };
     Box::pin(fut)
   }
+
+  fn read_dir_all<P: AsRef<std::path::Path>>(
+    &self,
+    dir: P,
+  ) -> BoxFuture<'_, rspack_fs::Result<Vec<String>>> {
+    let dir = dir.as_ref().to_string_lossy().to_string();
+    let fut = async move {
+      self
+        .fs_ts
+        .read_dir_all
+        .call(dir, ThreadsafeFunctionCallMode::NonBlocking)
+        .expect("Failed to call tsfn")
+        .await
+        .expect("Failed to poll")
+        .map_err(|e| {
+          rspack_fs::Error::Io(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            e.to_string(),
+          ))
+        })
+    };
+    Box::pin(fut)
+  }
 }
@@ -70,6 +70,7 @@ cfg_async! {
     pub mkdir: JsFunction,
     pub mkdirp: JsFunction,
     pub remove_dir_all: JsFunction,
+    pub read_dir_all: JsFunction,
   }
 
   trait TryIntoJsUnknown {
@@ -154,6 +155,7 @@ cfg_async! {
         mkdir: self.mkdir.try_into_tsfn(env)?,
         mkdirp: self.mkdirp.try_into_tsfn(env)?,
         remove_dir_all: self.remove_dir_all.try_into_tsfn(env)?,
+        read_dir_all: self.read_dir_all.try_into_tsfn(env)?,
       })
     }
   }
@@ -164,5 +166,6 @@ cfg_async! {
     pub(crate) mkdir: ThreadsafeFunction<String, ()>,
     pub(crate) mkdirp: ThreadsafeFunction<String, Either<String, ()>>,
     pub(crate) remove_dir_all: ThreadsafeFunction<String, Either<String, ()>>,
+    pub(crate) read_dir_all: ThreadsafeFunction<String, Vec<String>>,
   }
 }
@@ -0,0 +1,39 @@
+use std::hash::Hash;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+
+/// Roughly the shape of module sources in a medium sized project.
+fn module_sources() -> Vec<String> {
+  (0..5000)
+    .map(|i| {
+      format!(
+        "import {{ dep{i} }} from './dep{i}';\nexport function module{i}() {{\n  return dep{i}() + {i};\n}}\n"
+      )
+      .repeat(20)
+    })
+    .collect()
+}
+
+fn hash_sources(function: HashFunction, sources: &[String]) {
+  for source in sources {
+    let mut hasher = RspackHash::new(&function);
+    source.hash(&mut hasher);
+    black_box(hasher.digest(&HashDigest::Hex));
+  }
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+  let sources = module_sources();
+  let mut group = c.benchmark_group("hash_module_sources");
+  group.bench_function("xxhash64", |b| {
+    b.iter(|| hash_sources(HashFunction::Xxhash64, &sources))
+  });
+  group.bench_function("md4", |b| {
+    b.iter(|| hash_sources(HashFunction::MD4, &sources))
+  });
+  group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -3,7 +3,7 @@ use std::{
   hash::{Hash, Hasher},
 };
 
-use data_encoding::HEXLOWER_PERMISSIVE;
+use data_encoding::{BASE64, HEXLOWER_PERMISSIVE};
 use md4::Digest;
 use smol_str::SmolStr;
 use xxhash_rust::xxh3;
@@ -14,12 +14,16 @@ pub enum HashFunction {
   MD4,
 }
 
-impl From<&str> for HashFunction {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashFunction {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "xxhash64" => HashFunction::Xxhash64,
-      "md4" => HashFunction::MD4,
-      _ => unimplemented!("{}", value),
+      "xxhash64" => Ok(HashFunction::Xxhash64),
+      "md4" => Ok(HashFunction::MD4),
+      _ => Err(format!(
+        "Unsupported output.hashFunction '{value}', expected 'xxhash64' or 'md4'"
+      )),
     }
   }
 }
@@ -27,13 +31,19 @@ impl From<&str> for HashFunction {
 #[derive(Debug, Clone, Copy)]
 pub enum HashDigest {
   Hex,
+  Base64,
 }
 
-impl From<&str> for HashDigest {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashDigest {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "hex" => HashDigest::Hex,
-      _ => unimplemented!(),
+      "hex" => Ok(HashDigest::Hex),
+      "base64" => Ok(HashDigest::Base64),
+      _ => Err(format!(
+        "Unsupported output.hashDigest '{value}', expected 'hex' or 'base64'"
+      )),
     }
   }
 }
@@ -128,6 +138,7 @@ impl RspackHashDigest {
   pub fn new(inner: Vec<u8>, digest: &HashDigest) -> Self {
     let encoded = match digest {
       HashDigest::Hex => HEXLOWER_PERMISSIVE.encode(&inner).into(),
+      HashDigest::Base64 => BASE64.encode(&inner).into(),
     };
     Self { encoded }
   }
@@ -136,6 +147,18 @@ impl RspackHashDigest {
     &self.encoded
   }
 
+  /// Keep the first `length` characters of the encoded digest, see [RspackHashDigest::rendered].
+  pub fn truncated(self, length: usize) -> Self {
+    if self.encoded.len() <= length {
+      return self;
+    }
+    Self {
+      encoded: self.rendered(length).into(),
+    }
+  }
+
+  /// The encoded digest truncated to `length`, the truncation always happens after encoding,
+  /// so `[contenthash:8]` and `hashDigestLength: 8` render the same string.
   pub fn rendered(&self, length: usize) -> &str {
     let len = self.encoded.len().min(length);
     &self.encoded[..len]
@@ -153,3 +176,41 @@ impl PartialEq for RspackHashDigest {
     self.encoded == other.encoded
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn digest(function: HashFunction, digest: HashDigest) -> RspackHashDigest {
+    let mut hasher = RspackHash::new(&function);
+    "rspack".hash(&mut hasher);
+    hasher.digest(&digest)
+  }
+
+  #[test]
+  fn should_reject_unknown_options() {
+    assert!(matches!(
+      HashFunction::try_from("xxhash64"),
+      Ok(HashFunction::Xxhash64)
+    ));
+    assert!(HashFunction::try_from("sha256").is_err());
+    assert!(matches!(
+      HashDigest::try_from("base64"),
+      Ok(HashDigest::Base64)
+    ));
+    assert!(HashDigest::try_from("latin1").is_err());
+  }
+
+  #[test]
+  fn should_truncate_after_encoding() {
+    for function in [HashFunction::Xxhash64, HashFunction::MD4] {
+      let hex = digest(function, HashDigest::Hex);
+      assert_eq!(hex.rendered(8), &hex.encoded()[..8]);
+      assert_eq!(hex.rendered(usize::MAX), hex.encoded());
+
+      let base64 = digest(function, HashDigest::Base64);
+      assert_eq!(base64.rendered(8), &base64.encoded()[..8]);
+      assert_ne!(base64.rendered(8), hex.rendered(8));
+    }
+  }
+}
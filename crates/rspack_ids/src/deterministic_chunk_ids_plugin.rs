@@ -23,19 +23,16 @@ impl DeterministicChunkIdsPlugin {
 
 impl Plugin for DeterministicChunkIdsPlugin {
   fn chunk_ids(&self, compilation: &mut rspack_core::Compilation) -> rspack_error::Result<()> {
-    let mut used_ids = get_used_chunk_ids(compilation);
-    let used_ids_len = used_ids.len();
+    let used_ids = get_used_chunk_ids(compilation);
 
-    let chunk_graph = &mut compilation.chunk_graph;
+    let chunk_graph = &compilation.chunk_graph;
     let module_graph = &compilation.module_graph;
     let context = self
       .context
       .clone()
       .unwrap_or_else(|| compilation.options.context.as_str().to_string());
 
-    let max_length = 3;
-    let expand_factor = 10;
-    let salt = 10;
+    let min_length = 3;
 
     let chunks = compilation
       .chunk_by_ukey
@@ -48,20 +45,12 @@ impl Plugin for DeterministicChunkIdsPlugin {
       chunks,
       |chunk| get_full_chunk_name(chunk, chunk_graph, module_graph, &context),
       |a, b| compare_chunks_natural(chunk_graph, module_graph, a, b),
+      &compilation.options.output,
+      &used_ids,
+      min_length,
       |chunk, id| {
-        let size = used_ids.len();
-        used_ids.insert(id.clone());
-        if used_ids.len() == size {
-          return false;
-        }
-
         chunk_key_to_id.insert(chunk.ukey, id);
-        true
       },
-      &[usize::pow(10, max_length)],
-      expand_factor,
-      used_ids_len,
-      salt,
     );
 
     chunk_key_to_id.into_iter().for_each(|(chunk_ukey, id)| {
@@ -1,7 +1,7 @@
 use std::{
   borrow::Cow,
   cmp::Ordering,
-  collections::{hash_map::DefaultHasher, HashMap, HashSet},
+  collections::{hash_map::DefaultHasher, BTreeMap, HashMap, HashSet},
   hash::{Hash, Hasher},
 };
 
@@ -14,12 +14,12 @@ use rayon::prelude::*;
 use regex::Regex;
 use rspack_core::{
   compare_runtime, BoxModule, Chunk, ChunkGraph, ChunkUkey, Compilation, ModuleGraph,
-  ModuleIdentifier,
+  ModuleIdentifier, OutputOptions,
 };
+use rspack_hash::{HashDigest, RspackHash};
 use rspack_util::{
   comparators::{compare_ids, compare_numbers},
   identifier::make_paths_relative,
-  number_hash::get_number_hash,
 };
 
 #[allow(clippy::type_complexity)]
@@ -92,10 +92,22 @@ fn avoid_number(s: &str) -> Cow<str> {
   Cow::Borrowed(s)
 }
 
-pub fn get_long_module_name(short_name: &str, module: &BoxModule, context: &str) -> String {
+pub fn get_long_module_name(
+  short_name: &str,
+  module: &BoxModule,
+  context: &str,
+  output: &OutputOptions,
+) -> String {
   let full_name = get_full_module_name(module, context);
-
-  format!("{}?{}", short_name, get_hash(full_name, 4))
+  let mut hasher = RspackHash::from(output);
+  full_name.hash(&mut hasher);
+  let hash = hasher.digest(&output.hash_digest);
+  // 4 characters as webpack, unless `output.hashDigestLength` is shorter
+  format!(
+    "{}?{}",
+    short_name,
+    hash.rendered(output.hash_digest_length.min(4))
+  )
 }
 
 pub fn get_full_module_name(module: &BoxModule, context: &str) -> String {
@@ -249,41 +261,54 @@ pub fn assign_names_par<T: Copy + Send>(
 }
 
 #[allow(clippy::too_many_arguments)]
+/// Assign the shortest prefix of the hex digest of each name, starting at `min_length` characters,
+/// which collides neither with the other items nor with `used_ids`. Only the colliding items are
+/// given longer prefixes, so adding a module changes the ids of its colliding group at most.
 pub fn assign_deterministic_ids<T: Copy>(
   mut items: Vec<T>,
   get_name: impl Fn(T) -> String,
   comparator: impl Fn(&T, &T) -> Ordering,
-  mut assign_id: impl FnMut(T, String) -> bool,
-  ranges: &[usize],
-  expand_factor: usize,
-  extra_space: usize,
-  salt: usize,
+  output: &OutputOptions,
+  used_ids: &HashSet<String>,
+  min_length: usize,
+  mut assign_id: impl FnMut(T, String),
 ) {
+  // The items are created in parallel, sort them so that the ids don't depend on that order
   items.sort_unstable_by(comparator);
+  let mut pending = items
+    .into_iter()
+    .map(|item| {
+      let mut hasher = RspackHash::from(output);
+      get_name(item).hash(&mut hasher);
+      let digest = hasher.digest(&HashDigest::Hex);
+      (item, digest.encoded().to_string())
+    })
+    .collect::<Vec<_>>();
 
-  let optimal_range = usize::min(items.len() * 20 + extra_space, usize::MAX);
-  let mut i = 0;
-  debug_assert!(!ranges.is_empty());
-  let mut range = ranges[i];
-  while range < optimal_range {
-    i += 1;
-    if i < ranges.len() {
-      range = usize::min(ranges[i], usize::MAX);
-    } else if expand_factor != 0 {
-      range = usize::min(range * expand_factor, usize::MAX);
-    } else {
-      break;
+  let mut length = min_length;
+  while !pending.is_empty() {
+    let mut prefix_to_items: BTreeMap<String, Vec<(T, String)>> = BTreeMap::new();
+    for (item, digest) in pending {
+      let prefix = digest[..length.min(digest.len())].to_string();
+      prefix_to_items
+        .entry(prefix)
+        .or_default()
+        .push((item, digest));
     }
-  }
-
-  for item in items {
-    let ident = get_name(item);
-    let mut i = salt;
-    let mut id = get_number_hash(&format!("{ident}{i}"), range).to_string();
-    while !assign_id(item, id) {
-      i += 1;
-      id = get_number_hash(&format!("{ident}{i}"), range).to_string();
+    pending = vec![];
+    for (prefix, items) in prefix_to_items {
+      if items.len() == 1 && !used_ids.contains(&prefix) {
+        assign_id(items[0].0, prefix);
+      } else if length >= items[0].1.len() {
+        // The names have the same digest, tell them apart by their order
+        for (index, (item, digest)) in items.into_iter().enumerate() {
+          assign_id(item, format!("{digest}{index}"));
+        }
+      } else {
+        pending.extend(items);
+      }
     }
+    length += 1;
   }
 }
 
@@ -380,6 +405,7 @@ pub fn get_long_chunk_name(
   context: &str,
   delimiter: &str,
   module_graph: &ModuleGraph,
+  output: &OutputOptions,
 ) -> String {
   let modules = chunk_graph
     .get_chunk_root_modules(&chunk.ukey, module_graph)
@@ -398,7 +424,7 @@ pub fn get_long_chunk_name(
 
   let long_module_names = modules
     .iter()
-    .map(|m| request_to_id(&get_long_module_name("", m, context)))
+    .map(|m| request_to_id(&get_long_module_name("", m, context, output)))
     .collect::<Vec<_>>();
   let mut id_name_hints = chunk.id_name_hints.iter().cloned().collect::<Vec<_>>();
   id_name_hints.sort_unstable();
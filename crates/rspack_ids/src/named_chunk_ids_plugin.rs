@@ -27,6 +27,7 @@ impl Plugin for NamedChunkIdsPlugin {
     let mut used_ids = get_used_chunk_ids(compilation);
     let chunk_graph = &compilation.chunk_graph;
     let module_graph = &compilation.module_graph;
+    let output = &compilation.options.output;
     let context = self
       .context
       .clone()
@@ -49,7 +50,16 @@ impl Plugin for NamedChunkIdsPlugin {
     let unnamed_chunks = assign_names_par(
       chunks,
       |chunk| get_short_chunk_name(chunk, chunk_graph, &context, &self.delimiter, module_graph),
-      |chunk, _| get_long_chunk_name(chunk, chunk_graph, &context, &self.delimiter, module_graph),
+      |chunk, _| {
+        get_long_chunk_name(
+          chunk,
+          chunk_graph,
+          &context,
+          &self.delimiter,
+          module_graph,
+          output,
+        )
+      },
       // TODO: we should do compareChunksNatural
       |a, b| a.name.cmp(&b.name),
       &mut used_ids,
@@ -17,6 +17,7 @@ impl Plugin for NamedModuleIdsPlugin {
   fn module_ids(&self, compilation: &mut rspack_core::Compilation) -> Result<()> {
     // Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/ids/NamedModuleIdsPlugin.js
     let context: &str = compilation.options.context.as_ref();
+    let output = &compilation.options.output;
     let (mut used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
     let modules = modules
       .into_iter()
@@ -27,7 +28,7 @@ impl Plugin for NamedModuleIdsPlugin {
     let unnamed_modules = assign_names_par(
       modules,
       |m| get_short_module_name(m, context),
-      |module, short_name| get_long_module_name(short_name, module, context),
+      |module, short_name| get_long_module_name(short_name, module, context, output),
       |a, b| compare_modules_by_identifier(a, b),
       &mut used_ids,
       |m, name| chunk_graph.set_module_id(m.identifier(), name),
@@ -1,26 +1,39 @@
 use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use regex::Regex;
 use rspack_core::{ChunkUkey, Plugin};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::id_helpers::{
-  assign_ascending_chunk_ids, get_short_module_name, request_to_id, shorten_long_string,
+  assign_ascending_chunk_ids, get_hash, get_short_module_name, shorten_long_string,
 };
 
 #[derive(Debug)]
 pub struct StableNamedChunkIdsPlugin {
   pub delimiter: String,
   pub context: Option<String>,
+  /// The prefixes removed from the paths of the root modules to name the code splitting chunks
+  pub strip_prefixes: Vec<String>,
 }
 
 impl StableNamedChunkIdsPlugin {
-  pub fn new(delimiter: Option<String>, context: Option<String>) -> Self {
+  pub fn new(
+    delimiter: Option<String>,
+    context: Option<String>,
+    strip_prefixes: Option<Vec<String>>,
+  ) -> Self {
     Self {
       delimiter: delimiter.unwrap_or_else(|| "~".to_string()),
       context,
+      strip_prefixes: strip_prefixes.unwrap_or_else(default_strip_prefixes),
     }
   }
 }
 
+fn default_strip_prefixes() -> Vec<String> {
+  vec!["src/".to_string(), "node_modules/".to_string()]
+}
+
 impl Plugin for StableNamedChunkIdsPlugin {
   fn chunk_ids(&self, compilation: &mut rspack_core::Compilation) -> rspack_error::Result<()> {
     use rayon::prelude::*;
@@ -37,7 +50,8 @@ impl Plugin for StableNamedChunkIdsPlugin {
       .clone()
       .unwrap_or_else(|| compilation.options.context.to_string());
 
-    let mut used_code_splitting_chunk_name: FxHashSet<String> = FxHashSet::default();
+    let mut code_splitting_chunks_by_name: FxHashMap<String, Vec<(ChunkUkey, String)>> =
+      FxHashMap::default();
 
     let chunks_to_be_named = compilation
       .chunk_by_ukey
@@ -54,7 +68,10 @@ impl Plugin for StableNamedChunkIdsPlugin {
             .module_graph
             .module_by_identifier(root_id)
             .expect("Module should exist");
-          let root_module_name = request_to_id(&get_short_module_name(root_module, &context));
+          let root_module_name = path_to_chunk_name(
+            &get_short_module_name(root_module, &context),
+            &self.strip_prefixes,
+          );
 
           let name = chunk
             .id_name_hints
@@ -64,18 +81,11 @@ impl Plugin for StableNamedChunkIdsPlugin {
             .intersperse(self.delimiter.as_str())
             .collect::<String>();
 
-          if used_code_splitting_chunk_name.contains(&name) {
-            // This is not likely to happen, any module could not be the root of multiple chunks
-            panic!(
-              "Duplicate root module name {}",
-              chunk.chunk_reasons.join("_")
-            )
-          } else {
-            used_code_splitting_chunk_name.insert(name.clone());
-          }
-
-          chunk.id = Some(name.clone());
-          chunk.ids = vec![name];
+          code_splitting_chunks_by_name
+            .entry(name)
+            .or_default()
+            .push((chunk.ukey, root_id.to_string()));
+          return None;
         }
 
         if chunk.id.is_none() {
@@ -86,6 +96,12 @@ impl Plugin for StableNamedChunkIdsPlugin {
       })
       .collect::<Vec<_>>();
 
+    for (chunk, name) in disambiguate_names(code_splitting_chunks_by_name) {
+      let chunk = chunk.as_mut(&mut compilation.chunk_by_ukey);
+      chunk.id = Some(name.clone());
+      chunk.ids = vec![name];
+    }
+
     let name_to_chunks: DashMap<String, FxHashSet<ChunkUkey>> = Default::default();
 
     let chunks_has_no_name = chunks_to_be_named
@@ -181,3 +197,102 @@ impl Plugin for StableNamedChunkIdsPlugin {
     Ok(())
   }
 }
+
+static DRIVE_LETTER: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-z]:/").expect("Invalid regex"));
+static NON_ALPHANUMERIC: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"[^a-z0-9]+").expect("Invalid regex"));
+
+/// Name a code splitting chunk by the path of its root module relative to the context, e.g.
+/// `./src/pages/Home.js` is named `pages-home-js`. The path is lowercased, separated by `-`,
+/// and the first matching prefix of `strip_prefixes` is removed.
+fn path_to_chunk_name(path: &str, strip_prefixes: &[String]) -> String {
+  let path = path.replace('\\', "/").to_lowercase();
+  let path = DRIVE_LETTER.replace(&path, "");
+  let mut path = &*path;
+  while let Some(rest) = path.strip_prefix("./").or_else(|| path.strip_prefix("../")) {
+    path = rest;
+  }
+  if let Some(rest) = strip_prefixes
+    .iter()
+    .find_map(|prefix| path.strip_prefix(prefix.as_str()))
+  {
+    path = rest;
+  }
+  NON_ALPHANUMERIC
+    .replace_all(path, "-")
+    .trim_matches('-')
+    .to_string()
+}
+
+/// Root modules of different paths may have the same name, e.g. both `src/a/b.js` and `src/a_b.js`
+/// are named `a-b-js`, a short hash of the root module identifier is appended to each of them,
+/// so the names don't depend on the order of the chunks.
+fn disambiguate_names<T>(names: FxHashMap<String, Vec<(T, String)>>) -> Vec<(T, String)> {
+  names
+    .into_iter()
+    .flat_map(|(name, items)| {
+      let is_unique = items.len() == 1;
+      items.into_iter().map(move |(item, identifier)| {
+        if is_unique {
+          (item, name.clone())
+        } else {
+          (item, format!("{name}-{}", get_hash(&identifier, 4)))
+        }
+      })
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn names_of(requests: &[&str]) -> Vec<String> {
+    let mut names: FxHashMap<String, Vec<(usize, String)>> = FxHashMap::default();
+    for (index, request) in requests.iter().enumerate() {
+      names
+        .entry(path_to_chunk_name(request, &default_strip_prefixes()))
+        .or_default()
+        .push((index, request.to_string()));
+    }
+    let mut names = disambiguate_names(names);
+    names.sort_by_key(|(index, _)| *index);
+    names.into_iter().map(|(_, name)| name).collect()
+  }
+
+  #[test]
+  fn should_name_chunks_by_paths() {
+    assert_eq!(
+      names_of(&[
+        "./src/pages/Home.js",
+        "./node_modules/lodash/index.js",
+        "../shared/a.js"
+      ]),
+      vec!["pages-home-js", "lodash-index-js", "shared-a-js"]
+    );
+  }
+
+  #[test]
+  fn should_disambiguate_colliding_names() {
+    let names = names_of(&["./src/a/b.js", "./src/a_b.js", "./src/c.js"]);
+    assert!(names[0].starts_with("a-b-js-"));
+    assert!(names[1].starts_with("a-b-js-"));
+    assert_ne!(names[0], names[1]);
+    assert_eq!(names[2], "c-js");
+    assert_eq!(
+      names,
+      names_of(&["./src/a/b.js", "./src/a_b.js", "./src/c.js"])
+    );
+  }
+
+  #[test]
+  fn should_name_windows_style_paths() {
+    assert_eq!(
+      names_of(&[".\\src\\pages\\Home.js", "C:\\project\\src\\a.js"]),
+      vec!["pages-home-js", "project-src-a-js"]
+    );
+    let names = names_of(&[".\\src\\a\\b.js", ".\\src\\a_b.js"]);
+    assert_ne!(names[0], names[1]);
+    assert!(names.iter().all(|name| name.starts_with("a-b-js-")));
+  }
+}
@@ -10,7 +10,10 @@ pub use content::Content;
 pub use loader::{DisplayWithSuffix, Loader};
 pub use plugin::LoaderRunnerPlugin;
 pub use rspack_identifier::{Identifiable, Identifier};
-pub use runner::{run_loaders, DescriptionData, LoaderContext, ResourceData};
+pub use runner::{
+  run_loaders, run_loaders_with_profile, BuildInfoExtras, DescriptionData, LoaderContext,
+  LoaderError, ResourceData,
+};
 pub use scheme::{get_scheme, Scheme};
 
 pub const BUILTIN_LOADER_PREFIX: &str = "builtin:";
@@ -78,6 +78,11 @@ impl<C> LoaderItem<C> {
   pub fn __do_not_use_or_you_will_be_fired_set_normal_executed(&self) {
     self.set_normal_executed()
   }
+
+  /// Options of the underlying loader, see [Loader::options].
+  pub fn options(&self) -> Option<&str> {
+    self.loader.options()
+  }
 }
 
 bitflags::bitflags! {
@@ -238,6 +243,15 @@ pub trait Loader<C>: Identifiable + Send + Sync {
     // noop
     Ok(())
   }
+  /// Raw options of the loader, JSON serialized if the options are not a string.
+  /// Composed loaders should return `None` and carry the options of each loader by themselves.
+  fn options(&self) -> Option<&str> {
+    None
+  }
+  /// Whether the loader is composed of the loaders of a module, e.g. to be run by another runner.
+  fn is_composed(&self) -> bool {
+    false
+  }
 }
 
 impl<C> From<Arc<dyn Loader<C>>> for LoaderItem<C> {
@@ -1,7 +1,10 @@
 use std::{
+  collections::BTreeSet,
   fmt::Debug,
+  hash::{Hash, Hasher},
   path::{Path, PathBuf},
   sync::Arc,
+  time::{Duration, Instant},
 };
 
 use derivative::Derivative;
@@ -9,8 +12,8 @@ use once_cell::sync::OnceCell;
 use rspack_error::{
   internal_error, Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray,
 };
-use rspack_sources::SourceMap;
-use rustc_hash::FxHashSet as HashSet;
+use rspack_sources::{MapOptions, Source, SourceMap, SourceMapSource, SourceMapSourceOptions};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use crate::{
   content::Content,
@@ -134,6 +137,32 @@ impl DescriptionData {
   }
 }
 
+/// Open key-value store that loaders use to pass metadata to plugins, kept in the build info of
+/// the module.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BuildInfoExtras {
+  pub values: serde_json::Map<String, serde_json::Value>,
+  /// Keys of the values included in the module hash, other values never invalidate caches.
+  pub affects_hash: BTreeSet<String>,
+}
+
+impl BuildInfoExtras {
+  pub fn is_empty(&self) -> bool {
+    self.values.is_empty()
+  }
+}
+
+impl Hash for BuildInfoExtras {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    for key in &self.affects_hash {
+      if let Some(value) = self.values.get(key) {
+        key.hash(state);
+        value.to_string().hash(state);
+      }
+    }
+  }
+}
+
 #[derive(Derivative)]
 #[derivative(Debug)]
 pub struct LoaderContext<'c, C> {
@@ -156,6 +185,8 @@ pub struct LoaderContext<'c, C> {
   pub resource_fragment: Option<&'c str>,
 
   pub context: C,
+  /// The source map produced by the loader, the source map of its input is available with
+  /// [LoaderContext::take_source_map] at the normal stage.
   pub source_map: Option<SourceMap>,
   pub additional_data: Option<String>,
   pub cacheable: bool,
@@ -166,12 +197,19 @@ pub struct LoaderContext<'c, C> {
   pub build_dependencies: HashSet<PathBuf>,
 
   pub asset_filenames: HashSet<String>,
+  pub build_info_extras: BuildInfoExtras,
 
   pub __loader_index: usize,
   pub __loader_items: LoaderItemList<'c, C>,
   #[derivative(Debug = "ignore")]
   pub __plugins: &'c [Box<dyn LoaderRunnerPlugin>],
   pub __resource_data: &'c ResourceData,
+  /// Data shared between the pitching and normal stage of each loader, keyed by loader index.
+  pub __pitch_data: HashMap<usize, Vec<u8>>,
+  /// Time spent in each loader, keyed by loader index. Only collected when profiling.
+  pub __loader_durations: Option<Vec<Duration>>,
+  /// The source map of the input of the running loader at the normal stage.
+  pub __input_source_map: Option<SourceMap>,
 
   pub diagnostics: Vec<Diagnostic>,
 }
@@ -192,6 +230,13 @@ impl<'c, C> LoaderContext<'c, C> {
     LoaderItemList(&self.__loader_items[..self.__loader_index])
   }
 
+  /// Take the source map of the input at the normal stage.
+  /// The loader taking it is responsible for chaining it with the source map it produces,
+  /// otherwise the runner chains them once the loader is finished.
+  pub fn take_source_map(&mut self) -> Option<SourceMap> {
+    self.__input_source_map.take()
+  }
+
   pub fn request(&self) -> LoaderItemList<'_, C> {
     LoaderItemList(&self.__loader_items[..])
   }
@@ -203,6 +248,37 @@ impl<'c, C> LoaderContext<'c, C> {
   pub fn loader_index(&self) -> usize {
     self.__loader_index
   }
+
+  /// Data stashed by the pitching stage of the current loader.
+  pub fn pitch_data(&self) -> Option<&[u8]> {
+    self
+      .__pitch_data
+      .get(&self.__loader_index)
+      .map(Vec::as_slice)
+  }
+
+  /// Stash data for the normal stage of the current loader.
+  pub fn set_pitch_data(&mut self, data: Option<Vec<u8>>) {
+    match data {
+      Some(data) => self.__pitch_data.insert(self.__loader_index, data),
+      None => self.__pitch_data.remove(&self.__loader_index),
+    };
+  }
+
+  fn start_loader_timing(&self) -> Option<(usize, Instant)> {
+    self
+      .__loader_durations
+      .is_some()
+      .then(|| (self.__loader_index, Instant::now()))
+  }
+
+  fn end_loader_timing(&mut self, timing: Option<(usize, Instant)>) {
+    if let Some((index, start)) = timing
+      && let Some(durations) = &mut self.__loader_durations
+    {
+      durations[index] += start.elapsed();
+    }
+  }
 }
 
 async fn process_resource<C: Send>(loader_context: &mut LoaderContext<'_, C>) -> Result<()> {
@@ -242,6 +318,7 @@ async fn create_loader_context<'c, C: 'c>(
   resource_data: &'c ResourceData,
   plugins: &'c [Box<dyn LoaderRunnerPlugin>],
   context: C,
+  profile: bool,
 ) -> Result<LoaderContext<'c, C>> {
   let mut file_dependencies: HashSet<PathBuf> = Default::default();
   if resource_data.resource_path.is_absolute() {
@@ -255,6 +332,7 @@ async fn create_loader_context<'c, C: 'c>(
     missing_dependencies: Default::default(),
     build_dependencies: Default::default(),
     asset_filenames: Default::default(),
+    build_info_extras: Default::default(),
     content: None,
     resource: &resource_data.resource,
     resource_path: &resource_data.resource_path,
@@ -267,6 +345,9 @@ async fn create_loader_context<'c, C: 'c>(
     __loader_items: LoaderItemList(__loader_items),
     __plugins: plugins,
     __resource_data: resource_data,
+    __pitch_data: Default::default(),
+    __loader_durations: profile.then(|| vec![Duration::ZERO; __loader_items.len()]),
+    __input_source_map: None,
     diagnostics: vec![],
   };
 
@@ -287,11 +368,79 @@ async fn iterate_normal_loaders<C: Send>(loader_context: &mut LoaderContext<'_,
 
   let loader = current_loader_item.loader.clone();
   current_loader_item.set_normal_executed();
+  // The source map of the input is moved rather than cloned, it's only chained by the runner
+  // if the loader doesn't take it.
+  loader_context.__input_source_map = loader_context.source_map.take();
+  let timing = loader_context.start_loader_timing();
   loader.run(loader_context).await?;
+  loader_context.end_loader_timing(timing);
+
+  if let Some(input_map) = loader_context.__input_source_map.take() {
+    loader_context.source_map = match loader_context.source_map.take() {
+      Some(source_map) => Some(chain_source_map(
+        loader_context.resource,
+        loader_context.resource_path,
+        input_map,
+        loader_context
+          .content
+          .as_ref()
+          .map(|content| String::from_utf8_lossy(content.as_bytes()).into_owned())
+          .unwrap_or_default(),
+        source_map,
+      )),
+      // Keep the source map of the previous loader, a loader without source map support
+      // shouldn't wipe out the mappings to the original source.
+      None => Some(input_map),
+    };
+  }
 
   iterate_normal_loaders(loader_context).await
 }
 
+/// Compose the source map produced by a loader with the source map of its input,
+/// so the result maps back to the original source instead of the intermediate code.
+/// The intermediate code is the source of the output map named after the resource,
+/// the output map is kept as is if there isn't one.
+fn chain_source_map(
+  resource: &str,
+  resource_path: &Path,
+  input_map: SourceMap,
+  output: String,
+  output_map: SourceMap,
+) -> SourceMap {
+  // Loaders consuming the input source map (e.g. babel-loader) have chained it already.
+  if !output_map.sources_content().is_empty()
+    && output_map.sources_content() == input_map.sources_content()
+  {
+    return output_map;
+  }
+
+  let Some(name) = output_map
+    .sources()
+    .iter()
+    .find(|source| {
+      !source.is_empty()
+        && (*source == resource
+          || Path::new(&**source) == resource_path
+          || resource_path.ends_with(&**source))
+    })
+    .map(|source| source.to_string())
+  else {
+    return output_map;
+  };
+
+  SourceMapSource::new(SourceMapSourceOptions {
+    value: output,
+    name,
+    source_map: output_map.clone(),
+    original_source: None,
+    inner_source_map: Some(input_map),
+    remove_original_source: true,
+  })
+  .map(&MapOptions::default())
+  .unwrap_or(output_map)
+}
+
 #[async_recursion::async_recursion]
 async fn iterate_pitching_loaders<C: Send>(
   loader_context: &mut LoaderContext<'_, C>,
@@ -309,7 +458,9 @@ async fn iterate_pitching_loaders<C: Send>(
 
   let loader = current_loader_item.loader.clone();
   current_loader_item.set_pitch_executed();
+  let timing = loader_context.start_loader_timing();
   loader.pitch(loader_context).await?;
+  loader_context.end_loader_timing(timing);
 
   let current_loader_item = loader_context.current_loader();
 
@@ -339,9 +490,13 @@ pub struct LoaderResult {
   pub missing_dependencies: HashSet<PathBuf>,
   pub build_dependencies: HashSet<PathBuf>,
   pub asset_filenames: HashSet<String>,
+  pub build_info_extras: BuildInfoExtras,
   pub content: Content,
   pub source_map: Option<SourceMap>,
   pub additional_data: Option<String>,
+  /// Time spent in the pitching and normal stage of each loader, in the order of loaders.
+  /// Only available with [run_loaders_with_profile].
+  pub loader_durations: Option<Vec<Duration>>,
 }
 
 impl<C> TryFrom<LoaderContext<'_, C>> for TWithDiagnosticArray<LoaderResult> {
@@ -364,45 +519,106 @@ impl<C> TryFrom<LoaderContext<'_, C>> for TWithDiagnosticArray<LoaderResult> {
         missing_dependencies: loader_context.missing_dependencies,
         build_dependencies: loader_context.build_dependencies,
         asset_filenames: loader_context.asset_filenames,
+        build_info_extras: loader_context.build_info_extras,
         content,
         source_map: loader_context.source_map,
         additional_data: loader_context.additional_data,
+        loader_durations: loader_context.__loader_durations,
       }
       .with_diagnostic(loader_context.diagnostics),
     )
   }
 }
 
+/// The error of a loader failed midway, with the dependencies recorded by the loaders before the failure,
+/// so the module is rebuilt once the failure is fixed in watch mode.
+#[derive(Debug)]
+pub struct LoaderError {
+  pub error: rspack_error::Error,
+  pub cacheable: bool,
+  pub file_dependencies: HashSet<PathBuf>,
+  pub context_dependencies: HashSet<PathBuf>,
+  pub missing_dependencies: HashSet<PathBuf>,
+  pub build_dependencies: HashSet<PathBuf>,
+  /// Diagnostics emitted by the loaders before the failure.
+  pub diagnostics: Vec<Diagnostic>,
+}
+
+impl LoaderError {
+  fn new<C>(error: rspack_error::Error, loader_context: LoaderContext<'_, C>) -> Self {
+    Self {
+      error,
+      cacheable: loader_context.cacheable,
+      file_dependencies: loader_context.file_dependencies,
+      context_dependencies: loader_context.context_dependencies,
+      missing_dependencies: loader_context.missing_dependencies,
+      build_dependencies: loader_context.build_dependencies,
+      diagnostics: loader_context.diagnostics,
+    }
+  }
+}
+
+impl From<rspack_error::Error> for LoaderError {
+  fn from(error: rspack_error::Error) -> Self {
+    Self {
+      error,
+      cacheable: true,
+      file_dependencies: Default::default(),
+      context_dependencies: Default::default(),
+      missing_dependencies: Default::default(),
+      build_dependencies: Default::default(),
+      diagnostics: vec![],
+    }
+  }
+}
+
 pub async fn run_loaders<C: Send>(
   loaders: &[Arc<dyn Loader<C>>],
   resource_data: &ResourceData,
   plugins: &[Box<dyn LoaderRunnerPlugin>],
   context: C,
 ) -> Result<TWithDiagnosticArray<LoaderResult>> {
+  run_loaders_with_profile(loaders, resource_data, plugins, context, false)
+    .await
+    .map_err(|e| e.error)
+}
+
+/// Same as [run_loaders], the time spent in each loader is collected as well if `profile` is `true`,
+/// and the dependencies recorded before a failure are kept in [LoaderError].
+pub async fn run_loaders_with_profile<C: Send>(
+  loaders: &[Arc<dyn Loader<C>>],
+  resource_data: &ResourceData,
+  plugins: &[Box<dyn LoaderRunnerPlugin>],
+  context: C,
+  profile: bool,
+) -> std::result::Result<TWithDiagnosticArray<LoaderResult>, LoaderError> {
   let loaders = loaders
     .iter()
     .map(|i| i.clone().into())
     .collect::<Vec<LoaderItem<C>>>();
 
   let mut loader_context =
-    create_loader_context(&loaders[..], resource_data, plugins, context).await?;
+    create_loader_context(&loaders[..], resource_data, plugins, context, profile).await?;
 
   assert!(loader_context.content.is_none());
-  iterate_pitching_loaders(&mut loader_context).await?;
+  if let Err(error) = iterate_pitching_loaders(&mut loader_context).await {
+    return Err(LoaderError::new(error, loader_context));
+  }
 
-  loader_context.try_into()
+  loader_context.try_into().map_err(LoaderError::from)
 }
 
 #[cfg(test)]
 #[allow(unused)]
 mod test {
-  use std::{cell::RefCell, sync::Arc};
+  use std::{cell::RefCell, path::PathBuf, sync::Arc};
 
   use once_cell::sync::OnceCell;
   use rspack_error::Result;
   use rspack_identifier::{Identifiable, Identifier};
+  use rspack_sources::SourceMap;
 
-  use super::{run_loaders, Loader, LoaderContext, ResourceData};
+  use super::{run_loaders, run_loaders_with_profile, Loader, LoaderContext, ResourceData};
   use crate::{
     content::Content,
     loader::test::{Composed, Custom, Custom2},
@@ -678,4 +894,291 @@ mod test {
       assert_eq!(i.borrow()[1], "pitch-normal-pitch".to_string());
     });
   }
+
+  #[tokio::test]
+  async fn should_keep_pitch_data_for_each_loader() {
+    thread_local! {
+      static DATA: RefCell<Vec<Option<Vec<u8>>>> = RefCell::default();
+    }
+
+    struct PitchData(&'static str);
+
+    impl Identifiable for PitchData {
+      fn identifier(&self) -> Identifier {
+        format!("/rspack/pitch-data-loader-{}", self.0).into()
+      }
+    }
+
+    #[async_trait::async_trait]
+    impl Loader<()> for PitchData {
+      async fn pitch(&self, loader_context: &mut LoaderContext<'_, ()>) -> Result<()> {
+        loader_context.set_pitch_data(Some(self.0.as_bytes().to_vec()));
+        Ok(())
+      }
+
+      async fn run(&self, loader_context: &mut LoaderContext<'_, ()>) -> Result<()> {
+        DATA.with(|d| {
+          d.borrow_mut()
+            .push(loader_context.pitch_data().map(<[u8]>::to_vec))
+        });
+        Ok(())
+      }
+    }
+
+    struct Normal;
+
+    impl Identifiable for Normal {
+      fn identifier(&self) -> Identifier {
+        "/rspack/normal-loader".into()
+      }
+    }
+
+    #[async_trait::async_trait]
+    impl Loader<()> for Normal {
+      async fn run(&self, loader_context: &mut LoaderContext<'_, ()>) -> Result<()> {
+        DATA.with(|d| {
+          d.borrow_mut()
+            .push(loader_context.pitch_data().map(<[u8]>::to_vec))
+        });
+        Ok(())
+      }
+    }
+
+    let p1 = Arc::new(PitchData("a")) as Arc<dyn Loader<()>>;
+    let c1 = Arc::new(Normal) as Arc<dyn Loader<()>>;
+    let p2 = Arc::new(PitchData("b")) as Arc<dyn Loader<()>>;
+
+    let rs = ResourceData {
+      scheme: OnceCell::new(),
+      resource: "/rspack/main.js".to_owned(),
+      resource_description: None,
+      resource_fragment: None,
+      resource_query: None,
+      resource_path: Default::default(),
+      mimetype: None,
+      parameters: None,
+      encoding: None,
+      encoded_content: None,
+    };
+
+    run_loaders::<()>(&[p1, c1, p2], &rs, &[Box::new(TestContentPlugin)], ())
+      .await
+      .unwrap();
+    DATA.with(|d| {
+      assert_eq!(
+        *d.borrow(),
+        vec![Some(b"b".to_vec()), None, Some(b"a".to_vec())]
+      );
+    });
+  }
+
+  #[tokio::test]
+  async fn should_chain_source_maps() {
+    struct Transpile;
+
+    impl Identifiable for Transpile {
+      fn identifier(&self) -> Identifier {
+        "/rspack/transpile-loader".into()
+      }
+    }
+
+    #[async_trait::async_trait]
+    impl Loader<()> for Transpile {
+      async fn run(&self, loader_context: &mut LoaderContext<'_, ()>) -> Result<()> {
+        loader_context.content = Some(Content::String("var a = 1;".to_string()));
+        loader_context.source_map = Some(
+          SourceMap::from_json(
+            r#"{"version":3,"sources":["src/index.ts"],"sourcesContent":["const a: number = 1;"],"names":[],"mappings":"AAAA"}"#,
+          )
+          .unwrap(),
+        );
+        Ok(())
+      }
+    }
+
+    struct Wrap;
+
+    impl Identifiable for Wrap {
+      fn identifier(&self) -> Identifier {
+        "/rspack/wrap-loader".into()
+      }
+    }
+
+    #[async_trait::async_trait]
+    impl Loader<()> for Wrap {
+      async fn run(&self, loader_context: &mut LoaderContext<'_, ()>) -> Result<()> {
+        loader_context.content = Some(Content::String("\nvar a = 1;".to_string()));
+        loader_context.source_map = Some(
+          SourceMap::from_json(
+            r#"{"version":3,"sources":["main.js"],"sourcesContent":["var a = 1;"],"names":[],"mappings":";AAAA"}"#,
+          )
+          .unwrap(),
+        );
+        Ok(())
+      }
+    }
+
+    struct Unrelated;
+
+    impl Identifiable for Unrelated {
+      fn identifier(&self) -> Identifier {
+        "/rspack/unrelated-loader".into()
+      }
+    }
+
+    #[async_trait::async_trait]
+    impl Loader<()> for Unrelated {
+      async fn run(&self, loader_context: &mut LoaderContext<'_, ()>) -> Result<()> {
+        loader_context.source_map = Some(
+          SourceMap::from_json(
+            r#"{"version":3,"sources":["other.js"],"sourcesContent":["var b = 1;"],"names":[],"mappings":"AAAA"}"#,
+          )
+          .unwrap(),
+        );
+        Ok(())
+      }
+    }
+
+    struct WithoutSourceMap;
+
+    impl Identifiable for WithoutSourceMap {
+      fn identifier(&self) -> Identifier {
+        "/rspack/without-source-map-loader".into()
+      }
+    }
+
+    #[async_trait::async_trait]
+    impl Loader<()> for WithoutSourceMap {
+      async fn run(&self, loader_context: &mut LoaderContext<'_, ()>) -> Result<()> {
+        loader_context.source_map = None;
+        Ok(())
+      }
+    }
+
+    let rs = ResourceData {
+      scheme: OnceCell::new(),
+      resource: "/rspack/main.js".to_owned(),
+      resource_description: None,
+      resource_fragment: None,
+      resource_query: None,
+      resource_path: PathBuf::from("/rspack/main.js"),
+      mimetype: None,
+      parameters: None,
+      encoding: None,
+      encoded_content: None,
+    };
+
+    let result = run_loaders::<()>(
+      &[
+        Arc::new(WithoutSourceMap) as Arc<dyn Loader<()>>,
+        Arc::new(Wrap) as Arc<dyn Loader<()>>,
+        Arc::new(Transpile) as Arc<dyn Loader<()>>,
+      ],
+      &rs,
+      &[Box::new(TestContentPlugin)],
+      (),
+    )
+    .await
+    .unwrap();
+    let (result, _) = result.split_into_parts();
+    let source_map = result
+      .source_map
+      .expect("should keep the chained source map");
+    assert_eq!(source_map.sources(), ["src/index.ts"]);
+    assert_eq!(source_map.sources_content(), ["const a: number = 1;"]);
+    assert_eq!(source_map.mappings(), ";AAAA");
+
+    // The source map without a source of the resource isn't chained
+    let result = run_loaders::<()>(
+      &[
+        Arc::new(Unrelated) as Arc<dyn Loader<()>>,
+        Arc::new(Transpile) as Arc<dyn Loader<()>>,
+      ],
+      &rs,
+      &[Box::new(TestContentPlugin)],
+      (),
+    )
+    .await
+    .unwrap();
+    let (result, _) = result.split_into_parts();
+    let source_map = result.source_map.expect("should keep the source map");
+    assert_eq!(source_map.sources(), ["other.js"]);
+  }
+
+  #[tokio::test]
+  async fn should_keep_the_dependencies_of_failed_loaders() {
+    struct Failing;
+
+    impl Identifiable for Failing {
+      fn identifier(&self) -> Identifier {
+        "/rspack/failing-loader".into()
+      }
+    }
+
+    #[async_trait::async_trait]
+    impl Loader<()> for Failing {
+      async fn run(&self, loader_context: &mut LoaderContext<'_, ()>) -> Result<()> {
+        loader_context
+          .file_dependencies
+          .insert("/rspack/imported.css".into());
+        loader_context.cacheable = false;
+        Err(rspack_error::internal_error!("Failed to load"))
+      }
+    }
+
+    let rs = ResourceData {
+      scheme: OnceCell::new(),
+      resource: "/rspack/main.css".to_owned(),
+      resource_description: None,
+      resource_fragment: None,
+      resource_query: None,
+      resource_path: Default::default(),
+      mimetype: None,
+      parameters: None,
+      encoding: None,
+      encoded_content: None,
+    };
+
+    let failing = Arc::new(Failing) as Arc<dyn Loader<()>>;
+    let error =
+      run_loaders_with_profile(&[failing], &rs, &[Box::new(TestContentPlugin)], (), false)
+        .await
+        .expect_err("should fail");
+    assert!(error.error.to_string().contains("Failed to load"));
+    assert!(!error.cacheable);
+    assert!(error
+      .file_dependencies
+      .contains(std::path::Path::new("/rspack/imported.css")));
+  }
+
+  #[test]
+  fn build_info_extras_hash() {
+    use std::hash::{Hash, Hasher};
+
+    use super::BuildInfoExtras;
+
+    fn digest(extras: &BuildInfoExtras) -> u64 {
+      let mut hasher = std::collections::hash_map::DefaultHasher::new();
+      extras.hash(&mut hasher);
+      hasher.finish()
+    }
+
+    let mut extras = BuildInfoExtras::default();
+    extras
+      .values
+      .insert("descriptor".into(), serde_json::json!({ "scoped": true }));
+    let base = digest(&extras);
+
+    extras.values.insert("timestamp".into(), 1.into());
+    assert_eq!(digest(&extras), base);
+
+    extras.affects_hash.insert("descriptor".into());
+    let affected = digest(&extras);
+    assert_ne!(affected, base);
+
+    extras
+      .values
+      .insert("descriptor".into(), serde_json::json!({ "scoped": false }));
+    assert_ne!(digest(&extras), affected);
+  }
 }
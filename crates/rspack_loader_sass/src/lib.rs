@@ -368,13 +368,25 @@ impl Logger for RspackLogger {
 #[derive(Debug)]
 pub struct SassLoader {
   options: SassLoaderOptions,
+  identifier: Identifier,
 }
 
 pub const SASS_LOADER_IDENTIFIER: &str = "builtin:sass-loader";
 
 impl SassLoader {
-  pub fn new(options: SassLoaderOptions) -> Self {
-    Self { options }
+  /// The `identifier` is the loader request, which distinguishes the loaders with different options,
+  /// e.g. `builtin:sass-loader??ruleSet[1].rules[0].use[0]`.
+  ///
+  /// Panics:
+  /// Panics if `identifier` passed in is not starting with `builtin:sass-loader`.
+  pub fn new(options: SassLoaderOptions, identifier: Option<Identifier>) -> Self {
+    if let Some(identifier) = &identifier {
+      assert!(identifier.starts_with(SASS_LOADER_IDENTIFIER));
+    }
+    Self {
+      options,
+      identifier: identifier.unwrap_or(SASS_LOADER_IDENTIFIER.into()),
+    }
   }
 
   fn get_sass_options(
@@ -515,7 +527,7 @@ impl Loader<LoaderRunnerContext> for SassLoader {
 
 impl Identifiable for SassLoader {
   fn identifier(&self) -> Identifier {
-    "builtin:sass-loader".into()
+    self.identifier
   }
 }
 
@@ -21,8 +21,10 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
 
   let url = Url::from_file_path(actual_path.to_string_lossy().to_string()).expect("TODO:");
   let (result, _) = run_loaders(
-    &[Arc::new(SassLoader::new(SassLoaderOptions::default()))
-      as Arc<dyn Loader<LoaderRunnerContext>>],
+    &[
+      Arc::new(SassLoader::new(SassLoaderOptions::default(), None))
+        as Arc<dyn Loader<LoaderRunnerContext>>,
+    ],
     &ResourceData::new(
       actual_path.to_string_lossy().to_string(),
       url.to_file_path().expect("bad url file path"),
@@ -37,7 +39,7 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
         devtool: rspack_core::Devtool::default(),
         mode: rspack_core::Mode::None,
         output: rspack_core::OutputOptions {
-          clean: false,
+          clean: Default::default(),
           path: Default::default(),
           public_path: Default::default(),
           filename: rspack_core::Filename::from_str("").expect("TODO:"),
@@ -49,15 +51,18 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
           unique_name: Default::default(),
           chunk_loading: rspack_core::ChunkLoading::Enable(rspack_core::ChunkLoadingType::Jsonp),
           chunk_loading_global: "webpackChunkwebpack".to_string(),
+          hot_update_global: "webpackHotUpdatewebpack".to_string(),
           css_chunk_filename: rspack_core::Filename::from_str("").expect("TODO:"),
           css_filename: rspack_core::Filename::from_str("").expect("TODO:"),
           hot_update_chunk_filename: rspack_core::Filename::from_str("").expect("Should exist"),
           hot_update_main_filename: rspack_core::Filename::from_str("").expect("Should exist"),
           library: None,
           enabled_library_types: None,
+          enabled_chunk_loading_types: None,
           strict_module_error_handling: false,
           global_object: "self".to_string(),
           import_function_name: "import".to_string(),
+          import_meta_name: "import.meta".to_string(),
           iife: true,
           module: false,
           trusted_types: None,
@@ -72,6 +77,7 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
           ),
           worker_wasm_loading: rspack_core::WasmLoading::Disable,
           worker_public_path: String::new(),
+          environment: Default::default(),
         },
         target: rspack_core::Target::new(&vec![String::from("web")]).expect("TODO:"),
         resolve: rspack_core::Resolve::default(),
@@ -83,14 +89,21 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
         snapshot: Default::default(),
         experiments: Default::default(),
         node: Default::default(),
+        amd: true,
+        require_cache: true,
         optimization: rspack_core::Optimization {
           remove_available_modules: false,
           remove_empty_chunks: true,
           side_effects: SideEffectOption::False,
+          runtime_chunk: None,
+          concatenate_modules: false,
         },
         profile: false,
+        ignore_warnings: vec![],
+        infrastructure_logging: Default::default(),
       }),
       resolver_factory: Default::default(),
+      module_settings: Default::default(),
     },
   )
   .await
@@ -1,24 +1,29 @@
 #![feature(let_chains)]
 
+use std::fmt;
 use std::hash::Hash;
+use std::sync::Arc;
 
 use async_trait::async_trait;
+use dashmap::DashMap;
 use rayon::prelude::*;
 use rspack_core::{
   rspack_sources::{BoxSource, RawSource, SourceExt},
   tree_shaking::{
     analyzer::OptimizeAnalyzer, asset_module::AssetModule, visitor::OptimizeAnalyzeResult,
   },
-  AssetGeneratorDataUrl, AssetParserDataUrl, AssetParserOptions, BuildMetaDefaultObject,
-  BuildMetaExportsType, CodeGenerationDataAssetInfo, CodeGenerationDataFilename,
-  CodeGenerationDataUrl, Compilation, CompilerOptions, GenerateContext, Module, NormalModule,
-  ParseContext, ParserAndGenerator, PathData, Plugin, PluginContext,
-  PluginRenderManifestHookOutput, RenderManifestArgs, RenderManifestEntry, ResourceData,
-  RuntimeGlobals, SourceType,
+  AssetGeneratorDataUrl, AssetGeneratorDataUrlFn, AssetGeneratorDataUrlFnArgs, AssetInfo,
+  AssetParserDataUrl, AssetParserOptions, BuildMetaDefaultObject, BuildMetaExportsType,
+  CodeGenerationDataAssetInfo, CodeGenerationDataContentHash, CodeGenerationDataFilename,
+  CodeGenerationDataUrl, Compilation, CompilerOptions, GenerateContext, Logger, Module,
+  ModuleIdentifier, NormalModule, ParseContext, ParserAndGenerator, PathData, Plugin,
+  PluginContext, PluginRenderManifestHookOutput, RenderManifestArgs, RenderManifestEntry,
+  ResourceData, RuntimeGlobals, SourceType,
 };
 use rspack_error::{internal_error, IntoTWithDiagnosticArray, Result};
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_util::identifier::make_paths_relative;
+use rustc_hash::FxHashMap as HashMap;
 
 #[derive(Debug)]
 pub struct AssetConfig {
@@ -28,11 +33,63 @@ pub struct AssetConfig {
 #[derive(Debug)]
 pub struct AssetPlugin {
   config: AssetConfig,
+  data_urls: DataUrlFnResults,
 }
 
 impl AssetPlugin {
   pub fn new(config: AssetConfig) -> AssetPlugin {
-    AssetPlugin { config }
+    AssetPlugin {
+      config,
+      data_urls: Default::default(),
+    }
+  }
+}
+
+/// The `dataUrl` functions of the inline assets, which are called asynchronously in
+/// `finishModules` once the assets are parsed, the results are taken by the generator.
+#[derive(Default, Clone)]
+struct DataUrlFnResults {
+  pending: Arc<DashMap<ModuleIdentifier, (AssetGeneratorDataUrlFn, AssetGeneratorDataUrlFnArgs)>>,
+  resolved: Arc<DashMap<ModuleIdentifier, std::result::Result<String, String>>>,
+}
+
+impl fmt::Debug for DataUrlFnResults {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("DataUrlFnResults")
+      .field("pending", &self.pending.len())
+      .field("resolved", &self.resolved.len())
+      .finish()
+  }
+}
+
+impl DataUrlFnResults {
+  fn get(&self, module_identifier: &ModuleIdentifier) -> Result<String> {
+    match self.resolved.get(module_identifier).as_deref() {
+      Some(Ok(data_url)) => Ok(data_url.clone()),
+      Some(Err(error)) => Err(internal_error!(error.clone())),
+      None => Err(internal_error!(
+        "The dataUrl function of {module_identifier} isn't called"
+      )),
+    }
+  }
+
+  async fn call_pending(&self) {
+    let pending = self
+      .pending
+      .iter()
+      .map(|entry| *entry.key())
+      .collect::<Vec<_>>();
+    let calls = pending
+      .into_iter()
+      .filter_map(|module_identifier| self.pending.remove(&module_identifier))
+      .map(|(module_identifier, (function, args))| async move {
+        (module_identifier, function(args).await)
+      });
+    for (module_identifier, result) in futures::future::join_all(calls).await {
+      self
+        .resolved
+        .insert(module_identifier, result.map_err(|error| error.to_string()));
+    }
   }
 }
 
@@ -79,47 +136,49 @@ impl CanonicalizedDataUrlOption {
 pub struct AssetParserAndGenerator {
   data_url: DataUrlOptions,
   parsed_asset_config: Option<CanonicalizedDataUrlOption>,
+  /// The data url of an inline asset, generated in the parse phase so the size is exact
+  inline_data_url: Option<String>,
+  /// The results of the `dataUrl` functions, which can't be called in the parse phase
+  data_url_fn_results: DataUrlFnResults,
 }
 
 impl AssetParserAndGenerator {
-  pub fn with_auto(option: Option<AssetParserDataUrl>) -> Self {
+  fn with_auto(option: Option<AssetParserDataUrl>, data_url_fn_results: DataUrlFnResults) -> Self {
     Self {
       data_url: DataUrlOptions::Auto(option),
       parsed_asset_config: None,
+      inline_data_url: None,
+      data_url_fn_results,
     }
   }
 
-  pub fn with_inline() -> Self {
+  fn with_inline(data_url_fn_results: DataUrlFnResults) -> Self {
     Self {
       data_url: DataUrlOptions::Inline(true),
       parsed_asset_config: None,
+      inline_data_url: None,
+      data_url_fn_results,
     }
   }
 
-  pub fn with_resource() -> Self {
+  fn with_resource(data_url_fn_results: DataUrlFnResults) -> Self {
     Self {
       data_url: DataUrlOptions::Inline(false),
       parsed_asset_config: None,
+      inline_data_url: None,
+      data_url_fn_results,
     }
   }
 
-  pub fn with_source() -> Self {
+  fn with_source(data_url_fn_results: DataUrlFnResults) -> Self {
     Self {
       data_url: DataUrlOptions::Source,
       parsed_asset_config: None,
+      inline_data_url: None,
+      data_url_fn_results,
     }
   }
 
-  fn hash_for_source(
-    &self,
-    source: &BoxSource,
-    compiler_options: &CompilerOptions,
-  ) -> RspackHashDigest {
-    let mut hasher = RspackHash::from(&compiler_options.output);
-    source.hash(&mut hasher);
-    hasher.digest(&compiler_options.output.hash_digest)
-  }
-
   fn get_mimetype(
     &self,
     resource_data: &ResourceData,
@@ -166,7 +225,8 @@ impl AssetParserAndGenerator {
       return Ok(encoded_content.to_owned());
     }
     if encoding.is_empty() {
-      // to_lossy_string
+      // Everything but the unreserved characters is percent-encoded, including `#`, `%` and
+      // quotes, so the data url is safe to be used in JavaScript strings and CSS `url()`.
       return Ok(urlencoding::encode_binary(&source.buffer()).into_owned());
     }
     if encoding == DEFAULT_ENCODING {
@@ -175,6 +235,29 @@ impl AssetParserAndGenerator {
     Err(internal_error!("Unsupported encoding {encoding}"))
   }
 
+  fn get_data_url(
+    &self,
+    module_identifier: &ModuleIdentifier,
+    resource_data: &ResourceData,
+    data_url: Option<&AssetGeneratorDataUrl>,
+    source: &BoxSource,
+  ) -> Result<String> {
+    if let Some(AssetGeneratorDataUrl::Func(_)) = data_url {
+      return self.data_url_fn_results.get(module_identifier);
+    }
+    let mimetype = self.get_mimetype(resource_data, data_url)?;
+    let encoding = self.get_encoding(resource_data, data_url);
+    let encoded_content = self.get_encoded_content(resource_data, &encoding, source)?;
+    Ok(format!(
+      r#"data:{mimetype}{},{encoded_content}"#,
+      if encoding.is_empty() {
+        String::new()
+      } else {
+        format!(";{encoding}")
+      }
+    ))
+  }
+
   fn get_source_file_name(&self, module: &NormalModule, compilation: &Compilation) -> String {
     let relative = make_paths_relative(
       compilation.options.context.as_ref(),
@@ -190,10 +273,20 @@ impl AssetParserAndGenerator {
   }
 }
 
+fn hash_for_source(source: &BoxSource, compiler_options: &CompilerOptions) -> RspackHashDigest {
+  let mut hasher = RspackHash::from(&compiler_options.output);
+  source.hash(&mut hasher);
+  hasher.digest(&compiler_options.output.hash_digest)
+}
+
 // Webpack's default parser.dataUrlCondition.maxSize
 const DEFAULT_MAX_SIZE: u32 = 8096;
 
 impl ParserAndGenerator for AssetParserAndGenerator {
+  fn name(&self) -> &'static str {
+    "asset"
+  }
+
   fn source_types(&self) -> &[SourceType] {
     if let Some(config) = self.parsed_asset_config.as_ref() {
       if config.is_source() || config.is_inline() {
@@ -222,14 +315,20 @@ impl ParserAndGenerator for AssetParserAndGenerator {
             CanonicalizedDataUrlOption::Source => original_source_size,
             CanonicalizedDataUrlOption::Asset(meta) => {
               match *meta {
-                ASSET_INLINE => {
-                  // copied from webpack's AssetGenerator
-                  // roughly for data url
-                  // Example: m.exports="data:image/png;base64,ag82/f+2=="
-                  // 4/3 = base64 encoding
-                  // 34 = ~ data url header + footer + rounding
-                  original_source_size * 1.34 + 36.0
-                }
+                ASSET_INLINE => self
+                  .inline_data_url
+                  .clone()
+                  .or_else(|| self.data_url_fn_results.get(&module.identifier()).ok())
+                  // module.exports = "<data url>";
+                  .map(|data_url| (r#"module.exports = "";"#.len() + data_url.len()) as f64)
+                  .unwrap_or_else(|| {
+                    // copied from webpack's AssetGenerator
+                    // roughly for data url
+                    // Example: m.exports="data:image/png;base64,ag82/f+2=="
+                    // 4/3 = base64 encoding
+                    // 34 = ~ data url header + footer + rounding
+                    original_source_size * 1.34 + 36.0
+                  }),
                 ASSET_RESOURCE => {
                   // copied from webpack's AssetGenerator
                   // roughly for url
@@ -258,6 +357,8 @@ impl ParserAndGenerator for AssetParserAndGenerator {
       module_type,
       compiler_options,
       module_identifier,
+      module_generator_options,
+      resource_data,
       ..
     } = parse_context;
     build_info.strict = true;
@@ -289,6 +390,31 @@ impl ParserAndGenerator for AssetParserAndGenerator {
         ))
       }
     };
+    self.inline_data_url = if self
+      .parsed_asset_config
+      .as_ref()
+      .map_or(false, |config| config.is_inline())
+    {
+      let data_url = module_generator_options.and_then(|x| x.asset_data_url(module_type));
+      if let Some(AssetGeneratorDataUrl::Func(function)) = data_url {
+        self.data_url_fn_results.resolved.remove(&module_identifier);
+        self.data_url_fn_results.pending.insert(
+          module_identifier,
+          (
+            function.clone(),
+            AssetGeneratorDataUrlFnArgs {
+              filename: resource_data.resource_path.to_string_lossy().to_string(),
+              content: source.buffer().to_vec(),
+            },
+          ),
+        );
+        None
+      } else {
+        Some(self.get_data_url(&module_identifier, resource_data, data_url, &source)?)
+      }
+    } else {
+      None
+    };
     let analyze_result = if compiler_options.builtins.tree_shaking.enable() {
       AssetModule::new(module_identifier).analyze()
     } else {
@@ -328,21 +454,17 @@ impl ParserAndGenerator for AssetParserAndGenerator {
     let result = match generate_context.requested_source_type {
       SourceType::JavaScript => {
         let exported_content = if parsed_asset_config.is_inline() {
-          let resource_data = normal_module.resource_resolved_data();
-          let data_url = generate_context
-            .module_generator_options
-            .and_then(|x| x.asset_data_url(module_type));
-          let mimetype = self.get_mimetype(resource_data, data_url)?;
-          let encoding = self.get_encoding(resource_data, data_url);
-          let encoded_content = self.get_encoded_content(resource_data, &encoding, source)?;
-          let encoded_source = format!(
-            r#"data:{mimetype}{},{encoded_content}"#,
-            if encoding.is_empty() {
-              String::new()
-            } else {
-              format!(";{encoding}")
-            }
-          );
+          let encoded_source = match &self.inline_data_url {
+            Some(data_url) => data_url.clone(),
+            None => self.get_data_url(
+              &module.identifier(),
+              normal_module.resource_resolved_data(),
+              generate_context
+                .module_generator_options
+                .and_then(|x| x.asset_data_url(module_type)),
+              source,
+            )?,
+          };
 
           generate_context
             .data
@@ -356,11 +478,11 @@ impl ParserAndGenerator for AssetParserAndGenerator {
             .and_then(|x| x.asset_filename(module_type))
             .unwrap_or(&compilation.options.output.asset_module_filename);
 
-          let contenthash = self.hash_for_source(source, &compilation.options);
-          let contenthash = contenthash.rendered(compilation.options.output.hash_digest_length);
+          let content_hash = hash_for_source(source, &compilation.options);
+          let contenthash = content_hash.rendered(compilation.options.output.hash_digest_length);
 
           let source_file_name = self.get_source_file_name(normal_module, compilation);
-          let (filename, asset_info) = compilation.get_asset_path_with_info(
+          let (filename, mut asset_info) = compilation.get_asset_path_with_info(
             asset_filename_template,
             PathData::default()
               .module(module)
@@ -369,6 +491,7 @@ impl ParserAndGenerator for AssetParserAndGenerator {
               .hash(contenthash)
               .filename(&source_file_name),
           );
+          asset_info.set_source_filename(source_file_name);
 
           let asset_path = if let Some(public_path) = generate_context
             .module_generator_options
@@ -390,6 +513,9 @@ impl ParserAndGenerator for AssetParserAndGenerator {
           generate_context
             .data
             .insert(CodeGenerationDataAssetInfo::new(asset_info));
+          generate_context
+            .data
+            .insert(CodeGenerationDataContentHash::new(content_hash.clone()));
 
           asset_path
         } else if parsed_asset_config.is_source() {
@@ -435,30 +561,40 @@ impl Plugin for AssetPlugin {
       .as_ref()
       .and_then(|x| x.data_url_condition.clone());
 
+    let data_urls = self.data_urls.clone();
     ctx.context.register_parser_and_generator_builder(
       rspack_core::ModuleType::Asset,
       Box::new(move || {
         Box::new(AssetParserAndGenerator::with_auto(
           data_url_condition.clone(),
+          data_urls.clone(),
         ))
       }),
     );
+    let data_urls = self.data_urls.clone();
     ctx.context.register_parser_and_generator_builder(
       rspack_core::ModuleType::AssetInline,
-      Box::new(|| Box::new(AssetParserAndGenerator::with_inline())),
+      Box::new(move || Box::new(AssetParserAndGenerator::with_inline(data_urls.clone()))),
     );
+    let data_urls = self.data_urls.clone();
     ctx.context.register_parser_and_generator_builder(
       rspack_core::ModuleType::AssetResource,
-      Box::new(move || Box::new(AssetParserAndGenerator::with_resource())),
+      Box::new(move || Box::new(AssetParserAndGenerator::with_resource(data_urls.clone()))),
     );
+    let data_urls = self.data_urls.clone();
     ctx.context.register_parser_and_generator_builder(
       rspack_core::ModuleType::AssetSource,
-      Box::new(move || Box::new(AssetParserAndGenerator::with_source())),
+      Box::new(move || Box::new(AssetParserAndGenerator::with_source(data_urls.clone()))),
     );
 
     Ok(())
   }
 
+  async fn finish_modules(&self, _compilation: &mut Compilation) -> Result<()> {
+    self.data_urls.call_pending().await;
+    Ok(())
+  }
+
   async fn render_manifest(
     &self,
     _ctx: PluginContext,
@@ -522,22 +658,79 @@ impl Plugin for AssetPlugin {
             .get::<CodeGenerationDataAssetInfo>()
             .expect("should have asset_info")
             .inner();
-          RenderManifestEntry::new(
+          let content_hash = code_gen_result
+            .data
+            .get::<CodeGenerationDataContentHash>()
+            .expect("should have content hash for asset module")
+            .inner();
+          (
             source.clone(),
             asset_filename.to_owned(),
             asset_info.to_owned(),
-            true,
-            true,
+            content_hash.to_owned(),
           )
         });
 
         Ok(result)
       })
-      .collect::<Result<Vec<Option<RenderManifestEntry>>>>()?
-      .into_par_iter()
+      .collect::<Result<Vec<Option<(BoxSource, String, AssetInfo, RspackHashDigest)>>>>()?
+      .into_iter()
       .flatten()
-      .collect::<Vec<RenderManifestEntry>>();
+      .collect::<Vec<_>>();
+
+    Ok(
+      deduplicate_assets(compilation, assets)
+        .into_iter()
+        .map(|(source, filename, info)| {
+          RenderManifestEntry::new(source, filename, info, true, true)
+        })
+        .collect(),
+    )
+  }
+}
+
+/// Assets with identical content and the same filename are emitted only once, with all the
+/// source files recorded in the asset info. Identical content emitted to different filenames
+/// is kept, but a note is logged since a `[contenthash]` based filename would deduplicate it.
+fn deduplicate_assets(
+  compilation: &Compilation,
+  assets: Vec<(BoxSource, String, AssetInfo, RspackHashDigest)>,
+) -> Vec<(BoxSource, String, AssetInfo)> {
+  let mut deduplicated: Vec<(BoxSource, String, AssetInfo)> = Vec::with_capacity(assets.len());
+  let mut content_hashes: Vec<RspackHashDigest> = Vec::with_capacity(assets.len());
+  let mut index_by_filename: HashMap<String, usize> = HashMap::default();
+  let mut filenames_by_content: HashMap<RspackHashDigest, Vec<String>> = HashMap::default();
+  for (source, filename, info, content_hash) in assets {
+    if let Some(index) = index_by_filename.get(&filename) {
+      // different content is kept, the conflict is reported when the asset is emitted
+      if content_hashes[*index] == content_hash {
+        let (_, _, existing_info) = &mut deduplicated[*index];
+        existing_info.merge_another_asset(info);
+        continue;
+      }
+    }
+    let filenames = filenames_by_content
+      .entry(content_hash.clone())
+      .or_default();
+    if !filenames.contains(&filename) {
+      filenames.push(filename.clone());
+    }
+    index_by_filename.insert(filename.clone(), deduplicated.len());
+    content_hashes.push(content_hash);
+    deduplicated.push((source, filename, info));
+  }
 
-    Ok(assets)
+  let logger = compilation.get_logger("rspack.AssetPlugin");
+  let mut duplicated = filenames_by_content
+    .into_values()
+    .filter(|filenames| filenames.len() > 1)
+    .collect::<Vec<_>>();
+  duplicated.sort_unstable();
+  for filenames in duplicated {
+    logger.info(format!(
+      "Identical content is emitted to different filenames: {}. Consider using [contenthash] instead of [name] in the filename template to emit it once",
+      filenames.join(", ")
+    ));
   }
+  deduplicated
 }
@@ -4,12 +4,14 @@ use std::fmt::{self, Debug};
 
 use async_recursion::async_recursion;
 use async_trait::async_trait;
+use futures::future::BoxFuture;
 use rspack_core::{
   rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt},
-  to_comment, try_any, Logger, Plugin,
+  to_comment, try_any, Chunk, Logger, Plugin,
 };
 use rspack_error::Result;
 use rspack_regex::RspackRegex;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 pub enum BannerCondition {
   String(String),
@@ -43,12 +45,54 @@ impl BannerConditions {
   }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BannerContentFnCtx {
+  pub hash: String,
+  pub chunk: BannerContentFnCtxChunk,
+  pub filename: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BannerContentFnCtxChunk {
+  pub id: Option<String>,
+  pub name: Option<String>,
+  pub hash: Option<String>,
+}
+
+impl From<&Chunk> for BannerContentFnCtxChunk {
+  fn from(chunk: &Chunk) -> Self {
+    Self {
+      id: chunk.id.clone(),
+      name: chunk.name.clone(),
+      hash: chunk.hash.as_ref().map(|hash| hash.encoded().to_string()),
+    }
+  }
+}
+
+pub type BannerContentFn =
+  Box<dyn Fn(BannerContentFnCtx) -> BoxFuture<'static, Result<String>> + Sync + Send>;
+
+pub enum BannerContent {
+  String(String),
+  /// Evaluated for each asset with the chunk and the filename of it.
+  Fn(BannerContentFn),
+}
+
+impl fmt::Debug for BannerContent {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::String(i) => i.fmt(f),
+      Self::Fn(_) => f.write_str("Function"),
+    }
+  }
+}
+
 #[derive(Debug)]
 pub struct BannerConfig {
   /**
    * Specifies the banner.
    */
-  pub banner: String,
+  pub banner: BannerContent,
   /**
    * If true, the banner will only be added to the entry chunks.
    */
@@ -134,18 +178,19 @@ fn wrap_comment(str: &str) -> String {
 #[derive(Debug)]
 pub struct BannerPlugin {
   config: BannerConfig,
-  comment: String,
 }
 
 impl BannerPlugin {
   pub fn new(config: BannerConfig) -> Self {
-    let comment = if let Some(raw) = config.raw && raw {
-      config.banner.clone()
-    } else {
-      wrap_comment(&config.banner)
-    };
+    Self { config }
+  }
 
-    Self { config, comment }
+  fn to_comment(&self, banner: &str) -> String {
+    if let Some(raw) = self.config.raw && raw {
+      banner.to_string()
+    } else {
+      wrap_comment(banner)
+    }
   }
 
   fn update_source(&self, comment: String, old: BoxSource, footer: Option<bool>) -> BoxSource {
@@ -182,6 +227,7 @@ impl Plugin for BannerPlugin {
     let logger = compilation.get_logger(self.name());
     let start = logger.time("add banner");
     let mut chunk_files = vec![];
+    let hash = compilation.get_hash().unwrap_or_default().to_string();
 
     // filter file
     for chunk in compilation.chunk_by_ukey.values() {
@@ -197,16 +243,50 @@ impl Plugin for BannerPlugin {
         if !is_match {
           continue;
         }
-        chunk_files.push(file.clone());
+        chunk_files.push(BannerContentFnCtx {
+          hash: hash.clone(),
+          chunk: chunk.into(),
+          filename: file.clone(),
+        });
       }
     }
 
     // add comment to the matched file
-    for file in chunk_files {
+    let mut updated_files = HashSet::default();
+    // The banner function is evaluated once for the identical inputs within a compilation
+    let mut banner_cache: HashMap<BannerContentFnCtx, String> = HashMap::default();
+    for ctx in chunk_files {
+      // An asset may be listed in the files of multiple chunks, but it only gets the banner once
+      if !updated_files.insert(ctx.filename.clone()) {
+        continue;
+      }
+      let file = ctx.filename.clone();
       // todo: support placeholder, such as [fullhash]、[chunkhash]
-      let comment = self.comment.to_owned();
-      let _res = compilation.update_asset(file.as_str(), |old, info| {
-        let new = self.update_source(comment, old, self.config.footer);
+      let comment = match &self.config.banner {
+        BannerContent::String(banner) => self.to_comment(banner),
+        BannerContent::Fn(func) => {
+          let banner = match banner_cache.get(&ctx) {
+            Some(banner) => banner.clone(),
+            None => {
+              let banner = func(ctx.clone()).await?;
+              banner_cache.insert(ctx, banner.clone());
+              banner
+            }
+          };
+          self.to_comment(&banner)
+        }
+      };
+      // The asset is processed already, e.g. it's kept from the previous compilation by the incremental rebuild
+      if compilation
+        .assets()
+        .get(&file)
+        .map_or(false, |asset| asset.info.banners.contains(&comment))
+      {
+        continue;
+      }
+      let _res = compilation.update_asset(file.as_str(), |old, mut info| {
+        let new = self.update_source(comment.clone(), old, self.config.footer);
+        info.banners.insert(comment);
         Ok((new, info))
       });
     }
@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use rspack_core::{
+  ExternalModule, ExternalType, FactorizeArgs, ModuleExt, ModuleFactoryResult,
+  NormalModuleFactoryContext, Plugin, PluginContext, PluginFactorizeHookOutput, RemoteModule,
+};
+
+const EXTERNAL_REQUEST_PREFIX: &str = "webpack/container/reference/";
+
+#[derive(Debug)]
+pub struct RemoteOptions {
+  /// The external of the remote container, i.e. `app2@https://cdn/remoteEntry.js` for the `script` remote type
+  pub external: String,
+  pub share_scope: String,
+}
+
+#[derive(Debug)]
+pub struct ContainerReferencePluginOptions {
+  /// The external type of the remote containers
+  pub remote_type: ExternalType,
+  pub remotes: Vec<(String, RemoteOptions)>,
+}
+
+/// Consume the modules exposed by the remote containers, i.e. `import("app2/Button")` of
+/// `remotes: { app2: "app2@https://cdn/remoteEntry.js" }`.
+///
+/// A request prefixed by a remote name becomes a [RemoteModule], which depends on the external
+/// module of the remote container, i.e. `webpack/container/reference/app2`.
+///
+/// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/container/ContainerReferencePlugin.js
+#[derive(Debug)]
+pub struct ContainerReferencePlugin {
+  options: ContainerReferencePluginOptions,
+}
+
+impl ContainerReferencePlugin {
+  pub fn new(options: ContainerReferencePluginOptions) -> Self {
+    Self { options }
+  }
+}
+
+#[async_trait]
+impl Plugin for ContainerReferencePlugin {
+  fn name(&self) -> &'static str {
+    "ContainerReferencePlugin"
+  }
+
+  async fn factorize(
+    &self,
+    _ctx: PluginContext,
+    args: FactorizeArgs<'_>,
+    _job_ctx: &mut NormalModuleFactoryContext,
+  ) -> PluginFactorizeHookOutput {
+    let request = args.dependency.request();
+
+    // The container itself
+    if let Some(key) = request.strip_prefix(EXTERNAL_REQUEST_PREFIX)
+      && let Some((_, remote)) = self.options.remotes.iter().find(|(name, _)| name == key)
+    {
+      let module = ExternalModule::new(
+        vec![remote.external.clone()],
+        self.options.remote_type.clone(),
+        request.to_string(),
+      );
+      return Ok(Some(ModuleFactoryResult::new(module.boxed())));
+    }
+
+    // The modules exposed by the container
+    for (key, remote) in &self.options.remotes {
+      if let Some(internal_request) = request.strip_prefix(key.as_str())
+        && (internal_request.is_empty() || internal_request.starts_with('/'))
+      {
+        let module = RemoteModule::new(
+          request.to_string(),
+          format!("{EXTERNAL_REQUEST_PREFIX}{key}"),
+          format!(".{internal_request}"),
+          remote.share_scope.clone(),
+        );
+        return Ok(Some(ModuleFactoryResult::new(module.boxed())));
+      }
+    }
+    Ok(None)
+  }
+}
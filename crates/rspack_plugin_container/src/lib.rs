@@ -0,0 +1,7 @@
+#![feature(let_chains)]
+
+mod container_reference_plugin;
+
+pub use container_reference_plugin::{
+  ContainerReferencePlugin, ContainerReferencePluginOptions, RemoteOptions,
+};
@@ -1,5 +1,6 @@
 #![feature(let_chains)]
 use std::{
+  collections::HashMap,
   fs,
   hash::Hash,
   path::{Path, PathBuf, MAIN_SEPARATOR},
@@ -11,7 +12,8 @@ use dashmap::DashSet;
 use glob::MatchOptions;
 use regex::Regex;
 use rspack_core::{
-  rspack_sources::RawSource, AssetInfo, Compilation, CompilationAsset, CompilationLogger, Filename,
+  rspack_sources::{RawSource, Source},
+  AssetInfo, Compilation, CompilationAsset, CompilationLogger, CopyTo, CopyToFnCtx, Filename,
   FromType, Logger, PathData, Pattern, Plugin, ToType,
 };
 use rspack_error::Diagnostic;
@@ -27,6 +29,8 @@ pub struct RunPatternResult {
   pub info: Option<AssetInfo>,
   pub force: bool,
   pub priority: i32,
+  /// The target is an absolute path outside of `output.path`, which is written directly
+  pub outside_output_path: bool,
 }
 
 #[derive(Debug)]
@@ -94,15 +98,50 @@ impl CopyPlugin {
       context.join(&from)
     };
 
-    let to = if let Some(to) = pattern.to.as_ref() {
-      to.clone()
-        .as_path()
-        .normalize()
-        .to_string_lossy()
-        .to_string()
-    } else {
-      "".into()
+    // If this came from a glob or dir, add it to the file dependencies
+    if matches!(from_type, FromType::Dir | FromType::Glob) {
+      logger.debug(format!(
+        "added '{}' as a file dependency",
+        absolute_filename.display()
+      ));
+
+      file_dependencies.insert(absolute_filename.clone());
+    }
+
+    let to = match &pattern.to {
+      Some(CopyTo::String(to)) => Some(to.clone()),
+      Some(CopyTo::Fn(to_fn)) => {
+        let ctx = CopyToFnCtx {
+          context: context.to_path_buf(),
+          absolute_filename: absolute_filename.clone(),
+        };
+        match to_fn(ctx).await {
+          Ok(to) => {
+            logger.log(format!(
+              "'to' function returned '{to}' for '{}'",
+              absolute_filename.display()
+            ));
+            Some(to)
+          }
+          Err(e) => {
+            diagnostics.insert(Diagnostic::error(
+              "CopyRspackPlugin Error".into(),
+              format!(
+                "failed to call the 'to' function for '{}': {e}",
+                absolute_filename.display()
+              ),
+              0,
+              0,
+            ));
+            return None;
+          }
+        }
+      }
+      None => None,
     };
+    let to = to
+      .map(|to| to.as_path().normalize().to_string_lossy().to_string())
+      .unwrap_or_default();
 
     let to_type = if let Some(to_type) = pattern.to_type.as_ref() {
       to_type.clone()
@@ -127,7 +166,9 @@ impl CopyPlugin {
       to.into()
     };
 
-    let filename = if filename.is_absolute() {
+    let outside_output_path =
+      filename.is_absolute() && !filename.normalize().starts_with(output_path);
+    let filename = if filename.is_absolute() && !outside_output_path {
       pathdiff::diff_paths(filename, output_path)?
     } else {
       filename
@@ -141,16 +182,6 @@ impl CopyPlugin {
 
     let source_filename = relative?;
 
-    // If this came from a glob or dir, add it to the file dependencies
-    if matches!(from_type, FromType::Dir | FromType::Glob) {
-      logger.debug(format!(
-        "added '{}' as a file dependency",
-        absolute_filename.display()
-      ));
-
-      file_dependencies.insert(absolute_filename.clone());
-    }
-
     // TODO cache
 
     logger.debug(format!("reading '{}'...", absolute_filename.display()));
@@ -171,6 +202,7 @@ impl CopyPlugin {
       }
     };
 
+    let mut info = None;
     let filename = if matches!(&to_type, ToType::Template) {
       logger.log(format!(
         "interpolating template '{}' for '${}'...`",
@@ -185,13 +217,14 @@ impl CopyPlugin {
         &compilation.options.output.hash_salt,
       );
       let content_hash = content_hash.rendered(compilation.options.output.hash_digest_length);
-      let template_str = compilation.get_asset_path(
+      let (template_str, asset_info) = compilation.get_asset_path_with_info(
         &Filename::from(filename.to_string_lossy().to_string()),
         PathData::default()
           .filename(&source_filename.to_string_lossy())
           .content_hash(content_hash)
           .hash_optional(compilation.get_hash()),
       );
+      info = Some(asset_info);
 
       logger.log(format!(
         "interpolated template '{template_str}' for '{}'",
@@ -208,9 +241,10 @@ impl CopyPlugin {
       absolute_filename,
       filename,
       source,
-      info: None,
+      info,
       force: pattern.force,
       priority: pattern.priority,
+      outside_output_path,
     })
   }
 
@@ -220,6 +254,7 @@ impl CopyPlugin {
     _index: usize,
     file_dependencies: &DashSet<PathBuf>,
     context_dependencies: &DashSet<PathBuf>,
+    missing_dependencies: &DashSet<PathBuf>,
     diagnostics: &DashSet<Diagnostic>,
     logger: &CompilationLogger,
   ) -> Option<Vec<Option<RunPatternResult>>> {
@@ -308,6 +343,11 @@ impl CopyPlugin {
       FromType::Glob => {
         need_add_context_to_dependency = true;
         if Path::new(orig_from).is_absolute() {
+          // An absolute glob outside of the compiler context is relative to its own directory
+          let glob_base = get_glob_base(orig_from);
+          if pattern.context.is_none() && !glob_base.starts_with(&context) {
+            context = glob_base;
+          }
           orig_from.into()
         } else {
           context.join(orig_from).to_string_lossy().to_string()
@@ -346,11 +386,23 @@ impl CopyPlugin {
           })
           .collect();
 
-        if need_add_context_to_dependency &&
-        let Some(common_dir) = get_closest_common_parent_dir(
-          &entries.iter().map(|it| it.as_path()).collect(),
-        ) {
-          context_dependencies.insert(common_dir);
+        if need_add_context_to_dependency {
+          // Watch the base directory of the glob, so files added later are copied as well.
+          // If it doesn't exist yet, watch it as a missing dependency.
+          let glob_base = get_glob_base(&glob_query);
+          if glob_base.is_dir() {
+            logger.debug(format!(
+              "added '{}' as a context dependency",
+              glob_base.display()
+            ));
+            context_dependencies.insert(glob_base);
+          } else {
+            logger.debug(format!(
+              "added '{}' as a missing dependency",
+              glob_base.display()
+            ));
+            missing_dependencies.insert(glob_base);
+          }
         }
 
         if entries.is_empty() {
@@ -446,6 +498,7 @@ impl Plugin for CopyPlugin {
     let start = logger.time("run pattern");
     let file_dependencies = DashSet::default();
     let context_dependencies = DashSet::default();
+    let missing_dependencies = DashSet::default();
     let diagnostics = DashSet::default();
 
     let mut copied_result: Vec<(i32, RunPatternResult)> = self
@@ -454,9 +507,8 @@ impl Plugin for CopyPlugin {
       .enumerate()
       .map(|(index, pattern)| {
         let mut pattern = pattern.clone();
-        if pattern.context.is_none() {
-          pattern.context = Some(args.compilation.options.context.as_path().into());
-        } else if let Some(ctx) = pattern.context.clone() && !ctx.is_absolute() {
+        // The pattern without context defaults to the compiler context in `run_patter`
+        if let Some(ctx) = pattern.context.clone() && !ctx.is_absolute() {
           pattern.context = Some(args.compilation.options.context.as_path().join(ctx))
         };
 
@@ -466,6 +518,7 @@ impl Plugin for CopyPlugin {
           index,
           &file_dependencies,
           &context_dependencies,
+          &missing_dependencies,
           &diagnostics,
           &logger,
         )
@@ -489,50 +542,147 @@ impl Plugin for CopyPlugin {
     compilation
       .context_dependencies
       .extend(context_dependencies);
+    compilation
+      .missing_dependencies
+      .extend(missing_dependencies);
     compilation.push_batch_diagnostic(diagnostics.into_iter().collect());
 
-    copied_result.sort_unstable_by(|a, b| a.0.cmp(&b.0));
-    copied_result.into_iter().for_each(|(_priority, result)| {
+    // Patterns with a higher priority are written later, so they can overwrite the lower ones with `force`
+    copied_result.sort_by(|a, b| a.0.cmp(&b.0));
+    // The source file which each target path is copied from
+    let mut copied_sources: HashMap<String, PathBuf> = HashMap::new();
+    let mut emit_diagnostics = vec![];
+    for (priority, result) in copied_result {
+      if result.outside_output_path {
+        logger.log(format!(
+          "write '{}' to '{}' directly, because it's outside of the output path",
+          result.absolute_filename.display(),
+          result.filename
+        ));
+        if let Err(e) = write_outside_output_path(&result).await {
+          emit_diagnostics.push(Diagnostic::error(
+            "CopyRspackPlugin Error".into(),
+            format!("failed to write '{}': {e}", result.filename),
+            0,
+            0,
+          ));
+          continue;
+        }
+        emit_diagnostics.push(Diagnostic::warn(
+          "CopyRspackPlugin Warning".into(),
+          format!(
+            "'{}' is copied to '{}' outside of the output path, which is written directly, so it isn't hashed, cleaned by `output.clean` or listed in the assets",
+            result.absolute_filename.display(),
+            result.filename
+          ),
+          0,
+          0,
+        ));
+        continue;
+      }
       if let Some(exist_asset) = args.compilation.assets_mut().get_mut(&result.filename) {
+        let copied_source = copied_sources.get(&result.filename);
         if !result.force {
-          return;
+          match copied_source {
+            // the same file is matched by multiple patterns
+            Some(source_filename) if source_filename == &result.absolute_filename => {
+              logger.log(format!(
+                "skip copying '{}' to '{}', because it's already copied",
+                result.absolute_filename.display(),
+                result.filename
+              ));
+            }
+            Some(source_filename) => {
+              logger.log(format!(
+                "skip copying '{}' to '{}' with priority {priority}, because '{}' is already copied to it",
+                result.absolute_filename.display(),
+                result.filename,
+                source_filename.display()
+              ));
+              emit_diagnostics.push(Diagnostic::warn(
+                "CopyRspackPlugin Warning".into(),
+                format!(
+                  "Multiple source files are copied to the same target '{}': '{}' and '{}', the former is kept. Set `force: true` on the pattern with a higher priority to overwrite it",
+                  result.filename,
+                  source_filename.display(),
+                  result.absolute_filename.display()
+                ),
+                0,
+                0,
+              ));
+            }
+            None => {
+              logger.log(format!(
+                "skip copying '{}' to '{}', because the asset is emitted by the compilation",
+                result.absolute_filename.display(),
+                result.filename
+              ));
+              emit_diagnostics.push(Diagnostic::error(
+                "CopyRspackPlugin Error".into(),
+                format!(
+                  "'{}' is copied to '{}', which conflicts with the asset emitted by the compilation. Set `force: true` on the pattern to overwrite it",
+                  result.absolute_filename.display(),
+                  result.filename
+                ),
+                0,
+                0,
+              ));
+            }
+          }
+          continue;
         }
+        logger.log(format!(
+          "overwrite '{}' with '{}' of priority {priority}, because the pattern is forced",
+          result.filename,
+          result.absolute_filename.display()
+        ));
         exist_asset.set_source(Some(Arc::new(result.source)));
+        if let Some(info) = result.info {
+          exist_asset.set_info(info);
+        }
         // TODO set info { copied: true, sourceFilename }
       } else {
+        logger.log(format!(
+          "copy '{}' to '{}' with priority {priority}",
+          result.absolute_filename.display(),
+          result.filename
+        ));
         args.compilation.emit_asset(
-          result.filename,
+          result.filename.clone(),
           CompilationAsset {
             source: Some(Arc::new(result.source)),
-            info: AssetInfo::default(),
+            info: result.info.unwrap_or_default(),
           },
         )
       }
-    });
+      copied_sources.insert(result.filename, result.absolute_filename);
+    }
+    args.compilation.push_batch_diagnostic(emit_diagnostics);
     logger.time_end(start);
 
     Ok(())
   }
 }
 
-fn get_closest_common_parent_dir(paths: &Vec<&Path>) -> Option<PathBuf> {
-  // If there are no matching files, return `None`.
-  if paths.is_empty() {
-    return None;
-  }
-
-  // Get the first file path and use it as the initial value for the common parent directory.
-  let mut parent_dir: PathBuf = paths[0].parent()?.into();
-
-  // Iterate over the remaining file paths, updating the common parent directory as necessary.
-  for path in paths.iter().skip(1) {
-    // Find the common parent directory between the current file path and the previous common parent directory.
-    while !path.starts_with(&parent_dir) {
-      parent_dir = parent_dir.parent()?.into();
-    }
+async fn write_outside_output_path(result: &RunPatternResult) -> std::io::Result<()> {
+  let filename = Path::new(&result.filename);
+  if let Some(dir) = filename.parent() {
+    tokio::fs::create_dir_all(dir).await?;
   }
+  tokio::fs::write(filename, result.source.buffer()).await
+}
 
-  Some(parent_dir)
+/// The leading part of a glob which doesn't contain any glob pattern, e.g. `/a/b` for `/a/b/**/*.js`
+fn get_glob_base(glob: &str) -> PathBuf {
+  Path::new(glob)
+    .components()
+    .take_while(|component| {
+      !component
+        .as_os_str()
+        .to_string_lossy()
+        .contains(['*', '?', '[', '{'])
+    })
+    .collect()
 }
 
 fn escape_glob_chars(s: &str) -> String {
@@ -547,6 +697,13 @@ fn escape_glob_chars(s: &str) -> String {
   escaped
 }
 
+#[test]
+fn test_glob_base() {
+  assert_eq!(get_glob_base("/a/b/**/*.js"), PathBuf::from("/a/b"));
+  assert_eq!(get_glob_base("/a/b/*.{js,ts}"), PathBuf::from("/a/b"));
+  assert_eq!(get_glob_base("/a/b/c.txt"), PathBuf::from("/a/b/c.txt"));
+}
+
 #[test]
 fn test_escape() {
   assert_eq!(escape_glob_chars("a/b/**/*.js"), r#"a/b/\*\*/\*.js"#);
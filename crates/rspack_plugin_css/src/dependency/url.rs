@@ -6,7 +6,7 @@ use rspack_core::{
   PublicPath, TemplateContext, TemplateReplaceSource,
 };
 
-use crate::utils::AUTO_PUBLIC_PATH_PLACEHOLDER;
+use crate::utils::{is_data_url, AUTO_PUBLIC_PATH_PLACEHOLDER};
 
 #[derive(Debug, Clone)]
 pub struct CssUrlDependency {
@@ -15,15 +15,22 @@ pub struct CssUrlDependency {
   span: Option<ErrorSpan>,
   start: u32,
   end: u32,
+  /// The query of the request, like `?v=1` of `font.woff2?v=1`
+  query: Option<String>,
+  /// The fragment of the request, like `#iefix` of `font.woff2?#iefix`
+  fragment: Option<String>,
 }
 
 impl CssUrlDependency {
   pub fn new(request: String, span: Option<ErrorSpan>, start: u32, end: u32) -> Self {
+    let (query, fragment) = split_query_and_fragment(&request);
     Self {
       request,
       span,
       start,
       end,
+      query,
+      fragment,
       id: DependencyId::new(),
     }
   }
@@ -42,11 +49,30 @@ impl CssUrlDependency {
         Some(url.inner().to_string())
       } else if let Some(filename) = code_gen_result.data.get::<CodeGenerationDataFilename>() {
         let filename = filename.inner();
-        let public_path = match &compilation.options.output.public_path {
+        // Prefer `Rule.generator.publicPath` of the asset module over `output.publicPath`
+        let public_path = compilation
+          .module_graph
+          .module_by_identifier(identifier)
+          .and_then(|module| {
+            module
+              .as_normal_module()?
+              .generator_options()?
+              .asset_public_path(module.module_type())
+          })
+          .unwrap_or(&compilation.options.output.public_path);
+        let public_path = match public_path {
           PublicPath::String(p) => p,
           PublicPath::Auto => AUTO_PUBLIC_PATH_PLACEHOLDER,
         };
-        Some(format!("{public_path}{filename}"))
+        let mut url = format!("{public_path}{filename}");
+        // The query and fragment may be in the filename already with `[query]` and `[fragment]`
+        if let Some(query) = &self.query && !filename.contains('?') {
+          url.push_str(query);
+        }
+        if let Some(fragment) = &self.fragment && !filename.contains('#') {
+          url.push_str(fragment);
+        }
+        Some(url)
       } else {
         None
       }
@@ -106,6 +132,21 @@ impl DependencyTemplate for CssUrlDependency {
   }
 }
 
+fn split_query_and_fragment(request: &str) -> (Option<String>, Option<String>) {
+  if is_data_url(request) {
+    return (None, None);
+  }
+  let (path, fragment) = match request.find('#') {
+    Some(index) if index > 0 => (&request[..index], Some(request[index..].to_string())),
+    _ => (request, None),
+  };
+  let query = match path.find('?') {
+    Some(index) if index > 0 => Some(path[index..].to_string()),
+    _ => None,
+  };
+  (query, fragment)
+}
+
 static WHITE_OR_BRACKET_REGEX: Lazy<Regex> =
   Lazy::new(|| Regex::new(r#"[\n\t ()'"\\]"#).expect("Invalid Regexp"));
 static QUOTATION_REGEX: Lazy<Regex> =
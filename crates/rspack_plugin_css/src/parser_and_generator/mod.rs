@@ -42,6 +42,10 @@ pub struct CssParserAndGenerator {
 }
 
 impl ParserAndGenerator for CssParserAndGenerator {
+  fn name(&self) -> &'static str {
+    "css"
+  }
+
   fn source_types(&self) -> &[SourceType] {
     if self.config.modules.exports_only {
       CSS_MODULE_EXPORTS_ONLY_SOURCE_TYPE_LIST
@@ -145,9 +149,11 @@ impl ParserAndGenerator for CssParserAndGenerator {
     )?;
     diagnostic_vec.extend(new_diagnostic);
 
+    let mut presentational_dependencies = vec![];
     let mut dependencies = analyze_dependencies(
       &new_stylesheet_ast,
       code_generation_dependencies,
+      &mut presentational_dependencies,
       &mut diagnostic_vec,
     );
 
@@ -193,7 +199,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
     Ok(
       ParseResult {
         dependencies,
-        presentational_dependencies: vec![],
+        presentational_dependencies,
         source: new_source,
         analyze_result: Default::default(),
       }
@@ -217,6 +223,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
           module,
           runtime_requirements: generate_context.runtime_requirements,
           init_fragments: &mut vec![],
+          concatenation_scope: None,
         };
 
         let mgm = compilation
@@ -9,7 +9,9 @@ use bitflags::bitflags;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::Filename;
-use rspack_core::{Chunk, ChunkGraph, Compilation, Module, ModuleGraph, PathData, SourceType};
+use rspack_core::{
+  Chunk, ChunkGraph, Compilation, Logger, Module, ModuleGraph, PathData, SourceType,
+};
 use rspack_identifier::IdentifierSet;
 
 static ESCAPE_LOCAL_IDENT_REGEX: Lazy<Regex> =
@@ -224,8 +226,19 @@ impl CssPlugin {
       }
       if let Some(has_failed) = has_failed {
         // There is a not resolve-able conflict with the selectedModule
-        // TODO(hyf0): we should emit a warning here
-        tracing::warn!("Conflicting order between");
+        // TODO(hyf0): we should emit a warning of the compilation here
+        compilation
+          .get_infrastructure_logger("rspack.CssPlugin")
+          .warn(format!(
+            "chunk {}\nConflicting order between {} and {}",
+            chunk
+              .name
+              .as_deref()
+              .or(chunk.id.as_deref())
+              .unwrap_or_default(),
+            has_failed.readable_identifier(&compilation.options.context),
+            selected_module.readable_identifier(&compilation.options.context)
+          ));
         // 		if (compilation) {
         // 			// TODO print better warning
         // 			compilation.warnings.push(
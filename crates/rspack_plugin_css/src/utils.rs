@@ -155,6 +155,20 @@ static UNESCAPE: Lazy<Regex> =
   Lazy::new(|| Regex::new(r"\\([0-9a-fA-F]{1,6}[ \t\n\r\f]?|[\s\S])").expect("Invalid RegExp"));
 
 static DATA: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(?i)data:").expect("Invalid RegExp"));
+static EXTERNAL_URL: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^(?i)(https?:)?//").expect("Invalid RegExp"));
+static TEMPLATE_URL: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"\$\{|\{\{|#\{|<%").expect("Invalid RegExp"));
+
+pub fn is_data_url(s: &str) -> bool {
+  DATA.is_match(s)
+}
+
+/// Urls that are left untouched in `url()`, such as `http://example.com/a.png`, `//example.com/a.png`,
+/// and the ones with template syntax like `{{ asset }}`, which are expanded by other tools.
+pub fn is_ignored_url(s: &str) -> bool {
+  EXTERNAL_URL.is_match(s) || (!is_data_url(s) && TEMPLATE_URL.is_match(s))
+}
 
 pub fn normalize_url(s: &str) -> String {
   let result = STRING_MULTILINE.replace_all(s, "");
@@ -178,7 +192,7 @@ pub fn normalize_url(s: &str) -> String {
       .unwrap_or(caps[0].to_string())
   });
 
-  if DATA.is_match(&result) {
+  if is_data_url(&result) {
     return result.to_string();
   }
   if result.contains('%') {
@@ -1,16 +1,18 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
-use rspack_core::{BoxDependency, ModuleDependency, SpanExt};
+use rspack_core::{BoxDependency, ConstDependency, DependencyTemplate, ModuleDependency, SpanExt};
 use rspack_error::{Diagnostic, DiagnosticKind};
+use rustc_hash::FxHashSet as HashSet;
 use swc_core::common::Span;
 use swc_core::css::ast::{
-  AtRule, AtRuleName, ImportHref, ImportPrelude, Stylesheet, Url, UrlValue,
+  AtRule, AtRuleName, ComponentValue, Function, FunctionName, ImportHref, ImportPrelude,
+  Stylesheet, Url, UrlValue,
 };
 use swc_core::css::visit::{Visit, VisitWith};
 
 use crate::{
   dependency::{CssImportDependency, CssUrlDependency},
-  utils::normalize_url,
+  utils::{is_ignored_url, normalize_url},
 };
 
 static IS_MODULE_REQUEST: Lazy<Regex> = Lazy::new(|| Regex::new(r"^~").expect("TODO:"));
@@ -18,13 +20,16 @@ static IS_MODULE_REQUEST: Lazy<Regex> = Lazy::new(|| Regex::new(r"^~").expect("T
 pub fn analyze_dependencies(
   ss: &Stylesheet,
   code_generation_dependencies: &mut Vec<Box<dyn ModuleDependency>>,
+  presentational_dependencies: &mut Vec<Box<dyn DependencyTemplate>>,
   diagnostics: &mut Vec<Diagnostic>,
 ) -> Vec<BoxDependency> {
   let mut v = Analyzer {
     deps: Vec::new(),
     code_generation_dependencies,
+    presentational_dependencies,
     diagnostics,
     nearest_at_import_span: None,
+    imports: Default::default(),
     // in_support_contdition: false,
   };
   ss.visit_with(&mut v);
@@ -36,8 +41,11 @@ pub fn analyze_dependencies(
 struct Analyzer<'a> {
   deps: Vec<BoxDependency>,
   code_generation_dependencies: &'a mut Vec<Box<dyn ModuleDependency>>,
+  presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
   diagnostics: &'a mut Vec<Diagnostic>,
   nearest_at_import_span: Option<Span>,
+  /// Requests of `@import`, a stylesheet is only imported once
+  imports: HashSet<String>,
   // in_support_contdition: bool,
 }
 
@@ -80,6 +88,18 @@ impl Visit for Analyzer<'_> {
     };
     if let Some(specifier) = specifier {
       let specifier = replace_module_request_prefix(specifier, self.diagnostics);
+      if !self.imports.insert(specifier.clone()) {
+        // Only remove the repeated `@import`
+        self
+          .presentational_dependencies
+          .push(Box::new(ConstDependency::new(
+            span.real_lo(),
+            span.real_hi(),
+            "".into(),
+            None,
+          )));
+        return;
+      }
       self.deps.push(Box::new(CssImportDependency::new(
         specifier,
         Some(span.into()),
@@ -102,24 +122,50 @@ impl Visit for Analyzer<'_> {
 
   fn visit_url(&mut self, u: &Url) {
     u.visit_children_with(self);
-    // Wait for @supports
-    // if !self.in_support_contdition {
+    // TODO: skip the urls in the conditions of @supports, see `visit_supports_condition`
     let specifier = u.value.as_ref().map(|box v| match v {
       UrlValue::Str(s) => s.value.to_string(),
       UrlValue::Raw(r) => r.value.to_string(),
     });
-    if let Some(specifier) = specifier && !specifier.is_empty(){
-    let mut specifier = replace_module_request_prefix(specifier, self.diagnostics);
-    specifier = normalize_url(&specifier);
+    if let Some(specifier) = specifier {
+      self.add_url(specifier, u.span);
+    }
+  }
+
+  fn visit_function(&mut self, f: &Function) {
+    f.visit_children_with(self);
+    let FunctionName::Ident(name) = &f.name else {
+      return;
+    };
+    if !matches!(
+      name.value.to_ascii_lowercase().as_str(),
+      "image-set" | "-webkit-image-set"
+    ) {
+      return;
+    }
+    // The strings in `image-set()` are urls as well, which are replaced with `url()`
+    for value in &f.value {
+      if let ComponentValue::Str(s) = value {
+        self.add_url(s.value.to_string(), s.span);
+      }
+    }
+  }
+}
+
+impl Analyzer<'_> {
+  fn add_url(&mut self, specifier: String, span: Span) {
+    if specifier.is_empty() || is_ignored_url(&specifier) {
+      return;
+    }
+    let specifier = replace_module_request_prefix(specifier, self.diagnostics);
+    let specifier = normalize_url(&specifier);
     let dep = Box::new(CssUrlDependency::new(
       specifier,
-      Some(u.span.into()),
-      u.span.real_lo(),
-      u.span.real_hi()
+      Some(span.into()),
+      span.real_lo(),
+      span.real_hi(),
     ));
     self.deps.push(dep.clone());
     self.code_generation_dependencies.push(dep);
-  // }
-    }
   }
 }
@@ -17,6 +17,7 @@ use rspack_core::{
   ProcessAssetsArgs, RenderModuleContentArgs, SourceType,
 };
 use rspack_error::{internal_error, Error, Result};
+use rspack_regex::RspackRegex;
 use rspack_util::swc::normalize_custom_filename;
 use rustc_hash::FxHashMap as HashMap;
 use serde_json::json;
@@ -31,6 +32,25 @@ pub struct DevtoolPluginOptions {
   pub columns: bool,
   pub no_sources: bool,
   pub public_path: Option<String>,
+  /// The sources which are listed in the `ignoreList` of the source maps, i.e. `output.devtoolIgnoreList`
+  pub ignore_list: Option<IgnoreList>,
+}
+
+/// Matches the original path of a source, before it's formatted by the module filename template.
+#[derive(Debug)]
+pub enum IgnoreList {
+  /// The paths which contain the string
+  String(String),
+  Regexp(RspackRegex),
+}
+
+impl IgnoreList {
+  pub fn test(&self, path: &str) -> bool {
+    match self {
+      Self::String(string) => path.contains(string.as_str()),
+      Self::Regexp(regexp) => regexp.test(path),
+    }
+  }
 }
 
 #[derive(Debug)]
@@ -42,6 +62,7 @@ pub struct DevtoolPlugin {
   columns: bool,
   no_sources: bool,
   public_path: Option<String>,
+  ignore_list: Option<IgnoreList>,
 }
 
 impl DevtoolPlugin {
@@ -56,8 +77,22 @@ impl DevtoolPlugin {
       columns: options.columns,
       no_sources: options.no_sources,
       public_path: options.public_path,
+      ignore_list: options.ignore_list,
     }
   }
+
+  /// `devtool: "eval"`, evaluate each module with a `sourceURL` so that it shows up as a separate file.
+  fn wrap_eval(&self, source: &str, args: &RenderModuleContentArgs) -> BoxSource {
+    let compilation = args.compilation;
+    let name = compilation
+      .module_graph
+      .module_by_identifier(&args.module)
+      .map(|module| module.readable_identifier(&compilation.options.context))
+      .unwrap_or_else(|| args.module.as_str().into());
+    let source_url = format!("webpack://{}/{name}", self.namespace);
+    let footer = format!("\n//# sourceURL={source_url}\n");
+    RawSource::from(format!("eval({});", json!(format!("{source}{footer}")))).boxed()
+  }
 }
 
 static MODULE_RENDER_CACHE: Lazy<DashMap<BoxSource, BoxSource>> = Lazy::new(DashMap::default);
@@ -78,12 +113,20 @@ impl Plugin for DevtoolPlugin {
     if devtool.eval() && devtool.source_map() {
       if let Some(cached) = MODULE_RENDER_CACHE.get(&origin_source) {
         return Ok(Some(cached.value().clone()));
-      } else if let Some(map) = origin_source.map(&MapOptions::new(devtool.cheap())) {
-        let source = wrap_eval_source_map(&origin_source.source(), map, args.compilation)?;
+      } else if let Some(map) = origin_source.map(&MapOptions::new(!devtool.cheap())) {
+        let source = wrap_eval_source_map(
+          &origin_source.source(),
+          map,
+          args.compilation,
+          self.ignore_list.as_ref(),
+        )?;
         MODULE_RENDER_CACHE.insert(origin_source, source.clone());
         return Ok(Some(source));
       }
     }
+    if devtool.eval() && !devtool.source_map() {
+      return Ok(Some(self.wrap_eval(&origin_source.source(), args)));
+    }
     Ok(Some(origin_source))
   }
 
@@ -118,8 +161,14 @@ impl Plugin for DevtoolPlugin {
           .and_then(|source| source.map(&MapOptions::new(self.columns)))
           .map(|mut map| {
             map.set_file(Some(filename.clone()));
-            for source in map.sources_mut() {
+            let mut ignore_list = vec![];
+            for (index, source) in map.sources_mut().iter_mut().enumerate() {
               let resource_path = normalize_custom_filename(source);
+              if let Some(matcher) = &self.ignore_list
+                && matcher.test(resource_path)
+              {
+                ignore_list.push(index);
+              }
               let resource_path = contextify(&context, resource_path);
               *source = self
                 .module_filename_template
@@ -135,6 +184,9 @@ impl Plugin for DevtoolPlugin {
             map
               .to_writer(&mut map_buffer)
               .map_err(|e| internal_error!(e.to_string()))?;
+            if !ignore_list.is_empty() {
+              map_buffer = with_ignore_list(&map_buffer, ignore_list)?;
+            }
             Ok::<Vec<u8>, Error>(map_buffer)
           })
           .transpose()?;
@@ -261,13 +313,30 @@ impl Plugin for DevtoolPlugin {
   }
 }
 
+/// Add the indices of the ignored sources to the serialized source map, as `ignoreList`
+/// and the legacy `x_google_ignoreList` which is read by the older versions of Chrome.
+fn with_ignore_list(map_buffer: &[u8], ignore_list: Vec<usize>) -> Result<Vec<u8>> {
+  let mut map: serde_json::Map<String, serde_json::Value> =
+    serde_json::from_slice(map_buffer).map_err(|e| internal_error!(e.to_string()))?;
+  map.insert("ignoreList".to_string(), json!(ignore_list));
+  map.insert("x_google_ignoreList".to_string(), json!(ignore_list));
+  serde_json::to_vec(&map).map_err(|e| internal_error!(e.to_string()))
+}
+
 pub fn wrap_eval_source_map(
   source: &str,
   mut map: SourceMap,
   compilation: &Compilation,
+  ignore_list: Option<&IgnoreList>,
 ) -> Result<BoxSource> {
-  for source in map.sources_mut() {
+  let mut ignored = vec![];
+  for (index, source) in map.sources_mut().iter_mut().enumerate() {
     let resource_path = normalize_custom_filename(source);
+    if let Some(matcher) = ignore_list
+      && matcher.test(resource_path)
+    {
+      ignored.push(index);
+    }
     let resource_path = contextify(&compilation.options.context, resource_path);
     *source = resource_path;
   }
@@ -280,6 +349,9 @@ pub fn wrap_eval_source_map(
   map
     .to_writer(&mut map_buffer)
     .map_err(|e| internal_error!(e.to_string()))?;
+  if !ignored.is_empty() {
+    map_buffer = with_ignore_list(&map_buffer, ignored)?;
+  }
   let base64 = rspack_base64::encode_to_string(&map_buffer);
   let footer =
     format!("\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,{base64}");
@@ -1,9 +1,14 @@
 #![feature(let_chains)]
 
+use std::sync::Mutex;
+
+use futures::future::BoxFuture;
 use rspack_core::{
-  BoxDependency, Compilation, EntryDependency, EntryOptions, MakeParam, Plugin, PluginContext,
-  PluginMakeHookOutput,
+  BoxDependency, Compilation, DependencyId, EntryDependency, EntryDescription, EntryOptions,
+  MakeParam, Plugin, PluginContext, PluginMakeHookOutput,
 };
+use rspack_error::Result;
+use rustc_hash::FxHashSet as HashSet;
 
 #[derive(Debug)]
 pub struct EntryPlugin {
@@ -35,9 +40,118 @@ impl Plugin for EntryPlugin {
     }
     let dependency: BoxDependency = Box::new(EntryDependency::new(self.entry_request.clone()));
     let dependency_id = dependency.id();
-    compilation.add_entry(*dependency_id, self.name.clone(), self.options.clone());
+    compilation
+      .add_entry(*dependency_id, self.name.clone(), self.options.clone())
+      .await?;
     param.add_force_build_dependency(*dependency_id, None);
     compilation.module_graph.add_dependency(dependency);
     Ok(())
   }
 }
+
+pub type DynamicEntryFn =
+  Box<dyn Fn() -> BoxFuture<'static, Result<Vec<(String, EntryDescription)>>> + Sync + Send>;
+
+/// Entries returned by a function, which is called at the start of each compilation,
+/// so entries added during watching are picked up by the rebuild.
+pub struct DynamicEntryPlugin {
+  entry: DynamicEntryFn,
+  /// The entry dependencies added by this plugin, the ones which are no longer returned
+  /// by the function are removed from the entries kept by the incremental rebuild.
+  dependencies: Mutex<HashSet<DependencyId>>,
+}
+
+impl DynamicEntryPlugin {
+  pub fn new(entry: DynamicEntryFn) -> Self {
+    Self {
+      entry,
+      dependencies: Default::default(),
+    }
+  }
+}
+
+impl std::fmt::Debug for DynamicEntryPlugin {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("DynamicEntryPlugin")
+      .field("entry", &"Fn")
+      .finish()
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for DynamicEntryPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.DynamicEntryPlugin"
+  }
+
+  async fn make(
+    &self,
+    _ctx: PluginContext,
+    compilation: &mut Compilation,
+    param: &mut MakeParam,
+  ) -> PluginMakeHookOutput {
+    let entries = (self.entry)().await?;
+    let previous_dependencies =
+      std::mem::take(&mut *self.dependencies.lock().expect("should lock"));
+    let mut dependencies = HashSet::default();
+
+    // With incremental rebuild, entries of the previous compilation are kept,
+    // the requests which are no longer returned are removed from them.
+    let module_graph = &compilation.module_graph;
+    compilation.entries.retain(|name, entry| {
+      entry.dependencies.retain(|id| {
+        if !previous_dependencies.contains(id) {
+          return true;
+        }
+        let request = module_graph
+          .dependency_by_id(id)
+          .and_then(|dependency| dependency.as_module_dependency())
+          .map(|dependency| dependency.request());
+        let is_returned = entries.iter().any(|(entry_name, description)| {
+          entry_name == name
+            && request.map_or(false, |request| {
+              description.import.iter().any(|import| import == request)
+            })
+        });
+        if is_returned {
+          dependencies.insert(*id);
+        }
+        is_returned
+      });
+      !entry.dependencies.is_empty() || !entry.include_dependencies.is_empty()
+    });
+
+    for (name, description) in entries {
+      // Only requests which are new to the entry are added.
+      let existing_requests = compilation
+        .entries
+        .get(&name)
+        .map(|entry| {
+          entry
+            .dependencies
+            .iter()
+            .filter_map(|id| compilation.module_graph.dependency_by_id(id))
+            .filter_map(|dependency| dependency.as_module_dependency())
+            .map(|dependency| dependency.request().to_string())
+            .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+      let options = description.entry_options();
+      for request in description.import {
+        if existing_requests.contains(&request) {
+          continue;
+        }
+        let dependency: BoxDependency = Box::new(EntryDependency::new(request));
+        let dependency_id = dependency.id();
+        dependencies.insert(*dependency_id);
+        compilation
+          .add_entry(*dependency_id, name.clone(), options.clone())
+          .await?;
+        param.add_force_build_dependency(*dependency_id, None);
+        compilation.module_graph.add_dependency(dependency);
+      }
+    }
+    *self.dependencies.lock().expect("should lock") = dependencies;
+    Ok(())
+  }
+}
@@ -0,0 +1,133 @@
+use rspack_core::{
+  DependencyTemplate, InitFragment, InitFragmentStage, RuntimeGlobals, TemplateContext,
+  TemplateReplaceSource,
+};
+
+/// The kind of the factory of `define()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmdFactory {
+  /// `define(function (a) {})`, called with the dependencies
+  Function,
+  /// `define({ a: 1 })`, which is the exports itself
+  Value,
+  /// `define(factory)`, which is called if it turns out to be a function at runtime
+  Unknown,
+}
+
+/// Rewrite `define([deps], factory)` to call the factory with the required modules,
+/// the items of the dependency array are replaced by their own dependencies.
+#[derive(Debug)]
+pub struct AmdDefineDependency {
+  /// The range of the whole `define()` call
+  range: (u32, u32),
+  /// The range of the dependency array, `None` for `define(factory)`
+  array_range: Option<(u32, u32)>,
+  factory_range: (u32, u32),
+  factory: AmdFactory,
+}
+
+impl AmdDefineDependency {
+  pub fn new(
+    range: (u32, u32),
+    array_range: Option<(u32, u32)>,
+    factory_range: (u32, u32),
+    factory: AmdFactory,
+  ) -> Self {
+    Self {
+      range,
+      array_range,
+      factory_range,
+      factory,
+    }
+  }
+}
+
+impl DependencyTemplate for AmdDefineDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      runtime_requirements,
+      init_fragments,
+      compilation,
+      module,
+      ..
+    } = code_generatable_context;
+
+    let mgm = compilation
+      .module_graph
+      .module_graph_module_by_identifier(&module.identifier())
+      .expect("should have mgm");
+    let module_argument = mgm.get_module_argument();
+    let exports_argument = mgm.get_exports_argument();
+    runtime_requirements.insert(RuntimeGlobals::MODULE);
+
+    // The factory is called with `require, exports, module` if there is no dependency array
+    let call = if self.array_range.is_some() {
+      format!("apply({exports_argument}, __WEBPACK_AMD_DEFINE_ARRAY__)")
+    } else {
+      runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+      format!(
+        "call({exports_argument}, {}, {exports_argument}, {module_argument})",
+        RuntimeGlobals::REQUIRE
+      )
+    };
+    let result = format!(
+      "__WEBPACK_AMD_DEFINE_RESULT__ !== undefined && ({module_argument}.exports = __WEBPACK_AMD_DEFINE_RESULT__)"
+    );
+    let mut vars = vec![];
+    if self.array_range.is_some() {
+      vars.push("__WEBPACK_AMD_DEFINE_ARRAY__");
+    }
+    let (factory_prefix, factory_suffix) = match self.factory {
+      AmdFactory::Function => {
+        vars.push("__WEBPACK_AMD_DEFINE_RESULT__");
+        (
+          "__WEBPACK_AMD_DEFINE_RESULT__ = (".to_string(),
+          format!(").{call}, {result}"),
+        )
+      }
+      AmdFactory::Value => (format!("{module_argument}.exports = ("), ")".to_string()),
+      AmdFactory::Unknown => {
+        vars.push("__WEBPACK_AMD_DEFINE_FACTORY__");
+        vars.push("__WEBPACK_AMD_DEFINE_RESULT__");
+        (
+          "__WEBPACK_AMD_DEFINE_FACTORY__ = (".to_string(),
+          format!("), __WEBPACK_AMD_DEFINE_RESULT__ = (typeof __WEBPACK_AMD_DEFINE_FACTORY__ === 'function' ? (__WEBPACK_AMD_DEFINE_FACTORY__.{call}) : __WEBPACK_AMD_DEFINE_FACTORY__), {result}"),
+        )
+      }
+    };
+    if !vars.is_empty() {
+      init_fragments.push(InitFragment::new(
+        format!("var {};\n", vars.join(", ")),
+        InitFragmentStage::StageConstants,
+        None,
+      ));
+    }
+
+    let (start, end) = self.range;
+    let (factory_start, factory_end) = self.factory_range;
+    match self.array_range {
+      Some((array_start, array_end)) => {
+        source.replace(
+          start,
+          array_start,
+          "!(__WEBPACK_AMD_DEFINE_ARRAY__ = ",
+          None,
+        );
+        source.replace(
+          array_end,
+          factory_start,
+          &format!(", {factory_prefix}"),
+          None,
+        );
+      }
+      None => {
+        source.replace(start, factory_start, &format!("!({factory_prefix}"), None);
+      }
+    }
+    source.replace(factory_end, end, &format!("{factory_suffix})"), None);
+  }
+}
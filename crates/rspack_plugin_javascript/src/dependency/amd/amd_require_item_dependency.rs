@@ -0,0 +1,86 @@
+use rspack_core::{
+  module_id, Dependency, DependencyCategory, DependencyId, DependencyTemplate, DependencyType,
+  ErrorSpan, ModuleDependency, RuntimeGlobals, TemplateContext, TemplateReplaceSource,
+};
+use swc_core::ecma::atoms::JsWord;
+
+/// An item of the dependency array of `define()`, e.g. `"./a"` in `define(["./a"], factory)`.
+#[derive(Debug, Clone)]
+pub struct AmdRequireItemDependency {
+  id: DependencyId,
+  request: JsWord,
+  start: u32,
+  end: u32,
+  span: Option<ErrorSpan>,
+}
+
+impl AmdRequireItemDependency {
+  pub fn new(request: JsWord, span: Option<ErrorSpan>, start: u32, end: u32) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      start,
+      end,
+      span,
+    }
+  }
+}
+
+impl Dependency for AmdRequireItemDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Amd
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::AmdRequireItem
+  }
+}
+
+impl ModuleDependency for AmdRequireItemDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    self.span.as_ref()
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request.into();
+  }
+}
+
+impl DependencyTemplate for AmdRequireItemDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      runtime_requirements,
+      compilation,
+      ..
+    } = code_generatable_context;
+
+    runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+    source.replace(
+      self.start,
+      self.end,
+      format!(
+        "{}({})",
+        RuntimeGlobals::REQUIRE,
+        module_id(compilation, &self.id, &self.request, false).as_str()
+      )
+      .as_str(),
+      None,
+    );
+  }
+}
@@ -0,0 +1,4 @@
+mod amd_define_dependency;
+pub use amd_define_dependency::{AmdDefineDependency, AmdFactory};
+mod amd_require_item_dependency;
+pub use amd_require_item_dependency::AmdRequireItemDependency;
@@ -19,8 +19,12 @@ impl DependencyTemplate for HarmonyCompatibilityDependency {
       init_fragments,
       compilation,
       module,
-      ..
+      concatenation_scope,
     } = code_generatable_context;
+    // The concatenated module marks its own exports
+    if concatenation_scope.is_some() {
+      return;
+    }
     // TODO __esModule is used
     runtime_requirements.insert(RuntimeGlobals::MAKE_NAMESPACE_OBJECT);
     runtime_requirements.insert(RuntimeGlobals::EXPORTS);
@@ -39,20 +43,28 @@ impl DependencyTemplate for HarmonyCompatibilityDependency {
     ));
 
     if compilation.module_graph.is_async(&module.identifier()) {
+      let mgm = compilation
+        .module_graph
+        .module_graph_module_by_identifier(&module.identifier())
+        .expect("should have mgm");
+      let has_top_level_await = mgm
+        .build_meta
+        .as_ref()
+        .map(|meta| meta.has_top_level_await)
+        .unwrap_or_default();
       runtime_requirements.insert(RuntimeGlobals::MODULE);
       runtime_requirements.insert(RuntimeGlobals::ASYNC_MODULE);
       init_fragments.push(InitFragment::new(
         format!(
           "{}({}, async function (__webpack_handle_async_dependencies__, __webpack_async_result__) {{ try {{\n",
           RuntimeGlobals::ASYNC_MODULE,
-          compilation
-            .module_graph
-            .module_graph_module_by_identifier(&module.identifier())
-            .expect("should have mgm")
-            .get_module_argument()
+          mgm.get_module_argument()
         ),
         InitFragmentStage::StageAsyncBoundary,
-        Some("\n__webpack_async_result__();\n} catch(e) { __webpack_async_result__(e); } });".to_string()),
+        Some(format!(
+          "\n__webpack_async_result__();\n}} catch(e) {{ __webpack_async_result__(e); }} }}{});",
+          if has_top_level_await { ", 1" } else { "" }
+        )),
       ));
     }
   }
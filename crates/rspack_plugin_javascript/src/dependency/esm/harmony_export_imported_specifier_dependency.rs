@@ -55,12 +55,15 @@ impl DependencyTemplate for HarmonyExportImportedSpecifierDependency {
       None
     };
 
+    let exports_info = compilation
+      .module_graph
+      .get_exports_info(&module.identifier());
     let mut exports = vec![];
 
     for id in &self.ids {
       if used_exports.is_none() || matches!(used_exports.as_ref(), Some(x) if x.contains(&id.0)) {
         exports.push((
-          id.0.clone(),
+          exports_info.get_used_name(&id.0),
           JsWord::from(export_from_import(
             code_generatable_context,
             true,
@@ -52,8 +52,15 @@ impl DependencyTemplate for HarmonyExportSpecifierDependency {
       init_fragments,
       compilation,
       module,
-      ..
+      concatenation_scope,
     } = code_generatable_context;
+    // The exports are referenced by their local names in a concatenated module
+    if let Some(scope) = concatenation_scope {
+      for (export_name, symbol) in &self.exports {
+        scope.register_export(export_name.clone(), symbol.clone());
+      }
+      return;
+    }
     let exports_argument = compilation
       .module_graph
       .module_graph_module_by_identifier(&module.identifier())
@@ -72,16 +79,19 @@ impl DependencyTemplate for HarmonyExportSpecifierDependency {
       } else {
         None
       };
+      let exports_info = compilation
+        .module_graph
+        .get_exports_info(&module.identifier());
       let exports = self
         .exports
-        .clone()
-        .into_iter()
+        .iter()
         .filter(|s| {
           if let Some(export_map) = &used_exports {
             return export_map.contains(&s.0);
           }
           true
         })
+        .map(|(name, local)| (exports_info.get_used_name(name), local.clone()))
         .collect::<Vec<_>>();
       if !exports.is_empty() {
         runtime_requirements.insert(RuntimeGlobals::DEFINE_PROPERTY_GETTERS);
@@ -70,6 +70,12 @@ impl DependencyTemplate for HarmonyImportDependency {
     {
       return;
     }
+    // The imported module is a part of the same concatenated module
+    if let Some(scope) = &code_generatable_context.concatenation_scope
+      && scope.is_module_in_scope(&ref_mgm.module_identifier)
+    {
+      return;
+    }
 
     if !self.export_all {
       let specifiers = self
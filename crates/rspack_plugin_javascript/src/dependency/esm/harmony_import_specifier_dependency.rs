@@ -56,6 +56,11 @@ impl HarmonyImportSpecifierDependency {
     }
   }
 
+  /// The referenced export and properties, empty for the namespace object
+  pub fn ids(&self) -> &[JsWord] {
+    &self.ids
+  }
+
   // TODO move export_info
   pub fn check_used(&self, reference_mgm: &ModuleGraphModule, compilation: &Compilation) -> bool {
     if compilation.options.builtins.tree_shaking.is_false() {
@@ -140,6 +145,19 @@ impl DependencyTemplate for HarmonyImportSpecifierDependency {
       return;
     }
 
+    if let Some(scope) = &code_generatable_context.concatenation_scope
+      && scope.is_module_in_scope(&reference_mgm.module_identifier)
+    {
+      let reference =
+        scope.create_module_reference(&reference_mgm.module_identifier, &self.ids, self.is_call);
+      if self.shorthand {
+        source.insert(self.end, &format!(": {reference}"), None);
+      } else {
+        source.replace(self.start, self.end, &reference, None)
+      }
+      return;
+    }
+
     let import_var = code_generatable_context
       .compilation
       .module_graph
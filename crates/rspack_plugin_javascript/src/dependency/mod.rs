@@ -1,17 +1,21 @@
+mod amd;
 mod commonjs;
 mod context;
 mod esm;
 mod export_info_api_dep;
 mod hmr;
 mod module_argument_dependency;
+mod provided_dependency;
 mod url;
 mod worker;
+pub use amd::*;
 pub use commonjs::*;
 pub use context::*;
 pub use esm::*;
 pub use export_info_api_dep::*;
 pub use hmr::*;
 pub use module_argument_dependency::*;
+pub use provided_dependency::*;
 pub use worker::*;
 
 pub use self::url::*;
@@ -0,0 +1,122 @@
+use rspack_core::{
+  module_id, property_access, Dependency, DependencyCategory, DependencyId, DependencyTemplate,
+  DependencyType, ErrorSpan, ExportsReferencedType, InitFragment, InitFragmentStage,
+  ModuleDependency, ModuleGraph, ReferencedExport, RuntimeGlobals, RuntimeSpec, TemplateContext,
+  TemplateReplaceSource,
+};
+use swc_core::ecma::atoms::JsWord;
+
+/// A free variable provided by `builtins.provide`, such as `process` or `process.env`.
+/// All the usages of the same variable in a module share one dependency.
+#[derive(Debug, Clone)]
+pub struct ProvidedDependency {
+  id: DependencyId,
+  request: JsWord,
+  /// The name of the local binding, e.g. `__webpack_provided_process_dot_env`
+  identifier: String,
+  /// The export path of the provided module, e.g. `["Buffer"]` for `["buffer", "Buffer"]`
+  ids: Vec<String>,
+  /// `(start, end, shorthand)` of each usage
+  ranges: Vec<(u32, u32, Option<JsWord>)>,
+  span: Option<ErrorSpan>,
+}
+
+impl ProvidedDependency {
+  pub fn new(name: &str, request: JsWord, ids: Vec<String>, span: Option<ErrorSpan>) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      identifier: format!("__webpack_provided_{}", name.replace('.', "_dot_")),
+      ids,
+      ranges: vec![],
+      span,
+    }
+  }
+
+  /// Replace `start..end` with the provided binding. `shorthand` is the key of an
+  /// object shorthand property like `{ process }`, which must be kept.
+  pub fn add_range(&mut self, start: u32, end: u32, shorthand: Option<JsWord>) {
+    self.ranges.push((start, end, shorthand));
+  }
+}
+
+impl Dependency for ProvidedDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::Provided
+  }
+}
+
+impl ModuleDependency for ProvidedDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    self.span.as_ref()
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request.into();
+  }
+
+  fn get_referenced_exports(
+    &self,
+    _module_graph: &ModuleGraph,
+    _runtime: &RuntimeSpec,
+  ) -> ExportsReferencedType {
+    if self.ids.is_empty() {
+      return ExportsReferencedType::Object;
+    }
+    ExportsReferencedType::Value(vec![ReferencedExport::new(
+      self.ids.iter().map(|id| id.as_str().into()).collect(),
+      false,
+    )])
+  }
+}
+
+impl DependencyTemplate for ProvidedDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      runtime_requirements,
+      init_fragments,
+      compilation,
+      ..
+    } = code_generatable_context;
+
+    runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+    init_fragments.push(InitFragment::new(
+      format!(
+        "/* provided dependency */ var {} = {}({}){};\n",
+        self.identifier,
+        RuntimeGlobals::REQUIRE,
+        module_id(compilation, &self.id, &self.request, false),
+        property_access(&self.ids, 0)
+      ),
+      InitFragmentStage::StageProvides,
+      None,
+    ));
+    for (start, end, shorthand) in &self.ranges {
+      let content = match shorthand {
+        Some(key) => format!("{key}: {}", self.identifier),
+        None => self.identifier.clone(),
+      };
+      source.replace(*start, *end, &content, None);
+    }
+  }
+}
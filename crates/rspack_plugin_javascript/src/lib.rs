@@ -17,6 +17,8 @@ use swc_ecma_minifier::option::{
 };
 
 pub use crate::plugin::infer_async_modules_plugin::InferAsyncModulesPlugin;
+pub use crate::plugin::mangle_exports_plugin::MangleExportsPlugin;
+pub use crate::plugin::module_concatenation_plugin::ModuleConcatenationPlugin;
 
 #[derive(Debug)]
 pub struct TransformOutput {
@@ -9,11 +9,15 @@ use rspack_core::{
   GenerateContext, Module, ParseContext, ParseResult, ParserAndGenerator, SourceType,
   TemplateContext,
 };
-use rspack_error::{internal_error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_error::{
+  errors_to_diagnostics, internal_error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray,
+};
 
 use crate::runtime::render_init_fragments;
 use crate::utils::syntax_by_module_type;
-use crate::visitors::{run_before_pass, scan_dependencies, swc_visitor::resolver};
+use crate::visitors::{
+  run_before_pass, scan_dependencies, swc_visitor::resolver, ScanDependenciesResult,
+};
 #[derive(Debug)]
 pub struct JavaScriptParserAndGenerator;
 
@@ -27,6 +31,10 @@ impl JavaScriptParserAndGenerator {
 static SOURCE_TYPES: &[SourceType; 1] = &[SourceType::JavaScript];
 
 impl ParserAndGenerator for JavaScriptParserAndGenerator {
+  fn name(&self) -> &'static str {
+    "javascript"
+  }
+
   fn source_types(&self) -> &[SourceType] {
     SOURCE_TYPES
   }
@@ -44,6 +52,7 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
       build_info,
       build_meta,
       module_identifier,
+      js_parser_handlers,
       ..
     } = parse_context;
 
@@ -114,9 +123,14 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
       ));
     });
 
-    let (dependencies, presentational_dependencies) = scan_ast.visit(|program, context| {
+    let ScanDependenciesResult {
+      dependencies,
+      presentational_dependencies,
+      errors,
+    } = scan_ast.visit(|program, context| {
       scan_dependencies(
         program,
+        &output.code,
         context.unresolved_mark,
         resource_data,
         compiler_options,
@@ -124,6 +138,7 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
         build_info,
         build_meta,
         module_identifier,
+        js_parser_handlers,
       )
     });
 
@@ -138,6 +153,9 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
     } else {
       OptimizeAnalyzeResult::default()
     };
+    if compiler_options.optimization.side_effects.is_true() {
+      build_meta.side_effect_free = analyze_result.side_effect_free();
+    }
 
     let source = if let Some(map) = output.map {
       SourceMapSource::new(WithoutOriginalOptions {
@@ -157,7 +175,7 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
         presentational_dependencies,
         analyze_result,
       }
-      .with_empty_diagnostic(),
+      .with_diagnostic(errors_to_diagnostics(errors)),
     )
   }
 
@@ -180,6 +198,7 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
         module,
         runtime_requirements: generate_context.runtime_requirements,
         init_fragments: &mut init_fragments,
+        concatenation_scope: generate_context.concatenation_scope.as_deref_mut(),
       };
 
       let mgm = compilation
@@ -0,0 +1,456 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+use std::path::Path;
+
+use indexmap::IndexMap;
+use rspack_core::rspack_sources::{
+  BoxSource, ConcatSource, RawSource, ReplaceSource, Source, SourceExt,
+};
+use rspack_core::{
+  BoxModule, CodeGenerationResult, Compilation, ConcatenationScope, Context, LibIdentOptions,
+  Module, ModuleIdentifier, ModuleReference, ModuleType, NormalModule, RuntimeGlobals, SourceType,
+  SpanExt,
+};
+use rspack_error::{internal_error, Result};
+use rspack_identifier::{Identifiable, IdentifierMap};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use swc_core::common::SyntaxContext;
+use swc_core::ecma::ast::{
+  AssignPatProp, Ident, MemberProp, ObjectPatProp, Prop, PropName, SuperProp,
+};
+use swc_core::ecma::atoms::JsWord;
+use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
+
+use crate::dependency::format_exports;
+use crate::utils::syntax_by_module_type;
+use crate::visitors::swc_visitor::resolver;
+
+/// The ECMAScript modules which are generated in a single scope, i.e. scope hoisting.
+///
+/// The concatenated module takes the place of its root module, the other modules are only
+/// referenced by the modules in the same concatenated module.
+///
+/// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/optimize/ConcatenatedModule.js
+#[derive(Debug)]
+pub struct ConcatenatedModule {
+  root: BoxModule,
+  /// The modules in the evaluation order, the root module is the last one
+  modules: Vec<ModuleIdentifier>,
+  size: f64,
+}
+
+impl ConcatenatedModule {
+  pub fn new(root: BoxModule, modules: Vec<ModuleIdentifier>, size: f64) -> Self {
+    Self {
+      root,
+      modules,
+      size,
+    }
+  }
+
+  pub fn modules(&self) -> &[ModuleIdentifier] {
+    &self.modules
+  }
+
+  pub fn into_root(self) -> BoxModule {
+    self.root
+  }
+
+  fn get_module<'a>(
+    &'a self,
+    compilation: &'a Compilation,
+    identifier: &ModuleIdentifier,
+  ) -> Result<&'a NormalModule> {
+    let module = if *identifier == self.root.identifier() {
+      &self.root
+    } else {
+      compilation
+        .module_graph
+        .module_by_identifier(identifier)
+        .ok_or_else(|| internal_error!("Failed to get the concatenated module {identifier}"))?
+    };
+    module
+      .as_normal_module()
+      .ok_or_else(|| internal_error!("Failed to concatenate module {identifier}"))
+  }
+}
+
+impl Identifiable for ConcatenatedModule {
+  fn identifier(&self) -> ModuleIdentifier {
+    self.root.identifier()
+  }
+}
+
+#[async_trait::async_trait]
+impl Module for ConcatenatedModule {
+  fn module_type(&self) -> &ModuleType {
+    self.root.module_type()
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    self.root.source_types()
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    self.root.original_source()
+  }
+
+  fn readable_identifier(&self, context: &Context) -> Cow<str> {
+    Cow::Owned(format!(
+      "{} + {} modules",
+      self.root.readable_identifier(context),
+      self.modules.len() - 1
+    ))
+  }
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    self.size
+  }
+
+  fn code_generation(&self, compilation: &Compilation) -> Result<CodeGenerationResult> {
+    let module_indices: IdentifierMap<usize> = self
+      .modules
+      .iter()
+      .enumerate()
+      .map(|(index, module)| (*module, index))
+      .collect();
+    let mut runtime_requirements = RuntimeGlobals::default();
+    let infos = self
+      .modules
+      .iter()
+      .map(|identifier| {
+        let module = self.get_module(compilation, identifier)?;
+        let mut scope = ConcatenationScope::new(module_indices.clone());
+        let source =
+          module.generate_concatenated(compilation, &mut runtime_requirements, &mut scope)?;
+        ConcatenatedModuleInfo::new(
+          module
+            .readable_identifier(&compilation.options.context)
+            .to_string(),
+          module.module_type(),
+          source,
+          scope.take_exports(),
+        )
+      })
+      .collect::<Result<Vec<_>>>()?;
+
+    let mgm = compilation
+      .module_graph
+      .module_graph_module_by_identifier(&self.identifier())
+      .expect("should have mgm");
+    let exports_argument = mgm.get_exports_argument().to_string();
+    let renames = rename_top_level_bindings(
+      &infos,
+      [
+        RuntimeGlobals::REQUIRE.to_string(),
+        exports_argument.clone(),
+        mgm.get_module_argument().to_string(),
+      ],
+    );
+
+    let mut source = ConcatSource::default();
+    runtime_requirements.insert(RuntimeGlobals::MAKE_NAMESPACE_OBJECT);
+    runtime_requirements.insert(RuntimeGlobals::EXPORTS);
+    source.add(RawSource::from(format!(
+      "'use strict';\n{}({exports_argument});\n",
+      RuntimeGlobals::MAKE_NAMESPACE_OBJECT
+    )));
+    let root_index = infos.len() - 1;
+    let exports = infos[root_index]
+      .exports
+      .iter()
+      .map(|(name, local)| (name.clone(), get_final_name(&renames[root_index], local)))
+      .collect::<Vec<_>>();
+    if !exports.is_empty() {
+      runtime_requirements.insert(RuntimeGlobals::DEFINE_PROPERTY_GETTERS);
+      source.add(RawSource::from(format!(
+        "{}({exports_argument}, {});\n",
+        RuntimeGlobals::DEFINE_PROPERTY_GETTERS,
+        format_exports(&exports)
+      )));
+    }
+
+    for (info, rename) in infos.iter().zip(&renames) {
+      let mut module_source = ReplaceSource::new(info.source.clone());
+      for (name, occurrences) in &info.top_level {
+        let Some(new_name) = rename.get(name) else {
+          continue;
+        };
+        for occurrence in occurrences {
+          let content = if occurrence.shorthand {
+            format!("{name}: {new_name}")
+          } else {
+            new_name.to_string()
+          };
+          module_source.replace(occurrence.start, occurrence.end, &content, None);
+        }
+      }
+      for (name, start, end) in &info.references {
+        let reference = ConcatenationScope::match_module_reference(name)
+          .ok_or_else(|| internal_error!("Failed to match the module reference {name}"))?;
+        let content = render_reference(&infos, &renames, &reference)?;
+        module_source.replace(*start, *end, &content, None);
+      }
+      source.add(RawSource::from(format!(
+        "\n;// CONCATENATED MODULE: {}\n",
+        info.name
+      )));
+      source.add(module_source);
+      source.add(RawSource::from("\n"));
+    }
+
+    let mut code_generation_result = CodeGenerationResult::default();
+    code_generation_result
+      .runtime_requirements
+      .insert(runtime_requirements);
+    code_generation_result.add(SourceType::JavaScript, source.boxed());
+    code_generation_result.set_hash(
+      &compilation.options.output.hash_function,
+      &compilation.options.output.hash_digest,
+      &compilation.options.output.hash_salt,
+    );
+    Ok(code_generation_result)
+  }
+
+  fn name_for_condition(&self) -> Option<Box<str>> {
+    self.root.name_for_condition()
+  }
+
+  fn lib_ident(&self, options: LibIdentOptions) -> Option<Cow<str>> {
+    self.root.lib_ident(options)
+  }
+}
+
+impl Hash for ConcatenatedModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__ConcatenatedModule".hash(state);
+    self.identifier().hash(state);
+    self.modules.hash(state);
+  }
+}
+
+impl PartialEq for ConcatenatedModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier() == other.identifier()
+  }
+}
+
+impl Eq for ConcatenatedModule {}
+
+/// A module in the concatenated module, analyzed from its generated source
+struct ConcatenatedModuleInfo {
+  name: String,
+  source: BoxSource,
+  /// The exports and their local names
+  exports: Vec<(JsWord, JsWord)>,
+  /// The top level bindings and their occurrences
+  top_level: IndexMap<JsWord, Vec<Occurrence>>,
+  /// The placeholders of the references to the other modules and their ranges
+  references: Vec<(JsWord, u32, u32)>,
+  /// All the variable names, including the free variables and the ones in the nested scopes
+  names: HashSet<JsWord>,
+}
+
+impl ConcatenatedModuleInfo {
+  fn new(
+    name: String,
+    module_type: &ModuleType,
+    source: BoxSource,
+    exports: Vec<(JsWord, JsWord)>,
+  ) -> Result<Self> {
+    // The TypeScript is already stripped from the generated source
+    let module_type = if module_type.is_jsx_like() {
+      ModuleType::Jsx
+    } else {
+      ModuleType::Js
+    };
+    let syntax = syntax_by_module_type(Path::new(&name), &module_type, false);
+    let mut ast = crate::ast::parse(
+      source.source().to_string(),
+      syntax,
+      &name,
+      &ModuleType::JsEsm,
+    )?;
+    ast.transform(|program, context| {
+      program.visit_mut_with(&mut resolver(
+        context.unresolved_mark,
+        context.top_level_mark,
+        false,
+      ));
+    });
+    let collector = ast.visit(|program, context| {
+      let mut collector = IdentCollector {
+        top_level_ctxt: SyntaxContext::empty().apply_mark(context.top_level_mark),
+        unresolved_ctxt: SyntaxContext::empty().apply_mark(context.unresolved_mark),
+        top_level: Default::default(),
+        references: vec![],
+        names: Default::default(),
+      };
+      program.visit_with(&mut collector);
+      collector
+    });
+    Ok(Self {
+      name,
+      source,
+      exports,
+      top_level: collector.top_level,
+      references: collector.references,
+      names: collector.names,
+    })
+  }
+}
+
+/// An occurrence of a top level binding
+struct Occurrence {
+  start: u32,
+  end: u32,
+  /// `{ a }` of an object literal or an object pattern, which is renamed to `{ a: b }`
+  shorthand: bool,
+}
+
+struct IdentCollector {
+  top_level_ctxt: SyntaxContext,
+  unresolved_ctxt: SyntaxContext,
+  top_level: IndexMap<JsWord, Vec<Occurrence>>,
+  references: Vec<(JsWord, u32, u32)>,
+  names: HashSet<JsWord>,
+}
+
+impl IdentCollector {
+  fn add_ident(&mut self, ident: &Ident, shorthand: bool) {
+    if ident.span.ctxt == self.top_level_ctxt {
+      self
+        .top_level
+        .entry(ident.sym.clone())
+        .or_default()
+        .push(Occurrence {
+          start: ident.span.real_lo(),
+          end: ident.span.real_hi(),
+          shorthand,
+        });
+    } else if ident.span.ctxt == self.unresolved_ctxt
+      && ConcatenationScope::is_module_reference(&ident.sym)
+    {
+      self.references.push((
+        ident.sym.clone(),
+        ident.span.real_lo(),
+        ident.span.real_hi(),
+      ));
+      return;
+    }
+    self.names.insert(ident.sym.clone());
+  }
+}
+
+impl Visit for IdentCollector {
+  noop_visit_type!();
+
+  fn visit_ident(&mut self, ident: &Ident) {
+    self.add_ident(ident, false);
+  }
+
+  // The property names aren't variables
+  fn visit_member_prop(&mut self, prop: &MemberProp) {
+    if let MemberProp::Computed(computed) = prop {
+      computed.visit_with(self);
+    }
+  }
+
+  fn visit_super_prop(&mut self, prop: &SuperProp) {
+    if let SuperProp::Computed(computed) = prop {
+      computed.visit_with(self);
+    }
+  }
+
+  fn visit_prop_name(&mut self, name: &PropName) {
+    if let PropName::Computed(computed) = name {
+      computed.visit_with(self);
+    }
+  }
+
+  fn visit_prop(&mut self, prop: &Prop) {
+    match prop {
+      Prop::Shorthand(ident) => self.add_ident(ident, true),
+      _ => prop.visit_children_with(self),
+    }
+  }
+
+  fn visit_object_pat_prop(&mut self, prop: &ObjectPatProp) {
+    match prop {
+      ObjectPatProp::Assign(AssignPatProp { key, value, .. }) => {
+        self.add_ident(key, true);
+        value.visit_with(self);
+      }
+      _ => prop.visit_children_with(self),
+    }
+  }
+}
+
+/// Rename the top level bindings which conflict with the names of the other modules, returns
+/// the new names of each module.
+fn rename_top_level_bindings(
+  infos: &[ConcatenatedModuleInfo],
+  reserved_names: impl IntoIterator<Item = String>,
+) -> Vec<HashMap<JsWord, JsWord>> {
+  let mut name_counts: HashMap<&JsWord, usize> = HashMap::default();
+  for info in infos {
+    for name in &info.names {
+      *name_counts.entry(name).or_default() += 1;
+    }
+  }
+  let all_names: HashSet<&JsWord> = name_counts.keys().copied().collect();
+  let mut used_names: HashSet<JsWord> = reserved_names.into_iter().map(JsWord::from).collect();
+
+  infos
+    .iter()
+    .map(|info| {
+      let mut renames = HashMap::default();
+      for name in info.top_level.keys() {
+        // A name in the other modules may be shadowed by this one
+        let conflicted = used_names.contains(name) || name_counts.get(name).copied() > Some(1);
+        if !conflicted {
+          used_names.insert(name.clone());
+          continue;
+        }
+        let new_name = (1..)
+          .map(|i| JsWord::from(format!("{name}_{i}")))
+          .find(|new_name| !used_names.contains(new_name) && !all_names.contains(new_name))
+          .expect("should find a new name");
+        used_names.insert(new_name.clone());
+        renames.insert(name.clone(), new_name);
+      }
+      renames
+    })
+    .collect()
+}
+
+fn get_final_name(renames: &HashMap<JsWord, JsWord>, name: &JsWord) -> JsWord {
+  renames.get(name).unwrap_or(name).clone()
+}
+
+fn render_reference(
+  infos: &[ConcatenatedModuleInfo],
+  renames: &[HashMap<JsWord, JsWord>],
+  reference: &ModuleReference,
+) -> Result<String> {
+  let info = infos
+    .get(reference.index)
+    .ok_or_else(|| internal_error!("Failed to find module {} of the reference", reference.index))?;
+  let Some((export_name, properties)) = reference.ids.split_first() else {
+    return Err(internal_error!(
+      "The namespace object of the concatenated module {} is unsupported",
+      info.name
+    ));
+  };
+  let Some((_, local)) = info.exports.iter().find(|(name, _)| name == export_name) else {
+    return Ok(format!("/* \"{export_name}\" not found */undefined"));
+  };
+  let properties = properties
+    .iter()
+    .map(|property| format!(r#"["{property}"]"#))
+    .collect::<String>();
+  Ok(format!(
+    "{}{properties}",
+    get_final_name(&renames[reference.index], local)
+  ))
+}
@@ -8,17 +8,20 @@ use rspack_core::rspack_sources::{
   SourceMapSourceOptions,
 };
 use rspack_core::{
-  get_js_chunk_filename_template, AdditionalChunkRuntimeRequirementsArgs, AssetInfo, ChunkHashArgs,
-  ChunkKind, CompilationAsset, JsChunkHashArgs, ModuleType, ParserAndGenerator, PathData, Plugin,
-  PluginAdditionalChunkRuntimeRequirementsOutput, PluginChunkHashHookOutput, PluginContext,
-  PluginJsChunkHashHookOutput, PluginProcessAssetsOutput, PluginRenderManifestHookOutput,
-  ProcessAssetsArgs, RenderManifestEntry, RuntimeGlobals, SourceType,
+  get_exports_type, get_js_chunk_filename_template, missing_named_export_message,
+  tree_shaking::symbol::DEFAULT_JS_WORD, AdditionalChunkRuntimeRequirementsArgs, AssetInfo,
+  ChunkHashArgs, ChunkKind, Compilation, CompilationAsset, ExportsType, JsChunkHashArgs,
+  ModuleType, ParserAndGenerator, PathData, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput,
+  PluginChunkHashHookOutput, PluginContext, PluginJsChunkHashHookOutput, PluginProcessAssetsOutput,
+  PluginRenderManifestHookOutput, ProcessAssetsArgs, RenderManifestEntry, RuntimeGlobals,
+  SourceType,
 };
 use rspack_error::{internal_error, Diagnostic, Result};
 use rspack_hash::RspackHash;
 use swc_config::config_types::BoolOrDataConfig;
 use swc_ecma_minifier::option::terser::TerserCompressorOptions;
 
+use crate::dependency::HarmonyImportSpecifierDependency;
 use crate::parser_and_generator::JavaScriptParserAndGenerator;
 use crate::{JsMinifyCommentOption, JsMinifyFormatOptions, JsMinifyOptions, JsPlugin};
 
@@ -65,6 +68,57 @@ impl Plugin for JsPlugin {
     Ok(())
   }
 
+  /// Importing a named export from a default-only module in a strict ESM module throws at
+  /// runtime, report it at build time as well.
+  async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
+    let module_graph = &compilation.module_graph;
+    let mut diagnostics = vec![];
+    for mgm in module_graph.module_graph_modules().values() {
+      if !mgm.get_strict_harmony_module() {
+        continue;
+      }
+      for dependency_id in mgm.dependencies.iter() {
+        let Some(dependency) = module_graph
+          .dependency_by_id(dependency_id)
+          .and_then(|dependency| dependency.downcast_ref::<HarmonyImportSpecifierDependency>())
+        else {
+          continue;
+        };
+        let Some(export_name) = dependency.ids().first() else {
+          continue;
+        };
+        if *export_name == DEFAULT_JS_WORD
+          || module_graph
+            .module_identifier_by_dependency_id(dependency_id)
+            .is_none()
+        {
+          continue;
+        }
+        if matches!(
+          get_exports_type(module_graph, dependency_id, &mgm.module_identifier),
+          ExportsType::DefaultOnly
+        ) {
+          diagnostics.push(
+            Diagnostic::warn(
+              "Missing export".to_string(),
+              missing_named_export_message(
+                compilation,
+                dependency_id,
+                &mgm.module_identifier,
+                export_name,
+              ),
+              0,
+              0,
+            )
+            .with_module_identifier(Some(mgm.module_identifier.to_string())),
+          );
+        }
+      }
+    }
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+
   async fn chunk_hash(
     &self,
     _ctx: PluginContext,
@@ -0,0 +1,216 @@
+use rspack_core::{
+  number_to_identifier, BuildMetaExportsType, Compilation, ModuleIdentifier, Plugin,
+  NUMBER_OF_IDENTIFIER_CONTINUATION_CHARS, NUMBER_OF_IDENTIFIER_START_CHARS,
+};
+use rspack_error::Result;
+use rspack_identifier::IdentifierSet;
+use rspack_util::number_hash::get_number_hash;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use swc_core::ecma::atoms::JsWord;
+
+use crate::dependency::{
+  HarmonyExportImportedSpecifierDependency, HarmonyImportDependency,
+  HarmonyImportSpecifierDependency,
+};
+use crate::plugin::concatenated_module::ConcatenatedModule;
+
+/// Rename the used exports of the ECMAScript modules to short names, i.e. `optimization.mangleExports`.
+///
+/// The names are kept if the module is an entry, is referenced by the modules which aren't ECMAScript
+/// modules, or its namespace object is used as a whole, e.g. `ns[key]` and `export * from`.
+///
+/// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/optimize/MangleExportsPlugin.js
+#[derive(Debug)]
+pub struct MangleExportsPlugin {
+  /// Assign the names by hashing the export names, which keeps the names stable when the other
+  /// exports change, otherwise the most referenced exports get the shortest names
+  deterministic: bool,
+}
+
+impl MangleExportsPlugin {
+  pub fn new(deterministic: bool) -> Self {
+    Self { deterministic }
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for MangleExportsPlugin {
+  fn name(&self) -> &'static str {
+    "MangleExportsPlugin"
+  }
+
+  async fn optimize_code_generation(&self, compilation: &mut Compilation) -> Result<()> {
+    // The used exports are only collected by tree shaking
+    if !compilation.options.builtins.tree_shaking.is_true() {
+      return Ok(());
+    }
+
+    // The concatenated modules render their exports by the local names
+    let mut concatenated = IdentifierSet::default();
+    for (identifier, module) in compilation.module_graph.modules() {
+      if let Some(module) = module.downcast_ref::<ConcatenatedModule>() {
+        concatenated.insert(*identifier);
+        concatenated.extend(module.modules().iter().copied());
+      }
+    }
+
+    let mut renames = vec![];
+    for identifier in compilation.module_graph.modules().keys() {
+      if concatenated.contains(identifier) {
+        continue;
+      }
+      let Some(references) = get_export_references(compilation, identifier) else {
+        continue;
+      };
+      let names = self.assign_names(references);
+      if !names.is_empty() {
+        renames.push((*identifier, names));
+      }
+    }
+
+    for (identifier, names) in renames {
+      let mgm = compilation
+        .module_graph
+        .module_graph_module_by_identifier_mut(&identifier)
+        .expect("should have mgm");
+      for (name, used_name) in names {
+        if let Some(export_info) = mgm.exports.exports.get_mut(&name) {
+          export_info.set_used_name(used_name);
+        }
+      }
+    }
+    Ok(())
+  }
+}
+
+impl MangleExportsPlugin {
+  fn assign_names(&self, references: HashMap<JsWord, usize>) -> Vec<(JsWord, JsWord)> {
+    // The short names are kept as they are
+    let mut used_names = references
+      .keys()
+      .filter(|name| name.len() <= 2)
+      .map(|name| name.to_string())
+      .collect::<HashSet<_>>();
+    let mut mangleable = references
+      .into_iter()
+      .filter(|(name, _)| name.len() > 2)
+      .collect::<Vec<_>>();
+
+    let mut result = vec![];
+    if self.deterministic {
+      mangleable.sort_by(|a, b| a.0.cmp(&b.0));
+      // Leave enough space to keep the conflicts rare, start from the names of one char
+      let optimal_range = mangleable.len() * 20 + used_names.len();
+      let mut range = NUMBER_OF_IDENTIFIER_START_CHARS as usize;
+      while range < optimal_range {
+        range *= NUMBER_OF_IDENTIFIER_CONTINUATION_CHARS as usize;
+      }
+      for (name, _) in mangleable {
+        let mut salt = 0;
+        let used_name = loop {
+          let id = get_number_hash(&format!("{name}{salt}"), range);
+          let used_name = number_to_identifier(id as u32);
+          if used_names.insert(used_name.clone()) {
+            break used_name;
+          }
+          salt += 1;
+        };
+        result.push((name, JsWord::from(used_name)));
+      }
+    } else {
+      mangleable.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+      let mut id = 0;
+      for (name, _) in mangleable {
+        let used_name = loop {
+          let used_name = number_to_identifier(id);
+          id += 1;
+          if used_names.insert(used_name.clone()) {
+            break used_name;
+          }
+        };
+        result.push((name, JsWord::from(used_name)));
+      }
+    }
+    result
+  }
+}
+
+/// The used exports of the module with the number of references, `None` if the names must be kept.
+fn get_export_references(
+  compilation: &Compilation,
+  identifier: &ModuleIdentifier,
+) -> Option<HashMap<JsWord, usize>> {
+  let module_graph = &compilation.module_graph;
+  if compilation.entry_module_identifiers.contains(identifier)
+    || compilation
+      .bailout_module_identifiers
+      .contains_key(identifier)
+  {
+    return None;
+  }
+  let mgm = module_graph.module_graph_module_by_identifier(identifier)?;
+  if !mgm.module_type.is_js_like()
+    || !matches!(
+      mgm.build_meta.as_ref()?.exports_type,
+      BuildMetaExportsType::Namespace
+    )
+  {
+    return None;
+  }
+  // The exports of the other modules aren't rendered by the export dependencies
+  module_graph
+    .module_by_identifier(identifier)?
+    .as_normal_module()?;
+
+  // The names of `export * from` may conflict with the mangled names
+  let export_star = mgm.dependencies.iter().any(|dependency_id| {
+    module_graph
+      .dependency_by_id(dependency_id)
+      .and_then(|dependency| dependency.downcast_ref::<HarmonyImportDependency>())
+      .map_or(false, |dependency| dependency.export_all)
+  });
+  if export_star {
+    return None;
+  }
+
+  let mut references: HashMap<JsWord, usize> = mgm
+    .exports
+    .exports
+    .keys()
+    .map(|name| (name.clone(), 0))
+    .collect();
+  for connection in mgm.incoming_connections_unordered(module_graph).ok()? {
+    // Referenced by an entry
+    if connection.original_module_identifier.is_none() {
+      return None;
+    }
+    let dependency = module_graph.dependency_by_id(&connection.dependency_id)?;
+    let referenced =
+      if let Some(dependency) = dependency.downcast_ref::<HarmonyImportSpecifierDependency>() {
+        // The namespace object is used as a whole
+        vec![dependency.ids().first()?.clone()]
+      } else if let Some(dependency) = dependency.downcast_ref::<HarmonyImportDependency>() {
+        if dependency.export_all {
+          return None;
+        }
+        vec![]
+      } else if let Some(dependency) =
+        dependency.downcast_ref::<HarmonyExportImportedSpecifierDependency>()
+      {
+        // `export * as ns from` re-exports the namespace object
+        dependency
+          .ids
+          .iter()
+          .map(|(_, id)| id.clone())
+          .collect::<Option<Vec<_>>>()?
+      } else {
+        return None;
+      };
+    for name in referenced {
+      if let Some(count) = references.get_mut(&name) {
+        *count += 1;
+      }
+    }
+  }
+  Some(references)
+}
@@ -1,5 +1,8 @@
+pub mod concatenated_module;
 pub mod impl_plugin_for_js_plugin;
 pub mod infer_async_modules_plugin;
+pub mod mangle_exports_plugin;
+pub mod module_concatenation_plugin;
 pub mod side_effects_flag_plugin;
 
 use std::hash::Hash;
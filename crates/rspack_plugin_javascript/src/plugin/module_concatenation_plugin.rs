@@ -0,0 +1,417 @@
+use rspack_core::{
+  BuildMetaExportsType, Compilation, CompilationArgs, DependencyType, ModuleGraph,
+  ModuleIdentifier, NormalModuleSource, OptimizeChunksArgs, Plugin, PluginCompilationHookOutput,
+  SourceType,
+};
+use rspack_error::Result;
+use rspack_identifier::IdentifierSet;
+
+use crate::dependency::HarmonyImportSpecifierDependency;
+use crate::plugin::concatenated_module::ConcatenatedModule;
+
+/// Concatenate the ECMAScript modules into the module which imports them, i.e. scope hoisting.
+///
+/// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/optimize/ModuleConcatenationPlugin.js
+#[derive(Debug)]
+pub struct ModuleConcatenationPlugin;
+
+enum AddResult {
+  Added,
+  /// The module may be added once the other modules are added
+  Retry(String),
+  Failed(String),
+}
+
+#[async_trait::async_trait]
+impl Plugin for ModuleConcatenationPlugin {
+  fn name(&self) -> &'static str {
+    "ModuleConcatenationPlugin"
+  }
+
+  async fn compilation(&self, args: CompilationArgs<'_>) -> PluginCompilationHookOutput {
+    // The module graph is reused by the rebuild, restore the concatenated modules to be rebuilt
+    let module_graph = &mut args.compilation.module_graph;
+    let concatenated = module_graph
+      .modules()
+      .iter()
+      .filter(|(_, module)| module.downcast_ref::<ConcatenatedModule>().is_some())
+      .map(|(identifier, _)| *identifier)
+      .collect::<Vec<_>>();
+    for identifier in concatenated {
+      let module = module_graph
+        .modules_mut()
+        .remove(&identifier)
+        .expect("should have module");
+      let module = module
+        .downcast::<ConcatenatedModule>()
+        .expect("should be a concatenated module");
+      module_graph
+        .modules_mut()
+        .insert(identifier, module.into_root());
+    }
+    for mgm in module_graph
+      .module_identifier_to_module_graph_module
+      .values_mut()
+    {
+      mgm.optimization_bailout.clear();
+    }
+    Ok(())
+  }
+
+  async fn optimize_chunk_modules(&self, args: OptimizeChunksArgs<'_>) -> Result<()> {
+    let compilation = args.compilation;
+
+    let mut relevant_modules = vec![];
+    let mut bailouts = vec![];
+    for identifier in compilation.module_graph.modules().keys() {
+      match get_bailout_reason(compilation, identifier) {
+        Some(reason) => bailouts.push((*identifier, reason)),
+        None => relevant_modules.push(*identifier),
+      }
+    }
+    for (identifier, reason) in bailouts {
+      add_bailout(&mut compilation.module_graph, &identifier, reason);
+    }
+    let relevant_set: IdentifierSet = relevant_modules.iter().copied().collect();
+
+    // The outer modules are visited first to make the concatenated modules as large as possible
+    relevant_modules.sort_by_key(|identifier| {
+      compilation
+        .module_graph
+        .get_pre_order_index(identifier)
+        .unwrap_or(u32::MAX)
+    });
+
+    let mut used_modules = IdentifierSet::default();
+    let mut concatenations = vec![];
+    for root in relevant_modules {
+      if !used_modules.insert(root) {
+        continue;
+      }
+      let mut group = IdentifierSet::from_iter([root]);
+      let mut candidates = esm_import_targets(&compilation.module_graph, &root);
+      let mut failed = IdentifierSet::default();
+      let mut failures = vec![];
+      // The candidates waiting for the others are retried until nothing is added
+      loop {
+        let mut progress = false;
+        let mut waiting: Vec<(ModuleIdentifier, String)> = vec![];
+        let mut queue = std::mem::take(&mut candidates);
+        let mut index = 0;
+        while index < queue.len() {
+          let candidate = queue[index];
+          index += 1;
+          if group.contains(&candidate)
+            || failed.contains(&candidate)
+            || waiting.iter().any(|(module, _)| *module == candidate)
+          {
+            continue;
+          }
+          match try_to_add(
+            compilation,
+            &root,
+            &candidate,
+            &group,
+            &relevant_set,
+            &used_modules,
+          ) {
+            AddResult::Added => {
+              group.insert(candidate);
+              queue.extend(esm_import_targets(&compilation.module_graph, &candidate));
+              progress = true;
+            }
+            AddResult::Retry(reason) => waiting.push((candidate, reason)),
+            AddResult::Failed(reason) => {
+              failed.insert(candidate);
+              failures.push((candidate, reason));
+            }
+          }
+        }
+        if !progress {
+          failures.extend(waiting);
+          break;
+        }
+        candidates = waiting.into_iter().map(|(module, _)| module).collect();
+      }
+
+      for (candidate, reason) in failures {
+        let name = readable_identifier(compilation, &candidate);
+        add_bailout(
+          &mut compilation.module_graph,
+          &root,
+          format!("Cannot concat with {name}: {reason}"),
+        );
+      }
+      if group.len() > 1 {
+        let modules = evaluation_order(&compilation.module_graph, &root, &group);
+        used_modules.extend(modules.iter().copied());
+        concatenations.push(modules);
+      }
+    }
+
+    for modules in concatenations {
+      let root = *modules.last().expect("should have the root module");
+      for inner in &modules[..modules.len() - 1] {
+        let chunks = compilation.chunk_graph.get_module_chunks(*inner).clone();
+        for chunk in chunks {
+          compilation
+            .chunk_graph
+            .disconnect_chunk_and_module(&chunk, *inner);
+        }
+      }
+      let size = modules
+        .iter()
+        .filter_map(|identifier| compilation.module_graph.module_by_identifier(identifier))
+        .map(|module| module.size(&SourceType::JavaScript))
+        .sum();
+      let root_module = compilation
+        .module_graph
+        .modules_mut()
+        .remove(&root)
+        .expect("should have module");
+      compilation.module_graph.modules_mut().insert(
+        root,
+        Box::new(ConcatenatedModule::new(root_module, modules, size)),
+      );
+    }
+    Ok(())
+  }
+}
+
+fn add_bailout(module_graph: &mut ModuleGraph, module: &ModuleIdentifier, reason: String) {
+  if let Some(mgm) = module_graph.module_graph_module_by_identifier_mut(module) {
+    mgm
+      .optimization_bailout
+      .push(format!("ModuleConcatenation bailout: {reason}"));
+  }
+}
+
+fn readable_identifier(compilation: &Compilation, module: &ModuleIdentifier) -> String {
+  compilation
+    .module_graph
+    .module_by_identifier(module)
+    .map(|module| {
+      module
+        .readable_identifier(&compilation.options.context)
+        .to_string()
+    })
+    .unwrap_or_else(|| module.to_string())
+}
+
+/// Returns why the module can't be a part of any concatenated module.
+fn get_bailout_reason(compilation: &Compilation, identifier: &ModuleIdentifier) -> Option<String> {
+  let module_graph = &compilation.module_graph;
+  let module = module_graph.module_by_identifier(identifier)?;
+  let mgm = module_graph.module_graph_module_by_identifier(identifier)?;
+  let Some(normal_module) = module.as_normal_module() else {
+    return Some("Module is not an ECMAScript module".to_string());
+  };
+  if !matches!(
+    mgm.build_meta.as_ref().map(|meta| &meta.exports_type),
+    Some(BuildMetaExportsType::Namespace)
+  ) {
+    return Some("Module is not an ECMAScript module".to_string());
+  }
+  if let Some(reason) = mgm
+    .build_info
+    .as_ref()
+    .and_then(|info| info.module_concatenation_bailout.as_ref())
+  {
+    return Some(format!("Module uses {reason}"));
+  }
+  if module_graph.is_async(identifier) {
+    return Some("Module is async".to_string());
+  }
+  if !matches!(normal_module.source(), NormalModuleSource::BuiltSucceed(_)) {
+    return Some("Module is not built successfully".to_string());
+  }
+  if !compilation
+    .chunk_graph
+    .chunk_graph_module_by_module_identifier
+    .contains_key(identifier)
+    || compilation
+      .chunk_graph
+      .get_number_of_module_chunks(*identifier)
+      == 0
+  {
+    return Some("Module is not in any chunk".to_string());
+  }
+  let reexports = mgm.dependencies.iter().any(|dependency_id| {
+    module_graph
+      .dependency_by_id(dependency_id)
+      .map_or(false, |dependency| {
+        matches!(
+          dependency.dependency_type(),
+          DependencyType::EsmExport | DependencyType::EsmExportImportedSpecifier
+        )
+      })
+  });
+  if reexports {
+    return Some("Module re-exports from other modules".to_string());
+  }
+  None
+}
+
+fn try_to_add(
+  compilation: &Compilation,
+  root: &ModuleIdentifier,
+  candidate: &ModuleIdentifier,
+  group: &IdentifierSet,
+  relevant_modules: &IdentifierSet,
+  used_modules: &IdentifierSet,
+) -> AddResult {
+  let module_graph = &compilation.module_graph;
+  if !relevant_modules.contains(candidate) {
+    return AddResult::Failed("Module is not concatenable".to_string());
+  }
+  if used_modules.contains(candidate) {
+    return AddResult::Failed("Module is already concatenated".to_string());
+  }
+  if compilation.chunk_graph.get_module_chunks(*candidate)
+    != compilation.chunk_graph.get_module_chunks(*root)
+  {
+    return AddResult::Failed("Module is not in the same chunks".to_string());
+  }
+
+  let Some(mgm) = module_graph.module_graph_module_by_identifier(candidate) else {
+    return AddResult::Failed("Module is not found".to_string());
+  };
+  let Ok(connections) = mgm.incoming_connections_unordered(module_graph) else {
+    return AddResult::Failed("Module is not found".to_string());
+  };
+  let name = readable_identifier(compilation, candidate);
+  let mut unsupported = vec![];
+  let mut outside = vec![];
+  for connection in connections {
+    let Some(origin) = connection.original_module_identifier else {
+      return AddResult::Failed("Module is an entry point".to_string());
+    };
+    if origin == *candidate {
+      continue;
+    }
+    let Some(dependency) = module_graph.dependency_by_id(&connection.dependency_id) else {
+      continue;
+    };
+    let dependency_type = dependency.dependency_type();
+    let namespace = dependency
+      .downcast_ref::<HarmonyImportSpecifierDependency>()
+      .map_or(false, |dependency| dependency.ids().is_empty());
+    if namespace {
+      unsupported.push(format!(
+        "{} (referenced with the namespace object)",
+        readable_identifier(compilation, &origin)
+      ));
+    } else if !matches!(
+      dependency_type,
+      DependencyType::EsmImport | DependencyType::EsmImportSpecifier
+    ) {
+      unsupported.push(format!(
+        "{} (referenced with {dependency_type})",
+        readable_identifier(compilation, &origin)
+      ));
+    } else if !group.contains(&origin) {
+      outside.push(readable_identifier(compilation, &origin));
+    }
+  }
+  if !unsupported.is_empty() {
+    unsupported.sort();
+    unsupported.dedup();
+    return AddResult::Failed(format!(
+      "Module {name} is referenced from these modules with unsupported syntax: {}",
+      unsupported.join(", ")
+    ));
+  }
+  if !outside.is_empty() {
+    outside.sort();
+    outside.dedup();
+    return AddResult::Retry(format!(
+      "Module {name} is referenced from these modules which are not concatenated: {}",
+      outside.join(", ")
+    ));
+  }
+  if has_circular_import(module_graph, candidate, group) {
+    return AddResult::Failed(format!("Module {name} is imported circularly"));
+  }
+  AddResult::Added
+}
+
+/// The modules imported by `import` statements in the source order.
+fn esm_import_targets(
+  module_graph: &ModuleGraph,
+  module: &ModuleIdentifier,
+) -> Vec<ModuleIdentifier> {
+  let Some(mgm) = module_graph.module_graph_module_by_identifier(module) else {
+    return vec![];
+  };
+  let mut targets: Vec<ModuleIdentifier> = vec![];
+  for dependency_id in mgm.dependencies.iter() {
+    let is_import = module_graph
+      .dependency_by_id(dependency_id)
+      .map_or(false, |dependency| {
+        *dependency.dependency_type() == DependencyType::EsmImport
+      });
+    if !is_import {
+      continue;
+    }
+    if let Some(target) = module_graph.module_identifier_by_dependency_id(dependency_id)
+      && !targets.contains(target)
+    {
+      targets.push(*target);
+    }
+  }
+  targets
+}
+
+/// Whether the candidate imports itself through the modules of the group.
+fn has_circular_import(
+  module_graph: &ModuleGraph,
+  candidate: &ModuleIdentifier,
+  group: &IdentifierSet,
+) -> bool {
+  let mut visited = IdentifierSet::default();
+  let mut stack = esm_import_targets(module_graph, candidate);
+  while let Some(module) = stack.pop() {
+    if module == *candidate {
+      return true;
+    }
+    if group.contains(&module) && visited.insert(module) {
+      stack.extend(esm_import_targets(module_graph, &module));
+    }
+  }
+  false
+}
+
+/// The imported modules are evaluated before the importer, so the root module is the last one.
+fn evaluation_order(
+  module_graph: &ModuleGraph,
+  root: &ModuleIdentifier,
+  group: &IdentifierSet,
+) -> Vec<ModuleIdentifier> {
+  fn visit(
+    module_graph: &ModuleGraph,
+    module: ModuleIdentifier,
+    group: &IdentifierSet,
+    visited: &mut IdentifierSet,
+    order: &mut Vec<ModuleIdentifier>,
+  ) {
+    if !visited.insert(module) {
+      return;
+    }
+    for target in esm_import_targets(module_graph, &module) {
+      if group.contains(&target) {
+        visit(module_graph, target, group, visited, order);
+      }
+    }
+    order.push(module);
+  }
+
+  let mut order = vec![];
+  visit(
+    module_graph,
+    *root,
+    group,
+    &mut IdentifierSet::default(),
+    &mut order,
+  );
+  order
+}
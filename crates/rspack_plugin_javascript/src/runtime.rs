@@ -39,6 +39,7 @@ pub fn render_chunk_modules(
           .render_module_content(RenderModuleContentArgs {
             compilation,
             module_source: origin_source,
+            module: mgm.module_identifier,
           })
           .expect("render_module_content failed")
         {
@@ -2,6 +2,7 @@ use std::path::Path;
 
 use rspack_core::{ErrorSpan, ModuleType};
 use rspack_error::{DiagnosticKind, Error};
+use rspack_util::swc::normalize_custom_filename;
 use swc_core::common::{SourceFile, Span, Spanned, SyntaxContext, DUMMY_SP};
 use swc_core::ecma::ast::{CallExpr, Callee, Expr, ExprOrSpread, Ident, Lit, Str};
 use swc_core::ecma::atoms::js_word;
@@ -144,13 +145,13 @@ pub fn ecma_parse_error_to_rspack_error(
   };
   let message = error.kind().msg().to_string();
   let span: ErrorSpan = error.span().into();
-  let traceable_error = rspack_error::TraceableError::from_source_file(
-    fm,
-    span.start as usize,
-    span.end as usize,
-    format!("{file_type} parsing error"),
-    message,
-  )
-  .with_kind(diagnostic_kind);
+  let traceable_error = span
+    .to_traceable_error(
+      normalize_custom_filename(&fm.name.to_string()),
+      &fm.src,
+      format!("{file_type} parsing error"),
+      message,
+    )
+    .with_kind(diagnostic_kind);
   Error::TraceableError(traceable_error)
 }
@@ -0,0 +1,342 @@
+use rspack_core::{
+  BoxDependency, BoxDependencyTemplate, BuildMeta, BuildMetaDefaultObject, BuildMetaExportsType,
+  ConstDependency, ModuleType, RuntimeGlobals, SpanExt,
+};
+use rustc_hash::FxHashSet as HashSet;
+use swc_core::common::{Span, Spanned, SyntaxContext};
+use swc_core::ecma::ast::{
+  ArrayLit, ArrowExpr, BlockStmtOrExpr, CallExpr, Callee, Expr, ExprOrSpread, Function, GetterProp,
+  Id, Ident, Lit, MemberExpr, MemberProp, ModuleItem, Pat, Program, ReturnStmt, SetterProp,
+};
+use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
+
+use super::ClaimedCalls;
+use crate::dependency::{
+  AmdDefineDependency, AmdFactory, AmdRequireItemDependency, CommonJsRequireDependency,
+  ModuleArgumentDependency,
+};
+
+/// Rewrite the amd `define([deps], factory)`, `define(factory)` and `define(id, deps, factory)`.
+pub struct AmdDefineScanner<'a> {
+  dependencies: &'a mut Vec<BoxDependency>,
+  presentational_dependencies: &'a mut Vec<BoxDependencyTemplate>,
+  unresolved_ctxt: &'a SyntaxContext,
+  build_meta: &'a mut BuildMeta,
+  module_type: ModuleType,
+  parser_exports_state: &'a mut Option<bool>,
+  /// The harmony detection runs after this scanner, so it is calculated in `visit_program`
+  is_harmony: bool,
+  /// The factory parameters which are the amd `require`,
+  /// e.g. `req` in `define(["require"], function (req) {})`
+  require_params: HashSet<Id>,
+  /// The calls claimed by the handlers of the plugins, see [rspack_core::JsParserHandlers]
+  claimed_calls: &'a ClaimedCalls,
+}
+
+impl<'a> AmdDefineScanner<'a> {
+  pub fn new(
+    dependencies: &'a mut Vec<BoxDependency>,
+    presentational_dependencies: &'a mut Vec<BoxDependencyTemplate>,
+    unresolved_ctxt: &'a SyntaxContext,
+    build_meta: &'a mut BuildMeta,
+    module_type: ModuleType,
+    parser_exports_state: &'a mut Option<bool>,
+    claimed_calls: &'a ClaimedCalls,
+  ) -> Self {
+    Self {
+      dependencies,
+      presentational_dependencies,
+      unresolved_ctxt,
+      build_meta,
+      module_type,
+      parser_exports_state,
+      is_harmony: false,
+      require_params: Default::default(),
+      claimed_calls,
+    }
+  }
+
+  /// Returns the dependency array and the factory of a `define()` call.
+  fn parse_define<'e>(&self, call_expr: &'e CallExpr) -> Option<(Option<&'e ArrayLit>, &'e Expr)> {
+    let Callee::Expr(box Expr::Ident(ident)) = &call_expr.callee else {
+      return None;
+    };
+    if &ident.sym != "define" || ident.span.ctxt != *self.unresolved_ctxt {
+      return None;
+    }
+    if call_expr.args.iter().any(|arg| arg.spread.is_some()) {
+      return None;
+    }
+    let args: Vec<&Expr> = call_expr.args.iter().map(|arg| &*arg.expr).collect();
+    let (array, factory) = match args.as_slice() {
+      [factory] => (None, *factory),
+      [Expr::Array(array), factory] => (Some(array), *factory),
+      [Expr::Lit(Lit::Str(_)), factory] => (None, *factory),
+      [Expr::Lit(Lit::Str(_)), Expr::Array(array), factory] => (Some(array), *factory),
+      _ => return None,
+    };
+    // Only the string literals can be required
+    let is_static = array.map_or(true, |array| {
+      array.elems.iter().all(|elem| {
+        matches!(
+          elem,
+          Some(ExprOrSpread {
+            spread: None,
+            expr: box Expr::Lit(Lit::Str(_)),
+          })
+        )
+      })
+    });
+    is_static.then_some((array, factory))
+  }
+
+  fn add_define(&mut self, call_expr: &CallExpr, array: Option<&ArrayLit>, factory: &Expr) {
+    let mut factory_expr = factory;
+    while let Expr::Paren(paren) = factory_expr {
+      factory_expr = &paren.expr;
+    }
+    let (kind, params) = match factory_expr {
+      Expr::Fn(fn_expr) => (
+        AmdFactory::Function,
+        fn_expr.function.params.iter().map(|p| &p.pat).collect(),
+      ),
+      Expr::Arrow(arrow) => (AmdFactory::Function, arrow.params.iter().collect()),
+      Expr::Object(_) | Expr::Array(_) | Expr::Lit(_) | Expr::Tpl(_) => (AmdFactory::Value, vec![]),
+      _ => (AmdFactory::Unknown, vec![]),
+    };
+
+    // The factory is called with `require, exports, module` if there is no dependency array
+    let names: Vec<&str> = match array {
+      Some(array) => array
+        .elems
+        .iter()
+        .filter_map(|elem| match elem.as_ref().map(|elem| &*elem.expr) {
+          Some(Expr::Lit(Lit::Str(str))) => Some(&*str.value),
+          _ => None,
+        })
+        .collect(),
+      None => vec!["require", "exports", "module"],
+    };
+    let mut exports_param = None;
+    let mut module_param = None;
+    for (param, name) in params.into_iter().zip(names) {
+      let Pat::Ident(param) = param else {
+        continue;
+      };
+      match name {
+        "require" => {
+          self.require_params.insert(param.id.to_id());
+        }
+        "exports" => exports_param = Some(param.id.to_id()),
+        "module" => module_param = Some(param.id.to_id()),
+        _ => {}
+      }
+    }
+
+    if let Some(array) = array {
+      for elem in array.elems.iter().flatten() {
+        let Expr::Lit(Lit::Str(str)) = &*elem.expr else {
+          continue;
+        };
+        self.add_require_item(&str.value, str.span);
+      }
+    }
+
+    let mut analyzer = FactoryAnalyzer {
+      exports_param,
+      module_param,
+      ..Default::default()
+    };
+    match factory_expr {
+      Expr::Fn(fn_expr) => fn_expr.function.body.visit_with(&mut analyzer),
+      Expr::Arrow(ArrowExpr { body, .. }) => match &**body {
+        BlockStmtOrExpr::BlockStmt(block) => block.visit_with(&mut analyzer),
+        BlockStmtOrExpr::Expr(_) => analyzer.returns_value = true,
+      },
+      _ => {}
+    }
+    self.set_exports_type(kind, &analyzer);
+
+    self
+      .presentational_dependencies
+      .push(Box::new(AmdDefineDependency::new(
+        (call_expr.span.real_lo(), call_expr.span.real_hi()),
+        array.map(|array| (array.span.real_lo(), array.span.real_hi())),
+        (factory.span().real_lo(), factory.span().real_hi()),
+        kind,
+      )));
+  }
+
+  fn add_require_item(&mut self, request: &str, span: Span) {
+    let (start, end) = (span.real_lo(), span.real_hi());
+    match request {
+      "require" => self
+        .presentational_dependencies
+        .push(Box::new(ConstDependency::new(
+          start,
+          end,
+          RuntimeGlobals::REQUIRE.to_string().into(),
+          Some(RuntimeGlobals::REQUIRE),
+        ))),
+      "exports" => self
+        .presentational_dependencies
+        .push(Box::new(ConstDependency::new(
+          start,
+          end,
+          "exports".into(),
+          Some(RuntimeGlobals::EXPORTS),
+        ))),
+      "module" => self
+        .presentational_dependencies
+        .push(Box::new(ModuleArgumentDependency::new(start, end, None))),
+      _ => self
+        .dependencies
+        .push(Box::new(AmdRequireItemDependency::new(
+          request.into(),
+          Some(span.into()),
+          start,
+          end,
+        ))),
+    }
+  }
+
+  /// The exports is replaced if the factory returns a value, otherwise it's the `exports`
+  /// parameter which is assigned just like a commonjs module, except for the ES modules
+  /// whose exports are never replaced.
+  fn set_exports_type(&mut self, kind: AmdFactory, analyzer: &FactoryAnalyzer) {
+    if self.is_harmony {
+      return;
+    }
+    if kind != AmdFactory::Function || analyzer.returns_value || analyzer.uses_module {
+      self.build_meta.exports_type = BuildMetaExportsType::Unset;
+      self.build_meta.default_object = BuildMetaDefaultObject::False;
+      *self.parser_exports_state = Some(false);
+      return;
+    }
+    if matches!(self.parser_exports_state, Some(false)) {
+      return;
+    }
+    if analyzer.es_module {
+      self.build_meta.exports_type = BuildMetaExportsType::Dynamic;
+    } else {
+      self.build_meta.exports_type = BuildMetaExportsType::Default;
+      self.build_meta.default_object = BuildMetaDefaultObject::Redirect;
+    }
+    *self.parser_exports_state = Some(true);
+  }
+}
+
+impl Visit for AmdDefineScanner<'_> {
+  noop_visit_type!();
+
+  fn visit_program(&mut self, program: &Program) {
+    self.is_harmony = matches!(self.module_type, ModuleType::JsEsm | ModuleType::JsxEsm)
+      || matches!(program, Program::Module(module) if module.body.iter().any(|s| matches!(s, ModuleItem::ModuleDecl(_))));
+    program.visit_children_with(self);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if self.claimed_calls.contains(call_expr) {
+      return;
+    }
+    if let Some((array, factory)) = self.parse_define(call_expr) {
+      self.add_define(call_expr, array, factory);
+    } else if let Callee::Expr(box Expr::Ident(ident)) = &call_expr.callee
+      && self.require_params.contains(&ident.to_id())
+      && let [ExprOrSpread { spread: None, expr: box Expr::Lit(Lit::Str(request)) }] = call_expr.args.as_slice()
+    {
+      // `require("./a")` with the `require` of amd
+      self
+        .dependencies
+        .push(Box::new(CommonJsRequireDependency::new(
+          request.value.clone(),
+          Some(call_expr.span.into()),
+          call_expr.span.real_lo(),
+          call_expr.span.real_hi(),
+          false,
+        )));
+    }
+    call_expr.visit_children_with(self);
+  }
+}
+
+/// Find out how the factory sets the exports.
+#[derive(Default)]
+struct FactoryAnalyzer {
+  exports_param: Option<Id>,
+  module_param: Option<Id>,
+  /// Depth of the nested functions, whose `return` isn't the one of the factory
+  function_depth: usize,
+  returns_value: bool,
+  uses_module: bool,
+  /// `exports.__esModule` is set
+  es_module: bool,
+}
+
+impl FactoryAnalyzer {
+  fn is_exports(&self, expr: &Expr) -> bool {
+    matches!(expr, Expr::Ident(ident) if Some(ident.to_id()) == self.exports_param)
+  }
+}
+
+impl Visit for FactoryAnalyzer {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function) {
+    self.function_depth += 1;
+    function.visit_children_with(self);
+    self.function_depth -= 1;
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr) {
+    self.function_depth += 1;
+    arrow_expr.visit_children_with(self);
+    self.function_depth -= 1;
+  }
+
+  fn visit_getter_prop(&mut self, getter: &GetterProp) {
+    self.function_depth += 1;
+    getter.visit_children_with(self);
+    self.function_depth -= 1;
+  }
+
+  fn visit_setter_prop(&mut self, setter: &SetterProp) {
+    self.function_depth += 1;
+    setter.visit_children_with(self);
+    self.function_depth -= 1;
+  }
+
+  fn visit_return_stmt(&mut self, return_stmt: &ReturnStmt) {
+    if self.function_depth == 0 && return_stmt.arg.is_some() {
+      self.returns_value = true;
+    }
+    return_stmt.visit_children_with(self);
+  }
+
+  fn visit_ident(&mut self, ident: &Ident) {
+    if self.module_param.is_some() && Some(ident.to_id()) == self.module_param {
+      self.uses_module = true;
+    }
+  }
+
+  fn visit_member_expr(&mut self, member_expr: &MemberExpr) {
+    if self.is_exports(&member_expr.obj)
+      && matches!(&member_expr.prop, MemberProp::Ident(prop) if &prop.sym == "__esModule")
+    {
+      self.es_module = true;
+    }
+    member_expr.visit_children_with(self);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    // Object.defineProperty(exports, "__esModule", { value: true })
+    if let [target, ExprOrSpread { expr: box Expr::Lit(Lit::Str(key)), .. }, ..] =
+      call_expr.args.as_slice()
+      && self.is_exports(&target.expr)
+      && &key.value == "__esModule"
+    {
+      self.es_module = true;
+    }
+    call_expr.visit_children_with(self);
+  }
+}
@@ -1,19 +1,20 @@
 use rspack_core::{
-  ConstDependency, DependencyTemplate, ResourceData, RuntimeGlobals, RuntimeRequirementsDependency,
-  SpanExt,
+  BuildInfo, ConstDependency, DependencyTemplate, ResourceData, RuntimeGlobals,
+  RuntimeRequirementsDependency, SpanExt,
 };
 use swc_core::{
   common::{Spanned, SyntaxContext},
   ecma::{
-    ast::{AssignExpr, AssignOp, Expr, Ident, Pat, PatOrExpr, VarDeclarator},
+    ast::{AssignExpr, AssignOp, CallExpr, Callee, Expr, Ident, Pat, PatOrExpr, VarDeclarator},
     visit::{noop_visit_type, Visit, VisitWith},
   },
 };
 
-use super::expr_matcher;
+use super::{expr_matcher, ClaimedCalls};
 use crate::dependency::ModuleArgumentDependency;
 pub const WEBPACK_HASH: &str = "__webpack_hash__";
 pub const WEBPACK_PUBLIC_PATH: &str = "__webpack_public_path__";
+pub const WEBPACK_NONCE: &str = "__webpack_nonce__";
 pub const WEBPACK_MODULES: &str = "__webpack_modules__";
 pub const WEBPACK_MODULE: &str = "__webpack_module__";
 pub const WEBPACK_RESOURCE_QUERY: &str = "__resourceQuery";
@@ -24,6 +25,8 @@ pub struct ApiScanner<'a> {
   pub enter_assign: bool,
   pub resource_data: &'a ResourceData,
   pub presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
+  pub build_info: &'a mut BuildInfo,
+  pub claimed_calls: &'a ClaimedCalls,
 }
 
 impl<'a> ApiScanner<'a> {
@@ -31,12 +34,16 @@ impl<'a> ApiScanner<'a> {
     unresolved_ctxt: &'a SyntaxContext,
     resource_data: &'a ResourceData,
     presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
+    build_info: &'a mut BuildInfo,
+    claimed_calls: &'a ClaimedCalls,
   ) -> Self {
     Self {
       unresolved_ctxt,
       enter_assign: false,
       resource_data,
       presentational_dependencies,
+      build_info,
+      claimed_calls,
     }
   }
 }
@@ -57,15 +64,14 @@ impl Visit for ApiScanner<'_> {
   }
 
   fn visit_assign_expr(&mut self, assign_expr: &AssignExpr) {
-    if matches!(assign_expr.op, AssignOp::Assign) {
-      match &assign_expr.left {
-        PatOrExpr::Pat(box Pat::Ident(ident)) => {
-          self.enter_assign = true;
-          ident.visit_children_with(self);
-          self.enter_assign = false;
-        }
-        _ => assign_expr.left.visit_children_with(self),
+    // Compound assignments, e.g. `__webpack_public_path__ += "assets/"`, read the left side as well
+    match &assign_expr.left {
+      PatOrExpr::Pat(box Pat::Ident(ident)) if matches!(assign_expr.op, AssignOp::Assign) => {
+        self.enter_assign = true;
+        ident.visit_children_with(self);
+        self.enter_assign = false;
       }
+      _ => assign_expr.left.visit_children_with(self),
     }
     assign_expr.right.visit_children_with(self);
   }
@@ -95,6 +101,16 @@ impl Visit for ApiScanner<'_> {
             Some(RuntimeGlobals::PUBLIC_PATH),
           )));
       }
+      WEBPACK_NONCE => {
+        self
+          .presentational_dependencies
+          .push(Box::new(ConstDependency::new(
+            ident.span.real_lo(),
+            ident.span.real_hi(),
+            RuntimeGlobals::SCRIPT_NONCE.name().into(),
+            Some(RuntimeGlobals::SCRIPT_NONCE),
+          )));
+      }
       WEBPACK_MODULES => {
         if self.enter_assign {
           return;
@@ -133,6 +149,7 @@ impl Visit for ApiScanner<'_> {
           )));
       }
       WEBPACK_MODULE => {
+        self.build_info.module_concatenation_bailout = Some(WEBPACK_MODULE.to_string());
         self
           .presentational_dependencies
           .push(Box::new(ModuleArgumentDependency::new(
@@ -146,16 +163,8 @@ impl Visit for ApiScanner<'_> {
   }
 
   fn visit_expr(&mut self, expr: &Expr) {
-    if expr_matcher::is_require_cache(expr) {
-      self
-        .presentational_dependencies
-        .push(Box::new(ConstDependency::new(
-          expr.span().real_lo(),
-          expr.span().real_hi(),
-          RuntimeGlobals::MODULE_CACHE.name().into(),
-          Some(RuntimeGlobals::MODULE_CACHE),
-        )));
-    } else if expr_matcher::is_webpack_module_id(expr) {
+    if expr_matcher::is_webpack_module_id(expr) {
+      self.build_info.module_concatenation_bailout = Some(format!("{WEBPACK_MODULE}.id"));
       self
         .presentational_dependencies
         .push(Box::new(RuntimeRequirementsDependency::new(
@@ -172,4 +181,18 @@ impl Visit for ApiScanner<'_> {
     }
     expr.visit_children_with(self);
   }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if self.claimed_calls.contains(call_expr) {
+      return;
+    }
+    // The scope of the module is unknown to `eval()`, which can't be concatenated
+    if let Callee::Expr(box Expr::Ident(ident)) = &call_expr.callee
+      && &ident.sym == "eval"
+      && ident.span.ctxt == *self.unresolved_ctxt
+    {
+      self.build_info.module_concatenation_bailout = Some("eval()".to_string());
+    }
+    call_expr.visit_children_with(self);
+  }
 }
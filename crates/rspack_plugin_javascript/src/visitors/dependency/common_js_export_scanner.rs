@@ -1,19 +1,20 @@
 use rspack_core::{
-  BuildMeta, BuildMetaDefaultObject, BuildMetaExportsType, DependencyTemplate, ModuleType,
-  RuntimeGlobals,
+  BuildMeta, BuildMetaDefaultObject, BuildMetaExportsType, BuildMetaProvidedExports,
+  DependencyTemplate, ModuleType, RuntimeGlobals,
 };
 use swc_core::{
   common::SyntaxContext,
+  ecma::atoms::JsWord,
   ecma::{
     ast::{
-      AssignExpr, CallExpr, Callee, Expr, ExprOrSpread, Ident, Lit, MemberExpr, ModuleItem,
-      ObjectLit, Pat, PatOrExpr, Program, Prop, PropName, PropOrSpread, UnaryOp,
+      AssignExpr, CallExpr, Callee, Expr, ExprOrSpread, Ident, Lit, MemberExpr, MemberProp,
+      ModuleItem, ObjectLit, Pat, PatOrExpr, Program, Prop, PropName, PropOrSpread, UnaryOp,
     },
     visit::{noop_visit_type, Visit, VisitWith},
   },
 };
 
-use super::{expr_matcher, is_require_call_expr};
+use super::{expr_matcher, is_require_call_expr, ClaimedCalls};
 use crate::dependency::ModuleDecoratorDependency;
 
 pub struct CommonJsExportDependencyScanner<'a> {
@@ -24,6 +25,7 @@ pub struct CommonJsExportDependencyScanner<'a> {
   is_harmony: bool,
   parser_exports_state: &'a mut Option<bool>,
   enter_call: u32,
+  claimed_calls: &'a ClaimedCalls,
 }
 
 impl<'a> CommonJsExportDependencyScanner<'a> {
@@ -33,6 +35,7 @@ impl<'a> CommonJsExportDependencyScanner<'a> {
     build_meta: &'a mut BuildMeta,
     module_type: ModuleType,
     parser_exports_state: &'a mut Option<bool>,
+    claimed_calls: &'a ClaimedCalls,
   ) -> Self {
     Self {
       presentational_dependencies,
@@ -42,6 +45,7 @@ impl<'a> CommonJsExportDependencyScanner<'a> {
       is_harmony: false,
       parser_exports_state,
       enter_call: 0,
+      claimed_calls,
     }
   }
 }
@@ -99,6 +103,7 @@ impl Visit for CommonJsExportDependencyScanner<'_> {
       // exports.xxx = 1;
       if self.is_exports_member_expr_start(expr) {
         self.enable();
+        self.provide_member_export(expr);
       }
       if self.is_exports_expr(expr) {
         self.enable();
@@ -108,6 +113,14 @@ impl Visit for CommonJsExportDependencyScanner<'_> {
           // this = require('xx');
           // It's possible to reexport __esModule, so we must convert to a dynamic module
           self.set_dynamic();
+          self.build_meta.provided_exports = BuildMetaProvidedExports::Unknown;
+        } else if expr_matcher::is_module_exports(expr)
+          && let Expr::Object(object_lit) = &*assign_expr.right
+        {
+          // module.exports = { a, b, ...c };
+          // The object replaces the exports provided before
+          self.build_meta.provided_exports = BuildMetaProvidedExports::Names(vec![]);
+          self.provide_object_exports(object_lit);
         } else {
           // exports = {};
           // module.exports = {};
@@ -126,6 +139,9 @@ impl Visit for CommonJsExportDependencyScanner<'_> {
   }
 
   fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if self.claimed_calls.contains(call_expr) {
+      return;
+    }
     if let Callee::Expr(expr) = &call_expr.callee {
       // Object.defineProperty(exports, "__esModule", { value: true });
       // Object.defineProperty(module.exports, "__esModule", { value: true });
@@ -134,6 +150,28 @@ impl Visit for CommonJsExportDependencyScanner<'_> {
         self.enable();
         self.check_namespace(value);
       }
+      // Object.assign(exports, { a, b });
+      // Object.assign(module.exports, { a, b });
+      // Object.assign(this, { a, b });
+      if expr_matcher::is_object_assign(expr)
+        && let Some(ExprOrSpread { spread: None, expr }) = call_expr.args.get(0)
+        && self.is_exports_expr(expr)
+      {
+        self.enable();
+        for arg in call_expr.args.iter().skip(1) {
+          match arg {
+            ExprOrSpread {
+              spread: None,
+              expr: box Expr::Object(object_lit),
+            } => self.provide_object_exports(object_lit),
+            // The properties of the other objects, including `__esModule`, are unknown
+            _ => {
+              self.provide_unknown_exports();
+              self.set_dynamic();
+            }
+          }
+        }
+      }
       // exports()
       // module.exports()
       // this()
@@ -175,17 +213,93 @@ impl<'a> CommonJsExportDependencyScanner<'a> {
     }
   }
 
+  /// Provide the export assigned by `exports.xxx = 1`, the name of `exports.xxx.yyy = 1` is `xxx`.
+  fn provide_member_export(&mut self, mut expr: &Expr) {
+    while let Expr::Member(MemberExpr { obj, prop, .. }) = expr {
+      if !self.is_exports_expr(obj) {
+        expr = obj.as_ref();
+        continue;
+      }
+      match prop {
+        MemberProp::Ident(ident) => self.provide_export(ident.sym.clone()),
+        MemberProp::Computed(computed) => match &*computed.expr {
+          Expr::Lit(Lit::Str(str)) => self.provide_export(str.value.clone()),
+          _ => self.provide_unknown_exports(),
+        },
+        MemberProp::PrivateName(_) => {}
+      }
+      return;
+    }
+  }
+
+  /// Provide the keys of `module.exports = { ... }` and `Object.assign(exports, { ... })`, the
+  /// `__esModule` of the object decides the exports type.
+  fn provide_object_exports(&mut self, object_lit: &ObjectLit) {
+    for prop in &object_lit.props {
+      let PropOrSpread::Prop(prop) = prop else {
+        // The spread object may provide any export, including `__esModule`
+        self.provide_unknown_exports();
+        self.set_dynamic();
+        continue;
+      };
+      let (key, value) = match &**prop {
+        Prop::Shorthand(ident) => (Some(ident.sym.clone()), None),
+        Prop::KeyValue(key_value) => (get_prop_name(&key_value.key), Some(&*key_value.value)),
+        Prop::Assign(_) => continue,
+        Prop::Getter(getter) => (get_prop_name(&getter.key), None),
+        Prop::Setter(setter) => (get_prop_name(&setter.key), None),
+        Prop::Method(method) => (get_prop_name(&method.key), None),
+      };
+      match key {
+        Some(key) if &key == "__esModule" => match value {
+          Some(value) => self.check_namespace(value),
+          None => self.set_dynamic(),
+        },
+        Some(key) => self.provide_export(key),
+        // The computed key may be `__esModule`
+        None => {
+          self.provide_unknown_exports();
+          self.set_dynamic();
+        }
+      }
+    }
+  }
+
+  fn provide_export(&mut self, name: JsWord) {
+    if name == "__esModule" || !matches!(self.parser_exports_state, Some(true)) {
+      return;
+    }
+    match &mut self.build_meta.provided_exports {
+      BuildMetaProvidedExports::Names(names) | BuildMetaProvidedExports::Partial(names) => {
+        if !names.contains(&name) {
+          names.push(name);
+        }
+      }
+      BuildMetaProvidedExports::Unknown => {}
+    }
+  }
+
+  fn provide_unknown_exports(&mut self) {
+    if let BuildMetaProvidedExports::Names(names) = &mut self.build_meta.provided_exports {
+      self.build_meta.provided_exports = BuildMetaProvidedExports::Partial(std::mem::take(names));
+    }
+  }
+
   // can't scan `__esModule` value
   fn bailout(&mut self) {
     if matches!(self.parser_exports_state, Some(true)) {
       self.build_meta.exports_type = BuildMetaExportsType::Unset;
       self.build_meta.default_object = BuildMetaDefaultObject::False;
     }
+    self.build_meta.provided_exports = BuildMetaProvidedExports::Unknown;
     *self.parser_exports_state = Some(false);
   }
 
   // `__esModule` is false
   fn enable(&mut self) {
+    if self.parser_exports_state.is_none() {
+      self.build_meta.provided_exports = BuildMetaProvidedExports::Names(vec![]);
+    }
     if matches!(self.parser_exports_state, Some(false)) || self.parser_exports_state.is_none() {
       self.build_meta.exports_type = BuildMetaExportsType::Default;
       self.build_meta.default_object = BuildMetaDefaultObject::Redirect;
@@ -230,6 +344,15 @@ fn get_value_of_property_description<'a>(
   None
 }
 
+fn get_prop_name(prop_name: &PropName) -> Option<JsWord> {
+  match prop_name {
+    PropName::Ident(ident) => Some(ident.sym.clone()),
+    PropName::Str(str) => Some(str.value.clone()),
+    PropName::Num(num) => Some(JsWord::from(num.value.to_string())),
+    PropName::Computed(_) | PropName::BigInt(_) => None,
+  }
+}
+
 fn is_truthy_literal(expr: &Expr) -> bool {
   match expr {
     Expr::Lit(lit) => is_lit_truthy_literal(lit),
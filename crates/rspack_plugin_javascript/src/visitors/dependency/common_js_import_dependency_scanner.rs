@@ -2,6 +2,7 @@ use rspack_core::{
   BoxDependency, ConstDependency, ContextMode, ContextNameSpaceObject, ContextOptions,
   DependencyCategory, DependencyTemplate, RuntimeGlobals, SpanExt,
 };
+use rspack_error::Error;
 use rspack_regex::RspackRegex;
 use swc_core::{
   common::{Spanned, SyntaxContext},
@@ -13,7 +14,8 @@ use swc_core::{
 };
 
 use super::{
-  context_helper::scanner_context_module, expr_matcher, is_unresolved_member_object_ident,
+  context_helper::scanner_context_module, create_traceable_warning, expr_matcher,
+  is_unresolved_member_object_ident, module_not_found_callee, ClaimedCalls,
 };
 use crate::dependency::{
   CommonJsRequireContextDependency, CommonJsRequireDependency, RequireResolveDependency,
@@ -23,40 +25,133 @@ pub struct CommonJsImportDependencyScanner<'a> {
   dependencies: &'a mut Vec<BoxDependency>,
   presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
   unresolved_ctxt: &'a SyntaxContext,
+  errors: &'a mut Vec<Error>,
+  resource_path: &'a str,
+  source: &'a str,
   in_try: bool,
   in_if: bool,
+  /// Whether `require.cache` is the module cache, see [rspack_core::CompilerOptions::require_cache]
+  require_cache: bool,
+  /// The calls claimed by the handlers of the plugins, see [rspack_core::JsParserHandlers]
+  claimed_calls: &'a ClaimedCalls,
 }
 
 impl<'a> CommonJsImportDependencyScanner<'a> {
+  #[allow(clippy::too_many_arguments)]
   pub fn new(
     dependencies: &'a mut Vec<BoxDependency>,
     presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
     unresolved_ctxt: &'a SyntaxContext,
+    errors: &'a mut Vec<Error>,
+    resource_path: &'a str,
+    source: &'a str,
+    require_cache: bool,
+    claimed_calls: &'a ClaimedCalls,
   ) -> Self {
     Self {
       dependencies,
       presentational_dependencies,
       unresolved_ctxt,
+      errors,
+      resource_path,
+      source,
       in_try: false,
       in_if: false,
+      require_cache,
+      claimed_calls,
     }
   }
 
+  /// `require.cache` is the module cache of the node targets, so that a module can be
+  /// executed again by `delete require.cache[require.resolve(request)]`.
+  fn add_require_cache(&mut self, expr: &Expr) {
+    if !self.require_cache {
+      self.errors.push(create_traceable_warning(
+        self.resource_path,
+        self.source,
+        expr.span(),
+        "Unsupported feature".to_string(),
+        "`require.cache` is only supported for the node targets".to_string(),
+      ));
+      return;
+    }
+    self
+      .presentational_dependencies
+      .push(Box::new(ConstDependency::new(
+        expr.span().real_lo(),
+        expr.span().real_hi(),
+        RuntimeGlobals::MODULE_CACHE.name().into(),
+        Some(RuntimeGlobals::MODULE_CACHE),
+      )));
+  }
+
   fn add_require_resolve(&mut self, node: &CallExpr, weak: bool) {
-    if !node.args.is_empty() {
-      if let Some(Lit::Str(str)) = node.args.get(0).and_then(|x| x.expr.as_lit()) {
-        self
-          .dependencies
-          .push(Box::new(RequireResolveDependency::new(
-            node.span.real_lo(),
-            node.span.real_hi(),
-            str.value.to_string(),
-            weak,
-            node.span.into(),
-            self.in_try,
-          )));
+    let Some(arg) = node.args.get(0) else {
+      return;
+    };
+    let request = match arg.expr.as_ref() {
+      Expr::Lit(Lit::Str(str)) if arg.spread.is_none() => str.value.to_string(),
+      Expr::Tpl(tpl) if arg.spread.is_none() && tpl.exprs.is_empty() => tpl
+        .quasis
+        .first()
+        .expect("should have one quasis")
+        .raw
+        .to_string(),
+      _ => {
+        self.warn_non_literal_require_resolve(node);
+        return;
       }
-    }
+    };
+    self
+      .dependencies
+      .push(Box::new(RequireResolveDependency::new(
+        node.span.real_lo(),
+        node.span.real_hi(),
+        request,
+        weak,
+        node.span.into(),
+        self.in_try,
+      )));
+  }
+
+  /// The request of `require.resolve(expr)` can't be resolved at build time,
+  /// warn about it and throw at runtime instead of failing the build.
+  fn warn_non_literal_require_resolve(&mut self, node: &CallExpr) {
+    self.errors.push(create_traceable_warning(
+      self.resource_path,
+      self.source,
+      node.args[0].expr.span(),
+      "require.resolve() with a non-literal request".to_string(),
+      "The request of `require.resolve(request)` can't be statically analyzed, it will throw at runtime".to_string(),
+    ));
+    self
+      .presentational_dependencies
+      .push(Box::new(ConstDependency::new(
+        node.span.real_lo(),
+        node.span.real_hi(),
+        "(function() { var e = new Error(\"Cannot resolve a non-literal request of require.resolve()\"); e.code = 'MODULE_NOT_FOUND'; throw e; })()".into(),
+        None,
+      )));
+  }
+
+  /// The request of `require(expr)` can't be statically analyzed,
+  /// warn about it and throw a `Cannot find module` error at runtime, like webpack does.
+  fn warn_expression_require(&mut self, node: &CallExpr) {
+    self.errors.push(create_traceable_warning(
+      self.resource_path,
+      self.source,
+      node.args[0].expr.span(),
+      "Critical dependency".to_string(),
+      "the request of a dependency is an expression".to_string(),
+    ));
+    self
+      .presentational_dependencies
+      .push(Box::new(ConstDependency::new(
+        node.callee.span().real_lo(),
+        node.callee.span().real_hi(),
+        module_not_found_callee(false).into(),
+        None,
+      )));
   }
 
   fn replace_require_resolve(&mut self, expr: &Expr, value: &'static str) {
@@ -86,6 +181,9 @@ impl Visit for CommonJsImportDependencyScanner<'_> {
   }
 
   fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if self.claimed_calls.contains(call_expr) {
+      return;
+    }
     if let Callee::Expr(expr) = &call_expr.callee {
       if let Expr::Ident(ident) = &**expr {
         if "require".eq(&ident.sym) && ident.span.ctxt == *self.unresolved_ctxt {
@@ -132,12 +230,17 @@ impl Visit for CommonJsImportDependencyScanner<'_> {
                       exclude: None,
                       category: DependencyCategory::CommonJS,
                       request: context,
-                      namespace_object: ContextNameSpaceObject::Unset
+                      namespace_object: ContextNameSpaceObject::Unset,
+                      chunk_name: None
                     },
                     Some(call_expr.span.into()),
                   )));
                 return;
               }
+              self.warn_expression_require(call_expr);
+              // The request is evaluated at runtime, and it may contain other requires
+              call_expr.args.visit_with(self);
+              return;
             }
             self
               .presentational_dependencies
@@ -164,6 +267,16 @@ impl Visit for CommonJsImportDependencyScanner<'_> {
     call_expr.visit_children_with(self);
   }
 
+  fn visit_expr(&mut self, expr: &Expr) {
+    if is_unresolved_member_object_ident(expr, self.unresolved_ctxt)
+      && expr_matcher::is_require_cache(expr)
+    {
+      self.add_require_cache(expr);
+      return;
+    }
+    expr.visit_children_with(self);
+  }
+
   fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr) {
     if let UnaryExpr {
       op: UnaryOp::TypeOf,
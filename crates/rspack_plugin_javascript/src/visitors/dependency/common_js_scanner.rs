@@ -1,25 +1,31 @@
-use rspack_core::{DependencyTemplate, RuntimeGlobals, RuntimeRequirementsDependency};
+use rspack_core::{BuildInfo, DependencyTemplate, RuntimeGlobals, RuntimeRequirementsDependency};
 use swc_core::common::SyntaxContext;
-use swc_core::ecma::ast::{Expr, Ident};
+use swc_core::ecma::ast::{CallExpr, Expr, Ident};
 use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
 
-use super::expr_matcher;
+use super::{expr_matcher, ClaimedCalls};
 
 pub struct CommonJsScanner<'a> {
   unresolved_ctxt: &'a SyntaxContext,
   presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
+  build_info: &'a mut BuildInfo,
   has_module_ident: bool,
+  claimed_calls: &'a ClaimedCalls,
 }
 
 impl<'a> CommonJsScanner<'a> {
   pub fn new(
     presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
     unresolved_ctxt: &'a SyntaxContext,
+    build_info: &'a mut BuildInfo,
+    claimed_calls: &'a ClaimedCalls,
   ) -> Self {
     Self {
       presentational_dependencies,
       unresolved_ctxt,
+      build_info,
       has_module_ident: false,
+      claimed_calls,
     }
   }
 }
@@ -27,6 +33,13 @@ impl<'a> CommonJsScanner<'a> {
 impl Visit for CommonJsScanner<'_> {
   noop_visit_type!();
 
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if self.claimed_calls.contains(call_expr) {
+      return;
+    }
+    call_expr.visit_children_with(self);
+  }
+
   fn visit_ident(&mut self, ident: &Ident) {
     if self.has_module_ident {
       return;
@@ -37,6 +50,7 @@ impl Visit for CommonJsScanner<'_> {
         .push(Box::new(RuntimeRequirementsDependency::new(
           RuntimeGlobals::MODULE,
         )));
+      self.build_info.module_concatenation_bailout = Some("module".to_string());
       self.has_module_ident = true;
     }
   }
@@ -48,12 +48,13 @@ fn scan_context_module_tpl(tpl: &Tpl) -> (String, String) {
     String::new()
   };
   let (context, prefix) = split_context_from_prefix(prefix_raw);
+  // require(`./${a}/b/${c}.js`) has the inner quasis `/b/`
   let inner_reg = tpl
     .quasis
     .iter()
-    .skip(tpl.quasis.len())
     .skip(1)
-    .map(|s| s.raw.to_string() + ".*")
+    .take(tpl.quasis.len().saturating_sub(2))
+    .map(|s| quote_meta(s.raw.to_string()) + ".*")
     .collect::<Vec<String>>()
     .join("");
   let reg = format!(
@@ -3,15 +3,17 @@ use swc_core::{
   common::SyntaxContext,
   ecma::{
     ast::*,
-    visit::{noop_visit_type, Visit},
+    visit::{noop_visit_type, Visit, VisitWith},
   },
 };
 
+use super::ClaimedCalls;
 use crate::dependency::ExportInfoApiDependency;
 
 pub struct ExportInfoApiScanner<'a> {
   pub presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
   unresolved_ctxt: SyntaxContext,
+  claimed_calls: &'a ClaimedCalls,
 }
 
 //__webpack_exports_info__.a.used
@@ -19,10 +21,12 @@ impl<'a> ExportInfoApiScanner<'a> {
   pub fn new(
     presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
     unresolved_ctxt: SyntaxContext,
+    claimed_calls: &'a ClaimedCalls,
   ) -> Self {
     Self {
       presentational_dependencies,
       unresolved_ctxt,
+      claimed_calls,
     }
   }
 }
@@ -30,6 +34,13 @@ impl<'a> ExportInfoApiScanner<'a> {
 impl Visit for ExportInfoApiScanner<'_> {
   noop_visit_type!();
 
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if self.claimed_calls.contains(call_expr) {
+      return;
+    }
+    call_expr.visit_children_with(self);
+  }
+
   fn visit_member_expr(&mut self, member_expr: &MemberExpr) {
     let member_chain = extract_member_expression_chain(member_expr);
     if !member_chain.is_empty()
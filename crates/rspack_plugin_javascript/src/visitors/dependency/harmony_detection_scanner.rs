@@ -1,9 +1,11 @@
 use rspack_core::{
-  BuildInfo, BuildMeta, BuildMetaExportsType, DependencyTemplate, ExportsArgument, ModuleArgument,
-  ModuleType,
+  BuildInfo, BuildMeta, BuildMetaExportsType, DependencyTemplate, ErrorSpan, ExportsArgument,
+  ModuleArgument, ModuleType,
 };
-use swc_core::ecma::ast::{ModuleItem, Program};
-use swc_core::ecma::visit::{noop_visit_type, Visit};
+use rspack_error::Error;
+use swc_core::common::Span;
+use swc_core::ecma::ast::{ArrowExpr, AwaitExpr, Class, ForOfStmt, Function, ModuleItem, Program};
+use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
 
 use crate::dependency::HarmonyCompatibilityDependency;
 
@@ -12,21 +14,34 @@ pub struct HarmonyDetectionScanner<'a> {
   build_info: &'a mut BuildInfo,
   build_meta: &'a mut BuildMeta,
   module_type: &'a ModuleType,
+  top_level_await: bool,
   code_generable_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
+  errors: &'a mut Vec<Error>,
+  resource_path: &'a str,
+  source: &'a str,
 }
 
 impl<'a> HarmonyDetectionScanner<'a> {
+  #[allow(clippy::too_many_arguments)]
   pub fn new(
     build_info: &'a mut BuildInfo,
     build_meta: &'a mut BuildMeta,
     module_type: &'a ModuleType,
+    top_level_await: bool,
     code_generable_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
+    errors: &'a mut Vec<Error>,
+    resource_path: &'a str,
+    source: &'a str,
   ) -> Self {
     Self {
       build_info,
       build_meta,
       module_type,
+      top_level_await,
       code_generable_dependencies,
+      errors,
+      resource_path,
+      source,
     }
   }
 }
@@ -53,5 +68,54 @@ impl Visit for HarmonyDetectionScanner<'_> {
       self.build_meta.strict_harmony_module = true;
       self.build_meta.module_argument = ModuleArgument::WebpackModule;
     }
+
+    if !self.build_meta.esm {
+      return;
+    }
+    let mut finder = TopLevelAwaitFinder::default();
+    program.visit_with(&mut finder);
+    if let Some(span) = finder.span {
+      if self.top_level_await {
+        self.build_meta.has_top_level_await = true;
+        self.build_meta.is_async = true;
+      } else {
+        self.errors.push(Error::TraceableError(ErrorSpan::from(span).to_traceable_error(
+          self.resource_path,
+          self.source,
+          "Module parse failed".to_string(),
+          "The top-level-await experiment is not enabled (set experiments.topLevelAwait: true to enable it)"
+            .to_string(),
+        )));
+      }
+    }
   }
 }
+
+/// Find the first `await` which is not nested in a function or class body.
+#[derive(Default)]
+struct TopLevelAwaitFinder {
+  span: Option<Span>,
+}
+
+impl Visit for TopLevelAwaitFinder {
+  noop_visit_type!();
+
+  fn visit_await_expr(&mut self, await_expr: &AwaitExpr) {
+    if self.span.is_none() {
+      self.span = Some(await_expr.span);
+    }
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt) {
+    if for_of_stmt.is_await && self.span.is_none() {
+      self.span = Some(for_of_stmt.span);
+    }
+    for_of_stmt.visit_children_with(self);
+  }
+
+  fn visit_function(&mut self, _: &Function) {}
+
+  fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+  fn visit_class(&mut self, _: &Class) {}
+}
@@ -8,7 +8,7 @@ use swc_core::{
   },
 };
 
-use super::{expr_matcher, is_module_hot_accept_call, is_module_hot_decline_call};
+use super::{expr_matcher, is_module_hot_accept_call, is_module_hot_decline_call, ClaimedCalls};
 use crate::{
   dependency::{
     HarmonyAcceptDependency, ImportMetaHotAcceptDependency, ImportMetaHotDeclineDependency,
@@ -21,6 +21,8 @@ pub struct HotModuleReplacementScanner<'a> {
   pub dependencies: &'a mut Vec<BoxDependency>,
   pub presentational_dependencies: &'a mut Vec<BoxDependencyTemplate>,
   pub build_meta: &'a BuildMeta,
+  /// The calls claimed by the handlers of the plugins, see [rspack_core::JsParserHandlers]
+  claimed_calls: &'a ClaimedCalls,
 }
 
 type CreateDependency = fn(u32, u32, JsWord, Option<ErrorSpan>) -> BoxDependency;
@@ -30,11 +32,13 @@ impl<'a> HotModuleReplacementScanner<'a> {
     dependencies: &'a mut Vec<BoxDependency>,
     presentational_dependencies: &'a mut Vec<BoxDependencyTemplate>,
     build_meta: &'a BuildMeta,
+    claimed_calls: &'a ClaimedCalls,
   ) -> Self {
     Self {
       dependencies,
       presentational_dependencies,
       build_meta,
+      claimed_calls,
     }
   }
 
@@ -118,6 +122,9 @@ impl<'a> Visit for HotModuleReplacementScanner<'a> {
   }
 
   fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if self.claimed_calls.contains(call_expr) {
+      return;
+    }
     if is_module_hot_accept_call(call_expr) {
       self.collect_dependencies(call_expr, "accept", |start, end, request, span| {
         Box::new(ModuleHotAcceptDependency::new(start, end, request, span))
@@ -1,13 +1,16 @@
-use rspack_core::{CompilerOptions, ConstDependency, DependencyTemplate, ResourceData, SpanExt};
+use rspack_core::{
+  BuildMeta, CompilerOptions, ConstDependency, DependencyTemplate, ResourceData, RuntimeGlobals,
+  SpanExt,
+};
 use swc_core::common::Spanned;
-use swc_core::ecma::ast::{Expr, Ident, NewExpr, UnaryExpr, UnaryOp};
+use swc_core::ecma::ast::{CallExpr, Expr, Ident, NewExpr, UnaryExpr, UnaryOp};
 use swc_core::ecma::atoms::js_word;
 use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
 use url::Url;
 
 use super::{
   expr_matcher, is_member_expr_starts_with_import_meta,
-  is_member_expr_starts_with_import_meta_webpack_hot,
+  is_member_expr_starts_with_import_meta_webpack_hot, ClaimedCalls,
 };
 
 // Port from https://github.com/webpack/webpack/blob/main/lib/dependencies/ImportMetaPlugin.js
@@ -20,6 +23,8 @@ pub struct ImportMetaScanner<'a> {
   pub presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
   pub compiler_options: &'a CompilerOptions,
   pub resource_data: &'a ResourceData,
+  pub build_meta: &'a BuildMeta,
+  pub claimed_calls: &'a ClaimedCalls,
 }
 
 impl<'a> ImportMetaScanner<'a> {
@@ -27,18 +32,38 @@ impl<'a> ImportMetaScanner<'a> {
     presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
     resource_data: &'a ResourceData,
     compiler_options: &'a CompilerOptions,
+    build_meta: &'a BuildMeta,
+    claimed_calls: &'a ClaimedCalls,
   ) -> Self {
     Self {
       presentational_dependencies,
       resource_data,
       compiler_options,
+      build_meta,
+      claimed_calls,
     }
   }
+
+  fn hot(&self) -> bool {
+    self.compiler_options.dev_server.hot
+  }
+
+  fn url(&self) -> String {
+    let url = Url::from_file_path(&self.resource_data.resource).expect("should be a path");
+    format!("'{url}'")
+  }
 }
 
 impl Visit for ImportMetaScanner<'_> {
   noop_visit_type!();
 
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if self.claimed_calls.contains(call_expr) {
+      return;
+    }
+    call_expr.visit_children_with(self);
+  }
+
   fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr) {
     if let UnaryExpr {
       op: UnaryOp::TypeOf,
@@ -64,6 +89,8 @@ impl Visit for ImportMetaScanner<'_> {
             "'string'".into(),
             None,
           )));
+      } else if expr_matcher::is_import_meta_webpack_hot(expr) && self.hot() {
+        // `import.meta.webpackHot` is replaced by `module.hot` in HotModuleReplacementScanner
       } else if is_member_expr_starts_with_import_meta(expr) {
         self
           .presentational_dependencies
@@ -73,6 +100,8 @@ impl Visit for ImportMetaScanner<'_> {
             "'undefined'".into(),
             None,
           )));
+      } else {
+        unary_expr.visit_children_with(self);
       }
     } else {
       unary_expr.visit_children_with(self);
@@ -80,34 +109,73 @@ impl Visit for ImportMetaScanner<'_> {
   }
 
   fn visit_expr(&mut self, expr: &Expr) {
-    // exclude import.meta.webpackHot
+    // import.meta.webpackHot
+    if expr_matcher::is_import_meta_webpack_hot(expr) {
+      // It's replaced by `module.hot` in HotModuleReplacementScanner when hot is enabled
+      if !self.hot() {
+        self
+          .presentational_dependencies
+          .push(Box::new(ConstDependency::new(
+            expr.span().real_lo(),
+            expr.span().real_hi(),
+            "undefined".into(),
+            None,
+          )));
+      }
+      return;
+    }
+    // import.meta.webpackHot.xxx
     if is_member_expr_starts_with_import_meta_webpack_hot(expr) {
+      expr.visit_children_with(self);
       return;
     }
 
     // import.meta
     if expr_matcher::is_import_meta(expr) {
+      // `import.meta` is available natively in module output, reference it by `output.importMetaName`
+      if self.compiler_options.output.module {
+        self
+          .presentational_dependencies
+          .push(Box::new(ConstDependency::new(
+            expr.span().real_lo(),
+            expr.span().real_hi(),
+            self.compiler_options.output.import_meta_name.clone().into(),
+            None,
+          )));
+        return;
+      }
       // TODO(underfin): add warning
+      let url = self.url();
+      let (content, runtime_requirements) = if self.hot() {
+        (
+          format!(
+            "({{url: {url}, webpackHot: {}.hot}})",
+            self.build_meta.module_argument
+          ),
+          Some(RuntimeGlobals::MODULE),
+        )
+      } else {
+        (format!("({{url: {url}}})"), None)
+      };
       self
         .presentational_dependencies
         .push(Box::new(ConstDependency::new(
           expr.span().real_lo(),
           expr.span().real_hi(),
-          "({})".into(),
-          None,
+          content.into(),
+          runtime_requirements,
         )));
     } else if expr_matcher::is_import_meta_url(expr) {
       // import.meta.url
-      let url = Url::from_file_path(&self.resource_data.resource).expect("should be a path");
       self
         .presentational_dependencies
         .push(Box::new(ConstDependency::new(
           expr.span().real_lo(),
           expr.span().real_hi(),
-          format!("'{url}'").into(),
+          self.url().into(),
           None,
         )));
-    } else if is_member_expr_starts_with_import_meta(expr) {
+    } else if is_member_expr_starts_with_import_meta(expr) && !self.compiler_options.output.module {
       self
         .presentational_dependencies
         .push(Box::new(ConstDependency::new(
@@ -1,40 +1,81 @@
 use once_cell::sync::Lazy;
 use rspack_core::{
-  BoxDependency, BuildMeta, ChunkGroupOptions, ContextMode, ContextNameSpaceObject, ContextOptions,
-  DependencyCategory, SpanExt,
+  BoxDependency, BoxDependencyTemplate, BuildMeta, ChunkGroupFetchPriority, ChunkGroupOptions,
+  ConstDependency, ContextMode, ContextNameSpaceObject, ContextOptions, DependencyCategory,
+  SpanExt,
 };
+use rspack_error::Error;
 use rspack_regex::RspackRegex;
 use swc_core::{
-  common::{comments::Comments, Span},
+  common::{comments::Comments, Span, Spanned},
   ecma::{
-    ast::{CallExpr, Callee, Expr, Lit},
+    ast::{CallExpr, Callee, Expr, Import, Lit},
     atoms::JsWord,
     visit::{noop_visit_type, Visit, VisitWith},
   },
 };
 
-use super::context_helper::scanner_context_module;
+use super::{
+  context_helper::scanner_context_module, create_traceable_warning, module_not_found_callee,
+  ClaimedCalls,
+};
 use crate::dependency::{ImportContextDependency, ImportDependency};
 
 pub struct ImportScanner<'a> {
   pub dependencies: &'a mut Vec<BoxDependency>,
+  pub presentational_dependencies: &'a mut Vec<BoxDependencyTemplate>,
   pub comments: Option<&'a dyn Comments>,
   pub build_meta: &'a BuildMeta,
+  pub errors: &'a mut Vec<Error>,
+  pub resource_path: &'a str,
+  pub source: &'a str,
+  pub claimed_calls: &'a ClaimedCalls,
 }
 
 impl<'a> ImportScanner<'a> {
+  #[allow(clippy::too_many_arguments)]
   pub fn new(
     dependencies: &'a mut Vec<BoxDependency>,
+    presentational_dependencies: &'a mut Vec<BoxDependencyTemplate>,
     comments: Option<&'a dyn Comments>,
     build_meta: &'a BuildMeta,
+    errors: &'a mut Vec<Error>,
+    resource_path: &'a str,
+    source: &'a str,
+    claimed_calls: &'a ClaimedCalls,
   ) -> Self {
     Self {
       dependencies,
+      presentational_dependencies,
       comments,
       build_meta,
+      errors,
+      resource_path,
+      source,
+      claimed_calls,
     }
   }
 
+  /// The request of `import(expr)` has no static part to create a context from,
+  /// warn about it and reject at runtime with a `Cannot find module` error, like webpack does.
+  fn warn_expression_import(&mut self, node: &CallExpr, import_call: &Import) {
+    self.errors.push(create_traceable_warning(
+      self.resource_path,
+      self.source,
+      node.args[0].expr.span(),
+      "Critical dependency".to_string(),
+      "the request of a dependency is an expression".to_string(),
+    ));
+    self
+      .presentational_dependencies
+      .push(Box::new(ConstDependency::new(
+        import_call.span.real_lo(),
+        import_call.span.real_hi(),
+        module_not_found_callee(true).into(),
+        None,
+      )));
+  }
+
   fn try_extract_webpack_chunk_name(&self, first_arg_span_of_import_call: &Span) -> Option<String> {
     use swc_core::common::comments::CommentKind;
     static WEBPACK_CHUNK_NAME_CAPTURE_RE: Lazy<regex::Regex> = Lazy::new(|| {
@@ -65,28 +106,124 @@ impl<'a> ImportScanner<'a> {
         ret
       })
   }
+
+  /// `webpackPrefetch: true` is the order `0`, `webpackPrefetch: false` is ignored.
+  fn try_extract_webpack_order(
+    &self,
+    first_arg_span_of_import_call: &Span,
+    key: &str,
+  ) -> Option<i32> {
+    use swc_core::common::comments::CommentKind;
+    static WEBPACK_ORDER_CAPTURE_RE: Lazy<regex::Regex> = Lazy::new(|| {
+      regex::Regex::new(
+        r#"(?P<key>webpackPrefetch|webpackPreload)\s*:\s*(?P<value>true|false|-?\d+)"#,
+      )
+      .expect("invalid regex")
+    });
+    self
+      .comments
+      .with_leading(first_arg_span_of_import_call.lo, |comments| {
+        comments
+          .iter()
+          .rev()
+          .filter(|c| matches!(c.kind, CommentKind::Block))
+          .find_map(|comment| {
+            WEBPACK_ORDER_CAPTURE_RE
+              .captures_iter(&comment.text)
+              .find(|captures| &captures["key"] == key)
+              .map(|captures| match &captures["value"] {
+                "true" => Some(0),
+                "false" => None,
+                order => order.parse().ok(),
+              })
+          })
+          .flatten()
+      })
+  }
+
+  /// `webpackFetchPriority: "high" | "low" | "auto"`, the other values are warned and ignored.
+  fn try_extract_webpack_fetch_priority(
+    &mut self,
+    first_arg_span_of_import_call: &Span,
+  ) -> Option<ChunkGroupFetchPriority> {
+    use swc_core::common::comments::CommentKind;
+    static WEBPACK_FETCH_PRIORITY_CAPTURE_RE: Lazy<regex::Regex> = Lazy::new(|| {
+      regex::Regex::new(
+        r#"webpackFetchPriority\s*:\s*(?:"(?P<_1>[^"]*)"|'(?P<_2>[^']*)'|`(?P<_3>[^`]*)`)"#,
+      )
+      .expect("invalid regex")
+    });
+    let comments = self.comments?;
+    let (value, span) = comments.with_leading(first_arg_span_of_import_call.lo, |comments| {
+      comments
+        .iter()
+        .rev()
+        .filter(|c| matches!(c.kind, CommentKind::Block))
+        .find_map(|comment| {
+          WEBPACK_FETCH_PRIORITY_CAPTURE_RE
+            .captures(&comment.text)
+            .and_then(|captures| {
+              captures
+                .name("_1")
+                .or_else(|| captures.name("_2"))
+                .or_else(|| captures.name("_3"))
+            })
+            .map(|mat| (mat.as_str().to_string(), comment.span))
+        })
+    })?;
+    let fetch_priority = ChunkGroupFetchPriority::try_from(value.as_str()).ok();
+    if fetch_priority.is_none() {
+      self.errors.push(create_traceable_warning(
+        self.resource_path,
+        self.source,
+        span,
+        "Invalid magic comment".to_string(),
+        format!(
+          "`webpackFetchPriority` expected \"low\", \"high\" or \"auto\", but received: {value}"
+        ),
+      ));
+    }
+    fetch_priority
+  }
+
+  fn get_group_options(&mut self, first_arg_span_of_import_call: &Span) -> ChunkGroupOptions {
+    ChunkGroupOptions::default()
+      .name_optional(self.try_extract_webpack_chunk_name(first_arg_span_of_import_call))
+      .preload_order_optional(
+        self.try_extract_webpack_order(first_arg_span_of_import_call, "webpackPreload"),
+      )
+      .prefetch_order_optional(
+        self.try_extract_webpack_order(first_arg_span_of_import_call, "webpackPrefetch"),
+      )
+      .fetch_priority_optional(
+        self.try_extract_webpack_fetch_priority(first_arg_span_of_import_call),
+      )
+  }
 }
 
 impl Visit for ImportScanner<'_> {
   noop_visit_type!();
 
   fn visit_call_expr(&mut self, node: &CallExpr) {
+    if self.claimed_calls.contains(node) {
+      return;
+    }
     if let Callee::Import(import_call) = node.callee {
       if let Some(dyn_imported) = node.args.get(0) {
         if dyn_imported.spread.is_none() {
           match dyn_imported.expr.as_ref() {
             Expr::Lit(Lit::Str(imported)) => {
-              let chunk_name = self.try_extract_webpack_chunk_name(&imported.span);
+              let group_options = self.get_group_options(&imported.span);
               self.dependencies.push(Box::new(ImportDependency::new(
                 node.span.real_lo(),
                 node.span.real_hi(),
                 imported.value.clone(),
                 Some(node.span.into()),
-                ChunkGroupOptions::default().name_optional(chunk_name),
+                group_options,
               )));
             }
             Expr::Tpl(tpl) if tpl.quasis.len() == 1 => {
-              let chunk_name = self.try_extract_webpack_chunk_name(&tpl.span);
+              let group_options = self.get_group_options(&tpl.span);
               let request = JsWord::from(
                 tpl
                   .quasis
@@ -100,35 +237,40 @@ impl Visit for ImportScanner<'_> {
                 node.span.real_hi(),
                 request,
                 Some(node.span.into()),
-                ChunkGroupOptions::default().name_optional(chunk_name),
+                group_options,
               )));
             }
-            _ => {
-              if let Some((context, reg)) = scanner_context_module(dyn_imported.expr.as_ref()) {
-                self
-                  .dependencies
-                  .push(Box::new(ImportContextDependency::new(
-                    import_call.span.real_lo(),
-                    import_call.span.real_hi(),
-                    node.span.real_hi(),
-                    ContextOptions {
-                      mode: ContextMode::Lazy,
-                      recursive: true,
-                      reg_exp: RspackRegex::new(&reg).expect("reg failed"),
-                      reg_str: reg,
-                      include: None,
-                      exclude: None,
-                      category: DependencyCategory::Esm,
-                      request: context,
-                      namespace_object: if self.build_meta.strict_harmony_module {
-                        ContextNameSpaceObject::Strict
-                      } else {
-                        ContextNameSpaceObject::Bool(true)
-                      },
+            expr => {
+              let Some((context, reg)) = scanner_context_module(expr) else {
+                self.warn_expression_import(node, &import_call);
+                // The request is evaluated at runtime, and it may contain other imports
+                node.visit_children_with(self);
+                return;
+              };
+              self
+                .dependencies
+                .push(Box::new(ImportContextDependency::new(
+                  import_call.span.real_lo(),
+                  import_call.span.real_hi(),
+                  node.span.real_hi(),
+                  ContextOptions {
+                    mode: ContextMode::Lazy,
+                    recursive: true,
+                    reg_exp: RspackRegex::new(&reg).expect("reg failed"),
+                    reg_str: reg,
+                    include: None,
+                    exclude: None,
+                    category: DependencyCategory::Esm,
+                    request: context,
+                    namespace_object: if self.build_meta.strict_harmony_module {
+                      ContextNameSpaceObject::Strict
+                    } else {
+                      ContextNameSpaceObject::Bool(true)
                     },
-                    Some(node.span.into()),
-                  )));
-              }
+                    chunk_name: self.try_extract_webpack_chunk_name(&expr.span()),
+                  },
+                  Some(node.span.into()),
+                )));
             }
           }
         }
@@ -0,0 +1,60 @@
+use rspack_core::{
+  BoxDependency, BoxDependencyTemplate, BuildMeta, JsParserHandlerContext, JsParserHandlers,
+};
+use rspack_error::Error;
+use swc_core::common::SyntaxContext;
+use swc_core::ecma::{
+  ast::CallExpr,
+  visit::{noop_visit_type, Visit, VisitWith},
+};
+
+use super::ClaimedCalls;
+
+/// Call the handlers registered by the plugins, the calls claimed by the handlers are recorded
+/// in `claimed_calls` and skipped by the built-in scanners.
+pub struct JsParserHandlersScanner<'a> {
+  handlers: &'a JsParserHandlers,
+  claimed_calls: &'a mut ClaimedCalls,
+  context: JsParserHandlerContext<'a>,
+}
+
+impl<'a> JsParserHandlersScanner<'a> {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    handlers: &'a JsParserHandlers,
+    claimed_calls: &'a mut ClaimedCalls,
+    unresolved_ctxt: &'a SyntaxContext,
+    dependencies: &'a mut Vec<BoxDependency>,
+    presentational_dependencies: &'a mut Vec<BoxDependencyTemplate>,
+    build_meta: &'a mut BuildMeta,
+    errors: &'a mut Vec<Error>,
+    resource_path: &'a str,
+    source: &'a str,
+  ) -> Self {
+    Self {
+      handlers,
+      claimed_calls,
+      context: JsParserHandlerContext {
+        unresolved_ctxt,
+        dependencies,
+        presentational_dependencies,
+        build_meta,
+        errors,
+        resource_path,
+        source,
+      },
+    }
+  }
+}
+
+impl Visit for JsParserHandlersScanner<'_> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if self.handlers.handle_call(call_expr, &mut self.context) {
+      self.claimed_calls.claim(call_expr);
+      return;
+    }
+    call_expr.visit_children_with(self);
+  }
+}
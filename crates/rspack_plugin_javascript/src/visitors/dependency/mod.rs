@@ -1,3 +1,4 @@
+mod amd_define_scanner;
 mod api_scanner;
 mod common_js_export_scanner;
 mod common_js_import_dependency_scanner;
@@ -11,20 +12,24 @@ mod harmony_import_dependency_scanner;
 mod hot_module_replacement_scanner;
 mod import_meta_scanner;
 mod import_scanner;
+mod js_parser_handlers_scanner;
 mod node_stuff_scanner;
+mod provide_scanner;
 mod require_context_scanner;
 mod url_scanner;
 mod util;
 mod worker_scanner;
 use rspack_core::{
   ast::javascript::Program, BoxDependency, BoxDependencyTemplate, BuildInfo, BuildMeta,
-  CompilerOptions, ModuleIdentifier, ModuleType, ResourceData,
+  CompilerOptions, JsParserHandlers, ModuleIdentifier, ModuleType, ResourceData,
 };
+use rspack_error::Error;
 use swc_core::common::{comments::Comments, Mark, SyntaxContext};
 pub use util::*;
 
 use self::{
-  api_scanner::ApiScanner, common_js_export_scanner::CommonJsExportDependencyScanner,
+  amd_define_scanner::AmdDefineScanner, api_scanner::ApiScanner,
+  common_js_export_scanner::CommonJsExportDependencyScanner,
   common_js_import_dependency_scanner::CommonJsImportDependencyScanner,
   common_js_scanner::CommonJsScanner, compatibility_scanner::CompatibilityScanner,
   export_info_api_scanner::ExportInfoApiScanner,
@@ -33,15 +38,21 @@ use self::{
   harmony_import_dependency_scanner::HarmonyImportDependencyScanner,
   hot_module_replacement_scanner::HotModuleReplacementScanner,
   import_meta_scanner::ImportMetaScanner, import_scanner::ImportScanner,
-  node_stuff_scanner::NodeStuffScanner, require_context_scanner::RequireContextScanner,
+  js_parser_handlers_scanner::JsParserHandlersScanner, node_stuff_scanner::NodeStuffScanner,
+  provide_scanner::ProvideScanner, require_context_scanner::RequireContextScanner,
   url_scanner::UrlScanner, worker_scanner::WorkerScanner,
 };
 
-pub type ScanDependenciesResult = (Vec<BoxDependency>, Vec<BoxDependencyTemplate>);
+pub struct ScanDependenciesResult {
+  pub dependencies: Vec<BoxDependency>,
+  pub presentational_dependencies: Vec<BoxDependencyTemplate>,
+  pub errors: Vec<Error>,
+}
 
 #[allow(clippy::too_many_arguments)]
 pub fn scan_dependencies(
   program: &Program,
+  source: &str,
   unresolved_mark: Mark,
   resource_data: &ResourceData,
   compiler_options: &CompilerOptions,
@@ -49,16 +60,35 @@ pub fn scan_dependencies(
   build_info: &mut BuildInfo,
   build_meta: &mut BuildMeta,
   module_identifier: ModuleIdentifier,
+  js_parser_handlers: &JsParserHandlers,
 ) -> ScanDependenciesResult {
   let mut dependencies: Vec<BoxDependency> = vec![];
   let mut presentational_dependencies: Vec<BoxDependencyTemplate> = vec![];
+  let mut errors = vec![];
   let unresolved_ctxt = SyntaxContext::empty().apply_mark(unresolved_mark);
   let comments = program.comments.clone();
   let mut parser_exports_state = None;
+  // The calls claimed by the handlers of the plugins are skipped by the built-in scanners
+  let mut claimed_calls = ClaimedCalls::default();
+  if !js_parser_handlers.is_empty() {
+    program.visit_with(&mut JsParserHandlersScanner::new(
+      js_parser_handlers,
+      &mut claimed_calls,
+      &unresolved_ctxt,
+      &mut dependencies,
+      &mut presentational_dependencies,
+      build_meta,
+      &mut errors,
+      &resource_data.resource_path.to_string_lossy(),
+      source,
+    ));
+  }
   program.visit_with(&mut ApiScanner::new(
     &unresolved_ctxt,
     resource_data,
     &mut presentational_dependencies,
+    build_info,
+    &claimed_calls,
   ));
 
   program.visit_with(&mut CompatibilityScanner::new(
@@ -68,27 +98,62 @@ pub fn scan_dependencies(
   program.visit_with(&mut ExportInfoApiScanner::new(
     &mut presentational_dependencies,
     unresolved_ctxt,
+    &claimed_calls,
   ));
 
-  // TODO it should enable at js/auto or js/dynamic, but builtins provider will inject require at esm
+  if !compiler_options.builtins.provide.is_empty() {
+    program.visit_with(&mut ProvideScanner::new(
+      &mut dependencies,
+      &unresolved_ctxt,
+      &compiler_options.builtins.provide,
+      &claimed_calls,
+    ));
+  }
+
+  // TODO it should enable at js/auto or js/dynamic
   program.visit_with(&mut CommonJsImportDependencyScanner::new(
     &mut dependencies,
     &mut presentational_dependencies,
     &unresolved_ctxt,
+    &mut errors,
+    &resource_data.resource_path.to_string_lossy(),
+    source,
+    compiler_options.require_cache,
+    &claimed_calls,
   ));
   if module_type.is_js_auto() || module_type.is_js_dynamic() {
     program.visit_with(&mut CommonJsScanner::new(
       &mut presentational_dependencies,
       &unresolved_ctxt,
+      build_info,
+      &claimed_calls,
+    ));
+    program.visit_with(&mut RequireContextScanner::new(
+      &mut dependencies,
+      &mut errors,
+      &resource_data.resource_path.to_string_lossy(),
+      source,
+      &claimed_calls,
     ));
-    program.visit_with(&mut RequireContextScanner::new(&mut dependencies));
     program.visit_with(&mut CommonJsExportDependencyScanner::new(
       &mut presentational_dependencies,
       &unresolved_ctxt,
       build_meta,
       *module_type,
       &mut parser_exports_state,
+      &claimed_calls,
     ));
+    if compiler_options.amd {
+      program.visit_with(&mut AmdDefineScanner::new(
+        &mut dependencies,
+        &mut presentational_dependencies,
+        &unresolved_ctxt,
+        build_meta,
+        *module_type,
+        &mut parser_exports_state,
+        &claimed_calls,
+      ));
+    }
     if let Some(node_option) = &compiler_options.node {
       program.visit_with(&mut NodeStuffScanner::new(
         &mut presentational_dependencies,
@@ -96,6 +161,7 @@ pub fn scan_dependencies(
         compiler_options,
         node_option,
         resource_data,
+        &claimed_calls,
       ));
     }
   }
@@ -105,7 +171,11 @@ pub fn scan_dependencies(
       build_info,
       build_meta,
       module_type,
+      compiler_options.experiments.top_level_await,
       &mut presentational_dependencies,
+      &mut errors,
+      &resource_data.resource_path.to_string_lossy(),
+      source,
     ));
     let mut import_map = Default::default();
     program.visit_with(&mut HarmonyImportDependencyScanner::new(
@@ -127,22 +197,37 @@ pub fn scan_dependencies(
       &module_identifier,
       &compiler_options.output,
       worker_syntax_list,
+      &claimed_calls,
     );
     program.visit_with(&mut worker_scanner);
     dependencies.append(&mut worker_scanner.dependencies);
     presentational_dependencies.append(&mut worker_scanner.presentational_dependencies);
-    program.visit_with(&mut UrlScanner::new(&mut dependencies, worker_syntax_list));
+    program.visit_with(&mut UrlScanner::new(
+      &mut dependencies,
+      worker_syntax_list,
+      &mut errors,
+      &resource_data.resource_path.to_string_lossy(),
+      source,
+      &claimed_calls,
+    ));
     program.visit_with(&mut ImportMetaScanner::new(
       &mut presentational_dependencies,
       resource_data,
       compiler_options,
+      build_meta,
+      &claimed_calls,
     ));
   }
 
   program.visit_with(&mut ImportScanner::new(
     &mut dependencies,
+    &mut presentational_dependencies,
     comments.as_ref().map(|c| c as &dyn Comments),
     build_meta,
+    &mut errors,
+    &resource_data.resource_path.to_string_lossy(),
+    source,
+    &claimed_calls,
   ));
 
   if compiler_options.dev_server.hot {
@@ -150,8 +235,13 @@ pub fn scan_dependencies(
       &mut dependencies,
       &mut presentational_dependencies,
       build_meta,
+      &claimed_calls,
     ));
   }
 
-  (dependencies, presentational_dependencies)
+  ScanDependenciesResult {
+    dependencies,
+    presentational_dependencies,
+    errors,
+  }
 }
@@ -4,9 +4,11 @@ use rspack_core::{
 };
 use sugar_path::SugarPath;
 use swc_core::common::SyntaxContext;
-use swc_core::ecma::ast::Ident;
+use swc_core::ecma::ast::{CallExpr, Ident, Prop};
 use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
 
+use super::ClaimedCalls;
+
 const DIR_NAME: &str = "__dirname";
 const FILE_NAME: &str = "__filename";
 const GLOBAL: &str = "global";
@@ -17,6 +19,7 @@ pub struct NodeStuffScanner<'a> {
   pub compiler_options: &'a CompilerOptions,
   pub node_option: &'a NodeOption,
   pub resource_data: &'a ResourceData,
+  pub claimed_calls: &'a ClaimedCalls,
 }
 
 impl<'a> NodeStuffScanner<'a> {
@@ -26,6 +29,7 @@ impl<'a> NodeStuffScanner<'a> {
     compiler_options: &'a CompilerOptions,
     node_option: &'a NodeOption,
     resource_data: &'a ResourceData,
+    claimed_calls: &'a ClaimedCalls,
   ) -> Self {
     Self {
       presentational_dependencies,
@@ -33,6 +37,58 @@ impl<'a> NodeStuffScanner<'a> {
       compiler_options,
       node_option,
       resource_data,
+      claimed_calls,
+    }
+  }
+}
+
+impl NodeStuffScanner<'_> {
+  /// The replacement of the free `__dirname`, `__filename` and `global`. `false` and `"eval-only"`
+  /// leave them untouched, so they are resolved by node at runtime, as well as a resource
+  /// without a parent directory.
+  fn replacement(&self, ident: &Ident) -> Option<(String, Option<RuntimeGlobals>)> {
+    if ident.span.ctxt != *self.unresolved_ctxt {
+      return None;
+    }
+    match ident.sym.as_ref() as &str {
+      DIR_NAME => {
+        let dirname = match self.node_option.dirname.as_str() {
+          "mock" | "warn-mock" => "/".to_string(),
+          "true" => self
+            .resource_data
+            .resource_path
+            .parent()?
+            .relative(&self.compiler_options.context)
+            .to_string_lossy()
+            .to_string(),
+          _ => return None,
+        };
+        Some((
+          serde_json::to_string(&dirname).expect("should render dirname"),
+          None,
+        ))
+      }
+      FILE_NAME => {
+        let filename = match self.node_option.filename.as_str() {
+          "mock" | "warn-mock" => "/index.js".to_string(),
+          "true" => self
+            .resource_data
+            .resource_path
+            .relative(&self.compiler_options.context)
+            .to_string_lossy()
+            .to_string(),
+          _ => return None,
+        };
+        Some((
+          serde_json::to_string(&filename).expect("should render filename"),
+          None,
+        ))
+      }
+      GLOBAL if matches!(self.node_option.global.as_str(), "true" | "warn") => Some((
+        RuntimeGlobals::GLOBAL.name().to_string(),
+        Some(RuntimeGlobals::GLOBAL),
+      )),
+      _ => None,
     }
   }
 }
@@ -40,79 +96,41 @@ impl<'a> NodeStuffScanner<'a> {
 impl Visit for NodeStuffScanner<'_> {
   noop_visit_type!();
 
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if self.claimed_calls.contains(call_expr) {
+      return;
+    }
+    call_expr.visit_children_with(self);
+  }
+
   fn visit_ident(&mut self, ident: &Ident) {
-    if ident.span.ctxt == *self.unresolved_ctxt {
-      match ident.sym.as_ref() as &str {
-        DIR_NAME => {
-          let dirname = match self.node_option.dirname.as_str() {
-            "mock" => Some("/".to_string()),
-            "warn-mock" => Some("/".to_string()),
-            "true" => Some(
-              self
-                .resource_data
-                .resource_path
-                .parent()
-                .expect("TODO:")
-                .relative(&self.compiler_options.context)
-                .to_string_lossy()
-                .to_string(),
-            ),
-            _ => None,
-          };
-          if let Some(dirname) = dirname {
-            self
-              .presentational_dependencies
-              .push(Box::new(ConstDependency::new(
-                ident.span.real_lo(),
-                ident.span.real_hi(),
-                format!("'{dirname}'").into(),
-                None,
-              )));
-          }
-        }
-        FILE_NAME => {
-          let filename = match self.node_option.filename.as_str() {
-            "mock" => Some("/index.js".to_string()),
-            "warn-mock" => Some("/index.js".to_string()),
-            "true" => Some(
-              self
-                .resource_data
-                .resource_path
-                .relative(&self.compiler_options.context)
-                .to_string_lossy()
-                .to_string(),
-            ),
-            _ => None,
-          };
-          if let Some(filename) = filename {
-            self
-              .presentational_dependencies
-              .push(Box::new(ConstDependency::new(
-                ident.span.real_lo(),
-                ident.span.real_hi(),
-                serde_json::to_string(&filename)
-                  .expect("should render filename")
-                  .into(),
-                None,
-              )));
-          }
-        }
-        GLOBAL => {
-          if matches!(self.node_option.global.as_str(), "true" | "warn") {
-            self
-              .presentational_dependencies
-              .push(Box::new(ConstDependency::new(
-                ident.span.real_lo(),
-                ident.span.real_hi(),
-                RuntimeGlobals::GLOBAL.name().into(),
-                Some(RuntimeGlobals::GLOBAL),
-              )));
-          }
-        }
-        _ => {}
-      }
-    } else {
-      ident.visit_children_with(self);
+    if let Some((content, runtime_requirements)) = self.replacement(ident) {
+      self
+        .presentational_dependencies
+        .push(Box::new(ConstDependency::new(
+          ident.span.real_lo(),
+          ident.span.real_hi(),
+          content.into(),
+          runtime_requirements,
+        )));
+    }
+  }
+
+  fn visit_prop(&mut self, prop: &Prop) {
+    // `{ __dirname }` should be expanded to `{ __dirname: "/" }`
+    if let Prop::Shorthand(ident) = prop
+      && let Some((content, runtime_requirements)) = self.replacement(ident)
+    {
+      self
+        .presentational_dependencies
+        .push(Box::new(ConstDependency::new(
+          ident.span.real_lo(),
+          ident.span.real_hi(),
+          format!("{}: {content}", ident.sym).into(),
+          runtime_requirements,
+        )));
+      return;
     }
+    prop.visit_children_with(self);
   }
 }
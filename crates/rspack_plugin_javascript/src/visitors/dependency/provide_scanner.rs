@@ -0,0 +1,204 @@
+use indexmap::IndexMap;
+use rspack_core::{BoxDependency, Provide, SpanExt};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use swc_core::common::{Span, SyntaxContext};
+use swc_core::ecma::ast::{
+  CallExpr, Callee, Class, Expr, Function, GetterProp, Id, Ident, MemberProp, Pat, Program, Prop,
+  SetterProp,
+};
+use swc_core::ecma::atoms::JsWord;
+use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
+
+use super::ClaimedCalls;
+use crate::dependency::ProvidedDependency;
+
+/// Replace the free variables configured by `builtins.provide` with the provided modules.
+pub struct ProvideScanner<'a> {
+  dependencies: &'a mut Vec<BoxDependency>,
+  unresolved_ctxt: &'a SyntaxContext,
+  provide: &'a Provide,
+  /// The first segment of each key, e.g. `process` for `process.env`
+  roots: HashSet<JsWord>,
+  /// The dependency of each provided key, in the order of the first usage
+  provided: IndexMap<String, ProvidedDependency>,
+  /// The parameters of IIFEs which are passed a provided free variable directly,
+  /// e.g. `process` in `(function (process) {})(process)` is still the provided one.
+  renamed: HashMap<Id, JsWord>,
+  /// Depth of the functions which have their own `this`
+  function_depth: usize,
+  claimed_calls: &'a ClaimedCalls,
+}
+
+impl<'a> ProvideScanner<'a> {
+  pub fn new(
+    dependencies: &'a mut Vec<BoxDependency>,
+    unresolved_ctxt: &'a SyntaxContext,
+    provide: &'a Provide,
+    claimed_calls: &'a ClaimedCalls,
+  ) -> Self {
+    let roots = provide
+      .keys()
+      .filter_map(|key| key.split('.').next())
+      .map(JsWord::from)
+      .collect();
+    Self {
+      dependencies,
+      unresolved_ctxt,
+      provide,
+      roots,
+      provided: Default::default(),
+      renamed: Default::default(),
+      function_depth: 0,
+      claimed_calls,
+    }
+  }
+
+  /// The name of the free variable `ident` refers to, which isn't shadowed by a local binding.
+  fn free_name(&self, ident: &Ident) -> Option<JsWord> {
+    if ident.span.ctxt == *self.unresolved_ctxt {
+      Some(ident.sym.clone())
+    } else {
+      self.renamed.get(&ident.to_id()).cloned()
+    }
+  }
+
+  /// The segments of a member chain rooted at a free variable, with the span of each prefix.
+  /// e.g. `process.env.NODE_ENV` is `[("process", ..), ("env", ..), ("NODE_ENV", ..)]`.
+  fn member_chain(&self, expr: &Expr) -> Option<Vec<(JsWord, Span)>> {
+    match expr {
+      Expr::Ident(ident) => Some(vec![(self.free_name(ident)?, ident.span)]),
+      Expr::This(this) if self.function_depth == 0 => Some(vec![("this".into(), this.span)]),
+      Expr::Member(member) => {
+        let MemberProp::Ident(prop) = &member.prop else {
+          return None;
+        };
+        let mut chain = self.member_chain(&member.obj)?;
+        chain.push((prop.sym.clone(), member.span));
+        Some(chain)
+      }
+      _ => None,
+    }
+  }
+
+  /// Replace the longest prefix of the chain which is provided, returns `false` if there is none.
+  fn provide_chain(&mut self, chain: &[(JsWord, Span)]) -> bool {
+    if !self.roots.contains(&chain[0].0) {
+      return false;
+    }
+    let mut names = Vec::with_capacity(chain.len());
+    let mut name = String::new();
+    for (segment, _) in chain {
+      if !name.is_empty() {
+        name.push('.');
+      }
+      name.push_str(segment);
+      names.push(name.clone());
+    }
+    for (name, (_, span)) in names.iter().zip(chain).rev() {
+      if self.provide.contains_key(name) {
+        self.add_provided(name, *span, None);
+        return true;
+      }
+    }
+    false
+  }
+
+  fn add_provided(&mut self, name: &str, span: Span, shorthand: Option<JsWord>) {
+    let Some((request, ids)) = self.provide.get(name).and_then(|value| value.split_first()) else {
+      return;
+    };
+    let dependency = self.provided.entry(name.to_string()).or_insert_with(|| {
+      ProvidedDependency::new(
+        name,
+        request.as_str().into(),
+        ids.to_vec(),
+        Some(span.into()),
+      )
+    });
+    dependency.add_range(span.real_lo(), span.real_hi(), shorthand);
+  }
+}
+
+impl Visit for ProvideScanner<'_> {
+  noop_visit_type!();
+
+  fn visit_program(&mut self, program: &Program) {
+    program.visit_children_with(self);
+    self.dependencies.extend(
+      self
+        .provided
+        .drain(..)
+        .map(|(_, dependency)| Box::new(dependency) as BoxDependency),
+    );
+  }
+
+  fn visit_expr(&mut self, expr: &Expr) {
+    if let Some(chain) = self.member_chain(expr)
+      && self.provide_chain(&chain)
+    {
+      return;
+    }
+    expr.visit_children_with(self);
+  }
+
+  fn visit_prop(&mut self, prop: &Prop) {
+    if let Prop::Shorthand(ident) = prop
+      && let Some(name) = self.free_name(ident)
+    {
+      self.add_provided(&name, ident.span, Some(ident.sym.clone()));
+      return;
+    }
+    prop.visit_children_with(self);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if self.claimed_calls.contains(call_expr) {
+      return;
+    }
+    if let Callee::Expr(callee) = &call_expr.callee {
+      let mut callee = &**callee;
+      while let Expr::Paren(paren) = callee {
+        callee = &paren.expr;
+      }
+      let params: Vec<&Pat> = match callee {
+        Expr::Fn(fn_expr) => fn_expr.function.params.iter().map(|p| &p.pat).collect(),
+        Expr::Arrow(arrow) => arrow.params.iter().collect(),
+        _ => vec![],
+      };
+      for (param, arg) in params.into_iter().zip(&call_expr.args) {
+        if arg.spread.is_none()
+          && let (Pat::Ident(param), Expr::Ident(arg)) = (param, &*arg.expr)
+          && let Some(name) = self.free_name(arg)
+          && self.roots.contains(&name)
+        {
+          self.renamed.insert(param.id.to_id(), name);
+        }
+      }
+    }
+    call_expr.visit_children_with(self);
+  }
+
+  fn visit_function(&mut self, function: &Function) {
+    self.function_depth += 1;
+    function.visit_children_with(self);
+    self.function_depth -= 1;
+  }
+
+  fn visit_class(&mut self, class: &Class) {
+    self.function_depth += 1;
+    class.visit_children_with(self);
+    self.function_depth -= 1;
+  }
+
+  fn visit_getter_prop(&mut self, getter: &GetterProp) {
+    self.function_depth += 1;
+    getter.visit_children_with(self);
+    self.function_depth -= 1;
+  }
+
+  fn visit_setter_prop(&mut self, setter: &SetterProp) {
+    self.function_depth += 1;
+    setter.visit_children_with(self);
+    self.function_depth -= 1;
+  }
+}
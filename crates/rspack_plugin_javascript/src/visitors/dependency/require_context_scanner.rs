@@ -1,22 +1,53 @@
 use rspack_core::{
-  BoxDependency, ContextMode, ContextNameSpaceObject, ContextOptions, DependencyCategory, SpanExt,
+  BoxDependency, ContextMode, ContextNameSpaceObject, ContextOptions, DependencyCategory,
+  ErrorSpan, SpanExt,
 };
+use rspack_error::Error;
 use rspack_regex::RspackRegex;
+use swc_core::common::Span;
 use swc_core::ecma::{
   ast::{CallExpr, Lit},
   visit::{noop_visit_type, Visit, VisitWith},
 };
 
-use super::is_require_context_call;
+use super::{is_require_context_call, ClaimedCalls};
 use crate::dependency::RequireContextDependency;
 
 pub struct RequireContextScanner<'a> {
   pub dependencies: &'a mut Vec<BoxDependency>,
+  errors: &'a mut Vec<Error>,
+  resource_path: &'a str,
+  source: &'a str,
+  /// The calls claimed by the handlers of the plugins, see [rspack_core::JsParserHandlers]
+  claimed_calls: &'a ClaimedCalls,
 }
 
 impl<'a> RequireContextScanner<'a> {
-  pub fn new(dependencies: &'a mut Vec<BoxDependency>) -> Self {
-    Self { dependencies }
+  pub fn new(
+    dependencies: &'a mut Vec<BoxDependency>,
+    errors: &'a mut Vec<Error>,
+    resource_path: &'a str,
+    source: &'a str,
+    claimed_calls: &'a ClaimedCalls,
+  ) -> Self {
+    Self {
+      dependencies,
+      errors,
+      resource_path,
+      source,
+      claimed_calls,
+    }
+  }
+
+  fn add_error(&mut self, span: Span, message: String) {
+    self.errors.push(Error::TraceableError(
+      ErrorSpan::from(span).to_traceable_error(
+        self.resource_path,
+        self.source,
+        "Module parse failed".to_string(),
+        message,
+      ),
+    ));
   }
 }
 
@@ -24,6 +55,9 @@ impl Visit for RequireContextScanner<'_> {
   noop_visit_type!();
 
   fn visit_call_expr(&mut self, node: &CallExpr) {
+    if self.claimed_calls.contains(node) {
+      return;
+    }
     if is_require_context_call(node) && !node.args.is_empty() {
       if let Some(Lit::Str(str)) = node.args.get(0).and_then(|x| x.expr.as_lit()) {
         let recursive =
@@ -35,10 +69,16 @@ impl Visit for RequireContextScanner<'_> {
 
         let (reg_exp, reg_str) =
           if let Some(Lit::Regex(regex)) = node.args.get(2).and_then(|x| x.expr.as_lit()) {
-            (
-              RspackRegex::try_from(regex).expect("reg failed"),
-              format!("{}|{}", regex.exp, regex.flags),
-            )
+            match RspackRegex::try_from(regex) {
+              Ok(reg_exp) => (reg_exp, format!("{}|{}", regex.exp, regex.flags)),
+              Err(err) => {
+                self.add_error(
+                  regex.span,
+                  format!("Invalid regular expression in require.context: {err}"),
+                );
+                return;
+              }
+            }
           } else {
             (
               RspackRegex::new(r"^\.\/.*$").expect("reg failed"),
@@ -53,8 +93,15 @@ impl Visit for RequireContextScanner<'_> {
             "weak" => ContextMode::Weak,
             "lazy" => ContextMode::Lazy,
             "lazy-once" => ContextMode::LazyOnce,
-            // TODO should give warning
-            _ => unreachable!("unknown context mode"),
+            mode => {
+              self.add_error(
+                str.span,
+                format!(
+                  "Unsupported require.context mode \"{mode}\", expected one of \"sync\", \"eager\", \"weak\", \"lazy\" or \"lazy-once\""
+                ),
+              );
+              return;
+            }
           }
         } else {
           ContextMode::Sync
@@ -74,6 +121,7 @@ impl Visit for RequireContextScanner<'_> {
               category: DependencyCategory::CommonJS,
               request: str.value.to_string(),
               namespace_object: ContextNameSpaceObject::Unset,
+              chunk_name: None,
             },
             Some(node.span.into()),
           )));
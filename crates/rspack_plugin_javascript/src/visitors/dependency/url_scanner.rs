@@ -1,14 +1,21 @@
 use rspack_core::BoxDependency;
+use rspack_error::Error;
+use swc_core::common::Spanned;
 use swc_core::ecma::{
-  ast::NewExpr,
+  ast::{CallExpr, Expr, Ident, NewExpr},
   visit::{noop_visit_type, Visit, VisitWith},
 };
 
+use super::{create_traceable_warning, expr_matcher, ClaimedCalls};
 use crate::dependency::URLDependency;
 
 pub struct UrlScanner<'a> {
   pub dependencies: &'a mut Vec<BoxDependency>,
   worker_syntax_list: &'a rspack_core::needs_refactor::WorkerSyntaxList,
+  errors: &'a mut Vec<Error>,
+  resource_path: &'a str,
+  source: &'a str,
+  claimed_calls: &'a ClaimedCalls,
 }
 
 // new URL("./foo.png", import.meta.url);
@@ -16,17 +23,55 @@ impl<'a> UrlScanner<'a> {
   pub fn new(
     dependencies: &'a mut Vec<BoxDependency>,
     worker_syntax_list: &'a rspack_core::needs_refactor::WorkerSyntaxList,
+    errors: &'a mut Vec<Error>,
+    resource_path: &'a str,
+    source: &'a str,
+    claimed_calls: &'a ClaimedCalls,
   ) -> Self {
     Self {
       dependencies,
       worker_syntax_list,
+      errors,
+      resource_path,
+      source,
+      claimed_calls,
     }
   }
+
+  /// `new URL(expr, import.meta.url)` whose first argument is not a string literal
+  /// can't be resolved at build time, warn about it and leave it as is.
+  fn warn_unresolvable_url(&mut self, new_expr: &NewExpr) {
+    let Some(args) = &new_expr.args else {
+      return;
+    };
+    let is_url = matches!(&*new_expr.callee, Expr::Ident(Ident { sym, .. }) if sym == "URL");
+    if !is_url
+      || args.len() < 2
+      || args.iter().any(|arg| arg.spread.is_some())
+      || !expr_matcher::is_import_meta_url(&args[1].expr)
+    {
+      return;
+    }
+    self.errors.push(create_traceable_warning(
+      self.resource_path,
+      self.source,
+      args[0].expr.span(),
+      "new URL() with a non-literal request".to_string(),
+      "The request of `new URL(request, import.meta.url)` can't be statically analyzed, it is left as is and the asset won't be emitted".to_string(),
+    ));
+  }
 }
 
 impl Visit for UrlScanner<'_> {
   noop_visit_type!();
 
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if self.claimed_calls.contains(call_expr) {
+      return;
+    }
+    call_expr.visit_children_with(self);
+  }
+
   fn visit_new_expr(&mut self, new_expr: &NewExpr) {
     // TODO: https://github.com/web-infra-dev/rspack/discussions/3619
     if self.worker_syntax_list.match_new_worker(new_expr) && let Some(args) = &new_expr.args {
@@ -43,6 +88,7 @@ impl Visit for UrlScanner<'_> {
         Some(new_expr.span.into()),
       )));
     } else {
+      self.warn_unresolvable_url(new_expr);
       new_expr.visit_children_with(self);
     }
   }
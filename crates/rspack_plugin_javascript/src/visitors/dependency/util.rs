@@ -1,11 +1,59 @@
+use rspack_core::ErrorSpan;
+use rspack_error::{Error, Severity};
+use rustc_hash::FxHashSet as HashSet;
 use swc_core::{
-  common::{pass::AstNodePath, SyntaxContext},
+  common::{pass::AstNodePath, Span, SyntaxContext},
   ecma::{
     ast::{CallExpr, Expr, MemberExpr},
     visit::{AstParentKind, AstParentNodeRef},
   },
 };
 
+/// The calls claimed by the handlers of the plugins, see [rspack_core::JsParserHandlers].
+///
+/// The calls are identified by their addresses in the AST rather than their spans,
+/// since the nodes generated by the transformers may share the same span.
+#[derive(Debug, Default)]
+pub struct ClaimedCalls(HashSet<*const CallExpr>);
+
+impl ClaimedCalls {
+  pub fn claim(&mut self, call_expr: &CallExpr) {
+    self.0.insert(call_expr as *const CallExpr);
+  }
+
+  pub fn contains(&self, call_expr: &CallExpr) -> bool {
+    self.0.contains(&(call_expr as *const CallExpr))
+  }
+}
+
+/// A warning about the code at `span` of the module, rendered with the excerpt of the source.
+pub fn create_traceable_warning(
+  resource_path: &str,
+  source: &str,
+  span: Span,
+  title: String,
+  message: String,
+) -> Error {
+  Error::TraceableError(
+    ErrorSpan::from(span)
+      .to_traceable_error(resource_path, source, title, message)
+      .with_severity(Severity::Warn),
+  )
+}
+
+/// The function to replace the callee of `require(expr)` or `import(expr)` with, which throws, or
+/// rejects if `is_async`, with a `Cannot find module` error of the request evaluated at runtime.
+pub fn module_not_found_callee(is_async: bool) -> String {
+  let throw_error = "var e = new Error(\"Cannot find module '\" + request + \"'\"); e.code = 'MODULE_NOT_FOUND'; throw e;";
+  if is_async {
+    format!(
+      "(function(request) {{ return Promise.resolve().then(function() {{ {throw_error} }}); }})"
+    )
+  } else {
+    format!("(function(request) {{ {throw_error} }})")
+  }
+}
+
 pub fn as_parent_path(ast_path: &AstNodePath<AstParentNodeRef<'_>>) -> Vec<AstParentKind> {
   ast_path.iter().map(|n| n.kind()).collect()
 }
@@ -78,6 +126,7 @@ pub(crate) mod expr_matcher {
     is_this_esmodule: "this.__esModule",
     is_module_exports_esmodule: "module.exports.__esModule",
     is_object_define_property: "Object.defineProperty",
+    is_object_assign: "Object.assign",
   });
 }
 
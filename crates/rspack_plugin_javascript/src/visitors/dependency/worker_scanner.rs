@@ -1,17 +1,18 @@
 use std::hash::Hash;
 
 use rspack_core::{
-  BoxDependency, BoxDependencyTemplate, ChunkGroupOptions, ConstDependency, EntryOptions,
-  ModuleIdentifier, OutputOptions, SpanExt,
+  BoxDependency, BoxDependencyTemplate, ChunkGroupOptions, ChunkLoading, ChunkLoadingType,
+  ConstDependency, EntryOptions, ModuleIdentifier, OutputOptions, SpanExt,
 };
 use rspack_hash::RspackHash;
 use swc_core::common::Spanned;
 use swc_core::ecma::ast::ObjectLit;
 use swc_core::ecma::{
-  ast::{Expr, ExprOrSpread, Lit, NewExpr},
+  ast::{CallExpr, Expr, ExprOrSpread, Lit, NewExpr},
   visit::{noop_visit_type, Visit, VisitWith},
 };
 
+use super::ClaimedCalls;
 use crate::dependency::WorkerDependency;
 
 // TODO: should created by WorkerPlugin
@@ -22,6 +23,7 @@ pub struct WorkerScanner<'a> {
   module_identifier: &'a ModuleIdentifier,
   output_options: &'a OutputOptions,
   syntax_list: &'a rspack_core::needs_refactor::WorkerSyntaxList,
+  claimed_calls: &'a ClaimedCalls,
 }
 
 // new Worker(new URL("./foo.worker.js", import.meta.url));
@@ -30,6 +32,7 @@ impl<'a> WorkerScanner<'a> {
     module_identifier: &'a ModuleIdentifier,
     output_options: &'a OutputOptions,
     syntax_list: &'a rspack_core::needs_refactor::WorkerSyntaxList,
+    claimed_calls: &'a ClaimedCalls,
   ) -> Self {
     Self {
       presentational_dependencies: Vec::new(),
@@ -38,6 +41,7 @@ impl<'a> WorkerScanner<'a> {
       module_identifier,
       output_options,
       syntax_list,
+      claimed_calls,
     }
   }
 
@@ -56,8 +60,23 @@ impl<'a> WorkerScanner<'a> {
       .rendered(self.output_options.hash_digest_length)
       .to_owned();
     let range = parsed_options.as_ref().map(|options| options.range);
+    let is_module = self.output_options.module
+      || parsed_options
+        .as_ref()
+        .map_or(false, |options| options.r#type.as_deref() == Some("module"));
+    // A module worker loads its chunks by `import()` if it's enabled, no matter what the output
+    // format is
+    let import_enabled = self
+      .output_options
+      .enabled_chunk_loading_types
+      .as_ref()
+      .map_or(false, |types| types.iter().any(|t| t == "import"));
+    let chunk_loading = if is_module && !self.output_options.module && import_enabled {
+      ChunkLoading::Enable(ChunkLoadingType::Import)
+    } else {
+      self.output_options.worker_chunk_loading.clone()
+    };
     let name = parsed_options.and_then(|options| options.name);
-    let output_module = self.output_options.module;
     self.dependencies.push(Box::new(WorkerDependency::new(
       parsed_path.range.0,
       parsed_path.range.1,
@@ -68,12 +87,16 @@ impl<'a> WorkerScanner<'a> {
         name,
         entry_options: Some(EntryOptions {
           runtime: Some(runtime),
-          chunk_loading: Some(self.output_options.worker_chunk_loading.clone()),
+          chunk_loading: Some(chunk_loading),
           async_chunks: None,
           public_path: None,
           base_uri: None,
           filename: None,
+          depend_on: None,
         }),
+        preload_order: None,
+        prefetch_order: None,
+        fetch_priority: None,
       },
     )));
     if let Some(range) = range {
@@ -92,11 +115,7 @@ impl<'a> WorkerScanner<'a> {
           range.1,
           format!(
             ", {{ type: {} }})",
-            if output_module {
-              "\"module\""
-            } else {
-              "undefined"
-            }
+            if is_module { "\"module\"" } else { "undefined" }
           )
           .into(),
           None,
@@ -128,6 +147,13 @@ impl<'a> WorkerScanner<'a> {
 impl Visit for WorkerScanner<'_> {
   noop_visit_type!();
 
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if self.claimed_calls.contains(call_expr) {
+      return;
+    }
+    call_expr.visit_children_with(self);
+  }
+
   fn visit_new_expr(&mut self, new_expr: &NewExpr) {
     if let Some((parsed_path, parsed_options)) = self.parse_new_worker(new_expr) {
       self.add_dependencies(new_expr, parsed_path, parsed_options);
@@ -147,6 +173,7 @@ struct ParsedNewWorkerPath {
 struct ParsedNewWorkerOptions {
   pub range: (u32, u32),
   pub name: Option<String>,
+  pub r#type: Option<String>,
 }
 
 fn parse_new_worker_options(arg: &ExprOrSpread) -> ParsedNewWorkerOptions {
@@ -172,9 +199,11 @@ fn parse_new_worker_options(arg: &ExprOrSpread) -> ParsedNewWorkerOptions {
 
   let obj = arg.expr.as_object();
   let name = obj.and_then(|obj| get_prop_literal_str(obj, "name"));
+  let r#type = obj.and_then(|obj| get_prop_literal_str(obj, "type"));
   let span = arg.span();
   ParsedNewWorkerOptions {
     range: (span.real_lo(), span.real_hi()),
     name,
+    r#type,
   }
 }
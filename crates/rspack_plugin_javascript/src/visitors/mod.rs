@@ -126,13 +126,9 @@ pub fn run_before_pass(
       // enable if configurable
       // swc_visitor::const_modules(cm, globals),
       Optional::new(
-        swc_visitor::define(&options.builtins.define),
+        swc_visitor::define(&options.builtins.define, unresolved_mark),
         !options.builtins.define.is_empty()
       ),
-      Optional::new(
-        swc_visitor::provide_builtin(&options.builtins.provide, unresolved_mark),
-        !options.builtins.provide.is_empty()
-      ),
       Optional::new(
         swc_visitor::export_default_from(),
         syntax.export_default_from()
@@ -159,8 +155,10 @@ pub fn run_before_pass(
       ),
       swc_visitor::reserved_words(),
       swc_visitor::inject_helpers(unresolved_mark),
-      // The ordering of these two is important, `expr_simplifier` goes first and `dead_branch_remover` goes second.
+      // The ordering of these is important, `expr_simplifier` goes first, then `fold_logical` folds
+      // the tests which `dead_branch_remover` removes the branches of.
       swc_visitor::expr_simplifier(unresolved_mark, Default::default()),
+      swc_visitor::fold_logical(unresolved_mark),
       swc_visitor::dead_branch_remover(unresolved_mark),
       swc_visitor::hygiene(false, top_level_mark),
       swc_visitor::fixer(comments.map(|v| v as &dyn Comments)),
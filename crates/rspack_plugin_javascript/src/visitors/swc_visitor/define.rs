@@ -2,18 +2,24 @@ use std::sync::Arc;
 
 use rspack_core::Define;
 use swc_core::common::collections::AHashMap;
+use swc_core::common::{chain, Mark, DUMMY_SP};
+use swc_core::ecma::ast::{
+  Expr, Ident, Lit, MemberExpr, MemberProp, ObjectLit, Prop, PropName, PropOrSpread, Str,
+  UnaryExpr, UnaryOp,
+};
+use swc_core::ecma::atoms::JsWord;
 use swc_core::ecma::parser::EsConfig;
 use swc_core::ecma::transforms::optimization::inline_globals2;
 use swc_core::ecma::utils::NodeIgnoringSpan;
-use swc_core::ecma::visit::Fold;
+use swc_core::ecma::visit::{as_folder, Fold, VisitMut, VisitMutWith};
 use swc_core::{
   common::FileName,
   ecma::parser::{parse_file_as_expr, Syntax},
 };
 
-pub fn define(opts: &Define) -> impl Fold {
+pub fn define(opts: &Define, unresolved_mark: Mark) -> impl Fold {
   let cm: Arc<swc_core::common::SourceMap> = Default::default();
-  let defs = opts
+  let mut defs = opts
     .iter()
     .map(|(target, replacement)| {
       let target = {
@@ -39,14 +45,159 @@ pub fn define(opts: &Define) -> impl Fold {
         .unwrap_or_else(|_| panic!("builtins.define: Failed to parse {:?}", target))
       };
 
-      (NodeIgnoringSpan::owned(*target), *replacement)
+      (*target, *replacement)
     })
-    .collect::<AHashMap<_, _>>();
+    .collect::<Vec<_>>();
+
+  // Properties of object-valued definitions are defined as well, so `DEFINES.flags.beta`
+  // is replaced by the constant itself and can be folded by the dead branch remover.
+  // Explicit definitions always win over the derived ones.
+  let mut nested = vec![];
+  for (target, replacement) in &defs {
+    define_properties(target, replacement, &mut nested);
+  }
+  defs.splice(0..0, nested);
 
-  inline_globals2(
-    Default::default(),
-    Default::default(),
-    Arc::new(defs),
-    Default::default(),
+  let mut typeofs = AHashMap::default();
+  let mut global_exprs = AHashMap::default();
+  // Later definitions override the earlier ones
+  for (target, replacement) in defs {
+    if let Some(ty) = type_of(&replacement) {
+      typeofs.insert(NodeIgnoringSpan::owned(target.clone()), JsWord::from(ty));
+    } else {
+      typeofs.remove(&NodeIgnoringSpan::borrowed(&target));
+    }
+    global_exprs.insert(NodeIgnoringSpan::owned(target), replacement);
+  }
+
+  chain!(
+    as_folder(DefineTypeof {
+      typeofs,
+      unresolved_mark
+    }),
+    inline_globals2(
+      Default::default(),
+      Default::default(),
+      Arc::new(global_exprs),
+      Default::default(),
+    )
   )
 }
+
+fn define_properties(target: &Expr, replacement: &Expr, defs: &mut Vec<(Expr, Expr)>) {
+  let Some(object) = as_object_lit(replacement) else {
+    return;
+  };
+  // A spread may override any of the properties.
+  if object
+    .props
+    .iter()
+    .any(|prop| matches!(prop, PropOrSpread::Spread(_)))
+  {
+    return;
+  }
+  for prop in &object.props {
+    let PropOrSpread::Prop(prop) = prop else {
+      continue;
+    };
+    let Prop::KeyValue(prop) = &**prop else {
+      continue;
+    };
+    let key: JsWord = match &prop.key {
+      PropName::Ident(ident) => ident.sym.clone(),
+      PropName::Str(str) => str.value.clone(),
+      _ => continue,
+    };
+    // Only the dot access form is matched, just like the keys of `define`
+    if Ident::verify_symbol(&key).is_err() {
+      continue;
+    }
+    let member = Expr::Member(MemberExpr {
+      span: DUMMY_SP,
+      obj: Box::new(target.clone()),
+      prop: MemberProp::Ident(Ident::new(key, DUMMY_SP)),
+    });
+    defs.push((member.clone(), (*prop.value).clone()));
+    define_properties(&member, &prop.value, defs);
+  }
+}
+
+fn as_object_lit(expr: &Expr) -> Option<&ObjectLit> {
+  match expr {
+    Expr::Object(object) => Some(object),
+    Expr::Paren(paren) => as_object_lit(&paren.expr),
+    _ => None,
+  }
+}
+
+/// The result of `typeof` for the defined value, `None` if it can't be known statically.
+fn type_of(expr: &Expr) -> Option<&'static str> {
+  match expr {
+    Expr::Paren(paren) => type_of(&paren.expr),
+    Expr::Lit(Lit::Str(_)) | Expr::Tpl(_) => Some("string"),
+    Expr::Lit(Lit::Num(_)) => Some("number"),
+    Expr::Lit(Lit::Bool(_)) => Some("boolean"),
+    Expr::Lit(Lit::BigInt(_)) => Some("bigint"),
+    Expr::Lit(Lit::Null(_) | Lit::Regex(_)) | Expr::Object(_) | Expr::Array(_) => Some("object"),
+    Expr::Fn(_) | Expr::Arrow(_) | Expr::Class(_) => Some("function"),
+    Expr::Ident(ident) if &*ident.sym == "undefined" => Some("undefined"),
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::Void, ..
+    }) => Some("undefined"),
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::Plus | UnaryOp::Minus,
+      arg,
+      ..
+    }) if matches!(&**arg, Expr::Lit(Lit::Num(_))) => Some("number"),
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::TypeOf,
+      ..
+    }) => Some("string"),
+    _ => None,
+  }
+}
+
+/// Replace `typeof <defined>` with the type of the defined value,
+/// which must run before the definitions are inlined.
+struct DefineTypeof {
+  typeofs: AHashMap<NodeIgnoringSpan<'static, Expr>, JsWord>,
+  unresolved_mark: Mark,
+}
+
+impl DefineTypeof {
+  /// Whether the root of the member chain is a global, which isn't shadowed by a local binding.
+  fn is_global(&self, expr: &Expr) -> bool {
+    match expr {
+      Expr::Ident(ident) => ident.span.has_mark(self.unresolved_mark),
+      Expr::Member(member) => self.is_global(&member.obj),
+      Expr::This(_) => true,
+      _ => false,
+    }
+  }
+}
+
+impl VisitMut for DefineTypeof {
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    if let Expr::Unary(UnaryExpr {
+      op: UnaryOp::TypeOf,
+      arg,
+      span,
+    }) = expr
+      && self.is_global(arg)
+      && let Some(ty) = Ident::within_ignored_ctxt(|| {
+        self
+          .typeofs
+          .get(&NodeIgnoringSpan::borrowed(arg.as_ref()))
+          .cloned()
+      })
+    {
+      *expr = Expr::Lit(Lit::Str(Str {
+        span: *span,
+        value: ty,
+        raw: None,
+      }));
+      return;
+    }
+    expr.visit_mut_children_with(self);
+  }
+}
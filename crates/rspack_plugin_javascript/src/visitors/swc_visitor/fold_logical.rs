@@ -0,0 +1,348 @@
+use swc_core::common::{util::take::Take, Mark, Spanned, SyntaxContext};
+use swc_core::ecma::ast::{
+  op, AssignExpr, BinExpr, Bool, Callee, CondExpr, Expr, IfStmt, Lit, Number, OptChainBase,
+  OptChainExpr, ParenExpr, SeqExpr, UnaryExpr,
+};
+use swc_core::ecma::atoms::js_word;
+use swc_core::ecma::utils::{undefined, ExprCtx, ExprExt, Value};
+use swc_core::ecma::visit::{as_folder, Fold, VisitMut, VisitMutWith};
+
+/// Fold `??`, `?.`, the `&&` / `||` chains and the logical assignments whose result is known
+/// after `builtins.define`, i.e. `process.env.NODE_ENV === "production" || require("./dev")`.
+///
+/// The side effects of the kept operands are preserved, the dropped operands are never evaluated,
+/// so the dependencies inside them aren't created. It's supposed to run between `expr_simplifier`
+/// and `dead_branch_remover`, which removes the branches of `if` and `?:` tests folded here.
+pub fn fold_logical(unresolved_mark: Mark) -> impl Fold {
+  as_folder(FoldLogical {
+    ctx: ExprCtx {
+      unresolved_ctxt: SyntaxContext::empty().apply_mark(unresolved_mark),
+      is_unresolved_ref_safe: false,
+    },
+  })
+}
+
+struct FoldLogical {
+  ctx: ExprCtx,
+}
+
+impl FoldLogical {
+  /// Whether `expr` is truthy, `None` if unknown
+  fn truthiness(&self, expr: &Expr) -> Option<bool> {
+    match expr {
+      Expr::Paren(paren) => self.truthiness(&paren.expr),
+      Expr::Seq(seq) => seq.exprs.last().and_then(|expr| self.truthiness(expr)),
+      Expr::Bin(BinExpr {
+        op: op!("&&"),
+        left,
+        right,
+        ..
+      }) => match self.truthiness(left) {
+        Some(true) => self.truthiness(right),
+        Some(false) => Some(false),
+        None => (self.truthiness(right) == Some(false)).then_some(false),
+      },
+      Expr::Bin(BinExpr {
+        op: op!("||"),
+        left,
+        right,
+        ..
+      }) => match self.truthiness(left) {
+        Some(true) => Some(true),
+        Some(false) => self.truthiness(right),
+        None => (self.truthiness(right) == Some(true)).then_some(true),
+      },
+      Expr::Bin(BinExpr {
+        op: op!("??"),
+        left,
+        right,
+        ..
+      }) => match self.nullishness(left) {
+        Some(true) => self.truthiness(right),
+        Some(false) => self.truthiness(left),
+        None => self
+          .truthiness(left)
+          .filter(|left| Some(*left) == self.truthiness(right)),
+      },
+      Expr::Assign(assign) => self.assign_truthiness(assign),
+      Expr::Cond(CondExpr {
+        test, cons, alt, ..
+      }) => match self.truthiness(test) {
+        Some(true) => self.truthiness(cons),
+        Some(false) => self.truthiness(alt),
+        None => self
+          .truthiness(cons)
+          .filter(|cons| Some(*cons) == self.truthiness(alt)),
+      },
+      Expr::OptChain(chain) if self.short_circuits(chain) => Some(false),
+      _ => match expr.cast_to_bool(&self.ctx).1 {
+        Value::Known(value) => Some(value),
+        Value::Unknown => None,
+      },
+    }
+  }
+
+  /// The value of `a ||= b` is truthy if `b` is truthy, and `a &&= b` is falsy if `b` is falsy
+  fn assign_truthiness(&self, assign: &AssignExpr) -> Option<bool> {
+    let right = self.truthiness(&assign.right);
+    match assign.op {
+      op!("=") => right,
+      op!("||=") => (right == Some(true)).then_some(true),
+      op!("&&=") => (right == Some(false)).then_some(false),
+      _ => None,
+    }
+  }
+
+  /// Whether `expr` is `null` or `undefined`, `None` if unknown
+  fn nullishness(&self, expr: &Expr) -> Option<bool> {
+    match expr {
+      Expr::Paren(paren) => self.nullishness(&paren.expr),
+      Expr::Seq(seq) => seq.exprs.last().and_then(|expr| self.nullishness(expr)),
+      Expr::Lit(Lit::Null(_)) => Some(true),
+      Expr::Ident(ident) => (ident.sym == js_word!("undefined")
+        && ident.span.ctxt == self.ctx.unresolved_ctxt)
+        .then_some(true),
+      Expr::Unary(UnaryExpr {
+        op: op!("void"), ..
+      }) => Some(true),
+      Expr::Lit(_)
+      | Expr::Tpl(_)
+      | Expr::Array(_)
+      | Expr::Object(_)
+      | Expr::Fn(_)
+      | Expr::Arrow(_)
+      | Expr::Class(_)
+      | Expr::Unary(_)
+      | Expr::Update(_) => Some(false),
+      Expr::Bin(
+        bin @ BinExpr {
+          op: op!("&&") | op!("||"),
+          ..
+        },
+      ) => {
+        // The left side is the result if it's falsy for `&&` or truthy for `||`
+        let left_is_result = self.truthiness(&bin.left)? == (bin.op == op!("||"));
+        if left_is_result {
+          self.nullishness(&bin.left)
+        } else {
+          self.nullishness(&bin.right)
+        }
+      }
+      Expr::Bin(BinExpr {
+        op: op!("??"),
+        left,
+        right,
+        ..
+      }) => match self.nullishness(left) {
+        Some(true) => self.nullishness(right),
+        Some(false) => Some(false),
+        None => (self.nullishness(right) == Some(false)).then_some(false),
+      },
+      // The other binary operators produce the booleans, numbers and strings
+      Expr::Bin(_) => Some(false),
+      Expr::Assign(assign) => match assign.op {
+        op!("=") => self.nullishness(&assign.right),
+        op!("??=") => (self.nullishness(&assign.right) == Some(false)).then_some(false),
+        _ => None,
+      },
+      Expr::Cond(CondExpr {
+        test, cons, alt, ..
+      }) => match self.truthiness(test) {
+        Some(true) => self.nullishness(cons),
+        Some(false) => self.nullishness(alt),
+        None => self
+          .nullishness(cons)
+          .filter(|cons| Some(*cons) == self.nullishness(alt)),
+      },
+      Expr::OptChain(chain) => self.short_circuits(chain).then_some(true),
+      _ => None,
+    }
+  }
+
+  /// Whether an optional link of the chain is always skipped, e.g. `null?.a.b`,
+  /// the object of the link is evaluated before short-circuiting.
+  fn short_circuits(&self, chain: &OptChainExpr) -> bool {
+    let object = match &*chain.base {
+      OptChainBase::Member(member) => &member.obj,
+      OptChainBase::Call(call) => &call.callee,
+    };
+    if chain.optional && self.nullishness(object) == Some(true) {
+      return true;
+    }
+    match &**object {
+      Expr::OptChain(chain) => self.short_circuits(chain),
+      _ => false,
+    }
+  }
+
+  /// The object of the optional link which short-circuits the chain, see [Self::short_circuits]
+  fn short_circuit_object(&self, chain: &mut OptChainExpr) -> Option<Box<Expr>> {
+    let optional = chain.optional;
+    let object = match &mut *chain.base {
+      OptChainBase::Member(member) => &mut member.obj,
+      OptChainBase::Call(call) => &mut call.callee,
+    };
+    if optional && self.nullishness(object) == Some(true) {
+      return Some(object.take());
+    }
+    match &mut **object {
+      Expr::OptChain(chain) => self.short_circuit_object(chain),
+      _ => None,
+    }
+  }
+
+  /// The parts of the chain, but not the nested chains, which are folded as a whole with the chain
+  fn visit_mut_chain_parts(&mut self, chain: &mut OptChainExpr) {
+    match &mut *chain.base {
+      OptChainBase::Member(member) => {
+        self.visit_mut_chain_object(&mut member.obj);
+        member.prop.visit_mut_with(self);
+      }
+      OptChainBase::Call(call) => {
+        self.visit_mut_chain_object(&mut call.callee);
+        call.args.visit_mut_with(self);
+      }
+    }
+  }
+
+  fn visit_mut_chain_object(&mut self, object: &mut Expr) {
+    if let Expr::OptChain(chain) = object {
+      self.visit_mut_chain_parts(chain);
+    } else {
+      object.visit_mut_with(self);
+    }
+  }
+
+  /// Keep the side effects of `dropped`, which is evaluated before `kept`
+  fn preserve_effects(&self, dropped: Box<Expr>, kept: Box<Expr>) -> Expr {
+    if dropped.may_have_side_effects(&self.ctx) {
+      Expr::Seq(SeqExpr {
+        span: kept.span(),
+        exprs: vec![dropped, kept],
+      })
+    } else {
+      *kept
+    }
+  }
+
+  /// Visit the callee or the argument of `delete`, whose result is a reference rather than a value,
+  /// e.g. `obj.fn` is called with `obj` as `this`. The member expressions which the folded
+  /// expression results in are wrapped as `(0, obj.fn)` to keep them a value.
+  fn visit_mut_reference(&mut self, expr: &mut Expr) {
+    let mut inner = &mut *expr;
+    while let Expr::Paren(paren) = inner {
+      inner = &mut *paren.expr;
+    }
+    let kind = std::mem::discriminant(&*inner);
+    let is_foldable = matches!(
+      inner,
+      Expr::Bin(BinExpr {
+        op: op!("&&") | op!("||") | op!("??"),
+        ..
+      }) | Expr::OptChain(_)
+    );
+    inner.visit_mut_with(self);
+    if !is_foldable || std::mem::discriminant(&*inner) == kind {
+      return;
+    }
+    let mut result = inner;
+    while let Expr::Paren(paren) = result {
+      result = &mut *paren.expr;
+    }
+    if matches!(
+      result,
+      Expr::Member(_) | Expr::SuperProp(_) | Expr::OptChain(_)
+    ) {
+      let span = result.span();
+      *result = Expr::Paren(ParenExpr {
+        span,
+        expr: Box::new(Expr::Seq(SeqExpr {
+          span,
+          exprs: vec![
+            Box::new(Expr::Lit(Lit::Num(Number {
+              span,
+              value: 0.0,
+              raw: None,
+            }))),
+            Box::new(result.take()),
+          ],
+        })),
+      });
+    }
+  }
+
+  /// Replace a test whose truthiness is known with the boolean, for `dead_branch_remover`
+  fn fold_test(&self, test: &mut Box<Expr>) {
+    if matches!(&**test, Expr::Lit(Lit::Bool(_))) {
+      return;
+    }
+    if let Some(value) = self.truthiness(test) {
+      let value = Box::new(Expr::Lit(Lit::Bool(Bool {
+        span: test.span(),
+        value,
+      })));
+      *test = Box::new(self.preserve_effects(test.take(), value));
+    }
+  }
+}
+
+impl VisitMut for FoldLogical {
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    if let Expr::OptChain(chain) = expr {
+      self.visit_mut_chain_parts(chain);
+      if let Some(object) = self.short_circuit_object(chain) {
+        *expr = self.preserve_effects(object, undefined(chain.span));
+      }
+      return;
+    }
+
+    expr.visit_mut_children_with(self);
+
+    let Expr::Bin(BinExpr {
+      op,
+      left,
+      right,
+      ..
+    }) = expr
+    else {
+      return;
+    };
+    // Whether the result is the left side, then the right side is never evaluated
+    let left_is_result = match *op {
+      op!("&&") => self.truthiness(left).map(|left| !left),
+      op!("||") => self.truthiness(left),
+      op!("??") => self.nullishness(left).map(|left| !left),
+      _ => None,
+    };
+    match left_is_result {
+      Some(true) => *expr = *left.take(),
+      Some(false) => *expr = self.preserve_effects(left.take(), right.take()),
+      None => {}
+    }
+  }
+
+  fn visit_mut_callee(&mut self, callee: &mut Callee) {
+    match callee {
+      Callee::Expr(expr) => self.visit_mut_reference(expr),
+      _ => callee.visit_mut_children_with(self),
+    }
+  }
+
+  fn visit_mut_unary_expr(&mut self, expr: &mut UnaryExpr) {
+    if expr.op == op!("delete") {
+      self.visit_mut_reference(&mut expr.arg);
+    } else {
+      expr.visit_mut_children_with(self);
+    }
+  }
+
+  fn visit_mut_if_stmt(&mut self, stmt: &mut IfStmt) {
+    stmt.visit_mut_children_with(self);
+    self.fold_test(&mut stmt.test);
+  }
+
+  fn visit_mut_cond_expr(&mut self, expr: &mut CondExpr) {
+    expr.visit_mut_children_with(self);
+    self.fold_test(&mut expr.test);
+  }
+}
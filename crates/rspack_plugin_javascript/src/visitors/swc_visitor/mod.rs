@@ -13,8 +13,8 @@ pub use react::{fold_react_refresh, react};
 mod define;
 pub use define::define;
 
-mod provide;
-pub use provide::provide_builtin;
+mod fold_logical;
+pub use fold_logical::fold_logical;
 
 mod compat;
 pub use compat::compat;
@@ -1,4 +1,4 @@
-(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["async-entry_js"], {
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["async-entry-js"], {
 "./async-entry.js": function (module, exports, __webpack_require__) {
 "use strict";
 Object.defineProperty(exports, "__esModule", {
@@ -1,4 +1,4 @@
-(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["async-entry2_js"], {
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["async-entry2-js"], {
 "./async-entry2.js": function (module, exports, __webpack_require__) {
 "use strict";
 Object.defineProperty(exports, "__esModule", {
@@ -0,0 +1,17 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./index.js": function (__unused_webpack_module, exports, __webpack_require__) {
+const obj = {
+    fn: function() {
+        return this;
+    }
+};
+(0, obj.fn)();
+delete (0, obj.fn);
+},
+
+},function(__webpack_require__) {
+var __webpack_exec__ = function(moduleId) { return __webpack_require__(__webpack_require__.s = moduleId) }
+var __webpack_exports__ = (__webpack_exec__("./index.js"));
+
+}
+]);
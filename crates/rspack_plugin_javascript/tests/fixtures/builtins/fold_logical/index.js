@@ -0,0 +1,8 @@
+const obj = {
+	fn: function () {
+		return this;
+	}
+};
+
+(process.env.REMOTE ?? obj.fn)();
+delete (process.env.REMOTE ?? obj.fn);
@@ -0,0 +1,13 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./index.js": function (__unused_webpack_module, exports, __webpack_require__) {
+console.log(pseudoImport.meta);
+console.log(pseudoImport.meta.env.MODE);
+console.log('object');
+},
+
+},function(__webpack_require__) {
+var __webpack_exec__ = function(moduleId) { return __webpack_require__(__webpack_require__.s = moduleId) }
+var __webpack_exports__ = (__webpack_exec__("./index.js"));
+
+}
+]);
\ No newline at end of file
@@ -0,0 +1,3 @@
+console.log(import.meta);
+console.log(import.meta.env.MODE);
+console.log(typeof import.meta);
\ No newline at end of file
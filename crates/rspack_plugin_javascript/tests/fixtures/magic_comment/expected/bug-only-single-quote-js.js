@@ -1,4 +1,4 @@
-(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["bug_only_single_quote_js"], {
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["bug-only-single-quote-js"], {
 "./bug_only_single_quote.js": function (__unused_webpack_module, exports, __webpack_require__) {
 console.log('123');
 },
@@ -17,6 +17,10 @@ mod utils;
 struct JsonParserAndGenerator;
 
 impl ParserAndGenerator for JsonParserAndGenerator {
+  fn name(&self) -> &'static str {
+    "json"
+  }
+
   fn source_types(&self) -> &[SourceType] {
     &[SourceType::JavaScript]
   }
@@ -4,7 +4,7 @@ use rspack_core::{
   rspack_sources::{ConcatSource, RawSource, SourceExt},
   to_identifier,
   tree_shaking::webpack_ext::ExportInfoExt,
-  JsChunkHashArgs, Plugin, PluginContext, PluginJsChunkHashHookOutput,
+  BuildMetaExportsType, JsChunkHashArgs, Plugin, PluginContext, PluginJsChunkHashHookOutput,
   PluginRenderStartupHookOutput, RenderStartupArgs,
 };
 
@@ -35,6 +35,11 @@ impl Plugin for ModuleLibraryPlugin {
     }
     let mut source = ConcatSource::default();
     source.add(args.source.clone());
+    let declaration = if args.compilation.options.output.environment.supports_const() {
+      "const"
+    } else {
+      "var"
+    };
     let mut exports = vec![];
     if let Some(analyze_results) = args
       .compilation
@@ -45,12 +50,28 @@ impl Plugin for ModuleLibraryPlugin {
         let name = to_identifier(info.name.as_ref());
         let var_name = format!("__webpack_exports__{}", name);
         source.add(RawSource::from(format!(
-          "var {var_name} = __webpack_exports__{};\n",
+          "{declaration} {var_name} = __webpack_exports__{};\n",
           property_access(&vec![info.name.to_string()])
         )));
         exports.push(format!("{var_name} as {}", info.name));
       }
     }
+    // A non-harmony entry module has no static exports, export `module.exports` as the default export,
+    // which matches the interop of importing a CommonJS module from ESM.
+    let is_harmony = args
+      .compilation
+      .module_graph
+      .module_graph_module_by_identifier(&args.module)
+      .and_then(|mgm| mgm.build_meta.as_ref())
+      .map_or(false, |build_meta| {
+        matches!(build_meta.exports_type, BuildMetaExportsType::Namespace)
+      });
+    if !is_harmony && !exports.iter().any(|export| export.ends_with(" as default")) {
+      source.add(RawSource::from(format!(
+        "{declaration} __webpack_exports__default = __webpack_exports__;\n"
+      )));
+      exports.push("__webpack_exports__default as default".to_string());
+    }
     if !exports.is_empty() {
       source.add(RawSource::from(format!(
         "export {{ {} }};\n",
@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  AssetInfo, Chunk, Compilation, CompilationAsset, Logger, Plugin,
+};
+use rspack_error::internal_error;
+use serde::Serialize;
+
+#[derive(Debug)]
+pub struct ManifestPluginOptions {
+  pub filename: String,
+}
+
+impl Default for ManifestPluginOptions {
+  fn default() -> Self {
+    Self {
+      filename: "manifest.json".to_string(),
+    }
+  }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct EntrypointFiles {
+  js: Vec<String>,
+  css: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Manifest {
+  entrypoints: BTreeMap<String, EntrypointFiles>,
+  r#async: BTreeMap<String, Vec<String>>,
+  public_path: String,
+}
+
+/// Emit a manifest which maps the entrypoints to their js and css files in load order, and the ids
+/// of the on-demand loaded chunks to their files.
+#[derive(Debug, Default)]
+pub struct ManifestPlugin {
+  options: ManifestPluginOptions,
+}
+
+impl ManifestPlugin {
+  pub fn new(options: ManifestPluginOptions) -> Self {
+    Self { options }
+  }
+
+  /// The files of the chunk except the manifest itself, sorted to keep the manifest stable.
+  fn chunk_files(&self, chunk: &Chunk) -> Vec<String> {
+    let mut files = chunk
+      .files
+      .iter()
+      .filter(|file| **file != self.options.filename)
+      .cloned()
+      .collect::<Vec<_>>();
+    files.sort();
+    files
+  }
+
+  fn create_manifest(&self, compilation: &Compilation) -> Manifest {
+    let entrypoints = compilation
+      .entrypoints
+      .iter()
+      .map(|(name, ukey)| {
+        let entrypoint = compilation
+          .chunk_group_by_ukey
+          .get(ukey)
+          .expect("should have entrypoint");
+        let mut files = EntrypointFiles::default();
+        for chunk in &entrypoint.chunks {
+          let chunk = compilation
+            .chunk_by_ukey
+            .get(chunk)
+            .expect("should have chunk");
+          for file in self.chunk_files(chunk) {
+            let list = if file.ends_with(".css") {
+              &mut files.css
+            } else if file.ends_with(".js") || file.ends_with(".mjs") || file.ends_with(".cjs") {
+              &mut files.js
+            } else {
+              continue;
+            };
+            if !list.contains(&file) {
+              list.push(file);
+            }
+          }
+        }
+        (name.clone(), files)
+      })
+      .collect();
+
+    // A chunk is loaded on demand if any of its chunk groups is not initial, even if it's also
+    // loaded initially by another entrypoint
+    let r#async = compilation
+      .chunk_by_ukey
+      .values()
+      .filter(|chunk| !chunk.is_only_initial(&compilation.chunk_group_by_ukey))
+      .filter_map(|chunk| {
+        let id = chunk.id.clone()?;
+        Some((id, self.chunk_files(chunk)))
+      })
+      .collect();
+
+    Manifest {
+      entrypoints,
+      r#async,
+      public_path: compilation
+        .options
+        .output
+        .public_path
+        .render(compilation, &self.options.filename),
+    }
+  }
+}
+
+#[async_trait]
+impl Plugin for ManifestPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.ManifestPlugin"
+  }
+
+  // Run after the real content hash, so the files of the rehashed chunks are up to date.
+  async fn process_assets_stage_report(
+    &self,
+    _ctx: rspack_core::PluginContext,
+    args: rspack_core::ProcessAssetsArgs<'_>,
+  ) -> rspack_core::PluginProcessAssetsOutput {
+    let compilation = args.compilation;
+    let logger = compilation.get_logger(self.name());
+    let start = logger.time("emit manifest");
+    let manifest = self.create_manifest(compilation);
+    let content = serde_json::to_string_pretty(&manifest)
+      .map_err(|e| internal_error!("Failed to serialize the manifest: {e}"))?;
+    compilation.emit_asset(
+      self.options.filename.clone(),
+      CompilationAsset::new(Some(RawSource::from(content).boxed()), AssetInfo::default()),
+    );
+    logger.time_end(start);
+    Ok(())
+  }
+}
@@ -1,45 +1,153 @@
 use std::sync::atomic::Ordering::SeqCst;
-use std::sync::RwLock;
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
 use std::{cmp, sync::atomic::AtomicU32};
 
 use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
 use rspack_core::{
-  Compilation, DoneArgs, MakeParam, Module, OptimizeChunksArgs, Plugin, PluginBuildEndHookOutput,
-  PluginContext, PluginMakeHookOutput, PluginOptimizeChunksOutput, PluginProcessAssetsOutput,
-  ProcessAssetsArgs,
+  ChunkHashArgs, Compilation, CompilationLogger, Context, DoneArgs, Logger, MakeParam, Module,
+  OptimizeChunksArgs, Plugin, PluginBuildEndHookOutput, PluginChunkHashHookOutput, PluginContext,
+  PluginMakeHookOutput, PluginOptimizeChunksOutput, PluginProcessAssetsOutput, ProcessAssetsArgs,
 };
 use rspack_error::Result;
 
-#[derive(Debug, Clone, Default)]
+/// Callbacks are throttled to at most 50 times per second
+const THROTTLE_INTERVAL: Duration = Duration::from_millis(20);
+
+pub type ProgressHandler = Box<dyn Fn(f32, String, Vec<String>) + Send + Sync>;
+
+#[derive(Default)]
 pub struct ProgressPluginConfig {
   // the prefix name of progress bar
   pub prefix: Option<String>,
+  // log the elapsed time of each step to the compilation logger
+  pub profile: bool,
+  // report progress to this handler instead of rendering a progress bar
+  pub handler: Option<ProgressHandler>,
+}
+
+impl std::fmt::Debug for ProgressPluginConfig {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("ProgressPluginConfig")
+      .field("prefix", &self.prefix)
+      .field("profile", &self.profile)
+      .field("handler", &self.handler.as_ref().map(|_| "Function"))
+      .finish()
+  }
 }
 
 #[derive(Debug)]
 pub struct ProgressPlugin {
   pub options: ProgressPluginConfig,
-  pub progress_bar: ProgressBar,
+  pub progress_bar: Option<ProgressBar>,
   pub modules_count: AtomicU32,
   pub modules_done: AtomicU32,
   pub last_modules_count: RwLock<Option<u32>>,
+  context: RwLock<Context>,
+  last_report: Mutex<Option<Instant>>,
+  // the last report throttled in the current step, flushed when the step changes
+  pending_report: Mutex<Option<(f32, String, Vec<String>)>>,
+  current_step: Mutex<Option<(String, Instant)>>,
+  logger: Mutex<Option<CompilationLogger>>,
 }
 
 impl ProgressPlugin {
   pub fn new(options: ProgressPluginConfig) -> Self {
-    let progress_bar = ProgressBar::with_draw_target(Some(100), ProgressDrawTarget::stdout());
-    progress_bar.set_style(
-      ProgressStyle::with_template("{prefix} {bar:40.cyan/blue} {percent}% {wide_msg}")
-        .expect("TODO:"),
-    );
+    let progress_bar = options.handler.is_none().then(|| {
+      let progress_bar = ProgressBar::with_draw_target(Some(100), ProgressDrawTarget::stderr());
+      progress_bar.set_style(
+        ProgressStyle::with_template("{prefix} {bar:40.cyan/blue} {percent}% {wide_msg}")
+          .expect("TODO:"),
+      );
+      progress_bar
+    });
     Self {
       options,
       progress_bar,
       modules_count: AtomicU32::new(0),
       modules_done: AtomicU32::new(0),
       last_modules_count: RwLock::new(None),
+      context: RwLock::new(Context::default()),
+      last_report: Mutex::new(None),
+      pending_report: Mutex::new(None),
+      current_step: Mutex::new(None),
+      logger: Mutex::new(None),
+    }
+  }
+
+  /// Report the progress of a step, `percentage` is in the range of `[0, 1]`.
+  /// Reports within the same step are throttled, while a new step is always reported
+  /// after the last throttled report of the previous step.
+  fn report(&self, percentage: f32, step: &str, items: Vec<String>) {
+    let now = Instant::now();
+    let is_new_step = self.enter_step(step, now);
+    let pending = {
+      let mut last_report = self.last_report.lock().expect("TODO:");
+      let mut pending_report = self.pending_report.lock().expect("TODO:");
+      let throttled = last_report.map_or(false, |last_report| {
+        now.duration_since(last_report) < THROTTLE_INTERVAL
+      });
+      if !is_new_step && throttled {
+        *pending_report = Some((percentage, step.to_string(), items));
+        return;
+      }
+      *last_report = Some(now);
+      pending_report.take()
+    };
+
+    if let (true, Some((percentage, step, items))) = (is_new_step, pending) {
+      self.handle(percentage, &step, items);
+    }
+    self.handle(percentage, step, items);
+  }
+
+  fn handle(&self, percentage: f32, step: &str, items: Vec<String>) {
+    if let Some(handler) = &self.options.handler {
+      handler(percentage, step.to_string(), items);
+    } else if let Some(progress_bar) = &self.progress_bar {
+      let msg = match items.first() {
+        Some(item) => format!("{step} {item}"),
+        None => step.to_string(),
+      };
+      progress_bar.set_message(msg);
+      progress_bar.set_position((percentage * 100.0) as u64);
     }
   }
+
+  /// Returns `true` if `step` differs from the current one.
+  fn enter_step(&self, step: &str, now: Instant) -> bool {
+    let mut current_step = self.current_step.lock().expect("TODO:");
+    if matches!(&*current_step, Some((current, _)) if current == step) {
+      return false;
+    }
+    let previous = current_step.replace((step.to_string(), now));
+    if let (true, Some((previous, start))) = (self.options.profile, previous) {
+      if let Some(logger) = &*self.logger.lock().expect("TODO:") {
+        logger.info(format!(
+          "{} ms {previous}",
+          now.duration_since(start).as_millis()
+        ));
+      }
+    }
+    true
+  }
+
+  fn building_percentage(&self, modules_done: u32) -> f32 {
+    let percent = (modules_done as f32)
+      / (cmp::max(
+        self.last_modules_count.read().expect("TODO:").unwrap_or(1),
+        self.modules_count.load(SeqCst),
+      ) as f32);
+    0.1 + 0.55 * percent
+  }
+
+  fn prefix(&self) -> String {
+    self
+      .options
+      .prefix
+      .clone()
+      .unwrap_or_else(|| "Rspack".to_string())
+  }
 }
 
 #[async_trait::async_trait]
@@ -51,47 +159,49 @@ impl Plugin for ProgressPlugin {
   async fn make(
     &self,
     _ctx: PluginContext,
-    _compilation: &mut Compilation,
+    compilation: &mut Compilation,
     _param: &mut MakeParam,
   ) -> PluginMakeHookOutput {
-    self.progress_bar.reset();
-    self.progress_bar.set_prefix(
-      self
-        .options
-        .prefix
-        .clone()
-        .unwrap_or_else(|| "Rspack".to_string()),
-    );
+    *self.context.write().expect("TODO:") = compilation.options.context.clone();
+    if let Some(progress_bar) = &self.progress_bar {
+      progress_bar.reset();
+      progress_bar.set_prefix(self.prefix());
+    }
     self.modules_count.store(0, SeqCst);
     self.modules_done.store(0, SeqCst);
-    self.progress_bar.set_message("make");
-    self.progress_bar.set_position(1);
+    *self.current_step.lock().expect("TODO:") = None;
+    *self.pending_report.lock().expect("TODO:") = None;
+    *self.logger.lock().expect("TODO:") = Some(compilation.get_logger(self.name()));
+    self.report(0.01, "make", vec![]);
     Ok(())
   }
 
   async fn build_module(&self, module: &mut dyn Module) -> Result<()> {
-    if let Some(module) = module.as_normal_module() {
-      self
-        .progress_bar
-        .set_message(format!("building {}", module.raw_request()));
-    } else {
-      self.progress_bar.set_message("building");
-    }
-    self.modules_count.fetch_add(1, SeqCst);
+    let modules_count = self.modules_count.fetch_add(1, SeqCst) + 1;
+    let modules_done = self.modules_done.load(SeqCst);
+    let items = vec![
+      format!("{modules_done}/{modules_count} modules"),
+      module
+        .readable_identifier(&self.context.read().expect("TODO:"))
+        .into_owned(),
+    ];
+    self.report(self.building_percentage(modules_done), "building", items);
     Ok(())
   }
 
   async fn succeed_module(&self, _module: &dyn Module) -> Result<()> {
-    let previous_modules_done = self.modules_done.fetch_add(1, SeqCst);
-    let modules_done = previous_modules_done + 1;
-    let percent = (modules_done as f32)
-      / (cmp::max(
-        self.last_modules_count.read().expect("TODO:").unwrap_or(1),
-        self.modules_count.load(SeqCst),
-      ) as f32);
-    self
-      .progress_bar
-      .set_position((10.0 + 55.0 * percent) as u64);
+    let modules_done = self.modules_done.fetch_add(1, SeqCst) + 1;
+    let modules_count = self.modules_count.load(SeqCst);
+    self.report(
+      self.building_percentage(modules_done),
+      "building",
+      vec![format!("{modules_done}/{modules_count} modules")],
+    );
+    Ok(())
+  }
+
+  async fn finish_make(&self, _compilation: &mut Compilation) -> Result<()> {
+    self.report(0.65, "sealing", vec![]);
     Ok(())
   }
 
@@ -100,8 +210,22 @@ impl Plugin for ProgressPlugin {
     _ctx: PluginContext,
     _args: OptimizeChunksArgs<'_>,
   ) -> PluginOptimizeChunksOutput {
-    self.progress_bar.set_position(80);
-    self.progress_bar.set_message("optimizing chunks");
+    self.report(0.7, "optimizing chunks", vec![]);
+    Ok(())
+  }
+
+  fn chunk_ids(&self, _compilation: &mut Compilation) -> Result<()> {
+    // code generation starts right after chunk ids are assigned
+    self.report(0.75, "code generation", vec![]);
+    Ok(())
+  }
+
+  async fn chunk_hash(
+    &self,
+    _ctx: PluginContext,
+    _args: &mut ChunkHashArgs<'_>,
+  ) -> PluginChunkHashHookOutput {
+    self.report(0.8, "hashing", vec![]);
     Ok(())
   }
 
@@ -110,8 +234,12 @@ impl Plugin for ProgressPlugin {
     _ctx: PluginContext,
     _args: ProcessAssetsArgs<'_>,
   ) -> PluginProcessAssetsOutput {
-    self.progress_bar.set_position(90);
-    self.progress_bar.set_message("processing assets");
+    self.report(0.9, "processing assets", vec![]);
+    Ok(())
+  }
+
+  async fn emit(&self, _compilation: &mut Compilation) -> Result<()> {
+    self.report(0.95, "emitting", vec![]);
     Ok(())
   }
 
@@ -120,9 +248,62 @@ impl Plugin for ProgressPlugin {
     _ctx: PluginContext,
     _args: DoneArgs<'s, 'c>,
   ) -> PluginBuildEndHookOutput {
-    self.progress_bar.set_message("done");
-    self.progress_bar.finish();
+    self.report(1.0, "done", vec![]);
+    if let Some(progress_bar) = &self.progress_bar {
+      progress_bar.finish();
+    }
     *self.last_modules_count.write().expect("TODO:") = Some(self.modules_count.load(SeqCst));
     Ok(())
   }
 }
+
+#[cfg(test)]
+mod test {
+  use std::sync::{Arc, Mutex};
+  use std::time::Instant;
+
+  use super::{ProgressPlugin, ProgressPluginConfig, THROTTLE_INTERVAL};
+
+  #[test]
+  fn flush_throttled_report_on_step_change() {
+    let reports: Arc<Mutex<Vec<(String, Vec<String>)>>> = Default::default();
+    let recorded = reports.clone();
+    let plugin = ProgressPlugin::new(ProgressPluginConfig {
+      handler: Some(Box::new(move |_, step, items| {
+        recorded.lock().expect("should lock").push((step, items));
+      })),
+      ..Default::default()
+    });
+
+    plugin.report(0.01, "make", vec![]);
+    let start = Instant::now();
+    for done in 1..=100 {
+      plugin.report(
+        plugin.building_percentage(done),
+        "building",
+        vec![format!("{done}/100 modules")],
+      );
+    }
+    let elapsed = start.elapsed();
+    plugin.report(0.65, "sealing", vec![]);
+
+    let reports = reports.lock().expect("should lock");
+    let mut steps = reports
+      .iter()
+      .map(|(step, _)| step.as_str())
+      .collect::<Vec<_>>();
+    steps.dedup();
+    assert_eq!(steps, vec!["make", "building", "sealing"]);
+
+    let building = reports
+      .iter()
+      .filter(|(step, _)| step == "building")
+      .map(|(_, items)| items[0].as_str())
+      .collect::<Vec<_>>();
+    assert_eq!(building.first(), Some(&"1/100 modules"));
+    assert_eq!(building.last(), Some(&"100/100 modules"));
+    // the first report, one report per interval and the flushed last report
+    let max_reports = 2 + (elapsed.as_millis() / THROTTLE_INTERVAL.as_millis()) as usize;
+    assert!(building.len() <= max_reports);
+  }
+}
@@ -12,6 +12,7 @@ use rspack_error::Result;
 use rspack_plugin_javascript::runtime::{render_chunk_runtime_modules, render_runtime_modules};
 
 use super::{generate_entry_startup, update_hash_for_entry_startup};
+use crate::{runtime_module::uses_module_chunk_format, ModuleChunkFormatPlugin};
 
 #[derive(Debug)]
 pub struct ArrayPushCallbackChunkFormatPlugin;
@@ -28,9 +29,12 @@ impl Plugin for ArrayPushCallbackChunkFormatPlugin {
 
   fn additional_chunk_runtime_requirements(
     &self,
-    _ctx: PluginContext,
+    ctx: PluginContext,
     args: &mut AdditionalChunkRuntimeRequirementsArgs,
   ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    if uses_module_chunk_format(args.chunk, args.compilation) {
+      return ModuleChunkFormatPlugin.additional_chunk_runtime_requirements(ctx, args);
+    }
     let compilation = &mut args.compilation;
     let chunk_ukey = args.chunk;
     let runtime_requirements = &mut args.runtime_requirements;
@@ -58,9 +62,12 @@ impl Plugin for ArrayPushCallbackChunkFormatPlugin {
 
   fn js_chunk_hash(
     &self,
-    _ctx: PluginContext,
+    ctx: PluginContext,
     args: &mut JsChunkHashArgs,
   ) -> PluginJsChunkHashHookOutput {
+    if uses_module_chunk_format(args.chunk_ukey, args.compilation) {
+      return ModuleChunkFormatPlugin.js_chunk_hash(ctx, args);
+    }
     if args
       .chunk()
       .has_runtime(&args.compilation.chunk_group_by_ukey)
@@ -72,6 +79,7 @@ impl Plugin for ArrayPushCallbackChunkFormatPlugin {
     let output = &args.compilation.options.output;
     output.global_object.hash(&mut args.hasher);
     output.chunk_loading_global.hash(&mut args.hasher);
+    output.hot_update_global.hash(&mut args.hasher);
 
     update_hash_for_entry_startup(
       args.hasher,
@@ -88,9 +96,12 @@ impl Plugin for ArrayPushCallbackChunkFormatPlugin {
 
   async fn render_chunk(
     &self,
-    _ctx: PluginContext,
+    ctx: PluginContext,
     args: &RenderChunkArgs,
   ) -> PluginRenderChunkHookOutput {
+    if uses_module_chunk_format(args.chunk_ukey, args.compilation) {
+      return ModuleChunkFormatPlugin.render_chunk(ctx, args).await;
+    }
     let chunk = args.chunk();
     let runtime_modules = args
       .compilation
@@ -101,8 +112,9 @@ impl Plugin for ArrayPushCallbackChunkFormatPlugin {
 
     if matches!(chunk.kind, ChunkKind::HotUpdate) {
       source.add(RawSource::Source(format!(
-        "{}['hotUpdate']('{}', ",
+        "{}['{}']('{}', ",
         global_object,
+        args.compilation.options.output.hot_update_global,
         chunk.expect_id()
       )));
       source.add(args.module_source.clone());
@@ -0,0 +1,147 @@
+use async_trait::async_trait;
+use rspack_core::{
+  AdditionalChunkRuntimeRequirementsArgs, ChunkGroupOrderKey, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, RuntimeGlobals, RuntimeModuleExt,
+};
+use rspack_error::Result;
+
+use crate::runtime_module::{
+  ChunkPrefetchPreloadFunctionRuntimeModule, ChunkPrefetchStartupRuntimeModule,
+  ChunkPrefetchTriggerRuntimeModule, ChunkPreloadTriggerRuntimeModule,
+};
+
+/// Prefetch and preload the chunks of `import(/* webpackPrefetch: true */ "./a")`
+/// and `import(/* webpackPreload: true */ "./a")`.
+#[derive(Debug)]
+pub struct ChunkPrefetchPreloadPlugin;
+
+#[async_trait]
+impl Plugin for ChunkPrefetchPreloadPlugin {
+  fn name(&self) -> &'static str {
+    "ChunkPrefetchPreloadPlugin"
+  }
+
+  fn apply(&self, _ctx: rspack_core::PluginContext<&mut rspack_core::ApplyContext>) -> Result<()> {
+    Ok(())
+  }
+
+  fn additional_chunk_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &mut args.compilation;
+    if compilation
+      .chunk_graph
+      .get_number_of_entry_modules(args.chunk)
+      == 0
+    {
+      return Ok(());
+    }
+    let chunk = compilation
+      .chunk_by_ukey
+      .get(args.chunk)
+      .expect("should have chunk");
+    let to_ids = |chunks: Vec<_>| {
+      chunks
+        .into_iter()
+        .map(|chunk_ukey| {
+          compilation
+            .chunk_by_ukey
+            .get(&chunk_ukey)
+            .expect("should have chunk")
+            .expect_id()
+            .to_string()
+        })
+        .collect::<Vec<_>>()
+    };
+    let startup_child_chunks = chunk
+      .get_children_of_type_in_order(&ChunkGroupOrderKey::Prefetch, compilation)
+      .into_iter()
+      .map(|(on_chunks, chunks)| (to_ids(on_chunks), to_ids(chunks)))
+      .collect::<Vec<_>>();
+    if !startup_child_chunks.is_empty() {
+      args
+        .runtime_requirements
+        .insert(RuntimeGlobals::PREFETCH_CHUNK);
+      args
+        .runtime_requirements
+        .insert(RuntimeGlobals::ON_CHUNKS_LOADED);
+      compilation.add_runtime_module(
+        args.chunk,
+        ChunkPrefetchStartupRuntimeModule::new(startup_child_chunks).boxed(),
+      );
+    }
+    Ok(())
+  }
+
+  fn additional_tree_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &mut args.compilation;
+    let chunk = compilation
+      .chunk_by_ukey
+      .get(args.chunk)
+      .expect("should have chunk");
+    let prefetch_map =
+      chunk.get_child_ids_by_order_map(&ChunkGroupOrderKey::Prefetch, false, compilation);
+    let preload_map =
+      chunk.get_child_ids_by_order_map(&ChunkGroupOrderKey::Preload, false, compilation);
+    if !prefetch_map.is_empty() {
+      args
+        .runtime_requirements
+        .insert(RuntimeGlobals::PREFETCH_CHUNK);
+      compilation.add_runtime_module(
+        args.chunk,
+        ChunkPrefetchTriggerRuntimeModule::new(prefetch_map).boxed(),
+      );
+    }
+    if !preload_map.is_empty() {
+      args
+        .runtime_requirements
+        .insert(RuntimeGlobals::PRELOAD_CHUNK);
+      compilation.add_runtime_module(
+        args.chunk,
+        ChunkPreloadTriggerRuntimeModule::new(preload_map).boxed(),
+      );
+    }
+    Ok(())
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &mut args.compilation;
+    let runtime_requirements = &mut args.runtime_requirements;
+
+    if runtime_requirements.contains(RuntimeGlobals::PREFETCH_CHUNK) {
+      runtime_requirements.insert(RuntimeGlobals::PREFETCH_CHUNK_HANDLERS);
+      compilation.add_runtime_module(
+        args.chunk,
+        ChunkPrefetchPreloadFunctionRuntimeModule::new(
+          "prefetch",
+          RuntimeGlobals::PREFETCH_CHUNK,
+          RuntimeGlobals::PREFETCH_CHUNK_HANDLERS,
+        )
+        .boxed(),
+      );
+    }
+    if runtime_requirements.contains(RuntimeGlobals::PRELOAD_CHUNK) {
+      runtime_requirements.insert(RuntimeGlobals::PRELOAD_CHUNK_HANDLERS);
+      compilation.add_runtime_module(
+        args.chunk,
+        ChunkPrefetchPreloadFunctionRuntimeModule::new(
+          "preload",
+          RuntimeGlobals::PRELOAD_CHUNK,
+          RuntimeGlobals::PRELOAD_CHUNK_HANDLERS,
+        )
+        .boxed(),
+      );
+    }
+    Ok(())
+  }
+}
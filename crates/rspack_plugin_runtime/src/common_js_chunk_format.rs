@@ -13,7 +13,7 @@ use rspack_plugin_javascript::runtime::{render_chunk_runtime_modules, render_iif
 
 use crate::{
   generate_entry_startup, get_chunk_output_name, get_relative_path, get_runtime_chunk_output_name,
-  update_hash_for_entry_startup,
+  runtime_module::uses_module_chunk_format, update_hash_for_entry_startup, ModuleChunkFormatPlugin,
 };
 
 #[derive(Debug)]
@@ -31,9 +31,12 @@ impl Plugin for CommonJsChunkFormatPlugin {
 
   fn additional_chunk_runtime_requirements(
     &self,
-    _ctx: PluginContext,
+    ctx: PluginContext,
     args: &mut AdditionalChunkRuntimeRequirementsArgs,
   ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    if uses_module_chunk_format(args.chunk, args.compilation) {
+      return ModuleChunkFormatPlugin.additional_chunk_runtime_requirements(ctx, args);
+    }
     let compilation = &mut args.compilation;
     let chunk_ukey = args.chunk;
     let runtime_requirements = &mut args.runtime_requirements;
@@ -61,9 +64,12 @@ impl Plugin for CommonJsChunkFormatPlugin {
 
   fn js_chunk_hash(
     &self,
-    _ctx: PluginContext,
+    ctx: PluginContext,
     args: &mut JsChunkHashArgs,
   ) -> PluginJsChunkHashHookOutput {
+    if uses_module_chunk_format(args.chunk_ukey, args.compilation) {
+      return ModuleChunkFormatPlugin.js_chunk_hash(ctx, args);
+    }
     if args
       .chunk()
       .has_runtime(&args.compilation.chunk_group_by_ukey)
@@ -88,9 +94,12 @@ impl Plugin for CommonJsChunkFormatPlugin {
 
   async fn render_chunk(
     &self,
-    _ctx: PluginContext,
+    ctx: PluginContext,
     args: &RenderChunkArgs,
   ) -> PluginRenderChunkHookOutput {
+    if uses_module_chunk_format(args.chunk_ukey, args.compilation) {
+      return ModuleChunkFormatPlugin.render_chunk(ctx, args).await;
+    }
     let chunk = args.chunk();
     let base_chunk_output_name = get_chunk_output_name(chunk, args.compilation);
     let mut sources = ConcatSource::default();
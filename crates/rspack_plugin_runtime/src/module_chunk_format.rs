@@ -133,10 +133,23 @@ impl Plugin for ModuleChunkFormatPlugin {
 
       let mut startup_source = vec![];
 
-      startup_source.push(format!(
-        "var __webpack_exec__ = function(moduleId) {{ return __webpack_require__({} = moduleId); }}",
-        RuntimeGlobals::ENTRY_MODULE_ID
-      ));
+      let environment = &compilation.options.output.environment;
+      let declaration = if environment.supports_const() {
+        "const"
+      } else {
+        "var"
+      };
+      startup_source.push(if environment.supports_arrow_function() {
+        format!(
+          "{declaration} __webpack_exec__ = (moduleId) => (__webpack_require__({} = moduleId));",
+          RuntimeGlobals::ENTRY_MODULE_ID
+        )
+      } else {
+        format!(
+          "{declaration} __webpack_exec__ = function(moduleId) {{ return __webpack_require__({} = moduleId); }}",
+          RuntimeGlobals::ENTRY_MODULE_ID
+        )
+      });
 
       let mut loaded_chunks = HashSet::default();
       for (i, (module, entry)) in entries.iter().enumerate() {
@@ -183,9 +196,9 @@ impl Plugin for ModuleChunkFormatPlugin {
         startup_source.push(format!(
           "{}__webpack_exec__({module_id_expr});",
           if i + 1 == entries.len() {
-            "var __webpack_exports__ = "
+            format!("{declaration} __webpack_exports__ = ")
           } else {
-            ""
+            String::new()
           }
         ));
       }
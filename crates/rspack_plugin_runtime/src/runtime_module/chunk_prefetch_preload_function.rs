@@ -0,0 +1,47 @@
+use rspack_core::{
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeGlobals, RuntimeModule,
+};
+use rspack_identifier::Identifier;
+
+use crate::impl_runtime_module;
+
+/// The `__webpack_require__.E` and `__webpack_require__.G` functions, which call
+/// every handler registered by the chunk loading runtime.
+#[derive(Debug, Eq)]
+pub struct ChunkPrefetchPreloadFunctionRuntimeModule {
+  id: Identifier,
+  runtime_function: RuntimeGlobals,
+  runtime_handlers: RuntimeGlobals,
+}
+
+impl ChunkPrefetchPreloadFunctionRuntimeModule {
+  pub fn new(
+    kind: &str,
+    runtime_function: RuntimeGlobals,
+    runtime_handlers: RuntimeGlobals,
+  ) -> Self {
+    Self {
+      id: Identifier::from(format!("webpack/runtime/chunk_{kind}_function")),
+      runtime_function,
+      runtime_handlers,
+    }
+  }
+}
+
+impl RuntimeModule for ChunkPrefetchPreloadFunctionRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    RawSource::from(
+      include_str!("runtime/chunk_prefetch_preload_function.js")
+        .replace("$RUNTIME_FUNCTION$", &self.runtime_function.to_string())
+        .replace("$RUNTIME_HANDLERS$", &self.runtime_handlers.to_string()),
+    )
+    .boxed()
+  }
+}
+
+impl_runtime_module!(ChunkPrefetchPreloadFunctionRuntimeModule);
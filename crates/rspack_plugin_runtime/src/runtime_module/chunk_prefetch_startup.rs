@@ -0,0 +1,53 @@
+use rspack_core::{
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeGlobals, RuntimeModule, RUNTIME_MODULE_STAGE_TRIGGER,
+};
+use rspack_identifier::Identifier;
+
+use crate::impl_runtime_module;
+
+/// Prefetch the children of an entry chunk once the startup chunks are loaded.
+#[derive(Debug, Eq)]
+pub struct ChunkPrefetchStartupRuntimeModule {
+  id: Identifier,
+  /// Pairs of the ids of the chunks to wait for and the ids of the chunks to prefetch
+  startup_chunks: Vec<(Vec<String>, Vec<String>)>,
+}
+
+impl ChunkPrefetchStartupRuntimeModule {
+  pub fn new(startup_chunks: Vec<(Vec<String>, Vec<String>)>) -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/chunk_prefetch_startup"),
+      startup_chunks,
+    }
+  }
+}
+
+impl RuntimeModule for ChunkPrefetchStartupRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    let source = self
+      .startup_chunks
+      .iter()
+      .map(|(on_chunks, chunks)| {
+        format!(
+          "{}(0, {}, function () {{\n\t{}.map({});\n}}, 5);\n",
+          RuntimeGlobals::ON_CHUNKS_LOADED,
+          serde_json::to_string(on_chunks).expect("should able to be serde_json::to_string"),
+          serde_json::to_string(chunks).expect("should able to be serde_json::to_string"),
+          RuntimeGlobals::PREFETCH_CHUNK
+        )
+      })
+      .collect::<String>();
+    RawSource::from(source).boxed()
+  }
+
+  fn stage(&self) -> u8 {
+    RUNTIME_MODULE_STAGE_TRIGGER
+  }
+}
+
+impl_runtime_module!(ChunkPrefetchStartupRuntimeModule);
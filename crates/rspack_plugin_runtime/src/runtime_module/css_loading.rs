@@ -103,10 +103,16 @@ impl RuntimeModule for CssLoadingRuntimeModule {
       )));
 
       source.add(RawSource::from(
-        include_str!("runtime/css_loading.js").replace(
-          "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
-          &compilation.options.output.cross_origin_loading.to_string(),
-        ),
+        include_str!("runtime/css_loading.js")
+          .replace(
+            "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
+            &compilation.options.output.cross_origin_loading.to_string(),
+          )
+          .replace(
+            "$UNIQUE_NAME$",
+            &serde_json::to_string(&compilation.options.output.unique_name)
+              .expect("should render unique name"),
+          ),
       ));
 
       if with_loading {
@@ -31,10 +31,11 @@ impl RuntimeModule for GetTrustedTypesPolicyRuntimeModule {
       .trusted_types
       .as_ref()
       .expect("should have trusted_types");
-    RawSource::from(include_str!("runtime/get_trusted_types_policy.js").replace(
-      "$policyName$",
-      &trusted_types.policy_name.clone().unwrap_or_default(),
-    ))
+    let policy_name = serde_json::to_string(&trusted_types.policy_name.clone().unwrap_or_default())
+      .expect("should able to be serde_json::to_string");
+    RawSource::from(
+      include_str!("runtime/get_trusted_types_policy.js").replace("$policyName$", &policy_name),
+    )
     .boxed()
   }
 }
@@ -131,10 +131,14 @@ impl RuntimeModule for ImportScriptsChunkLoadingRuntimeModule {
           RuntimeGlobals::GET_CHUNK_UPDATE_SCRIPT_FILENAME
         )
       };
+      let hot_update_global_expr = format!(
+        "{}['{}']",
+        &compilation.options.output.global_object, &compilation.options.output.hot_update_global
+      );
       source.add(RawSource::from(
         include_str!("runtime/import_scripts_chunk_loading_with_hmr.js")
           .replace("$URL$", &url)
-          .replace("$globalObject$", &compilation.options.output.global_object),
+          .replace("$HOT_UPDATE_GLOBAL_EXPR$", &hot_update_global_expr),
       ));
       source.add(RawSource::from(
         include_str!("runtime/javascript_hot_module_replacement.js")
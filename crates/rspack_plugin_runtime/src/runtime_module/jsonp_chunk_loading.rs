@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use rspack_core::{
   rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt},
   Chunk, ChunkUkey, Compilation, RuntimeGlobals, RuntimeModule, RUNTIME_MODULE_STAGE_ATTACH,
@@ -32,6 +34,26 @@ impl JsonpChunkLoadingRuntimeModule {
       .unwrap_or_else(|| "document.baseURI || self.location.href".to_string());
     RawSource::from(format!("{} = {};\n", RuntimeGlobals::BASE_URI, base_uri)).boxed()
   }
+
+  /// The `fetchPriority` of the async chunks, the highest priority of the chunk groups wins.
+  fn generate_fetch_priorities(&self, chunk: &Chunk, compilation: &Compilation) -> Option<String> {
+    let fetch_priorities = chunk
+      .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+      .iter()
+      .filter_map(|chunk_ukey| compilation.chunk_by_ukey.get(chunk_ukey))
+      .filter_map(|chunk| {
+        let fetch_priority = chunk.get_fetch_priority(&compilation.chunk_group_by_ukey)?;
+        Some((chunk.id.clone()?, fetch_priority.as_str()))
+      })
+      .collect::<BTreeMap<_, _>>();
+    if fetch_priorities.is_empty() {
+      return None;
+    }
+    Some(format!(
+      "var chunkFetchPriorities = {};\n",
+      serde_json::to_string(&fetch_priorities).expect("should serialize the fetch priorities")
+    ))
+  }
 }
 
 impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
@@ -66,6 +88,34 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
       &stringify_chunks(&initial_chunks, 0)
     )));
 
+    // the expression of the `fetchPriority` of `chunkId` in the runtime
+    let fetch_priority = match self.generate_fetch_priorities(chunk, compilation) {
+      Some(fetch_priorities) => {
+        source.add(RawSource::from(fetch_priorities));
+        "chunkFetchPriorities[chunkId]"
+      }
+      None => "undefined",
+    };
+
+    // the url of the prefetched and preloaded chunks, passed through the trusted types policy
+    let link_url = if self
+      .runtime_requirements
+      .contains(RuntimeGlobals::CREATE_SCRIPT_URL)
+    {
+      format!(
+        "{}({} + {}(chunkId))",
+        RuntimeGlobals::CREATE_SCRIPT_URL,
+        RuntimeGlobals::PUBLIC_PATH,
+        RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME
+      )
+    } else {
+      format!(
+        "{} + {}(chunkId)",
+        RuntimeGlobals::PUBLIC_PATH,
+        RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME
+      )
+    };
+
     let with_loading = self
       .runtime_requirements
       .contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS);
@@ -77,7 +127,42 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
       source.add(RawSource::from(
         include_str!("runtime/jsonp_chunk_loading.js")
           // TODO
-          .replace("JS_MATCHER", "chunkId"),
+          .replace("JS_MATCHER", "chunkId")
+          .replace("FETCH_PRIORITY", fetch_priority),
+      ));
+    }
+
+    if self
+      .runtime_requirements
+      .contains(RuntimeGlobals::PREFETCH_CHUNK_HANDLERS)
+    {
+      source.add(RawSource::from(
+        include_str!("runtime/jsonp_chunk_loading_with_prefetch.js")
+          // TODO
+          .replace("JS_MATCHER", "chunkId")
+          .replace(
+            "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
+            &compilation.options.output.cross_origin_loading.to_string(),
+          )
+          .replace("FETCH_PRIORITY", fetch_priority)
+          .replace("$URL$", &link_url),
+      ));
+    }
+
+    if self
+      .runtime_requirements
+      .contains(RuntimeGlobals::PRELOAD_CHUNK_HANDLERS)
+    {
+      source.add(RawSource::from(
+        include_str!("runtime/jsonp_chunk_loading_with_preload.js")
+          // TODO
+          .replace("JS_MATCHER", "chunkId")
+          .replace(
+            "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
+            &compilation.options.output.cross_origin_loading.to_string(),
+          )
+          .replace("FETCH_PRIORITY", fetch_priority)
+          .replace("$URL$", &link_url),
       ));
     }
 
@@ -85,9 +170,13 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
       .runtime_requirements
       .contains(RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS)
     {
+      let hot_update_global_expr = format!(
+        "{}['{}']",
+        &compilation.options.output.global_object, &compilation.options.output.hot_update_global
+      );
       source.add(RawSource::from(
         include_str!("runtime/jsonp_chunk_loading_with_hmr.js")
-          .replace("$globalObject$", &compilation.options.output.global_object),
+          .replace("$HOT_UPDATE_GLOBAL_EXPR$", &hot_update_global_expr),
       ));
       source.add(RawSource::from(
         include_str!("runtime/javascript_hot_module_replacement.js").replace("$key$", "jsonp"),
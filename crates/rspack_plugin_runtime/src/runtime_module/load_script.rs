@@ -32,8 +32,16 @@ impl RuntimeModule for LoadScriptRuntimeModule {
     } else {
       "url".to_string()
     };
+    // The scripts of the builds on the same page are distinguished by `output.uniqueName`
+    let data_webpack_prefix = if compilation.options.output.unique_name.is_empty() {
+      "undefined".to_string()
+    } else {
+      serde_json::to_string(&format!("{}:", compilation.options.output.unique_name))
+        .expect("should render unique name")
+    };
     RawSource::from(
       include_str!("runtime/load_script.js")
+        .replace("$DATA_WEBPACK_PREFIX$", &data_webpack_prefix)
         .replace(
           "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
           &compilation.options.output.cross_origin_loading.to_string(),
@@ -1,5 +1,9 @@
 mod async_module;
 mod base_uri;
+mod chunk_prefetch_preload_function;
+mod chunk_prefetch_startup;
+mod chunk_prefetch_trigger;
+mod chunk_preload_trigger;
 mod compat_get_default_export;
 mod create_fake_namespace_object;
 mod create_script_url;
@@ -29,11 +33,16 @@ mod on_chunk_loaded;
 mod public_path;
 mod readfile_chunk_loading;
 mod require_js_chunk_loading;
+mod sharing;
 mod startup_chunk_dependencies;
 mod startup_entry_point;
 mod utils;
 pub use async_module::AsyncRuntimeModule;
 pub use base_uri::BaseUriRuntimeModule;
+pub use chunk_prefetch_preload_function::ChunkPrefetchPreloadFunctionRuntimeModule;
+pub use chunk_prefetch_startup::ChunkPrefetchStartupRuntimeModule;
+pub use chunk_prefetch_trigger::ChunkPrefetchTriggerRuntimeModule;
+pub use chunk_preload_trigger::ChunkPreloadTriggerRuntimeModule;
 pub use compat_get_default_export::CompatGetDefaultExportRuntimeModule;
 pub use create_fake_namespace_object::CreateFakeNamespaceObjectRuntimeModule;
 pub use create_script_url::CreateScriptUrlRuntimeModule;
@@ -62,6 +71,7 @@ pub use on_chunk_loaded::OnChunkLoadedRuntimeModule;
 pub use public_path::PublicPathRuntimeModule;
 pub use readfile_chunk_loading::ReadFileChunkLoadingRuntimeModule;
 pub use require_js_chunk_loading::RequireChunkLoadingRuntimeModule;
+pub use sharing::ShareRuntimeModule;
 pub use startup_chunk_dependencies::StartupChunkDependenciesRuntimeModule;
 pub use startup_entry_point::StartupEntrypointRuntimeModule;
 pub use utils::*;
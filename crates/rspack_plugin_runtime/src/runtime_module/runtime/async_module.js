@@ -8,6 +8,22 @@ var webpackExports =
 		: "__webpack_exports__";
 var webpackError =
 	typeof Symbol === "function" ? Symbol("webpack error") : "__webpack_error__";
+var webpackWaiting =
+	typeof Symbol === "function"
+		? Symbol("webpack waiting")
+		: "__webpack_waiting__";
+var isWaitingFor = (from, target) => {
+	var visited = new Set();
+	var stack = [from];
+	while (stack.length) {
+		var current = stack.pop();
+		if (current === target) return true;
+		if (!current[webpackWaiting] || visited.has(current)) continue;
+		visited.add(current);
+		current[webpackWaiting].forEach(dep => stack.push(dep));
+	}
+	return false;
+};
 var resolveQueue = queue => {
 	if (queue && !queue.d) {
 		queue.d = 1;
@@ -55,6 +71,7 @@ __webpack_require__.a = (module, body, hasAwait) => {
 		outerResolve = resolve;
 	});
 	promise[webpackExports] = exports;
+	promise[webpackWaiting] = [];
 	promise[webpackQueues] = fn => (
 		queue && fn(queue), depQueues.forEach(fn), promise["catch"](x => {})
 	);
@@ -63,23 +80,37 @@ __webpack_require__.a = (module, body, hasAwait) => {
 		deps => {
 			currentDeps = wrapDeps(deps);
 			var fn;
+			var waiting = [];
 			var getResult = () =>
 				currentDeps.map(d => {
 					if (d[webpackError]) throw d[webpackError];
 					return d[webpackExports];
 				});
-			var promise = new Promise(resolve => {
+			var depsPromise = new Promise(resolve => {
 				fn = () => resolve(getResult);
 				fn.r = 0;
 				var fnQueue = q =>
 					q !== queue &&
 					!depQueues.has(q) &&
 					(depQueues.add(q), q && !q.d && (fn.r++, q.push(fn)));
-				currentDeps.map(dep => dep[webpackQueues](fnQueue));
+				currentDeps.map(dep => {
+					var r = fn.r;
+					dep[webpackQueues](fnQueue);
+					if (fn.r > r) waiting.push(dep);
+				});
 			});
-			return fn.r ? promise : getResult();
+			// Waiting on a module which is (transitively) waiting on us would never settle
+			if (waiting.some(dep => isWaitingFor(dep, promise))) {
+				throw new Error(
+					"Circular dependency between async modules detected, " +
+						"the modules in the cycle are waiting on each other"
+				);
+			}
+			promise[webpackWaiting] = waiting;
+			return fn.r ? depsPromise : getResult();
 		},
 		err => (
+			(promise[webpackWaiting] = 0),
 			err ? reject((promise[webpackError] = err)) : outerResolve(exports),
 			resolveQueue(queue)
 		)
@@ -1,4 +1,4 @@
-var uniqueName = "webpack";
+var uniqueName = $UNIQUE_NAME$;
 // loadCssChunkData is unnecessary
 var loadingAttribute = "data-webpack-loading";
 var loadStylesheet = function(chunkId, url, done, hmr) {
@@ -32,6 +32,9 @@ var loadStylesheet = function(chunkId, url, done, hmr) {
 		link.setAttribute(loadingAttribute, 1);
 		link.rel = "stylesheet";
 		link.href = url;
+		if (__webpack_require__.nc) {
+			link.setAttribute("nonce", __webpack_require__.nc);
+		}
 
 		if (__CROSS_ORIGIN_LOADING_PLACEHOLDER__ && link.href.indexOf(window.location.origin + '/') !== 0) {
 			link.crossOrigin = __CROSS_ORIGIN_LOADING_PLACEHOLDER__;
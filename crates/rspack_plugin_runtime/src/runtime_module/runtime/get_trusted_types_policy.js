@@ -1,19 +1,25 @@
 var policy;
 __webpack_require__.tt = function () {
-    // Create Trusted Type policy if Trusted Types are available and the policy doesn't exist yet.
-    if (policy === undefined) {
-        policy = {
-            createScript: function (script) {
-                return script;
-            },
-            createScriptURL: function (url) {
-                return url;
-            }
-        };
-    }
-
-    if (typeof trustedTypes !== "undefined" && trustedTypes.createPolicy) {
-        policy = trustedTypes.createPolicy('$policyName$', policy);
-    }
-    return policy;
-}
\ No newline at end of file
+	// Create Trusted Type policy if Trusted Types are available and the policy doesn't exist yet.
+	if (policy === undefined) {
+		policy = {
+			createScript: function (script) {
+				return script;
+			},
+			createScriptURL: function (url) {
+				return url;
+			}
+		};
+		if (typeof trustedTypes !== "undefined" && trustedTypes.createPolicy) {
+			try {
+				policy = trustedTypes.createPolicy($policyName$, policy);
+			} catch (e) {
+				// The policy name may be taken already, keep loading the chunks with the raw URLs
+				console.warn(
+					"Could not create trusted-types policy " + JSON.stringify($policyName$)
+				);
+			}
+		}
+	}
+	return policy;
+};
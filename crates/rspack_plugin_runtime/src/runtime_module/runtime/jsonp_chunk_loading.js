@@ -44,7 +44,13 @@ __webpack_require__.f.j = function (chunkId, promises) {
 						}
 					}
 				};
-				__webpack_require__.l(url, loadingEnded, "chunk-" + chunkId, chunkId);
+				__webpack_require__.l(
+					url,
+					loadingEnded,
+					"chunk-" + chunkId,
+					chunkId,
+					FETCH_PRIORITY
+				);
 			} else installedChunks[chunkId] = 0;
 		}
 	}
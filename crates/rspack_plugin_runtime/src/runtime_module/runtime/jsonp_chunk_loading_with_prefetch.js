@@ -0,0 +1,27 @@
+__webpack_require__.F.j = function (chunkId) {
+	if (
+		(!__webpack_require__.o(installedChunks, chunkId) ||
+			installedChunks[chunkId] === undefined) &&
+		JS_MATCHER
+	) {
+		installedChunks[chunkId] = null;
+		var link = document.createElement("link");
+		if (__webpack_require__.nc) {
+			link.setAttribute("nonce", __webpack_require__.nc);
+		}
+		link.rel = "prefetch";
+		link.as = "script";
+		link.href = $URL$;
+		if (
+			__CROSS_ORIGIN_LOADING_PLACEHOLDER__ &&
+			link.href.indexOf(window.location.origin + "/") !== 0
+		) {
+			link.crossOrigin = __CROSS_ORIGIN_LOADING_PLACEHOLDER__;
+		}
+		var fetchPriority = FETCH_PRIORITY;
+		if (fetchPriority) {
+			link.fetchPriority = fetchPriority;
+		}
+		document.head.appendChild(link);
+	}
+};
@@ -0,0 +1,28 @@
+__webpack_require__.H.j = function (chunkId) {
+	if (
+		(!__webpack_require__.o(installedChunks, chunkId) ||
+			installedChunks[chunkId] === undefined) &&
+		JS_MATCHER
+	) {
+		installedChunks[chunkId] = null;
+		var link = document.createElement("link");
+		link.charset = "utf-8";
+		if (__webpack_require__.nc) {
+			link.setAttribute("nonce", __webpack_require__.nc);
+		}
+		link.rel = "preload";
+		link.as = "script";
+		link.href = $URL$;
+		if (
+			__CROSS_ORIGIN_LOADING_PLACEHOLDER__ &&
+			link.href.indexOf(window.location.origin + "/") !== 0
+		) {
+			link.crossOrigin = __CROSS_ORIGIN_LOADING_PLACEHOLDER__;
+		}
+		var fetchPriority = FETCH_PRIORITY;
+		if (fetchPriority) {
+			link.fetchPriority = fetchPriority;
+		}
+		document.head.appendChild(link);
+	}
+};
@@ -1,8 +1,8 @@
 var inProgress = {};
 
-// var dataWebpackPrefix = "webpack:";
+var dataWebpackPrefix = $DATA_WEBPACK_PREFIX$;
 // loadScript function to load a script via script tag
-__webpack_require__.l = function loadScript(url, done, key, chunkId) {
+__webpack_require__.l = function loadScript(url, done, key, chunkId, fetchPriority) {
 	if (inProgress[url]) {
 		inProgress[url].push(done);
 		return;
@@ -13,8 +13,9 @@ __webpack_require__.l = function loadScript(url, done, key, chunkId) {
 		for (var i = 0; i < scripts.length; i++) {
 			var s = scripts[i];
 			if (
-				s.getAttribute("src") == url
-				// || s.getAttribute("data-webpack") == dataWebpackPrefix + key
+				s.getAttribute("src") == url ||
+				(dataWebpackPrefix &&
+					s.getAttribute("data-webpack") == dataWebpackPrefix + key)
 			) {
 				script = s;
 				break;
@@ -27,12 +28,20 @@ __webpack_require__.l = function loadScript(url, done, key, chunkId) {
 
 		script.charset = "utf-8";
 		script.timeout = 120;
-		// script.setAttribute("data-webpack", dataWebpackPrefix + key);
+		if (__webpack_require__.nc) {
+			script.setAttribute("nonce", __webpack_require__.nc);
+		}
+		if (dataWebpackPrefix) {
+			script.setAttribute("data-webpack", dataWebpackPrefix + key);
+		}
 		script.src = $URL$;
 
 		if (__CROSS_ORIGIN_LOADING_PLACEHOLDER__ && script.src.indexOf(window.location.origin + '/') !== 0) {
 			script.crossOrigin = __CROSS_ORIGIN_LOADING_PLACEHOLDER__;
 		}
+		if (fetchPriority) {
+			script.setAttribute("fetchpriority", fetchPriority);
+		}
 	}
 	inProgress[url] = [done];
 	var onScriptComplete = function (prev, event) {
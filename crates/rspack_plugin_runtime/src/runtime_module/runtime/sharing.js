@@ -0,0 +1,48 @@
+__webpack_require__.S = {};
+var initPromises = {};
+var initTokens = {};
+__webpack_require__.I = function (name, initScope) {
+	if (!initScope) initScope = [];
+	// handling circular init calls
+	var initToken = initTokens[name];
+	if (!initToken) initToken = initTokens[name] = {};
+	if (initScope.indexOf(initToken) >= 0) return;
+	initScope.push(initToken);
+	// only runs once
+	if (initPromises[name]) return initPromises[name];
+	// creates a new share scope if needed
+	if (!__webpack_require__.o(__webpack_require__.S, name))
+		__webpack_require__.S[name] = {};
+	var warn = function (msg) {
+		if (typeof console !== "undefined" && console.warn) console.warn(msg);
+	};
+	var promises = [];
+	// initializes the remote containers with the share scope, a failed container is reported when its modules are loaded
+	var initExternal = function (id) {
+		var handleError = function (err) {
+			warn("Initialization of sharing external failed: " + err);
+		};
+		try {
+			var module = __webpack_require__(id);
+			if (!module) return;
+			var initFn = function (module) {
+				return (
+					module &&
+					module.init &&
+					module.init(__webpack_require__.S[name], initScope)
+				);
+			};
+			if (module.then) return promises.push(module.then(initFn, handleError));
+			var initResult = initFn(module);
+			if (initResult && initResult.then)
+				return promises.push(initResult["catch"](handleError));
+		} catch (err) {
+			handleError(err);
+		}
+	};
+	$INIT_EXTERNALS$
+	if (!promises.length) return (initPromises[name] = 1);
+	return (initPromises[name] = Promise.all(promises).then(function () {
+		return (initPromises[name] = 1);
+	}));
+};
@@ -0,0 +1,88 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use rspack_core::{
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeModule,
+};
+use rspack_identifier::Identifier;
+
+use crate::impl_runtime_module;
+
+/// Provides the share scopes and initializes the remote containers using them.
+///
+/// Only the remote containers are initialized for now, the share scopes are left empty.
+#[derive(Debug, Eq)]
+pub struct ShareRuntimeModule {
+  id: Identifier,
+}
+
+impl Default for ShareRuntimeModule {
+  fn default() -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/sharing"),
+    }
+  }
+}
+
+impl RuntimeModule for ShareRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    // The ids of the containers of the remote modules by share scope
+    let mut externals_by_share_scope: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for (identifier, module) in compilation.module_graph.modules() {
+      let Some(remote_module) = module.as_remote_module() else {
+        continue;
+      };
+      let external_module_ids = compilation
+        .module_graph
+        .dependencies_by_module_identifier(identifier)
+        .into_iter()
+        .flatten()
+        .filter_map(|dependency_id| {
+          let external_module = compilation
+            .module_graph
+            .module_identifier_by_dependency_id(dependency_id)?;
+          compilation
+            .chunk_graph
+            .get_module_id(*external_module)
+            .as_deref()
+        });
+      externals_by_share_scope
+        .entry(remote_module.share_scope())
+        .or_default()
+        .extend(external_module_ids);
+    }
+
+    let init_externals = externals_by_share_scope
+      .into_iter()
+      .map(|(share_scope, external_module_ids)| {
+        let init_externals = external_module_ids
+          .into_iter()
+          .map(|id| {
+            format!(
+              "initExternal({});",
+              serde_json::to_string(id).expect("invalid json to_string")
+            )
+          })
+          .collect::<Vec<_>>()
+          .join("\n");
+        format!(
+          "case {}: {{\n{init_externals}\n}}\nbreak;",
+          serde_json::to_string(share_scope).expect("invalid json to_string")
+        )
+      })
+      .collect::<Vec<_>>()
+      .join("\n");
+
+    RawSource::from(include_str!("runtime/sharing.js").replace(
+      "$INIT_EXTERNALS$",
+      &format!("switch (name) {{\n{init_externals}\n}}"),
+    ))
+    .boxed()
+  }
+}
+
+impl_runtime_module!(ShareRuntimeModule);
@@ -1,5 +1,6 @@
 use rspack_core::{
-  get_js_chunk_filename_template, Chunk, ChunkLoading, ChunkUkey, Compilation, PathData, SourceType,
+  get_js_chunk_filename_template, Chunk, ChunkLoading, ChunkLoadingType, ChunkUkey, Compilation,
+  PathData, SourceType,
 };
 use rustc_hash::FxHashSet as HashSet;
 
@@ -149,13 +150,47 @@ pub fn is_enabled_for_chunk(
   expected: &ChunkLoading,
   compilation: &Compilation,
 ) -> bool {
-  let chunk_loading = compilation
-    .chunk_by_ukey
-    .get(chunk_ukey)
-    .and_then(|chunk| chunk.get_entry_options(&compilation.chunk_group_by_ukey))
+  get_chunk_loading(chunk_ukey, compilation) == expected
+}
+
+/// The chunk loading of the chunk, async chunks of an async entrypoint (such as a worker)
+/// use the chunk loading of the async entrypoint which owns their runtime.
+pub fn get_chunk_loading<'a>(
+  chunk_ukey: &ChunkUkey,
+  compilation: &'a Compilation,
+) -> &'a ChunkLoading {
+  let Some(chunk) = compilation.chunk_by_ukey.get(chunk_ukey) else {
+    return &compilation.options.output.chunk_loading;
+  };
+  let entry_options = chunk
+    .get_entry_options(&compilation.chunk_group_by_ukey)
+    .or_else(|| {
+      if chunk.runtime.len() != 1 {
+        return None;
+      }
+      compilation
+        .async_entrypoints
+        .iter()
+        .filter_map(|ukey| compilation.chunk_group_by_ukey.get(ukey))
+        .filter_map(|entrypoint| entrypoint.options.entry_options.as_ref())
+        .find(|options| {
+          options
+            .runtime
+            .as_deref()
+            .map_or(false, |runtime| chunk.runtime.contains(runtime))
+        })
+    });
+  entry_options
     .and_then(|options| options.chunk_loading.as_ref())
-    .unwrap_or(&compilation.options.output.chunk_loading);
-  chunk_loading == expected
+    .unwrap_or(&compilation.options.output.chunk_loading)
+}
+
+/// Whether the chunk should be rendered in the module chunk format although the configured
+/// chunk format is a different one, which is the case for the chunks of module workers.
+pub fn uses_module_chunk_format(chunk_ukey: &ChunkUkey, compilation: &Compilation) -> bool {
+  let import = ChunkLoading::Enable(ChunkLoadingType::Import);
+  compilation.options.output.chunk_loading != import
+    && get_chunk_loading(chunk_ukey, compilation) == &import
 }
 
 #[test]
@@ -17,7 +17,7 @@ use crate::runtime_module::{
   HarmonyModuleDecoratorRuntimeModule, HasOwnPropertyRuntimeModule,
   LoadChunkWithModuleRuntimeModule, LoadScriptRuntimeModule, MakeNamespaceObjectRuntimeModule,
   NodeModuleDecoratorRuntimeModule, NormalRuntimeModule, OnChunkLoadedRuntimeModule,
-  PublicPathRuntimeModule,
+  PublicPathRuntimeModule, ShareRuntimeModule,
 };
 
 #[derive(Debug)]
@@ -115,6 +115,12 @@ impl Plugin for RuntimePlugin {
       runtime_requirements.insert(RuntimeGlobals::REQUIRE);
     }
 
+    if runtime_requirements.contains(RuntimeGlobals::INITIALIZE_SHARING) {
+      runtime_requirements.insert(RuntimeGlobals::SHARE_SCOPE_MAP);
+      runtime_requirements.insert(RuntimeGlobals::HAS_OWN_PROPERTY);
+      runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+    }
+
     if runtime_requirements.contains(RuntimeGlobals::DEFINE_PROPERTY_GETTERS) {
       runtime_requirements.insert(RuntimeGlobals::HAS_OWN_PROPERTY);
     }
@@ -225,6 +231,9 @@ impl Plugin for RuntimePlugin {
         RuntimeGlobals::NODE_MODULE_DECORATOR => {
           compilation.add_runtime_module(chunk, NodeModuleDecoratorRuntimeModule::default().boxed())
         }
+        RuntimeGlobals::INITIALIZE_SHARING => {
+          compilation.add_runtime_module(chunk, ShareRuntimeModule::default().boxed())
+        }
         _ => {}
       }
     }
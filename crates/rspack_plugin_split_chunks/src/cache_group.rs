@@ -20,6 +20,7 @@ pub struct CacheGroup {
   pub id_hint: String,
   pub automatic_name_delimiter: String,
   pub reuse_existing_chunk: bool,
+  pub enforce: bool,
   // TODO: supports used_exports
   // pub used_exports: bool,
   pub min_size: SplitChunkSizes,
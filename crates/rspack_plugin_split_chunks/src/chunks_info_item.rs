@@ -1,9 +1,12 @@
 use derivative::Derivative;
-use rspack_core::ChunkUkey;
+use rspack_core::{ChunkUkey, ModuleIdentifier, SplitChunksDecision};
 use rspack_identifier::IdentifierSet;
 use rustc_hash::FxHashSet;
 
-use crate::{cache_group::CacheGroup, cache_group_source::SplitChunkSizes, CacheGroupByKey};
+use crate::{
+  cache_group::CacheGroup, cache_group_source::SplitChunkSizes, utils::check_min_size,
+  CacheGroupByKey,
+};
 
 #[derive(Derivative)]
 #[derivative(Debug)]
@@ -17,11 +20,31 @@ pub(crate) struct ChunksInfoItem {
   pub sizes: SplitChunkSizes,
   pub chunks: FxHashSet<ChunkUkey>,
   pub _reusable_chunks: FxHashSet<ChunkUkey>,
+  /// Candidate modules removed from this item, with the reason
+  pub excluded_modules: Vec<(ModuleIdentifier, String)>,
+  /// Whether `maxInitialRequests` or `maxAsyncRequests` prevented splitting from some chunks
+  pub max_requests_limited: bool,
   // bigint | Chunk
   // pub chunks_keys: Hash
 }
 
 impl ChunksInfoItem {
+  pub(crate) fn to_decision(&self, cache_group: &CacheGroup) -> SplitChunksDecision {
+    let mut modules = self.modules.iter().copied().collect::<Vec<_>>();
+    modules.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+    SplitChunksDecision {
+      cache_group: self.cache_group.clone(),
+      name: self.name.clone(),
+      chunks: self.chunks.iter().copied().collect(),
+      modules,
+      enforce: cache_group.enforce,
+      min_size_satisfied: check_min_size(&self.sizes, &cache_group.min_size),
+      max_requests_limited: self.max_requests_limited,
+      excluded_modules: self.excluded_modules.clone(),
+      rejected_reason: None,
+    }
+  }
+
   pub(crate) fn cache_group<'cache_group>(
     &self,
     map: &'cache_group CacheGroupByKey,
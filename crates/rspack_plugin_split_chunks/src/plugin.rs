@@ -109,6 +109,7 @@ pub fn create_cache_group(
       .clone()
       .unwrap_or_else(|| group_source.key.clone()),
     reuse_existing_chunk: group_source.reuse_existing_chunk.unwrap_or_default(),
+    enforce: group_source.enforce.unwrap_or_default(),
     validate_size: min_size.values().any(|size| size > &0f64),
     min_size_for_max_size: merge_sizes2(group_source.min_size.clone(), options.min_size.clone()),
     validate_remaining_size: min_remaining_size.values().any(|size| size > &0f64),
@@ -174,6 +175,7 @@ pub(crate) fn remove_modules_with_source_type(
   info: &mut ChunksInfoItem,
   source_types: &[SourceType],
   module_graph: &mut ModuleGraph,
+  reason: &str,
 ) {
   info.modules.retain(|module_identifier| {
     let module = module_graph
@@ -187,6 +189,9 @@ pub(crate) fn remove_modules_with_source_type(
         .for_each(|(ty, size)| *size -= module.size(ty));
       true
     } else {
+      info
+        .excluded_modules
+        .push((*module_identifier, reason.to_string()));
       false
     }
   });
@@ -205,7 +210,7 @@ pub(crate) fn remove_min_size_violating_modules(
   };
   let violating_sizes = get_violating_min_sizes(&info.sizes, &cache_group.min_size);
   if let Some(violating_sizes) = violating_sizes {
-    remove_modules_with_source_type(info, &violating_sizes, module_graph);
+    remove_modules_with_source_type(info, &violating_sizes, module_graph, "violates minSize");
     info.modules.is_empty()
   } else {
     false
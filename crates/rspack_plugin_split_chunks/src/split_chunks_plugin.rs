@@ -263,6 +263,8 @@ impl SplitChunksPlugin {
         sizes: Default::default(),
         chunks: Default::default(),
         _reusable_chunks: Default::default(),
+        excluded_modules: Default::default(),
+        max_requests_limited: false,
       });
     let old_size = info.modules.len();
     info.modules.insert(module.identifier());
@@ -453,13 +455,15 @@ impl SplitChunksPlugin {
     compilation: &mut Compilation,
   ) {
     // Align with https://github.com/webpack/webpack/blob/8241da7f1e75c5581ba535d127fa66aeb9eb2ac8/lib/optimize/SplitChunksPlugin.js#L1280
-    let mut to_be_removed: HashSet<String> = HashSet::default();
+    let mut to_be_removed: HashMap<String, &'static str> = HashMap::default();
     for (key, info) in chunks_info_map.iter_mut() {
       if remove_min_size_violating_modules(
         info,
         &self.cache_group_by_key,
         &mut compilation.module_graph,
-      ) || !check_min_size_reduction(
+      ) {
+        to_be_removed.insert(key.clone(), "minSize is not satisfied");
+      } else if !check_min_size_reduction(
         &info.sizes,
         &self
           .cache_group_by_key
@@ -468,16 +472,24 @@ impl SplitChunksPlugin {
           .min_size_reduction,
         info.chunks.len(),
       ) {
-        to_be_removed.insert(key.clone());
+        to_be_removed.insert(key.clone(), "minSizeReduction is not satisfied");
       }
     }
-    to_be_removed.into_iter().for_each(|cache_group_key| {
-      let info = chunks_info_map.remove(&cache_group_key);
-      tracing::debug!(
-        "Remove cache group '{:?}' because of minSize violation",
-        info
-      );
-    });
+    to_be_removed
+      .into_iter()
+      .for_each(|(cache_group_key, reason)| {
+        let info = chunks_info_map
+          .remove(&cache_group_key)
+          .expect("item should exist");
+        tracing::debug!(
+          "Remove cache group '{:?}' because of minSize violation",
+          info
+        );
+        compilation.split_chunks_decisions.add_rejected(
+          info.to_decision(info.cache_group(&self.cache_group_by_key)),
+          reason,
+        );
+      });
   }
 
   fn remove_all_modules_from_other_entries_and_update_size(
@@ -487,7 +499,7 @@ impl SplitChunksPlugin {
     used_chunks: &mut HashSet<ChunkUkey>,
     compilation: &mut Compilation,
   ) {
-    let mut to_be_deleted = HashSet::default();
+    let mut to_be_deleted: HashMap<String, &'static str> = HashMap::default();
     // remove all modules from other entries and update size
     for (key, info) in chunks_info_map.iter_mut() {
       let is_overlap = info.chunks.union(used_chunks).next().is_some();
@@ -498,6 +510,10 @@ impl SplitChunksPlugin {
         for module in &item.modules {
           if info.modules.contains(module) {
             info.modules.remove(module);
+            info.excluded_modules.push((
+              *module,
+              format!("moved into the chunk of cache group {}", item.cache_group),
+            ));
             let module = compilation
               .module_graph
               .module_by_identifier(module)
@@ -519,29 +535,41 @@ impl SplitChunksPlugin {
 
         if updated {
           if info.modules.is_empty() {
-            to_be_deleted.insert(key.to_string());
+            to_be_deleted.insert(
+              key.to_string(),
+              "all modules are moved into chunks of other cache groups",
+            );
             continue;
           }
           if remove_min_size_violating_modules(
             info,
             &self.cache_group_by_key,
             &mut compilation.module_graph,
-          ) || !check_min_size_reduction(
+          ) {
+            to_be_deleted.insert(key.to_string(), "minSize is not satisfied");
+            continue;
+          }
+          if !check_min_size_reduction(
             &info.sizes,
             &info
               .cache_group(&self.cache_group_by_key)
               .min_size_reduction,
             info.chunks.len(),
           ) {
-            to_be_deleted.insert(key.to_string());
+            to_be_deleted.insert(key.to_string(), "minSizeReduction is not satisfied");
             continue;
           }
         }
       }
     }
 
-    to_be_deleted.into_iter().for_each(|key| {
-      chunks_info_map.remove(&key);
+    to_be_deleted.into_iter().for_each(|(key, reason)| {
+      if let Some(info) = chunks_info_map.remove(&key) {
+        compilation.split_chunks_decisions.add_rejected(
+          info.to_decision(info.cache_group(&self.cache_group_by_key)),
+          reason,
+        );
+      }
     });
   }
 
@@ -703,6 +731,7 @@ impl Plugin for SplitChunksPlugin {
               && get_requests(chunk, &compilation.chunk_group_by_ukey) > max_requests
             {
               used_chunks.remove(&chunk.ukey);
+              item.max_requests_limited = true;
             }
           }
         }
@@ -712,6 +741,14 @@ impl Plugin for SplitChunksPlugin {
         // Were some (invalid) chunks removed from usedChunks?
         // => readd all modules to the queue, as things could have been changed
         if used_chunks.len() < item.chunks.len() {
+          compilation.split_chunks_decisions.add_rejected(
+            item.to_decision(item_cache_group),
+            if item.max_requests_limited {
+              "maxInitialRequests or maxAsyncRequests is exceeded, modules are queued again with the remaining chunks"
+            } else {
+              "some chunks no longer contain the modules, modules are queued again with the remaining chunks"
+            },
+          );
           if is_existing_chunk {
             used_chunks.insert(*new_chunk.as_ref().expect("New chunk not found"));
           }
@@ -770,11 +807,17 @@ impl Plugin for SplitChunksPlugin {
               &mut item,
               &violating_sizes,
               &mut compilation.module_graph,
+              "violates minRemainingSize",
             );
             if !item.modules.is_empty() && item.modules.len() != old_modules_size {
               // queue this item again to be processed again
               // without violating modules
               chunks_info_map.insert(best_entry_key, item);
+            } else {
+              compilation.split_chunks_decisions.add_rejected(
+                item.to_decision(item_cache_group),
+                "minRemainingSize is not satisfied",
+              );
             }
             continue;
           }
@@ -837,6 +880,12 @@ impl Plugin for SplitChunksPlugin {
           compilation,
         );
 
+        let mut decision = item.to_decision(item_cache_group);
+        decision.chunks = used_chunks.iter().copied().collect();
+        compilation
+          .split_chunks_decisions
+          .add_produced(new_chunk_ukey, decision);
+
         let mut max_size_queue_map: HashMap<ChunkUkey, MaxSizeQueueItem> = Default::default();
 
         if !item
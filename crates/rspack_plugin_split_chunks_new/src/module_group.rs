@@ -1,9 +1,9 @@
 use derivative::Derivative;
-use rspack_core::{ChunkUkey, Module};
+use rspack_core::{ChunkUkey, Module, SplitChunksDecision};
 use rspack_identifier::IdentifierSet;
 use rustc_hash::FxHashSet;
 
-use crate::common::SplitChunkSizes;
+use crate::{common::SplitChunkSizes, CacheGroup};
 
 /// `ModuleGroup` is a abstraction of middle step for splitting chunks.
 ///
@@ -31,6 +31,22 @@ pub(crate) struct ModuleGroup {
 }
 
 impl ModuleGroup {
+  pub fn to_decision(&self, cache_group: &CacheGroup) -> SplitChunksDecision {
+    let mut modules = self.modules.iter().copied().collect::<Vec<_>>();
+    modules.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+    SplitChunksDecision {
+      cache_group: cache_group.key.clone(),
+      name: self.chunk_name.clone(),
+      chunks: self.chunks.iter().copied().collect(),
+      modules,
+      min_size_satisfied: cache_group
+        .min_size
+        .iter()
+        .all(|(ty, min_size)| self.sizes.get(ty).copied().unwrap_or_default() >= *min_size),
+      ..Default::default()
+    }
+  }
+
   pub fn add_module(&mut self, module: &dyn Module) {
     let old_len = self.modules.len();
     self.modules.insert(module.identifier());
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use rayon::prelude::*;
-use rspack_core::{ChunkUkey, Compilation, Module, ModuleIdentifier};
+use rspack_core::{ChunkUkey, Compilation, Logger, Module, ModuleIdentifier};
 use rspack_util::identifier::make_paths_relative;
 use rustc_hash::FxHashMap;
 
@@ -170,6 +170,7 @@ impl SplitChunksPlugin {
     let automatic_name_delimiter = "~".to_string();
     let chunk_group_db = &compilation.chunk_group_by_ukey;
     let compilation_ref = &*compilation;
+    let logger = compilation_ref.get_infrastructure_logger("rspack.SplitChunksPlugin");
 
     let chunks_with_size_info = compilation_ref
     .chunk_by_ukey
@@ -222,11 +223,11 @@ impl SplitChunksPlugin {
         if let Some(ty_min_size) = min_size.get(ty) {
           if ty_min_size > ty_max_size {
             is_invalid = true;
-            tracing::warn!(
+            logger.warn(format!(
               "minSize({}) should not be bigger than maxSize({})",
               ty_min_size,
               ty_max_size
-            );
+            ));
           }
         }
       });
@@ -72,9 +72,10 @@ impl SplitChunksPlugin {
   #[tracing::instrument(skip_all)]
   pub(crate) fn ensure_min_size_fit(
     &self,
-    compilation: &Compilation,
+    compilation: &mut Compilation,
     module_group_map: &mut ModuleGroupMap,
   ) {
+    let compilation_ref = &*compilation;
     let invalidated_module_groups = module_group_map
       .par_iter_mut()
       .filter_map(|(module_group_key, module_group)| {
@@ -91,7 +92,7 @@ impl SplitChunksPlugin {
 
         if Self::remove_min_size_violating_modules(
           module_group_key,
-          compilation,
+          compilation_ref,
           module_group,
           cache_group,
         ) {
@@ -107,7 +108,13 @@ impl SplitChunksPlugin {
         "ModuleGroup({}) is removed. Reason: empty modules cause by `minSize` checking",
         key,
       );
-      module_group_map.remove(&key);
+      if let Some(module_group) = module_group_map.remove(&key) {
+        let cache_group = &self.cache_groups[module_group.cache_group_index];
+        compilation.split_chunks_decisions.add_rejected(
+          module_group.to_decision(cache_group),
+          "minSize is not satisfied",
+        );
+      }
     });
   }
 }
@@ -102,6 +102,12 @@ impl SplitChunksPlugin {
         if used_chunks_len < cache_group.min_chunks as usize {
           // `min_size` is not satisfied, ignore this invalid `ModuleGroup`
           tracing::trace!("ModuleGroup({module_group_key}) is skipped. Reason: used_chunks_len({used_chunks_len:?}) < cache_group.min_chunks({:?})", cache_group.min_chunks);
+          let mut decision = module_group.to_decision(cache_group);
+          decision.max_requests_limited = true;
+          compilation.split_chunks_decisions.add_rejected(
+            decision,
+            "minChunks is not satisfied after applying maxInitialRequests or maxAsyncRequests",
+          );
           return;
         }
       }
@@ -124,6 +130,13 @@ impl SplitChunksPlugin {
         compilation,
       );
 
+      let mut decision = module_group.to_decision(cache_group);
+      decision.chunks = used_chunks.iter().copied().collect();
+      decision.max_requests_limited = used_chunks.len() != module_group.chunks.len();
+      compilation
+        .split_chunks_decisions
+        .add_produced(new_chunk, decision);
+
       self.split_from_original_chunks(&module_group, &used_chunks, new_chunk, compilation);
 
       self.remove_all_modules_from_other_module_groups(
@@ -76,7 +76,15 @@ impl Plugin for ReadFileCompileAsyncWasmPlugin {
         args.chunk,
         AsyncWasmLoadingRuntimeModule::new(
           if self.import {
-            include_str!("runtime/read_file_compile_async_wasm_with_import.js").to_string()
+            include_str!("runtime/read_file_compile_async_wasm_with_import.js")
+              .replace(
+                "$importFunctionName$",
+                &args.compilation.options.output.import_function_name,
+              )
+              .replace(
+                "$importMetaName$",
+                &args.compilation.options.output.import_meta_name,
+              )
           } else {
             include_str!("runtime/read_file_compile_async_wasm.js").to_string()
           },
@@ -1,15 +1,16 @@
-use std::collections::hash_map::DefaultHasher;
-use std::hash::{Hash, Hasher};
+use std::hash::Hash;
 
 use dashmap::DashMap;
 use rspack_core::rspack_sources::{BoxSource, RawSource, Source, SourceExt};
 use rspack_core::DependencyType::WasmImport;
 use rspack_core::{
-  AssetInfo, BoxDependency, BuildMetaExportsType, Compilation, Filename, GenerateContext, Module,
-  ModuleDependency, ModuleIdentifier, NormalModule, ParseContext, ParseResult, ParserAndGenerator,
-  PathData, RuntimeGlobals, SourceType,
+  AssetInfo, BoxDependency, BuildMetaExportsType, BuildMetaProvidedExports, Compilation,
+  CompilerOptions, Filename, GenerateContext, Module, ModuleDependency, ModuleIdentifier,
+  NormalModule, ParseContext, ParseResult, ParserAndGenerator, PathData, RuntimeGlobals,
+  SourceType,
 };
 use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_identifier::Identifier;
 use wasmparser::{Import, Parser, Payload};
 
@@ -24,6 +25,10 @@ pub struct AsyncWasmParserAndGenerator {
 pub(crate) static WASM_SOURCE_TYPE: &[SourceType; 2] = &[SourceType::Wasm, SourceType::JavaScript];
 
 impl ParserAndGenerator for AsyncWasmParserAndGenerator {
+  fn name(&self) -> &'static str {
+    "webassembly/async"
+  }
+
   fn source_types(&self) -> &[SourceType] {
     WASM_SOURCE_TYPE
   }
@@ -45,7 +50,7 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
           Payload::ExportSection(s) => {
             for export in s {
               match export {
-                Ok(export) => exports.push(export.name.to_string()),
+                Ok(export) => exports.push(export.name.into()),
                 Err(err) => diagnostic.push(Diagnostic::error(
                   "Wasm Export Parse Error".into(),
                   err.to_string(),
@@ -87,9 +92,8 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
       }
     }
 
-    // FIXME: marking StatsExportDependency as a ModuleDependency is not correct
-    // dependencies
-    //   .push(box StaticExportsDependency::new(exports, false) as Box<dyn ModuleDependency>);
+    // The exports of the instance are all known from the export section
+    parse_context.build_meta.provided_exports = BuildMetaProvidedExports::Names(exports);
 
     Ok(
       ParseResult {
@@ -124,7 +128,8 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
   ) -> Result<BoxSource> {
     let compilation = generate_context.compilation;
     let wasm_filename_template = &compilation.options.output.webassembly_module_filename;
-    let hash = hash_for_source(source);
+    let hash = hash_for_source(source, &compilation.options);
+    let hash = hash.rendered(compilation.options.output.hash_digest_length);
     let normal_module = module
       .as_normal_module()
       .expect("module should be a NormalModule in AsyncWasmParserAndGenerator::generate");
@@ -276,14 +281,14 @@ fn render_wasm_name(
   compilation: &Compilation,
   normal_module: &NormalModule,
   wasm_filename_template: &Filename,
-  hash: String,
+  hash: &str,
 ) -> (String, AssetInfo) {
   compilation.get_asset_path_with_info(
     wasm_filename_template,
     PathData::default()
       .filename(&normal_module.resource_resolved_data().resource)
-      .content_hash(&hash)
-      .hash(&hash),
+      .content_hash(hash)
+      .hash(hash),
   )
 }
 
@@ -292,8 +297,8 @@ fn render_import_stmt(import_var: &str, module_id: &str) -> String {
   format!("var {import_var} = __webpack_require__({module_id});\n",)
 }
 
-fn hash_for_source(source: &BoxSource) -> String {
-  let mut hasher = DefaultHasher::new();
+fn hash_for_source(source: &BoxSource, compiler_options: &CompilerOptions) -> RspackHashDigest {
+  let mut hasher = RspackHash::from(&compiler_options.output);
   source.hash(&mut hasher);
-  format!("{:016x}", hasher.finish())
+  hasher.digest(&compiler_options.output.hash_digest)
 }
@@ -1,5 +1,5 @@
-Promise.all([import('fs'), import('url')]).then(([{ readFile }, { URL }]) => new Promise((resolve, reject) => {
-    readFile(new URL($PATH, import.meta.url), (err, buffer) => {          
+Promise.all([$importFunctionName$('fs'), $importFunctionName$('url')]).then(([{ readFile }, { URL }]) => new Promise((resolve, reject) => {
+    readFile(new URL($PATH, $importMetaName$.url), (err, buffer) => {          
         if (err) return reject(err);
         // Fake fetch response
         resolve({
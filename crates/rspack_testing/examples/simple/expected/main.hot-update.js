@@ -1,4 +1,4 @@
-self['hotUpdate']('main', {
+self['webpackHotUpdatewebpack']('main', {
 "./a.js": function (module, exports, __webpack_require__) {
 console.log('b');
 },
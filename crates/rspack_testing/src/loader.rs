@@ -1,36 +1,14 @@
-use std::{fmt::Debug, path::Path, sync::Arc};
+use std::{fmt::Debug, path::Path};
 
+use rspack_binding_options::get_builtin_loader;
 use rspack_core::{
   BoxLoader, CompilerOptions, NormalModule, Plugin, Resolver, BUILTIN_LOADER_PREFIX,
 };
 use rspack_error::{internal_error, Result};
-use rspack_loader_sass::SASS_LOADER_IDENTIFIER;
-use rspack_loader_swc::SWC_LOADER_IDENTIFIER;
 
 #[derive(Debug)]
 pub struct BuiltinLoaderResolver;
 
-fn get_builtin_loader(builtin: &str, options: Option<&str>) -> BoxLoader {
-  if builtin.starts_with(SASS_LOADER_IDENTIFIER) {
-    return Arc::new(rspack_loader_sass::SassLoader::new(
-      serde_json::from_str(options.unwrap_or("{}")).unwrap_or_else(|e| {
-        panic!("Could not parse builtin:sass-loader options: {options:?}, error: {e:?}")
-      }),
-    ));
-  }
-
-  if builtin.starts_with(SWC_LOADER_IDENTIFIER) {
-    return Arc::new(rspack_loader_swc::SwcLoader::new(
-      serde_json::from_str(options.unwrap_or("{}")).unwrap_or_else(|e| {
-        panic!("Could not parse builtin:swc-loader options:{options:?},error: {e:?}")
-      }),
-      Some(builtin.into()),
-    ));
-  }
-
-  unreachable!("Unexpected builtin loader: {builtin}")
-}
-
 #[async_trait::async_trait]
 impl Plugin for BuiltinLoaderResolver {
   async fn before_loaders(&self, module: &mut NormalModule) -> Result<()> {
@@ -54,7 +32,7 @@ impl Plugin for BuiltinLoaderResolver {
     loader_options: Option<&str>,
   ) -> Result<Option<BoxLoader>> {
     if loader_request.starts_with(BUILTIN_LOADER_PREFIX) {
-      return Ok(Some(get_builtin_loader(loader_request, loader_options)));
+      return get_builtin_loader(loader_request, loader_options).map(Some);
     }
 
     Err(internal_error!(
@@ -60,6 +60,10 @@ fn default_map_filename() -> String {
   "[file].map".to_string()
 }
 
+fn default_import_meta_name() -> String {
+  "import.meta".to_string()
+}
+
 fn default_optimization_module_ids() -> String {
   "named".to_string()
 }
@@ -72,6 +76,10 @@ fn default_optimization_side_effects() -> String {
   "false".to_string()
 }
 
+fn default_optimization_mangle_exports() -> String {
+  "false".to_string()
+}
+
 fn true_by_default() -> bool {
   true
 }
@@ -107,6 +115,9 @@ pub struct Experiments {
   // True by default to reduce code in snapshots.
   #[serde(default = "true_by_default")]
   pub async_web_assembly: bool,
+  // True by default to align with webpack.
+  #[serde(default = "true_by_default")]
+  pub top_level_await: bool,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -123,6 +134,14 @@ pub struct Optimization {
   pub chunk_ids: String,
   #[serde(default = "default_optimization_side_effects")]
   pub side_effects: String,
+  /// `"single"`, `"multiple"` or the name of the runtime chunk
+  #[serde(default)]
+  pub runtime_chunk: Option<String>,
+  #[serde(default)]
+  pub concatenate_modules: bool,
+  /// `"false"`, `"deterministic"` or `"size"`
+  #[serde(default = "default_optimization_mangle_exports")]
+  pub mangle_exports: String,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -227,6 +246,10 @@ pub struct Output {
   pub source_map_filename: String,
   #[serde(default)]
   pub library: Option<LibraryOptions>,
+  #[serde(default)]
+  pub module: bool,
+  #[serde(default = "default_import_meta_name")]
+  pub import_meta_name: String,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -356,7 +379,10 @@ impl TestConfig {
     let options = CompilerOptions {
       context: c::Context::new(context.to_string_lossy().to_string()),
       output: c::OutputOptions {
-        clean: self.output.clean,
+        clean: c::CleanOptions {
+          enable: self.output.clean,
+          keep: None,
+        },
         filename: c::Filename::from_str(&self.output.filename).expect("Should exist"),
         chunk_filename: c::Filename::from_str(&self.output.chunk_filename).expect("Should exist"),
         cross_origin_loading: rspack_core::CrossOriginLoading::Disable,
@@ -375,6 +401,7 @@ impl TestConfig {
         unique_name: "__rspack_test__".to_string(),
         chunk_loading: c::ChunkLoading::Enable(c::ChunkLoadingType::Jsonp),
         chunk_loading_global: "webpackChunkwebpack".to_string(),
+        hot_update_global: "webpackHotUpdatewebpack".to_string(),
         path: context.join("dist"),
         library: self.output.library.map(|l| c::LibraryOptions {
           name: None,
@@ -384,11 +411,13 @@ impl TestConfig {
           auxiliary_comment: None,
         }),
         enabled_library_types: Some(vec!["system".to_string()]),
+        enabled_chunk_loading_types: None,
         strict_module_error_handling: false,
         global_object: "self".to_string(),
         import_function_name: "import".to_string(),
+        import_meta_name: self.output.import_meta_name,
         iife: true,
-        module: false,
+        module: self.output.module,
         trusted_types: None,
         source_map_filename: c::Filename::from_str(&self.output.source_map_filename)
           .expect("Should exist"),
@@ -400,6 +429,7 @@ impl TestConfig {
         worker_chunk_loading: c::ChunkLoading::Enable(c::ChunkLoadingType::ImportScripts),
         worker_wasm_loading: c::WasmLoading::Enable(c::WasmLoadingType::from("fetch")),
         worker_public_path: String::new(),
+        environment: Default::default(),
       },
       mode: c::Mode::from(self.mode),
       target: c::Target::new(&self.target).expect("Can't construct target"),
@@ -440,11 +470,14 @@ impl TestConfig {
         rules,
         ..Default::default()
       },
-      devtool: c::Devtool::from(self.devtool),
+      devtool: c::Devtool::try_from(self.devtool).expect("should be a valid devtool"),
       stats: Default::default(),
       snapshot: Default::default(),
       cache: c::CacheOptions::Disabled,
-      experiments: Default::default(),
+      experiments: c::Experiments {
+        top_level_await: self.experiments.top_level_await,
+        ..Default::default()
+      },
       dev_server: Default::default(),
       node: Some(c::NodeOption {
         dirname: "mock".to_string(),
@@ -455,8 +488,22 @@ impl TestConfig {
         remove_available_modules: self.optimization.remove_available_modules,
         remove_empty_chunks: self.optimization.remove_empty_chunks,
         side_effects: c::SideEffectOption::from(self.optimization.side_effects.as_str()),
+        runtime_chunk: self.optimization.runtime_chunk.as_deref().map(|name| {
+          c::RuntimeChunkOptions {
+            name: match name {
+              "single" => c::RuntimeChunkName::Single,
+              "multiple" => c::RuntimeChunkName::Multiple,
+              _ => c::RuntimeChunkName::String(name.to_string()),
+            },
+          }
+        }),
+        concatenate_modules: self.optimization.concatenate_modules,
       },
+      amd: true,
+      require_cache: true,
       profile: false,
+      ignore_warnings: vec![],
+      infrastructure_logging: Default::default(),
     };
     let mut plugins = Vec::new();
     for (name, desc) in &self.entry {
@@ -472,6 +519,7 @@ impl TestConfig {
               public_path: None,
               base_uri: None,
               filename: None,
+              depend_on: None,
             },
           )
           .boxed(),
@@ -522,6 +570,7 @@ impl TestConfig {
       }
     }
     plugins.push(rspack_plugin_json::JsonPlugin {}.boxed());
+    plugins.push(rspack_plugin_runtime::ChunkPrefetchPreloadPlugin.boxed());
     plugins.push(rspack_plugin_runtime::ArrayPushCallbackChunkFormatPlugin {}.boxed());
     plugins.push(rspack_plugin_runtime::CssModulesPlugin {}.boxed());
     plugins.push(rspack_plugin_runtime::JsonpChunkLoadingPlugin {}.boxed());
@@ -535,7 +584,7 @@ impl TestConfig {
     // plugins.push(rspack_plugin_externals::ExternalPlugin::default().boxed());
     plugins.push(rspack_plugin_javascript::JsPlugin::new().boxed());
 
-    if options.devtool.source_map() {
+    if options.devtool.source_map() || options.devtool.eval() {
       plugins.push(
         rspack_plugin_devtool::DevtoolPlugin::new(rspack_plugin_devtool::DevtoolPluginOptions {
           inline: options.devtool.inline(),
@@ -544,26 +593,37 @@ impl TestConfig {
           columns: !options.devtool.cheap(),
           no_sources: options.devtool.no_sources(),
           public_path: None,
+          ignore_list: None,
         })
         .boxed(),
       );
     }
 
-    if self.optimization.module_ids == "named" {
-      plugins.push(rspack_ids::NamedModuleIdsPlugin::default().boxed());
-    } else {
-      plugins.push(rspack_ids::DeterministicModuleIdsPlugin::default().boxed());
+    match self.optimization.module_ids.as_str() {
+      "named" => plugins.push(rspack_ids::NamedModuleIdsPlugin::default().boxed()),
+      "natural" => plugins.push(rspack_ids::NaturalModuleIdsPlugin::default().boxed()),
+      _ => plugins.push(rspack_ids::DeterministicModuleIdsPlugin::default().boxed()),
     }
-    if self.optimization.chunk_ids == "named" {
-      plugins.push(rspack_ids::NamedChunkIdsPlugin::new(None, None).boxed());
-    } else {
-      plugins.push(rspack_ids::DeterministicChunkIdsPlugin::default().boxed());
+    match self.optimization.chunk_ids.as_str() {
+      "named" => plugins.push(rspack_ids::NamedChunkIdsPlugin::new(None, None).boxed()),
+      "natural" => plugins.push(rspack_ids::NaturalChunkIdsPlugin::default().boxed()),
+      _ => plugins.push(rspack_ids::DeterministicChunkIdsPlugin::default().boxed()),
     }
-    plugins.push(rspack_ids::StableNamedChunkIdsPlugin::new(None, None).boxed());
+    plugins.push(rspack_ids::StableNamedChunkIdsPlugin::new(None, None, None).boxed());
     // Notice the plugin need to be placed after SplitChunksPlugin
     plugins.push(rspack_plugin_remove_empty_chunks::RemoveEmptyChunksPlugin.boxed());
 
     plugins.push(rspack_plugin_javascript::InferAsyncModulesPlugin {}.boxed());
+    if self.optimization.concatenate_modules {
+      plugins.push(rspack_plugin_javascript::ModuleConcatenationPlugin.boxed());
+    }
+    match self.optimization.mangle_exports.as_str() {
+      "deterministic" => {
+        plugins.push(rspack_plugin_javascript::MangleExportsPlugin::new(true).boxed())
+      }
+      "size" => plugins.push(rspack_plugin_javascript::MangleExportsPlugin::new(false).boxed()),
+      _ => {}
+    }
     if self.experiments.async_web_assembly {
       plugins.push(rspack_plugin_wasm::FetchCompileAsyncWasmPlugin {}.boxed());
       plugins.push(rspack_plugin_wasm::AsyncWasmPlugin::new().boxed());
@@ -3,6 +3,7 @@ use std::{any::Any, hash::Hash};
 pub trait AsAny {
   fn as_any(&self) -> &dyn Any;
   fn as_any_mut(&mut self) -> &mut dyn Any;
+  fn into_any(self: Box<Self>) -> Box<dyn Any>;
 }
 
 impl<T: Any> AsAny for T {
@@ -12,6 +13,9 @@ impl<T: Any> AsAny for T {
   fn as_any_mut(&mut self) -> &mut dyn Any {
     self
   }
+  fn into_any(self: Box<Self>) -> Box<dyn Any> {
+    self
+  }
 }
 
 pub trait DynHash {
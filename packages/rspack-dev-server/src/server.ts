@@ -238,8 +238,19 @@ export class RspackDevServer extends WebpackDevServer {
 			additionalEntries.push(`${clientPath}?${webSocketURLStr}`);
 		}
 
-		for (const key in compiler.options.entry) {
-			compiler.options.entry[key].import.unshift(...additionalEntries);
+		const { entry } = compiler.options;
+		if (typeof entry === "function") {
+			compiler.options.entry = () =>
+				entry().then(entries => {
+					for (const key in entries) {
+						entries[key].import?.unshift(...additionalEntries);
+					}
+					return entries;
+				});
+		} else {
+			for (const key in entry) {
+				entry[key].import!.unshift(...additionalEntries);
+			}
 		}
 	}
 
@@ -7,7 +7,8 @@ const config = {
 		"<rootDir>/tests/*.longtest.ts",
 		"<rootDir>/tests/*.unittest.ts",
 		"<rootDir>/tests/copyPlugin/*.test.js",
-		"<rootDir>/tests/WatchSuspend.test.js"
+		"<rootDir>/tests/WatchSuspend.test.js",
+		"<rootDir>/tests/Watching.test.js"
 	],
 	testTimeout: process.env.CI ? 120000 : 30000,
 	cache: false,
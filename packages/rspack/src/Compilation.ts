@@ -8,10 +8,11 @@
  * https://github.com/webpack/webpack/blob/main/LICENSE
  */
 import * as tapable from "tapable";
-import { Source } from "webpack-sources";
+import { RawSource, Source, SourceMapSource } from "webpack-sources";
 
 import type {
 	ExternalObject,
+	JsAddEntryArgs,
 	JsAssetInfo,
 	JsChunk,
 	JsCompatSource,
@@ -34,6 +35,7 @@ import ResolverFactory from "./ResolverFactory";
 import { ChunkGroup } from "./ChunkGroup";
 import { Compiler } from "./Compiler";
 import ErrorHelpers from "./ErrorHelpers";
+import ModuleFilenameHelpers from "./lib/ModuleFilenameHelpers";
 import { LogType, Logger } from "./logging/Logger";
 import { NormalModule } from "./NormalModule";
 import { NormalModuleFactory } from "./NormalModuleFactory";
@@ -61,6 +63,33 @@ export interface Asset {
 	source?: Source;
 	info: JsAssetInfo;
 }
+
+type AssetCondition = string | RegExp;
+type AssetConditions = AssetCondition | AssetCondition[];
+
+export interface MinimizeAssetsOptions {
+	name: string;
+	test?: AssetConditions;
+	include?: AssetConditions;
+	exclude?: AssetConditions;
+}
+
+export interface MinimizeAsset {
+	name: string;
+	source: Source;
+	map: Object | null;
+	info: JsAssetInfo;
+}
+
+export interface MinimizeResult {
+	code: string;
+	/**
+	 * The source map from the source of the asset to `code`,
+	 * which is composed with the source map of the asset.
+	 */
+	map?: Object | string | null;
+}
+
 export interface LogEntry {
 	type: string;
 	args: any[];
@@ -257,6 +286,8 @@ export class Compilation {
 			options.chunkRelations,
 			!context.forToString
 		);
+		// computing the explanation is expensive, so it is not enabled by `all`
+		options.chunkExplanations = options.chunkExplanations ?? false;
 		options.modules = optionOrLocalFallback(options.modules, true);
 		options.reasons = optionOrLocalFallback(
 			options.reasons,
@@ -271,6 +302,9 @@ export class Compilation {
 		options.errorsCount = optionOrLocalFallback(options.errorsCount, true);
 		options.warnings = optionOrLocalFallback(options.warnings, true);
 		options.warningsCount = optionOrLocalFallback(options.warningsCount, true);
+		// not enabled by `all`, the existing output of the errors is kept by default
+		options.errorDetails = options.errorDetails ?? false;
+		options.moduleTrace = options.moduleTrace ?? false;
 		options.hash = optionOrLocalFallback(options.hash, true);
 		options.version = optionOrLocalFallback(options.version, true);
 		options.publicPath = optionOrLocalFallback(options.publicPath, true);
@@ -279,6 +313,7 @@ export class Compilation {
 			!context.forToString
 		);
 		options.timings = optionOrLocalFallback(options.timings, true);
+		options.profile = optionOrLocalFallback(options.profile, false);
 		options.builtAt = optionOrLocalFallback(
 			options.builtAt,
 			!context.forToString
@@ -289,6 +324,10 @@ export class Compilation {
 			!context.forToString
 		);
 		options.source = optionOrLocalFallback(options.source, false);
+		options.optimizationBailout = optionOrLocalFallback(
+			options.optimizationBailout,
+			!context.forToString
+		);
 		options.logging = optionOrLocalFallback(
 			options.logging,
 			context.forToString ? "info" : true
@@ -300,6 +339,14 @@ export class Compilation {
 		options.loggingDebug = []
 			.concat(optionsOrFallback(options.loggingDebug, []))
 			.map(normalizeFilter);
+		// Sorting and space limits only apply to the string output
+		if (!context.forToString) {
+			delete options.assetsSort;
+			delete options.chunksSort;
+			delete options.modulesSort;
+			delete options.assetsSpace;
+			delete options.chunksSpace;
+		}
 
 		return options;
 	}
@@ -358,6 +405,63 @@ export class Compilation {
 		);
 	}
 
+	/**
+	 * Minimize the assets matched by `options` with a JS minimizer, e.g. terser or esbuild.
+	 * It runs at `PROCESS_ASSETS_STAGE_OPTIMIZE_SIZE` before the builtin minimizer, which skips
+	 * the assets minimized here. Return nothing to keep the asset as it is.
+	 */
+	minimizeAssets(
+		options: MinimizeAssetsOptions,
+		minimize: (
+			asset: MinimizeAsset
+		) => Promise<MinimizeResult | void> | MinimizeResult | void
+	) {
+		this.hooks.processAssets.tapPromise(
+			{
+				name: options.name,
+				stage: Compilation.PROCESS_ASSETS_STAGE_OPTIMIZE_SIZE
+			},
+			async () => {
+				const assets = this.getAssets().filter(
+					({ name, source, info }) =>
+						source &&
+						!info.minimized &&
+						ModuleFilenameHelpers.matchObject(options, name)
+				);
+				await Promise.all(
+					assets.map(async ({ name, source, info }) => {
+						const original = source!;
+						const map = original.map();
+						let result;
+						try {
+							result = await minimize({ name, source: original, map, info });
+						} catch (err: any) {
+							throw new Error(
+								`${options.name} failed to minimize ${name}: ${
+									err?.message ?? err
+								}`
+							);
+						}
+						if (!result) {
+							return;
+						}
+						const minimized = result.map
+							? new SourceMapSource(
+									result.code,
+									name,
+									result.map,
+									original.source().toString(),
+									map ?? undefined,
+									true
+							  )
+							: new RawSource(result.code);
+						this.updateAsset(name, minimized, { ...info, minimized: true });
+					})
+				);
+			}
+		);
+	}
+
 	/**
 	 *
 	 * @param moduleIdentifier moduleIdentifier of the module you want to modify
@@ -669,6 +773,9 @@ export class Compilation {
 				modules: this.__internal__getAssociatedModules(chunk),
 				isOnlyInitial: function () {
 					return this.initial;
+				},
+				getModules: function () {
+					return this.modules ?? [];
 				}
 			};
 		});
@@ -743,6 +850,14 @@ export class Compilation {
 		return this.#inner.getChunks();
 	}
 
+	/**
+	 * Explain why the chunk with the given id or name exists,
+	 * including the splitChunks decisions which produced it.
+	 */
+	explainChunk(idOrName: string) {
+		return this.#inner.explainChunk(idOrName);
+	}
+
 	getStats() {
 		return new Stats(this);
 	}
@@ -764,16 +879,26 @@ export class Compilation {
 	}
 
 	_rebuildModuleCaller = new MergeCaller(
-		(args: Array<[string, (err: any, m: JsModule) => void]>) => {
+		(args: Array<[JsModule, (err: any, m: JsModule) => void]>) => {
 			this.#inner.rebuildModule(
-				args.map(item => item[0]),
-				function (err: any, modules: JsModule[]) {
-					for (const [id, callback] of args) {
-						const m = modules.find(item => item.moduleIdentifier === id);
-						if (m) {
-							callback(err, m);
+				args.map(item => item[0].moduleIdentifier),
+				(err, results) => {
+					for (const [module, callback] of args) {
+						const result = results?.find(
+							item => item.moduleIdentifier === module.moduleIdentifier
+						);
+						if (err || !result || result.error) {
+							callback(
+								err ??
+									new Error(
+										result?.error ??
+											`Failed to rebuild ${module.moduleIdentifier}`
+									),
+								module
+							);
 						} else {
-							callback(err || new Error("module no found"), null as any);
+							// The module is left intact if its hash is unchanged
+							callback(null, result.module ?? module);
 						}
 					}
 				}
@@ -782,7 +907,78 @@ export class Compilation {
 		10
 	);
 	rebuildModule(m: JsModule, f: (err: any, m: JsModule) => void) {
-		this._rebuildModuleCaller.push([m.moduleIdentifier, f]);
+		this._rebuildModuleCaller.push([m, f]);
+	}
+
+	#addEntryQueue: Promise<void> = Promise.resolve();
+	_addEntryCaller = new MergeCaller(
+		(
+			args: Array<[JsAddEntryArgs, (err: any, module?: JsModule) => void]>
+		) => {
+			// Entries added in the same tick are built together, while the batches are built
+			// one by one as they all update the module graph.
+			this.#addEntryQueue = this.#addEntryQueue.then(
+				() =>
+					new Promise<void>(resolve => {
+						this.#inner.addEntry(
+							args.map(item => item[0]),
+							(err, results) => {
+								args.forEach(([entry, callback], index) => {
+									const result = results?.[index];
+									if (err || !result || result.error) {
+										callback(
+											err ??
+												new Error(
+													result?.error ??
+														`Failed to add entry "${entry.name}"`
+												)
+										);
+									} else {
+										callback(null, result.module);
+									}
+								});
+								resolve();
+							}
+						);
+					})
+			);
+		},
+		10
+	);
+
+	/**
+	 * Add an entry while making, e.g. in the `make` hook.
+	 * The module of the request is built before the callback is called.
+	 */
+	addEntry(
+		context: string,
+		request: string,
+		optionsOrName: string | { name: string },
+		callback: (err?: Error | null, module?: JsModule) => void
+	) {
+		const name =
+			typeof optionsOrName === "string" ? optionsOrName : optionsOrName.name;
+		this._addEntryCaller.push([
+			{ context, request, name, isInclude: false },
+			callback
+		]);
+	}
+
+	/**
+	 * Add a module to the entry chunk, which is not executed on startup.
+	 */
+	addInclude(
+		context: string,
+		request: string,
+		optionsOrName: string | { name: string },
+		callback: (err?: Error | null, module?: JsModule) => void
+	) {
+		const name =
+			typeof optionsOrName === "string" ? optionsOrName : optionsOrName.name;
+		this._addEntryCaller.push([
+			{ context, request, name, isInclude: true },
+			callback
+		]);
 	}
 
 	/**
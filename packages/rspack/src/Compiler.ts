@@ -27,6 +27,7 @@ import ResolverFactory from "./ResolverFactory";
 import { getRawOptions } from "./config/adapter";
 import { LoaderContext, LoaderResult } from "./config/adapterRuleUse";
 import ConcurrentCompilationError from "./error/ConcurrentCompilationError";
+import CompilerClosedError from "./error/CompilerClosedError";
 import { createThreadsafeNodeFSFromRaw } from "./fileSystem";
 import Cache from "./lib/Cache";
 import { makePathsRelative } from "./util/identifier";
@@ -85,6 +86,9 @@ class HotModuleReplacementPlugin {
 
 class Compiler {
 	#_instance?: binding.Rspack;
+	#closed: boolean = false;
+	#pendingIdleCallbacks: (() => void)[] = [];
+	#outputFileSystemKind: "node" | "memory" = "node";
 
 	webpack: any;
 	// @ts-expect-error
@@ -287,6 +291,9 @@ class Compiler {
 		};
 		const options = getRawOptions(this.options, this, processResource);
 
+		if (this.#closed) {
+			return callback(new CompilerClosedError());
+		}
 		let error = checkVersion();
 		if (error) {
 			return callback(error);
@@ -294,6 +301,7 @@ class Compiler {
 
 		const instanceBinding: typeof binding = require("@rspack/binding");
 
+		const created = this.#_instance === undefined;
 		this.#_instance =
 			this.#_instance ??
 			new instanceBinding.Rspack(
@@ -387,6 +395,7 @@ class Compiler {
 					chunkAsset: this.#chunkAsset.bind(this),
 					beforeResolve: this.#beforeResolve.bind(this),
 					afterResolve: this.#afterResolve.bind(this),
+					createModule: this.#createModule.bind(this),
 					contextModuleBeforeResolve:
 						this.#contextModuleBeforeResolve.bind(this),
 					succeedModule: this.#succeedModule.bind(this),
@@ -397,10 +406,38 @@ class Compiler {
 				(loaderContext: binding.JsLoaderContext) =>
 					runLoader(loaderContext, this)
 			);
+		if (created) {
+			this.#_instance.unsafe_set_infrastructure_logger(
+				this.#infrastructureLog.bind(this)
+			);
+		}
+		if (this.#outputFileSystemKind !== "node") {
+			this.#_instance.unsafe_set_output_file_system(
+				this.#outputFileSystemKind
+			);
+		}
 
 		callback(null, this.#_instance);
 	}
 
+	/**
+	 * Keep the emitted assets in memory instead of writing them with `outputFileSystem`,
+	 * they can be read back with `readOutputFile`.
+	 * The memory store is dropped when switching back to `"node"`.
+	 */
+	setOutputFileSystem(kind: "node" | "memory") {
+		this.#outputFileSystemKind = kind;
+		this.#_instance?.unsafe_set_output_file_system(kind);
+	}
+
+	/**
+	 * Read an emitted asset from the memory output file system,
+	 * returns `null` if the asset doesn't exist.
+	 */
+	readOutputFile(path: string): Buffer | null {
+		return this.#_instance?.unsafe_read_output_file(path) ?? null;
+	}
+
 	createChildCompiler(
 		compilation: Compilation,
 		compilerName: string,
@@ -531,6 +568,20 @@ class Compiler {
 		const isRoot = this.root === this;
 		return !isRoot;
 	}
+	/**
+	 * Report the infrastructure logs of the Rust side the same way as `getInfrastructureLogger`
+	 */
+	#infrastructureLog(log: binding.JsStatsLogging) {
+		const args = log.args ?? [];
+		if (
+			this.hooks.infrastructureLog.call(log.name, log.type, args) === undefined
+		) {
+			if (this.infrastructureLogger !== undefined) {
+				this.infrastructureLogger(log.name, log.type, args);
+			}
+		}
+	}
+
 	getInfrastructureLogger(name: string | Function) {
 		if (!name) {
 			throw new TypeError(
@@ -695,6 +746,7 @@ class Compiler {
 			chunkAsset: this.compilation.hooks.chunkAsset,
 			beforeResolve: this.compilation.normalModuleFactory?.hooks.beforeResolve,
 			afterResolve: this.compilation.normalModuleFactory?.hooks.afterResolve,
+			createModule: this.compilation.normalModuleFactory?.hooks.createModule,
 			succeedModule: this.compilation.hooks.succeedModule,
 			stillValidModule: this.compilation.hooks.stillValidModule,
 			buildModule: this.compilation.hooks.buildModule
@@ -750,6 +802,7 @@ class Compiler {
 		const normalizedResolveData = {
 			request: resolveData.request,
 			context: resolveData.context,
+			issuer: resolveData.issuer,
 			fileDependencies: [],
 			missingDependencies: [],
 			contextDependencies: []
@@ -785,6 +838,15 @@ class Compiler {
 		return res;
 	}
 
+	async #createModule(createData: binding.JsCreateModuleData) {
+		await this.compilation.normalModuleFactory?.hooks.createModule.promise(
+			createData
+		);
+
+		this.#updateDisabledHooks();
+		return createData;
+	}
+
 	async #contextModuleBeforeResolve(resourceData: binding.BeforeResolveData) {
 		let res =
 			await this.compilation.contextModuleFactory?.hooks.beforeResolve.promise(
@@ -901,6 +963,9 @@ class Compiler {
 	}
 
 	run(callback: Callback<Error, Stats>) {
+		if (this.#closed) {
+			return callback(new CompilerClosedError());
+		}
 		if (this.running) {
 			return callback(new ConcurrentCompilationError());
 		}
@@ -920,6 +985,7 @@ class Compiler {
 					callback(err, stats);
 				}
 				this.hooks.afterDone.call(stats);
+				this.#flushPendingIdleCallbacks();
 			};
 			this.hooks.beforeRun.callAsync(this, err => {
 				if (err) {
@@ -1004,7 +1070,37 @@ class Compiler {
 		});
 	}
 
+	// Safety: Build and rebuild are rejected by the native compiler until the returned watching is stopped.
+	nativeWatch(
+		watchOptions: binding.RawWatchOptions,
+		watchRun: (
+			args: binding.JsWatchRunArgs
+		) => Promise<"build" | "rebuild" | "blocked">,
+		done: (error: string | null) => Promise<void>,
+		callback: (error: Error | null) => void
+	): binding.Rspack | undefined {
+		let watching: binding.Rspack | undefined;
+		this.#getInstance((error, instance) => {
+			if (error) {
+				return callback(error);
+			}
+			try {
+				instance!.unsafe_watch(watchOptions, watchRun, done, error => {
+					callback(error);
+				});
+				watching = instance;
+			} catch (err) {
+				callback(err as Error);
+			}
+		});
+		return watching;
+	}
+
 	watch(watchOptions: WatchOptions, handler: Callback<Error, Stats>): Watching {
+		if (this.#closed) {
+			// @ts-expect-error
+			return handler(new CompilerClosedError());
+		}
 		if (this.running) {
 			// @ts-expect-error
 			return handler(new ConcurrentCompilationError());
@@ -1023,15 +1119,6 @@ class Compiler {
 	}
 
 	close(callback: (error?: Error | null) => void) {
-		// WARNING: Arbitrarily dropping the instance is not safe, as it may still be in use by the background thread.
-		// A hint is necessary for the compiler to know when it is safe to drop the instance.
-		// For example: register a callback to the background thread, and drop the instance when the callback is called (calling the `close` method queues the signal)
-		// See: https://github.com/webpack/webpack/blob/4ba225225b1348c8776ca5b5fe53468519413bc0/lib/Compiler.js#L1218
-		if (!this.running) {
-			// Manually drop the instance.
-			// this.#_instance = undefined;
-		}
-
 		if (this.watching) {
 			// When there is still an active watching, close this first
 			this.watching.close(() => {
@@ -1039,12 +1126,37 @@ class Compiler {
 			});
 			return;
 		}
+		if (this.running) {
+			// Dropping the instance is not safe while the background thread is still building,
+			// wait for the running compilation to finish first.
+			// See: https://github.com/webpack/webpack/blob/4ba225225b1348c8776ca5b5fe53468519413bc0/lib/Compiler.js#L1218
+			this.#pendingIdleCallbacks.push(() => this.close(callback));
+			return;
+		}
+		if (this.#closed) {
+			return callback();
+		}
+		this.#closed = true;
 		this.hooks.shutdown.callAsync(err => {
 			if (err) return callback(err);
-			this.cache.shutdown(callback);
+			this.cache.shutdown(err => {
+				// Dropping the instance releases the threadsafe functions held by the native compiler,
+				// including the JS loader runner, so they no longer keep the event loop alive.
+				this.#_instance?.unsafe_drop();
+				this.#_instance = undefined;
+				callback(err);
+			});
 		});
 	}
 
+	#flushPendingIdleCallbacks() {
+		const callbacks = this.#pendingIdleCallbacks;
+		this.#pendingIdleCallbacks = [];
+		for (const cb of callbacks) {
+			cb();
+		}
+	}
+
 	getAsset(name: string) {
 		let source = this.compilation.__internal__getAssetSource(name);
 		if (!source) {
@@ -14,6 +14,7 @@ type ResourceDataWithData = ResourceData & { data?: Record<string, any> };
 type ResolveData = {
 	context?: string;
 	request: string;
+	issuer?: string;
 	fileDependencies: string[];
 	missingDependencies: string[];
 	contextDependencies: string[];
@@ -21,6 +22,13 @@ type ResolveData = {
 	// assertions: Record<string, any> | undefined;
 	// dependencies: ModuleDependency[];
 };
+type CreateData = {
+	rawRequest: string;
+	userRequest: string;
+	resourceData: ResourceData;
+	matchResource?: ResourceData;
+	settings: Record<string, any>;
+};
 
 export class NormalModuleFactory {
 	hooks: {
@@ -30,6 +38,7 @@ export class NormalModuleFactory {
 		>;
 		beforeResolve: AsyncSeriesBailHook<[ResolveData], boolean | void>;
 		afterResolve: AsyncSeriesBailHook<[ResolveData], boolean | void>;
+		createModule: AsyncSeriesBailHook<[CreateData], void>;
 	};
 	constructor() {
 		this.hooks = {
@@ -48,9 +57,9 @@ export class NormalModuleFactory {
 			// /** @type {AsyncSeriesBailHook<[ResolveData], false | void>} */
 			beforeResolve: new AsyncSeriesBailHook(["resolveData"]),
 			// /** @type {AsyncSeriesBailHook<[ResolveData], false | void>} */
-			afterResolve: new AsyncSeriesBailHook(["resolveData"])
-			// /** @type {AsyncSeriesBailHook<[ResolveData["createData"], ResolveData], Module | void>} */
-			// createModule: new AsyncSeriesBailHook(["createData", "resolveData"]),
+			afterResolve: new AsyncSeriesBailHook(["resolveData"]),
+			// TODO: second param resolveData
+			createModule: new AsyncSeriesBailHook(["createData"])
 			// /** @type {SyncWaterfallHook<[Module, ResolveData["createData"], ResolveData], Module>} */
 			// module: new SyncWaterfallHook(["module", "createData", "resolveData"]),
 			// createParser: new HookMap(() => new SyncBailHook(["parserOptions"])),
@@ -116,15 +116,16 @@ function presetToOptions(name?: boolean | string): StatsOptions {
 			};
 		case "verbose":
 			return {
-				all: true
+				all: true,
+				chunkExplanations: true
 			};
 		case "errors-only":
 			return {
 				all: false,
 				errors: true,
 				errorsCount: true,
-				logging: "error"
-				// TODO: moduleTrace: true,
+				logging: "error",
+				moduleTrace: true
 			};
 		case "errors-warnings":
 			return {
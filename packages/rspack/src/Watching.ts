@@ -7,16 +7,22 @@
  * Copyright (c) JS Foundation and other contributors
  * https://github.com/webpack/webpack/blob/main/LICENSE
  */
+import type * as binding from "@rspack/binding";
 import { Callback } from "tapable";
 import type { Compilation, Compiler } from ".";
 import { Stats } from ".";
 import { WatchOptions } from "./config";
-import { FileSystemInfoEntry, Watcher } from "./util/fs";
+import { Watcher } from "./util/fs";
 import assert from "assert";
 
+/**
+ * The changes are aggregated and compiled by the native watching, which queues a single
+ * compilation for the changes arriving in the middle of a compilation.
+ * The file events are reported by the watch file system, unless `poll` is a number and the
+ * native watching polls the files itself.
+ */
 class Watching {
 	watcher?: Watcher;
-	pausedWatcher?: Watcher;
 	compiler: Compiler;
 	handler: (error?: Error, stats?: Stats) => void;
 	callbacks: Callback<Error, void>[];
@@ -34,8 +40,13 @@ class Watching {
 	#closeCallbacks?: ((err?: Error) => void)[];
 	#initial: boolean;
 	#closed: boolean;
-	#collectedChangedFiles?: Set<string>;
-	#collectedRemovedFiles?: Set<string>;
+	#native?: binding.Rspack;
+	#polling: boolean;
+	#dependencies?: {
+		files: Iterable<string>;
+		dirs: Iterable<string>;
+		missing: Iterable<string>;
+	};
 	suspended: boolean;
 
 	constructor(
@@ -57,24 +68,61 @@ class Watching {
 		this.watchOptions = watchOptions;
 		this.handler = handler;
 		this.suspended = false;
+		// A `RegExp` of `ignored` is only understood by the watch file system
+		this.#polling =
+			typeof watchOptions.poll === "number" &&
+			!(watchOptions.ignored instanceof RegExp);
 
 		process.nextTick(() => {
-			if (this.#initial) this.#invalidate();
+			if (this.#initial) this.#start();
 		});
 	}
 
+	#start() {
+		if (this.#closed || this.#native) return;
+		const { aggregateTimeout, ignored } = this.watchOptions;
+		this.#native = this.compiler.nativeWatch(
+			{
+				aggregateTimeout,
+				poll: this.#polling ? (this.watchOptions.poll as number) : undefined,
+				ignored:
+					typeof ignored === "string"
+						? [ignored]
+						: Array.isArray(ignored)
+						? ignored
+						: undefined
+			},
+			args => this.#watchRun(args),
+			error => this.#done(error),
+			err => {
+				if (err) this.handler(err);
+			}
+		);
+		if (this.suspended) {
+			this.#native?.unsafe_watching_suspend();
+		}
+	}
+
 	watch(
 		files: Iterable<string>,
 		dirs: Iterable<string>,
 		missing: Iterable<string>
 	) {
-		this.pausedWatcher = undefined;
+		this.#dependencies = { files, dirs, missing };
+		this.#watchFiles(this.lastWatcherStartTime);
+	}
+
+	#watchFiles(startTime: number) {
+		if (!this.#dependencies) return;
+		const { files, dirs, missing } = this.#dependencies;
+		this.lastWatcherStartTime = startTime;
 		this.watcher = this.compiler.watchFileSystem.watch(
 			files,
 			dirs,
 			missing,
-			this.lastWatcherStartTime,
-			this.watchOptions,
+			startTime,
+			// The changes are aggregated by the native watching
+			{ ...this.watchOptions, aggregateTimeout: 0 },
 			(
 				err,
 				fileTimeInfoEntries,
@@ -87,12 +135,13 @@ class Watching {
 					this.compiler.removedFiles = undefined;
 					return this.handler(err);
 				}
-				this.#invalidate(
-					fileTimeInfoEntries,
-					contextTimeInfoEntries,
-					changedFiles,
-					removedFiles
+				// The watcher stops after reporting the changes, keep watching from now on
+				const now = Date.now();
+				this.#native?.unsafe_watching_notify(
+					[...changedFiles],
+					[...removedFiles]
 				);
+				if (!this.#closed) this.#watchFiles(now);
 				// @ts-expect-error
 				this.onChange();
 			},
@@ -155,20 +204,15 @@ class Watching {
 			this.watcher.close();
 			this.watcher = undefined;
 		}
-		if (this.pausedWatcher) {
-			this.pausedWatcher.close();
-			this.pausedWatcher = undefined;
-		}
 		this.compiler.watching = undefined;
 		this.compiler.watchMode = false;
 		this.#closeCallbacks = [];
 		if (callback) {
 			this.#closeCallbacks.push(callback);
 		}
-		if (this.running) {
-			this.invalid = true;
-
-			this._done = finalCallback;
+		if (this.#native) {
+			// The native watching waits for the running compilation before stopping
+			this.#native.unsafe_watching_close(err => finalCallback(err));
 		} else {
 			finalCallback(null);
 		}
@@ -184,77 +228,66 @@ class Watching {
 		}
 		// @ts-expect-error
 		this.onChange();
-		this.#invalidate();
+		this.invalid = true;
+		this.#native?.unsafe_watching_invalidate();
 	}
 
-	#invalidate(
-		fileTimeInfoEntries?: Map<string, FileSystemInfoEntry | "ignore">,
-		contextTimeInfoEntries?: Map<string, FileSystemInfoEntry | "ignore">,
-		changedFiles?: Set<string>,
-		removedFiles?: Set<string>
-	) {
-		// @ts-expect-error
-		this.#mergeWithCollected(changedFiles, removedFiles);
-		// @ts-expect-error
-		if (this.suspended || (this.isBlocked() && (this.blocked = true))) {
-			return;
-		}
-
-		if (this.running) {
-			this.invalid = true;
-			return;
-		}
-
-		this.#go(changedFiles, removedFiles);
-	}
-
-	#go(changedFiles?: ReadonlySet<string>, removedFiles?: ReadonlySet<string>) {
-		if (this.startTime === undefined) this.startTime = Date.now();
-		this.running = true;
-		if (this.watcher) {
-			this.pausedWatcher = this.watcher;
-			this.lastWatcherStartTime = Date.now();
-			this.watcher.pause();
-			this.watcher = undefined;
-		} else if (!this.lastWatcherStartTime) {
-			this.lastWatcherStartTime = Date.now();
-		}
-
-		if (changedFiles && removedFiles) {
-			this.#mergeWithCollected(changedFiles, removedFiles);
-		} else if (this.pausedWatcher) {
-			const { changes, removals } = this.pausedWatcher.getInfo();
-			this.#mergeWithCollected(changes, removals);
-		}
-
-		const modifiedFiles = (this.compiler.modifiedFiles =
-			this.#collectedChangedFiles);
-		const deleteFiles = (this.compiler.removedFiles =
-			this.#collectedRemovedFiles);
-		this.#collectedChangedFiles = undefined;
-		this.#collectedRemovedFiles = undefined;
-		this.invalid = false;
-		this.#invalidReported = false;
-		this.compiler.hooks.watchRun.callAsync(this.compiler, err => {
-			if (err) return this._done(err, null);
+	#watchRun({
+		changedFiles,
+		removedFiles
+	}: binding.JsWatchRunArgs): Promise<"build" | "rebuild" | "blocked"> {
+		return new Promise((resolve, reject) => {
+			// @ts-expect-error
+			if (this.isBlocked()) {
+				// The changes are kept by the native watching until it's invalidated again
+				this.blocked = true;
+				return resolve("blocked");
+			}
+			this.blocked = false;
+			if (this.startTime === undefined) this.startTime = Date.now();
+			if (!this.lastWatcherStartTime) this.lastWatcherStartTime = Date.now();
+			this.running = true;
 
-			const canRebuild =
-				this.compiler.options.devServer &&
-				!this.#initial &&
-				(modifiedFiles?.size || deleteFiles?.size);
+			if (this.#polling) {
+				// The changes are not reported by the watch file system
+				const inputFileSystem = this.compiler.inputFileSystem;
+				if (inputFileSystem && inputFileSystem.purge) {
+					for (const file of [...changedFiles, ...removedFiles]) {
+						inputFileSystem.purge(file);
+					}
+				}
+			}
 
-			const onBuild = (err: Error | null) => {
-				if (err) return this._done(err, null);
-				// if (this.invalid) return this._done(null);
-				this._done(null, this.compiler.compilation);
-			};
+			const modifiedFiles = (this.compiler.modifiedFiles = new Set(
+				changedFiles
+			));
+			const deleteFiles = (this.compiler.removedFiles = new Set(removedFiles));
+			this.invalid = false;
+			this.#invalidReported = false;
+			this.compiler.hooks.watchRun.callAsync(this.compiler, err => {
+				if (err) return reject(err);
 
-			if (canRebuild) {
-				this.compiler.rebuild(modifiedFiles, deleteFiles, onBuild as any);
-			} else {
-				this.compiler.build(onBuild);
+				const canRebuild =
+					this.compiler.options.devServer &&
+					!this.#initial &&
+					(modifiedFiles.size || deleteFiles.size);
 				this.#initial = false;
+				resolve(canRebuild ? "rebuild" : "build");
+			});
+		});
+	}
+
+	#done(error: string | null): Promise<void> {
+		return new Promise(resolve => {
+			if (this.#closed) {
+				// The compilation finished after closing is not reported, like webpack
+				this.running = false;
+				return resolve();
+			}
+			if (error !== null) {
+				return this._done(new Error(error), null, resolve);
 			}
+			this._done(null, this.compiler.compilation, resolve);
 		});
 	}
 
@@ -262,9 +295,17 @@ class Watching {
 	 * The reason why this is _done instead of #done, is that in Webpack,
 	 * it will rewrite this function to another function
 	 */
-	private _done(error: Error, compilation: null): void;
-	private _done(error: null, compilation: Compilation): void;
-	private _done(error: Error | null, compilation: Compilation | null) {
+	private _done(error: Error, compilation: null, callback: () => void): void;
+	private _done(
+		error: null,
+		compilation: Compilation,
+		callback: () => void
+	): void;
+	private _done(
+		error: Error | null,
+		compilation: Compilation | null,
+		callback: () => void
+	) {
 		this.running = false;
 		let stats: undefined | Stats = undefined;
 
@@ -278,6 +319,7 @@ class Watching {
 				this.callbacks = [];
 			}
 			for (const cb of cbs) cb(err);
+			callback();
 		};
 
 		const cbs = this.callbacks;
@@ -300,7 +342,7 @@ class Watching {
 			this.handler(null, stats);
 
 			process.nextTick(() => {
-				if (!this.#closed) {
+				if (!this.#polling && !this.#closed) {
 					this.watch(
 						compilation.fileDependencies,
 						compilation.contextDependencies,
@@ -310,39 +352,19 @@ class Watching {
 			});
 			for (const cb of cbs) cb(null);
 			this.compiler.hooks.afterDone.call(stats!);
+			callback();
 		});
 	}
 
-	#mergeWithCollected(
-		changedFiles: ReadonlySet<string>,
-		removedFiles: ReadonlySet<string>
-	) {
-		if (!changedFiles) return;
-		if (!this.#collectedChangedFiles) {
-			this.#collectedChangedFiles = new Set(changedFiles);
-			this.#collectedRemovedFiles = new Set(removedFiles);
-		} else {
-			for (const file of changedFiles) {
-				this.#collectedChangedFiles.add(file);
-				// @ts-expect-error
-				this.#collectedRemovedFiles.delete(file);
-			}
-			for (const file of removedFiles) {
-				this.#collectedChangedFiles.delete(file);
-				// @ts-expect-error
-				this.#collectedRemovedFiles.add(file);
-			}
-		}
-	}
-
 	suspend() {
 		this.suspended = true;
+		this.#native?.unsafe_watching_suspend();
 	}
 
 	resume() {
 		if (this.suspended) {
 			this.suspended = false;
-			this.#invalidate();
+			this.#native?.unsafe_watching_resume();
 		}
 	}
 }
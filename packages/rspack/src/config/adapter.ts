@@ -12,13 +12,18 @@ import type {
 	RawAssetParserOptions,
 	RawAssetParserDataUrl,
 	RawAssetGeneratorDataUrl,
+	RawAssetGeneratorDataUrlFnArgs,
 	RawAssetInlineGeneratorOptions,
 	RawAssetResourceGeneratorOptions,
 	RawIncrementalRebuild,
 	RawModuleRuleUses,
-	RawFuncUseCtx
+	RawFuncUseCtx,
+	RawIgnoreWarning,
+	RawIgnoreWarningFnCtx,
+	RawSnapshotPath
 } from "@rspack/binding";
 import assert from "assert";
+import path from "path";
 import { Compiler } from "../Compiler";
 import { normalizeStatsPreset } from "../Stats";
 import { isNil } from "../util";
@@ -28,8 +33,10 @@ import {
 	createRawModuleRuleUses
 } from "./adapterRuleUse";
 import {
+	Clean,
 	CrossOriginLoading,
-	EntryNormalized,
+	DevtoolIgnoreList,
+	EntryStaticNormalized,
 	ExternalItem,
 	ExternalItemValue,
 	Externals,
@@ -56,7 +63,10 @@ import {
 	ParserOptionsByModuleType,
 	GeneratorOptionsByModuleType,
 	ExperimentsNormalized,
-	IncrementalRebuildOptions
+	IgnoreWarningsNormalized,
+	InfrastructureLogging,
+	IncrementalRebuildOptions,
+	OptimizationRuntimeChunkNormalized
 } from "./types";
 import { SplitChunksConfig } from "./zod/optimization/split-chunks";
 
@@ -74,16 +84,26 @@ export const getRawOptions = (
 		"context, devtool, cache should not be nil after defaults"
 	);
 	const devtool = options.devtool === false ? "" : options.devtool;
-	let rawEntry = getRawEntry(options.entry);
+	const { entry } = options;
+	let rawEntry = typeof entry === "function" ? {} : getRawEntry(entry);
 	return {
 		entry: rawEntry,
 		entryOrder: Object.keys(rawEntry),
+		dynamicEntry:
+			typeof entry === "function"
+				? {
+						function: async () =>
+							Object.entries(getRawEntry(await entry())).map(
+								([name, description]) => ({ name, description })
+							)
+				  }
+				: undefined,
 		mode: options.mode,
 		target: getRawTarget(options.target),
 		context: options.context,
 		output: getRawOutput(options.output),
-		resolve: getRawResolve(options.resolve),
-		resolveLoader: getRawResolve(options.resolveLoader),
+		resolve: getRawResolve(options.resolve, options.context),
+		resolveLoader: getRawResolve(options.resolveLoader, options.context),
 		module: getRawModule(options.module, {
 			compiler,
 			devtool,
@@ -116,13 +136,21 @@ export const getRawOptions = (
 		},
 		experiments: getRawExperiments(options.experiments),
 		node: getRawNode(options.node),
+		amd: options.amd !== false,
+		requireCache: !!options.externalsPresets.node,
 		profile: options.profile!,
+		ignoreWarnings: options.ignoreWarnings
+			? getRawIgnoreWarnings(options.ignoreWarnings, compiler)
+			: undefined,
+		infrastructureLogging: getRawInfrastructureLogging(
+			options.infrastructureLogging
+		),
 		// TODO: refactor builtins
 		builtins: options.builtins as any
 	};
 };
 
-function getRawEntry(entry: EntryNormalized): RawOptions["entry"] {
+function getRawEntry(entry: EntryStaticNormalized): RawOptions["entry"] {
 	const raw: RawOptions["entry"] = {};
 	for (const key of Object.keys(entry)) {
 		const runtime = entry[key].runtime;
@@ -132,6 +160,7 @@ function getRawEntry(entry: EntryNormalized): RawOptions["entry"] {
 			publicPath: entry[key].publicPath,
 			baseUri: entry[key].baseUri,
 			runtime: runtime === false ? undefined : runtime,
+			dependOn: entry[key].dependOn,
 			chunkLoading: chunkLoading === false ? "false" : chunkLoading,
 			asyncChunks: entry[key].asyncChunks,
 			filename: entry[key].filename
@@ -164,15 +193,19 @@ function getRawAlias(
 }
 
 function getRawResolveByDependency(
-	byDependency: Resolve["byDependency"]
+	byDependency: Resolve["byDependency"],
+	context: string
 ): RawOptions["resolve"]["byDependency"] {
 	if (byDependency === undefined) return byDependency;
 	return Object.fromEntries(
-		Object.entries(byDependency).map(([k, v]) => [k, getRawResolve(v)])
+		Object.entries(byDependency).map(([k, v]) => [k, getRawResolve(v, context)])
 	);
 }
 
-function getRawResolve(resolve: Resolve): RawOptions["resolve"] {
+function getRawResolve(
+	resolve: Resolve,
+	context: string
+): RawOptions["resolve"] {
 	return {
 		...resolve,
 		alias: getRawAlias(resolve.alias),
@@ -181,10 +214,69 @@ function getRawResolve(resolve: Resolve): RawOptions["resolve"] {
 			string,
 			Array<string>
 		>,
-		byDependency: getRawResolveByDependency(resolve.byDependency)
+		tsConfigPath:
+			resolve.tsConfigPath === undefined
+				? undefined
+				: path.resolve(context, resolve.tsConfigPath),
+		tsconfig: getRawResolveTsconfig(resolve.tsconfig, context),
+		byDependency: getRawResolveByDependency(resolve.byDependency, context)
+	};
+}
+
+function getRawResolveTsconfig(
+	tsconfig: Resolve["tsconfig"],
+	context: string
+): RawOptions["resolve"]["tsconfig"] {
+	if (tsconfig === undefined) return tsconfig;
+	// The relative paths are resolved against the context
+	if (typeof tsconfig === "string") {
+		return {
+			configFile: path.resolve(context, tsconfig),
+			referencesType: "disabled"
+		};
+	}
+	const configFile = path.resolve(context, tsconfig.configFile);
+	const { references } = tsconfig;
+	if (references === undefined) {
+		return { configFile, referencesType: "disabled" };
+	}
+	if (references === "auto") {
+		return { configFile, referencesType: "auto" };
+	}
+	return {
+		configFile,
+		referencesType: "manual",
+		references: references.map(reference => path.resolve(context, reference))
 	};
 }
 
+function getRawClean(clean: Clean): RawOptions["output"]["clean"] {
+	if (typeof clean === "boolean") {
+		return { enable: clean };
+	}
+	const { keep } = clean;
+	return {
+		enable: true,
+		keep:
+			keep === undefined
+				? undefined
+				: keep instanceof RegExp
+				? { type: "regexp", regexpMatcher: keep.source }
+				: { type: "string", stringMatcher: keep }
+	};
+}
+
+function getRawDevtoolIgnoreList(
+	devtoolIgnoreList: DevtoolIgnoreList | undefined
+): RawOptions["output"]["devtoolIgnoreList"] {
+	if (devtoolIgnoreList === undefined || devtoolIgnoreList === false) {
+		return undefined;
+	}
+	return devtoolIgnoreList instanceof RegExp
+		? { type: "regexp", regexpMatcher: devtoolIgnoreList.source }
+		: { type: "string", stringMatcher: devtoolIgnoreList };
+}
+
 function getRawCrossOriginLoading(
 	crossOriginLoading: CrossOriginLoading
 ): RawOptions["output"]["crossOriginLoading"] {
@@ -202,7 +294,7 @@ function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 	return {
 		path: output.path!,
 		publicPath: output.publicPath!,
-		clean: output.clean!,
+		clean: getRawClean(output.clean!),
 		assetModuleFilename: output.assetModuleFilename!,
 		filename: output.filename!,
 		chunkFormat: output.chunkFormat === false ? "false" : output.chunkFormat!,
@@ -215,11 +307,13 @@ function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 		hotUpdateMainFilename: output.hotUpdateMainFilename!,
 		uniqueName: output.uniqueName!,
 		chunkLoadingGlobal: output.chunkLoadingGlobal!,
+		hotUpdateGlobal: output.hotUpdateGlobal!,
 		enabledLibraryTypes: output.enabledLibraryTypes,
 		library: output.library && getRawLibrary(output.library),
 		strictModuleErrorHandling: output.strictModuleErrorHandling!,
 		globalObject: output.globalObject!,
 		importFunctionName: output.importFunctionName!,
+		importMetaName: output.importMetaName!,
 		iife: output.iife!,
 		module: output.module!,
 		wasmLoading: wasmLoading === false ? "false" : wasmLoading,
@@ -228,6 +322,7 @@ function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 		webassemblyModuleFilename: output.webassemblyModuleFilename!,
 		trustedTypes: output.trustedTypes!,
 		sourceMapFilename: output.sourceMapFilename!,
+		devtoolIgnoreList: getRawDevtoolIgnoreList(output.devtoolIgnoreList),
 		hashFunction: output.hashFunction!,
 		hashDigest: output.hashDigest!,
 		hashDigestLength: output.hashDigestLength!,
@@ -237,7 +332,11 @@ function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 			workerChunkLoading === false ? "false" : workerChunkLoading,
 		workerWasmLoading:
 			workerWasmLoading === false ? "false" : workerWasmLoading,
-		workerPublicPath: output.workerPublicPath!
+		workerPublicPath: output.workerPublicPath!,
+		environment: {
+			arrowFunction: output.environment!.arrowFunction!,
+			const: output.environment!.const!
+		}
 	};
 }
 
@@ -397,7 +496,9 @@ const getRawModuleRule = (
 		generator: rule.generator
 			? getRawGeneratorOptions(rule.generator, rule.type ?? "javascript/auto")
 			: undefined,
-		resolve: rule.resolve ? getRawResolve(rule.resolve) : undefined,
+		resolve: rule.resolve
+			? getRawResolve(rule.resolve, options.context)
+			: undefined,
 		oneOf: rule.oneOf
 			? rule.oneOf.map((rule, index) =>
 					getRawModuleRule(rule, `${path}.oneOf[${index}]`, options)
@@ -590,8 +691,15 @@ function getRawAssetGeneratorDaraUrl(
 			}
 		};
 	}
+	if (typeof dataUrl === "function") {
+		return {
+			type: "function",
+			function: ({ filename, content }: RawAssetGeneratorDataUrlFnArgs) =>
+				dataUrl(content, { filename })
+		};
+	}
 	throw new Error(
-		`unreachable: AssetGeneratorDataUrl type should be one of "options", but got ${dataUrl}`
+		`unreachable: AssetGeneratorDataUrl type should be one of "options", "function", but got ${dataUrl}`
 	);
 }
 
@@ -668,8 +776,10 @@ function getRawOptimization(
 			!isNil(optimization.removeAvailableModules) &&
 			!isNil(optimization.removeEmptyChunks) &&
 			!isNil(optimization.sideEffects) &&
-			!isNil(optimization.realContentHash),
-		"optimization.moduleIds, optimization.removeAvailableModules, optimization.removeEmptyChunks, optimization.sideEffects, optimization.realContentHash should not be nil after defaults"
+			!isNil(optimization.realContentHash) &&
+			!isNil(optimization.concatenateModules) &&
+			!isNil(optimization.mangleExports),
+		"optimization.moduleIds, optimization.removeAvailableModules, optimization.removeEmptyChunks, optimization.sideEffects, optimization.realContentHash, optimization.concatenateModules, optimization.mangleExports should not be nil after defaults"
 	);
 	return {
 		chunkIds: optimization.chunkIds,
@@ -678,7 +788,27 @@ function getRawOptimization(
 		removeAvailableModules: optimization.removeAvailableModules,
 		removeEmptyChunks: optimization.removeEmptyChunks,
 		sideEffects: String(optimization.sideEffects),
-		realContentHash: optimization.realContentHash
+		realContentHash: optimization.realContentHash,
+		concatenateModules: optimization.concatenateModules,
+		mangleExports:
+			optimization.mangleExports === true
+				? "deterministic"
+				: String(optimization.mangleExports),
+		runtimeChunk: toRawRuntimeChunkOptions(
+			optimization.runtimeChunk as OptimizationRuntimeChunkNormalized
+		)
+	};
+}
+
+function toRawRuntimeChunkOptions(
+	runtimeChunk?: OptimizationRuntimeChunkNormalized
+): RawOptions["optimization"]["runtimeChunk"] {
+	if (!runtimeChunk) {
+		return;
+	}
+	return {
+		type: "function",
+		fnPayload: ({ name }) => runtimeChunk.name({ name })
 	};
 }
 
@@ -711,11 +841,54 @@ function toRawSplitChunksOptions(
 	};
 }
 
+function getRawIgnoreWarnings(
+	ignoreWarnings: IgnoreWarningsNormalized,
+	compiler: Compiler
+): RawIgnoreWarning[] {
+	return ignoreWarnings.map((ignore): RawIgnoreWarning => {
+		if (typeof ignore === "function") {
+			return {
+				type: "function",
+				funcMatcher: ({
+					message,
+					moduleIdentifier,
+					file
+				}: RawIgnoreWarningFnCtx) =>
+					ignore(
+						Object.assign(new Error(message), { moduleIdentifier, file }),
+						compiler.compilation
+					)
+			};
+		}
+		const { message, module } = ignore;
+		if (message && module) {
+			// Both of them should be matched, which is not supported by a single matcher
+			return {
+				type: "function",
+				funcMatcher: (warning: RawIgnoreWarningFnCtx) =>
+					message.test(warning.message) &&
+					!!warning.moduleIdentifier &&
+					module.test(warning.moduleIdentifier)
+			};
+		}
+		if (module) {
+			return { type: "module", regexpMatcher: module.source };
+		}
+		// `{}` ignores all the warnings
+		return { type: "message", regexpMatcher: (message ?? /(?:)/).source };
+	});
+}
+
 function getRawSnapshotOptions(
 	snapshot: SnapshotOptions
 ): RawOptions["snapshot"] {
-	const { resolve, module } = snapshot;
-	assert(!isNil(resolve) && !isNil(module));
+	const { resolve, module, immutablePaths, managedPaths } = snapshot;
+	assert(
+		!isNil(resolve) &&
+			!isNil(module) &&
+			!isNil(immutablePaths) &&
+			!isNil(managedPaths)
+	);
 	const { timestamp: resolveTimestamp, hash: resolveHash } = resolve;
 	const { timestamp: moduleTimestamp, hash: moduleHash } = module;
 	assert(
@@ -732,7 +905,22 @@ function getRawSnapshotOptions(
 		module: {
 			timestamp: moduleTimestamp,
 			hash: moduleHash
-		}
+		},
+		immutablePaths: immutablePaths.map(getRawSnapshotPath),
+		managedPaths: managedPaths.map(getRawSnapshotPath)
+	};
+}
+
+function getRawSnapshotPath(path: string | RegExp): RawSnapshotPath {
+	if (typeof path === "string") {
+		return {
+			type: "string",
+			stringMatcher: path
+		};
+	}
+	return {
+		type: "regexp",
+		regexpMatcher: path.source
 	};
 }
 
@@ -744,14 +932,16 @@ function getRawExperiments(
 		incrementalRebuild,
 		asyncWebAssembly,
 		newSplitChunks,
-		css
+		css,
+		topLevelAwait
 	} = experiments;
 	assert(
 		!isNil(lazyCompilation) &&
 			!isNil(incrementalRebuild) &&
 			!isNil(asyncWebAssembly) &&
 			!isNil(newSplitChunks) &&
-			!isNil(css)
+			!isNil(css) &&
+			!isNil(topLevelAwait)
 	);
 
 	return {
@@ -759,7 +949,8 @@ function getRawExperiments(
 		incrementalRebuild: getRawIncrementalRebuild(incrementalRebuild),
 		asyncWebAssembly,
 		newSplitChunks,
-		css
+		css,
+		topLevelAwait
 	};
 }
 
@@ -794,9 +985,25 @@ function getRawNode(node: Node): RawOptions["node"] {
 	};
 }
 
+function getRawInfrastructureLogging(
+	infrastructureLogging: InfrastructureLogging
+): RawOptions["infrastructureLogging"] {
+	const { level, debug } = infrastructureLogging;
+	return {
+		level: level ?? "info",
+		// The loggers are filtered by name on the JS side
+		debug: Array.isArray(debug) ? debug.length > 0 : !!debug
+	};
+}
+
 function getRawStats(stats: StatsValue): RawOptions["stats"] {
 	const statsOptions = normalizeStatsPreset(stats);
 	return {
-		colors: statsOptions.colors ?? false
+		colors: statsOptions.colors ?? false,
+		similarDiagnosticsThreshold:
+			statsOptions.similarDiagnosticsThreshold === false
+				? undefined
+				: statsOptions.similarDiagnosticsThreshold ?? 5,
+		moduleTraceDepth: statsOptions.moduleTraceDepth ?? 10
 	};
 }
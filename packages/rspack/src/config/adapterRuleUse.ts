@@ -53,6 +53,8 @@ export interface LoaderObject {
 	pitch: Function | undefined;
 	raw: boolean | undefined;
 	data: object | undefined;
+	/** Where the loader comes from, `undefined` if unknown */
+	origin: "pre" | "normal" | "post" | "inline" | undefined;
 	pitchExecuted: boolean;
 	normalExecuted: boolean;
 }
@@ -131,6 +133,7 @@ export interface LoaderContext<OptionsType = {}> {
 	dependency(file: string): void;
 	addContextDependency(context: string): void;
 	addMissingDependency(missing: string): void;
+	addGlobDependency(glob: string): void;
 	clearDependencies(): void;
 	getDependencies(): string[];
 	getContextDependencies(): string[];
@@ -144,6 +147,22 @@ export interface LoaderContext<OptionsType = {}> {
 	};
 	query: string | OptionsType;
 	data: unknown;
+	/**
+	 * Settings attached to the module by `normalModuleFactory.hooks.createModule`
+	 */
+	moduleSettings: Record<string, any>;
+	/**
+	 * Set a value in the build info of the module, which is readable by plugins as `module.buildInfo`.
+	 * The value is included in the module hash only with `affectsHash: true`.
+	 */
+	setBuildInfo(
+		key: string,
+		value: any,
+		options?: { affectsHash?: boolean }
+	): void;
+	_module: {
+		buildInfo: Record<string, any>;
+	};
 	_compiler: Compiler;
 	_compilation: Compiler["compilation"];
 	/**
@@ -243,6 +262,8 @@ function createRawModuleRuleUsesImpl(
 				);
 			}
 			o = isNil(o) ? undefined : typeof o === "string" ? o : JSON.stringify(o);
+		} else {
+			o = serializeLoaderOptions(use, path, index, options.compiler);
 		}
 
 		return {
@@ -256,6 +277,33 @@ function createRawModuleRuleUsesImpl(
 	});
 }
 
+/**
+ * The options of JS loaders are always JSON serialized, string options included,
+ * so that the loader runner parses them back without guessing their type.
+ */
+function serializeLoaderOptions(
+	use: RuleSetLoaderWithOptions,
+	path: string,
+	index: number,
+	compiler: Compiler
+) {
+	if (isNil(use.options)) return undefined;
+	try {
+		return JSON.stringify(use.options);
+	} catch (err) {
+		// Options which can't be serialized are only available via `compiler.ruleSet.references`
+		compiler
+			.getInfrastructureLogger("rspack.RuleSetCompiler")
+			.warn(
+				`Failed to serialize the options of ${use.loader} at ${path}[${index}], ` +
+					`they are only available in the compiler they are defined in: ${
+						(err as Error).message
+					}`
+			);
+		return undefined;
+	}
+}
+
 function resolveStringifyLoaders(
 	use: RuleSetLoaderWithOptions,
 	path: string,
@@ -3,6 +3,7 @@ import * as path from "path";
 import type {
 	RawBuiltins,
 	RawBannerConfig,
+	RawBannerContent,
 	RawHtmlPluginConfig,
 	RawDecoratorOptions,
 	RawMinification,
@@ -18,7 +19,9 @@ import type {
 	RawBannerConditions,
 	RawBannerCondition,
 	RawMinificationCondition,
-	RawMinificationConditions
+	RawMinificationConditions,
+	RawManifestConfig,
+	RawContainerReferenceConfig
 } from "@rspack/binding";
 import { loadConfig } from "browserslist";
 import { Optimization } from "..";
@@ -60,11 +63,17 @@ export type MinificationConfig = {
 	include?: MinifyConditions;
 };
 
+export type ProgressPluginConfig = {
+	prefix?: string;
+	profile?: boolean;
+	handler?: (percentage: number, msg: string, ...items: string[]) => void;
+};
+
 export interface Builtins {
 	css?: CssPluginConfig;
 	postcss?: any;
 	treeShaking?: boolean | "module";
-	progress?: boolean | RawProgressPluginConfig;
+	progress?: boolean | ProgressPluginConfig;
 	react?: RawReactOptions;
 	noEmitAssets?: boolean;
 	define?: Record<string, string | boolean | undefined>;
@@ -81,8 +90,34 @@ export interface Builtins {
 	pluginImport?: PluginImportConfig[];
 	relay?: RelayConfig;
 	codeGeneration?: Partial<RawCodeGeneration>;
+	manifest?: boolean | ManifestConfig;
+	containerReference?: ContainerReferenceConfig;
 }
 
+export type ManifestConfig = {
+	filename?: string;
+};
+
+export type RemotesConfig = {
+	/**
+	 * The container of the remote, i.e. `app2@https://cdn/remoteEntry.js`
+	 */
+	external: string;
+	shareScope?: string;
+};
+
+export type ContainerReferenceConfig = {
+	/**
+	 * The external type of the remote containers, defaults to `script`
+	 */
+	remoteType?: string;
+	/**
+	 * The remote containers, the requests prefixed by a key are loaded from the container
+	 */
+	remotes: Record<string, string | RemotesConfig>;
+	shareScope?: string;
+};
+
 export type PluginImportConfig = {
 	libraryName: string;
 	libraryDirectory?: string;
@@ -96,12 +131,18 @@ export type PluginImportConfig = {
 	ignoreStyleComponent?: Array<string>;
 };
 
+export type CopyToFunction = (pathData: {
+	context: string;
+	absoluteFilename: string;
+}) => string;
+
 export type CopyConfig = {
 	patterns: (
 		| string
 		| ({
 				from: string;
-		  } & Partial<RawPattern>)
+				to?: string | CopyToFunction;
+		  } & Partial<Omit<RawPattern, "to" | "toFn">>)
 	)[];
 };
 
@@ -113,10 +154,22 @@ export type MinifyCondition = string | RegExp;
 
 export type MinifyConditions = MinifyCondition | MinifyCondition[];
 
+export type BannerFunction = (args: {
+	hash: string;
+	chunk: {
+		id?: string;
+		name?: string;
+		hash?: string;
+	};
+	filename: string;
+}) => string;
+
+export type BannerContent = string | BannerFunction;
+
 type BannerConfig =
 	| string
 	| {
-			banner: string;
+			banner: BannerContent;
 			entryOnly?: boolean;
 			footer?: boolean;
 			raw?: boolean;
@@ -261,7 +314,13 @@ function resolveProgress(
 		progress = {};
 	}
 
-	return progress;
+	const { handler, ...rest } = progress;
+	return {
+		...rest,
+		handler: handler
+			? ({ percentage, msg, items }) => handler(percentage, msg, ...items)
+			: undefined
+	};
 }
 
 function resolveEmotion(
@@ -305,12 +364,17 @@ function resolveCopy(copy?: Builtins["copy"]): RawCopyConfig | undefined {
 			pattern = { from: pattern };
 		}
 
-		pattern.force ??= false;
-		pattern.noErrorOnMissing ??= false;
-		pattern.priority ??= 0;
-		pattern.globOptions ??= {};
+		const { to, ...rest } = pattern;
+		const rawPattern = (
+			typeof to === "function" ? { ...rest, toFn: to } : pattern
+		) as RawPattern;
 
-		return pattern as RawPattern;
+		rawPattern.force ??= false;
+		rawPattern.noErrorOnMissing ??= false;
+		rawPattern.priority ??= 0;
+		rawPattern.globOptions ??= {};
+
+		return rawPattern;
 	});
 
 	return ret;
@@ -415,19 +479,65 @@ export function resolveBuiltinsOptions(
 		relay: builtins.relay
 			? resolveRelay(builtins.relay, contextPath)
 			: undefined,
-		codeGeneration: resolveCodeGeneration(builtins)
+		codeGeneration: resolveCodeGeneration(builtins),
+		manifest: resolveManifest(builtins.manifest),
+		containerReference: resolveContainerReference(builtins.containerReference)
+	};
+}
+
+function resolveContainerReference(
+	containerReference: Builtins["containerReference"]
+): RawContainerReferenceConfig | undefined {
+	if (!containerReference) {
+		return undefined;
+	}
+	const shareScope = containerReference.shareScope ?? "default";
+	return {
+		remoteType: containerReference.remoteType ?? "script",
+		remotes: Object.entries(containerReference.remotes).map(([key, remote]) =>
+			typeof remote === "string"
+				? { key, external: remote, shareScope }
+				: {
+						key,
+						external: remote.external,
+						shareScope: remote.shareScope ?? shareScope
+				  }
+		)
+	};
+}
+
+function resolveManifest(
+	manifest: Builtins["manifest"]
+): RawManifestConfig | undefined {
+	if (!manifest) {
+		return undefined;
+	}
+	return manifest === true ? {} : manifest;
+}
+
+function getBannerContent(content: BannerContent): RawBannerContent {
+	if (typeof content === "function") {
+		return {
+			type: "function",
+			fnPayload: content
+		};
+	}
+	return {
+		type: "string",
+		stringPayload: content
 	};
 }
 
 function resolveBannerConfig(bannerConfig: BannerConfig): RawBannerConfig {
 	if (typeof bannerConfig === "string") {
 		return {
-			banner: bannerConfig
+			banner: getBannerContent(bannerConfig)
 		};
 	}
 
 	return {
 		...bannerConfig,
+		banner: getBannerContent(bannerConfig.banner),
 		test: getBannerConditions(bannerConfig.test),
 		include: getBannerConditions(bannerConfig.include),
 		exclude: getBannerConditions(bannerConfig.exclude)
@@ -516,9 +626,11 @@ export function resolveMinifyOptions(
 	builtins: Builtins,
 	optimization: Optimization
 ): RawMinification | undefined {
+	// The builtin minimizer is kept by "...", and it skips the assets minimized by the others
 	const disable_minify =
 		!optimization.minimize ||
-		optimization.minimizer?.some(item => item !== "...");
+		(!!optimization.minimizer?.length &&
+			!optimization.minimizer.includes("..."));
 
 	if (disable_minify) {
 		return undefined;
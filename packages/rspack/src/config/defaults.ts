@@ -164,6 +164,7 @@ const applyExperimentsDefaults = (
 	D(experiments, "asyncWebAssembly", false);
 	D(experiments, "newSplitChunks", true);
 	D(experiments, "css", true); // we not align with webpack about the default value for better DX
+	D(experiments, "topLevelAwait", true);
 
 	if (typeof experiments.incrementalRebuild === "object") {
 		D(experiments.incrementalRebuild, "make", true);
@@ -184,6 +185,8 @@ const applySnapshotDefaults = (
 	snapshot: SnapshotOptions,
 	{ production }: { production: boolean }
 ) => {
+	A(snapshot, "immutablePaths", () => []);
+	A(snapshot, "managedPaths", () => []);
 	F(snapshot, "module", () =>
 		production
 			? { timestamp: true, hash: true }
@@ -385,6 +388,7 @@ const applyOutputDefaults = (
 	});
 
 	F(output, "chunkLoadingGlobal", () => "webpackChunk" + output.uniqueName);
+	F(output, "hotUpdateGlobal", () => "webpackHotUpdate" + output.uniqueName);
 	F(output, "module", () => !!outputModule);
 	D(output, "filename", output.module ? "[name].mjs" : "[name].js");
 	F(output, "iife", () => !output.module);
@@ -541,12 +545,24 @@ const applyOutputDefaults = (
 		return "self";
 	});
 	D(output, "importFunctionName", "import");
+	D(output, "importMetaName", "import.meta");
 	F(output, "clean", () => !!output.clean);
 	D(output, "crossOriginLoading", false);
 	D(output, "workerPublicPath", "");
+	const environment = output.environment!;
+	// Unknown features of the target are assumed to be supported,
+	// ESM output implies an ES2015+ environment.
+	const optimistic = (v?: boolean) => v || v === undefined;
+	F(environment, "arrowFunction", () =>
+		Boolean(output.module || (tp && optimistic(tp.arrowFunction)))
+	);
+	F(environment, "const", () =>
+		Boolean(output.module || (tp && optimistic(tp.const)))
+	);
 	F(output, "sourceMapFilename", () => {
 		return "[file].map";
 	});
+	D(output, "devtoolIgnoreList", /[\\/]node_modules[\\/]/);
 
 	const { trustedTypes } = output;
 	if (trustedTypes) {
@@ -559,6 +575,10 @@ const applyOutputDefaults = (
 	}
 
 	const forEachEntry = (fn: (desc: EntryDescriptionNormalized) => void) => {
+		// the descriptions of a dynamic entry are not known until the make stage
+		if (typeof entry === "function") {
+			return;
+		}
 		for (const name of Object.keys(entry)) {
 			fn(entry[name]);
 		}
@@ -663,14 +683,21 @@ const applyOptimizationDefaults = (
 ) => {
 	D(optimization, "removeAvailableModules", true);
 	D(optimization, "removeEmptyChunks", true);
-	F(optimization, "moduleIds", (): "named" | "deterministic" => {
+	F(optimization, "moduleIds", (): "named" | "natural" | "deterministic" => {
 		if (production) return "deterministic";
 		return "named";
 	});
-	F(optimization, "chunkIds", (): "named" | "deterministic" => "named");
+	F(
+		optimization,
+		"chunkIds",
+		(): "named" | "natural" | "deterministic" => "named"
+	);
 	F(optimization, "sideEffects", () => (production ? true : "flag"));
 	D(optimization, "runtimeChunk", false);
 	D(optimization, "realContentHash", production);
+	// TODO: enable in production once the concatenation covers more syntax
+	D(optimization, "concatenateModules", false);
+	D(optimization, "mangleExports", production);
 	D(optimization, "minimize", production);
 	A(optimization, "minimizer", () => []);
 	const { splitChunks } = optimization;
@@ -774,7 +801,7 @@ const getResolveDefaults = ({
 				preferRelative: true
 			},
 			commonjs: cjsDeps(),
-			// amd: cjsDeps(),
+			amd: cjsDeps(),
 			// for backward-compat: loadModule
 			// loader: cjsDeps(),
 			// for backward-compat: Custom Dependency and getResolve without dependencyType
@@ -9,6 +9,7 @@
  */
 
 import type {
+	EntryDynamic,
 	EntryStatic,
 	EntryStaticNormalized,
 	LibraryOptions,
@@ -25,15 +26,9 @@ export const getNormalizedRspackOptions = (
 	return {
 		ignoreWarnings:
 			config.ignoreWarnings !== undefined
-				? config.ignoreWarnings.map(ignore => {
-						if (typeof ignore === "function") {
-							return ignore as (...args: any[]) => boolean;
-						} else {
-							return warning => {
-								return ignore.test(warning.message);
-							};
-						}
-				  })
+				? config.ignoreWarnings.map(ignore =>
+						ignore instanceof RegExp ? { message: ignore } : ignore
+				  )
 				: undefined,
 		name: config.name,
 		dependencies: config.dependencies,
@@ -42,6 +37,11 @@ export const getNormalizedRspackOptions = (
 		entry:
 			config.entry === undefined
 				? { main: {} }
+				: typeof config.entry === "function"
+				? () =>
+						Promise.resolve()
+							.then(config.entry as EntryDynamic)
+							.then(getNormalizedEntryStatic)
 				: getNormalizedEntryStatic(config.entry),
 		output: nestedConfig(config.output, output => {
 			const { library } = output;
@@ -108,14 +108,17 @@ export const getNormalizedRspackOptions = (
 				webassemblyModuleFilename: output.webassemblyModuleFilename,
 				uniqueName: output.uniqueName,
 				chunkLoadingGlobal: output.chunkLoadingGlobal,
+				hotUpdateGlobal: output.hotUpdateGlobal,
 				enabledLibraryTypes: output.enabledLibraryTypes
 					? [...output.enabledLibraryTypes]
 					: ["..."],
 				globalObject: output.globalObject,
 				importFunctionName: output.importFunctionName,
+				importMetaName: output.importMetaName,
 				iife: output.iife,
 				module: output.module,
 				sourceMapFilename: output.sourceMapFilename,
+				devtoolIgnoreList: output.devtoolIgnoreList,
 				library: libraryBase && {
 					type:
 						output.libraryTarget !== undefined
@@ -151,7 +154,8 @@ export const getNormalizedRspackOptions = (
 				asyncChunks: output.asyncChunks,
 				workerChunkLoading: output.workerChunkLoading,
 				workerWasmLoading: output.workerWasmLoading,
-				workerPublicPath: output.workerPublicPath
+				workerPublicPath: output.workerPublicPath,
+				environment: { ...output.environment }
 			};
 		}),
 		resolve: nestedConfig(config.resolve, resolve => ({
@@ -187,7 +191,10 @@ export const getNormalizedRspackOptions = (
 					...node
 				}
 		),
+		amd: config.amd,
 		snapshot: nestedConfig(config.snapshot, snapshot => ({
+			immutablePaths: optionalNestedArray(snapshot.immutablePaths, p => [...p]),
+			managedPaths: optionalNestedArray(snapshot.managedPaths, p => [...p]),
 			resolve: optionalNestedConfig(snapshot.resolve, resolve => ({
 				timestamp: resolve.timestamp,
 				hash: resolve.hash
@@ -282,6 +289,12 @@ const getNormalizedEntryStatic = (entry: EntryStatic) => {
 			result[key] = {
 				import: Array.isArray(value.import) ? value.import : [value.import],
 				runtime: value.runtime,
+				dependOn:
+					value.dependOn === undefined
+						? undefined
+						: Array.isArray(value.dependOn)
+						? value.dependOn
+						: [value.dependOn],
 				publicPath: value.publicPath,
 				baseUri: value.baseUri,
 				chunkLoading: value.chunkLoading,
@@ -226,7 +226,7 @@ module.exports = {
 				"A developer tool to enhance debugging (false | eval | [inline-|hidden-|eval-][nosources-][cheap-[module-]]source-map).",
 			anyOf: [
 				{
-					enum: [false]
+					enum: [false, "eval"]
 				},
 				{
 					type: "string",
@@ -399,6 +399,10 @@ module.exports = {
 				css: {
 					description: "Enable native css support.",
 					type: "boolean"
+				},
+				topLevelAwait: {
+					description: "Allow using top-level-await in EcmaScript Modules.",
+					type: "boolean"
 				}
 			}
 		},
@@ -606,6 +610,11 @@ module.exports = {
 				"The name of the native import() function (can be exchanged for a polyfill).",
 			type: "string"
 		},
+		ImportMetaName: {
+			description:
+				"The name of the native import.meta object (can be exchanged for a polyfill).",
+			type: "string"
+		},
 		InfrastructureLogging: {
 			description: "Options for infrastructure level logging.",
 			type: "object",
@@ -827,6 +836,21 @@ module.exports = {
 					{
 						instanceof: "RegExp"
 					},
+					{
+						type: "object",
+						additionalProperties: false,
+						properties: {
+							message: {
+								description: "A RegExp to select the warning message.",
+								instanceof: "RegExp"
+							},
+							module: {
+								description:
+									"A RegExp to select the origin module for the warning.",
+								instanceof: "RegExp"
+							}
+						}
+					},
 					{
 						instanceof: "Function"
 					}
@@ -860,6 +884,17 @@ module.exports = {
 				}
 			}
 		},
+		Amd: {
+			description: "Parse the AMD `define()` calls, or disable it with `false`.",
+			anyOf: [
+				{
+					enum: [false]
+				},
+				{
+					type: "object"
+				}
+			]
+		},
 		Name: {
 			description:
 				"Name of the configuration. Used when loading multiple configurations.",
@@ -903,7 +938,7 @@ module.exports = {
 				chunkIds: {
 					description:
 						"Define the algorithm to choose chunk ids (named: readable ids for better debugging, deterministic: numeric hash ids for better long term caching, size: numeric ids focused on minimal initial download size, total-size: numeric ids focused on minimal total download size, false: no algorithm used, as custom one can be provided via plugin).",
-					enum: ["named", "deterministic"]
+					enum: ["named", "natural", "deterministic"]
 				},
 				minimize: {
 					description:
@@ -931,7 +966,7 @@ module.exports = {
 				moduleIds: {
 					description:
 						"Define the algorithm to choose module ids (natural: numeric ids in order of usage, named: readable ids for better debugging, hashed: (deprecated) short hashes as ids for better long term caching, deterministic: numeric hash ids for better long term caching, size: numeric ids focused on minimal initial download size, false: no algorithm used, as custom one can be provided via plugin).",
-					enum: ["named", "deterministic"]
+					enum: ["named", "natural", "deterministic"]
 				},
 				removeAvailableModules: {
 					description:
@@ -973,6 +1008,23 @@ module.exports = {
 					description:
 						"Use real [contenthash] based on final content of the assets.",
 					type: "boolean"
+				},
+				concatenateModules: {
+					description:
+						"Concatenate modules when possible to generate less modules, more efficient code and enable more optimizations by the minimizer.",
+					type: "boolean"
+				},
+				mangleExports: {
+					description:
+						"Rename exports when possible to generate shorter code (depends on optimization.usedExports and optimization.providedExports, true/\"deterministic\": generate short deterministic names optimized for caching, \"size\": generate the shortest possible names).",
+					anyOf: [
+						{
+							enum: ["size", "deterministic"]
+						},
+						{
+							type: "boolean"
+						}
+					]
 				}
 			}
 		},
@@ -1214,7 +1266,33 @@ module.exports = {
 		},
 		Clean: {
 			description: "Clears the output build directory",
-			type: "boolean"
+			anyOf: [
+				{
+					type: "boolean"
+				},
+				{
+					$ref: "#/definitions/CleanOptions"
+				}
+			]
+		},
+		CleanOptions: {
+			description: "Advanced options for cleaning assets.",
+			type: "object",
+			additionalProperties: false,
+			properties: {
+				keep: {
+					description: "Keep these assets.",
+					anyOf: [
+						{
+							instanceof: "RegExp"
+						},
+						{
+							type: "string",
+							absolutePath: false
+						}
+					]
+				}
+			}
 		},
 		Output: {
 			description:
@@ -1286,6 +1364,9 @@ module.exports = {
 				importFunctionName: {
 					$ref: "#/definitions/ImportFunctionName"
 				},
+				importMetaName: {
+					$ref: "#/definitions/ImportMetaName"
+				},
 				library: {
 					$ref: "#/definitions/Library"
 				},
@@ -1328,6 +1409,9 @@ module.exports = {
 				chunkLoadingGlobal: {
 					$ref: "#/definitions/ChunkLoadingGlobal"
 				},
+				hotUpdateGlobal: {
+					$ref: "#/definitions/HotUpdateGlobal"
+				},
 				trustedTypes: {
 					description:
 						"Use a Trusted Types policy to create urls for chunks. 'output.uniqueName' is used a default policy name. Passing a string sets a custom policy name.",
@@ -1349,6 +1433,9 @@ module.exports = {
 				sourceMapFilename: {
 					$ref: "#/definitions/SourceMapFilename"
 				},
+				devtoolIgnoreList: {
+					$ref: "#/definitions/DevtoolIgnoreList"
+				},
 				hashDigest: {
 					$ref: "#/definitions/HashDigest"
 				},
@@ -1593,6 +1680,44 @@ module.exports = {
 					description: "Path to tsconfig.json",
 					type: "string"
 				},
+				tsconfig: {
+					description:
+						"The tsconfig whose `paths` and `baseUrl` are used to map the requests.",
+					anyOf: [
+						{
+							description: "Path to tsconfig.json or the directory of it.",
+							type: "string"
+						},
+						{
+							type: "object",
+							additionalProperties: false,
+							properties: {
+								configFile: {
+									description: "Path to tsconfig.json or the directory of it.",
+									type: "string"
+								},
+								references: {
+									description:
+										'Map the paths of the referenced projects, "auto" uses the references field of the tsconfig.',
+									anyOf: [
+										{
+											enum: ["auto"]
+										},
+										{
+											type: "array",
+											items: {
+												description:
+													"Path to tsconfig.json of a referenced project or the directory of it.",
+												type: "string"
+											}
+										}
+									]
+								}
+							},
+							required: ["configFile"]
+						}
+					]
+				},
 				exportsFields: {
 					description:
 						"Fields in the description file (usually package.json) which are used to redirect requests inside the module.",
@@ -1941,6 +2066,44 @@ module.exports = {
 			type: "object",
 			additionalProperties: false,
 			properties: {
+				immutablePaths: {
+					description:
+						"List of paths that are managed by a package manager and contain a version or a hash in their paths, which are never checked.",
+					type: "array",
+					items: {
+						description:
+							"List of paths that are managed by a package manager and contain a version or a hash in their paths.",
+						anyOf: [
+							{
+								instanceof: "RegExp"
+							},
+							{
+								type: "string",
+								absolutePath: true,
+								minLength: 1
+							}
+						]
+					}
+				},
+				managedPaths: {
+					description:
+						"List of paths that are managed by a package manager, the versions of the packages in them are checked instead of the files.",
+					type: "array",
+					items: {
+						description:
+							"List of paths that are managed by a package manager.",
+						anyOf: [
+							{
+								instanceof: "RegExp"
+							},
+							{
+								type: "string",
+								absolutePath: true,
+								minLength: 1
+							}
+						]
+					}
+				},
 				module: {
 					description:
 						"Options for snapshotting dependencies of modules to determine if they need to be built again.",
@@ -2075,10 +2238,20 @@ module.exports = {
 						"Add information about parent, children and sibling chunks to chunk information.",
 					type: "boolean"
 				},
+				chunkExplanations: {
+					description:
+						"Add the reason why a chunk is created, including the splitChunks decisions, to chunk information.",
+					type: "boolean"
+				},
 				timings: {
 					description: "Add timing information.",
 					type: "boolean"
 				},
+				profile: {
+					description:
+						"Add the slowest modules and loaders to build, which requires `profile: true`.",
+					type: "boolean"
+				},
 				builtAt: {
 					description: "Add built at time information.",
 					type: "boolean"
@@ -2087,6 +2260,63 @@ module.exports = {
 					description:
 						"Add information about modules nested in other modules (like with module concatenation).",
 					type: "boolean"
+				},
+				optimizationBailout: {
+					description:
+						"Show reasons why optimization bailed out for modules.",
+					type: "boolean"
+				},
+				assetsSort: {
+					description:
+						"Sort the assets by that field in the string output, prefix with `!` to reverse the order.",
+					type: "string"
+				},
+				chunksSort: {
+					description:
+						"Sort the chunks by that field in the string output, prefix with `!` to reverse the order.",
+					type: "string"
+				},
+				modulesSort: {
+					description:
+						"Sort the modules by that field in the string output, prefix with `!` to reverse the order.",
+					type: "string"
+				},
+				assetsSpace: {
+					description:
+						"Space to display assets in the string output (groups will be collapsed to fit this space).",
+					type: "number"
+				},
+				chunksSpace: {
+					description:
+						"Space to display chunks in the string output (groups will be collapsed to fit this space).",
+					type: "number"
+				},
+				similarDiagnosticsThreshold: {
+					description:
+						"Merge the errors and warnings with the same message when they are reported more than this number of times, `false` to never merge them.",
+					anyOf: [
+						{
+							enum: [false]
+						},
+						{
+							type: "number",
+							minimum: 0
+						}
+					]
+				},
+				errorDetails: {
+					description: "Add details to errors (like resolving log).",
+					type: "boolean"
+				},
+				moduleTrace: {
+					description: "Add dependencies and origin of warnings/errors.",
+					type: "boolean"
+				},
+				moduleTraceDepth: {
+					description:
+						"The maximum number of importers listed in the module trace of errors and warnings.",
+					type: "number",
+					minimum: 0
 				}
 			}
 		},
@@ -2160,6 +2390,27 @@ module.exports = {
 			type: "string",
 			minLength: 1
 		},
+		DevtoolIgnoreList: {
+			description:
+				"The sources listed in the 'ignoreList' of the source maps, matched against their original paths.",
+			anyOf: [
+				{
+					enum: [false]
+				},
+				{
+					type: "string"
+				},
+				{
+					instanceof: "RegExp"
+				}
+			]
+		},
+		HotUpdateGlobal: {
+			description:
+				"The global variable used by rspack for loading of hot update chunks.",
+			type: "string",
+			minLength: 1
+		},
 		Watch: {
 			description: "Enter watch mode, which rebuilds on file change.",
 			type: "boolean"
@@ -2265,6 +2516,9 @@ module.exports = {
 		experiments: {
 			$ref: "#/definitions/Experiments"
 		},
+		amd: {
+			$ref: "#/definitions/Amd"
+		},
 		externals: {
 			$ref: "#/definitions/Externals"
 		},
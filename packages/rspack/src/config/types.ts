@@ -45,6 +45,7 @@ export interface RspackOptionsNormalized {
 	infrastructureLogging: InfrastructureLogging;
 	devtool?: DevTool;
 	node: Node;
+	amd?: Amd;
 	snapshot: SnapshotOptions;
 	cache?: CacheOptions;
 	stats: StatsValue;
@@ -72,11 +73,13 @@ export type Context = string;
 export type Mode = "development" | "production" | "none";
 
 ///// Entry /////
-export type Entry = EntryStatic;
+export type Entry = EntryStatic | EntryDynamic;
+export type EntryDynamic = () => EntryStatic | Promise<EntryStatic>;
 export type EntryStatic = EntryObject | EntryUnnamed;
 export type EntryUnnamed = EntryItem;
 export type EntryRuntime = false | string;
 export type EntryItem = string[] | string;
+export type EntryDependOn = string[] | string;
 export type ChunkLoading = false | ChunkLoadingType;
 export type ChunkLoadingType =
 	| ("jsonp" | "import-scripts" | "require" | "async-node" | "import")
@@ -88,6 +91,7 @@ export type EntryFilename = FilenameTemplate;
 export interface EntryDescription {
 	import: EntryItem;
 	runtime?: EntryRuntime;
+	dependOn?: EntryDependOn;
 	chunkLoading?: ChunkLoading;
 	asyncChunks?: boolean;
 	publicPath?: PublicPath;
@@ -95,13 +99,15 @@ export interface EntryDescription {
 	filename?: EntryFilename;
 }
 
-export type EntryNormalized = EntryStaticNormalized;
+export type EntryNormalized = EntryDynamicNormalized | EntryStaticNormalized;
+export type EntryDynamicNormalized = () => Promise<EntryStaticNormalized>;
 export interface EntryStaticNormalized {
 	[k: string]: EntryDescriptionNormalized;
 }
 export interface EntryDescriptionNormalized {
 	import?: string[];
 	runtime?: EntryRuntime;
+	dependOn?: string[];
 	chunkLoading?: ChunkLoading;
 	asyncChunks?: boolean;
 	publicPath?: PublicPath;
@@ -124,6 +130,7 @@ export interface Output {
 	hotUpdateChunkFilename?: HotUpdateChunkFilename;
 	uniqueName?: UniqueName;
 	chunkLoadingGlobal?: ChunkLoadingGlobal;
+	hotUpdateGlobal?: HotUpdateGlobal;
 	enabledLibraryTypes?: EnabledLibraryTypes;
 	libraryExport?: LibraryExport;
 	libraryTarget?: LibraryType;
@@ -134,6 +141,7 @@ export interface Output {
 	strictModuleErrorHandling?: StrictModuleErrorHandling;
 	globalObject?: GlobalObject;
 	importFunctionName?: ImportFunctionName;
+	importMetaName?: ImportMetaName;
 	iife?: Iife;
 	wasmLoading?: WasmLoading;
 	enabledWasmLoadingTypes?: EnabledWasmLoadingTypes;
@@ -143,6 +151,7 @@ export interface Output {
 	enabledChunkLoadingTypes?: string[];
 	trustedTypes?: true | string | TrustedTypes;
 	sourceMapFilename?: SourceMapFilename;
+	devtoolIgnoreList?: DevtoolIgnoreList;
 	hashDigest?: HashDigest;
 	hashDigestLength?: HashDigestLength;
 	hashFunction?: HashFunction;
@@ -151,6 +160,7 @@ export interface Output {
 	workerChunkLoading?: ChunkLoading;
 	workerWasmLoading?: WasmLoading;
 	workerPublicPath?: WorkerPublicPath;
+	environment?: Environment;
 }
 export type Path = string;
 export type PublicPath = "auto" | RawPublicPath;
@@ -167,12 +177,21 @@ export type HotUpdateMainFilename = FilenameTemplate;
 export type FilenameTemplate = string;
 export type UniqueName = string;
 export type ChunkLoadingGlobal = string;
+export type HotUpdateGlobal = string;
 export type Library = LibraryName | LibraryOptions;
 export type StrictModuleErrorHandling = boolean;
 export type OutputModule = boolean;
 export type SourceMapFilename = FilenameTemplate;
+export type DevtoolIgnoreList = false | string | RegExp;
 export type Iife = boolean;
-export type Clean = boolean;
+export interface Environment {
+	arrowFunction?: boolean;
+	const?: boolean;
+}
+export type Clean = boolean | CleanOptions;
+export interface CleanOptions {
+	keep?: string | RegExp;
+}
 export interface LibraryCustomUmdCommentObject {
 	amd?: string;
 	commonjs?: string;
@@ -220,6 +239,7 @@ export type UmdNamedDefine = boolean;
 export type EnabledLibraryTypes = LibraryType[];
 export type GlobalObject = string;
 export type ImportFunctionName = string;
+export type ImportMetaName = string;
 export type WasmLoading = false | WasmLoadingType;
 export type WasmLoadingType =
 	| ("fetch-streaming" | "fetch" | "async-node")
@@ -247,12 +267,14 @@ export interface OutputNormalized {
 	assetModuleFilename?: AssetModuleFilename;
 	uniqueName?: UniqueName;
 	chunkLoadingGlobal?: ChunkLoadingGlobal;
+	hotUpdateGlobal?: HotUpdateGlobal;
 	enabledLibraryTypes?: EnabledLibraryTypes;
 	library?: LibraryOptions;
 	module?: OutputModule;
 	strictModuleErrorHandling?: StrictModuleErrorHandling;
 	globalObject?: GlobalObject;
 	importFunctionName?: ImportFunctionName;
+	importMetaName?: ImportMetaName;
 	iife?: Iife;
 	wasmLoading?: WasmLoading;
 	enabledWasmLoadingTypes?: EnabledWasmLoadingTypes;
@@ -262,6 +284,7 @@ export interface OutputNormalized {
 	enabledChunkLoadingTypes?: string[];
 	trustedTypes?: TrustedTypes;
 	sourceMapFilename?: SourceMapFilename;
+	devtoolIgnoreList?: DevtoolIgnoreList;
 	hashDigest?: HashDigest;
 	hashDigestLength?: HashDigestLength;
 	hashFunction?: HashFunction;
@@ -270,6 +293,7 @@ export interface OutputNormalized {
 	workerChunkLoading?: ChunkLoading;
 	workerWasmLoading?: WasmLoading;
 	workerPublicPath?: WorkerPublicPath;
+	environment?: Environment;
 }
 
 ///// Resolve /////
@@ -289,6 +313,7 @@ export interface ResolveOptions {
 	modules?: string[];
 	preferRelative?: boolean;
 	tsConfigPath?: string;
+	tsconfig?: ResolveTsconfig;
 	fullySpecified?: boolean;
 	exportsFields?: string[];
 	extensionAlias?: Record<string, string | string[]>;
@@ -296,6 +321,12 @@ export interface ResolveOptions {
 		[k: string]: ResolveOptions;
 	};
 }
+export type ResolveTsconfig =
+	| string
+	| {
+			configFile: string;
+			references?: "auto" | string[];
+	  };
 export type ResolveAlias = {
 	[k: string]: false | string | Array<string | false>;
 };
@@ -391,11 +422,17 @@ export type AssetGeneratorOptions = AssetInlineGeneratorOptions &
 export interface AssetInlineGeneratorOptions {
 	dataUrl?: AssetGeneratorDataUrl;
 }
-export type AssetGeneratorDataUrl = AssetGeneratorDataUrlOptions;
+export type AssetGeneratorDataUrl =
+	| AssetGeneratorDataUrlOptions
+	| AssetGeneratorDataUrlFunction;
 export interface AssetGeneratorDataUrlOptions {
 	encoding?: false | "base64";
 	mimetype?: string;
 }
+export type AssetGeneratorDataUrlFunction = (
+	content: Buffer,
+	context: { filename: string }
+) => string;
 export interface AssetResourceGeneratorOptions {
 	filename?: FilenameTemplate;
 	publicPath?: RawPublicPath;
@@ -534,6 +571,7 @@ export type FilterItemTypes = RegExp | string | ((value: string) => boolean);
 ///// DevTool /////
 export type DevTool =
 	| false
+	| "eval"
 	| "cheap-source-map"
 	| "cheap-module-source-map"
 	| "source-map"
@@ -567,8 +605,13 @@ export interface NodeOptions {
 	global?: boolean | "warn";
 }
 
+///// Amd /////
+export type Amd = false | { [k: string]: any };
+
 ///// Snapshot /////
 export interface SnapshotOptions {
+	immutablePaths?: (string | RegExp)[];
+	managedPaths?: (string | RegExp)[];
 	module?: {
 		hash?: boolean;
 		timestamp?: boolean;
@@ -608,14 +651,26 @@ export interface StatsOptions {
 	outputPath?: boolean;
 	chunkModules?: boolean;
 	chunkRelations?: boolean;
+	chunkExplanations?: boolean;
 	timings?: boolean;
+	profile?: boolean;
 	builtAt?: boolean;
 	moduleAssets?: boolean;
 	nestedModules?: boolean;
 	source?: boolean;
+	optimizationBailout?: boolean;
 	logging?: ("none" | "error" | "warn" | "info" | "log" | "verbose") | boolean;
 	loggingDebug?: boolean | FilterTypes;
 	loggingTrace?: boolean;
+	assetsSort?: string;
+	chunksSort?: string;
+	modulesSort?: string;
+	assetsSpace?: number;
+	chunksSpace?: number;
+	similarDiagnosticsThreshold?: number | false;
+	errorDetails?: boolean;
+	moduleTrace?: boolean;
+	moduleTraceDepth?: number;
 }
 
 export type OptimizationRuntimeChunk =
@@ -646,6 +701,7 @@ export interface Experiments {
 	outputModule?: boolean;
 	newSplitChunks?: boolean;
 	css?: boolean;
+	topLevelAwait?: boolean;
 }
 export interface IncrementalRebuildOptions {
 	make?: boolean;
@@ -658,6 +714,7 @@ export interface ExperimentsNormalized {
 	outputModule?: boolean;
 	newSplitChunks?: boolean;
 	css?: boolean;
+	topLevelAwait?: boolean;
 	futureDefaults?: boolean;
 }
 
@@ -675,12 +732,19 @@ export interface DevServer extends webpackDevServer.Configuration {
 ///// IgnoreWarnings /////
 export type IgnoreWarningsPattern = (
 	| RegExp
+	| IgnoreWarningsObject
+	| ((warning: Error, compilation: Compilation) => boolean)
+)[];
+export interface IgnoreWarningsObject {
+	/** A RegExp to select the warning message */
+	message?: RegExp;
+	/** A RegExp to select the origin module for the warning */
+	module?: RegExp;
+}
+export type IgnoreWarningsNormalized = (
+	| IgnoreWarningsObject
 	| ((warning: Error, compilation: Compilation) => boolean)
 )[];
-export type IgnoreWarningsNormalized = ((
-	warning: Error,
-	compilation: Compilation
-) => boolean)[];
 
 ///// Profile /////
 export type Profile = boolean;
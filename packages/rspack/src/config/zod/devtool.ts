@@ -5,6 +5,7 @@ export function devtool() {
 		.literal(false)
 		.or(
 			z.enum([
+				"eval",
 				"cheap-source-map",
 				"cheap-module-source-map",
 				"source-map",
@@ -11,6 +11,11 @@ const entryDescription = z
 	.object({
 		import: entryItem,
 		runtime: z.literal(false).or(z.string().min(1)).optional(),
+		dependOn: z
+			.string()
+			.min(1)
+			.or(z.string().min(1).array().min(1))
+			.optional(),
 		publicPath: publicPath().optional(),
 		baseUri: z.string().optional(),
 		chunkLoading: z
@@ -32,6 +37,12 @@ const entryDescription = z
 
 const entryObject = z.record(entryItem.or(entryDescription));
 
+const entryStatic = entryItem.or(entryObject);
+
+const entryDynamic = z
+	.function()
+	.returns(entryStatic.or(z.promise(entryStatic)));
+
 export function entry() {
-	return entryItem.or(entryObject);
+	return entryStatic.or(entryDynamic);
 }
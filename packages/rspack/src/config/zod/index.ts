@@ -33,7 +33,20 @@ export function configSchema() {
 			dependencies: z.string().array().optional(),
 			devtool: devtool().optional(),
 			node: node().optional(),
-			ignoreWarnings: z.instanceof(RegExp).or(z.function()).array().optional(),
+			amd: z.literal(false).or(z.record(z.any())).optional(),
+			ignoreWarnings: z
+				.instanceof(RegExp)
+				.or(
+					z
+						.object({
+							message: z.instanceof(RegExp).optional(),
+							module: z.instanceof(RegExp).optional()
+						})
+						.strict()
+				)
+				.or(z.function())
+				.array()
+				.optional(),
 			watchOptions: watchOptions().optional(),
 			watch: z.boolean().optional(),
 			stats: stats().optional(),
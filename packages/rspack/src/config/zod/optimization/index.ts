@@ -8,8 +8,8 @@ const rspackPluginInstance = z.object({
 
 export function optimization() {
 	return z.strictObject({
-		moduleIds: z.enum(["named", "deterministic"]).optional(),
-		chunkIds: z.enum(["named", "deterministic"]).optional(),
+		moduleIds: z.enum(["named", "natural", "deterministic"]).optional(),
+		chunkIds: z.enum(["named", "natural", "deterministic"]).optional(),
 		minimize: z.boolean().optional(),
 		minimizer: z.literal("...").or(rspackPluginInstance).array().optional(),
 		splitChunks: splitChunks().optional(),
@@ -28,6 +28,8 @@ export function optimization() {
 		removeAvailableModules: z.boolean().optional(),
 		removeEmptyChunks: z.boolean().optional(),
 		realContentHash: z.boolean().optional(),
+		concatenateModules: z.boolean().optional(),
+		mangleExports: z.enum(["size", "deterministic"]).or(z.boolean()).optional(),
 		sideEffects: z.enum(["flag"]).or(z.boolean()).optional()
 	});
 }
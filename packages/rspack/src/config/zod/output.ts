@@ -87,7 +87,14 @@ const libraryOptions = z.strictObject({
 export function output() {
 	return z.strictObject({
 		iife: z.boolean().optional(),
-		clean: z.boolean().optional(),
+		clean: z
+			.boolean()
+			.or(
+				z.strictObject({
+					keep: z.string().or(z.instanceof(RegExp)).optional()
+				})
+			)
+			.optional(),
 		assetModuleFilename: z.string().optional(),
 		auxiliaryComment: auxiliaryComment.optional(),
 		chunkFormat: z
@@ -105,7 +112,13 @@ export function output() {
 		hashSalt: z.string().optional(),
 		filename: filename().optional(),
 		sourceMapFilename: z.string().optional(),
+		devtoolIgnoreList: z
+			.literal(false)
+			.or(z.string())
+			.or(z.instanceof(RegExp))
+			.optional(),
 		importFunctionName: z.string().optional(),
+		importMetaName: z.string().optional(),
 		publicPath: publicPath().optional(),
 		uniqueName: z.string().optional(),
 		path: z.string().optional(),
@@ -125,6 +138,7 @@ export function output() {
 		strictModuleErrorHandling: z.boolean().optional(),
 		umdNamedDefine: umdNamedDefine.optional(),
 		chunkLoadingGlobal: z.string().optional(),
+		hotUpdateGlobal: z.string().optional(),
 		trustedTypes: z
 			.literal(true)
 			.or(z.string())
@@ -140,6 +154,12 @@ export function output() {
 		asyncChunks: z.boolean().optional(),
 		workerChunkLoading: chunkLoading().optional(),
 		workerWasmLoading: wasmLoading().optional(),
-		workerPublicPath: workerPublicPath().optional()
+		workerPublicPath: workerPublicPath().optional(),
+		environment: z
+			.strictObject({
+				arrowFunction: z.boolean().optional(),
+				const: z.boolean().optional()
+			})
+			.optional()
 	});
 }
@@ -18,6 +18,15 @@ const baseResolve = z.strictObject({
 	modules: z.string().array().optional(),
 	preferRelative: z.boolean().optional(),
 	tsConfigPath: z.string().optional(),
+	tsconfig: z
+		.string()
+		.or(
+			z.strictObject({
+				configFile: z.string(),
+				references: z.literal("auto").or(z.string().array()).optional()
+			})
+		)
+		.optional(),
 	fullySpecified: z.boolean().optional(),
 	exportsFields: z.string().array().optional(),
 	extensionAlias: z.record(z.string().or(z.string().array())).optional()
@@ -2,6 +2,8 @@ import { z } from "zod";
 
 export function snapshot() {
 	return z.strictObject({
+		immutablePaths: z.string().or(z.instanceof(RegExp)).array().optional(),
+		managedPaths: z.string().or(z.instanceof(RegExp)).array().optional(),
 		module: z
 			.strictObject({
 				hash: z.boolean().optional(),
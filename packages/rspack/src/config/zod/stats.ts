@@ -26,10 +26,22 @@ export function stats() {
 				outputPath: z.boolean().optional(),
 				chunkModules: z.boolean().optional(),
 				chunkRelations: z.boolean().optional(),
+				chunkExplanations: z.boolean().optional(),
 				timings: z.boolean().optional(),
+				profile: z.boolean().optional(),
 				builtAt: z.boolean().optional(),
 				nestedModules: z.boolean().optional(),
-				source: z.boolean().optional()
+				source: z.boolean().optional(),
+				optimizationBailout: z.boolean().optional(),
+				assetsSort: z.string().optional(),
+				chunksSort: z.string().optional(),
+				modulesSort: z.string().optional(),
+				assetsSpace: z.number().optional(),
+				chunksSpace: z.number().optional(),
+				similarDiagnosticsThreshold: z.number().or(z.literal(false)).optional(),
+				errorDetails: z.boolean().optional(),
+				moduleTrace: z.boolean().optional(),
+				moduleTraceDepth: z.number().optional()
 			})
 		);
 }
@@ -0,0 +1,11 @@
+export default class CompilerClosedError extends Error {
+	name: string;
+	message: string;
+
+	constructor() {
+		super();
+		this.name = "CompilerClosedError";
+		this.message =
+			"The compiler has been closed. Create a new compiler to run another compilation.";
+	}
+}
@@ -6,6 +6,7 @@ export interface ThreadsafeWritableNodeFS {
 	mkdir: (...args: any[]) => any;
 	mkdirp: (...args: any[]) => any;
 	removeDirAll: (...args: any[]) => any;
+	readDirAll: (...args: any[]) => any;
 }
 
 function createThreadsafeNodeFSFromRaw(
@@ -22,7 +23,8 @@ function createThreadsafeNodeFSFromRaw(
 		removeDirAll: dir => {
 			// memfs don't support rmSync
 			rmrfBuild(fs)(dir);
-		}
+		},
+		readDirAll: dir => readDirAllBuild(fs)(dir)
 	};
 }
 
@@ -44,4 +46,24 @@ const rmrfBuild = (fs: typeof import("fs")) => {
 	return rmrf;
 };
 
+// The paths of the files in the directory and its subdirectories, relative to it
+const readDirAllBuild = (fs: typeof import("fs")) => {
+	const readDirAll = (dir: string, prefix = ""): string[] => {
+		if (!fs.existsSync(dir)) {
+			return [];
+		}
+		const files: string[] = [];
+		for (const file of fs.readdirSync(dir) as string[]) {
+			const filePath = join(dir, file);
+			if (fs.lstatSync(filePath).isDirectory()) {
+				files.push(...readDirAll(filePath, `${prefix}${file}/`));
+			} else {
+				files.push(`${prefix}${file}`);
+			}
+		}
+		return files;
+	};
+	return readDirAll;
+};
+
 export { createThreadsafeNodeFSFromRaw };
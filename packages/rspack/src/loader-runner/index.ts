@@ -8,7 +8,7 @@
  * https://github.com/webpack/loader-runner/blob/main/LICENSE
  */
 
-import type { JsLoaderContext } from "@rspack/binding";
+import type { JsLoaderContext, JsLoaderResult } from "@rspack/binding";
 import {
 	OriginalSource,
 	RawSource,
@@ -81,6 +81,7 @@ function createLoaderObject(loader: any, compiler: Compiler): LoaderObject {
 		pitch: null,
 		raw: null,
 		data: null,
+		origin: undefined,
 		pitchExecuted: false,
 		normalExecuted: false
 	};
@@ -147,6 +148,7 @@ function createLoaderObject(loader: any, compiler: Compiler): LoaderObject {
 	return obj;
 }
 
+
 function getCurrentLoader(
 	loaderContext: LoaderContext,
 	index = loaderContext.loaderIndex
@@ -183,11 +185,34 @@ export async function runLoader(
 	let contextDependencies: string[] = rawContext.contextDependencies.slice();
 	let missingDependencies: string[] = rawContext.missingDependencies.slice();
 	let buildDependencies: string[] = rawContext.buildDependencies.slice();
+	let globDependencies: string[] = [];
 	let assetFilenames = rawContext.assetFilenames.slice();
+	const extras = parseBuildInfoExtras(rawContext.extras);
 
 	const loaders = rawContext.currentLoader
 		.split("$")
-		.map(loader => createLoaderObject(loader, compiler));
+		.map((loader, index) => {
+			const obj = createLoaderObject(loader, compiler);
+			const rawOptions = rawContext.loaderOptions[index];
+			// Options of loaders referenced by ident are kept in `compiler.ruleSet.references`,
+			// fallback to the options carried from the native side.
+			if (isNil(obj.options) && !isNil(rawOptions)) {
+				// The options are always JSON serialized, see `serializeLoaderOptions`
+				obj.options = JSON.parse(rawOptions);
+				obj.ident = rawContext.loaderIdents[index];
+			}
+			obj.origin = rawContext.loaderOrigins[index];
+			return obj;
+		});
+	// Restore the `data` stashed by the pitching stage of each loader.
+	if (rawContext.pitchData) {
+		const pitchData: any[] = JSON.parse(
+			rawContext.pitchData.toString("utf-8")
+		);
+		pitchData.forEach((data, index) => {
+			if (loaders[index]) loaders[index].data = data;
+		});
+	}
 
 	loaderContext.__internal__context = rawContext;
 	loaderContext.context = contextDirectory;
@@ -320,6 +345,18 @@ export async function runLoader(
 		? isUseSourceMap(compiler.options.devtool)
 		: false;
 	loaderContext.mode = compiler.options.mode;
+	loaderContext.moduleSettings = rawContext.moduleSettings;
+	loaderContext._module = {
+		buildInfo: extras.values
+	};
+	loaderContext.setBuildInfo = function setBuildInfo(key, value, options) {
+		extras.values[key] = value;
+		if (options?.affectsHash) {
+			extras.affectsHash.add(key);
+		} else {
+			extras.affectsHash.delete(key);
+		}
+	};
 	loaderContext.hot = compiler.options.devServer?.hot;
 
 	const getResolveContext = () => {
@@ -487,10 +524,14 @@ export async function runLoader(
 	loaderContext.addMissingDependency = function addMissingDependency(missing) {
 		missingDependencies.push(missing);
 	};
+	loaderContext.addGlobDependency = function addGlobDependency(glob) {
+		globDependencies.push(glob);
+	};
 	loaderContext.clearDependencies = function clearDependencies() {
 		fileDependencies.length = 0;
 		contextDependencies.length = 0;
 		missingDependencies.length = 0;
+		globDependencies.length = 0;
 	};
 	loaderContext.getDependencies = function getDependencies() {
 		return fileDependencies.slice();
@@ -540,11 +581,31 @@ export async function runLoader(
 		}
 	}
 
-	return new Promise((resolve, reject) => {
+	// The dependencies recorded before a loader failed are returned along with the error,
+	// so fixing the failure triggers a rebuild in watch mode.
+	const toFailedResult = (err: unknown): JsLoaderResult => {
+		const message = err instanceof Error ? concatErrorMsgAndStack(err) : err;
+		const currentLoader = getCurrentLoader(loaderContext);
+		return {
+			buildDependencies,
+			cacheable,
+			fileDependencies,
+			contextDependencies,
+			missingDependencies,
+			globDependencies,
+			extras: serializeBuildInfoExtras(extras),
+			isPitching: loaderContext.__internal__context.isPitching,
+			error: currentLoader
+				? `${message}\n(from: ${stringifyLoaderObject(currentLoader)})`
+				: `${message}`
+		};
+	};
+
+	return new Promise(resolve => {
 		if (loaderContext.__internal__context.isPitching) {
 			iteratePitchingLoaders(loaderContext, [], (err: Error, result: any[]) => {
 				if (err) {
-					return reject(err);
+					return resolve(toFailedResult(err));
 				}
 				const [content, sourceMap, additionalData] = result;
 				resolve({
@@ -556,8 +617,11 @@ export async function runLoader(
 					fileDependencies,
 					contextDependencies,
 					missingDependencies,
+					globDependencies,
 					assetFilenames,
-					isPitching: loaderContext.__internal__context.isPitching
+					extras: serializeBuildInfoExtras(extras),
+					isPitching: loaderContext.__internal__context.isPitching,
+					pitchData: serializePitchData(loaders)
 				});
 			});
 		} else {
@@ -576,7 +640,7 @@ export async function runLoader(
 				],
 				(err: Error, result: any[]) => {
 					if (err) {
-						return reject(err);
+						return resolve(toFailedResult(err));
 					}
 					const [content, sourceMap, additionalData] = result;
 					resolve({
@@ -588,7 +652,9 @@ export async function runLoader(
 						fileDependencies,
 						contextDependencies,
 						missingDependencies,
+						globDependencies,
 						assetFilenames,
+						extras: serializeBuildInfoExtras(extras),
 						isPitching: loaderContext.__internal__context.isPitching
 					});
 				}
@@ -597,6 +663,50 @@ export async function runLoader(
 	});
 }
 
+/**
+ * The `data` of each loader must be JSON serializable to be passed to the normal stage,
+ * as the pitching and normal stages of a loader are executed in separated calls.
+ */
+function serializePitchData(loaders: LoaderObject[]): Buffer | undefined {
+	if (loaders.every(loader => isNil(loader.data))) {
+		return undefined;
+	}
+	return Buffer.from(
+		JSON.stringify(loaders.map(loader => loader.data ?? null))
+	);
+}
+
+interface BuildInfoExtras {
+	values: Record<string, any>;
+	affectsHash: Set<string>;
+}
+
+function parseBuildInfoExtras(raw: Buffer | undefined): BuildInfoExtras {
+	if (!raw) {
+		return { values: {}, affectsHash: new Set() };
+	}
+	const { values, affectsHash } = JSON.parse(raw.toString("utf-8"));
+	return { values, affectsHash: new Set(affectsHash) };
+}
+
+/**
+ * Values of the build info must be JSON serializable, as they are kept by the native side
+ * along with the cached module.
+ */
+function serializeBuildInfoExtras(extras: BuildInfoExtras): Buffer | undefined {
+	if (Object.keys(extras.values).length === 0) {
+		return undefined;
+	}
+	return Buffer.from(
+		JSON.stringify({
+			values: extras.values,
+			affectsHash: Array.from(extras.affectsHash).filter(
+				key => key in extras.values
+			)
+		})
+	);
+}
+
 function utf8BufferToString(buf: Buffer) {
 	var str = buf.toString("utf-8");
 	if (str.charCodeAt(0) === 0xfeff) {
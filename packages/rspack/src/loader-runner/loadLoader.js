@@ -36,12 +36,32 @@ module.exports = function loadLoader(loader, callback) {
 				module = async function (content, sourceMap, additionalData) {
 					assert(!this.__internal__context.isPitching);
 					const callback = this.async();
-					const { runBuiltinLoader } = require("@rspack/binding");
-					let options = this.getOptions() ?? {};
+					const { runBuiltinLoaders } = require("@rspack/binding");
+					// The following builtin loaders are run along with this one in a single call
+					const builtinLoaders = [
+						{
+							builtin: loader.path,
+							options: JSON.stringify(this.getOptions() ?? {})
+						}
+					];
+					for (let index = this.loaderIndex - 1; index >= 0; index--) {
+						const loaderObject = this.loaders[index];
+						if (
+							!loaderObject.path.startsWith("builtin:") ||
+							loaderObject.normalExecuted
+						) {
+							break;
+						}
+						loaderObject.normalExecuted = true;
+						builtinLoaders.push({
+							builtin: loaderObject.path,
+							options: JSON.stringify(loaderObject.options ?? {})
+						});
+					}
 					try {
-						const context = await runBuiltinLoader(
-							loader.path,
-							JSON.stringify(options),
+						const context = await runBuiltinLoaders(
+							// In the order of the loaders, the normal loaders run in reverse
+							builtinLoaders.reverse(),
 							Object.assign({}, this.__internal__context, {
 								content: isNil(content) ? undefined : toBuffer(content),
 								sourceMap: serializeObject(sourceMap),
@@ -30,7 +30,6 @@ import { Callback } from "tapable";
 import MultiStats from "./MultiStats";
 import assert from "assert";
 import { asArray, isNil } from "./util";
-import IgnoreWarningsPlugin from "./lib/ignoreWarningsPlugin";
 
 function createMultiCompiler(options: MultiRspackOptions): MultiCompiler {
 	const compilers = options.map(createCompiler);
@@ -76,10 +75,6 @@ function createCompiler(userOptions: RspackOptions): Compiler {
 		}
 	}
 
-	if (options.ignoreWarnings !== undefined) {
-		new IgnoreWarningsPlugin(options.ignoreWarnings).apply(compiler);
-	}
-
 	applyRspackOptionsDefaults(compiler.options);
 	logger.debug(
 		"NormalizedOptions:",
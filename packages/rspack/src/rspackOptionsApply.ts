@@ -7,11 +7,7 @@
  * Copyright (c) JS Foundation and other contributors
  * https://github.com/webpack/webpack/blob/main/LICENSE
  */
-import {
-	RspackOptionsNormalized,
-	Compiler,
-	OptimizationRuntimeChunkNormalized
-} from ".";
+import { RspackOptionsNormalized, Compiler } from ".";
 import fs from "graceful-fs";
 
 import { ResolveSwcPlugin } from "./web/ResolveSwcPlugin";
@@ -41,15 +37,6 @@ export class RspackOptionsApply {
 				}
 			}
 		}
-		const runtimeChunk = options.optimization
-			.runtimeChunk as OptimizationRuntimeChunkNormalized;
-		if (runtimeChunk) {
-			Object.entries(options.entry).forEach(([entryName, value]) => {
-				if (value.runtime === undefined) {
-					value.runtime = runtimeChunk.name({ name: entryName });
-				}
-			});
-		}
 		if (options.builtins.devFriendlySplitChunks) {
 			options.optimization.splitChunks = undefined;
 		}
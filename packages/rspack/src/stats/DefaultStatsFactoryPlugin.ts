@@ -11,6 +11,7 @@ import { compareSelect, compareIds as _compareIds } from "../util/comparators";
 import { makePathsRelative } from "../util/identifier";
 import * as util from "node:util";
 import type { Compiler } from "../Compiler";
+import type * as binding from "@rspack/binding";
 import type { StatsOptions } from "../config";
 import type { GroupConfig } from "../util/smartGrouping";
 
@@ -33,6 +34,7 @@ import type {
 	StatsChunk,
 	NormalizedStatsOptions,
 	KnownStatsLoggingEntry,
+	StatsError,
 	StatsProfile
 } from "./statsFactoryUtils";
 import {
@@ -42,6 +44,27 @@ import {
 	LogTypeEnum
 } from "../logging/Logger";
 
+/**
+ * The module trace and the details are only kept with `stats.moduleTrace` and `stats.errorDetails`.
+ */
+const extractError = (
+	error: binding.JsStatsError | binding.JsStatsWarning,
+	options: StatsOptions
+): StatsError => {
+	const { moduleIdentifier, moduleName, moduleTrace, details, ...rest } =
+		error;
+	const object: StatsError = rest as StatsError;
+	if (options.moduleTrace && moduleIdentifier) {
+		object.moduleIdentifier = moduleIdentifier;
+		object.moduleName = moduleName;
+		object.moduleTrace = moduleTrace;
+	}
+	if (options.errorDetails && details) {
+		object.details = details;
+	}
+	return object;
+};
+
 const compareIds = _compareIds as <T>(a: T, b: T) => -1 | 0 | 1;
 const GROUP_EXTENSION_REGEXP = /(\.[^.]+?)(?:\?|(?: \+ \d+ modules?)?$)/;
 const GROUP_PATH_REGEXP = /(.+)[/\\][^/\\]+?(?:\?|(?: \+ \d+ modules?)?$)/;
@@ -540,6 +563,19 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 		timings: (object, compilation) => {
 			object.time = compilation.endTime! - compilation.startTime!;
 		},
+		profile: (object, _compilation, context: KnownStatsFactoryContext) => {
+			const { modules, loaders } = context._inner.getProfileSummary();
+			object.profile = {
+				modules: modules.map(({ building, ...module }) => ({
+					...module,
+					building: resolveStatsMillisecond(building)
+				})),
+				loaders: loaders.map(({ duration, ...loader }) => ({
+					...loader,
+					duration: resolveStatsMillisecond(duration)
+				}))
+			};
+		},
 		builtAt: (object, compilation) => {
 			object.builtAt = compilation.endTime;
 		},
@@ -575,10 +611,20 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 				groupedAssets,
 				options.assetsSpace || Infinity
 			);
+			const children: KnownStatsAsset[] = limited.children || [];
+			let filteredAssets: number = limited.filteredChildren || 0;
+			// Assets over the size limit are always visible
+			for (const asset of groupedAssets) {
+				if (asset.isOverSizeLimit && !children.includes(asset)) {
+					children.push(asset);
+					filteredAssets--;
+				}
+			}
 
-			// object.filteredAssets = limited.filteredChildren;
-			// const limited = spaceLimited(groupedAssets, options.assetsSpace);
-			object.assets = limited.children;
+			object.assets = children;
+			if (filteredAssets > 0) {
+				object.filteredAssets = filteredAssets;
+			}
 		},
 		chunks: (
 			object,
@@ -588,15 +634,38 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			factory
 		) => {
 			const { type } = context;
+			// The modules are fetched chunk by chunk rather than along with all the chunks,
+			// so the modules of a huge graph aren't converted in one go
 			const chunks = context._inner.getChunks(
-				options.chunkModules!,
+				false,
 				options.chunkRelations!,
+				options.chunkExplanations!,
 				options.reasons!,
 				options.moduleAssets!,
 				options.nestedModules!,
 				options.source!
 			);
-			object.chunks = factory.create(`${type}.chunks`, chunks, context);
+			if (options.chunkModules) {
+				for (const chunk of chunks) {
+					chunk.modules =
+						context._inner.getChunkModules(
+							chunk.id,
+							options.reasons!,
+							options.moduleAssets!,
+							options.nestedModules!,
+							options.source!
+						) ?? undefined;
+				}
+			}
+			const groupedChunks = factory.create(`${type}.chunks`, chunks, context);
+			const limited = spaceLimited(
+				groupedChunks,
+				options.chunksSpace || Infinity
+			);
+			object.chunks = limited.children;
+			if (limited.filteredChildren) {
+				object.filteredChunks = limited.filteredChildren;
+			}
 		},
 		modules: (
 			object,
@@ -669,11 +738,13 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			object,
 			compilation,
 			context: KnownStatsFactoryContext,
-			_options,
+			options,
 			_factory
 		) => {
 			const { cachedGetErrors } = context;
-			object.errors = cachedGetErrors!(compilation);
+			object.errors = cachedGetErrors!(compilation).map(error =>
+				extractError(error, options)
+			);
 		},
 		errorsCount: (
 			object,
@@ -688,11 +759,13 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			object,
 			compilation,
 			context: KnownStatsFactoryContext,
-			_options,
+			options,
 			_factory
 		) => {
 			const { cachedGetWarnings } = context;
-			object.warnings = cachedGetWarnings!(compilation);
+			object.warnings = cachedGetWarnings!(compilation).map(warning =>
+				extractError(warning, options)
+			);
 		},
 		warningsCount: (object, compilation, context: KnownStatsFactoryContext) => {
 			const { cachedGetWarnings } = context;
@@ -728,7 +801,11 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			factory
 		) => {
 			const { type } = context;
-			Object.assign(object, module);
+			const { optimizationBailout, ...rest } = module;
+			Object.assign(object, rest);
+			if (options.optimizationBailout) {
+				object.optimizationBailout = optimizationBailout;
+			}
 			const profile = module.profile;
 			if (profile) {
 				object.profile = factory.create(`${type}.profile`, profile, context);
@@ -744,8 +821,15 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 				total: factory + integration + building,
 				resolving: factory,
 				integration,
-				building
+				building,
+				loaders: profile.loaders.map(({ identifier, duration }) => ({
+					identifier,
+					duration: resolveStatsMillisecond(duration)
+				}))
 			};
+			if (profile.parsing) {
+				statsProfile.parsing = resolveStatsMillisecond(profile.parsing);
+			}
 			Object.assign(object, statsProfile);
 		}
 	}
@@ -50,6 +50,18 @@ const getModuleName = (name: string) => {
 const mapLines = (str: string, fn: (val: string) => string) =>
 	str.split("\n").map(fn).join("\n");
 
+const appendModuleTrace = (
+	formatted: string,
+	moduleTrace: { moduleName: string; loc?: string }[]
+) => {
+	const trace = moduleTrace
+		.map(({ moduleName, loc }) => ` @ ${moduleName}${loc ? ` ${loc}` : ""}`)
+		.join("\n");
+	return formatted.endsWith("\n")
+		? `${formatted}${trace}\n`
+		: `${formatted}\n${trace}`;
+};
+
 const twoDigit = (n: number) => (n >= 10 ? `${n}` : `0${n}`);
 
 const isValidId = (id: any) => {
@@ -215,6 +227,14 @@ const SIMPLE_PRINTERS: Record<
 					"assets"
 			  )}`
 			: undefined,
+	"compilation.filteredChunks": (filteredChunks, { compilation: { chunks } }) =>
+		filteredChunks > 0
+			? `${moreCount(chunks, filteredChunks)} ${plural(
+					filteredChunks,
+					"chunk",
+					"chunks"
+			  )}`
+			: undefined,
 	"compilation.logging": (logging, context, printer) =>
 		Array.isArray(logging)
 			? undefined
@@ -226,6 +246,28 @@ const SIMPLE_PRINTERS: Record<
 					})),
 					context
 			  ),
+	"compilation.profile": (profile, { bold, formatTime }) => {
+		const lines: string[] = [];
+		if (profile.modules.length > 0) {
+			lines.push(bold("Slowest modules to build:"));
+			for (const { name, building } of profile.modules) {
+				lines.push(`  ${formatTime(building)} ${name}`);
+			}
+		}
+		if (profile.loaders.length > 0) {
+			lines.push(bold("Slowest loaders:"));
+			for (const { identifier, duration, modules } of profile.loaders) {
+				lines.push(
+					`  ${formatTime(duration)} ${identifier} (${modules} ${plural(
+						modules,
+						"module",
+						"modules"
+					)})`
+				);
+			}
+		}
+		return lines.length > 0 ? lines.join("\n") : undefined;
+	},
 	"compilation.warningsInChildren!": (_, { yellow, compilation }) => {
 		if (
 			!compilation.children &&
@@ -480,6 +522,17 @@ const SIMPLE_PRINTERS: Record<
 		`integration: ${formatTime(value)}`,
 	"module.profile.building": (value, { formatTime }) =>
 		`building: ${formatTime(value)}`,
+	"module.profile.parsing": (value, { formatTime }) =>
+		`parsing: ${formatTime(value)}`,
+	"module.profile.loaders": (loaders, { formatTime }) =>
+		loaders.length > 0
+			? `loaders: ${loaders
+					.map(
+						({ identifier, duration }) =>
+							`${identifier} ${formatTime(duration)}`
+					)
+					.join(", ")}`
+			: undefined,
 	"module.profile.storing": (value, { formatTime }) =>
 		`storing: ${formatTime(value)}`,
 	"module.profile.additionalResolving": (value, { formatTime }) =>
@@ -586,8 +639,11 @@ const SIMPLE_PRINTERS: Record<
 	"chunkOrigin.moduleName": (moduleName, { bold }) => bold(moduleName),
 	"chunkOrigin.loc": loc => loc,
 
-	// Error was already formatted on the native.
-	error: error => error.formatted,
+	// Error was already formatted on the native, only the module trace is appended.
+	error: error =>
+		error.moduleTrace && error.moduleTrace.length > 0
+			? appendModuleTrace(error.formatted, error.moduleTrace)
+			: error.formatted,
 
 	"loggingEntry(error).loggingEntry.message": (message, { red }) =>
 		mapLines(message, x => `<e> ${red(x)}`),
@@ -708,10 +764,12 @@ const PREFERRED_ORDERS: Record<string, string[]> = {
 		"entrypoints",
 		"namedChunkGroups",
 		"chunks",
+		"filteredChunks",
 		"modules",
 		"filteredModules",
 		"children",
 		"logging",
+		"profile",
 		"warnings",
 		"warningsInChildren!",
 		"filteredWarningDetailsCount",
@@ -817,6 +875,8 @@ const PREFERRED_ORDERS: Record<string, string[]> = {
 		"restoring",
 		"integration",
 		"building",
+		"parsing",
+		"loaders",
 		"storing",
 		"additionalResolving",
 		"additionalIntegration"
@@ -32,11 +32,29 @@ export type KnownStatsProfile = {
 	resolving: number;
 	integration: number;
 	building: number;
+	loaders: StatsLoaderProfile[];
+	parsing?: number;
+};
+
+export type StatsLoaderProfile = {
+	identifier: string;
+	duration: number;
+};
+
+export type StatsProfileSummary = {
+	modules: {
+		identifier: string;
+		name: string;
+		building: number;
+	}[];
+	loaders: (StatsLoaderProfile & {
+		modules: number;
+	})[];
 };
 
 export type StatsModule = KnownStatsModule & Record<string, any>;
 
-type StatsError = binding.JsStatsError & Record<string, any>;
+export type StatsError = binding.JsStatsError & Record<string, any>;
 
 type StatsWarnings = binding.JsStatsWarning & Record<string, any>;
 
@@ -68,13 +86,15 @@ export type KnownStatsCompilation = {
 	warnings?: StatsWarnings[];
 	warningsCount?: number;
 	filteredModules?: number;
+	filteredAssets?: number;
+	filteredChunks?: number;
 	children?: StatsCompilation[];
 	logging?: Record<string, StatsLogging>;
+	profile?: StatsProfileSummary;
 
 	// TODO: not aligned with webpack
 	// env?: any;
 	// needAdditionalPass?: boolean;
-	// filteredAssets?: number;
 };
 
 export type StatsCompilation = KnownStatsCompilation & Record<string, any>;
@@ -148,17 +168,13 @@ export type SimpleExtractors = {
 type KnownNormalizedStatsOptions = {
 	context: string;
 	// requestShortener: any;
-	// chunksSort: string;
-	// modulesSort: string;
 	// chunkModulesSort: string;
 	// nestedModulesSort: string;
-	// assetsSort: string;
 	// ids: boolean;
 	// cachedAssets: boolean;
 	// groupAssetsByEmitStatus: boolean;
 	// groupAssetsByPath: boolean;
 	// groupAssetsByExtension: boolean;
-	// assetsSpace: number;
 	// excludeAssets: ((value: string, asset: StatsAsset) => boolean)[];
 	// excludeModules: ((
 	// 	name: string,
@@ -102,7 +102,9 @@ export function toJsAssetInfo(info?: AssetInfo): JsAssetInfo {
 		related: {},
 		chunkHash: [],
 		contentHash: [],
+		sourceFilename: [],
 		version: "",
+		banners: [],
 		...info
 	};
 }
@@ -290,6 +290,47 @@ describe("Compiler", () => {
 			});
 		});
 	});
+	it("should return an error when running a closed compiler", done => {
+		const c = rspack({
+			context: path.join(__dirname, "fixtures"),
+			entry: "./c",
+			output: {
+				path: "/directory"
+			}
+		});
+		c.outputFileSystem = createFsFromVolume(new Volume());
+		c.run(err => {
+			if (err) return done(err);
+			c.close(err => {
+				if (err) return done(err);
+				c.run(err => {
+					expect(err).toBeTruthy();
+					expect(err.name).toBe("CompilerClosedError");
+					done();
+				});
+			});
+		});
+	});
+	it("should wait for the running compilation before closing", done => {
+		const c = rspack({
+			context: path.join(__dirname, "fixtures"),
+			entry: "./c",
+			output: {
+				path: "/directory"
+			}
+		});
+		c.outputFileSystem = createFsFromVolume(new Volume());
+		let built = false;
+		c.run(err => {
+			if (err) return done(err);
+			built = true;
+		});
+		c.close(err => {
+			if (err) return done(err);
+			expect(built).toBe(true);
+			done();
+		});
+	});
 	it("should not emit on errors", done => {
 		compiler = rspack({
 			context: __dirname,
@@ -307,6 +348,329 @@ describe("Compiler", () => {
 			done();
 		});
 	});
+	it("should emit to the memory output file system", done => {
+		const c = rspack({
+			context: path.join(__dirname, "fixtures"),
+			entry: "./c",
+			output: {
+				path: "/directory",
+				clean: true
+			}
+		});
+		c.outputFileSystem = createFsFromVolume(new Volume());
+		c.setOutputFileSystem("memory");
+		c.run(err => {
+			if (err) return done(err);
+			expect(c.readOutputFile("/directory/main.js")?.toString()).toContain(
+				"This is c"
+			);
+			expect(c.readOutputFile("/directory/missing.js")).toBeNull();
+			expect(c.outputFileSystem.existsSync("/directory/main.js")).toBe(false);
+			c.run(err => {
+				if (err) return done(err);
+				// The unchanged assets are kept in the memory store
+				expect(c.readOutputFile("/directory/main.js")).toBeTruthy();
+				c.setOutputFileSystem("node");
+				expect(c.readOutputFile("/directory/main.js")).toBeNull();
+				c.run(err => {
+					if (err) return done(err);
+					expect(c.outputFileSystem.existsSync("/directory/main.js")).toBe(
+						true
+					);
+					c.close(done);
+				});
+			});
+		});
+	});
+	it("should only write the changed assets and remove the stale ones", done => {
+		let first = true;
+		const c = rspack({
+			context: path.join(__dirname, "fixtures"),
+			entry: "./c",
+			mode: "development",
+			output: {
+				path: "/directory",
+				clean: { keep: /kept/ }
+			},
+			plugins: [
+				compiler => {
+					compiler.hooks.thisCompilation.tap("Plugin", compilation => {
+						compilation.hooks.processAssets.tap("Plugin", () => {
+							if (first) {
+								compilation.emitAsset("stale.txt", new RawSource("stale"));
+								compilation.emitAsset("kept.txt", new RawSource("kept"));
+							}
+						});
+					});
+				}
+			]
+		});
+		c.outputFileSystem = createFsFromVolume(new Volume());
+		c.outputFileSystem.mkdirSync("/directory");
+		c.outputFileSystem.mkdirSync("/directory/nested");
+		c.outputFileSystem.writeFileSync("/directory/foreign.txt", "foreign");
+		c.outputFileSystem.writeFileSync("/directory/nested/foreign.txt", "foreign");
+		c.outputFileSystem.writeFileSync("/directory/kept-foreign.txt", "foreign");
+		const emitted = [];
+		c.hooks.assetEmitted.tap("Test", file => {
+			emitted.push(file);
+		});
+		c.run(err => {
+			if (err) return done(err);
+			expect(emitted.sort()).toEqual(["kept.txt", "main.js", "stale.txt"]);
+			const fs = c.outputFileSystem;
+			// The foreign files are removed by the first emit unless they are kept
+			expect(fs.existsSync("/directory/foreign.txt")).toBe(false);
+			expect(fs.existsSync("/directory/nested/foreign.txt")).toBe(false);
+			expect(fs.existsSync("/directory/kept-foreign.txt")).toBe(true);
+			first = false;
+			emitted.length = 0;
+			fs.writeFileSync("/directory/foreign.txt", "foreign");
+			c.run(err => {
+				if (err) return done(err);
+				expect(emitted).toEqual([]);
+				expect(fs.existsSync("/directory/main.js")).toBe(true);
+				expect(fs.existsSync("/directory/stale.txt")).toBe(false);
+				expect(fs.existsSync("/directory/kept.txt")).toBe(true);
+				expect(fs.existsSync("/directory/foreign.txt")).toBe(true);
+				c.close(done);
+			});
+		});
+	});
+	it("should write the changed assets with an unchanged content hash", done => {
+		let content = "first";
+		const c = rspack({
+			context: path.join(__dirname, "fixtures"),
+			entry: "./c",
+			mode: "development",
+			output: {
+				path: "/directory"
+			},
+			plugins: [
+				compiler => {
+					compiler.hooks.thisCompilation.tap("Plugin", compilation => {
+						compilation.hooks.processAssets.tap("Plugin", () => {
+							compilation.emitAsset("data.txt", new RawSource(content), {
+								contentHash: ["fixed"]
+							});
+						});
+					});
+				}
+			]
+		});
+		c.outputFileSystem = createFsFromVolume(new Volume());
+		const emitted = [];
+		c.hooks.assetEmitted.tap("Test", file => {
+			emitted.push(file);
+		});
+		c.run(err => {
+			if (err) return done(err);
+			content = "second";
+			emitted.length = 0;
+			c.run(err => {
+				if (err) return done(err);
+				expect(emitted).toEqual(["data.txt"]);
+				expect(
+					c.outputFileSystem.readFileSync("/directory/data.txt", "utf-8")
+				).toBe("second");
+				c.close(done);
+			});
+		});
+	});
+	it("should ignore the stale assets which are already removed", done => {
+		let first = true;
+		const c = rspack({
+			context: path.join(__dirname, "fixtures"),
+			entry: "./c",
+			mode: "development",
+			output: {
+				path: "/directory",
+				clean: true
+			},
+			plugins: [
+				compiler => {
+					compiler.hooks.thisCompilation.tap("Plugin", compilation => {
+						compilation.hooks.processAssets.tap("Plugin", () => {
+							if (first) {
+								compilation.emitAsset("stale.txt", new RawSource("stale"));
+							}
+						});
+					});
+				}
+			]
+		});
+		c.outputFileSystem = createFsFromVolume(new Volume());
+		c.run(err => {
+			if (err) return done(err);
+			first = false;
+			c.outputFileSystem.unlinkSync("/directory/stale.txt");
+			c.run(err => {
+				if (err) return done(err);
+				expect(c.outputFileSystem.existsSync("/directory/main.js")).toBe(true);
+				c.close(done);
+			});
+		});
+	});
+	it("should write the unchanged assets again without incremental emit", done => {
+		const c = rspack({
+			context: path.join(__dirname, "fixtures"),
+			entry: "./c",
+			mode: "development",
+			output: {
+				path: "/directory"
+			},
+			experiments: {
+				incrementalRebuild: {
+					emitAsset: false
+				}
+			}
+		});
+		c.outputFileSystem = createFsFromVolume(new Volume());
+		const emitted = [];
+		c.hooks.assetEmitted.tap("Test", file => {
+			emitted.push(file);
+		});
+		c.run(err => {
+			if (err) return done(err);
+			c.run(err => {
+				if (err) return done(err);
+				expect(emitted).toEqual(["main.js", "main.js"]);
+				c.close(done);
+			});
+		});
+	});
+	describe("rebuild", () => {
+		const fs = require("fs");
+		const os = require("os");
+		let context;
+		beforeEach(() => {
+			context = fs.mkdtempSync(path.join(os.tmpdir(), "rspack-rebuild-"));
+		});
+		afterEach(() => {
+			fs.rmSync(context, { recursive: true, force: true });
+		});
+		function writeFiles(files) {
+			for (const [name, content] of Object.entries(files)) {
+				const file = path.join(context, name);
+				fs.mkdirSync(path.dirname(file), { recursive: true });
+				fs.writeFileSync(file, content);
+			}
+		}
+		function rebuild(options, files, callback) {
+			const builtModuleCounts = [];
+			const c = rspack({
+				context,
+				entry: "./index.js",
+				mode: "development",
+				output: {
+					path: "/directory"
+				},
+				...options
+			});
+			c.outputFileSystem = createFsFromVolume(new Volume());
+			c.hooks.done.tap("Test", stats => {
+				const { logging } = stats.toJson({ all: false, logging: "log" });
+				for (const entry of logging["rspack.Compiler"].entries) {
+					const matched = /^(\d+) modules are built/.exec(entry.message);
+					if (matched) builtModuleCounts.push(Number(matched[1]));
+				}
+			});
+			c.build(err => {
+				if (err) return callback(err);
+				writeFiles(files);
+				const changed = new Set(
+					Object.keys(files).map(name => path.join(context, name))
+				);
+				c.rebuild(changed, new Set(), err => {
+					if (err) return callback(err);
+					const output = c.outputFileSystem
+						.readFileSync("/directory/main.js")
+						.toString();
+					c.close(() => callback(null, builtModuleCounts, output));
+				});
+			});
+		}
+
+		it("should only rebuild the modules whose files changed", done => {
+			const count = 2000;
+			writeFiles({ "index.js": 'import "./module-0.js";' });
+			for (let i = 0; i < count; i++) {
+				writeFiles({
+					[`module-${i}.js`]:
+						i === count - 1
+							? "export default 0;"
+							: `import next from "./module-${i + 1}.js";\nexport default ${i} + next;`
+				});
+			}
+			rebuild(
+				{},
+				{ [`module-${count / 2}.js`]: "export default -1;" },
+				(err, builtModuleCounts) => {
+					if (err) return done(err);
+					expect(builtModuleCounts).toEqual([count + 1, 1]);
+					done();
+				}
+			);
+		});
+
+		it("should rebuild the upstream modules once the exports changed", done => {
+			writeFiles({
+				"index.js": 'import value from "./a.js";\nexport default value;',
+				"a.js": 'import value from "./b.js";\nexport default value;',
+				"b.js": "module.exports = 1;"
+			});
+			rebuild(
+				{},
+				{ "b.js": "export default 2;" },
+				(err, builtModuleCounts) => {
+					if (err) return done(err);
+					expect(builtModuleCounts).toEqual([3, 1, 1]);
+					done();
+				}
+			);
+		});
+
+		it("should always rebuild the modules which are not cacheable", done => {
+			writeFiles({
+				"index.js": 'import "./a.js";\nimport "./b.js";',
+				"a.js": "export default 1;",
+				"b.js": "export default 1;",
+				"uncacheable-loader.js":
+					"module.exports = function (source) {\n\tthis.cacheable(false);\n\treturn source;\n};"
+			});
+			rebuild(
+				{
+					module: {
+						rules: [
+							{
+								test: /a\.js$/,
+								use: [path.join(context, "uncacheable-loader.js")]
+							}
+						]
+					}
+				},
+				{ "b.js": "export default 2;" },
+				(err, builtModuleCounts) => {
+					if (err) return done(err);
+					expect(builtModuleCounts).toEqual([3, 2]);
+					done();
+				}
+			);
+		});
+
+		it("should rebuild the context module once a file is added to the directory", done => {
+			writeFiles({
+				"index.js":
+					'const ctx = require.context("./dir", false, /\\.js$/);\nexport default ctx.keys();',
+				"dir/a.js": "export default 1;"
+			});
+			rebuild({}, { "dir/b.js": "export default 2;" }, (err, _, output) => {
+				if (err) return done(err);
+				expect(output).toContain("./b.js");
+				done();
+			});
+		});
+	});
 	// TODO: support `bail`
 	it.skip("should bubble up errors when wrapped in a promise and bail is true", async () => {
 		try {
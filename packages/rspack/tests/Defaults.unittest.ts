@@ -127,7 +127,9 @@ describe("snapshots", () => {
 		-   "mode": "none",
 		+   "mode": undefined,
 		@@ ... @@
+		-     "mangleExports": false,
 		-     "minimize": false,
+		+     "mangleExports": true,
 		+     "minimize": true,
 		@@ ... @@
 		-     "moduleIds": "named",
@@ -181,7 +183,9 @@ describe("snapshots", () => {
 		-   "mode": "none",
 		+   "mode": "production",
 		@@ ... @@
+		-     "mangleExports": false,
 		-     "minimize": false,
+		+     "mangleExports": true,
 		+     "minimize": true,
 		@@ ... @@
 		-     "moduleIds": "named",
@@ -629,6 +633,11 @@ describe("snapshots", () => {
 		@@ ... @@
 		-           "browser",
 		@@ ... @@
+		-         "browserField": true,
+		+         "browserField": false,
+		@@ ... @@
+		-           "browser",
+		@@ ... @@
 		-       "browser",
 		+       "node",
 		@@ ... @@
@@ -731,6 +740,11 @@ describe("snapshots", () => {
 		@@ ... @@
 		-           "browser",
 		@@ ... @@
+		-         "browserField": true,
+		+         "browserField": false,
+		@@ ... @@
+		-           "browser",
+		@@ ... @@
 		-       "browser",
 		+       "node",
 		+       "electron",
@@ -816,6 +830,11 @@ describe("snapshots", () => {
 		@@ ... @@
 		-           "browser",
 		@@ ... @@
+		-         "browserField": true,
+		+         "browserField": false,
+		@@ ... @@
+		-           "browser",
+		@@ ... @@
 		+       "node",
 		@@ ... @@
 		+       "electron",
@@ -967,6 +986,9 @@ describe("snapshots", () => {
 			-     "chunkLoadingGlobal": "webpackChunk@rspack/core",
 			+     "chunkLoadingGlobal": "webpackChunk@@@Hello World!",
 			@@ ... @@
+			-     "hotUpdateGlobal": "webpackHotUpdate@rspack/core",
+			+     "hotUpdateGlobal": "webpackHotUpdate@@@Hello World!",
+			@@ ... @@
 			-     "trustedTypes": undefined,
 			-     "uniqueName": "@rspack/core",
 			+     "trustedTypes": Object {
@@ -1030,6 +1052,9 @@ describe("snapshots", () => {
 			-     "chunkLoadingGlobal": "webpackChunk@rspack/core",
 			+     "chunkLoadingGlobal": "webpackChunkbrowserslist-test",
 			@@ ... @@
+			-     "hotUpdateGlobal": "webpackHotUpdate@rspack/core",
+			+     "hotUpdateGlobal": "webpackHotUpdatebrowserslist-test",
+			@@ ... @@
 			-     "uniqueName": "@rspack/core",
 			+     "uniqueName": "browserslist-test",
 		`)
@@ -1061,6 +1086,9 @@ describe("snapshots", () => {
 			-     "chunkLoadingGlobal": "webpackChunk@rspack/core",
 			+     "chunkLoadingGlobal": "webpackChunk",
 			@@ ... @@
+			-     "hotUpdateGlobal": "webpackHotUpdate@rspack/core",
+			+     "hotUpdateGlobal": "webpackHotUpdate",
+			@@ ... @@
 			-     "path": "<cwd>/dist",
 			+     "path": "<cwd>/tests/fixtures/dist",
 			@@ ... @@
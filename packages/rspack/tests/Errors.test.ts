@@ -151,3 +151,33 @@ it("should emit warnings for resolve failure in esm", async () => {
 		}
 	`);
 });
+
+it("should attribute the warnings of the builtin loaders to the loader", async () => {
+	const { warnings } = await compile({
+		entry: "./file",
+		module: {
+			rules: [
+				{
+					test: /file\.js$/,
+					use: [
+						{
+							loader: "builtin:swc-loader",
+							options: {
+								jsc: {
+									experimental: {
+										plugins: []
+									}
+								}
+							}
+						}
+					]
+				}
+			]
+		}
+	});
+	expect(warnings).toHaveLength(1);
+	expect(warnings[0].message).toContain(
+		"Experimental plugins are not currently supported."
+	);
+	expect(warnings[0].formatted).toContain("from loader builtin:swc-loader");
+});
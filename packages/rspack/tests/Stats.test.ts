@@ -35,8 +35,13 @@ describe("Stats", () => {
 		      ],
 		      "emitted": true,
 		      "info": {
+		        "chunkHash": [],
+		        "contentHash": [],
 		        "development": false,
 		        "hotModuleReplacement": false,
+		        "immutable": false,
+		        "minimized": true,
+		        "related": {},
 		      },
 		      "name": "main.js",
 		      "size": 215,
@@ -69,6 +74,7 @@ describe("Stats", () => {
 		          "issuerPath": [],
 		          "moduleType": "javascript/auto",
 		          "name": "./fixtures/a.js",
+		          "optimizationBailout": [],
 		          "reasons": [
 		            {
 		              "type": "entry",
@@ -122,6 +128,7 @@ describe("Stats", () => {
 		      "issuerPath": [],
 		      "moduleType": "javascript/auto",
 		      "name": "./fixtures/a.js",
+		      "optimizationBailout": [],
 		      "reasons": [
 		        {
 		          "type": "entry",
@@ -181,6 +188,45 @@ describe("Stats", () => {
 		).toEqual({});
 	});
 
+	it("should have chunk modules and entrypoints for bundle analysis", async () => {
+		const stats = await compile({
+			context: __dirname,
+			entry: {
+				main: "./fixtures/a"
+			}
+		});
+		const withoutModules = stats?.toJson({
+			all: false,
+			chunks: true,
+			chunkModules: false
+		});
+		expect(withoutModules?.chunks?.[0].modules).toBeUndefined();
+
+		const { chunks, entrypoints } = stats!.toJson({
+			all: false,
+			chunks: true,
+			chunkModules: true,
+			entrypoints: true
+		});
+		const [chunk] = chunks!;
+		expect(chunk).toMatchObject({
+			id: "main",
+			names: ["main"],
+			files: ["main.js"],
+			initial: true,
+			entry: true
+		});
+		expect(chunk.modules!.map(m => m.name)).toEqual(["./fixtures/a.js"]);
+		expect(chunk.size).toBe(
+			chunk.modules!.reduce((size, m) => size + m.size, 0)
+		);
+		expect(entrypoints!.main.chunks).toEqual(["main"]);
+		expect(entrypoints!.main.assetsSize).toBeGreaterThan(0);
+
+		const [compilationChunk] = stats!.compilation.chunks!;
+		expect(compilationChunk.getModules()).toHaveLength(1);
+	});
+
 	it("should look not bad for default stats toString", async () => {
 		const stats = await compile({
 			context: __dirname,
@@ -296,15 +342,38 @@ describe("Stats", () => {
 		).toMatchInlineSnapshot(`
 		"./fixtures/a.js [876] {main}
 		  [222] ->
-		  X ms (resolving: X ms, integration: X ms, building: X ms)
+		  X ms (resolving: X ms, integration: X ms, building: X ms, parsing: X ms)
 		./fixtures/b.js [211] {main}
 		  [222] ->
-		  X ms (resolving: X ms, integration: X ms, building: X ms)
+		  X ms (resolving: X ms, integration: X ms, building: X ms, parsing: X ms)
 		./fixtures/c.js [537] {main}
 		  [222] ->
-		  X ms (resolving: X ms, integration: X ms, building: X ms)
+		  X ms (resolving: X ms, integration: X ms, building: X ms, parsing: X ms)
 		./fixtures/abc.js [222] {main}
-		  X ms (resolving: X ms, integration: X ms, building: X ms)"
+		  X ms (resolving: X ms, integration: X ms, building: X ms, parsing: X ms)"
 	`);
 	});
+
+	it("should have the slowest modules when stats.profile is true", async () => {
+		const stats = await compile({
+			context: __dirname,
+			entry: "./fixtures/abc",
+			profile: true
+		});
+		const { profile, modules } = stats!.toJson({
+			all: false,
+			modules: true,
+			profile: true
+		});
+		expect(profile!.modules.map(module => module.name).sort()).toEqual([
+			"./fixtures/a.js",
+			"./fixtures/abc.js",
+			"./fixtures/b.js",
+			"./fixtures/c.js"
+		]);
+		for (const module of modules!) {
+			expect(module.profile!.parsing).toBeGreaterThanOrEqual(0);
+			expect(module.profile!.loaders).toEqual([]);
+		}
+	});
 });
@@ -76,7 +76,7 @@ describe("WatchSuspend", () => {
 			};
 		});
 
-		it.skip("should suspend compilation", done => {
+		it("should suspend compilation", done => {
 			onChange = jest.fn();
 			watching.suspend();
 			fs.writeFileSync(filePath, "'bar'", "utf-8");
@@ -87,7 +87,7 @@ describe("WatchSuspend", () => {
 			}, 1000);
 		});
 
-		it.skip("should resume compilation", done => {
+		it("should resume compilation", done => {
 			onChange = () => {
 				expect(fs.readFileSync(outputFile, "utf-8")).toContain("'bar'");
 				onChange = null;
@@ -99,7 +99,7 @@ describe("WatchSuspend", () => {
 		for (const changeBefore of [false, true])
 			for (const delay of [200, 1500]) {
 				// eslint-disable-next-line no-loop-func
-				it.skip(`should not ignore changes during resumed compilation (changeBefore: ${changeBefore}, delay: ${delay}ms)`, async () => {
+				it(`should not ignore changes during resumed compilation (changeBefore: ${changeBefore}, delay: ${delay}ms)`, async () => {
 					// aggregateTimeout must be long enough for this test
 					//  So set-up new watcher and wait when initial compilation is done
 					await new Promise(resolve => {
@@ -138,7 +138,7 @@ describe("WatchSuspend", () => {
 				});
 			}
 
-		it.skip("should not drop changes when suspended", done => {
+		it("should not drop changes when suspended", done => {
 			const aggregateTimeout = 50;
 			// Trigger initial compilation with file2.js (assuming correct)
 			fs.writeFileSync(
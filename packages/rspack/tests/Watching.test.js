@@ -0,0 +1,110 @@
+"use strict";
+
+const path = require("path");
+const fs = require("fs");
+
+describe("Watching", () => {
+	if (process.env.NO_WATCH_TESTS) {
+		it.skip("long running tests excluded", () => {});
+		return;
+	}
+
+	const fixturePath = path.join(
+		__dirname,
+		"fixtures",
+		"temp-watching-" + Date.now()
+	);
+	const aPath = path.join(fixturePath, "a.js");
+	const bPath = path.join(fixturePath, "b.js");
+	const cPath = path.join(fixturePath, "c.js");
+
+	const createCompiler = name => {
+		const { rspack } = require("../");
+		return rspack({
+			mode: "development",
+			context: fixturePath,
+			entry: "./index.js",
+			output: {
+				path: path.join(__dirname, "js/Watching", name),
+				filename: "bundle.js"
+			}
+		});
+	};
+
+	beforeEach(() => {
+		fs.mkdirSync(fixturePath, { recursive: true });
+		fs.writeFileSync(
+			path.join(fixturePath, "index.js"),
+			'require("./a.js"); require("./b.js"); require("./c.js");',
+			"utf-8"
+		);
+		fs.writeFileSync(aPath, "'a'", "utf-8");
+		fs.writeFileSync(bPath, "'b'", "utf-8");
+		fs.writeFileSync(cPath, "'c'", "utf-8");
+	});
+
+	it("should compile the changes during a compilation together", done => {
+		const compiler = createCompiler("union");
+		const outputFile = path.join(__dirname, "js/Watching/union/bundle.js");
+		let written = false;
+		compiler.hooks.compilation.tap("WatchingTest", () => {
+			if (
+				!written &&
+				compiler.modifiedFiles &&
+				compiler.modifiedFiles.has(aPath)
+			) {
+				written = true;
+				// The compilation of the change of a.js is running
+				fs.writeFileSync(bPath, "'b1'", "utf-8");
+				fs.writeFileSync(cPath, "'c1'", "utf-8");
+			}
+		});
+		let initial = true;
+		const watching = compiler.watch({ aggregateTimeout: 50 }, err => {
+			if (err) return done(err);
+			if (initial) {
+				initial = false;
+				fs.writeFileSync(aPath, "'a1'", "utf-8");
+				return;
+			}
+			const modifiedFiles = [...compiler.modifiedFiles];
+			if (!modifiedFiles.includes(bPath) && !modifiedFiles.includes(cPath)) {
+				return;
+			}
+			// A single compilation for both changes rather than one for each
+			expect(modifiedFiles).toEqual(expect.arrayContaining([bPath, cPath]));
+			const content = fs.readFileSync(outputFile, "utf-8");
+			expect(content).toContain("'b1'");
+			expect(content).toContain("'c1'");
+			watching.close(done);
+		});
+	});
+
+	it("should finish the running compilation before closing", done => {
+		const compiler = createCompiler("close");
+		const outputFile = path.join(__dirname, "js/Watching/close/bundle.js");
+		let emitted = false;
+		compiler.hooks.afterEmit.tap("WatchingTest", () => {
+			emitted = true;
+		});
+		compiler.hooks.compilation.tap("WatchingTest", () => {
+			watching.close(() => {
+				expect(emitted).toBe(true);
+				expect(fs.existsSync(outputFile)).toBe(true);
+				done();
+			});
+		});
+		const watching = compiler.watch({}, () => {
+			done(new Error("The compilation after closing should not be reported"));
+		});
+	});
+
+	it("should reject building while watching", done => {
+		const compiler = createCompiler("build");
+		const watching = compiler.watch({}, err => {
+			if (err) return done(err);
+			expect(() => compiler.build(() => {})).toThrow("is watching");
+			watching.close(done);
+		});
+	});
+});
@@ -0,0 +1,5 @@
+import { b } from "./b";
+
+await Promise.resolve();
+
+export const a = "a" + b;
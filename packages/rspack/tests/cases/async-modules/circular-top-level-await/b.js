@@ -0,0 +1,5 @@
+import { a } from "./a";
+
+await Promise.resolve();
+
+export const b = "b" + a;
@@ -0,0 +1,12 @@
+it("should reject the async modules waiting on each other", () => {
+	return import("./a").then(
+		() => {
+			throw new Error("should not resolve the cycle");
+		},
+		err => {
+			expect(err.message).toMatch(
+				/Circular dependency between async modules detected/
+			);
+		}
+	);
+});
@@ -0,0 +1,3 @@
+import value from "./module";
+
+export const doubled = value * 2;
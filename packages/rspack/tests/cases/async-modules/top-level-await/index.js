@@ -0,0 +1,12 @@
+it("should allow to use top-level-await", () => {
+	return import("./reexport").then(({ default: value, other }) => {
+		expect(value).toBe(42);
+		expect(other).toBe(42);
+	});
+});
+
+it("should propagate async-ness to importers", () => {
+	return import("./importer").then(({ doubled }) => {
+		expect(doubled).toBe(84);
+	});
+});
@@ -0,0 +1,3 @@
+await new Promise(r => setTimeout(r, 100));
+
+export default 42;
@@ -0,0 +1,4 @@
+export { default } from "./module";
+import value from "./module";
+
+export const other = value;
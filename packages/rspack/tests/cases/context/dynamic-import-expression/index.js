@@ -0,0 +1,10 @@
+it("should reject the dynamic import of an expression at runtime", async () => {
+	const request = "./a";
+	await expect(import(request)).rejects.toThrow("Cannot find module './a'");
+});
+
+it("should handle the dynamic imports nested in the expression", async () => {
+	await expect(import((await import("./request")).default)).rejects.toThrow(
+		"Cannot find module './missing'"
+	);
+});
@@ -0,0 +1,23 @@
+it("should enumerate matching files in sync mode", function () {
+	var ctx = require.context("./locales", true, /\.js$/, "sync");
+	expect(ctx.keys()).toEqual(["./en.js", "./nested/fr.js", "./zh.js"]);
+	expect(ctx("./en.js")).toBe("en");
+	expect(ctx("./nested/fr.js")).toBe("fr");
+	expect(ctx.resolve("./zh.js")).toBeDefined();
+});
+
+it("should not recurse into sub directories when recursive is false", function () {
+	var ctx = require.context("./locales", false, /\.js$/);
+	expect(ctx.keys()).toEqual(["./en.js", "./zh.js"]);
+});
+
+it("should load matching files on demand in lazy mode", function () {
+	var ctx = require.context("./locales", true, /\.js$/, "lazy");
+	expect(ctx.keys()).toEqual(["./en.js", "./nested/fr.js", "./zh.js"]);
+	return Promise.all([ctx("./zh.js"), ctx("./nested/fr.js")]).then(function (
+		modules
+	) {
+		expect(modules[0]).toBe("zh");
+		expect(modules[1]).toBe("fr");
+	});
+});
@@ -46,3 +46,33 @@ it("should return undefined for unknown property", () => {
 	// expect(() => import.meta.other.other.other).toThrowError();
 	// if (typeof import.meta.other.other.other !== "undefined") require("fail");
 });
+
+it("should be undefined for import.meta.webpackHot without hmr", () => {
+	expect(typeof import.meta.webpackHot).toBe("undefined");
+	expect(import.meta.webpackHot).toBe(undefined);
+	if (import.meta.webpackHot) {
+		import.meta.webpackHot.accept();
+	}
+});
+
+it("should replace bare import.meta with an object", () => {
+	const meta = import.meta;
+	expect(typeof meta).toBe("object");
+	expect(meta.url).toBe(url);
+	expect(meta.webpackHot).toBe(undefined);
+});
+
+it("should replace import.meta in nested functions", () => {
+	function outer() {
+		const url = "shadowed";
+		return function inner(module) {
+			return [url, module, import.meta.url, import.meta];
+		};
+	}
+	const [shadowed, module, metaUrl, meta] = outer()("module");
+	expect(shadowed).toBe("shadowed");
+	expect(module).toBe("module");
+	expect(metaUrl).toBe(url);
+	expect(meta.url).toBe(url);
+	expect(typeof typeof import.meta.url).toBe("string");
+});
@@ -0,0 +1,16 @@
+import { count, increment } from "./reexport";
+import { current } from "./local-reexport";
+import * as star from "./star";
+
+it("should keep live bindings through re-exports", () => {
+	const before = count;
+	increment();
+	expect(count).toBe(before + 1);
+	expect(current).toBe(count);
+	expect(star.count).toBe(count);
+});
+
+it("should not re-export default through star exports", () => {
+	expect(star.own).toBe("own");
+	expect("default" in star).toBe(false);
+});
@@ -0,0 +1,3 @@
+import { count as current } from "./counter";
+
+export { current };
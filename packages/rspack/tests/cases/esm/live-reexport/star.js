@@ -0,0 +1,3 @@
+export * from "./counter";
+
+export const own = "own";
@@ -0,0 +1,17 @@
+import { data } from "./data.json";
+import def from "./data.json";
+import cjs from "./cjs.js";
+
+it("should import the default export from a default-only module", () => {
+	expect(def).toEqual({ data: "ok" });
+});
+
+it("should throw when importing a named export from a default-only module", () => {
+	expect(() => data).toThrow(
+		/Can't import the named export 'data' from default-exporting module '.*data\.json'.+index\.mjs/
+	);
+});
+
+it("should import the exports object as the default export of a CommonJs module", () => {
+	expect(cjs).toEqual({ __esModule: true, default: "default", data: "ok" });
+});
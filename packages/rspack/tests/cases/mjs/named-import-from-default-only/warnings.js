@@ -0,0 +1,5 @@
+module.exports = [
+	[
+		/Can't import the named export 'data' from default-exporting module '.*data\.json'/
+	]
+];
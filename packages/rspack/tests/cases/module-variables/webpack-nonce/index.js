@@ -0,0 +1,6 @@
+it("__webpack_nonce__", function () {
+	expect(__webpack_nonce__).toBeUndefined();
+	__webpack_nonce__ = "nonce";
+	expect(__webpack_nonce__).toBe("nonce");
+	expect(__webpack_require__.nc).toBe("nonce");
+});
@@ -0,0 +1,3 @@
+export function getPublicPath() {
+	return __webpack_public_path__;
+}
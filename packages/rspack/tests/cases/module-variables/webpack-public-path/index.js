@@ -13,3 +13,9 @@ it("__webpack_public_path__ use as local varable", function () {
 	expect(__webpack_require__.p).toBe("/a");
 	expect(__webpack_public_path__).toBe("/test");
 });
+
+it("__webpack_public_path__ assigned in another module", function () {
+	require("./set-public-path");
+	expect(__webpack_public_path__).toBe("/cdn/assets/");
+	expect(require("./get-public-path").getPublicPath()).toBe("/cdn/assets/");
+});
@@ -0,0 +1,2 @@
+__webpack_public_path__ = "/cdn";
+__webpack_public_path__ += "/assets/";
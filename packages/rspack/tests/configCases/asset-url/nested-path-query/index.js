@@ -0,0 +1,17 @@
+const fs = require("fs");
+const path = require("path");
+const currentDir = require("url").pathToFileURL(__dirname);
+
+it("should resolve nested relative paths in URL()", () => {
+	const { href } = new URL("./images/nested/logo.svg", import.meta.url);
+
+	expect(href).toBe(currentDir + "/assets/logo.svg");
+	expect(fs.existsSync(path.join(__dirname, "assets/logo.svg"))).toBe(true);
+});
+
+it("should resolve requests with a query string in URL()", () => {
+	const { href } = new URL("./images/icon.svg?v=1", import.meta.url);
+
+	expect(href).toBe(currentDir + "/assets/icon.svg");
+	expect(fs.existsSync(path.join(__dirname, "assets/icon.svg"))).toBe(true);
+});
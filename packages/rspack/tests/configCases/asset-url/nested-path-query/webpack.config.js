@@ -0,0 +1,9 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "development",
+	target: "node",
+	devtool: false,
+	output: {
+		assetModuleFilename: "assets/[name][ext]"
+	}
+};
@@ -0,0 +1,14 @@
+import encoded from "./logo.svg";
+import custom from "./logo.svg?custom";
+
+it("should url encode the content when the encoding is false", () => {
+	const prefix = "data:image/svg+xml;charset=utf-8,";
+	expect(encoded.startsWith(prefix)).toBe(true);
+	const content = encoded.slice(prefix.length);
+	expect(/[#"'<> ]/.test(content)).toBe(false);
+	expect(decodeURIComponent(content)).toContain('fill="#000"');
+});
+
+it("should use the data url returned by the function", () => {
+	expect(custom).toBe("data:text/plain,logo.svg,true");
+});
@@ -0,0 +1,39 @@
+const path = require("path");
+
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	module: {
+		generator: {
+			"asset/inline": {
+				dataUrl: {
+					mimetype: "image/svg+xml;charset=utf-8"
+				}
+			}
+		},
+		rules: [
+			{
+				test: /\.svg$/,
+				type: "asset/inline",
+				generator: {
+					dataUrl: {
+						encoding: false
+					}
+				}
+			},
+			{
+				test: /\.svg$/,
+				resourceQuery: /custom/,
+				type: "asset/inline",
+				generator: {
+					dataUrl: (content, { filename }) =>
+						`data:text/plain,${path.basename(filename)},${content
+							.toString("utf-8")
+							.includes("<svg")}`
+				}
+			}
+		]
+	}
+};
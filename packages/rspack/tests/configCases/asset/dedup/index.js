@@ -0,0 +1,6 @@
+import A from "./a/logo.svg";
+import B from "./b/icon.svg";
+
+it("should emit identical content of different source files only once", () => {
+	expect(A).toBe(B);
+});
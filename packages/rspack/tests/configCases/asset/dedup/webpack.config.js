@@ -0,0 +1,32 @@
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap(pluginName, compilation => {
+			compilation.hooks.processAssets.tap(pluginName, () => {
+				const svgs = compilation
+					.getAssets()
+					.filter(asset => asset.name.endsWith(".svg"));
+				expect(svgs).toHaveLength(1);
+				expect(svgs[0].info.sourceFilename.sort()).toEqual([
+					"a/logo.svg",
+					"b/icon.svg"
+				]);
+			});
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /\.svg$/,
+				type: "asset/resource"
+			}
+		]
+	},
+	plugins: [new Plugin()]
+};
@@ -0,0 +1,18 @@
+import fs from "fs";
+import path from "path";
+import image from "./images/large.png";
+import font from "./fonts/icons.woff";
+import "./style.css";
+
+it("should use the filename of `Rule.generator`", () => {
+	expect(image).toMatch(/^\/img\/[0-9a-f]+\.png$/);
+	expect(font).toBe("/fonts/icons.woff");
+	expect(fs.existsSync(path.join(__dirname, image))).toBe(true);
+	expect(fs.existsSync(path.join(__dirname, font))).toBe(true);
+});
+
+it("should resolve `auto` public path of `Rule.generator` the same for js and css", () => {
+	const css = fs.readFileSync(path.join(__dirname, "css/main.css"), "utf-8");
+	expect(css).toContain(image);
+	expect(css).toMatch(/url\(["']?\/fonts\/icons\.woff/);
+});
@@ -0,0 +1,26 @@
+module.exports = {
+	context: __dirname,
+	output: {
+		publicPath: "/",
+		cssFilename: "css/[name].css"
+	},
+	module: {
+		rules: [
+			{
+				test: /\.png$/,
+				type: "asset/resource",
+				generator: {
+					filename: "img/[contenthash][ext]"
+				}
+			},
+			{
+				test: /\.woff$/,
+				type: "asset/resource",
+				generator: {
+					filename: "fonts/[name][ext]",
+					publicPath: "auto"
+				}
+			}
+		]
+	}
+};
@@ -0,0 +1,5 @@
+import logo from "./logo.svg";
+
+it("should inline the svg as a base64 data url", () => {
+	expect(logo.startsWith("data:image/svg+xml;base64,")).toBe(true);
+});
@@ -0,0 +1,31 @@
+const fs = require("fs");
+const path = require("path");
+
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const { modules } = stats.toJson({ all: false, modules: true });
+			const logo = modules.find(m => m.name.endsWith("logo.svg"));
+			const content = fs.readFileSync(path.resolve(__dirname, "logo.svg"));
+			const dataUrl = `data:image/svg+xml;base64,${content.toString("base64")}`;
+			// module.exports = "<data url>";
+			expect(logo.size).toBe(`module.exports = "${dataUrl}";`.length);
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /\.svg$/,
+				type: "asset/inline"
+			}
+		]
+	},
+	plugins: [new Plugin()]
+};
@@ -0,0 +1,6 @@
+const fs = require("fs");
+
+it("should add the banner returned by the function", () => {
+	const mainFile = fs.readFileSync(__filename, "utf-8");
+	expect(mainFile.startsWith("/*! main main.js */")).toBeTruthy();
+});
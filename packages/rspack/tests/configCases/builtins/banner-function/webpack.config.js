@@ -0,0 +1,12 @@
+module.exports = {
+	entry: {
+		main: "./index"
+	},
+	builtins: {
+		banner: [
+			{
+				banner: ({ chunk, filename }) => `${chunk.name} ${filename}`
+			}
+		]
+	}
+};
@@ -0,0 +1,51 @@
+it("should fold `??` with the defined left side", () => {
+	const mode = process.env.NODE_ENV ?? require("./dev-mode");
+	expect(mode).toBe("production");
+});
+
+it("should short-circuit the optional chain on the folded nullish object", () => {
+	const hook = (
+		process.env.NODE_ENV === "production"
+			? null
+			: globalThis.__REACT_DEVTOOLS_GLOBAL_HOOK__
+	)?.inject(require("./dev-hook"));
+	expect(hook).toBe(undefined);
+});
+
+it("should fold the `&&` and `||` chains and keep the side effects", () => {
+	let calls = 0;
+	const check =
+		(calls++, process.env.NODE_ENV !== "production") && require("./dev-check");
+	expect(check).toBe(false);
+	expect(calls).toBe(1);
+
+	const flag =
+		globalThis.__FLAG__ &&
+		process.env.NODE_ENV !== "production" &&
+		require("./dev-flag");
+	expect(flag).toBe(undefined);
+
+	const prod = process.env.NODE_ENV === "production" || require("./dev-prod");
+	expect(prod).toBe(true);
+});
+
+it("should fold the logical assignments", () => {
+	const options = {};
+	const dev =
+		(options.dev ||= process.env.NODE_ENV === "production") ||
+		require("./dev-options");
+	expect(dev).toBe(true);
+	expect(options.dev).toBe(true);
+
+	const verbose =
+		(options.verbose &&= process.env.NODE_ENV !== "production") &&
+		require("./dev-verbose");
+	expect(verbose).toBe(undefined);
+});
+
+it("should not create the dependencies of the dev only branches", () => {
+	const devModules = __STATS__.modules
+		.map(m => m.name)
+		.filter(name => name.includes("dev-"));
+	expect(devModules).toEqual([]);
+});
@@ -0,0 +1,7 @@
+module.exports = {
+	builtins: {
+		define: {
+			"process.env.NODE_ENV": '"production"'
+		}
+	}
+};
@@ -178,3 +178,30 @@ it("should builtins define works", () => {
 	// assert.equal(wurst).toBe(unde);
 	// assert.equal(suppe).toBe(wurst);
 });
+
+it("should define member expression chains and typeof", () => {
+	expect(process.env.NODE_ENV).toBe("production");
+	expect(process.env.NODE_ENV.length).toBe(10);
+	expect(typeof process.env.NODE_ENV).toBe("string");
+	expect(typeof P1.P2.P3).toBe("number");
+	expect(typeof OBJECT).toBe("object");
+	expect(typeof UNDEFINED).toBe("undefined");
+
+	expect(DEFINES).toEqual({ flags: { beta: true }, name: "app" });
+	expect(DEFINES.flags).toEqual({ beta: true });
+	expect(DEFINES.flags.beta).toBe(true);
+	expect(typeof DEFINES.flags.beta).toBe("boolean");
+	expect(DEFINES.name).toBe("app");
+});
+
+it("should remove dead branches of defined conditions", () => {
+	if (process.env.NODE_ENV !== "production") {
+		require("./dead-branch-should-be-removed");
+	}
+	if (!DEFINES.flags.beta) {
+		require("./dead-branch-should-be-removed");
+	}
+	if (typeof DEFINES.flags !== "object") {
+		require("./dead-branch-should-be-removed");
+	}
+});
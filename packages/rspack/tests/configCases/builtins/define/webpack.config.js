@@ -44,7 +44,9 @@ module.exports = {
 			IN_BLOCK: "SHOULD_BE_CONVERTED_IN_UNDEFINED_BLOCK",
 			"M1.M2.M3": "{}",
 			SHOULD_CONVERTED: "205",
-			CONVERTED_TO_MEMBER: "A1.A2.A3"
+			CONVERTED_TO_MEMBER: "A1.A2.A3",
+			"process.env.NODE_ENV": '"production"',
+			DEFINES: '{"flags": {"beta": true}, name: "app"}'
 		}
 	}
 };
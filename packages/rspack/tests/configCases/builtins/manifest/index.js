@@ -0,0 +1,22 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should emit the manifest of the entrypoints and async chunks", async () => {
+	await import(/* webpackChunkName: "async" */ "./async");
+	const manifest = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "assets-manifest.json"), "utf-8")
+	);
+	expect(manifest.entrypoints.main).toEqual({ js: ["main.js"], css: [] });
+	expect(manifest.entrypoints.other.js).toContain("other.js");
+	expect(Object.values(manifest.async)).toContainEqual(["async.chunk.js"]);
+	expect(manifest.publicPath).toBe("/static/");
+});
+
+it("should list the chunk loaded initially and on demand in both", async () => {
+	await import(/* webpackChunkName: "shared" */ "./shared");
+	const manifest = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "assets-manifest.json"), "utf-8")
+	);
+	expect(manifest.entrypoints.other.js).toEqual(["shared.js", "other.js"]);
+	expect(Object.values(manifest.async)).toContainEqual(["shared.js"]);
+});
@@ -0,0 +1,28 @@
+module.exports = {
+	target: "node",
+	entry: {
+		main: "./index",
+		other: "./other"
+	},
+	output: {
+		publicPath: "/static/",
+		chunkFilename: "[name].chunk.js"
+	},
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				shared: {
+					chunks: "all",
+					test: /shared\.js$/,
+					name: "shared",
+					enforce: true
+				}
+			}
+		}
+	},
+	builtins: {
+		manifest: {
+			filename: "assets-manifest.json"
+		}
+	}
+};
@@ -64,6 +64,22 @@ it("should not provide for function defined variable", function () {
 	test({});
 });
 
+it("should only provide the root of a longer member chain", function () {
+	expect(process.env.FOO).toBe(undefined);
+	expect(process.browser).toBe(true);
+});
+
+it("should provide a module for an object shorthand", function () {
+	const obj = { aaa };
+	expect(obj.aaa).toBe("aaa");
+});
+
+it("should not provide a shadowed variable", function () {
+	const aaa = "local";
+	expect(aaa).toBe("local");
+	expect({ aaa }.aaa).toBe("local");
+});
+
 // TODO: Add support for these cases
 // it("should provide a module for a nested var within a IIFE's this", function () {
 // 	(function () {
@@ -0,0 +1 @@
+global.__INCLUDED__ = true;
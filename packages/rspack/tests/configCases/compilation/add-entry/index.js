@@ -0,0 +1,10 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should emit the entry added in the make hook", () => {
+	expect(fs.existsSync(path.join(__dirname, "added.js"))).toBe(true);
+});
+
+it("should not execute the included module on startup", () => {
+	expect(global.__INCLUDED__).toBeUndefined();
+});
@@ -0,0 +1,36 @@
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.make.tapPromise(pluginName, async compilation => {
+			const addEntry = (method, request, name) =>
+				new Promise((resolve, reject) => {
+					compilation[method](__dirname, request, name, (err, module) => {
+						if (err) {
+							reject(err);
+						} else {
+							resolve(module);
+						}
+					});
+				});
+
+			const [added, included] = await Promise.all([
+				addEntry("addEntry", "./added.js", "added"),
+				addEntry("addInclude", "./included.js", "main")
+			]);
+			expect(added.resource.endsWith("added.js")).toBe(true);
+			expect(included.resource.endsWith("included.js")).toBe(true);
+		});
+	}
+}
+
+/**@type {import('@rspack/cli').Configuration}*/
+module.exports = {
+	entry: {
+		main: "./index.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	plugins: [new Plugin()]
+};
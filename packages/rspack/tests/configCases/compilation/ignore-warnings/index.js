@@ -0,0 +1,8 @@
+it("should still build the modules with ignored warnings", () => {
+	const request = "./module";
+	expect(() => require(request)).toThrow("Cannot find module 'request'");
+	expect(() => require.resolve(request)).toThrow(
+		"Cannot resolve a non-literal request of require.resolve()"
+	);
+	expect(require("./module")).toBe("module");
+});
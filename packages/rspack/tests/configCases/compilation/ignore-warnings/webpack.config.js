@@ -0,0 +1,24 @@
+const assert = require("assert");
+
+class AssertWarningsPlugin {
+	apply(compiler) {
+		compiler.hooks.done.tap("AssertWarningsPlugin", stats => {
+			const { warnings } = stats.toJson({ warnings: true });
+			assert.deepStrictEqual(warnings, []);
+			assert(!stats.hasWarnings());
+		});
+	}
+}
+
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	target: "node",
+	ignoreWarnings: [
+		/the request of a dependency is an expression/,
+		{ module: /index\.js/, message: /can't be statically analyzed/ }
+	],
+	plugins: [new AssertWarningsPlugin()]
+};
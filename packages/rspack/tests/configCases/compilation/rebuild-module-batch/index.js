@@ -0,0 +1,7 @@
+import { a } from "./a";
+import { b } from "./b";
+
+it("should rebuild the modules pushed in the same tick together", () => {
+	expect(a).toEqual(2);
+	expect(b).toEqual(1);
+});
@@ -0,0 +1,54 @@
+const pluginName = "plugin";
+
+const rebuildModule = (compilation, module) =>
+	new Promise((res, rej) => {
+		compilation.rebuildModule(module, function (err, m) {
+			if (err) {
+				rej(err);
+			} else {
+				res(m);
+			}
+		});
+	});
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap(pluginName, compilation => {
+			compilation.hooks.finishModules.tapPromise(pluginName, async modules => {
+				const oldA = modules.find(item => item.resource.endsWith("a.js"));
+				const oldB = modules.find(item => item.resource.endsWith("b.js"));
+				if (!oldA || !oldB) {
+					throw new Error("module not found");
+				}
+
+				const [newA, newB] = await Promise.all([
+					rebuildModule(compilation, oldA),
+					rebuildModule(compilation, oldB)
+				]);
+
+				expect(newA.originalSource.source.toString().includes("a = 2")).toBe(
+					true
+				);
+				// The hash of `b.js` is unchanged, so the module is left intact
+				expect(newB).toBe(oldB);
+			});
+		});
+	}
+}
+
+/**@type {import('@rspack/cli').Configuration}*/
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /a\.js$/,
+				use: [
+					{
+						loader: "./loader"
+					}
+				]
+			}
+		]
+	},
+	plugins: [new Plugin()]
+};
@@ -0,0 +1 @@
+module.exports = [[/Failed to run the loader again/]];
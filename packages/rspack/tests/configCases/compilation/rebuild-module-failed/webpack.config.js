@@ -0,0 +1,40 @@
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap(pluginName, compilation => {
+			compilation.hooks.finishModules.tapPromise(pluginName, async modules => {
+				const oldModule = modules.find(item => item.resource.endsWith("a.js"));
+				if (!oldModule) {
+					throw new Error("module not found");
+				}
+
+				const [err, newModule] = await new Promise(res => {
+					compilation.rebuildModule(oldModule, function (err, m) {
+						res([err, m]);
+					});
+				});
+
+				expect(err.message).toMatch(/Failed to run the loader again/);
+				expect(newModule).toBe(oldModule);
+			});
+		});
+	}
+}
+
+/**@type {import('@rspack/cli').Configuration}*/
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /a\.js$/,
+				use: [
+					{
+						loader: "./loader"
+					}
+				]
+			}
+		]
+	},
+	plugins: [new Plugin()]
+};
@@ -0,0 +1,41 @@
+let warnSpy;
+
+beforeEach(() => {
+	warnSpy = jest.spyOn(console, "warn").mockImplementation(() => {});
+});
+
+afterEach(() => {
+	warnSpy.mockRestore();
+});
+
+it("should get the modules from the remote container", async function () {
+	const init = jest.fn();
+	window.app2 = {
+		init,
+		get: function (request) {
+			return Promise.resolve(function () {
+				return { request: request };
+			});
+		}
+	};
+
+	const button = await import("app2/Button");
+	expect(button.request).toBe("./Button");
+	expect(init).toHaveBeenCalledTimes(1);
+	expect(init).toHaveBeenCalledWith({}, expect.any(Array));
+
+	// The container is initialized only once
+	const input = await import("app2/components/Input");
+	expect(input.request).toBe("./components/Input");
+	expect(init).toHaveBeenCalledTimes(1);
+});
+
+it("should reject when the remote container is unreachable", async function () {
+	const error = await import("app3/Button").catch(error => error);
+	expect(error.name).toBe("ScriptExternalLoadError");
+	expect(error.type).toBe("missing");
+	expect(error.request).toBe("https://cdn.example.com/app3/remoteEntry.js");
+	expect(error.message).toContain(
+		'while loading "./Button" from webpack/container/reference/app3'
+	);
+});
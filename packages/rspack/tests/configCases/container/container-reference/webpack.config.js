@@ -0,0 +1,13 @@
+module.exports = {
+	target: "web",
+	builtins: {
+		containerReference: {
+			remotes: {
+				app2: "app2@https://cdn.example.com/app2/remoteEntry.js",
+				app3: {
+					external: "app3@https://cdn.example.com/app3/remoteEntry.js"
+				}
+			}
+		}
+	}
+};
@@ -0,0 +1,18 @@
+import "./style.css";
+const fs = require("fs");
+const path = require("path");
+
+it("should handle @import and url() in css", () => {
+	const css = fs.readFileSync(path.resolve(__dirname, "main.css"), "utf-8");
+	expect(css).not.toContain("@import");
+	expect(css.match(/\.imported/g)).toHaveLength(1);
+	expect(css).toMatch(/\.a {\s*background: url\(\/assets\/[0-9a-f]+\.png\);/);
+	expect(css).toContain(
+		"background: url(http://example.com/a.png), url(//example.com/b.png);"
+	);
+	expect(css).toMatch(
+		/image-set\(url\(\/assets\/[0-9a-f]+\.png\) 1x, url\(\/assets\/[0-9a-f]+\.png\) 2x\)/
+	);
+	expect(css).toContain('background: url("{{ cdn }}/a.png");');
+	expect(css).toMatch(/src: url\(\/assets\/[0-9a-f]+\.woff2\?#iefix\)/);
+});
@@ -0,0 +1,13 @@
+module.exports = {
+	output: {
+		publicPath: "/assets/"
+	},
+	module: {
+		rules: [
+			{
+				test: /\.(png|woff2)$/,
+				type: "asset/resource"
+			}
+		]
+	}
+};
@@ -0,0 +1,6 @@
+const shared = require("./shared");
+
+it("should load the entry which is depended on", () => {
+	expect(shared.value).toBe("shared-value");
+	global.sharedFromA = shared;
+});
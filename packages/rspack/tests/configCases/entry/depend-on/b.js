@@ -0,0 +1,9 @@
+const fs = require("fs");
+const shared = require("./shared");
+
+it("should share modules and runtime with the entry it depends on", () => {
+	expect(shared).toBe(global.sharedFromA);
+	delete global.sharedFromA;
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content).not.toContain("shared-" + "value");
+});
@@ -0,0 +1 @@
+module.exports = { value: "shared-value" };
@@ -0,0 +1,12 @@
+module.exports = {
+	entry: {
+		a: "./a.js",
+		b: {
+			import: "./b.js",
+			dependOn: "a"
+		}
+	},
+	output: {
+		filename: "[name].js"
+	}
+};
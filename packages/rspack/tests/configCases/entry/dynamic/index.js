@@ -0,0 +1,3 @@
+it("should load the entry returned by an async function", () => {
+	expect(__filename).toMatch(/main\.js$/);
+});
@@ -0,0 +1,5 @@
+module.exports = {
+	entry: async () => ({
+		main: "./index.js"
+	})
+};
@@ -0,0 +1 @@
+module.exports = [[/Entrypoints have a circular dependOn/]];
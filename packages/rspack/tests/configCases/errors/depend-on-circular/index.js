@@ -0,0 +1,3 @@
+it("should build the entries which are not in the dependOn cycle", () => {
+	expect(__filename).toMatch(/main\.js$/);
+});
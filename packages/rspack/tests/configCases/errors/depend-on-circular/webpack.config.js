@@ -0,0 +1,46 @@
+const assert = require("assert");
+
+class AssertCircularDependOnPlugin {
+	apply(compiler) {
+		compiler.hooks.done.tap("AssertCircularDependOnPlugin", stats => {
+			const { errors, entrypoints } = stats.toJson({
+				all: false,
+				errors: true,
+				entrypoints: true
+			});
+			// Both entries in the cycle are reported, and built as if they didn't depend on others
+			assert.strictEqual(errors.length, 2);
+			assert.match(
+				errors[0].message,
+				/Entrypoints have a circular dependOn: a -> b -> a/
+			);
+			assert.match(
+				errors[1].message,
+				/Entrypoints have a circular dependOn: b -> a -> b/
+			);
+			assert.ok(entrypoints.a);
+			assert.ok(entrypoints.b);
+		});
+	}
+}
+
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		a: {
+			import: "./a.js",
+			dependOn: "b"
+		},
+		b: {
+			import: "./b.js",
+			dependOn: "a"
+		}
+	},
+	output: {
+		filename: "[name].js"
+	},
+	plugins: [new AssertCircularDependOnPlugin()]
+};
@@ -0,0 +1,3 @@
+module.exports = [
+	[/Failed after the dependencies are collected[\s\S]*\(from: .*my-loader\.js\)/]
+];
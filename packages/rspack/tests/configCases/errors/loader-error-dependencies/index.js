@@ -0,0 +1,9 @@
+it("should report the loader error", () => {
+	let errored = false;
+	try {
+		require("./lib");
+	} catch (e) {
+		errored = true;
+	}
+	expect(errored).toBeTruthy();
+});
@@ -0,0 +1,6 @@
+const path = require("path");
+
+module.exports = function (content) {
+	this.addDependency(path.resolve(__dirname, "imported.txt"));
+	throw new Error("Failed after the dependencies are collected");
+};
@@ -0,0 +1,32 @@
+const path = require("path");
+const assert = require("assert").strict;
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const { fileDependencies } = stats.compilation;
+			assert(fileDependencies.has(path.resolve(__dirname, "imported.txt")));
+		});
+	}
+}
+
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: path.join(__dirname, "lib.js"),
+				use: [
+					{
+						loader: "./my-loader.js"
+					}
+				]
+			}
+		]
+	},
+	plugins: [new Plugin()]
+};
@@ -14,6 +14,18 @@ it("parse error module should have 'throw error'", () => {
 		output
 	)[1];
 	let recCode = /".\/recoverable.js":.*\n(.*)/.exec(output)[1];
-	expect(nonRecCode.includes("throw new Error")).toBe(true);
-	expect(recCode.includes("throw new Error")).toBe(true);
+	expect(nonRecCode.includes("new Error")).toBe(true);
+	expect(recCode.includes("new Error")).toBe(true);
+});
+
+it("parse error module should throw the build error object", () => {
+	let error;
+	try {
+		require("./non-recoverable.js");
+	} catch (e) {
+		error = e;
+	}
+	expect(error.__rspack_build_error__).toBe(true);
+	expect(error.module).toBe("./non-recoverable.js");
+	expect(error.details.join("\n")).toBe(error.message);
 });
@@ -0,0 +1 @@
+import "./not-exist";
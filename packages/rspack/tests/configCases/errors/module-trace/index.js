@@ -0,0 +1,9 @@
+it("should report the missing module", () => {
+	let errored = false;
+	try {
+		require("./a");
+	} catch (e) {
+		errored = true;
+	}
+	expect(errored).toBeTruthy();
+});
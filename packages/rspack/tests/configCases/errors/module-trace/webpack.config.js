@@ -0,0 +1,32 @@
+const assert = require("assert");
+
+class AssertModuleTracePlugin {
+	apply(compiler) {
+		compiler.hooks.done.tap("AssertModuleTracePlugin", stats => {
+			const [error] = stats.toJson({
+				all: false,
+				errors: true,
+				moduleTrace: true
+			}).errors;
+			assert.strictEqual(error.moduleName, "./b.js");
+			assert.deepStrictEqual(
+				error.moduleTrace.map(item => item.moduleName),
+				["./a.js", "./index.js"]
+			);
+			assert.strictEqual(error.moduleTrace[0].loc, "1:0-13");
+
+			const [plain] = stats.toJson({ all: false, errors: true }).errors;
+			assert.strictEqual(plain.moduleTrace, undefined);
+		});
+	}
+}
+
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	stats: {
+		moduleTrace: true
+	},
+	plugins: [new AssertModuleTracePlugin()]
+};
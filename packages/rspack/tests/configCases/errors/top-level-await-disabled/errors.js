@@ -0,0 +1 @@
+module.exports = [[/The top-level-await experiment is not enabled/]];
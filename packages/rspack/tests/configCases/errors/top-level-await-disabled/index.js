@@ -0,0 +1,11 @@
+it("should report the top-level await used without the experiment", () => {
+	// The chunk is not wrapped as an async module, so it fails to load
+	return import("./tla").then(
+		() => {
+			throw new Error("should not load the module");
+		},
+		err => {
+			expect(err).toBeTruthy();
+		}
+	);
+});
@@ -0,0 +1,8 @@
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	experiments: {
+		topLevelAwait: false
+	}
+};
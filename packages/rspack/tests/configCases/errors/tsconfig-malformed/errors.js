@@ -0,0 +1 @@
+module.exports = [[/Failed to parse the tsconfig .*tsconfig\.json/]];
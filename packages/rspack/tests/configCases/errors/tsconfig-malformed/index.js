@@ -0,0 +1,3 @@
+it("should report the malformed tsconfig", () => {
+	expect(() => require("./lib")).toThrow();
+});
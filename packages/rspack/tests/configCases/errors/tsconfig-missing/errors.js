@@ -0,0 +1 @@
+module.exports = [[/Can't read the tsconfig .*missing/]];
@@ -0,0 +1,3 @@
+it("should report the missing tsconfig", () => {
+	expect(() => require("./lib")).toThrow();
+});
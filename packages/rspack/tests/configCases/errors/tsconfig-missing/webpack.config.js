@@ -0,0 +1,16 @@
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /lib\.js$/,
+				resolve: {
+					tsconfig: "./missing/tsconfig.json"
+				}
+			}
+		]
+	}
+};
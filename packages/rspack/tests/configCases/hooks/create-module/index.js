@@ -0,0 +1,6 @@
+import route from "./route";
+
+it("should match the rules with the resource data changed by createModule", () => {
+	expect(route.query).toBe("?compiled");
+	expect(route.settings).toEqual({ generated: true });
+});
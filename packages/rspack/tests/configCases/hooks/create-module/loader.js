@@ -0,0 +1,6 @@
+module.exports = function () {
+	return `module.exports = ${JSON.stringify({
+		query: this.resourceQuery,
+		settings: this.moduleSettings
+	})};`;
+};
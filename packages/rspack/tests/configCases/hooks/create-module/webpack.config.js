@@ -0,0 +1,33 @@
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap(
+			pluginName,
+			(compilation, { normalModuleFactory }) => {
+				normalModuleFactory.hooks.createModule.tap(pluginName, createData => {
+					const { resourceData } = createData;
+					if (resourceData.path.endsWith("route.js")) {
+						resourceData.query = "?compiled";
+						resourceData.resource = `${resourceData.path}?compiled`;
+						createData.settings.generated = true;
+					}
+				});
+			}
+		);
+	}
+}
+
+/**@type {import('@rspack/cli').Configuration}*/
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				resourceQuery: /compiled/,
+				use: [{ loader: "./loader.js" }]
+			}
+		]
+	},
+	plugins: [new Plugin()]
+};
@@ -0,0 +1,7 @@
+it("should report the infrastructure logs of the Rust side to the JS hooks", () => {
+	expect(infrastructureLogs).toContainEqual({
+		name: "rspack.SplitChunksPlugin",
+		type: "warn",
+		args: ["minSize(100) should not be bigger than maxSize(50)"]
+	});
+});
@@ -0,0 +1,7 @@
+const logs = require("./logs");
+
+module.exports = {
+	moduleScope(scope) {
+		scope.infrastructureLogs = logs;
+	}
+};
@@ -0,0 +1,24 @@
+const logs = require("./logs");
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.infrastructureLog.tap("Plugin", (name, type, args) => {
+			if (name === "rspack.SplitChunksPlugin") {
+				logs.push({ name, type, args });
+				return true;
+			}
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	optimization: {
+		splitChunks: {
+			chunks: "all",
+			minSize: 100,
+			maxSize: 50
+		}
+	},
+	plugins: [new Plugin()]
+};
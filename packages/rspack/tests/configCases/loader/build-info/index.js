@@ -0,0 +1,5 @@
+import { lib } from "./lib";
+
+it("should build the module with the build info of the loaders", () => {
+	expect(lib).toBe("lib");
+});
@@ -0,0 +1,5 @@
+module.exports = function (content) {
+	// set by the previous loader
+	this.setBuildInfo("scoped", this._module.buildInfo.descriptor.scoped);
+	return content;
+};
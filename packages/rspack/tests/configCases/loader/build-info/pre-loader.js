@@ -0,0 +1,5 @@
+module.exports = function (content) {
+	this.setBuildInfo("descriptor", { scoped: true }, { affectsHash: true });
+	this.setBuildInfo("timestamp", Date.now());
+	return content;
+};
@@ -0,0 +1,42 @@
+const path = require("path");
+const assert = require("assert").strict;
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap(pluginName, compilation => {
+			compilation.hooks.processAssets.tap(pluginName, () => {
+				const lib = compilation.modules.find(
+					module => module.resource === path.resolve(__dirname, "lib.js")
+				);
+				assert.deepEqual(lib.buildInfo.descriptor, { scoped: true });
+				assert.equal(lib.buildInfo.scoped, true);
+				assert.equal(typeof lib.buildInfo.timestamp, "number");
+				assert.equal(lib.buildMeta.esm, true);
+			});
+		});
+	}
+}
+
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: path.join(__dirname, "lib.js"),
+				use: [
+					{
+						loader: "./my-loader.js"
+					},
+					{
+						loader: "./pre-loader.js"
+					}
+				]
+			}
+		]
+	},
+	plugins: [new Plugin()]
+};
@@ -0,0 +1,5 @@
+import { lib } from "./lib";
+
+it("should build the module with the glob dependencies", () => {
+	expect(lib).toBe("lib");
+});
@@ -0,0 +1,6 @@
+module.exports = function (content) {
+	this.addDependency("./relative.txt");
+	this.addDependency("./styles/*.scss");
+	this.addGlobDependency("./styles/*.scss");
+	return content;
+};
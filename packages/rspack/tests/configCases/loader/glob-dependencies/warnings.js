@@ -0,0 +1 @@
+module.exports = [[/\.\/styles\/\*\.scss looks like a glob pattern/]];
@@ -0,0 +1,35 @@
+const path = require("path");
+const assert = require("assert").strict;
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const { fileDependencies, contextDependencies } = stats.compilation;
+			assert(fileDependencies.has(path.resolve(__dirname, "relative.txt")));
+			assert(fileDependencies.has(path.resolve(__dirname, "styles/a.scss")));
+			assert(fileDependencies.has(path.resolve(__dirname, "styles/b.scss")));
+			assert(contextDependencies.has(path.resolve(__dirname, "styles")));
+		});
+	}
+}
+
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: path.join(__dirname, "lib.js"),
+				use: [
+					{
+						loader: "./my-loader.js"
+					}
+				]
+			}
+		]
+	},
+	plugins: [new Plugin()]
+};
@@ -0,0 +1,12 @@
+interface A {
+	a: number;
+}
+
+const b: A = { a: 123 };
+const getA = (value: A) => value.a;
+
+it("should run the chained builtin loaders in reverse order", () => {
+	expect(getA(b)).toBe(123);
+	// Transformed to es5 by the first loader after the types are stripped
+	expect(getA.toString()).not.toContain("=>");
+});
@@ -0,0 +1,35 @@
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /\.js$/,
+				use: [
+					{
+						loader: "builtin:swc-loader",
+						options: {
+							jsc: {
+								parser: {
+									syntax: "ecmascript"
+								},
+								target: "es5"
+							}
+						}
+					},
+					{
+						loader: "builtin:swc-loader",
+						options: {
+							jsc: {
+								parser: {
+									syntax: "typescript"
+								},
+								target: "esnext"
+							}
+						}
+					}
+				],
+				type: "javascript/auto"
+			}
+		]
+	}
+};
@@ -0,0 +1,4 @@
+it("should pass the origin of each loader to the loader runner", () => {
+	const origins = require("./origin-loader.js!./lib");
+	expect(origins).toEqual(["post", "inline", "normal", "pre"]);
+});
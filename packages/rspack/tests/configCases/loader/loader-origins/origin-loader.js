@@ -0,0 +1,8 @@
+module.exports = function (source) {
+	// The last loader in the normal stage
+	if (this.loaderIndex === 0) {
+		const origins = this.loaders.map(loader => loader.origin);
+		return `module.exports = ${JSON.stringify(origins)}`;
+	}
+	return source;
+};
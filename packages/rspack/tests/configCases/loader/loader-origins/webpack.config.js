@@ -0,0 +1,24 @@
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /lib\.js$/,
+				use: ["./origin-loader.js"]
+			},
+			{
+				test: /lib\.js$/,
+				enforce: "pre",
+				use: ["./origin-loader.js"]
+			},
+			{
+				test: /lib\.js$/,
+				enforce: "post",
+				use: ["./origin-loader.js"]
+			}
+		]
+	}
+};
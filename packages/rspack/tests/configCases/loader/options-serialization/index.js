@@ -0,0 +1,21 @@
+it("should keep the string options as strings", () => {
+	expect(require("./string-number")).toEqual({ type: "string", options: "123" });
+	expect(require("./string-boolean")).toEqual({
+		type: "string",
+		options: "true"
+	});
+});
+
+it("should pass the object options", () => {
+	expect(require("./object")).toEqual({
+		type: "object",
+		options: { value: 1, nested: { list: [1, "2"] } }
+	});
+});
+
+it("should pass the options referenced by ident", () => {
+	expect(require("./ident")).toEqual({
+		type: "object",
+		options: { value: "ident" }
+	});
+});
@@ -0,0 +1,4 @@
+module.exports = function () {
+	const options = this.getOptions();
+	return `module.exports = ${JSON.stringify({ type: typeof options, options })};`;
+};
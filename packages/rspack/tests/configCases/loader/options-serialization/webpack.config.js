@@ -0,0 +1,35 @@
+/** @type {import('@rspack/core').RspackOptions} */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /string-number\.js$/,
+				use: [{ loader: "./loader.js", options: "123" }]
+			},
+			{
+				test: /string-boolean\.js$/,
+				use: [{ loader: "./loader.js", options: "true" }]
+			},
+			{
+				test: /object\.js$/,
+				use: [
+					{
+						loader: "./loader.js",
+						options: { value: 1, nested: { list: [1, "2"] } }
+					}
+				]
+			},
+			{
+				test: /ident\.js$/,
+				use: [
+					{
+						loader: "./loader.js",
+						ident: "custom-ident",
+						options: { value: "ident" }
+					}
+				]
+			}
+		]
+	}
+};
@@ -0,0 +1,8 @@
+exports.pitch = function (remainingRequest, previousRequest, data) {
+	data.value = `${this.getOptions().name}-pitched`;
+};
+
+module.exports = function (source) {
+	const { name } = this.getOptions();
+	return `${source}|${name}:${this.data.value}`;
+};
@@ -0,0 +1,3 @@
+module.exports = function (source) {
+	return `module.exports = ${JSON.stringify(source)}`;
+};
@@ -0,0 +1,4 @@
+it("should pass the data of pitching stage to the normal stage of each loader", () => {
+	const result = require("./lib");
+	expect(result).toEqual("lib|first:first-pitched|second:second-pitched");
+});
@@ -0,0 +1,24 @@
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /lib\.js$/,
+				use: [
+					"./export-loader.js",
+					{
+						loader: "./data-loader.js",
+						options: { name: "second" }
+					},
+					{
+						loader: "./data-loader.js",
+						options: { name: "first" }
+					}
+				]
+			}
+		]
+	}
+};
@@ -0,0 +1 @@
+module.exports = "__VALUE__";
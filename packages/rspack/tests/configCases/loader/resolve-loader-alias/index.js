@@ -0,0 +1,3 @@
+it("should resolve loaders with resolveLoader.alias", () => {
+	expect(require("./a")).toBe("my-loader");
+});
@@ -0,0 +1,3 @@
+module.exports = function (content) {
+	return content.replace("__VALUE__", "my-loader");
+};
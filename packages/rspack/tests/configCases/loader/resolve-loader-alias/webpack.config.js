@@ -0,0 +1,21 @@
+const path = require("path");
+
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	resolveLoader: {
+		alias: {
+			"my-loader": "./tools/my-loader.js"
+		}
+	},
+	module: {
+		rules: [
+			{
+				test: path.join(__dirname, "a.js"),
+				use: "my-loader"
+			}
+		]
+	}
+};
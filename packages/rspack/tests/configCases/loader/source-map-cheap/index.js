@@ -0,0 +1,6 @@
+it("should pass the upstream source map to a loader asking for it under a cheap devtool", () => {
+	let result = require("./a");
+	expect(result.sourceMap).toBe(false);
+	expect(result.sources).toHaveLength(1);
+	expect(result.sources[0]).toMatch(/a\.js$/);
+});
@@ -0,0 +1,10 @@
+module.exports = function (content, sourceMap) {
+	const map = typeof sourceMap === "string" ? JSON.parse(sourceMap) : sourceMap;
+	this.callback(
+		null,
+		`module.exports = ${JSON.stringify({
+			sourceMap: this.sourceMap,
+			sources: map ? map.sources : null
+		})}`
+	);
+};
@@ -0,0 +1,30 @@
+const path = require("path");
+
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	devtool: "cheap-source-map",
+	module: {
+		rules: [
+			{
+				test: path.join(__dirname, "a.js"),
+				use: [
+					{ loader: "./loader.js", options: { sourceMap: true } },
+					{
+						loader: "builtin:swc-loader",
+						options: {
+							sourceMaps: true,
+							jsc: {
+								parser: {
+									syntax: "ecmascript"
+								}
+							}
+						}
+					}
+				]
+			}
+		]
+	}
+};
@@ -0,0 +1,5 @@
+import * as wasm from "./wasm.wat";
+
+it("should provide the exports of the WebAssembly module", function () {
+	expect(wasm.add(wasm.getNumber(), 2)).toEqual(42);
+});
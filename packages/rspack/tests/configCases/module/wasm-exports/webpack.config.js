@@ -0,0 +1,46 @@
+const path = require("path");
+const assert = require("assert").strict;
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap(pluginName, compilation => {
+			compilation.hooks.processAssets.tap(pluginName, assets => {
+				const wasm = compilation.modules.find(
+					module => module.resource === path.resolve(__dirname, "wasm.wat")
+				);
+				assert.equal(wasm.buildMeta.exportsType, "namespace");
+				assert.deepEqual(wasm.buildMeta.providedExports, ["add", "getNumber"]);
+				const wasmAssets = Object.keys(assets).filter(name =>
+					name.endsWith(".module.wasm")
+				);
+				assert.equal(wasmAssets.length, 1);
+				assert.match(wasmAssets[0], /^wasm\/[0-9a-f]{8}\.module\.wasm$/);
+			});
+		});
+	}
+}
+
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	output: {
+		wasmLoading: "async-node",
+		webassemblyModuleFilename: "wasm/[hash:8].module.wasm"
+	},
+	module: {
+		rules: [
+			{
+				test: /\.wat$/,
+				use: "wast-loader",
+				type: "webassembly/async"
+			}
+		]
+	},
+	experiments: {
+		asyncWebAssembly: true
+	},
+	plugins: [new Plugin()]
+};
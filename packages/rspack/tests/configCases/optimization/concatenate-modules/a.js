@@ -0,0 +1,7 @@
+import { b } from "./b";
+
+export const value = b + 1;
+
+export default function () {
+	return "a";
+}
@@ -0,0 +1,4 @@
+const value = "b";
+
+export const b = 1;
+export { value };
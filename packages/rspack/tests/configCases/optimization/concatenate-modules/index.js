@@ -0,0 +1,11 @@
+import getA, { value } from "./a";
+import { b, value as bValue } from "./b";
+import { d } from "./d";
+
+it("should concatenate the esm modules", () => {
+	expect(getA()).toBe("a");
+	expect(value).toBe(2);
+	expect(b).toBe(1);
+	expect(bValue).toBe("b");
+	expect(d).toBe("d");
+});
@@ -0,0 +1,27 @@
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const { modules } = stats.toJson({
+				all: false,
+				modules: true,
+				optimizationBailout: true
+			});
+			const a = modules.find(m => m.name === "./a.js");
+			expect(a.chunks).toEqual([]);
+			const d = modules.find(m => m.name === "./d.js");
+			expect(d.optimizationBailout).toContain(
+				"ModuleConcatenation bailout: Module uses eval()"
+			);
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	optimization: {
+		concatenateModules: true
+	},
+	plugins: [new Plugin()]
+};
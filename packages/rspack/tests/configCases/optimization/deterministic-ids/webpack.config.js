@@ -0,0 +1,29 @@
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const { modules, chunks } = stats.toJson({
+				all: false,
+				modules: true,
+				chunks: true
+			});
+			// The shortest unique prefixes of the hex digests, at least 3 characters
+			const moduleIds = modules.map(m => m.id);
+			expect(new Set(moduleIds).size).toBe(moduleIds.length);
+			moduleIds.forEach(id => expect(id).toMatch(/^[0-9a-f]{3,}$/));
+			const chunkIds = chunks.map(c => c.id);
+			expect(new Set(chunkIds).size).toBe(chunkIds.length);
+			chunkIds.forEach(id => expect(id).toMatch(/^[0-9a-f]{3,}$/));
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	optimization: {
+		moduleIds: "deterministic",
+		chunkIds: "deterministic"
+	},
+	plugins: [new Plugin()]
+};
@@ -0,0 +1 @@
+export const accessedDynamically = "dynamic";
@@ -0,0 +1,21 @@
+import { somethingVeryLong, anotherVeryLongName, ok } from "./module";
+import * as dynamic from "./dynamic";
+
+const getterOf = name => ["'", name, "': function"].join("");
+
+it("should mangle the exports accessed by static member accesses", () => {
+	expect(somethingVeryLong).toBe(1);
+	expect(anotherVeryLongName()).toBe(2);
+	expect(ok).toBe(3);
+	const content = require("fs").readFileSync(__filename, "utf-8");
+	expect(content).not.toContain(getterOf("somethingVeryLong"));
+	expect(content).not.toContain(getterOf("anotherVeryLongName"));
+	expect(content).toContain(getterOf("ok"));
+});
+
+it("should keep the exports of the namespace object used as a whole", () => {
+	const key = ["accessed", "Dynamically"].join("");
+	expect(dynamic[key]).toBe("dynamic");
+	const content = require("fs").readFileSync(__filename, "utf-8");
+	expect(content).toContain(getterOf(key));
+});
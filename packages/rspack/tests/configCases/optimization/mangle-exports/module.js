@@ -0,0 +1,7 @@
+export const somethingVeryLong = 1;
+
+export function anotherVeryLongName() {
+	return somethingVeryLong + 1;
+}
+
+export const ok = 3;
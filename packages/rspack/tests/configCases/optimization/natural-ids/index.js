@@ -0,0 +1,7 @@
+import a from "./a";
+
+it("should load modules with natural ids", async () => {
+	expect(a).toBe("a");
+	const { default: b } = await import("./b");
+	expect(b).toBe("b");
+});
@@ -0,0 +1,26 @@
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const { modules, chunks } = stats.toJson({
+				all: false,
+				modules: true,
+				chunks: true
+			});
+			const moduleIds = modules.map(m => m.id).sort((a, b) => a - b);
+			expect(moduleIds).toEqual(moduleIds.map((_, i) => String(i)));
+			const chunkIds = chunks.map(c => c.id).sort((a, b) => a - b);
+			expect(chunkIds).toEqual(chunkIds.map((_, i) => String(i)));
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	optimization: {
+		moduleIds: "natural",
+		chunkIds: "natural"
+	},
+	plugins: [new Plugin()]
+};
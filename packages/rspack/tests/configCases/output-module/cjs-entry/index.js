@@ -0,0 +1,5 @@
+it("should execute a CommonJS entry as module", () => {
+	expect(module.exports.value).toBe(1);
+});
+
+module.exports = { value: 1 };
@@ -0,0 +1,40 @@
+const assert = require("assert").strict;
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const source = stats.compilation
+				.getAsset("main.js")
+				.source.source()
+				.toString();
+			assert(
+				source.includes("export { __webpack_exports__default as default }"),
+				"should export module.exports of a CommonJS entry as default"
+			);
+			assert(
+				source.includes("const __webpack_exec__ = (moduleId) =>"),
+				"should use arrow functions and const in ESM output"
+			);
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		filename: "[name].js",
+		chunkFormat: "module",
+		chunkLoading: "import",
+		library: {
+			type: "module"
+		}
+	},
+	experiments: {
+		outputModule: true
+	},
+	optimization: {
+		runtimeChunk: true
+	},
+	plugins: [new Plugin()]
+};
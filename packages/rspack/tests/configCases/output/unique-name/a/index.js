@@ -0,0 +1,4 @@
+it("should load the chunk of the build a", async () => {
+	const { value } = await import("./chunk");
+	expect(value).toBe("a");
+});
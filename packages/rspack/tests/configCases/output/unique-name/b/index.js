@@ -0,0 +1,10 @@
+it("should load the chunk of the build b", async () => {
+	const { value } = await import("./chunk");
+	expect(value).toBe("b");
+});
+
+it("should use a chunk loading global for each build", () => {
+	expect(Array.isArray(window["webpackChunkapp_a"])).toBe(true);
+	expect(Array.isArray(window["webpackChunkapp_b"])).toBe(true);
+	expect(window["webpackChunkapp_a"]).not.toBe(window["webpackChunkapp_b"]);
+});
@@ -0,0 +1,28 @@
+const fs = require("fs");
+const path = require("path");
+const vm = require("vm");
+
+module.exports = {
+	findBundle(i) {
+		// Both builds run on the same page
+		return i === 0 ? ["a.js", "b.js"] : [];
+	},
+	moduleScope(scope) {
+		const document = scope.window.document;
+		const appendChild = document.head.appendChild;
+		// Execute the chunks like a browser does
+		document.head.appendChild = function (node) {
+			if (node._type === "script") {
+				const file = node.src.slice("https://test.cases/path/".length);
+				const code = fs.readFileSync(
+					path.join(document._basePath, file),
+					"utf-8"
+				);
+				vm.runInThisContext(`(function (self) {${code}\n})`, {
+					filename: file
+				})(scope.window);
+			}
+			return appendChild.call(this, node);
+		};
+	}
+};
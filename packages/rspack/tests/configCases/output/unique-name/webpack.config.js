@@ -0,0 +1,13 @@
+const createConfig = name => ({
+	entry: `./${name}/index.js`,
+	target: "web",
+	output: {
+		uniqueName: `app-${name}`,
+		publicPath: "https://test.cases/path/",
+		filename: `${name}.js`,
+		chunkFilename: `${name}.[name].js`
+	}
+});
+
+/** @type {import("../../../../").Configuration[]} */
+module.exports = [createConfig("a"), createConfig("b")];
@@ -0,0 +1 @@
+define(["./missing"], function () {});
@@ -0,0 +1,7 @@
+it("should not parse define when amd is false", () => {
+	const defined = [];
+	globalThis.define = deps => defined.push(deps);
+	require("./a");
+	delete globalThis.define;
+	expect(defined).toEqual([["./missing"]]);
+});
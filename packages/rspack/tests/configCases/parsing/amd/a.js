@@ -0,0 +1,3 @@
+define(["./b", "exports"], function (b, exports) {
+	exports.value = b + 1;
+});
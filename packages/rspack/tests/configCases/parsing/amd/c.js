@@ -0,0 +1,3 @@
+define("c", ["require", "module"], function (req, module) {
+	module.exports = { b: req("./b"), id: module.id };
+});
@@ -0,0 +1,4 @@
+var factory = function (a) {
+	return "e" + a.value;
+};
+define(["./a"], factory);
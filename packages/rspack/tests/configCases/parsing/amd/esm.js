@@ -0,0 +1,5 @@
+define(["exports"], function (exports) {
+	Object.defineProperty(exports, "__esModule", { value: true });
+	exports.default = "default";
+	exports.named = "named";
+});
@@ -0,0 +1,4 @@
+export default "harmony";
+export const named = "harmony-named";
+
+define(function () {});
@@ -0,0 +1,30 @@
+import * as a from "./a";
+import b from "./b";
+import esm, { named } from "./esm";
+import harmony, { named as harmonyNamed } from "./harmony";
+
+it("should require the dependency array", () => {
+	expect(a.value).toBe(2);
+	expect(require("./e")).toBe("e2");
+});
+
+it("should use the returned value as the exports", () => {
+	expect(b).toBe(1);
+	expect(require("./d")).toEqual({ name: "d" });
+});
+
+it("should support require and module in the dependency array", () => {
+	const c = require("./c");
+	expect(c.b).toBe(1);
+	expect(c.id).toBe(require.resolve("./c"));
+});
+
+it("should respect __esModule of the exports", () => {
+	expect(esm).toBe("default");
+	expect(named).toBe("named");
+});
+
+it("should keep the exports of an ES module calling define", () => {
+	expect(harmony).toBe("harmony");
+	expect(harmonyNamed).toBe("harmony-named");
+});
@@ -0,0 +1,2 @@
+const a = 1;
+Object.assign(module.exports, { a, b: 2 }, { "c": 3 });
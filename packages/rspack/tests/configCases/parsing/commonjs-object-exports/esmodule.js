@@ -0,0 +1 @@
+module.exports = { __esModule: true, default: "default", a: 1 };
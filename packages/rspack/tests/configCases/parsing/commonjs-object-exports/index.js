@@ -0,0 +1,16 @@
+import assign from "./assign";
+import literal, { b } from "./literal";
+import spread from "./spread";
+import esmodule, { a } from "./esmodule";
+
+it("should provide the exports of the object literals", () => {
+	expect(assign).toEqual({ a: 1, b: 2, c: 3 });
+	expect(literal.a).toBe(1);
+	expect(b()).toBe(2);
+	expect(spread).toEqual({ a: 1, b: 2 });
+});
+
+it("should respect the __esModule of the object literal", () => {
+	expect(esmodule).toBe("default");
+	expect(a).toBe(1);
+});
@@ -0,0 +1,6 @@
+module.exports = {
+	a: 1,
+	b() {
+		return 2;
+	}
+};
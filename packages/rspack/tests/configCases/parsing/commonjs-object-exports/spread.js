@@ -0,0 +1,2 @@
+const base = { a: 1 };
+module.exports = { ...base, b: 2 };
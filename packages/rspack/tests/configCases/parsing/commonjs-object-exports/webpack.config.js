@@ -0,0 +1,40 @@
+const path = require("path");
+const assert = require("assert").strict;
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap(pluginName, compilation => {
+			compilation.hooks.processAssets.tap(pluginName, () => {
+				const buildMeta = name =>
+					compilation.modules.find(
+						module => module.resource === path.resolve(__dirname, name)
+					).buildMeta;
+				assert.equal(buildMeta("assign.js").exportsType, "default");
+				assert.deepEqual(buildMeta("assign.js").providedExports, [
+					"a",
+					"b",
+					"c"
+				]);
+				assert.equal(buildMeta("literal.js").exportsType, "default");
+				assert.deepEqual(buildMeta("literal.js").providedExports, ["a", "b"]);
+				// The spread object may provide `__esModule`
+				assert.equal(buildMeta("spread.js").exportsType, "dynamic");
+				assert.equal(buildMeta("spread.js").providedExports, undefined);
+				assert.equal(buildMeta("esmodule.js").exportsType, "flagged");
+				assert.deepEqual(buildMeta("esmodule.js").providedExports, [
+					"default",
+					"a"
+				]);
+			});
+		});
+	}
+}
+
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	plugins: [new Plugin()]
+};
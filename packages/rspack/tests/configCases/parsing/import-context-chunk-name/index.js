@@ -0,0 +1,15 @@
+import fs from "fs";
+import path from "path";
+
+function load(name) {
+	return import(/* webpackChunkName: "page-[request]" */ `./pages/${name}.js`);
+}
+
+it("should name the chunks of the context elements", async () => {
+	const { default: home } = await load("home");
+	expect(home).toBe("home");
+	const { default: about } = await load("about");
+	expect(about).toBe("about");
+	expect(fs.existsSync(path.resolve(__dirname, "page-home-js.js"))).toBe(true);
+	expect(fs.existsSync(path.resolve(__dirname, "page-about-js.js"))).toBe(true);
+});
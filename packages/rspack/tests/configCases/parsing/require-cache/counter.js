@@ -0,0 +1,2 @@
+globalThis.requireCacheCount = (globalThis.requireCacheCount || 0) + 1;
+module.exports = { count: globalThis.requireCacheCount };
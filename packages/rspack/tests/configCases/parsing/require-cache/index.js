@@ -0,0 +1,11 @@
+it("should execute the module again after deleting it from require.cache", () => {
+	const first = require("./counter");
+	expect(require("./counter")).toBe(first);
+	expect(Object.keys(require.cache)).toContain(
+		String(require.resolve("./counter"))
+	);
+	delete require.cache[require.resolve("./counter")];
+	const second = require("./counter");
+	expect(second).not.toBe(first);
+	expect(second.count).toBe(first.count + 1);
+});
@@ -0,0 +1,5 @@
+const value = "__NOT_MINIMIZED__";
+
+it("should replace the asset with the result of the JS minimizer", () => {
+	expect(value).toBe("__" + "MINIMIZED__");
+});
@@ -0,0 +1,37 @@
+class JsMinimizerPlugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap("JsMinimizerPlugin", compilation => {
+			compilation.minimizeAssets(
+				{ name: "JsMinimizerPlugin", test: /\.js$/ },
+				({ name, source, info }) => {
+					expect(name).toBe("main.js");
+					expect(info.minimized).toBe(false);
+					return {
+						code: source
+							.source()
+							.toString()
+							.replace(/__NOT_MINIMIZED__/g, "__MINIMIZED__")
+					};
+				}
+			);
+			compilation.hooks.processAssets.tap(
+				{
+					name: "JsMinimizerPlugin",
+					stage: compilation.constructor.PROCESS_ASSETS_STAGE_REPORT
+				},
+				() => {
+					expect(compilation.getAsset("main.js").info.minimized).toBe(true);
+				}
+			);
+		});
+	}
+}
+
+/**@type {import('@rspack/cli').Configuration}*/
+module.exports = {
+	target: "node",
+	optimization: {
+		minimize: true,
+		minimizer: ["...", new JsMinimizerPlugin()]
+	}
+};
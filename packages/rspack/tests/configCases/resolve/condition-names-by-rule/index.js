@@ -0,0 +1,15 @@
+import value from "conditional";
+import replaced from "./worker/replace";
+import extended from "./extend";
+
+it("should resolve with the default condition names", () => {
+	expect(value).toBe("browser");
+});
+
+it("should replace the condition names by the rule", () => {
+	expect(replaced).toBe("worker");
+});
+
+it("should extend the condition names by the rule with '...'", () => {
+	expect(extended).toBe("browser");
+});
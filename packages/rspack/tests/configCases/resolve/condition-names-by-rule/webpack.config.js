@@ -0,0 +1,19 @@
+module.exports = {
+	target: "web",
+	module: {
+		rules: [
+			{
+				test: /worker[\\/]replace\.js$/,
+				resolve: {
+					conditionNames: ["worker"]
+				}
+			},
+			{
+				test: /extend[\\/]index\.js$/,
+				resolve: {
+					conditionNames: ["worker", "..."]
+				}
+			}
+		]
+	}
+};
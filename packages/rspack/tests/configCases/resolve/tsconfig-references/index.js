@@ -0,0 +1,10 @@
+import appValue from "@app/value";
+import libValue from "./packages/lib";
+
+it("should map the paths of the extended tsconfig", () => {
+	expect(appValue).toBe("app");
+});
+
+it("should map the paths of the referenced project", () => {
+	expect(libValue).toBe("lib");
+});
@@ -0,0 +1,13 @@
+const path = require("path");
+
+module.exports = {
+	entry: {
+		main: "./index.js"
+	},
+	resolve: {
+		tsconfig: {
+			configFile: path.resolve(__dirname, "./tsconfig.json"),
+			references: "auto"
+		}
+	}
+};
@@ -0,0 +1,7 @@
+import { value as esmValue } from "./lib";
+
+it("`dependency` should match the category of the dependency", () => {
+	const { value: cjsValue } = require("./lib");
+	expect(esmValue).toBe("lib(#)esm");
+	expect(cjsValue).toBe("lib(#)commonjs");
+});
@@ -0,0 +1,20 @@
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /lib\.js$/,
+				dependency: "esm",
+				use: { loader: "./loader.js", options: { suffix: "esm" } }
+			},
+			{
+				test: /lib\.js$/,
+				dependency: { not: ["esm"] },
+				use: { loader: "./loader.js", options: { suffix: "commonjs" } }
+			}
+		]
+	}
+};
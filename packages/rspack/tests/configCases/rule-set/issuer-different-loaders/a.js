@@ -0,0 +1 @@
+export { shared as a } from "./shared";
@@ -0,0 +1 @@
+export { shared as b } from "./shared";
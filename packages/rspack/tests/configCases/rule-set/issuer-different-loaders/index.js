@@ -0,0 +1,7 @@
+import { a } from "./a";
+import { b } from "./b";
+
+it("should create a module for each loader list of the same resource", () => {
+	expect(a).toBe("shared(#)a");
+	expect(b).toBe("shared(#)b");
+});
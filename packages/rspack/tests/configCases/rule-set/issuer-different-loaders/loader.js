@@ -0,0 +1,4 @@
+module.exports = function (content) {
+	const { suffix } = this.getOptions();
+	this.callback(null, content.replace("(#)", "(#)" + suffix));
+};
@@ -0,0 +1,20 @@
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /shared\.js$/,
+				issuer: /a\.js$/,
+				use: { loader: "./loader.js", options: { suffix: "a" } }
+			},
+			{
+				test: /shared\.js$/,
+				issuer: /b\.js$/,
+				use: { loader: "./loader.js", options: { suffix: "b" } }
+			}
+		]
+	}
+};
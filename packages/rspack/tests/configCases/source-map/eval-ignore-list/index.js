@@ -0,0 +1,20 @@
+import app from "./app";
+import lib from "lib";
+
+it("should list the sources of node_modules in ignoreList of eval source maps", () => {
+	expect(app + lib).toBe("applib");
+	const fs = require("fs");
+	const code = fs.readFileSync(__filename, "utf-8");
+	const maps = Array.from(
+		code.matchAll(
+			/sourceMappingURL=data:application\/json;charset=utf-8;base64,([A-Za-z0-9+/=]+)/g
+		),
+		([, base64]) =>
+			JSON.parse(Buffer.from(base64, "base64").toString("utf-8"))
+	);
+	const libMap = maps.find(map => /node_modules/.test(map.sources[0]));
+	expect(libMap.ignoreList).toEqual([0]);
+	expect(libMap.x_google_ignoreList).toEqual([0]);
+	const appMap = maps.find(map => /app\.js/.test(map.sources[0]));
+	expect(appMap.ignoreList).toBeUndefined();
+});
@@ -0,0 +1,3 @@
+module.exports = {
+	devtool: "eval-nosources-cheap-module-source-map"
+};
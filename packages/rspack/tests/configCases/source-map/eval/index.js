@@ -0,0 +1,7 @@
+it("should evaluate modules with sourceURL if eval option is used", function () {
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(/sourceURL=webpack:\/\/.*\.\/index\.js/.test(source)).toBe(true);
+	expect(/sourceMappingURL\s*=/.test(source)).toBe(false);
+	expect(fs.existsSync(__filename + ".map")).toBe(false);
+});
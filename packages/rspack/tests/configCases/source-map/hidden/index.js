@@ -6,4 +6,5 @@ it("should not have map from url comments if hidden options is used", function (
 	const map = JSON.parse(mapSource);
 	expect(map.sources).toContain("./index.js");
 	expect(map.file).toEqual("main.js");
+	expect(map.sourcesContent[0]).toContain("hidden options");
 });
@@ -0,0 +1,14 @@
+import app from "./app";
+import lib from "lib";
+
+it("should list the sources of node_modules in ignoreList", () => {
+	expect(app + lib).toBe("applib");
+	const fs = require("fs");
+	const map = JSON.parse(fs.readFileSync(__filename + ".map", "utf-8"));
+	const ignored = map.sources
+		.map((source, index) => (/node_modules/.test(source) ? index : -1))
+		.filter(index => index !== -1);
+	expect(ignored.length).toBe(1);
+	expect(map.ignoreList).toEqual(ignored);
+	expect(map.x_google_ignoreList).toEqual(ignored);
+});
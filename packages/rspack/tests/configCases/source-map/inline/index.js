@@ -8,4 +8,5 @@ it("should have map from url comments if inline options is used", function () {
 	const map = JSON.parse(Buffer.from(base64, "base64").toString("utf-8"));
 	expect(map.sources).toContain("./index.js");
 	expect(map.file).toEqual("main.js");
+	expect(map.sourcesContent[0]).toContain("inline options");
 });
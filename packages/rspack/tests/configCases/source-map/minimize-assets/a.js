@@ -0,0 +1,3 @@
+export function a0() {
+	return "*a0*";
+}
@@ -0,0 +1,11 @@
+import { a0 } from "./a";
+const checkMap = require("../checkSourceMap").default;
+
+it("should compose the source map of the JS minimizer with the asset's", async () => {
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename + ".map", "utf-8");
+	const out = fs.readFileSync(__filename, "utf-8");
+	expect(a0()).toBe("*a0*");
+	expect(out.startsWith("// minimized\n// minimized\n")).toBe(true);
+	expect(await checkMap(out, source, { ['"*a0*"']: "a.js" })).toBe(true);
+});
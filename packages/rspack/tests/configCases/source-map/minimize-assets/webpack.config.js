@@ -0,0 +1,31 @@
+const { OriginalSource, ReplaceSource } = require("webpack-sources");
+
+class JsMinimizerPlugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap("JsMinimizerPlugin", compilation => {
+			compilation.minimizeAssets(
+				{ name: "JsMinimizerPlugin", test: /\.js$/ },
+				({ name, source }) => {
+					// Shift the lines, the result map is from the asset to the code
+					const replaced = new ReplaceSource(
+						new OriginalSource(source.source().toString(), name)
+					);
+					replaced.insert(0, "// minimized\n// minimized\n");
+					const { source: code, map } = replaced.sourceAndMap();
+					return { code, map };
+				}
+			);
+		});
+	}
+}
+
+/**@type {import('@rspack/cli').Configuration}*/
+module.exports = {
+	devtool: "source-map",
+	externals: ["source-map"],
+	externalsType: "commonjs",
+	optimization: {
+		minimize: true,
+		minimizer: [new JsMinimizerPlugin()]
+	}
+};
@@ -0,0 +1,4 @@
+import "./b";
+import "./c";
+
+it("should compile fine", () => {});
@@ -0,0 +1,5 @@
+module.exports = {
+	findBundle: function (i, options) {
+		return ["common.js", "main.js"];
+	}
+};
@@ -0,0 +1,51 @@
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap(pluginName, compilation => {
+			compilation.hooks.processAssets.tap(pluginName, () => {
+				const common = compilation.explainChunk("common");
+				expect(common.createdBy).toBe("split chunk (cache group: b)");
+				expect(common.decisions).toHaveLength(1);
+				expect(common.decisions[0].enforce).toBe(true);
+				expect(
+					common.modules.some(m => m.identifier.endsWith("b.js"))
+				).toBe(true);
+
+				const main = compilation.explainChunk("main");
+				expect(main.createdBy).toBe("entry main");
+				const rejected = main.rejected.find(d => d.cacheGroup === "c");
+				expect(rejected.minSizeSatisfied).toBe(false);
+				expect(rejected.rejectedReason).toBeTruthy();
+
+				expect(compilation.explainChunk("not-exist")).toBeNull();
+			});
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		filename: "[name].js"
+	},
+	target: "web",
+	optimization: {
+		splitChunks: {
+			chunks: "all",
+			cacheGroups: {
+				b: {
+					test: /b\.js/,
+					name: "common",
+					enforce: true
+				},
+				c: {
+					test: /c\.js/,
+					name: "small",
+					minSize: 100000
+				}
+			}
+		}
+	},
+	plugins: [new Plugin()]
+};
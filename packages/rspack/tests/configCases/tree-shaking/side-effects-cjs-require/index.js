@@ -0,0 +1,5 @@
+import "./package";
+
+it("should evaluate side effects required by a side effects free module", () => {
+	expect(globalThis.__polyfilled__).toBe(true);
+});
@@ -0,0 +1,3 @@
+require("./polyfill");
+
+export const a = "a";
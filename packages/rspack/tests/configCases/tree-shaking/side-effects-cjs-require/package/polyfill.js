@@ -0,0 +1 @@
+globalThis.__polyfilled__ = true;
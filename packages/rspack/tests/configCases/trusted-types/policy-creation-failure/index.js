@@ -0,0 +1,26 @@
+it("should load chunk with the raw url when the policy can't be created", function () {
+	// emulate trusted types in a window object
+	window.trustedTypes = {
+		createPolicy: () => {
+			throw new Error("Policy already exists");
+		}
+	};
+	const createPolicySpy = jest.spyOn(window.trustedTypes, "createPolicy");
+	const warnSpy = jest.spyOn(console, "warn").mockImplementation(() => {});
+
+	const promise = import(
+		"./empty?b" /* webpackChunkName: "policy-creation-failure" */
+	);
+	var script = document.head._children.pop();
+	__non_webpack_require__("./policy-creation-failure.web.js");
+	expect(script.src).toBe(
+		"https://test.cases/path/policy-creation-failure.web.js"
+	);
+	expect(createPolicySpy).toHaveBeenCalledTimes(1);
+	expect(warnSpy).toHaveBeenCalledWith(
+		'Could not create trusted-types policy "duplicatedPolicyName"'
+	);
+	warnSpy.mockRestore();
+
+	return promise;
+});
@@ -0,0 +1,11 @@
+module.exports = {
+	target: "web",
+	output: {
+		// TODO should be `[name].web.js`
+		chunkFilename: "policy-creation-failure.web.js",
+		trustedTypes: "duplicatedPolicyName"
+	},
+	optimization: {
+		minimize: false
+	}
+};
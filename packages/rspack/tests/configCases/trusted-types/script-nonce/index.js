@@ -0,0 +1,11 @@
+it("should set the nonce on the injected script", function () {
+	window.trustedTypes = undefined;
+	__webpack_nonce__ = "nonce";
+	const promise = import("./empty?b" /* webpackChunkName: "script-nonce" */);
+	var script = document.head._children.pop();
+	__non_webpack_require__("./script-nonce.web.js");
+	expect(script.src).toBe("https://test.cases/path/script-nonce.web.js");
+	expect(script.getAttribute("nonce")).toBe("nonce");
+
+	return promise;
+});
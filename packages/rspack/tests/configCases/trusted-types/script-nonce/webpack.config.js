@@ -0,0 +1,11 @@
+module.exports = {
+	target: "web",
+	output: {
+		// TODO should be `[name].web.js`
+		chunkFilename: "script-nonce.web.js",
+		trustedTypes: "scriptNoncePolicy"
+	},
+	optimization: {
+		minimize: false
+	}
+};
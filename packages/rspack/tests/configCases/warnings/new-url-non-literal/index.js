@@ -0,0 +1,6 @@
+it("should leave URL() with a non-literal request untouched", () => {
+	const name = "./index.js";
+	const url = new URL(name, import.meta.url);
+
+	expect(url.href).toMatch(/index\.js$/);
+});
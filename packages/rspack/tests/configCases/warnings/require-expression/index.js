@@ -0,0 +1,22 @@
+it("should throw a descriptive error at runtime for expression requests", () => {
+	const request = "./locales/en.json";
+	expect(() => require(request)).toThrow(
+		"Cannot find module './locales/en.json'"
+	);
+	try {
+		require(request);
+	} catch (e) {
+		expect(e.code).toBe("MODULE_NOT_FOUND");
+	}
+});
+
+it("should create a context module for requests with a static prefix", () => {
+	const load = lang => require("./locales/" + lang + ".json");
+	expect(load("en").greeting).toBe("hello");
+	expect(load("fr").greeting).toBe("bonjour");
+});
+
+it("should create a context module for template literal requests", () => {
+	const lang = "fr";
+	expect(require(`./locales/${lang}.json`).greeting).toBe("bonjour");
+});
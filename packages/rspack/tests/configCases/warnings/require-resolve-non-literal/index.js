@@ -0,0 +1,11 @@
+it("should resolve literal requests to module ids", () => {
+	expect(require.resolve("./module")).toBe(require.resolve(`./module`));
+	expect(typeof require.resolve).toBe("function");
+});
+
+it("should throw at runtime for non-literal requests", () => {
+	const request = "./module";
+	expect(() => require.resolve(request)).toThrow(
+		"Cannot resolve a non-literal request of require.resolve()"
+	);
+});
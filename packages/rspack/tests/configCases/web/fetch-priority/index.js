@@ -0,0 +1,34 @@
+it("should set the fetchPriority of the chunks on the script elements", () => {
+	const promises = [
+		import(
+			/* webpackChunkName: "shared", webpackFetchPriority: "low" */ "./shared"
+		),
+		import(
+			/* webpackChunkName: "shared", webpackFetchPriority: "high" */ "./shared"
+		),
+		import(/* webpackChunkName: "low", webpackFetchPriority: "low" */ "./low"),
+		import(/* webpackChunkName: "plain" */ "./plain"),
+		import(
+			/* webpackChunkName: "invalid", webpackFetchPriority: "urgent" */ "./invalid"
+		)
+	];
+
+	const fetchPriority = name =>
+		document.head._children
+			.find(
+				child =>
+					child._type === "script" &&
+					child.src === `https://example.com/public/path/${name}.js`
+			)
+			.getAttribute("fetchpriority");
+	// the highest priority of the imports wins
+	expect(fetchPriority("shared")).toBe("high");
+	expect(fetchPriority("low")).toBe("low");
+	expect(fetchPriority("plain")).toBeUndefined();
+	expect(fetchPriority("invalid")).toBeUndefined();
+
+	for (const name of ["shared", "low", "plain", "invalid"]) {
+		__non_webpack_require__(`./${name}.js`);
+	}
+	return Promise.all(promises);
+});
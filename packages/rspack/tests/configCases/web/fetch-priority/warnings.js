@@ -0,0 +1,5 @@
+module.exports = [
+	[
+		/`webpackFetchPriority` expected "low", "high" or "auto", but received: urgent/
+	]
+];
@@ -0,0 +1,11 @@
+/** @type {import("../../../../").Configuration} */
+module.exports = {
+	target: "web",
+	output: {
+		publicPath: "https://example.com/public/path/",
+		chunkFilename: "[name].js"
+	},
+	optimization: {
+		minimize: false
+	}
+};
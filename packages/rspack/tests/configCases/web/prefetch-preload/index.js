@@ -0,0 +1,46 @@
+it("should prefetch and preload child chunks on chunk load", () => {
+	let link, script;
+
+	expect(document.head._children).toHaveLength(1);
+
+	// Test prefetch from entry chunk
+	link = document.head._children[0];
+	expect(link._type).toBe("link");
+	expect(link.rel).toBe("prefetch");
+	expect(link.as).toBe("script");
+	expect(link.href).toBe("https://example.com/public/path/chunk1.js");
+	expect(link.crossOrigin).toBe("anonymous");
+
+	const promise = import(
+		/* webpackChunkName: "chunk1", webpackPrefetch: true */ "./chunk1"
+	);
+
+	expect(document.head._children).toHaveLength(3);
+
+	// Test normal script loading
+	script = document.head._children.find(child => child._type === "script");
+	expect(script.src).toBe("https://example.com/public/path/chunk1.js");
+
+	// Test preload of chunk1-b
+	link = document.head._children.find(child => child.rel === "preload");
+	expect(link._type).toBe("link");
+	expect(link.as).toBe("script");
+	expect(link.href).toBe("https://example.com/public/path/chunk1-b.js");
+	expect(link.charset).toBe("utf-8");
+	expect(link.crossOrigin).toBe("anonymous");
+
+	// Run the script
+	__non_webpack_require__("./chunk1.js");
+
+	return promise.then(() => {
+		// Test prefetching for chunk1-c and chunk1-a in this order
+		const prefetched = document.head._children
+			.filter(child => child.rel === "prefetch")
+			.map(child => child.href);
+		expect(prefetched).toEqual([
+			"https://example.com/public/path/chunk1.js",
+			"https://example.com/public/path/chunk1-c.js",
+			"https://example.com/public/path/chunk1-a.js"
+		]);
+	});
+});
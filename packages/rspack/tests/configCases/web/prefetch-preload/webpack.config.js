@@ -0,0 +1,12 @@
+/** @type {import("../../../../").Configuration} */
+module.exports = {
+	target: "web",
+	output: {
+		publicPath: "https://example.com/public/path/",
+		chunkFilename: "[name].js",
+		crossOriginLoading: "anonymous"
+	},
+	optimization: {
+		minimize: false
+	}
+};
@@ -0,0 +1,22 @@
+import fs from "fs";
+import path from "path";
+
+function startWorker() {
+	return new Worker(new URL("./worker.js", import.meta.url), {
+		type: "module"
+	});
+}
+
+it("should keep the type of module workers", () => {
+	expect(typeof startWorker).toBe("function");
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toContain(["}, { type: ", '"module" })'].join(""));
+});
+
+it("should render the chunks of module workers as ES modules", () => {
+	const chunks = fs
+		.readdirSync(__dirname)
+		.filter(file => file.endsWith(".js") && file !== path.basename(__filename))
+		.map(file => fs.readFileSync(path.join(__dirname, file), "utf-8"));
+	expect(chunks.some(chunk => chunk.startsWith("export const ids"))).toBe(true);
+});
@@ -0,0 +1,6 @@
+module.exports = {
+	output: {
+		// The chunks of module workers are loaded by `import()` only if it's enabled
+		enabledChunkLoadingTypes: ["require", "import"]
+	}
+};
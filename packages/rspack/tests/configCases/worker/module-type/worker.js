@@ -0,0 +1,4 @@
+onmessage = async event => {
+	const { default: answer } = await import("./answer");
+	postMessage(answer + event.data);
+};
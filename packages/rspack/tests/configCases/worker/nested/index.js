@@ -0,0 +1,11 @@
+import fs from "fs";
+
+function startWorker() {
+	return new Worker(new URL("./worker.js", import.meta.url));
+}
+
+it("should create chunks for nested workers", () => {
+	expect(typeof startWorker).toBe("function");
+	const scripts = fs.readdirSync(__dirname).filter(file => file.endsWith(".js"));
+	expect(scripts).toHaveLength(3);
+});
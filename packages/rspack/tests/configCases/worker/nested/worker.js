@@ -0,0 +1,5 @@
+const nested = new Worker(new URL("./nested.js", import.meta.url));
+
+onmessage = event => {
+	nested.postMessage(event.data);
+};
@@ -1,11 +1,13 @@
 import path from "path";
 import fs from "fs";
+import os from "os";
 
 import { rspack } from "../../src";
 
 import { run, runEmit, runChange } from "./helpers/run";
 
 import { readAssets, getCompiler, compile } from "./helpers";
+import readAsset from "./helpers/readAsset";
 
 const FIXTURES_DIR = path.join(__dirname, "fixtures");
 
@@ -197,6 +199,186 @@ describe("CopyPlugin", () => {
 				.catch(done);
 		});
 
+		it("should interpolate the template of `to`", done => {
+			run({
+				patterns: [
+					{
+						from: "directory",
+						to: "[path][name].[contenthash:8][ext]"
+					}
+				]
+			})
+				.then(({ compilation }) => {
+					const names = Object.keys(compilation.assets)
+						.filter(a => a !== "main.js")
+						.sort();
+
+					expect(names).toHaveLength(4);
+					for (const name of names) {
+						expect(name).toMatch(/\.[0-9a-f]{8}(\.txt)?$/);
+						expect(compilation.getAsset(name).info.immutable).toBe(true);
+					}
+					expect(
+						names.some(name =>
+							/^nested\/nestedfile\.[0-9a-f]{8}\.txt$/.test(name)
+						)
+					).toBe(true);
+				})
+				.then(done)
+				.catch(done);
+		});
+
+		it("should copy a file to the target returned by the `to` function", done => {
+			const calls = [];
+
+			runEmit({
+				expectedAssetKeys: ["newdirectory/file-copied.txt"],
+				patterns: [
+					{
+						from: "file.txt",
+						to(pathData) {
+							calls.push(pathData);
+							return "newdirectory/[name]-copied[ext]";
+						}
+					}
+				]
+			})
+				.then(() => {
+					expect(calls).toEqual([
+						{
+							context: FIXTURES_DIR,
+							absoluteFilename: path.join(FIXTURES_DIR, "file.txt")
+						}
+					]);
+				})
+				.then(done)
+				.catch(done);
+		});
+
+		it("should copy an absolute glob outside of the context relative to its own directory", done => {
+			runEmit({
+				expectedAssetKeys: [
+					"fs.js",
+					"path.js",
+					"process.js",
+					"stream.js",
+					"url.js",
+					"util.js"
+				],
+				patterns: [
+					{
+						from: path.resolve(__dirname, "helpers/built-in-modules/*.js")
+					}
+				]
+			})
+				.then(done)
+				.catch(done);
+		});
+
+		it("should write the absolute target outside of the output path directly", done => {
+			const outside = path.join(os.tmpdir(), "rspack-copy-plugin-outside");
+			fs.rmSync(outside, { recursive: true, force: true });
+
+			run({
+				patterns: [
+					{
+						from: "file.txt",
+						to: path.join(outside, "file.txt")
+					}
+				]
+			})
+				.then(() => done(new Error("should have warned")))
+				.catch(error => {
+					expect(error.message).toMatch(
+						/is copied to .* outside of the output path, which is written directly/
+					);
+					expect(fs.readFileSync(path.join(outside, "file.txt"), "utf-8")).toBe(
+						"new"
+					);
+					done();
+				});
+		});
+
+		it("should warn and keep the former when different files are copied to the same target", done => {
+			run({
+				patterns: [
+					{
+						from: "file.txt",
+						to: "same.txt"
+					},
+					{
+						from: "directory/directoryfile.txt",
+						to: "same.txt"
+					}
+				]
+			})
+				.then(() => done(new Error("should have warned")))
+				.catch(error => {
+					expect(error.message).toMatch(
+						/Multiple source files are copied to the same target 'same\.txt'.*the former is kept/
+					);
+					done();
+				});
+		});
+
+		it("should error when the target conflicts with the asset emitted by the compilation", done => {
+			run({
+				patterns: [
+					{
+						from: "file.txt",
+						to: "main.js"
+					}
+				]
+			})
+				.then(() => done(new Error("should have failed")))
+				.catch(error => {
+					expect(error.message).toMatch(
+						/conflicts with the asset emitted by the compilation/
+					);
+					done();
+				});
+		});
+
+		it("should overwrite the asset emitted by the compilation with `force`", done => {
+			run({
+				patterns: [
+					{
+						from: "file.txt",
+						to: "main.js",
+						force: true
+					}
+				]
+			})
+				.then(({ compiler, stats }) => {
+					expect(readAsset("main.js", compiler, stats)).toBe("new");
+				})
+				.then(done)
+				.catch(done);
+		});
+
+		it("should overwrite the target with `force` on the higher priority pattern", done => {
+			runEmit({
+				expectedAssetKeys: ["same.txt"],
+				expectedAssetContent: {
+					"same.txt": "new"
+				},
+				patterns: [
+					{
+						from: "file.txt",
+						to: "same.txt"
+					},
+					{
+						from: "directory/directoryfile.txt",
+						to: "same.txt",
+						force: true,
+						priority: 10
+					}
+				]
+			})
+				.then(done)
+				.catch(done);
+		});
+
 		it("should works with multiple patterns as String", done => {
 			runEmit({
 				expectedAssetKeys: ["binextension.bin", "file.txt", "noextension"],
@@ -491,6 +673,50 @@ describe("CopyPlugin", () => {
 				.catch(done);
 		});
 
+		it("should add the base directory of a nested glob to the watch list", done => {
+			run({
+				patterns: [
+					{
+						from: "directory/nested/**/*"
+					}
+				]
+			})
+				.then(({ stats }) => {
+					const { contextDependencies, fileDependencies } = stats.compilation;
+
+					expect(
+						contextDependencies.has(path.join(FIXTURES_DIR, "directory/nested"))
+					).toBe(true);
+					expect(
+						fileDependencies.has(
+							path.join(FIXTURES_DIR, "directory/nested/nestedfile.txt")
+						)
+					).toBe(true);
+				})
+				.then(done)
+				.catch(done);
+		});
+
+		it("should add a missing glob base to the missing dependencies", done => {
+			run({
+				patterns: [
+					{
+						from: "not-exists/**/*",
+						noErrorOnMissing: true
+					}
+				]
+			})
+				.then(({ stats }) => {
+					const { missingDependencies } = stats.compilation;
+
+					expect(
+						missingDependencies.has(path.join(FIXTURES_DIR, "not-exists"))
+					).toBe(true);
+				})
+				.then(done)
+				.catch(done);
+		});
+
 		it("should not add the directory to the watch list when glob is a file", done => {
 			const expectedAssetKeys = ["directoryfile.txt"];
 
@@ -0,0 +1,28 @@
+module.exports = {
+	validate(stats) {
+		const single0 = stats.stats[0].toJson({ assets: true });
+		const single1 = stats.stats[1].toJson({ assets: true });
+		const embedded0 = stats.stats[2].toJson({ assets: true });
+		const embedded1 = stats.stats[3].toJson({ assets: true });
+
+		// only the async route module changed
+		expect(single0.assetsByChunkName.route).not.toEqual(
+			single1.assetsByChunkName.route
+		);
+		expect(single0.assetsByChunkName.main).toEqual(
+			single1.assetsByChunkName.main
+		);
+		// the runtime references the new filename of the route chunk
+		expect(single0.assetsByChunkName.runtime).not.toEqual(
+			single1.assetsByChunkName.runtime
+		);
+
+		// the entry embeds the runtime, so it has to reference the new route chunk as well
+		expect(embedded0.assetsByChunkName.route).not.toEqual(
+			embedded1.assetsByChunkName.route
+		);
+		expect(embedded0.assetsByChunkName.main).not.toEqual(
+			embedded1.assetsByChunkName.main
+		);
+	}
+};
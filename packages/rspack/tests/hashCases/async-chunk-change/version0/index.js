@@ -0,0 +1,2 @@
+import(/* webpackChunkName: "route" */ "./route");
+console.log("entry");
@@ -0,0 +1 @@
+export const route = "version1";
@@ -0,0 +1,25 @@
+module.exports = {
+	validate(stats) {
+		const single0 = stats.stats[0].toJson({ assets: true });
+		const single1 = stats.stats[1].toJson({ assets: true });
+		const multiple0 = stats.stats[2].toJson({ assets: true });
+		const multiple1 = stats.stats[3].toJson({ assets: true });
+
+		expect(single0.assetsByChunkName.runtime).toHaveLength(1);
+		expect(single0.assetsByChunkName.main).not.toEqual(
+			single1.assetsByChunkName.main
+		);
+		// only the entry module changed
+		expect(single0.assetsByChunkName.runtime).toEqual(
+			single1.assetsByChunkName.runtime
+		);
+
+		expect(multiple0.assetsByChunkName["runtime~main"]).toHaveLength(1);
+		expect(multiple0.assetsByChunkName.main).not.toEqual(
+			multiple1.assetsByChunkName.main
+		);
+		expect(multiple0.assetsByChunkName["runtime~main"]).toEqual(
+			multiple1.assetsByChunkName["runtime~main"]
+		);
+	}
+};
@@ -0,0 +1,2 @@
+import("./lazy");
+console.log("version0");
@@ -0,0 +1,26 @@
+const path = require("path");
+
+function config(subpath, runtimeChunk) {
+	return {
+		entry: `./index.js`,
+		context: path.resolve(__dirname, subpath),
+		output: {
+			path: path.resolve(__dirname, `dist/${runtimeChunk}/${subpath}`),
+			filename: "[name].[contenthash].js",
+			chunkFilename: "[name].[contenthash].js"
+		},
+		optimization: {
+			moduleIds: "named",
+			minimize: false,
+			runtimeChunk
+		}
+	};
+}
+
+/** @type {import("../../../dist").Configuration} */
+module.exports = [
+	config("version0", "single"),
+	config("version1", "single"),
+	config("version0", "multiple"),
+	config("version1", "multiple")
+];
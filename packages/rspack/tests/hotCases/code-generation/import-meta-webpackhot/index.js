@@ -0,0 +1,23 @@
+import x from "./module";
+
+it("should replace import.meta.webpackHot with module.hot", done => {
+	expect(x).toEqual("ok1");
+	expect(typeof import.meta.webpackHot).toBe("object");
+	expect(import.meta.webpackHot).toBe(module.hot);
+
+	function nested(module) {
+		// `module` is shadowed here, but `import.meta.webpackHot` still refers to the module itself
+		const meta = import.meta;
+		expect(typeof meta.url).toBe("string");
+		expect(meta.webpackHot).toBe(import.meta.webpackHot);
+		expect(module).toBe(undefined);
+		return meta.webpackHot;
+	}
+
+	nested(undefined).accept("./module", () => {
+		expect(x).toEqual("ok2");
+		done();
+	});
+
+	NEXT(require("../../update")(done));
+});
@@ -0,0 +1,11 @@
+import b from "./b";
+export default b + 1;
+---
+]});
+export default 2;
+---
+import b from "./b";
+]});
+---
+import b from "./b";
+export default b + 3;
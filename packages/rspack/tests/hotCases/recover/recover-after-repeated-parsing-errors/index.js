@@ -0,0 +1,25 @@
+import a from "./a";
+
+it("should recover after the module fails to build twice", (done) => {
+	expect(a).toBe(11);
+	NEXT(require("../../update")(done, {
+		ignoreErrored: true
+	}, () => {
+		expect(a).toBe(11);
+		NEXT(require("../../update")(done, {
+			ignoreErrored: true
+		}, () => {
+			expect(a).toBe(11);
+			NEXT(require("../../update")(done, {
+				ignoreErrored: true
+			}, () => {
+				expect(a).toBe(13);
+				done();
+			}));
+		}));
+	}));
+});
+
+if(module.hot) {
+	module.hot.accept("./a");
+}
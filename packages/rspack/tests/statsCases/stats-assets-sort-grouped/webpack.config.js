@@ -0,0 +1,54 @@
+// The emitted code changes with the runtime, replace it to keep the sizes stable
+const sizes = {
+	"bundle.js": 400,
+	"a_js.chunk.js": 100,
+	"b_js.chunk.js": 300,
+	"c_js.chunk.js": 200
+};
+
+// Two more assets with another extension to have more than one group
+const extraSizes = {
+	"a.txt": 20,
+	"b.txt": 10
+};
+
+class FixedSizeAssetsPlugin {
+	apply(compiler) {
+		const { RawSource } = compiler.webpack.sources;
+		compiler.hooks.compilation.tap("FixedSizeAssetsPlugin", compilation => {
+			compilation.hooks.processAssets.tap(
+				{
+					name: "FixedSizeAssetsPlugin",
+					stage: compiler.webpack.Compilation.PROCESS_ASSETS_STAGE_REPORT
+				},
+				assets => {
+					for (const name of Object.keys(assets)) {
+						compilation.updateAsset(
+							name,
+							new RawSource("/".repeat(sizes[name])),
+							info => info
+						);
+					}
+					for (const [name, size] of Object.entries(extraSizes)) {
+						compilation.emitAsset(name, new RawSource("-".repeat(size)));
+					}
+				}
+			);
+		});
+	}
+}
+
+module.exports = {
+	output: {
+		chunkFilename: "[name].chunk.js"
+	},
+	stats: {
+		all: false,
+		assets: true,
+		assetsSort: "name",
+		assetsSpace: 7,
+		groupAssetsByEmitStatus: true,
+		groupAssetsByExtension: true
+	},
+	plugins: [new FixedSizeAssetsPlugin()]
+};
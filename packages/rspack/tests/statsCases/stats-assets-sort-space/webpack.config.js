@@ -0,0 +1,43 @@
+// The emitted code changes with the runtime, replace it to keep the sizes stable
+const sizes = {
+	"bundle.js": 400,
+	"a_js.chunk.js": 100,
+	"b_js.chunk.js": 300,
+	"c_js.chunk.js": 200
+};
+
+class FixedSizeAssetsPlugin {
+	apply(compiler) {
+		const { RawSource } = compiler.webpack.sources;
+		compiler.hooks.compilation.tap("FixedSizeAssetsPlugin", compilation => {
+			compilation.hooks.processAssets.tap(
+				{
+					name: "FixedSizeAssetsPlugin",
+					stage: compiler.webpack.Compilation.PROCESS_ASSETS_STAGE_REPORT
+				},
+				assets => {
+					for (const name of Object.keys(assets)) {
+						compilation.updateAsset(
+							name,
+							new RawSource("/".repeat(sizes[name])),
+							info => info
+						);
+					}
+				}
+			);
+		});
+	}
+}
+
+module.exports = {
+	output: {
+		chunkFilename: "[name].chunk.js"
+	},
+	stats: {
+		all: false,
+		assets: true,
+		assetsSort: "!size",
+		assetsSpace: 3
+	},
+	plugins: [new FixedSizeAssetsPlugin()]
+};
@@ -0,0 +1,11 @@
+module.exports = {
+	output: {
+		chunkFilename: "[name].chunk.js"
+	},
+	stats: {
+		all: false,
+		chunks: true,
+		chunksSort: "!id",
+		chunksSpace: 3
+	}
+};
@@ -0,0 +1,10 @@
+module.exports = {
+	optimization: {
+		moduleIds: "named"
+	},
+	stats: {
+		all: false,
+		modules: true,
+		modulesSort: "!size"
+	}
+};
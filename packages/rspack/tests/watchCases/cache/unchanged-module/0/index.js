@@ -0,0 +1,7 @@
+import changed from "./changed";
+import unchanged from "./unchanged";
+
+it("should skip building the modules with unchanged content", function () {
+	expect(changed).toBe(WATCH_STEP);
+	expect(unchanged).toBe("unchanged");
+});
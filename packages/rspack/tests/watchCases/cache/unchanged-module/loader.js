@@ -0,0 +1,8 @@
+// The resources passed through the loader, shared with webpack.config.js
+const calls = [];
+
+module.exports = function (source) {
+	calls.push(this.resourcePath);
+	return source;
+};
+module.exports.calls = calls;
@@ -0,0 +1,41 @@
+const path = require("path");
+const currentWatchStep = require("../../../helpers/currentWatchStep");
+
+const loader = path.resolve(__dirname, "loader.js");
+const { calls } = require(loader);
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap("Plugin", () => {
+			const built = calls.splice(0).map(file => path.basename(file));
+			if (currentWatchStep.step === "0") {
+				expect(built.sort()).toEqual([
+					"changed.js",
+					"index.js",
+					"unchanged.js"
+				]);
+			} else {
+				// unchanged.js is written again with the same content
+				expect(built).toEqual(["changed.js"]);
+			}
+		});
+	}
+}
+
+/** @type {import('@rspack/core').RspackOptions} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.js$/,
+				use: [{ loader }]
+			}
+		]
+	},
+	snapshot: {
+		module: {
+			hash: true
+		}
+	},
+	plugins: [new Plugin()]
+};
@@ -0,0 +1,5 @@
+import { a } from "./a";
+
+it("should keep the module intact if the rebuild fails", () => {
+	expect(a).toBe(WATCH_STEP);
+});
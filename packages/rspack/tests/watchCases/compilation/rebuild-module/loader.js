@@ -0,0 +1,9 @@
+const runs = new WeakMap();
+module.exports = function loader(content) {
+	const times = (runs.get(this._compilation) || 0) + 1;
+	runs.set(this._compilation, times);
+	if (times > 1) {
+		throw new Error("Failed to run the loader again");
+	}
+	return content;
+};
@@ -0,0 +1 @@
+global.extraEntryStep = WATCH_STEP;
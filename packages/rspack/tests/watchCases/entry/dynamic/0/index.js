@@ -0,0 +1,8 @@
+it("should add and remove the entries returned by the function on watch", function () {
+	expect(require("./changing-file")).toBe(WATCH_STEP);
+	if (WATCH_STEP === "1") {
+		expect(global.extraEntryStep).toBe("1");
+	} else {
+		expect(global.extraEntryStep).not.toBe(WATCH_STEP);
+	}
+});
@@ -0,0 +1,12 @@
+const currentWatchStep = require("../../../helpers/currentWatchStep");
+
+/** @type {import('@rspack/core').RspackOptions} */
+module.exports = {
+	// The function is called again by each compilation of watching
+	entry: async () => ({
+		main:
+			currentWatchStep.step === "1"
+				? ["./index.js", "./extra.js"]
+				: ["./index.js"]
+	})
+};
@@ -0,0 +1,3 @@
+it("should resolve the module added to an earlier module directory", function () {
+	expect(require("value")).toBe(WATCH_STEP === "0" ? "second" : "first");
+});
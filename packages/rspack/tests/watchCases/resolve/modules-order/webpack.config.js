@@ -0,0 +1,8 @@
+const path = require("path");
+
+/** @type {import('@rspack/core').RspackOptions} */
+module.exports = (env, { srcPath }) => ({
+	resolve: {
+		modules: [path.join(srcPath, "first"), path.join(srcPath, "second")]
+	}
+});
@@ -0,0 +1,3 @@
+it("should map the requests by the changed tsconfig", function () {
+	expect(require("@/value")).toBe(WATCH_STEP === "0" ? "a" : "b");
+});
@@ -0,0 +1,7 @@
+/** @type {import('@rspack/core').RspackOptions} */
+module.exports = {
+	resolve: {
+		// Resolved against the context
+		tsconfig: "./tsconfig.json"
+	}
+};
@@ -0,0 +1,45 @@
+import "zx/globals";
+
+await import("../meta/check_is_workspace_root.js");
+
+// A wide and shallow module graph with 10k leaf modules and a fan-out of 100,
+// where many modules finish building at the same time.
+const FAN_OUT = 100;
+
+await fs.ensureDir("./benchcases/wide-shallow/src");
+
+for (let i = 0; i < FAN_OUT; i++) {
+	await fs.ensureDir(`./benchcases/wide-shallow/src/${i}`);
+	for (let j = 0; j < FAN_OUT; j++) {
+		await fs.writeFile(
+			`./benchcases/wide-shallow/src/${i}/${j}.js`,
+			`export default ${i * FAN_OUT + j};\n`
+		);
+	}
+	const indexCode = Array(FAN_OUT)
+		.fill(null)
+		.map((_, j) => `export { default as m${j} } from "./${j}.js";`)
+		.join("\n");
+	await fs.writeFile(`./benchcases/wide-shallow/src/${i}/index.js`, indexCode);
+}
+
+const entryCode = Array(FAN_OUT)
+	.fill(null)
+	.map((_, i) => `export * as m${i} from "./${i}/index.js";`)
+	.join("\n");
+
+await fs.writeFile("./benchcases/wide-shallow/src/entry.js", entryCode);
+
+// Create test.config.json
+
+const testConfig = `
+{
+    "entry": {
+        "index": {
+            "import": ["./src/entry.js"]
+        }
+    }
+}
+`;
+
+await fs.writeFile("./benchcases/wide-shallow/test.config.json", testConfig);